@@ -0,0 +1,158 @@
+/*
+ * C interface of pkmcore, matching src/ffi. Keep both in sync when changing either.
+ *
+ * Handles (PkmSave, PkmPokemon, PkmLegality) are owned by the caller and released with the
+ * matching *_free function. Strings and buffers returned by the library are owned by the caller
+ * and released with pkm_string_free and pkm_buffer_free. Every function accepts null handles.
+ *
+ * Only Generation 3 (Ruby/Sapphire/Emerald and FireRed/LeafGreen) saves and Pokemon are supported.
+ */
+
+#ifndef PKMCORE_H
+#define PKMCORE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* GameVersion values returned by pkm_save_game_version */
+#define PKM_GAME_INVALID -2
+#define PKM_GAME_EMERALD 3
+#define PKM_GAME_RUBY_SAPPHIRE 57
+#define PKM_GAME_FIRERED_LEAFGREEN 59
+
+typedef enum PkmStatus {
+    PKM_STATUS_OK = 0,
+    /* A required pointer was null */
+    PKM_STATUS_NULL_POINTER = 1,
+    /* An argument is out of range or can't be stored by the game */
+    PKM_STATUS_INVALID_ARGUMENT = 2,
+    /* The slot has no Pokemon */
+    PKM_STATUS_EMPTY_SLOT = 3,
+    PKM_STATUS_TRUNCATED_DATA = 4,
+    PKM_STATUS_UNSUPPORTED_SIZE = 5,
+    PKM_STATUS_OUT_OF_BOUNDS = 6,
+    PKM_STATUS_INVALID_SPECIES_INDEX = 7,
+    PKM_STATUS_MISSING_OFFSET = 8,
+    PKM_STATUS_UNSUPPORTED = 9,
+    /* Reading or writing a file failed */
+    PKM_STATUS_IO = 10,
+} PkmStatus;
+
+/* Area of the Pokemon a legality check is about */
+typedef enum PkmCheckIdentifier {
+    PKM_CHECK_SPECIES = 0,
+    PKM_CHECK_CHECKSUM = 1,
+    PKM_CHECK_LEVEL = 2,
+    PKM_CHECK_MOVES = 3,
+    PKM_CHECK_MET = 4,
+    PKM_CHECK_BALL = 5,
+    PKM_CHECK_HELD_ITEM = 6,
+    PKM_CHECK_PID_IV = 7,
+    PKM_CHECK_EFFORT_VALUES = 8,
+    PKM_CHECK_ABILITY = 9,
+    PKM_CHECK_NICKNAME = 10,
+    PKM_CHECK_TRAINER = 11,
+    PKM_CHECK_RIBBONS = 12,
+    PKM_CHECK_ENCOUNTER = 13,
+    PKM_CHECK_EVENT = 14,
+} PkmCheckIdentifier;
+
+typedef enum PkmCheckSeverity {
+    PKM_SEVERITY_VALID = 0,
+    PKM_SEVERITY_FISHY = 1,
+    PKM_SEVERITY_INVALID = 2,
+} PkmCheckSeverity;
+
+typedef struct PkmSave PkmSave;
+typedef struct PkmPokemon PkmPokemon;
+typedef struct PkmLegality PkmLegality;
+
+/* Bytes owned by the caller, released with pkm_buffer_free */
+typedef struct PkmBuffer {
+    uint8_t *data;
+    size_t len;
+} PkmBuffer;
+
+typedef struct PkmCheckResult {
+    /* PkmCheckIdentifier */
+    uint8_t identifier;
+    /* PkmCheckSeverity */
+    uint8_t severity;
+    /* Owned by the caller, released with pkm_string_free */
+    char *message;
+} PkmCheckResult;
+
+/* Memory */
+const char *pkm_status_message(PkmStatus status);
+void pkm_buffer_free(PkmBuffer buffer);
+void pkm_string_free(char *string);
+
+/* Saves */
+PkmStatus pkm_save_load(const uint8_t *data, size_t len, PkmSave **out);
+void pkm_save_free(PkmSave *save);
+PkmStatus pkm_save_export(PkmSave *save, PkmBuffer *out);
+int32_t pkm_save_game_version(const PkmSave *save);
+char *pkm_save_trainer_name(const PkmSave *save);
+uint16_t pkm_save_trainer_id(const PkmSave *save);
+uint16_t pkm_save_secret_id(const PkmSave *save);
+uint8_t pkm_save_party_count(const PkmSave *save);
+uint32_t pkm_save_box_count(const PkmSave *save);
+uint16_t pkm_save_box_size(const PkmSave *save);
+/* The Pokemon is a copy, changing it does not change the save */
+PkmStatus pkm_save_party_slot(const PkmSave *save, uint8_t slot, PkmPokemon **out);
+PkmStatus pkm_save_box_slot(const PkmSave *save, uint32_t box_index, uint32_t slot, PkmPokemon **out);
+/* The Pokemon is copied into the save, empty party slots are filled from the end of the party */
+PkmStatus pkm_save_set_party_slot(PkmSave *save, uint8_t slot, const PkmPokemon *pokemon);
+PkmStatus pkm_save_set_box_slot(PkmSave *save, uint32_t box_index, uint32_t slot, const PkmPokemon *pokemon);
+
+/* Pokemon, IVs and EVs are in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order */
+PkmStatus pkm_pokemon_load(const uint8_t *data, size_t len, PkmPokemon **out);
+void pkm_pokemon_free(PkmPokemon *pokemon);
+/* Decrypted data */
+PkmStatus pkm_pokemon_data(const PkmPokemon *pokemon, PkmBuffer *out);
+
+uint16_t pkm_pokemon_species(const PkmPokemon *pokemon);
+uint8_t pkm_pokemon_level(const PkmPokemon *pokemon);
+uint32_t pkm_pokemon_experience(const PkmPokemon *pokemon);
+uint32_t pkm_pokemon_personality_id(const PkmPokemon *pokemon);
+uint16_t pkm_pokemon_trainer_id(const PkmPokemon *pokemon);
+uint16_t pkm_pokemon_secret_id(const PkmPokemon *pokemon);
+uint8_t pkm_pokemon_nature(const PkmPokemon *pokemon);
+uint16_t pkm_pokemon_held_item(const PkmPokemon *pokemon);
+bool pkm_pokemon_is_egg(const PkmPokemon *pokemon);
+bool pkm_pokemon_is_shiny(const PkmPokemon *pokemon);
+char *pkm_pokemon_nickname(const PkmPokemon *pokemon);
+char *pkm_pokemon_ot_name(const PkmPokemon *pokemon);
+PkmStatus pkm_pokemon_moves(const PkmPokemon *pokemon, uint16_t (*out)[4]);
+PkmStatus pkm_pokemon_ivs(const PkmPokemon *pokemon, uint8_t (*out)[6]);
+PkmStatus pkm_pokemon_evs(const PkmPokemon *pokemon, uint8_t (*out)[6]);
+
+PkmStatus pkm_pokemon_set_species(PkmPokemon *pokemon, uint16_t species);
+PkmStatus pkm_pokemon_set_level(PkmPokemon *pokemon, uint8_t level);
+PkmStatus pkm_pokemon_set_held_item(PkmPokemon *pokemon, uint16_t item);
+PkmStatus pkm_pokemon_set_moves(PkmPokemon *pokemon, const uint16_t (*moves)[4]);
+PkmStatus pkm_pokemon_set_ivs(PkmPokemon *pokemon, const uint8_t (*ivs)[6]);
+PkmStatus pkm_pokemon_set_evs(PkmPokemon *pokemon, const uint8_t (*evs)[6]);
+/* UTF-8 nickname */
+PkmStatus pkm_pokemon_set_nickname(PkmPokemon *pokemon, const char *nickname);
+
+/* Legality, save can be null when the Pokemon is not in a save */
+PkmStatus pkm_legality_analyze(const PkmPokemon *pokemon, const PkmSave *save, PkmLegality **out);
+void pkm_legality_free(PkmLegality *legality);
+bool pkm_legality_is_valid(const PkmLegality *legality);
+/* PkmCheckSeverity of the most severe result */
+uint8_t pkm_legality_severity(const PkmLegality *legality);
+size_t pkm_legality_result_count(const PkmLegality *legality);
+PkmStatus pkm_legality_result(const PkmLegality *legality, size_t index, PkmCheckResult *out);
+char *pkm_legality_report(const PkmLegality *legality, bool verbose);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PKMCORE_H */
@@ -0,0 +1,360 @@
+//! Command-line tool to inspect and edit Generation 3 saves and Pokémon files.
+//!
+//! Every listing is printed as tab-separated values, one record per line, for scripts to parse.
+
+use std::{env, fs, path::Path, process::ExitCode};
+
+use pkmcore::{
+    game::enums::language_id::LanguageID,
+    json::{self, generation3::SaveDocument},
+    legality::{analysis::LegalityAnalysis, check::CheckSeverity},
+    pokemon::{
+        base::PokemonTrait,
+        file::{PokemonFile, PokemonFileFormat},
+        Generation3,
+    },
+    save::{
+        base::{SaveFileTrait, SaveSlot},
+        generation3::base::Generation3SaveFileBase,
+        slots::SlotStorage,
+    },
+    strings::names::species_name,
+    trainer::TrainerInfo,
+};
+
+const USAGE: &str = "Usage: pkmcore <command> [arguments]
+
+Commands:
+  info <save>                               Summary and checksum status of the save
+  dump <save> [party|boxes]                 Lists the Pokémon in the party and boxes
+  export <save> <slot> <file>               Writes the Pokémon in the slot to a .pk3/.ek3 file
+  import <save> <file> <slot> -o <output>   Stores the .pk3/.ek3 file in the slot
+  move <save> <slot> <slot> -o <output>     Moves the Pokémon to the slot, swapping them when it is not empty
+  delete <save> <slot> -o <output>          Removes the Pokémon, moving up the rest of the party
+  fix-checksums <save> -o <output>          Recalculates every checksum of the save
+  export-json <save> [file]                 Writes the save as JSON, to the standard output without a file
+  import-json <save> <file> -o <output>     Applies a JSON document, listing the fields that were not applied
+  convert <file> <output> [--party|--stored]
+                                            Converts a .pk1/.pk3/.ek3 file to the format of the output extension
+  legality <save|file> [--verbose]          Legality report of every Pokémon
+
+Slots are `party <slot>` or `box <box> <slot>`, counted from 0.";
+
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let Some((command, args)) = args.split_first() else {
+        return Err(USAGE.to_string());
+    };
+
+    let mut args = args.to_vec();
+    match command.as_str() {
+        "info" => info(&args),
+        "dump" => dump(&args),
+        "export" => export(&args),
+        "import" => {
+            let output = take_option(&mut args, "-o")?;
+            import(&args, &output)
+        }
+        "move" => {
+            let output = take_option(&mut args, "-o")?;
+            move_slot(&args, &output)
+        }
+        "delete" => {
+            let output = take_option(&mut args, "-o")?;
+            delete(&args, &output)
+        }
+        "fix-checksums" => {
+            let output = take_option(&mut args, "-o")?;
+            fix_checksums(&args, &output)
+        }
+        "export-json" => export_json(&args),
+        "import-json" => {
+            let output = take_option(&mut args, "-o")?;
+            import_json(&args, &output)
+        }
+        "convert" => convert(&args),
+        "legality" => {
+            let verbose = take_flag(&mut args, "--verbose");
+            legality(&args, verbose)
+        }
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(format!("unknown command {}\n\n{}", command, USAGE)),
+    }
+}
+
+// region: Arguments
+/// Removes the option and its value from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<String, String> {
+    let index = args.iter().position(|arg| arg == name).ok_or(format!("missing {} <path>", name))?;
+    if index + 1 >= args.len() {
+        return Err(format!("missing value of {}", name));
+    }
+
+    let value = args.remove(index + 1);
+    args.remove(index);
+    Ok(value)
+}
+
+/// Removes the flag from the arguments, returning if it was present
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let present = args.iter().any(|arg| arg == name);
+    args.retain(|arg| arg != name);
+    present
+}
+
+/// Parses `party <slot>` or `box <box> <slot>` from the start of the arguments, returning the arguments left
+fn slot_argument(args: &[String]) -> Result<(SaveSlot, &[String]), String> {
+    let number = |index: usize| -> Result<usize, String> {
+        let value = args.get(index).ok_or("missing slot number")?;
+        value.parse().map_err(|_| format!("invalid slot number {}", value))
+    };
+
+    match args.first().map(String::as_str) {
+        Some("party") => Ok((SaveSlot::Party(number(1)?.min(u8::MAX as usize) as u8), &args[2..])),
+        Some("box") => Ok((SaveSlot::Box(number(1)?, number(2)?), &args[3..])),
+        _ => Err("expected a slot, `party <slot>` or `box <box> <slot>`".to_string()),
+    }
+}
+
+fn argument<'a>(args: &'a [String], index: usize, name: &str) -> Result<&'a str, String> {
+    args.get(index).map(String::as_str).ok_or(format!("missing {}\n\n{}", name, USAGE))
+}
+// endregion
+
+// region: Files
+fn read_file(path: &str) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|error| format!("can't read {}: {}", path, error))
+}
+
+fn write_file(path: &str, data: &[u8]) -> Result<(), String> {
+    fs::write(path, data).map_err(|error| format!("can't write {}: {}", path, error))
+}
+
+fn load_save(path: &str) -> Result<Generation3SaveFileBase, String> {
+    Generation3SaveFileBase::try_initilize(read_file(path)?).map_err(|error| format!("{}: {}", path, error))
+}
+
+fn load_pokemon(path: &str) -> Result<PokemonFile, String> {
+    PokemonFile::load(path).map_err(|error| format!("{}: {}", path, error))
+}
+
+/// Writes the Pokémon file in the format of the extension, `.pk3` when it is unknown
+fn write_pokemon(path: &str, pokemon: &PokemonFile, party: bool) -> Result<(), String> {
+    let format = PokemonFileFormat::from_path(path.as_ref()).unwrap_or(PokemonFileFormat::Generation3);
+    let data = pokemon.write(format, party).map_err(|error| format!("{}: {}", path, error))?;
+
+    write_file(path, &data)
+}
+// endregion
+
+// region: Save
+/// Gets every Pokémon of the save with its location, skipping the empty box slots
+fn all_pokemon(save: &Generation3SaveFileBase, party: bool, boxes: bool) -> Vec<(String, Generation3)> {
+    let mut result = Vec::new();
+
+    if party {
+        for (slot, pokemon) in save.party().into_iter().enumerate() {
+            result.push((format!("party\t\t{}", slot), pokemon));
+        }
+    }
+
+    if boxes {
+        for box_index in 0..save.base.constants.box_count as usize {
+            for slot in 0..save.base.constants.box_size as usize {
+                match save.get_box_slot(box_index, slot) {
+                    Some(pokemon) if pokemon.get_species() != 0 || pokemon.is_bad_egg => {
+                        result.push((format!("box\t{}\t{}", box_index, slot), pokemon));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn info(args: &[String]) -> Result<(), String> {
+    let save = load_save(argument(args, 0, "save")?)?;
+    let base = &save.base;
+
+    println!("summary\t{}", save.short_summary());
+    println!("game\t{}", base.game);
+    println!("trainer\t{}", base.trainer_info.name);
+    println!("trainer_id\t{}", base.trainer_info.public_id);
+    println!("secret_id\t{}", base.trainer_info.secret_id);
+    println!("playtime\t{}", save.playtime_string());
+    println!("party_count\t{}", base.party_count);
+    println!("current_box\t{}", base.current_box);
+    println!("active_slot\t{}", save.active_slot);
+    println!("checksums_valid\t{}", save.validate_checksum());
+    for line in save.get_checksum_info().lines() {
+        println!("checksum\t{}", line);
+    }
+
+    Ok(())
+}
+
+fn dump(args: &[String]) -> Result<(), String> {
+    let save = load_save(argument(args, 0, "save")?)?;
+    let (party, boxes) = match args.get(1).map(String::as_str) {
+        None => (true, true),
+        Some("party") => (true, false),
+        Some("boxes") => (false, true),
+        Some(other) => return Err(format!("unknown listing {}, expected party or boxes", other)),
+    };
+
+    println!("location\tbox\tslot\tspecies\tlevel\tnickname\tot\ttrainer_id\tsecret_id\tpid\tshiny\tegg");
+    for (location, pokemon) in all_pokemon(&save, party, boxes) {
+        let base = &pokemon.base;
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:08X}\t{}\t{}",
+            location,
+            species_name(base.species, LanguageID::English).unwrap_or("?"),
+            base.level,
+            base.nickname,
+            base.ot_info.name,
+            base.ot_info.public_id,
+            base.ot_info.secret_id,
+            base.personality_id,
+            base.is_shiny(),
+            pokemon.is_egg(),
+        );
+    }
+
+    Ok(())
+}
+
+fn export(args: &[String]) -> Result<(), String> {
+    let save = load_save(argument(args, 0, "save")?)?;
+    let (slot, rest) = slot_argument(&args[1..])?;
+    let path = argument(rest, 0, "file")?;
+
+    let pokemon = match slot {
+        SaveSlot::Party(slot) if slot < save.base.party_count => save.party().remove(slot as usize),
+        SaveSlot::Party(slot) => return Err(format!("party slot {} is empty", slot)),
+        SaveSlot::Box(box_index, slot) => save.get_box_slot(box_index, slot).ok_or(format!("box {} slot {} does not exist", box_index, slot))?,
+    };
+
+    write_pokemon(path, &PokemonFile::Generation3(pokemon), matches!(slot, SaveSlot::Party(_)))
+}
+
+fn import(args: &[String], output: &str) -> Result<(), String> {
+    let mut save = load_save(argument(args, 0, "save")?)?;
+    let pokemon = load_pokemon(argument(args, 1, "file")?)?;
+    let (slot, _) = slot_argument(&args[2..])?;
+
+    pokemon.inject(&mut save, slot).map_err(|error| format!("can't store a Pokémon in {:?}: {}", slot, error))?;
+
+    write_file(output, &save.get_final_data())
+}
+
+fn move_slot(args: &[String], output: &str) -> Result<(), String> {
+    let mut save = load_save(argument(args, 0, "save")?)?;
+    let (from, rest) = slot_argument(&args[1..])?;
+    let (to, _) = slot_argument(rest)?;
+
+    let result = match save.read_slot(to) {
+        Some(_) => save.swap_slots(from, to),
+        None => save.move_slot(from, to).map(|_| ()),
+    };
+
+    result.map_err(|error| error.to_string())?;
+    write_file(output, &save.get_final_data())
+}
+
+fn delete(args: &[String], output: &str) -> Result<(), String> {
+    let mut save = load_save(argument(args, 0, "save")?)?;
+    let (slot, _) = slot_argument(&args[1..])?;
+
+    save.delete_slot(slot).map_err(|error| error.to_string())?;
+    write_file(output, &save.get_final_data())
+}
+
+fn fix_checksums(args: &[String], output: &str) -> Result<(), String> {
+    let mut save = load_save(argument(args, 0, "save")?)?;
+    write_file(output, &save.get_final_data())
+}
+
+fn export_json(args: &[String]) -> Result<(), String> {
+    let save = load_save(argument(args, 0, "save")?)?;
+    let document = json::to_json(&json::generation3::export(&save)).map_err(|error| error.to_string())?;
+
+    match args.get(1) {
+        Some(path) => write_file(path, document.as_bytes()),
+        None => {
+            println!("{}", document);
+            Ok(())
+        }
+    }
+}
+
+fn import_json(args: &[String], output: &str) -> Result<(), String> {
+    let mut save = load_save(argument(args, 0, "save")?)?;
+    let path = argument(args, 1, "file")?;
+    let text = fs::read_to_string(path).map_err(|error| format!("can't read {}: {}", path, error))?;
+    let document: SaveDocument = json::from_json(&text).map_err(|error| format!("{}: {}", path, error))?;
+
+    let report = json::generation3::apply(&mut save, &document);
+    for path in &report.unapplied {
+        println!("unapplied\t{}", path);
+    }
+
+    write_file(output, &save.get_final_data())
+}
+// endregion
+
+// region: Pokémon
+fn convert(args: &[String]) -> Result<(), String> {
+    let mut args = args.to_vec();
+    let party = take_flag(&mut args, "--party");
+    let stored = take_flag(&mut args, "--stored");
+
+    let pokemon = load_pokemon(argument(&args, 0, "file")?)?;
+    let path = argument(&args, 1, "output")?;
+    let party = party || (!stored && pokemon.base().data.len() >= pokemon.base().size_on_party as usize);
+
+    write_pokemon(path, &pokemon, party)
+}
+
+fn legality(args: &[String], verbose: bool) -> Result<(), String> {
+    let path = argument(args, 0, "save or file")?;
+    let data = read_file(path)?;
+
+    let (pokemon, trainer): (Vec<(String, Generation3)>, Option<TrainerInfo>) = if PokemonFileFormat::detect(&data).is_ok() {
+        let extension = Path::new(path).extension().and_then(|extension| extension.to_str());
+        let pokemon = PokemonFile::read(&data, extension).and_then(|pokemon| pokemon.to_generation3()).map_err(|error| format!("{}: {}", path, error))?;
+        (vec![("file\t\t".to_string(), pokemon)], None)
+    } else {
+        let save = Generation3SaveFileBase::try_initilize(data).map_err(|error| format!("{}: {}", path, error))?;
+        (all_pokemon(&save, true, true), Some(save.base.trainer_info.clone()))
+    };
+
+    println!("location\tbox\tslot\tspecies\tseverity\tcheck\tmessage");
+    for (location, pokemon) in pokemon {
+        let analysis = LegalityAnalysis::generation3(&pokemon, trainer.as_ref());
+        let species = species_name(pokemon.base.species, LanguageID::English).unwrap_or("?");
+
+        for result in analysis.results.iter().filter(|result| verbose || result.severity != CheckSeverity::Valid) {
+            println!("{}\t{}\t{:?}\t{:?}\t{}", location, species, result.severity, result.identifier, result.message);
+        }
+    }
+
+    Ok(())
+}
+// endregion
@@ -0,0 +1,72 @@
+use std::{array::TryFromSliceError, fmt::Display};
+
+use crate::species::conversion::InvalidSpeciesIndex;
+
+/// Error of the fallible parsing functions, returned instead of panicking on malformed data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PkmError {
+    /// The data is shorter than the format requires
+    TruncatedData { expected: usize, found: usize },
+    /// The data size does not match any supported format
+    UnsupportedSize(usize),
+    /// A value was read outside of the data
+    OutOfBounds,
+    InvalidSpeciesIndex(InvalidSpeciesIndex),
+    /// An offset required by the operation was never set for the save
+    MissingOffset(&'static str),
+    /// The format or operation is not implemented yet
+    Unsupported(&'static str),
+    /// The value can't be stored by the format
+    InvalidValue(&'static str),
+    /// The document does not have the expected structure
+    InvalidDocument(String),
+    /// Reading or writing a file failed
+    Io(String),
+}
+
+pub type Result<T> = std::result::Result<T, PkmError>;
+
+impl Display for PkmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PkmError::TruncatedData { expected, found } => write!(f, "Data is {} bytes, expected at least {}", found, expected),
+            PkmError::UnsupportedSize(size) => write!(f, "No supported format is {} bytes", size),
+            PkmError::OutOfBounds => write!(f, "Value is outside of the data"),
+            PkmError::InvalidSpeciesIndex(error) => error.fmt(f),
+            PkmError::MissingOffset(name) => write!(f, "The {} offset is not set", name),
+            PkmError::Unsupported(feature) => write!(f, "{} is not supported yet", feature),
+            PkmError::InvalidValue(reason) => write!(f, "Invalid value, {}", reason),
+            PkmError::InvalidDocument(reason) => write!(f, "Invalid document, {}", reason),
+            PkmError::Io(reason) => write!(f, "File error, {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PkmError {}
+
+impl From<TryFromSliceError> for PkmError {
+    fn from(_: TryFromSliceError) -> Self {
+        PkmError::OutOfBounds
+    }
+}
+
+impl From<std::io::Error> for PkmError {
+    fn from(error: std::io::Error) -> Self {
+        PkmError::Io(error.to_string())
+    }
+}
+
+impl From<InvalidSpeciesIndex> for PkmError {
+    fn from(error: InvalidSpeciesIndex) -> Self {
+        PkmError::InvalidSpeciesIndex(error)
+    }
+}
+
+/// Checks that the data has at least the expected size
+pub(crate) fn ensure_size(data: &[u8], expected: usize) -> Result<()> {
+    if data.len() < expected {
+        return Err(PkmError::TruncatedData { expected, found: data.len() });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! C ABI of the library, built into the `cdylib` and `staticlib` artifacts.
+//!
+//! Saves, Pokémon and legality results are opaque handles created by the `*_load`/`*_analyze`
+//! functions and owned by the caller until passed to the matching `*_free` function. Strings and
+//! buffers returned by the library are owned by the caller too, and are released with
+//! `pkm_string_free` and `pkm_buffer_free`. The declarations are in `include/pkmcore.h`.
+//!
+//! Only Generation 3 (Ruby/Sapphire/Emerald and FireRed/LeafGreen) saves and Pokémon are supported.
+//!
+//! # Safety
+//! Every pointer given to these functions must either be null or valid for the access the
+//! function documents: handles must come from this library and not be freed yet, input buffers
+//! must be readable for `len` bytes and output pointers must be writable. Handles are not
+//! thread-safe and must not be used from two threads at once.
+
+#![allow(clippy::missing_safety_doc)]
+
+pub mod legality;
+pub mod pokemon;
+pub mod save;
+
+use std::{
+    ffi::{c_char, CString},
+    ptr,
+};
+
+use crate::error::PkmError;
+
+/// Result of a fallible FFI function
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PkmStatus {
+    Ok = 0,
+    /// A required pointer was null
+    NullPointer = 1,
+    /// An argument is out of range or can't be stored by the game
+    InvalidArgument = 2,
+    /// The slot has no Pokémon
+    EmptySlot = 3,
+    TruncatedData = 4,
+    UnsupportedSize = 5,
+    OutOfBounds = 6,
+    InvalidSpeciesIndex = 7,
+    MissingOffset = 8,
+    Unsupported = 9,
+    /// Reading or writing a file failed
+    Io = 10,
+}
+
+impl From<PkmError> for PkmStatus {
+    fn from(error: PkmError) -> Self {
+        match error {
+            PkmError::TruncatedData { .. } => PkmStatus::TruncatedData,
+            PkmError::UnsupportedSize(_) => PkmStatus::UnsupportedSize,
+            PkmError::OutOfBounds => PkmStatus::OutOfBounds,
+            PkmError::InvalidSpeciesIndex(_) => PkmStatus::InvalidSpeciesIndex,
+            PkmError::MissingOffset(_) => PkmStatus::MissingOffset,
+            PkmError::Unsupported(_) => PkmStatus::Unsupported,
+            PkmError::InvalidValue(_) | PkmError::InvalidDocument(_) => PkmStatus::InvalidArgument,
+            PkmError::Io(_) => PkmStatus::Io,
+        }
+    }
+}
+
+/// Bytes owned by the caller, released with `pkm_buffer_free`
+#[repr(C)]
+#[derive(Debug)]
+pub struct PkmBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl PkmBuffer {
+    fn from_vec(data: Vec<u8>) -> Self {
+        let len = data.len();
+        let data = Box::into_raw(data.into_boxed_slice()) as *mut u8;
+
+        Self { data, len }
+    }
+}
+
+/// Gets a static description of the status, which must not be freed
+#[no_mangle]
+pub extern "C" fn pkm_status_message(status: PkmStatus) -> *const c_char {
+    let message: &'static [u8] = match status {
+        PkmStatus::Ok => b"Ok\0",
+        PkmStatus::NullPointer => b"A required pointer was null\0",
+        PkmStatus::InvalidArgument => b"An argument is out of range or can't be stored\0",
+        PkmStatus::EmptySlot => b"The slot has no Pokemon\0",
+        PkmStatus::TruncatedData => b"The data is shorter than the format requires\0",
+        PkmStatus::UnsupportedSize => b"No supported format has the size of the data\0",
+        PkmStatus::OutOfBounds => b"A value is outside of the data\0",
+        PkmStatus::InvalidSpeciesIndex => b"The species index does not map to any species\0",
+        PkmStatus::MissingOffset => b"An offset required by the operation is not set\0",
+        PkmStatus::Unsupported => b"The operation is not supported yet\0",
+        PkmStatus::Io => b"Reading or writing a file failed\0",
+    };
+
+    message.as_ptr() as *const c_char
+}
+
+/// Releases a buffer returned by the library
+#[no_mangle]
+pub unsafe extern "C" fn pkm_buffer_free(buffer: PkmBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+}
+
+/// Releases a string returned by the library
+#[no_mangle]
+pub unsafe extern "C" fn pkm_string_free(string: *mut c_char) {
+    if string.is_null() {
+        return;
+    }
+
+    drop(CString::from_raw(string));
+}
+
+/// Copies the string into a C string owned by the caller, cut at the first NUL character
+fn to_c_string(value: &str) -> *mut c_char {
+    let value = value.split('\0').next().unwrap_or_default();
+    CString::new(value).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Writes the value into the output pointer, failing when it is null
+unsafe fn write_out<T>(out: *mut T, value: T) -> PkmStatus {
+    if out.is_null() {
+        return PkmStatus::NullPointer;
+    }
+
+    out.write(value);
+    PkmStatus::Ok
+}
+
+/// Moves the value into a new handle written into the output pointer, failing when it is null
+unsafe fn write_handle<T>(out: *mut *mut T, value: T) -> PkmStatus {
+    if out.is_null() {
+        return PkmStatus::NullPointer;
+    }
+
+    out.write(Box::into_raw(Box::new(value)));
+    PkmStatus::Ok
+}
+
+/// Gets the input buffer as a slice, `None` when the pointer is null
+unsafe fn input_slice<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if data.is_null() {
+        return None;
+    }
+
+    Some(std::slice::from_raw_parts(data, len))
+}
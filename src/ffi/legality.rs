@@ -0,0 +1,101 @@
+use std::ffi::c_char;
+
+use crate::legality::analysis::LegalityAnalysis;
+
+use super::{pokemon::PkmPokemon, save::PkmSave, to_c_string, write_handle, write_out, PkmStatus};
+
+/// Opaque handle to the legality results of a Pokémon
+pub struct PkmLegality(pub(crate) LegalityAnalysis);
+
+/// Single check of a legality analysis
+#[repr(C)]
+#[derive(Debug)]
+pub struct PkmCheckResult {
+    /// `CheckIdentifier` value, the area of the Pokémon that was checked
+    pub identifier: u8,
+    /// 0 for valid, 1 for fishy and 2 for invalid
+    pub severity: u8,
+    /// Description of the result, to be freed with `pkm_string_free`
+    pub message: *mut c_char,
+}
+
+/// Analyzes the legality of the Pokémon, the results must be freed with `pkm_legality_free`.
+///
+/// # Arguments
+/// * `pokemon` - Pokémon to analyze
+/// * `save` - Save holding the Pokémon, used to compare its trainer data, can be null
+/// * `out` - Receives the legality handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_analyze(pokemon: *const PkmPokemon, save: *const PkmSave, out: *mut *mut PkmLegality) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let trainer = save.as_ref().map(|save| &save.0.base.trainer_info);
+    write_handle(out, PkmLegality(LegalityAnalysis::generation3(&pokemon.0, trainer)))
+}
+
+/// Releases a legality handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_free(legality: *mut PkmLegality) {
+    if !legality.is_null() {
+        drop(Box::from_raw(legality));
+    }
+}
+
+/// Checks if no result is invalid
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_is_valid(legality: *const PkmLegality) -> bool {
+    legality.as_ref().is_some_and(|legality| legality.0.is_valid())
+}
+
+/// Gets the most severe result, 0 for valid, 1 for fishy and 2 for invalid
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_severity(legality: *const PkmLegality) -> u8 {
+    legality.as_ref().map_or(0, |legality| legality.0.severity() as u8)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_result_count(legality: *const PkmLegality) -> usize {
+    legality.as_ref().map_or(0, |legality| legality.0.results.len())
+}
+
+/// Gets a result of the analysis, its message must be freed with `pkm_string_free`
+///
+/// # Arguments
+/// * `legality` - Analysis holding the results
+/// * `index` - Result, below `pkm_legality_result_count`
+/// * `out` - Receives the result
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_result(legality: *const PkmLegality, index: usize, out: *mut PkmCheckResult) -> PkmStatus {
+    let Some(legality) = legality.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let Some(result) = legality.0.results.get(index) else {
+        return PkmStatus::InvalidArgument;
+    };
+
+    if out.is_null() {
+        return PkmStatus::NullPointer;
+    }
+
+    write_out(
+        out,
+        PkmCheckResult {
+            identifier: result.identifier as u8,
+            severity: result.severity as u8,
+            message: to_c_string(&result.message),
+        },
+    )
+}
+
+/// Gets a human-readable report, to be freed with `pkm_string_free`
+///
+/// # Arguments
+/// * `legality` - Analysis to report
+/// * `verbose` - Also lists the valid results
+#[no_mangle]
+pub unsafe extern "C" fn pkm_legality_report(legality: *const PkmLegality, verbose: bool) -> *mut c_char {
+    legality.as_ref().map_or(std::ptr::null_mut(), |legality| to_c_string(&legality.0.report(verbose)))
+}
@@ -0,0 +1,352 @@
+use std::ffi::{c_char, CStr};
+
+use crate::{
+    error::PkmError,
+    game::enums::species_id::SpeciesID,
+    legality::tables::generation3::{MAX_MOVE_ID, MAX_SPECIES_ID, MOVE_PP},
+    pokemon::{base::PokemonTrait, utils::experience::get_minimum_level_experience, Generation3},
+    strings::{self, StringConverterOption},
+    utils::constants::poke_crypto::SIZE_GENERATION_3_PARTY,
+};
+
+use super::{input_slice, to_c_string, write_handle, write_out, PkmBuffer, PkmStatus};
+
+/// Opaque handle to a Generation 3 Pokémon
+pub struct PkmPokemon(pub(crate) Generation3);
+
+/// Loads a stored (80 bytes) or party (100 bytes) Pokémon, encrypted or not, which must be freed with `pkm_pokemon_free`.
+///
+/// # Arguments
+/// * `data` - Pokémon data, copied by the library
+/// * `len` - Size of the data
+/// * `out` - Receives the Pokémon handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_load(data: *const u8, len: usize, out: *mut *mut PkmPokemon) -> PkmStatus {
+    let Some(data) = input_slice(data, len) else {
+        return PkmStatus::NullPointer;
+    };
+
+    match Generation3::try_read(data) {
+        Ok(pokemon) => write_handle(out, PkmPokemon(pokemon)),
+        Err(error) => error.into(),
+    }
+}
+
+/// Releases a Pokémon handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_free(pokemon: *mut PkmPokemon) {
+    if !pokemon.is_null() {
+        drop(Box::from_raw(pokemon));
+    }
+}
+
+/// Gets the decrypted data of the Pokémon, to be freed with `pkm_buffer_free`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_data(pokemon: *const PkmPokemon, out: *mut PkmBuffer) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    write_out(out, PkmBuffer::from_vec(pokemon.0.decrypt()))
+}
+
+// region: Getters
+/// Gets the National Pokédex number of the species, 0 when the species index is invalid
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_species(pokemon: *const PkmPokemon) -> u16 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.get_species())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_level(pokemon: *const PkmPokemon) -> u8 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.level)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_experience(pokemon: *const PkmPokemon) -> u32 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.experience)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_personality_id(pokemon: *const PkmPokemon) -> u32 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.personality_id)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_trainer_id(pokemon: *const PkmPokemon) -> u16 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.ot_info.public_id)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_secret_id(pokemon: *const PkmPokemon) -> u16 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.ot_info.secret_id)
+}
+
+/// Gets the nature, 0 (Hardy) to 24 (Quirky)
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_nature(pokemon: *const PkmPokemon) -> u8 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.nature as u8)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_held_item(pokemon: *const PkmPokemon) -> u16 {
+    pokemon.as_ref().map_or(0, |pokemon| pokemon.0.base.held_item)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_is_egg(pokemon: *const PkmPokemon) -> bool {
+    pokemon.as_ref().is_some_and(|pokemon| pokemon.0.is_egg())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_is_shiny(pokemon: *const PkmPokemon) -> bool {
+    pokemon.as_ref().is_some_and(|pokemon| pokemon.0.base.is_shiny())
+}
+
+/// Gets the nickname, to be freed with `pkm_string_free`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_nickname(pokemon: *const PkmPokemon) -> *mut c_char {
+    pokemon.as_ref().map_or(std::ptr::null_mut(), |pokemon| to_c_string(&pokemon.0.base.nickname))
+}
+
+/// Gets the Original Trainer name, to be freed with `pkm_string_free`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_ot_name(pokemon: *const PkmPokemon) -> *mut c_char {
+    pokemon.as_ref().map_or(std::ptr::null_mut(), |pokemon| to_c_string(&pokemon.0.base.ot_info.name))
+}
+
+/// Writes the 4 move IDs into `out`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_moves(pokemon: *const PkmPokemon, out: *mut [u16; 4]) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let base = &pokemon.0.base;
+    write_out(out, [base.move_1, base.move_2, base.move_3, base.move_4])
+}
+
+/// Writes the 6 IVs into `out`, in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_ivs(pokemon: *const PkmPokemon, out: *mut [u8; 6]) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    write_out(out, pokemon.0.base.ivs())
+}
+
+/// Writes the 6 EVs into `out`, in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_evs(pokemon: *const PkmPokemon, out: *mut [u8; 6]) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let base = &pokemon.0.base;
+    let evs = [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack, base.ev_special_defense];
+    write_out(out, evs.map(|ev| ev as u8))
+}
+// endregion
+
+// region: Setters
+/// Changes the species, keeping the PID, IVs and EXP
+///
+/// # Arguments
+/// * `species` - National Pokédex number, up to Deoxys
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_species(pokemon: *mut PkmPokemon, species: u16) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_mut() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let Some(species) = SpeciesID::from_national_id(species).filter(|species| *species != SpeciesID::None && species.national_id() <= MAX_SPECIES_ID) else {
+        return PkmStatus::InvalidArgument;
+    };
+
+    if let Err(error) = pokemon.0.set_species(species).and_then(|_| pokemon.0.update_checksum()) {
+        return error.into();
+    }
+
+    pokemon.0.refresh_stats();
+    PkmStatus::Ok
+}
+
+/// Sets the level, with the minimum EXP of the level and recalculated stats
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_level(pokemon: *mut PkmPokemon, level: u8) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_mut() else {
+        return PkmStatus::NullPointer;
+    };
+
+    if !(1..=100).contains(&level) {
+        return PkmStatus::InvalidArgument;
+    }
+
+    let experience = get_minimum_level_experience(level, pokemon.0.base.species_info.exp_growth as usize);
+    if let Err(error) = pokemon.0.patch(0x24, &experience.to_le_bytes()) {
+        return error.into();
+    }
+
+    if pokemon.0.base.data.len() >= SIZE_GENERATION_3_PARTY as usize {
+        pokemon.0.base.level = level;
+        pokemon.0.refresh_stats();
+    }
+
+    PkmStatus::Ok
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_held_item(pokemon: *mut PkmPokemon, item: u16) -> PkmStatus {
+    let Some(pokemon) = pokemon.as_mut() else {
+        return PkmStatus::NullPointer;
+    };
+
+    change_status(pokemon.0.patch(0x22, &item.to_le_bytes()))
+}
+
+/// Sets the 4 move IDs, 0 for an empty slot. The changed moves get their full PP, with the PP Ups of the slot.
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_moves(pokemon: *mut PkmPokemon, moves: *const [u16; 4]) -> PkmStatus {
+    let (Some(pokemon), Some(moves)) = (pokemon.as_mut(), moves.as_ref()) else {
+        return PkmStatus::NullPointer;
+    };
+
+    if moves.iter().any(|move_id| *move_id as u32 > MAX_MOVE_ID) {
+        return PkmStatus::InvalidArgument;
+    }
+
+    // Moves at 0x2C, followed by the PP at 0x34
+    let mut attacks = pokemon.0.base.data[0x2C..0x38].to_vec();
+    let pp_ups = pokemon.0.base.data[0x28];
+    for (slot, move_id) in moves.iter().enumerate() {
+        if attacks[slot * 2..slot * 2 + 2] == move_id.to_le_bytes() {
+            continue;
+        }
+
+        let base_pp = MOVE_PP[*move_id as usize];
+        attacks[slot * 2..slot * 2 + 2].copy_from_slice(&move_id.to_le_bytes());
+        attacks[8 + slot] = base_pp + base_pp * ((pp_ups >> (slot * 2)) & 3) / 5;
+    }
+
+    change_status(pokemon.0.patch(0x2C, &attacks))
+}
+
+/// Sets the 6 IVs, in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_ivs(pokemon: *mut PkmPokemon, ivs: *const [u8; 6]) -> PkmStatus {
+    let (Some(pokemon), Some(ivs)) = (pokemon.as_mut(), ivs.as_ref()) else {
+        return PkmStatus::NullPointer;
+    };
+
+    if ivs.iter().any(|iv| *iv > 31) {
+        return PkmStatus::InvalidArgument;
+    }
+
+    // Egg and ability bits are kept
+    let flags = pokemon.0.base.data[0x4B] as u32 >> 6 << 30;
+    let iv32 = ivs.iter().enumerate().fold(flags, |iv32, (index, iv)| iv32 | ((*iv as u32) << (index * 5)));
+    if let Err(error) = pokemon.0.patch(0x48, &iv32.to_le_bytes()) {
+        return error.into();
+    }
+
+    pokemon.0.refresh_stats();
+    PkmStatus::Ok
+}
+
+/// Sets the 6 EVs, in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_evs(pokemon: *mut PkmPokemon, evs: *const [u8; 6]) -> PkmStatus {
+    let (Some(pokemon), Some(evs)) = (pokemon.as_mut(), evs.as_ref()) else {
+        return PkmStatus::NullPointer;
+    };
+
+    if let Err(error) = pokemon.0.patch(0x38, evs) {
+        return error.into();
+    }
+
+    pokemon.0.refresh_stats();
+    PkmStatus::Ok
+}
+
+/// Sets the nickname, which must be UTF-8 and have only characters the game can encode
+#[no_mangle]
+pub unsafe extern "C" fn pkm_pokemon_set_nickname(pokemon: *mut PkmPokemon, nickname: *const c_char) -> PkmStatus {
+    if nickname.is_null() {
+        return PkmStatus::NullPointer;
+    }
+
+    let Some(pokemon) = pokemon.as_mut() else {
+        return PkmStatus::NullPointer;
+    };
+
+    let Ok(nickname) = CStr::from_ptr(nickname).to_str() else {
+        return PkmStatus::InvalidArgument;
+    };
+
+    let max_length = if pokemon.0.is_japanese { 5 } else { 10 };
+    let mut buffer = [0u8; 10];
+    if nickname.chars().count() > max_length
+        || strings::generation_3::encode(&mut buffer, nickname, max_length, pokemon.0.base.language, StringConverterOption::ClearFF).is_err()
+    {
+        return PkmStatus::InvalidArgument;
+    }
+
+    change_status(pokemon.0.patch(0x08, &buffer))
+}
+// endregion
+
+fn change_status(result: Result<(), PkmError>) -> PkmStatus {
+    match result {
+        Ok(()) => PkmStatus::Ok,
+        Err(error) => error.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr};
+
+    use crate::{
+        ffi::{pkm_string_free, PkmStatus},
+        game::enums::species_id::SpeciesID,
+        species::conversion::generation3_index_from_species,
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    use super::*;
+
+    #[test]
+    fn loads_and_edits_pokemon() {
+        let mut data = vec![0u8; 100];
+        data[0x12] = 2;
+        data[0x20..0x22].copy_from_slice(&generation3_index_from_species(SpeciesID::Zigzagoon).unwrap().to_le_bytes());
+        let checksum = get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        unsafe {
+            let mut pokemon = ptr::null_mut();
+            assert_eq!(pkm_pokemon_load(data.as_ptr(), 40, &mut pokemon), PkmStatus::TruncatedData);
+            assert_eq!(pkm_pokemon_load(data.as_ptr(), data.len(), &mut pokemon), PkmStatus::Ok);
+            assert_eq!(pkm_pokemon_species(pokemon), 263);
+
+            assert_eq!(pkm_pokemon_set_level(pokemon, 30), PkmStatus::Ok);
+            assert_eq!(pkm_pokemon_set_nickname(pokemon, c"ZIGGY".as_ptr()), PkmStatus::Ok);
+            assert_eq!(pkm_pokemon_set_ivs(pokemon, &[31, 0, 0, 0, 0, 32]), PkmStatus::InvalidArgument);
+            assert_eq!(pkm_pokemon_set_moves(pokemon, &[33, 39, 0, 355]), PkmStatus::InvalidArgument);
+            assert_eq!(pkm_pokemon_set_moves(pokemon, &[33, 39, 0, 0]), PkmStatus::Ok);
+            assert_eq!((&(*pokemon).0.base.data)[0x34..0x38], [35, 30, 0, 0]);
+
+            let nickname = pkm_pokemon_nickname(pokemon);
+            assert_eq!(CStr::from_ptr(nickname).to_str(), Ok("ZIGGY"));
+            pkm_string_free(nickname);
+
+            let mut moves = [0u16; 4];
+            assert_eq!(pkm_pokemon_moves(pokemon, &mut moves), PkmStatus::Ok);
+            assert_eq!(moves, [33, 39, 0, 0]);
+            assert_eq!(pkm_pokemon_level(pokemon), 30);
+
+            pkm_pokemon_free(pokemon);
+        }
+    }
+}
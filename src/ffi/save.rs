@@ -0,0 +1,237 @@
+use std::ffi::c_char;
+
+use crate::{
+    pokemon::base::PokemonTrait,
+    save::{
+        base::{SaveFileTrait, SaveSlot},
+        generation3::base::Generation3SaveFileBase,
+        slots::{SlotError, SlotStorage},
+    },
+};
+
+use super::{input_slice, pokemon::PkmPokemon, to_c_string, write_handle, write_out, PkmBuffer, PkmStatus};
+
+/// Opaque handle to a Generation 3 save
+pub struct PkmSave(pub(crate) Generation3SaveFileBase);
+
+/// Loads a save, which must be freed with `pkm_save_free`.
+///
+/// # Arguments
+/// * `data` - Save data, copied by the library
+/// * `len` - Size of the data
+/// * `out` - Receives the save handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_load(data: *const u8, len: usize, out: *mut *mut PkmSave) -> PkmStatus {
+    let Some(data) = input_slice(data, len) else {
+        return PkmStatus::NullPointer;
+    };
+
+    match Generation3SaveFileBase::try_initilize(data.to_vec()) {
+        Ok(save) => write_handle(out, PkmSave(save)),
+        Err(error) => error.into(),
+    }
+}
+
+/// Releases a save handle
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_free(save: *mut PkmSave) {
+    if !save.is_null() {
+        drop(Box::from_raw(save));
+    }
+}
+
+/// Gets the save data with updated checksums, to be freed with `pkm_buffer_free`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_export(save: *mut PkmSave, out: *mut PkmBuffer) -> PkmStatus {
+    let Some(save) = save.as_mut() else {
+        return PkmStatus::NullPointer;
+    };
+
+    write_out(out, PkmBuffer::from_vec(save.0.get_final_data()))
+}
+
+/// Gets the game of the save as a `GameVersion` value, `-2` (Invalid) for a null save
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_game_version(save: *const PkmSave) -> i32 {
+    save.as_ref().map_or(-2, |save| save.0.base.game as i32)
+}
+
+/// Gets the trainer name, to be freed with `pkm_string_free`
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_trainer_name(save: *const PkmSave) -> *mut c_char {
+    save.as_ref().map_or(std::ptr::null_mut(), |save| to_c_string(&save.0.base.trainer_info.name))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_trainer_id(save: *const PkmSave) -> u16 {
+    save.as_ref().map_or(0, |save| save.0.base.trainer_info.public_id)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_secret_id(save: *const PkmSave) -> u16 {
+    save.as_ref().map_or(0, |save| save.0.base.trainer_info.secret_id)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_party_count(save: *const PkmSave) -> u8 {
+    save.as_ref().map_or(0, |save| save.0.base.party_count)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_box_count(save: *const PkmSave) -> u32 {
+    save.as_ref().map_or(0, |save| save.0.base.constants.box_count)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_box_size(save: *const PkmSave) -> u16 {
+    save.as_ref().map_or(0, |save| save.0.base.constants.box_size)
+}
+
+/// Gets a copy of the Pokémon in the party slot, which must be freed with `pkm_pokemon_free`.
+///
+/// # Arguments
+/// * `save` - Save holding the party
+/// * `slot` - Party slot, from 0
+/// * `out` - Receives the Pokémon handle, only written when the slot has a Pokémon
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_party_slot(save: *const PkmSave, slot: u8, out: *mut *mut PkmPokemon) -> PkmStatus {
+    let Some(save) = save.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    if slot >= save.0.base.party_count {
+        return PkmStatus::EmptySlot;
+    }
+
+    let pokemon = save.0.get_pokemon_at_party_slot(&save.0.large_data, slot);
+    write_handle(out, PkmPokemon(pokemon))
+}
+
+/// Gets a copy of the Pokémon in the box slot, which must be freed with `pkm_pokemon_free`.
+///
+/// # Arguments
+/// * `save` - Save holding the boxes
+/// * `box_index` - Box, from 0
+/// * `slot` - Slot in the box, from 0
+/// * `out` - Receives the Pokémon handle, only written when the slot has a Pokémon
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_box_slot(save: *const PkmSave, box_index: u32, slot: u32, out: *mut *mut PkmPokemon) -> PkmStatus {
+    let Some(save) = save.as_ref() else {
+        return PkmStatus::NullPointer;
+    };
+
+    match save.0.get_box_slot(box_index as usize, slot as usize) {
+        None => PkmStatus::InvalidArgument,
+        Some(pokemon) if pokemon.get_species() == 0 => PkmStatus::EmptySlot,
+        Some(pokemon) => write_handle(out, PkmPokemon(pokemon)),
+    }
+}
+
+/// Stores a copy of the Pokémon in the party slot, replacing the Pokémon in it.
+/// Empty slots are filled from the end of the party, and the last Pokémon able to battle can't be replaced by an egg.
+///
+/// # Arguments
+/// * `save` - Save holding the party
+/// * `slot` - Party slot, from 0
+/// * `pokemon` - Pokémon to store, its party stats are recalculated
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_set_party_slot(save: *mut PkmSave, slot: u8, pokemon: *const PkmPokemon) -> PkmStatus {
+    let (Some(save), Some(pokemon)) = (save.as_mut(), pokemon.as_ref()) else {
+        return PkmStatus::NullPointer;
+    };
+
+    slot_status(save.0.set_slot(SaveSlot::Party(slot), &pokemon.0))
+}
+
+/// Stores a copy of the Pokémon in the box slot, replacing the Pokémon in it
+///
+/// # Arguments
+/// * `save` - Save holding the boxes
+/// * `box_index` - Box, from 0
+/// * `slot` - Slot in the box, from 0
+/// * `pokemon` - Pokémon to store, the party data is left out
+#[no_mangle]
+pub unsafe extern "C" fn pkm_save_set_box_slot(save: *mut PkmSave, box_index: u32, slot: u32, pokemon: *const PkmPokemon) -> PkmStatus {
+    let (Some(save), Some(pokemon)) = (save.as_mut(), pokemon.as_ref()) else {
+        return PkmStatus::NullPointer;
+    };
+
+    slot_status(save.0.set_slot(SaveSlot::Box(box_index as usize, slot as usize), &pokemon.0))
+}
+
+fn slot_status<T>(result: Result<T, SlotError>) -> PkmStatus {
+    match result {
+        Ok(_) => PkmStatus::Ok,
+        Err(SlotError::EmptySlot(_)) => PkmStatus::EmptySlot,
+        // Missing slots, or a party left without a Pokémon able to battle
+        Err(_) => PkmStatus::InvalidArgument,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use crate::{
+        ffi::{pkm_buffer_free, pokemon::{pkm_pokemon_free, pkm_pokemon_species}, PkmBuffer, PkmStatus},
+        game::enums::species_id::SpeciesID,
+        save::generation3::utils::SAVE_RAW_HALF_SIZE,
+        species::conversion::generation3_index_from_species,
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    use super::*;
+
+    /// Half-size Emerald save with a Zigzagoon in the party
+    fn emerald_save() -> Vec<u8> {
+        let mut data = vec![0u8; SAVE_RAW_HALF_SIZE];
+        for sector_id in 0..14usize {
+            data[sector_id * 0x1000 + 0xFF4..sector_id * 0x1000 + 0xFF6].copy_from_slice(&(sector_id as u16).to_le_bytes());
+        }
+        data[0xAC..0xB0].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+
+        let party = 0x1000 + 0x234;
+        data[party] = 1;
+        let pokemon = &mut data[party + 4..party + 4 + 100];
+        pokemon[0x20..0x22].copy_from_slice(&generation3_index_from_species(SpeciesID::Zigzagoon).unwrap().to_le_bytes());
+        let checksum = get_generatation3_checksum(pokemon);
+        pokemon[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        data
+    }
+
+    #[test]
+    fn stores_pokemon_in_slots() {
+        let data = emerald_save();
+
+        unsafe {
+            let mut save = ptr::null_mut();
+            assert_eq!(pkm_save_load(data.as_ptr(), data.len(), &mut save), PkmStatus::Ok);
+
+            let mut pokemon = ptr::null_mut();
+            assert_eq!(pkm_save_party_slot(save, 0, &mut pokemon), PkmStatus::Ok);
+            assert_eq!(pkm_save_set_box_slot(save, 3, 7, pokemon), PkmStatus::Ok);
+            assert_eq!(pkm_save_set_box_slot(save, 14, 0, pokemon), PkmStatus::InvalidArgument);
+            assert_eq!(pkm_save_set_party_slot(save, 4, pokemon), PkmStatus::Ok);
+            assert_eq!(pkm_save_set_party_slot(save, 0, ptr::null()), PkmStatus::NullPointer);
+            pkm_pokemon_free(pokemon);
+
+            let mut exported = PkmBuffer { data: ptr::null_mut(), len: 0 };
+            assert_eq!(pkm_save_export(save, &mut exported), PkmStatus::Ok);
+            pkm_save_free(save);
+
+            let mut reloaded = ptr::null_mut();
+            assert_eq!(pkm_save_load(exported.data, exported.len, &mut reloaded), PkmStatus::Ok);
+            pkm_buffer_free(exported);
+
+            // The empty party slot 4 is filled from the end of the party
+            assert_eq!(pkm_save_party_count(reloaded), 2);
+            let mut stored = ptr::null_mut();
+            assert_eq!(pkm_save_box_slot(reloaded, 3, 7, &mut stored), PkmStatus::Ok);
+            assert_eq!(pkm_pokemon_species(stored), 263);
+
+            pkm_pokemon_free(stored);
+            pkm_save_free(reloaded);
+        }
+    }
+}
@@ -6,3 +6,4 @@ pub mod species_id;
 pub mod gamecube_region;
 pub mod location;
 pub mod generation;
+pub mod pokemon_type;
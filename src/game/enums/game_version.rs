@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
 pub enum GameVersion {
     Invalid = -2,
     #[default]
@@ -160,6 +160,24 @@ pub enum GameVersion {
 }
 
 impl GameVersion {
+    /// Checks if the given version is this version or belongs to this group.
+    ///
+    /// # Arguments
+    /// * `version` - Version stored within Pokemon Data
+    pub fn contains(&self, version: GameVersion) -> bool {
+        use GameVersion::*;
+
+        match self {
+            Any => true,
+            GroupRubySapphire => matches!(version, Ruby | Sapphire),
+            GroupRubySapphireEmerald => matches!(version, Ruby | Sapphire | Emerald),
+            GroupFireRedLeafGreen => matches!(version, FireRed | LeafGreen),
+            GroupColosseum | GroupXD => version == ColosseumXD,
+            CollectionGeneration3 => matches!(version, Ruby | Sapphire | Emerald | FireRed | LeafGreen | ColosseumXD),
+            _ => *self == version,
+        }
+    }
+
     /// Colosseum/XD analogues used instead of the main-series values.
     pub fn to_colosseumxd_id(&self) -> u8 {
         match self {
@@ -187,8 +205,74 @@ impl GameVersion {
     }
 }
 
+impl From<u8> for GameVersion {
+    /// Converts a version value stored within Pokemon Data
+    fn from(item: u8) -> Self {
+        match item {
+            0 => GameVersion::Unknown,
+            1 => GameVersion::Sapphire,
+            2 => GameVersion::Ruby,
+            3 => GameVersion::Emerald,
+            4 => GameVersion::FireRed,
+            5 => GameVersion::LeafGreen,
+            7 => GameVersion::HeartGold,
+            8 => GameVersion::SoulSilver,
+            9 => GameVersion::Generation4Unused,
+            10 => GameVersion::Diamond,
+            11 => GameVersion::Pearl,
+            12 => GameVersion::Platinum,
+            15 => GameVersion::ColosseumXD,
+            20 => GameVersion::White,
+            21 => GameVersion::Black,
+            22 => GameVersion::White2,
+            23 => GameVersion::Black2,
+            24 => GameVersion::X,
+            25 => GameVersion::Y,
+            26 => GameVersion::AlphaSapphire,
+            27 => GameVersion::OmegaRuby,
+            30 => GameVersion::Sun,
+            31 => GameVersion::Moon,
+            32 => GameVersion::UltraSun,
+            33 => GameVersion::UltraMoon,
+            34 => GameVersion::Go,
+            35 => GameVersion::VirtualRed,
+            36 => GameVersion::VirtualGreenJPBlueInt,
+            37 => GameVersion::VirtualBlueJP,
+            38 => GameVersion::VirtualYellow,
+            39 => GameVersion::VirtualGold,
+            40 => GameVersion::VirtualSilver,
+            41 => GameVersion::VirtualCrystal,
+            42 => GameVersion::LetsGoPikachu,
+            43 => GameVersion::LetsGoEevee,
+            44 => GameVersion::Sword,
+            45 => GameVersion::Shield,
+            46 => GameVersion::Home,
+            47 => GameVersion::LegendsArceus,
+            48 => GameVersion::BrilliantDiamond,
+            49 => GameVersion::ShiningPearl,
+            _ => GameVersion::Invalid,
+        }
+    }
+}
+
 impl Display for GameVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        todo!()
+        let name = match self {
+            GameVersion::Sapphire => "Sapphire",
+            GameVersion::Ruby => "Ruby",
+            GameVersion::Emerald => "Emerald",
+            GameVersion::FireRed => "FireRed",
+            GameVersion::LeafGreen => "LeafGreen",
+            GameVersion::ColosseumXD => "Colosseum/XD",
+            GameVersion::GroupRedBlueYellow => "Red/Blue/Yellow",
+            GameVersion::GroupGoldSilverCrystal => "Gold/Silver/Crystal",
+            GameVersion::GroupRubySapphire => "Ruby/Sapphire",
+            GameVersion::GroupRubySapphireEmerald => "Ruby/Sapphire/Emerald",
+            GameVersion::GroupFireRedLeafGreen => "FireRed/LeafGreen",
+            // The remaining games are not supported yet
+            _ => return write!(f, "{:?}", self),
+        };
+
+        write!(f, "{}", name)
     }
 }
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LanguageID {
     /// Undefined Language ID, usually indicative of a value not being set.
     /// Obs.: Generation 5 Japanese In-game Trades happen to not have their Language value set, and express Language=0
@@ -66,3 +66,21 @@ impl LanguageID {
         }
     }
 }
+
+impl From<u8> for LanguageID {
+    fn from(item: u8) -> Self {
+        match item {
+            1 => LanguageID::Japanese,
+            2 => LanguageID::English,
+            3 => LanguageID::French,
+            4 => LanguageID::Italian,
+            5 => LanguageID::German,
+            6 => LanguageID::UnusedKoreanGen3,
+            7 => LanguageID::Spanish,
+            8 => LanguageID::Korean,
+            9 => LanguageID::ChineseSimplified,
+            10 => LanguageID::ChineseTraditional,
+            _ => LanguageID::Hacked,
+        }
+    }
+}
@@ -19,6 +19,11 @@ pub const POKE_WALKER_GENERATION4: u16 = 233;
 pub const RANGER_GENERATION4: u16 = 3001;
 pub const FARAWAY_GENERATION4: u16 = 3002;
 
+/// Met location of Generation 3 eggs that did not hatch yet, replaced by the hatch location
+pub const EGG_UNHATCHED_GENERATION3: u16 = 253;
+/// Met location of Generation 3 Pokémon received from events
+pub const FATEFUL_ENCOUNTER_GENERATION3: u16 = 255;
+
 /// Goldenrod City in Crystal
 pub const HATCH_LOCATION_GOLDENROD_CITY_CRYSTAL: u16 = 16;
 
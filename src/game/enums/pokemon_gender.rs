@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PokemonGender {
 	Male = 0,
 	Female = 1,
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PokemonNature {
     Hardy = 0,
     Lonely = 1,
@@ -29,3 +29,36 @@ pub enum PokemonNature {
     #[default]
     Random = 25,
 }
+
+impl From<u8> for PokemonNature {
+    fn from(item: u8) -> Self {
+        match item {
+            0 => PokemonNature::Hardy,
+            1 => PokemonNature::Lonely,
+            2 => PokemonNature::Brave,
+            3 => PokemonNature::Adamant,
+            4 => PokemonNature::Naughty,
+            5 => PokemonNature::Bold,
+            6 => PokemonNature::Docile,
+            7 => PokemonNature::Relaxed,
+            8 => PokemonNature::Impish,
+            9 => PokemonNature::Lax,
+            10 => PokemonNature::Timid,
+            11 => PokemonNature::Hasty,
+            12 => PokemonNature::Serious,
+            13 => PokemonNature::Jolly,
+            14 => PokemonNature::Naive,
+            15 => PokemonNature::Modest,
+            16 => PokemonNature::Mild,
+            17 => PokemonNature::Quiet,
+            18 => PokemonNature::Bashful,
+            19 => PokemonNature::Rash,
+            20 => PokemonNature::Calm,
+            21 => PokemonNature::Gentle,
+            22 => PokemonNature::Sassy,
+            23 => PokemonNature::Careful,
+            24 => PokemonNature::Quirky,
+            _ => PokemonNature::Random,
+        }
+    }
+}
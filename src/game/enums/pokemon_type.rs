@@ -0,0 +1,71 @@
+/// Elemental types, values are the ones used by Generation 3
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PokemonType {
+    #[default]
+    Normal = 0,
+    Fighting = 1,
+    Flying = 2,
+    Poison = 3,
+    Ground = 4,
+    Rock = 5,
+    Bug = 6,
+    Ghost = 7,
+    Steel = 8,
+    /// Type of the move Curse, not used by any species
+    Mystery = 9,
+    Fire = 10,
+    Water = 11,
+    Grass = 12,
+    Electric = 13,
+    Psychic = 14,
+    Ice = 15,
+    Dragon = 16,
+    Dark = 17,
+}
+
+impl PokemonType {
+    /// Gets the value stored by Red/Blue/Yellow, `None` for the types added in Generation 2
+    pub fn generation1_id(&self) -> Option<u8> {
+        use PokemonType::*;
+
+        match self {
+            Normal | Fighting | Flying | Poison | Ground | Rock => Some(*self as u8),
+            // 6 is an unused bird type
+            Bug => Some(7),
+            Ghost => Some(8),
+            Fire => Some(20),
+            Water => Some(21),
+            Grass => Some(22),
+            Electric => Some(23),
+            Psychic => Some(24),
+            Ice => Some(25),
+            Dragon => Some(26),
+            Steel | Mystery | Dark => None,
+        }
+    }
+}
+
+impl From<u8> for PokemonType {
+    fn from(item: u8) -> Self {
+        match item {
+            1 => PokemonType::Fighting,
+            2 => PokemonType::Flying,
+            3 => PokemonType::Poison,
+            4 => PokemonType::Ground,
+            5 => PokemonType::Rock,
+            6 => PokemonType::Bug,
+            7 => PokemonType::Ghost,
+            8 => PokemonType::Steel,
+            9 => PokemonType::Mystery,
+            10 => PokemonType::Fire,
+            11 => PokemonType::Water,
+            12 => PokemonType::Grass,
+            13 => PokemonType::Electric,
+            14 => PokemonType::Psychic,
+            15 => PokemonType::Ice,
+            16 => PokemonType::Dragon,
+            17 => PokemonType::Dark,
+            _ => PokemonType::Normal,
+        }
+    }
+}
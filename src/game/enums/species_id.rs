@@ -1,5 +1,6 @@
 #[allow(non_camel_case_types)]
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
 pub enum SpeciesID {
     #[default]
     None,
@@ -910,3 +911,23 @@ pub enum SpeciesID {
     Enamorus,
     MAX_COUNT,
 }
+
+impl SpeciesID {
+    /// Gets the species from its National Pokédex number.
+    ///
+    /// Returns `None` if the number is past the last known species.
+    pub fn from_national_id(id: u16) -> Option<Self> {
+        if id >= SpeciesID::MAX_COUNT as u16 {
+            return None;
+        }
+
+        // SAFETY: `SpeciesID` is `repr(u16)` with contiguous discriminants starting at 0,
+        // and `id` was checked to be below `MAX_COUNT`.
+        Some(unsafe { std::mem::transmute::<u16, SpeciesID>(id) })
+    }
+
+    /// National Pokédex number of the species
+    pub fn national_id(&self) -> u16 {
+        *self as u16
+    }
+}
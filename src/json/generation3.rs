@@ -0,0 +1,513 @@
+//! JSON documents of the Generation 3 saves and Pokémon.
+//!
+//! Names (species, items, natures...) are exported in English next to the IDs to make the documents
+//! readable. They are never applied, changing one only shows up in the `ApplyReport`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::enums::{language_id::LanguageID, species_id::SpeciesID},
+    legality::tables::generation3::MOVE_PP,
+    pokemon::{base::PokemonTrait, utils::experience::get_minimum_level_experience, Generation3},
+    save::{
+        base::SaveFileTrait,
+        generation3::{
+            base::Generation3SaveFileBase,
+            inventory::{InventoryItem, Pouch},
+        },
+    },
+    species::conversion::generation3_index_from_species,
+    strings::{
+        self,
+        names::{ability_name, item_name, nature_name, species_name},
+        StringConverterOption,
+    },
+    utils::{
+        constants::poke_crypto::{get_generatation3_checksum, SIZE_GENERATION_3_PARTY, SIZE_GENERATION_3_STORED},
+        data_manipulation::SliceUtils,
+    },
+};
+
+use super::ApplyReport;
+
+// region: Documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SaveDocument {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub game: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trainer: Option<TrainerDocument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playtime: Option<PlaytimeDocument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub money: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_box: Option<u16>,
+    /// Replaces the whole party when present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party: Option<Vec<PokemonDocument>>,
+    /// Each box present replaces the whole box, the slots not listed are emptied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boxes: Option<Vec<BoxDocument>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<Vec<PouchDocument>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct TrainerDocument {
+    pub name: String,
+    pub gender: u8,
+    pub trainer_id: u16,
+    pub secret_id: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PlaytimeDocument {
+    pub hours: u16,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BoxDocument {
+    pub index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Occupied slots only
+    #[serde(default)]
+    pub slots: Vec<BoxSlotDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BoxSlotDocument {
+    pub slot: usize,
+    #[serde(flatten)]
+    pub pokemon: PokemonDocument,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PouchDocument {
+    pub pouch: Pouch,
+    pub items: Vec<ItemDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ItemDocument {
+    pub item: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub count: u16,
+}
+
+/// Stored fields of a Pokémon, the stats are calculated when it is in the party.
+/// IVs and EVs are in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct PokemonDocument {
+    /// National Pokédex number
+    pub species: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub species_name: Option<String>,
+    pub nickname: String,
+    pub is_egg: bool,
+    /// Only applied when the experience is unchanged, the level always follows the experience
+    pub level: u8,
+    pub experience: u32,
+    pub personality_id: u32,
+    /// Derived from the PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nature: Option<String>,
+    /// Derived from the PID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<String>,
+    /// Derived from the species and the ability number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ability: Option<String>,
+    pub ability_number: u8,
+    /// Derived from the PID and the Original Trainer IDs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_shiny: Option<bool>,
+    pub held_item: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub held_item_name: Option<String>,
+    pub moves: [u16; 4],
+    pub pp_ups: [u8; 4],
+    pub ivs: [u8; 6],
+    pub evs: [u8; 6],
+    pub friendship: u8,
+    pub language: u8,
+    pub original_trainer: TrainerDocument,
+    pub met_location: u8,
+    pub met_level: u8,
+    pub ball: u8,
+    pub version: u8,
+    pub fateful_encounter: bool,
+}
+// endregion
+
+// region: Export
+/// Exports the stored fields of the Pokémon
+pub fn export_pokemon(pokemon: &Generation3) -> PokemonDocument {
+    let base = &pokemon.base;
+
+    PokemonDocument {
+        species: base.species.national_id(),
+        species_name: species_name(base.species, LanguageID::English).map(str::to_string),
+        nickname: base.nickname.clone(),
+        is_egg: pokemon.is_egg(),
+        level: base.level,
+        experience: base.experience,
+        personality_id: base.personality_id,
+        nature: nature_name(base.nature, LanguageID::English).map(str::to_string),
+        gender: Some(format!("{:?}", base.gender)),
+        ability: ability_name(base.ability as u16).map(str::to_string),
+        ability_number: base.ability_number as u8,
+        is_shiny: Some(base.is_shiny()),
+        held_item: base.held_item,
+        held_item_name: item_name(base.held_item).map(str::to_string),
+        moves: [base.move_1, base.move_2, base.move_3, base.move_4],
+        pp_ups: [base.move_1_pp_ups, base.move_2_pp_ups, base.move_3_pp_ups, base.move_4_pp_ups],
+        ivs: [base.iv_hitpoints, base.iv_attack, base.iv_defense, base.iv_speed, base.iv_special_attack, base.iv_special_defense].map(|iv| iv as u8),
+        evs: [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack, base.ev_special_defense].map(|ev| ev as u8),
+        friendship: base.current_friendship,
+        language: base.language as u8,
+        original_trainer: TrainerDocument {
+            name: base.ot_info.name.clone(),
+            gender: base.ot_info.gender,
+            trainer_id: base.ot_info.public_id,
+            secret_id: base.ot_info.secret_id,
+        },
+        met_location: base.met_location as u8,
+        met_level: base.met_level,
+        ball: base.ball_type,
+        version: base.version as u8,
+        fateful_encounter: base.fateful_encounter,
+    }
+}
+
+/// Exports the trainer, party, boxes and inventory of the save
+pub fn export(save: &Generation3SaveFileBase) -> SaveDocument {
+    let base = &save.base;
+
+    let boxes = (0..base.constants.box_count as usize)
+        .map(|index| BoxDocument {
+            index,
+            name: Some(save.get_box_name(index)),
+            slots: (0..base.constants.box_size as usize)
+                .filter_map(|slot| {
+                    let pokemon = save.get_box_slot(index, slot)?;
+                    let occupied = pokemon.base.species != SpeciesID::None || pokemon.is_bad_egg;
+
+                    occupied.then(|| BoxSlotDocument { slot, pokemon: export_pokemon(&pokemon) })
+                })
+                .collect(),
+        })
+        .collect();
+
+    let inventory = Pouch::ALL
+        .iter()
+        .map(|pouch| PouchDocument {
+            pouch: *pouch,
+            items: save
+                .pouch(*pouch)
+                .iter()
+                .map(|entry| ItemDocument {
+                    item: entry.item,
+                    name: item_name(entry.item).map(str::to_string),
+                    count: entry.count,
+                })
+                .collect(),
+        })
+        .collect();
+
+    SaveDocument {
+        game: Some(base.game.to_string()),
+        trainer: Some(TrainerDocument {
+            name: base.trainer_info.name.clone(),
+            gender: base.trainer_info.gender,
+            trainer_id: base.trainer_info.public_id,
+            secret_id: base.trainer_info.secret_id,
+        }),
+        playtime: Some(PlaytimeDocument {
+            hours: base.hours_played as u16,
+            minutes: base.minutes_played as u8,
+            seconds: base.seconds_played as u8,
+        }),
+        money: Some(base.money),
+        current_box: Some(base.current_box),
+        party: Some(save.party().iter().map(export_pokemon).collect()),
+        boxes: Some(boxes),
+        inventory: Some(inventory),
+    }
+}
+// endregion
+
+// region: Import
+/// Writes the stored fields of the document over the Pokémon.
+/// Text that can't be encoded and unknown species are left unchanged.
+///
+/// A level change sets the minimum experience of the level, which is also set in the document
+/// so that it is not reported as unapplied.
+fn write_pokemon(pokemon: &Generation3, document: &mut PokemonDocument) -> Generation3 {
+    let mut data = pokemon.base.data.clone();
+    if data.len() < SIZE_GENERATION_3_STORED as usize {
+        data.resize(SIZE_GENERATION_3_STORED as usize, 0);
+    }
+
+    let language = LanguageID::from(document.language);
+    let is_japanese = language == LanguageID::Japanese;
+
+    data[0x00..0x04].copy_from_slice(&document.personality_id.to_le_bytes());
+    data[0x04..0x06].copy_from_slice(&document.original_trainer.trainer_id.to_le_bytes());
+    data[0x06..0x08].copy_from_slice(&document.original_trainer.secret_id.to_le_bytes());
+
+    let mut nickname = [0u8; 10];
+    let max_length = if is_japanese { 5 } else { 10 };
+    if strings::generation_3::encode(&mut nickname, &document.nickname, max_length, language, StringConverterOption::ClearFF).is_ok() {
+        data[0x08..0x12].copy_from_slice(&nickname);
+    }
+
+    data[0x12] = document.language;
+
+    let species = SpeciesID::from_national_id(document.species).filter(|species| *species != SpeciesID::None);
+    let species_index = species.and_then(generation3_index_from_species);
+
+    // Has species and use egg name flags
+    let mut flags = data[0x13] & !0b110;
+    if species_index.is_some() || pokemon.base.species != SpeciesID::None {
+        flags |= 0b010;
+    }
+    if document.is_egg {
+        flags |= 0b100;
+    }
+    data[0x13] = flags;
+
+    let mut ot_name = [0u8; 7];
+    let max_length = if is_japanese { 5 } else { 7 };
+    if strings::generation_3::encode(&mut ot_name, &document.original_trainer.name, max_length, language, StringConverterOption::ClearFF).is_ok() {
+        data[0x14..0x1B].copy_from_slice(&ot_name);
+    }
+
+    // region: Growth
+    if let Some(index) = species_index {
+        data[0x20..0x22].copy_from_slice(&index.to_le_bytes());
+    }
+
+    data[0x22..0x24].copy_from_slice(&document.held_item.to_le_bytes());
+    data[0x24..0x28].copy_from_slice(&document.experience.to_le_bytes());
+
+    let pp_ups = document.pp_ups.iter().enumerate().fold(0u8, |pp_ups, (slot, value)| pp_ups | ((value & 3) << (slot * 2)));
+    data[0x28] = pp_ups;
+    data[0x29] = document.friendship;
+    // endregion
+
+    // region: Attacks
+    for (slot, move_id) in document.moves.iter().enumerate() {
+        let offset = 0x2C + slot * 2;
+        if data[offset..offset + 2] == move_id.to_le_bytes() {
+            continue;
+        }
+
+        let base_pp = MOVE_PP.get(*move_id as usize).copied().unwrap_or(0);
+        data[offset..offset + 2].copy_from_slice(&move_id.to_le_bytes());
+        data[0x34 + slot] = base_pp + base_pp * (document.pp_ups[slot] & 3) / 5;
+    }
+    // endregion
+
+    data[0x38..0x3E].copy_from_slice(&document.evs);
+
+    // region: Misc
+    let origins = (document.met_level as u16 & 0x7F)
+        | ((document.version as u16 & 0xF) << 7)
+        | ((document.ball as u16 & 0xF) << 11)
+        | ((document.original_trainer.gender as u16 & 1) << 15);
+    data[0x45] = document.met_location;
+    data[0x46..0x48].copy_from_slice(&origins.to_le_bytes());
+
+    let iv32 = document.ivs.iter().enumerate().fold(0u32, |iv32, (index, iv)| iv32 | (((*iv as u32) & 0x1F) << (index * 5)))
+        | ((document.is_egg as u32) << 30)
+        | (((document.ability_number & 1) as u32) << 31);
+    data[0x48..0x4C].copy_from_slice(&iv32.to_le_bytes());
+
+    let ribbons = (data.get_u32_le_offset(0x4C).unwrap_or_default() & !(1 << 31)) | ((document.fateful_encounter as u32) << 31);
+    data[0x4C..0x50].copy_from_slice(&ribbons.to_le_bytes());
+    // endregion
+
+    let checksum = get_generatation3_checksum(&data);
+    data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+    let mut result = Generation3::from_decrypted(&data);
+
+    // A level change is applied as the minimum experience of the level
+    let level = result.base.current_level();
+    if document.experience == pokemon.base.experience && document.level != level && (1..=100).contains(&document.level) {
+        let experience = get_minimum_level_experience(document.level, result.base.species_info.exp_growth as usize);
+        if result.patch(0x24, &experience.to_le_bytes()).is_ok() {
+            document.experience = experience;
+        }
+    }
+
+    if result.base.data.len() >= SIZE_GENERATION_3_PARTY as usize {
+        result.base.level = result.base.current_level();
+        result.refresh_stats();
+        result.regenerate_checksum();
+    }
+
+    result
+}
+
+/// Applies the document onto the Pokémon
+pub fn apply_pokemon(pokemon: &mut Generation3, document: &PokemonDocument) -> ApplyReport {
+    let mut expected = document.clone();
+    *pokemon = write_pokemon(pokemon, &mut expected);
+
+    ApplyReport::compare(&expected, &export_pokemon(pokemon))
+}
+
+/// Applies the sections present in the document onto the save
+pub fn apply(save: &mut Generation3SaveFileBase, document: &SaveDocument) -> ApplyReport {
+    let mut expected = document.clone();
+
+    if let Some(trainer) = &document.trainer {
+        let mut info = save.base.trainer_info.clone();
+        info.name = trainer.name.clone();
+        info.gender = trainer.gender;
+        info.public_id = trainer.trainer_id;
+        info.secret_id = trainer.secret_id;
+
+        // Reported by the comparison
+        let _ = save.set_trainer_info(&info);
+    }
+
+    if let Some(playtime) = document.playtime {
+        save.set_playtime(playtime.hours, playtime.minutes, playtime.seconds);
+    }
+
+    if let Some(money) = document.money {
+        save.set_money(money);
+    }
+
+    if let Some(current_box) = document.current_box {
+        save.set_current_box(current_box);
+    }
+
+    if let Some(party) = &mut expected.party {
+        apply_party(save, party);
+    }
+
+    for box_document in expected.boxes.iter_mut().flatten() {
+        apply_box(save, box_document);
+    }
+
+    for pouch in document.inventory.iter().flatten() {
+        let items: Vec<InventoryItem> = pouch.items.iter().map(|entry| InventoryItem { item: entry.item, count: entry.count }).collect();
+        let _ = save.set_pouch(pouch.pouch, &items);
+    }
+
+    ApplyReport::compare(&expected, &export(save))
+}
+
+/// Replaces the party, a party can't be emptied
+fn apply_party(save: &mut Generation3SaveFileBase, party: &mut [PokemonDocument]) {
+    if party.is_empty() {
+        return;
+    }
+
+    let current = save.party();
+    let empty = Generation3::from_decrypted(&[0; SIZE_GENERATION_3_PARTY as usize]);
+    let count = party.len().min(save.base.constants.max_party_count as usize);
+
+    for (slot, document) in party.iter_mut().take(count).enumerate() {
+        let pokemon = write_pokemon(current.get(slot).unwrap_or(&empty), document);
+        save.set_party_slot(slot as u8, &pokemon);
+    }
+
+    save.set_party_count(count as u8);
+}
+
+/// Replaces the name and every slot of the box
+fn apply_box(save: &mut Generation3SaveFileBase, document: &mut BoxDocument) {
+    if document.index >= save.base.constants.box_count as usize {
+        return;
+    }
+
+    // Unchanged names are not written again, keeping the bytes after the terminator
+    if let Some(name) = document.name.as_ref().filter(|name| **name != save.get_box_name(document.index)) {
+        save.set_box_name(document.index, name.clone());
+    }
+
+    for slot in 0..save.base.constants.box_size as usize {
+        let pokemon = match document.slots.iter_mut().find(|entry| entry.slot == slot) {
+            Some(entry) => write_pokemon(&save.get_box_slot(document.index, slot).unwrap_or_default(), &mut entry.pokemon),
+            None => Generation3::from_decrypted(&[0; SIZE_GENERATION_3_STORED as usize]),
+        };
+
+        save.set_box_slot(document.index, slot, &pokemon);
+    }
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, pokemon_nature::PokemonNature, species_id::SpeciesID},
+        json::{from_json, to_json},
+        legality::generator::{generation3::generate, GeneratorCriteria},
+        pokemon::Generation3,
+        save::generation3::{base::Generation3SaveFileBase, inventory::Pouch},
+        trainer::TrainerInfo,
+    };
+
+    use super::{apply, apply_pokemon, export, export_pokemon, BoxSlotDocument, ItemDocument, SaveDocument};
+
+    fn treecko() -> Generation3 {
+        let trainer = TrainerInfo {
+            name: "ASH".to_string(),
+            public_id: 12345,
+            secret_id: 54321,
+            ..Default::default()
+        };
+
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Treecko, GameVersion::Emerald);
+        criteria.nature = Some(PokemonNature::Jolly);
+        generate(&criteria, &trainer).unwrap()
+    }
+
+    #[test]
+    fn applies_pokemon_documents() {
+        let mut pokemon = treecko();
+        let mut document = export_pokemon(&pokemon);
+        assert_eq!(document.nature.as_deref(), Some("Jolly"));
+
+        document.nickname = "WOODY".to_string();
+        document.level = 10;
+        document.evs[0] = 20;
+        assert!(apply_pokemon(&mut pokemon, &document).is_complete());
+        assert_eq!(pokemon.base.nickname, "WOODY");
+        assert_eq!(pokemon.base.level, 10);
+        assert_eq!(pokemon.base.ev_hitpoints, 20);
+
+        let mut document = export_pokemon(&pokemon);
+        document.nature = Some("Adamant".to_string());
+        document.ivs[1] = 40;
+        assert_eq!(apply_pokemon(&mut pokemon, &document).unapplied, ["ivs[1]", "nature"]);
+    }
+
+    #[test]
+    fn applies_save_documents() {
+        let mut save = Generation3SaveFileBase::initilize(vec![0; 0x10000]);
+        save.set_party_slot(0, &treecko());
+
+        let mut document: SaveDocument = from_json(&to_json(&export(&save)).unwrap()).unwrap();
+        let party = document.party.clone().unwrap();
+        document.money = Some(5000);
+        document.trainer.as_mut().unwrap().name = "MAY".to_string();
+        document.boxes.as_mut().unwrap()[1].slots.push(BoxSlotDocument { slot: 3, pokemon: party[0].clone() });
+        document.inventory.as_mut().unwrap()[0].items.push(ItemDocument { item: 13, name: None, count: 5 });
+        document.party.as_mut().unwrap()[0].species_name = Some("Mudkip".to_string());
+
+        assert_eq!(apply(&mut save, &document).unapplied, ["party[0].species_name"]);
+        assert_eq!(save.base.trainer_info.name, "MAY");
+        assert_eq!(save.read_money(), 5000);
+        assert_eq!(save.get_box_slot(1, 3).unwrap().base.species, SpeciesID::Treecko);
+        assert_eq!(save.pouch(Pouch::Items)[0].count, 5);
+    }
+}
@@ -0,0 +1,121 @@
+//! JSON documents of the saves and Pokémon, for tools that don't read the binary formats.
+//!
+//! Applying a document writes every field the format can store, then compares the document with an
+//! export of the result. The fields that differ are listed in the `ApplyReport`, so values the game
+//! can't represent (e.g. a nature that does not match the PID) are reported instead of dropped silently.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::error::PkmError;
+
+pub mod generation3;
+
+/// Keys identifying the elements of the arrays compared by key instead of position
+const ELEMENT_KEYS: [&str; 3] = ["index", "slot", "pouch"];
+
+/// Fields of a document that could not be applied
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ApplyReport {
+    /// Paths of the fields, like `party[0].nature` or `boxes[2].slots[5]`
+    pub unapplied: Vec<String>,
+}
+
+impl ApplyReport {
+    /// Whether every field of the document was applied
+    pub fn is_complete(&self) -> bool {
+        self.unapplied.is_empty()
+    }
+
+    /// Compares the applied document with an export of the result
+    ///
+    /// # Arguments
+    /// * `document` - Document that was applied
+    /// * `result` - Document exported after applying it
+    pub(crate) fn compare<T: Serialize>(document: &T, result: &T) -> Self {
+        let mut report = Self::default();
+
+        if let (Ok(document), Ok(result)) = (serde_json::to_value(document), serde_json::to_value(result)) {
+            collect_differences("", &document, &result, &mut report.unapplied);
+        }
+
+        report
+    }
+}
+
+/// Serializes the document as indented JSON
+pub fn to_json<T: Serialize>(document: &T) -> Result<String, PkmError> {
+    serde_json::to_string_pretty(document).map_err(|error| PkmError::InvalidDocument(error.to_string()))
+}
+
+/// Parses a document from JSON
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, PkmError> {
+    serde_json::from_str(json).map_err(|error| PkmError::InvalidDocument(error.to_string()))
+}
+
+fn element_key(value: &Value) -> Option<String> {
+    let object = value.as_object()?;
+
+    ELEMENT_KEYS.iter().find_map(|key| match object.get(*key)? {
+        Value::String(key) => Some(key.clone()),
+        key => Some(key.to_string()),
+    })
+}
+
+/// Adds the path of every value of the document that is missing or different in the result
+fn collect_differences(path: &str, document: &Value, result: &Value, differences: &mut Vec<String>) {
+    match (document, result) {
+        (Value::Object(document), Value::Object(result)) => {
+            for (key, value) in document {
+                let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+
+                match result.get(key) {
+                    Some(result) => collect_differences(&path, value, result, differences),
+                    None => differences.push(path),
+                }
+            }
+        }
+        (Value::Array(document), Value::Array(result)) if !document.is_empty() && document.iter().all(|value| element_key(value).is_some()) => {
+            for value in document {
+                let key = element_key(value);
+                let path = format!("{}[{}]", path, key.clone().unwrap_or_default());
+
+                match result.iter().find(|result| element_key(result) == key) {
+                    Some(result) => collect_differences(&path, value, result, differences),
+                    None => differences.push(path),
+                }
+            }
+        }
+        (Value::Array(document), Value::Array(result)) => {
+            if document.len() != result.len() {
+                differences.push(path.to_string());
+            }
+
+            for (index, (value, result)) in document.iter().zip(result).enumerate() {
+                collect_differences(&format!("{}[{}]", path, index), value, result, differences);
+            }
+        }
+        _ => {
+            if document != result {
+                differences.push(path.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::collect_differences;
+
+    #[test]
+    fn differences_use_element_keys() {
+        let document = json!({ "money": 10, "boxes": [{ "index": 2, "slots": [{ "slot": 5, "nature": "Jolly" }] }], "moves": [1, 2] });
+        let result = json!({ "money": 10, "boxes": [{ "index": 0 }, { "index": 2, "slots": [{ "slot": 5, "nature": "Hardy" }] }], "moves": [1, 3, 0] });
+
+        let mut differences = Vec::new();
+        collect_differences("", &document, &result, &mut differences);
+        assert_eq!(differences, ["boxes[2].slots[5].nature", "moves", "moves[1]"]);
+    }
+}
@@ -0,0 +1,67 @@
+pub mod generation3;
+
+use crate::{pokemon::Generation3, save::base::SaveFile, trainer::TrainerInfo};
+
+use super::check::{CheckIdentifier, CheckResult, CheckSeverity};
+
+/// Result of checking every area of a Pokémon against what is possible in the games
+#[derive(Debug, Clone, Default)]
+pub struct LegalityAnalysis {
+    pub results: Vec<CheckResult>,
+}
+
+impl LegalityAnalysis {
+    /// Analyzes a Generation 3 Pokémon.
+    ///
+    /// # Arguments
+    /// * `pokemon` - Pokémon to analyze
+    /// * `trainer` - Trainer of the save holding the Pokémon, when known
+    pub fn generation3(pokemon: &Generation3, trainer: Option<&TrainerInfo>) -> Self {
+        Self {
+            results: generation3::analyze(pokemon, trainer),
+        }
+    }
+
+    /// Analyzes a Generation 3 Pokémon stored in the given save.
+    pub fn generation3_in_save(pokemon: &Generation3, save: &SaveFile) -> Self {
+        Self::generation3(pokemon, Some(&save.trainer_info))
+    }
+
+    /// Checks if no result is invalid
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(|result| result.is_valid())
+    }
+
+    /// Gets the most severe result, `Valid` when there are no results
+    pub fn severity(&self) -> CheckSeverity {
+        self.results
+            .iter()
+            .map(|result| result.severity)
+            .max()
+            .unwrap_or(CheckSeverity::Valid)
+    }
+
+    /// Gets the results about the given area
+    pub fn results_for(&self, identifier: CheckIdentifier) -> impl Iterator<Item = &CheckResult> {
+        self.results.iter().filter(move |result| result.identifier == identifier)
+    }
+
+    /// Gets a human-readable report, one result per line.
+    ///
+    /// # Arguments
+    /// * `verbose` - Also lists the valid results
+    pub fn report(&self, verbose: bool) -> String {
+        let lines = self
+            .results
+            .iter()
+            .filter(|result| verbose || result.severity != CheckSeverity::Valid)
+            .map(|result| result.to_string())
+            .collect::<Vec<String>>();
+
+        if lines.is_empty() {
+            return "Legal!".to_string();
+        }
+
+        lines.join("\n")
+    }
+}
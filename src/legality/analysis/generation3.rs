@@ -0,0 +1,571 @@
+use crate::{
+    game::enums::{game_version::GameVersion, language_id::LanguageID, location::FATEFUL_ENCOUNTER_GENERATION3, species_id::SpeciesID},
+    legality::{
+        check::{CheckIdentifier, CheckResult},
+        encounters::{generation3::find_encounters_for, Encounter, EncounterKind, SlotType},
+        events::generation3::{find_events, find_events_for, validate as validate_event},
+        items::{held_items, is_ball_allowed_generation3, POKE_BALL},
+        learnsets::generation3::{find_move_sources, learnset},
+        met_location::{validate_generation3_met, InvalidMetData},
+        tables::generation3::{MAX_ITEM_ID, MAX_MOVE_ID, MAX_POKEBALL_ID},
+    },
+    pokemon::{utils::experience::get_minimum_level_experience, Generation3},
+    rng::generation3::{find_pidiv_matches_for, PIDIVMethod},
+    species::personal::PersonalInfo,
+    strings::names::{item_name, move_name},
+    trainer::TrainerInfo,
+    utils::{
+        constants::poke_crypto::{get_generatation3_checksum, SIZE_GENERATION_3_PARTY, SIZE_GENERATION_3_STORED},
+        data_manipulation::SliceUtils,
+    },
+};
+
+/// Nickname of every Generation 3 egg, regardless of the game language
+pub const EGG_NAME: &str = "タマゴ";
+
+pub const MAX_EV_TOTAL: u16 = 510;
+
+/// Bits of the ribbon value that are never set by the games
+const RIBBONS_UNUSED_MASK: u32 = 0b1111 << 27;
+/// Bits of the ribbon value that are not ribbons
+const RIBBONS_FATEFUL_ENCOUNTER_MASK: u32 = 1 << 31;
+const CONTEST_RIBBON_CATEGORIES: [&str; 5] = ["Cool", "Beauty", "Cute", "Smart", "Tough"];
+const CONTEST_RIBBON_MAX_RANK: u32 = 4;
+
+/// Runs every Generation 3 check, in the order the data is usually read.
+pub(super) fn analyze(pokemon: &Generation3, trainer: Option<&TrainerInfo>) -> Vec<CheckResult> {
+    let mut results = Vec::new();
+
+    check_checksum(pokemon, &mut results);
+    check_species(pokemon, &mut results);
+
+    // Every other check depends on the species data
+    let Some(personal) = PersonalInfo::generation3(pokemon.base.species).filter(|_| pokemon.base.valid) else {
+        return results;
+    };
+
+    check_level(pokemon, personal, &mut results);
+    check_moves(pokemon, &mut results);
+    check_met(pokemon, &mut results);
+    check_encounter(pokemon, &mut results);
+    check_event(pokemon, &mut results);
+    check_ball(pokemon, &mut results);
+    check_pidiv(pokemon, &mut results);
+    check_held_item(pokemon, &mut results);
+    check_effort_values(pokemon, &mut results);
+    check_ability(pokemon, personal, &mut results);
+    check_nickname(pokemon, &mut results);
+    check_trainer(pokemon, trainer, &mut results);
+    check_ribbons(pokemon, &mut results);
+
+    results
+}
+
+fn check_checksum(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let data = &pokemon.base.data;
+    if data.len() < SIZE_GENERATION_3_STORED as usize {
+        results.push(CheckResult::invalid(CheckIdentifier::Checksum, format!("Data is {} bytes, expected at least {}", data.len(), SIZE_GENERATION_3_STORED)));
+        return;
+    }
+
+    let stored = data.get_u16_le_offset(0x1C).unwrap();
+    let expected = get_generatation3_checksum(data);
+    if stored != expected {
+        results.push(CheckResult::invalid(CheckIdentifier::Checksum, format!("Checksum is {:#06X}, expected {:#06X}", stored, expected)));
+    }
+}
+
+fn check_species(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    if let Some(error) = &base.invalid_species_index {
+        results.push(CheckResult::invalid(CheckIdentifier::Species, error.to_string()));
+        return;
+    }
+
+    if pokemon.is_bad_egg {
+        results.push(CheckResult::invalid(CheckIdentifier::Species, "Bad Egg flag is set"));
+        return;
+    }
+
+    if base.species == SpeciesID::None {
+        results.push(CheckResult::invalid(CheckIdentifier::Species, "Slot has no species"));
+        return;
+    }
+
+    if !pokemon.has_species {
+        results.push(CheckResult::invalid(CheckIdentifier::Species, "Species flag is not set"));
+    } else if pokemon.use_egg_name != pokemon.is_egg {
+        results.push(CheckResult::invalid(CheckIdentifier::Species, "Egg name flag does not match the egg flag"));
+    } else {
+        results.push(CheckResult::valid(CheckIdentifier::Species, "Species is valid"));
+    }
+}
+
+fn check_level(pokemon: &Generation3, personal: &PersonalInfo, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+    let growth_rate = personal.growth_rate as usize;
+
+    let max_experience = get_minimum_level_experience(100, growth_rate);
+    if base.experience > max_experience {
+        results.push(CheckResult::invalid(CheckIdentifier::Level, format!("Experience {} is above the level 100 amount ({})", base.experience, max_experience)));
+        return;
+    }
+
+    let level = base.current_level();
+    if base.data.len() >= SIZE_GENERATION_3_PARTY as usize && base.level != level {
+        results.push(CheckResult::invalid(CheckIdentifier::Level, format!("Level {} does not match the experience (level {})", base.level, level)));
+        return;
+    }
+
+    if pokemon.is_egg {
+        if level != 5 || base.met_level != 0 {
+            results.push(CheckResult::invalid(CheckIdentifier::Level, "Eggs must be level 5 with met level 0"));
+            return;
+        }
+    } else if base.met_level > level {
+        results.push(CheckResult::invalid(CheckIdentifier::Level, format!("Met level {} is above the current level {}", base.met_level, level)));
+        return;
+    }
+
+    results.push(CheckResult::valid(CheckIdentifier::Level, "Level matches the experience"));
+}
+
+fn check_moves(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+    let moves = [base.move_1, base.move_2, base.move_3, base.move_4];
+
+    if moves[0] == 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::Moves, "First move slot is empty"));
+        return;
+    }
+
+    for (slot, move_id) in moves.iter().enumerate() {
+        if *move_id as u32 > MAX_MOVE_ID {
+            results.push(CheckResult::invalid(CheckIdentifier::Moves, format!("Move {} has an invalid ID {}", slot + 1, move_id)));
+            return;
+        }
+
+        if *move_id != 0 && moves[..slot].contains(move_id) {
+            results.push(CheckResult::invalid(CheckIdentifier::Moves, format!("Move {} is duplicated", slot + 1)));
+            return;
+        }
+
+        if *move_id != 0 && moves[..slot].contains(&0) {
+            results.push(CheckResult::invalid(CheckIdentifier::Moves, format!("Move {} is after an empty slot", slot + 1)));
+            return;
+        }
+    }
+
+    results.push(CheckResult::valid(CheckIdentifier::Moves, "Move slots are valid"));
+    check_learnset(pokemon, &moves, results);
+}
+
+fn check_learnset(pokemon: &Generation3, moves: &[u16], results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    if learnset(base.species).is_none() {
+        results.push(CheckResult::fishy(CheckIdentifier::Moves, format!("No learnset data for {:?}", base.species)));
+        return;
+    }
+
+    let hatched = base.met_level == 0;
+    let event_moves: Vec<u16> = find_events_for(pokemon).iter().flat_map(|event| event.moves.iter().copied()).collect();
+    let mut all_learnable = true;
+
+    for (slot, &move_id) in moves.iter().enumerate().filter(|(_, &move_id)| move_id != 0) {
+        if event_moves.contains(&move_id) || !find_move_sources(base.species, move_id, base.current_level(), hatched, base.fateful_encounter).is_empty() {
+            continue;
+        }
+
+        all_learnable = false;
+        let name = move_name(move_id).unwrap_or_default();
+        results.push(CheckResult::invalid(CheckIdentifier::Moves, format!("Move {} {} can't be learned", slot + 1, name)));
+    }
+
+    if all_learnable {
+        results.push(CheckResult::valid(CheckIdentifier::Moves, "Every move can be learned"));
+    }
+}
+
+fn check_met(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    match validate_generation3_met(base.version, base.met_location, base.met_level, base.egg_location, pokemon.is_egg) {
+        Ok(origin) => results.push(CheckResult::valid(CheckIdentifier::Met, format!("Met data matches {:?}", origin))),
+        // Some tools hatch eggs without updating the met data, the egg itself is still possible
+        Err(error @ InvalidMetData::UnhatchedEgg { .. }) => results.push(CheckResult::fishy(CheckIdentifier::Met, error.to_string())),
+        Err(error) => results.push(CheckResult::invalid(CheckIdentifier::Met, error.to_string())),
+    }
+}
+
+fn check_encounter(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    if pokemon.is_egg {
+        return;
+    }
+
+    match find_encounters_for(pokemon).first() {
+        Some(encounter) => results.push(CheckResult::valid(CheckIdentifier::Encounter, format!("Matches {:?} encounter", encounter.kind))),
+        // Only some of the Colosseum and XD shadow Pokémon are listed, a missing encounter is not invalid
+        None => results.push(CheckResult::fishy(CheckIdentifier::Encounter, "No matching encounter found")),
+    }
+}
+
+fn check_event(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    // Only gifts are checked, event tickets and eggs are checked as normal encounters
+    if !base.fateful_encounter && base.met_location != FATEFUL_ENCOUNTER_GENERATION3 {
+        return;
+    }
+
+    let events = find_events(base.species);
+    let checked: Vec<_> = events.iter().map(|event| (event, validate_event(pokemon, event))).collect();
+
+    if let Some((event, _)) = checked.iter().find(|(_, result)| result.is_ok()) {
+        results.push(CheckResult::valid(CheckIdentifier::Event, format!("Matches the {} distribution", event.name)));
+    } else if let Some((event, Err(mismatch))) = checked.first() {
+        results.push(CheckResult::invalid(CheckIdentifier::Event, format!("Does not match the {} distribution: {}", event.name, mismatch)));
+    } else {
+        // Only the best known distributions are listed, see `legality::events::generation3`
+        results.push(CheckResult::fishy(CheckIdentifier::Event, format!("No known distribution of {:?}", base.species)));
+    }
+}
+
+fn check_ball(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+    let ball = base.ball_type as u16;
+
+    if ball == 0 || ball > MAX_POKEBALL_ID {
+        results.push(CheckResult::invalid(CheckIdentifier::Ball, format!("Ball {} does not exist", ball)));
+        return;
+    }
+
+    let name = item_name(ball).unwrap_or_default();
+    if pokemon.is_egg || base.met_level == 0 {
+        if base.ball_type != POKE_BALL {
+            results.push(CheckResult::invalid(CheckIdentifier::Ball, format!("Eggs hatch in a Poké Ball, found {}", name)));
+        } else {
+            results.push(CheckResult::valid(CheckIdentifier::Ball, "Ball is possible for an egg"));
+        }
+        return;
+    }
+
+    let encounters = find_encounters_for(pokemon);
+    if encounters.is_empty() {
+        results.push(CheckResult::valid(CheckIdentifier::Ball, "Ball exists"));
+    } else if encounters.iter().any(|encounter| is_ball_allowed_generation3(base.ball_type, encounter)) {
+        results.push(CheckResult::valid(CheckIdentifier::Ball, "Ball is possible for the encounter"));
+    } else {
+        results.push(CheckResult::invalid(CheckIdentifier::Ball, format!("{} can't be used for {:?} encounters", name, encounters[0].kind)));
+    }
+}
+
+/// Gets the PID/IV methods the games use to generate Pokémon of the encounter
+fn allowed_pidiv_methods(encounter: &Encounter) -> &'static [PIDIVMethod] {
+    // The PID of Poké Spot Pokémon is rolled apart from its IVs
+    if encounter.kind == EncounterKind::Wild(SlotType::PokeSpot) {
+        return &[];
+    }
+
+    if encounter.version.contains(GameVersion::ColosseumXD) {
+        return &[PIDIVMethod::ColosseumXD];
+    }
+
+    match encounter.kind {
+        EncounterKind::Wild(_) => &[PIDIVMethod::H1, PIDIVMethod::H2, PIDIVMethod::H4],
+        EncounterKind::Shadow => &[PIDIVMethod::ColosseumXD],
+        EncounterKind::Event => &[PIDIVMethod::Method1, PIDIVMethod::WishmkrJirachi, PIDIVMethod::ChannelJirachi, PIDIVMethod::ColosseumXD],
+        // The PID of eggs is not generated from the IVs
+        EncounterKind::Egg => &[],
+        EncounterKind::Static | EncounterKind::Roaming | EncounterKind::Gift | EncounterKind::Trade => &[PIDIVMethod::Method1],
+    }
+}
+
+fn check_pidiv(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    // Hatched Pokémon have no correlation between their PID and IVs
+    if pokemon.is_egg || pokemon.base.met_level == 0 {
+        return;
+    }
+
+    // Unknown encounters are reported by the encounter check
+    let encounters = find_encounters_for(pokemon);
+    let Some(first) = encounters.first() else {
+        return;
+    };
+
+    if encounters.iter().any(|encounter| allowed_pidiv_methods(encounter).is_empty()) {
+        return;
+    }
+
+    let matches = find_pidiv_matches_for(pokemon);
+    let found = encounters
+        .iter()
+        .flat_map(|encounter| allowed_pidiv_methods(encounter).iter())
+        .find(|method| matches.iter().any(|found| found.method == **method));
+
+    match found {
+        Some(method) => results.push(CheckResult::valid(CheckIdentifier::PidIv, format!("PID and IVs match {:?}", method))),
+        None => results.push(CheckResult::invalid(CheckIdentifier::PidIv, format!("PID and IVs do not match any method of {:?} encounters", first.kind))),
+    }
+}
+
+fn check_held_item(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let item = pokemon.base.held_item;
+    if item == 0 {
+        return;
+    }
+
+    if item > MAX_ITEM_ID || item_name(item).is_none() {
+        results.push(CheckResult::invalid(CheckIdentifier::HeldItem, format!("Held item {} does not exist", item)));
+    } else if !held_items(pokemon.base.version).contains(&item) {
+        results.push(CheckResult::invalid(CheckIdentifier::HeldItem, format!("{} can't be held", item_name(item).unwrap_or_default())));
+    } else if pokemon.is_egg {
+        results.push(CheckResult::invalid(CheckIdentifier::HeldItem, "Eggs can't hold items"));
+    } else {
+        results.push(CheckResult::valid(CheckIdentifier::HeldItem, "Held item can be held"));
+    }
+}
+
+fn check_effort_values(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+    let total = base.ev_total();
+
+    if total > MAX_EV_TOTAL {
+        results.push(CheckResult::invalid(CheckIdentifier::EffortValues, format!("EV total {} is above {}", total, MAX_EV_TOTAL)));
+    } else if pokemon.is_egg && total != 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::EffortValues, "Eggs can't have EVs"));
+    } else {
+        results.push(CheckResult::valid(CheckIdentifier::EffortValues, format!("EV total {} is valid", total)));
+    }
+}
+
+fn check_ability(pokemon: &Generation3, personal: &PersonalInfo, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+    let ability_bit = base.ability_number & 1;
+
+    if !personal.has_two_abilities() {
+        if ability_bit == 1 {
+            // Allowed when evolved from a species with two abilities
+            results.push(CheckResult::fishy(CheckIdentifier::Ability, "Ability bit is set but the species has only one ability"));
+        } else {
+            results.push(CheckResult::valid(CheckIdentifier::Ability, "Ability is valid"));
+        }
+        return;
+    }
+
+    if ability_bit != (base.personality_id & 1) as u16 {
+        results.push(CheckResult::invalid(CheckIdentifier::Ability, "Ability bit does not match the PID"));
+    } else {
+        results.push(CheckResult::valid(CheckIdentifier::Ability, "Ability matches the PID"));
+    }
+}
+
+fn check_nickname(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    if pokemon.is_egg {
+        if base.nickname != EGG_NAME || base.language != LanguageID::Japanese {
+            results.push(CheckResult::invalid(CheckIdentifier::Nickname, format!("Eggs must be named {} with the Japanese language", EGG_NAME)));
+        } else {
+            results.push(CheckResult::valid(CheckIdentifier::Nickname, "Egg name is valid"));
+        }
+        return;
+    }
+
+    let max_length = if pokemon.is_japanese { 5 } else { 10 };
+    let length = base.nickname.chars().count();
+    if length == 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::Nickname, "Nickname is empty"));
+    } else if length > max_length {
+        results.push(CheckResult::invalid(CheckIdentifier::Nickname, format!("Nickname is longer than {} characters", max_length)));
+    } else {
+        results.push(CheckResult::valid(CheckIdentifier::Nickname, "Nickname is valid"));
+    }
+}
+
+fn check_trainer(pokemon: &Generation3, trainer: Option<&TrainerInfo>, results: &mut Vec<CheckResult>) {
+    let base = &pokemon.base;
+
+    match base.language {
+        LanguageID::Japanese | LanguageID::English | LanguageID::French | LanguageID::Italian | LanguageID::German | LanguageID::Spanish => {},
+        language => {
+            results.push(CheckResult::invalid(CheckIdentifier::Trainer, format!("Language {:?} does not exist in Generation 3", language)));
+            return;
+        }
+    }
+
+    let max_length = if pokemon.is_japanese { 5 } else { 7 };
+    let length = base.ot_info.name.chars().count();
+    if length == 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::Trainer, "OT name is empty"));
+        return;
+    }
+
+    if length > max_length {
+        results.push(CheckResult::invalid(CheckIdentifier::Trainer, format!("OT name is longer than {} characters", max_length)));
+        return;
+    }
+
+    if let Some(trainer) = trainer {
+        let same_id = trainer.public_id == base.ot_info.public_id && trainer.secret_id == base.ot_info.secret_id;
+        if same_id && (trainer.name != base.ot_info.name || trainer.gender != base.ot_info.gender) {
+            results.push(CheckResult::fishy(CheckIdentifier::Trainer, "OT has the IDs of the save trainer but a different name or gender"));
+            return;
+        }
+    }
+
+    results.push(CheckResult::valid(CheckIdentifier::Trainer, "OT is valid"));
+}
+
+fn check_ribbons(pokemon: &Generation3, results: &mut Vec<CheckResult>) {
+    let ribbons = pokemon.ribbons;
+
+    if ribbons & RIBBONS_UNUSED_MASK != 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::Ribbons, "Unused ribbon bits are set"));
+        return;
+    }
+
+    if pokemon.is_egg && ribbons & !RIBBONS_FATEFUL_ENCOUNTER_MASK != 0 {
+        results.push(CheckResult::invalid(CheckIdentifier::Ribbons, "Eggs can't have ribbons"));
+        return;
+    }
+
+    for (index, category) in CONTEST_RIBBON_CATEGORIES.iter().enumerate() {
+        let rank = (ribbons >> (index * 3)) & 0b111;
+        if rank > CONTEST_RIBBON_MAX_RANK {
+            results.push(CheckResult::invalid(CheckIdentifier::Ribbons, format!("{} contest ribbon rank {} does not exist", category, rank)));
+            return;
+        }
+    }
+
+    results.push(CheckResult::valid(CheckIdentifier::Ribbons, "Ribbons are valid"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        legality::{analysis::LegalityAnalysis, check::{CheckIdentifier, CheckSeverity}},
+        pokemon::Generation3,
+        rng::lcrng::{high, POKEMON_LCRNG},
+        trainer::TrainerInfo,
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    /// Level 5 Treecko from Emerald with Method 1 PID and IVs, decrypted
+    fn treecko() -> Vec<u8> {
+        let mut data = vec![0u8; 100];
+        let (pid, ivs) = method1(0x1234_5678);
+        data[0x00..0x04].copy_from_slice(&pid.to_le_bytes());
+        data[0x48..0x4C].copy_from_slice(&ivs.to_le_bytes());
+        data[0x04..0x06].copy_from_slice(&12345u16.to_le_bytes());
+        data[0x06..0x08].copy_from_slice(&54321u16.to_le_bytes());
+        data[0x08..0x12].copy_from_slice(&[0xCE, 0xCC, 0xBF, 0xBF, 0xBD, 0xC5, 0xC9, 0xFF, 0xFF, 0xFF]);
+        data[0x12] = 2;
+        data[0x13] = 0x02;
+        data[0x14..0x1B].copy_from_slice(&[0xBB, 0xCD, 0xC2, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        data[0x20..0x22].copy_from_slice(&277u16.to_le_bytes());
+        data[0x24..0x28].copy_from_slice(&135u32.to_le_bytes());
+        data[0x2C..0x2E].copy_from_slice(&1u16.to_le_bytes());
+        data[0x2E..0x30].copy_from_slice(&43u16.to_le_bytes());
+        data[0x34] = 35;
+        data[0x35] = 30;
+        data[0x45] = 16;
+        data[0x46..0x48].copy_from_slice(&(5u16 | (3 << 7) | (4 << 11)).to_le_bytes());
+        data[0x54] = 5;
+
+        set_checksum(&mut data);
+        data
+    }
+
+    /// Gets the PID and packed IVs generated by Method 1 from the seed
+    fn method1(seed: u32) -> (u32, u32) {
+        let [low, high_pid, iv1, iv2] = [1, 2, 3, 4].map(|count| high(POKEMON_LCRNG.advance(seed, count)) as u32);
+        (low | (high_pid << 16), (iv1 & 0x7FFF) | ((iv2 & 0x7FFF) << 15))
+    }
+
+    fn set_checksum(data: &mut [u8]) {
+        let checksum = get_generatation3_checksum(data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+    }
+
+    #[test]
+    fn valid_pokemon_is_legal() {
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&treecko()), None);
+        assert!(analysis.is_valid(), "{}", analysis.report(true));
+        assert_eq!(analysis.severity(), CheckSeverity::Valid);
+    }
+
+    #[test]
+    fn invalid_values_are_reported() {
+        let mut data = treecko();
+        data[0x30..0x32].copy_from_slice(&1u16.to_le_bytes()); // Duplicated move
+        data[0x38] = 255;
+        data[0x39] = 255;
+        data[0x3A] = 1; // 511 EVs
+        set_checksum(&mut data);
+
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&data), None);
+        assert!(!analysis.is_valid());
+        assert_eq!(analysis.results_for(CheckIdentifier::Moves).next().unwrap().severity, CheckSeverity::Invalid);
+        assert_eq!(analysis.results_for(CheckIdentifier::EffortValues).next().unwrap().severity, CheckSeverity::Invalid);
+    }
+
+    #[test]
+    fn level_must_match_experience() {
+        let mut data = treecko();
+        data[0x54] = 6;
+        set_checksum(&mut data);
+
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&data), None);
+        assert_eq!(analysis.results_for(CheckIdentifier::Level).next().unwrap().severity, CheckSeverity::Invalid);
+    }
+
+    #[test]
+    fn gifts_must_be_in_a_poke_ball() {
+        let mut data = treecko();
+        data[0x46..0x48].copy_from_slice(&(5u16 | (3 << 7) | (1 << 11)).to_le_bytes()); // Master Ball
+        data[0x22..0x24].copy_from_slice(&259u16.to_le_bytes()); // Mach Bike
+        set_checksum(&mut data);
+
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&data), None);
+        assert_eq!(analysis.results_for(CheckIdentifier::Ball).next().unwrap().severity, CheckSeverity::Invalid);
+        assert_eq!(analysis.results_for(CheckIdentifier::HeldItem).next().unwrap().severity, CheckSeverity::Invalid);
+    }
+
+    #[test]
+    fn pidiv_must_match_the_encounter() {
+        let mut data = treecko();
+        data[0x48] ^= 1;
+        set_checksum(&mut data);
+
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&data), None);
+        assert_eq!(analysis.results_for(CheckIdentifier::PidIv).next().unwrap().severity, CheckSeverity::Invalid);
+    }
+
+    #[test]
+    fn machine_moves_depend_on_the_species() {
+        let mut data = treecko();
+        data[0x30..0x32].copy_from_slice(&57u16.to_le_bytes()); // Surf
+        set_checksum(&mut data);
+
+        let analysis = LegalityAnalysis::generation3(&Generation3::new(&data), None);
+        assert!(analysis.results_for(CheckIdentifier::Moves).any(|result| result.severity == CheckSeverity::Invalid));
+    }
+
+    #[test]
+    fn trainer_context() {
+        let pokemon = Generation3::new(&treecko());
+        let trainer = TrainerInfo {
+            public_id: 12345,
+            secret_id: 54321,
+            name: "MAY".to_string(),
+            ..Default::default()
+        };
+
+        let analysis = LegalityAnalysis::generation3(&pokemon, Some(&trainer));
+        assert_eq!(analysis.results_for(CheckIdentifier::Trainer).next().unwrap().severity, CheckSeverity::Fishy);
+        assert!(analysis.is_valid());
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+/// How much a check result affects the legality of a Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CheckSeverity {
+    /// The value was checked and is possible in the games
+    Valid,
+    /// The value is possible but unlikely, or could not be fully verified
+    Fishy,
+    /// The value is not possible in the games
+    Invalid,
+}
+
+/// Area of the Pokémon data a check is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckIdentifier {
+    Species,
+    Checksum,
+    Level,
+    Moves,
+    Met,
+    Ball,
+    HeldItem,
+    PidIv,
+    EffortValues,
+    Ability,
+    Nickname,
+    Trainer,
+    Ribbons,
+    Encounter,
+    Event,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub identifier: CheckIdentifier,
+    pub severity: CheckSeverity,
+    pub message: String,
+}
+
+impl CheckResult {
+    pub fn new(identifier: CheckIdentifier, severity: CheckSeverity, message: impl Into<String>) -> Self {
+        Self { identifier, severity, message: message.into() }
+    }
+
+    pub fn valid(identifier: CheckIdentifier, message: impl Into<String>) -> Self {
+        Self::new(identifier, CheckSeverity::Valid, message)
+    }
+
+    pub fn fishy(identifier: CheckIdentifier, message: impl Into<String>) -> Self {
+        Self::new(identifier, CheckSeverity::Fishy, message)
+    }
+
+    pub fn invalid(identifier: CheckIdentifier, message: impl Into<String>) -> Self {
+        Self::new(identifier, CheckSeverity::Invalid, message)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.severity != CheckSeverity::Invalid
+    }
+}
+
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} {:?}: {}", self.severity, self.identifier, self.message)
+    }
+}
@@ -0,0 +1,116 @@
+pub mod generation3;
+
+use crate::game::enums::{game_version::GameVersion, species_id::SpeciesID};
+
+/// Method used to find a wild Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotType {
+    Grass,
+    Surf,
+    OldRod,
+    GoodRod,
+    SuperRod,
+    RockSmash,
+    /// Poké Spot of XD, where Pokémon are lured with Poké Snacks
+    PokeSpot,
+}
+
+/// How a Pokémon can be obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncounterKind {
+    Wild(SlotType),
+    /// Fixed overworld encounter, like legendaries
+    Static,
+    /// Roaming encounter, can be met at any route of the game
+    Roaming,
+    /// Received from a character, like starters and fossils
+    Gift,
+    /// Snagged from an opponent in Colosseum/XD
+    Shadow,
+    /// Received from an in-game trade
+    Trade,
+    Egg,
+    /// Received from an event distribution
+    Event,
+}
+
+/// Single wild Pokémon that can appear in an area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncounterSlot {
+    pub species: SpeciesID,
+    pub min_level: u8,
+    pub max_level: u8,
+}
+
+impl EncounterSlot {
+    pub const fn new(species: SpeciesID, min_level: u8, max_level: u8) -> Self {
+        Self { species, min_level, max_level }
+    }
+}
+
+/// Wild Pokémon of a location found with the same method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncounterArea {
+    /// Version or group of versions with this area
+    pub version: GameVersion,
+    pub location: u16,
+    pub slot_type: SlotType,
+    pub slots: &'static [EncounterSlot],
+}
+
+/// Way a Pokémon could have been obtained, either stored in a table or expanded from a wild area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encounter {
+    pub kind: EncounterKind,
+    /// Version or group of versions with this encounter
+    pub version: GameVersion,
+    pub species: SpeciesID,
+    /// Met location, `None` when it can be any location of the game
+    pub location: Option<u16>,
+    pub min_level: u8,
+    pub max_level: u8,
+    /// Pokémon is flagged as a fateful encounter
+    pub fateful: bool,
+}
+
+impl Encounter {
+    /// Creates an encounter met at a single level.
+    pub const fn new(kind: EncounterKind, version: GameVersion, species: SpeciesID, location: Option<u16>, level: u8) -> Self {
+        Self {
+            kind,
+            version,
+            species,
+            location,
+            min_level: level,
+            max_level: level,
+            fateful: false,
+        }
+    }
+
+    /// Sets the level range of the encounter
+    pub const fn with_levels(mut self, min_level: u8, max_level: u8) -> Self {
+        self.min_level = min_level;
+        self.max_level = max_level;
+        self
+    }
+
+    /// Flags the encounter as a fateful encounter
+    pub const fn fateful(mut self) -> Self {
+        self.fateful = true;
+        self
+    }
+
+    /// Checks if a Pokémon with the given data could come from this encounter.
+    ///
+    /// # Arguments
+    /// * `species` - Species the Pokémon could be when it was met, itself and its pre-evolutions
+    /// * `met_level` - Met level of the Pokémon
+    /// * `met_location` - Met location of the Pokémon
+    /// * `version` - Origin game of the Pokémon
+    pub fn matches(&self, species: &[SpeciesID], met_level: u8, met_location: u16, version: GameVersion) -> bool {
+        species.contains(&self.species)
+            && self.version.contains(version)
+            && self.location.is_none_or(|location| location == met_location)
+            && (self.min_level..=self.max_level).contains(&met_level)
+    }
+}
@@ -0,0 +1,2061 @@
+//! Encounters of Ruby/Sapphire/Emerald, FireRed/LeafGreen and Colosseum/XD.
+//!
+//! The met locations are the map section values stored by the Game Boy Advance games, the
+//! GameCube games store their own location values instead.
+
+use crate::{
+    game::enums::{
+        game_version::GameVersion,
+        location::{FATEFUL_ENCOUNTER_GENERATION3, LINK_TRADE_GENERATION3_NPC},
+        species_id::SpeciesID,
+    },
+    pokemon::Generation3,
+    species::evolution::{evolution_chain, generation3::EVOLUTIONS},
+};
+
+use super::{Encounter, EncounterArea, EncounterKind, EncounterSlot, SlotType};
+
+// region: Locations
+const DEWFORD_TOWN: u16 = 2;
+const PACIFIDLOG_TOWN: u16 = 6;
+const PETALBURG_CITY: u16 = 7;
+const SLATEPORT_CITY: u16 = 8;
+const RUSTBORO_CITY: u16 = 10;
+const LILYCOVE_CITY: u16 = 12;
+const MOSSDEEP_CITY: u16 = 13;
+const SOOTOPOLIS_CITY: u16 = 14;
+const EVER_GRANDE_CITY: u16 = 15;
+const ROUTE101: u16 = 16;
+const ROUTE102: u16 = 17;
+const ROUTE103: u16 = 18;
+const ROUTE104: u16 = 19;
+const ROUTE105: u16 = 20;
+const ROUTE106: u16 = 21;
+const ROUTE107: u16 = 22;
+const ROUTE108: u16 = 23;
+const ROUTE109: u16 = 24;
+const ROUTE110: u16 = 25;
+const ROUTE111: u16 = 26;
+const ROUTE112: u16 = 27;
+const ROUTE113: u16 = 28;
+const ROUTE114: u16 = 29;
+const ROUTE115: u16 = 30;
+const ROUTE116: u16 = 31;
+const ROUTE117: u16 = 32;
+const ROUTE118: u16 = 33;
+const ROUTE119: u16 = 34;
+const ROUTE120: u16 = 35;
+const ROUTE121: u16 = 36;
+const ROUTE122: u16 = 37;
+const ROUTE123: u16 = 38;
+const ROUTE124: u16 = 39;
+const ROUTE125: u16 = 40;
+const ROUTE126: u16 = 41;
+const ROUTE127: u16 = 42;
+const ROUTE128: u16 = 43;
+const ROUTE129: u16 = 44;
+const ROUTE130: u16 = 45;
+const ROUTE131: u16 = 46;
+const ROUTE132: u16 = 47;
+const ROUTE133: u16 = 48;
+const ROUTE134: u16 = 49;
+const UNDERWATER_ROUTE124: u16 = 50;
+const UNDERWATER_ROUTE126: u16 = 51;
+const UNDERWATER_ROUTE127: u16 = 52;
+const UNDERWATER_ROUTE128: u16 = 53;
+const GRANITE_CAVE: u16 = 55;
+const SAFARI_ZONE: u16 = 57;
+const BATTLE_FRONTIER: u16 = 58;
+const PETALBURG_WOODS: u16 = 59;
+const RUSTURF_TUNNEL: u16 = 60;
+const ABANDONED_SHIP: u16 = 61;
+const NEW_MAUVILLE: u16 = 62;
+const METEOR_FALLS: u16 = 63;
+const MT_PYRE: u16 = 65;
+const SHOAL_CAVE: u16 = 67;
+const SEAFLOOR_CAVERN: u16 = 68;
+const VICTORY_ROAD: u16 = 70;
+const CAVE_OF_ORIGIN: u16 = 72;
+pub const SOUTHERN_ISLAND: u16 = 73;
+const FIERY_PATH: u16 = 74;
+const JAGGED_PASS: u16 = 76;
+const ISLAND_CAVE: u16 = 81;
+const DESERT_RUINS: u16 = 82;
+const ANCIENT_TOMB: u16 = 83;
+const SKY_PILLAR: u16 = 85;
+
+const PALLET_TOWN: u16 = 88;
+const VIRIDIAN_CITY: u16 = 89;
+const CERULEAN_CITY: u16 = 91;
+const VERMILION_CITY: u16 = 93;
+const CELADON_CITY: u16 = 94;
+const FUCHSIA_CITY: u16 = 95;
+const CINNABAR_ISLAND: u16 = 96;
+const SAFFRON_CITY: u16 = 98;
+const ROUTE4_POKEMON_CENTER: u16 = 99;
+const ROUTE1: u16 = 101;
+const ROUTE2: u16 = 102;
+const ROUTE3: u16 = 103;
+const ROUTE4: u16 = 104;
+const ROUTE5: u16 = 105;
+const ROUTE6: u16 = 106;
+const ROUTE7: u16 = 107;
+const ROUTE8: u16 = 108;
+const ROUTE9: u16 = 109;
+const ROUTE10: u16 = 110;
+const ROUTE11: u16 = 111;
+const ROUTE12: u16 = 112;
+const ROUTE13: u16 = 113;
+const ROUTE14: u16 = 114;
+const ROUTE15: u16 = 115;
+const ROUTE16: u16 = 116;
+const ROUTE17: u16 = 117;
+const ROUTE18: u16 = 118;
+const ROUTE19: u16 = 119;
+const ROUTE20: u16 = 120;
+const ROUTE21: u16 = 121;
+const ROUTE22: u16 = 122;
+const ROUTE23: u16 = 123;
+const ROUTE24: u16 = 124;
+const ROUTE25: u16 = 125;
+const VIRIDIAN_FOREST: u16 = 126;
+const MT_MOON: u16 = 127;
+const DIGLETTS_CAVE: u16 = 131;
+const VICTORY_ROAD_KANTO: u16 = 132;
+const SILPH_CO: u16 = 134;
+const POKEMON_MANSION: u16 = 135;
+const SAFARI_ZONE_KANTO: u16 = 136;
+const ROCK_TUNNEL: u16 = 138;
+const SEAFOAM_ISLANDS: u16 = 139;
+const POKEMON_TOWER: u16 = 140;
+const CERULEAN_CAVE: u16 = 141;
+const POWER_PLANT: u16 = 142;
+const KINDLE_ROAD: u16 = 150;
+const TREASURE_BEACH: u16 = 151;
+const CAPE_BRINK: u16 = 152;
+const BOND_BRIDGE: u16 = 153;
+const WATER_LABYRINTH: u16 = 160;
+const FIVE_ISLE_MEADOW: u16 = 161;
+const MEMORIAL_PILLAR: u16 = 162;
+const OUTCAST_ISLAND: u16 = 163;
+const GREEN_PATH: u16 = 164;
+const WATER_PATH: u16 = 165;
+const RUIN_VALLEY: u16 = 166;
+const SEVAULT_CANYON: u16 = 169;
+const TANOBY_RUINS: u16 = 170;
+pub const NAVEL_ROCK_FIRE_RED_LEAF_GREEN: u16 = 174;
+const MT_EMBER: u16 = 175;
+const BERRY_FOREST: u16 = 176;
+const ICEFALL_CAVE: u16 = 177;
+const LOST_CAVE: u16 = 181;
+const PATTERN_BUSH: u16 = 182;
+const ALTERING_CAVE_FIRE_RED_LEAF_GREEN: u16 = 183;
+const TANOBY_CHAMBERS: u16 = 184;
+pub const BIRTH_ISLAND_FIRE_RED_LEAF_GREEN: u16 = 187;
+
+const MAGMA_HIDEOUT: u16 = 198;
+const MIRAGE_TOWER: u16 = 199;
+pub const BIRTH_ISLAND_EMERALD: u16 = 200;
+pub const FARAWAY_ISLAND: u16 = 201;
+const ARTISAN_CAVE: u16 = 202;
+const MARINE_CAVE: u16 = 203;
+const UNDERWATER_MARINE_CAVE: u16 = 204;
+const TERRA_CAVE: u16 = 205;
+const DESERT_UNDERPASS: u16 = 209;
+const ALTERING_CAVE_EMERALD: u16 = 210;
+pub const NAVEL_ROCK_EMERALD: u16 = 211;
+
+// Colosseum and XD share the value of the gifts received from characters
+const GAMECUBE_GIFT: u16 = 254;
+// Colosseum
+const PHENAC_CITY: u16 = 8;
+const PYRITE_TOWN: u16 = 15;
+const THE_UNDER: u16 = 47;
+const MT_BATTLE: u16 = 55;
+const REALGAM_TOWER: u16 = 67;
+// XD
+const PYRITE_TOWN_XD: u16 = 30;
+const POKEMON_HQ_LAB: u16 = 68;
+const GATEON_PORT: u16 = 73;
+const POKE_SPOT_ROCK: u16 = 90;
+const POKE_SPOT_OASIS: u16 = 91;
+const POKE_SPOT_CAVE: u16 = 92;
+const CITADARK_ISLE: u16 = 153;
+// endregion
+
+// region: Wild
+const fn area(version: GameVersion, location: u16, slot_type: SlotType, slots: &'static [EncounterSlot]) -> EncounterArea {
+    EncounterArea { version, location, slot_type, slots }
+}
+
+const HOENN_OLD_ROD_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 5, 10),
+    EncounterSlot::new(SpeciesID::Tentacool, 5, 10),
+];
+
+const HOENN_GOOD_ROD_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+    EncounterSlot::new(SpeciesID::Tentacool, 10, 30),
+    EncounterSlot::new(SpeciesID::Wailmer, 10, 30),
+];
+
+const HOENN_SUPER_ROD_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+    EncounterSlot::new(SpeciesID::Tentacool, 20, 45),
+];
+
+const HOENN_SUPER_ROD_SHARPEDO: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+    EncounterSlot::new(SpeciesID::Sharpedo, 30, 35),
+];
+
+const HOENN_SURF_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+    EncounterSlot::new(SpeciesID::Wingull, 10, 30),
+    EncounterSlot::new(SpeciesID::Pelipper, 25, 30),
+];
+
+const HOENN_OLD_ROD_FRESH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 5, 10),
+    EncounterSlot::new(SpeciesID::Goldeen, 5, 10),
+];
+
+const HOENN_GOOD_ROD_FRESH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+    EncounterSlot::new(SpeciesID::Goldeen, 10, 30),
+];
+
+const HOENN_GOOD_ROD_CORPHISH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+    EncounterSlot::new(SpeciesID::Goldeen, 10, 30),
+    EncounterSlot::new(SpeciesID::Corphish, 10, 30),
+];
+
+const HOENN_SUPER_ROD_CORPHISH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Corphish, 20, 45),
+];
+
+const HOENN_SUPER_ROD_BARBOACH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Barboach, 20, 45),
+    EncounterSlot::new(SpeciesID::Goldeen, 20, 45),
+];
+
+const HOENN_SURF_FRESH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Marill, 5, 35),
+    EncounterSlot::new(SpeciesID::Surskit, 20, 30),
+];
+
+const HOENN_UNDERWATER: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Clamperl, 20, 35),
+    EncounterSlot::new(SpeciesID::Chinchou, 20, 30),
+    EncounterSlot::new(SpeciesID::Relicanth, 25, 30),
+];
+
+const HOENN_ROCK_SMASH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Geodude, 5, 20),
+    EncounterSlot::new(SpeciesID::Nosepass, 10, 20),
+];
+
+const KANTO_OLD_ROD: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 5, 5),
+];
+
+const KANTO_GOOD_ROD: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Magikarp, 5, 15),
+    EncounterSlot::new(SpeciesID::Poliwag, 5, 15),
+    EncounterSlot::new(SpeciesID::Goldeen, 5, 15),
+];
+
+const KANTO_SUPER_ROD_FRESH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Poliwag, 15, 25),
+    EncounterSlot::new(SpeciesID::Goldeen, 15, 25),
+    EncounterSlot::new(SpeciesID::Magikarp, 15, 25),
+    EncounterSlot::new(SpeciesID::Poliwhirl, 20, 30),
+    EncounterSlot::new(SpeciesID::Seaking, 20, 30),
+];
+
+const KANTO_SUPER_ROD_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Horsea, 15, 25),
+    EncounterSlot::new(SpeciesID::Krabby, 15, 25),
+    EncounterSlot::new(SpeciesID::Gyarados, 15, 25),
+    EncounterSlot::new(SpeciesID::Seadra, 25, 30),
+    EncounterSlot::new(SpeciesID::Kingler, 25, 30),
+];
+
+const KANTO_SURF_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Tentacool, 5, 40),
+    EncounterSlot::new(SpeciesID::Tentacruel, 35, 40),
+];
+
+const SEVII_SUPER_ROD_SEA: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Horsea, 25, 35),
+    EncounterSlot::new(SpeciesID::Krabby, 25, 35),
+    EncounterSlot::new(SpeciesID::Gyarados, 25, 35),
+    EncounterSlot::new(SpeciesID::Seadra, 30, 40),
+    EncounterSlot::new(SpeciesID::Kingler, 30, 40),
+    EncounterSlot::new(SpeciesID::Qwilfish, 25, 35),
+];
+
+const KANTO_ROCK_SMASH: &[EncounterSlot] = &[
+    EncounterSlot::new(SpeciesID::Geodude, 5, 30),
+    EncounterSlot::new(SpeciesID::Graveler, 25, 40),
+];
+
+pub const WILD_AREAS: &[EncounterArea] = &[
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE101, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Wurmple, 2, 3),
+        EncounterSlot::new(SpeciesID::Poochyena, 2, 3),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 2, 3),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE102, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 3, 4),
+        EncounterSlot::new(SpeciesID::Wurmple, 3, 4),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 3, 4),
+        EncounterSlot::new(SpeciesID::Ralts, 4, 4),
+        EncounterSlot::new(SpeciesID::Surskit, 3, 3),
+    ]),
+    area(GameVersion::Ruby, ROUTE102, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Lotad, 3, 4),
+    ]),
+    area(GameVersion::Sapphire, ROUTE102, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seedot, 3, 4),
+    ]),
+    area(GameVersion::Emerald, ROUTE102, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Lotad, 3, 4),
+        EncounterSlot::new(SpeciesID::Seedot, 3, 4),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE102, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE102, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE102, SlotType::GoodRod, HOENN_GOOD_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE102, SlotType::SuperRod, HOENN_SUPER_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE103, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 2, 4),
+        EncounterSlot::new(SpeciesID::Wingull, 2, 4),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 2, 4),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE103, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE103, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE103, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE103, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE104, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 4, 5),
+        EncounterSlot::new(SpeciesID::Wurmple, 4, 5),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 4, 5),
+        EncounterSlot::new(SpeciesID::Taillow, 4, 5),
+        EncounterSlot::new(SpeciesID::Wingull, 3, 5),
+    ]),
+    area(GameVersion::GroupRubySapphire, ROUTE104, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Marill, 4, 5),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE104, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE104, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE104, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE104, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE105, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE105, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE105, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE105, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE106, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE106, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE106, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE106, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE107, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE107, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE107, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE107, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE108, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE108, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE108, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE108, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE109, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE109, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE109, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE109, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE110, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 12, 13),
+        EncounterSlot::new(SpeciesID::Electrike, 12, 13),
+        EncounterSlot::new(SpeciesID::Gulpin, 12, 13),
+        EncounterSlot::new(SpeciesID::Minun, 13, 13),
+        EncounterSlot::new(SpeciesID::Plusle, 13, 13),
+        EncounterSlot::new(SpeciesID::Oddish, 13, 13),
+        EncounterSlot::new(SpeciesID::Wingull, 12, 12),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 12, 13),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE110, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE110, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE110, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE110, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 19, 21),
+        EncounterSlot::new(SpeciesID::Trapinch, 19, 21),
+        EncounterSlot::new(SpeciesID::Baltoy, 19, 21),
+        EncounterSlot::new(SpeciesID::Cacnea, 19, 22),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::GoodRod, HOENN_GOOD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::SuperRod, HOENN_SUPER_ROD_BARBOACH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE111, SlotType::RockSmash, HOENN_ROCK_SMASH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE112, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Numel, 14, 16),
+    ]),
+    area(GameVersion::GroupRubySapphire, ROUTE112, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Marill, 14, 15),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE113, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spinda, 14, 16),
+        EncounterSlot::new(SpeciesID::Slugma, 14, 16),
+        EncounterSlot::new(SpeciesID::Skarmory, 16, 16),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Swablu, 16, 18),
+        EncounterSlot::new(SpeciesID::Lombre, 18, 18),
+        EncounterSlot::new(SpeciesID::Nuzleaf, 18, 18),
+    ]),
+    area(GameVersion::Ruby, ROUTE114, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Lotad, 16, 18),
+        EncounterSlot::new(SpeciesID::Seviper, 16, 18),
+    ]),
+    area(GameVersion::Sapphire, ROUTE114, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seedot, 16, 18),
+        EncounterSlot::new(SpeciesID::Zangoose, 16, 18),
+    ]),
+    area(GameVersion::Emerald, ROUTE114, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Lotad, 16, 18),
+        EncounterSlot::new(SpeciesID::Seedot, 16, 18),
+        EncounterSlot::new(SpeciesID::Seviper, 16, 18),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::GoodRod, HOENN_GOOD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::SuperRod, HOENN_SUPER_ROD_BARBOACH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE114, SlotType::RockSmash, HOENN_ROCK_SMASH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE115, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Swablu, 23, 25),
+        EncounterSlot::new(SpeciesID::Taillow, 23, 25),
+        EncounterSlot::new(SpeciesID::Swellow, 25, 25),
+        EncounterSlot::new(SpeciesID::Wingull, 24, 25),
+        EncounterSlot::new(SpeciesID::Jigglypuff, 23, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE115, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE115, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE115, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE115, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE116, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 6, 8),
+        EncounterSlot::new(SpeciesID::Taillow, 6, 8),
+        EncounterSlot::new(SpeciesID::Whismur, 6, 8),
+        EncounterSlot::new(SpeciesID::Nincada, 6, 8),
+        EncounterSlot::new(SpeciesID::Abra, 7, 8),
+    ]),
+    area(GameVersion::GroupRubySapphire, ROUTE116, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 6, 8),
+    ]),
+    area(GameVersion::Emerald, ROUTE116, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Skitty, 8, 8),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE117, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 13, 14),
+        EncounterSlot::new(SpeciesID::Linoone, 14, 14),
+        EncounterSlot::new(SpeciesID::Oddish, 13, 14),
+        EncounterSlot::new(SpeciesID::Marill, 13, 14),
+        EncounterSlot::new(SpeciesID::Roselia, 13, 14),
+        EncounterSlot::new(SpeciesID::Volbeat, 13, 13),
+        EncounterSlot::new(SpeciesID::Illumise, 13, 13),
+        EncounterSlot::new(SpeciesID::Poochyena, 13, 14),
+    ]),
+    area(GameVersion::Sapphire, ROUTE117, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seedot, 13, 13),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE117, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE117, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE117, SlotType::GoodRod, HOENN_GOOD_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE117, SlotType::SuperRod, HOENN_SUPER_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE118, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 24, 26),
+        EncounterSlot::new(SpeciesID::Linoone, 26, 26),
+        EncounterSlot::new(SpeciesID::Electrike, 24, 26),
+        EncounterSlot::new(SpeciesID::Manectric, 26, 26),
+        EncounterSlot::new(SpeciesID::Wingull, 25, 26),
+        EncounterSlot::new(SpeciesID::Kecleon, 25, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE118, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE118, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE118, SlotType::GoodRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+        EncounterSlot::new(SpeciesID::Tentacool, 10, 30),
+        EncounterSlot::new(SpeciesID::Carvanha, 10, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE118, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE119, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 25, 27),
+        EncounterSlot::new(SpeciesID::Linoone, 27, 27),
+        EncounterSlot::new(SpeciesID::Oddish, 25, 27),
+        EncounterSlot::new(SpeciesID::Tropius, 27, 27),
+        EncounterSlot::new(SpeciesID::Kecleon, 25, 27),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE119, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Wingull, 10, 30),
+        EncounterSlot::new(SpeciesID::Pelipper, 25, 30),
+        EncounterSlot::new(SpeciesID::Marill, 5, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE119, SlotType::OldRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 5, 10),
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 10),
+        EncounterSlot::new(SpeciesID::Feebas, 20, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE119, SlotType::GoodRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+        EncounterSlot::new(SpeciesID::Tentacool, 10, 30),
+        EncounterSlot::new(SpeciesID::Carvanha, 10, 30),
+        EncounterSlot::new(SpeciesID::Feebas, 20, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE119, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Carvanha, 20, 45),
+        EncounterSlot::new(SpeciesID::Feebas, 20, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE120, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 25, 27),
+        EncounterSlot::new(SpeciesID::Linoone, 27, 27),
+        EncounterSlot::new(SpeciesID::Oddish, 25, 27),
+        EncounterSlot::new(SpeciesID::Gloom, 27, 27),
+        EncounterSlot::new(SpeciesID::Marill, 25, 27),
+        EncounterSlot::new(SpeciesID::Absol, 25, 27),
+        EncounterSlot::new(SpeciesID::Kecleon, 25, 25),
+    ]),
+    area(GameVersion::Sapphire, ROUTE120, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seedot, 25, 25),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE120, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE120, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE120, SlotType::GoodRod, HOENN_GOOD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE120, SlotType::SuperRod, HOENN_SUPER_ROD_BARBOACH),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE121, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 26, 28),
+        EncounterSlot::new(SpeciesID::Linoone, 26, 28),
+        EncounterSlot::new(SpeciesID::Oddish, 26, 28),
+        EncounterSlot::new(SpeciesID::Gloom, 28, 28),
+        EncounterSlot::new(SpeciesID::Wingull, 26, 28),
+        EncounterSlot::new(SpeciesID::Kecleon, 25, 25),
+    ]),
+    area(GameVersion::Ruby, ROUTE121, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Shuppet, 26, 28),
+    ]),
+    area(GameVersion::Sapphire, ROUTE121, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Duskull, 26, 28),
+    ]),
+    area(GameVersion::Emerald, ROUTE121, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Shuppet, 26, 28),
+        EncounterSlot::new(SpeciesID::Duskull, 26, 28),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE121, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE121, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE121, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE121, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE122, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE122, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE122, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE122, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE123, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zigzagoon, 26, 28),
+        EncounterSlot::new(SpeciesID::Linoone, 26, 28),
+        EncounterSlot::new(SpeciesID::Oddish, 26, 28),
+        EncounterSlot::new(SpeciesID::Gloom, 28, 28),
+        EncounterSlot::new(SpeciesID::Wingull, 26, 28),
+        EncounterSlot::new(SpeciesID::Kecleon, 25, 25),
+    ]),
+    area(GameVersion::Ruby, ROUTE123, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Shuppet, 26, 28),
+    ]),
+    area(GameVersion::Sapphire, ROUTE123, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Duskull, 26, 28),
+    ]),
+    area(GameVersion::Emerald, ROUTE123, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Shuppet, 26, 28),
+        EncounterSlot::new(SpeciesID::Duskull, 26, 28),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE123, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE123, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE123, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE123, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE124, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE124, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE124, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE124, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE125, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE125, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE125, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE125, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE126, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE126, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE126, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE126, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE127, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE127, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE127, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE127, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE128, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE128, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE128, SlotType::GoodRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+        EncounterSlot::new(SpeciesID::Tentacool, 10, 30),
+        EncounterSlot::new(SpeciesID::Luvdisc, 10, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE128, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Luvdisc, 20, 30),
+        EncounterSlot::new(SpeciesID::Corsola, 20, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE129, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Wingull, 10, 30),
+        EncounterSlot::new(SpeciesID::Pelipper, 25, 30),
+        EncounterSlot::new(SpeciesID::Wailord, 35, 40),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE129, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE129, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE129, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE130, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Wynaut, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE130, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE130, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE130, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE130, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE131, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE131, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE131, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE131, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE132, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE132, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE132, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE132, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Sharpedo, 30, 35),
+        EncounterSlot::new(SpeciesID::Horsea, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE133, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE133, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE133, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE133, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Sharpedo, 30, 35),
+        EncounterSlot::new(SpeciesID::Horsea, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE134, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE134, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE134, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ROUTE134, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Sharpedo, 30, 35),
+        EncounterSlot::new(SpeciesID::Horsea, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, UNDERWATER_ROUTE124, SlotType::Surf, HOENN_UNDERWATER),
+    area(GameVersion::GroupRubySapphireEmerald, UNDERWATER_ROUTE126, SlotType::Surf, HOENN_UNDERWATER),
+    area(GameVersion::GroupRubySapphireEmerald, UNDERWATER_ROUTE127, SlotType::Surf, HOENN_UNDERWATER),
+    area(GameVersion::GroupRubySapphireEmerald, UNDERWATER_ROUTE128, SlotType::Surf, HOENN_UNDERWATER),
+    area(GameVersion::GroupRubySapphireEmerald, PETALBURG_CITY, SlotType::Surf, HOENN_SURF_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, PETALBURG_CITY, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, PETALBURG_CITY, SlotType::GoodRod, HOENN_GOOD_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, PETALBURG_CITY, SlotType::SuperRod, HOENN_SUPER_ROD_CORPHISH),
+    area(GameVersion::GroupRubySapphireEmerald, SLATEPORT_CITY, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SLATEPORT_CITY, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SLATEPORT_CITY, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SLATEPORT_CITY, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, LILYCOVE_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Wingull, 10, 30),
+        EncounterSlot::new(SpeciesID::Pelipper, 25, 30),
+        EncounterSlot::new(SpeciesID::Staryu, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, LILYCOVE_CITY, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, LILYCOVE_CITY, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, LILYCOVE_CITY, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Staryu, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, MOSSDEEP_CITY, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, MOSSDEEP_CITY, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, MOSSDEEP_CITY, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, MOSSDEEP_CITY, SlotType::SuperRod, HOENN_SUPER_ROD_SHARPEDO),
+    area(GameVersion::GroupRubySapphireEmerald, SOOTOPOLIS_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 5, 35),
+        EncounterSlot::new(SpeciesID::Gyarados, 5, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SOOTOPOLIS_CITY, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SOOTOPOLIS_CITY, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SOOTOPOLIS_CITY, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Gyarados, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, EVER_GRANDE_CITY, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, EVER_GRANDE_CITY, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, EVER_GRANDE_CITY, SlotType::GoodRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+        EncounterSlot::new(SpeciesID::Tentacool, 10, 30),
+        EncounterSlot::new(SpeciesID::Luvdisc, 10, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, EVER_GRANDE_CITY, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Luvdisc, 20, 30),
+        EncounterSlot::new(SpeciesID::Corsola, 20, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, PACIFIDLOG_TOWN, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, PACIFIDLOG_TOWN, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, PACIFIDLOG_TOWN, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, PACIFIDLOG_TOWN, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, DEWFORD_TOWN, SlotType::Surf, HOENN_SURF_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, DEWFORD_TOWN, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, DEWFORD_TOWN, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, DEWFORD_TOWN, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, PETALBURG_WOODS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Poochyena, 5, 6),
+        EncounterSlot::new(SpeciesID::Wurmple, 5, 6),
+        EncounterSlot::new(SpeciesID::Zigzagoon, 5, 6),
+        EncounterSlot::new(SpeciesID::Silcoon, 5, 5),
+        EncounterSlot::new(SpeciesID::Cascoon, 5, 5),
+        EncounterSlot::new(SpeciesID::Shroomish, 5, 6),
+        EncounterSlot::new(SpeciesID::Taillow, 5, 6),
+        EncounterSlot::new(SpeciesID::Slakoth, 5, 6),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, GRANITE_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 6, 10),
+        EncounterSlot::new(SpeciesID::Makuhita, 6, 10),
+        EncounterSlot::new(SpeciesID::Geodude, 6, 10),
+        EncounterSlot::new(SpeciesID::Abra, 6, 10),
+        EncounterSlot::new(SpeciesID::Aron, 6, 10),
+        EncounterSlot::new(SpeciesID::Sableye, 6, 10),
+        EncounterSlot::new(SpeciesID::Mawile, 6, 10),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, GRANITE_CAVE, SlotType::RockSmash, HOENN_ROCK_SMASH),
+    area(GameVersion::GroupRubySapphireEmerald, RUSTURF_TUNNEL, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Whismur, 5, 8),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, FIERY_PATH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Numel, 14, 16),
+        EncounterSlot::new(SpeciesID::Grimer, 15, 16),
+        EncounterSlot::new(SpeciesID::Koffing, 15, 16),
+        EncounterSlot::new(SpeciesID::Slugma, 15, 16),
+        EncounterSlot::new(SpeciesID::Torkoal, 15, 16),
+        EncounterSlot::new(SpeciesID::Machop, 15, 16),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, JAGGED_PASS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Numel, 20, 22),
+        EncounterSlot::new(SpeciesID::Spoink, 20, 22),
+    ]),
+    area(GameVersion::Emerald, JAGGED_PASS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Machop, 21, 22),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, METEOR_FALLS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 14, 16),
+        EncounterSlot::new(SpeciesID::Golbat, 33, 35),
+        EncounterSlot::new(SpeciesID::Bagon, 25, 35),
+    ]),
+    area(GameVersion::Ruby, METEOR_FALLS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Solrock, 14, 16),
+    ]),
+    area(GameVersion::Sapphire, METEOR_FALLS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Lunatone, 14, 16),
+    ]),
+    area(GameVersion::Emerald, METEOR_FALLS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Solrock, 14, 16),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, METEOR_FALLS, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Zubat, 5, 35),
+        EncounterSlot::new(SpeciesID::Golbat, 30, 35),
+        EncounterSlot::new(SpeciesID::Solrock, 5, 35),
+        EncounterSlot::new(SpeciesID::Lunatone, 5, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, METEOR_FALLS, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, METEOR_FALLS, SlotType::GoodRod, HOENN_GOOD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, METEOR_FALLS, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Barboach, 20, 45),
+        EncounterSlot::new(SpeciesID::Whiscash, 30, 45),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, MT_PYRE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Vulpix, 25, 29),
+        EncounterSlot::new(SpeciesID::Wingull, 25, 29),
+        EncounterSlot::new(SpeciesID::Shuppet, 22, 29),
+        EncounterSlot::new(SpeciesID::Duskull, 22, 29),
+        EncounterSlot::new(SpeciesID::Chimecho, 28, 30),
+    ]),
+    area(GameVersion::Emerald, MT_PYRE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Meditite, 27, 29),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SHOAL_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 26, 30),
+        EncounterSlot::new(SpeciesID::Golbat, 32, 32),
+        EncounterSlot::new(SpeciesID::Spheal, 26, 32),
+        EncounterSlot::new(SpeciesID::Snorunt, 26, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SHOAL_CAVE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Zubat, 5, 35),
+        EncounterSlot::new(SpeciesID::Spheal, 25, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SHOAL_CAVE, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SHOAL_CAVE, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SHOAL_CAVE, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SEAFLOOR_CAVERN, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 30, 35),
+        EncounterSlot::new(SpeciesID::Golbat, 33, 36),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SEAFLOOR_CAVERN, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Zubat, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SEAFLOOR_CAVERN, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SEAFLOOR_CAVERN, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, SEAFLOOR_CAVERN, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Wailmer, 20, 45),
+        EncounterSlot::new(SpeciesID::Gyarados, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, CAVE_OF_ORIGIN, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 30, 35),
+        EncounterSlot::new(SpeciesID::Golbat, 31, 40),
+        EncounterSlot::new(SpeciesID::Sableye, 30, 35),
+        EncounterSlot::new(SpeciesID::Mawile, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, VICTORY_ROAD, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 36, 38),
+        EncounterSlot::new(SpeciesID::Golbat, 38, 40),
+        EncounterSlot::new(SpeciesID::Whismur, 36, 36),
+        EncounterSlot::new(SpeciesID::Loudred, 38, 40),
+        EncounterSlot::new(SpeciesID::Makuhita, 36, 36),
+        EncounterSlot::new(SpeciesID::Hariyama, 38, 40),
+        EncounterSlot::new(SpeciesID::Aron, 36, 36),
+        EncounterSlot::new(SpeciesID::Lairon, 38, 40),
+        EncounterSlot::new(SpeciesID::Sableye, 38, 40),
+        EncounterSlot::new(SpeciesID::Mawile, 38, 40),
+        EncounterSlot::new(SpeciesID::Meditite, 38, 38),
+        EncounterSlot::new(SpeciesID::Medicham, 40, 40),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, VICTORY_ROAD, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Golbat, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, VICTORY_ROAD, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, VICTORY_ROAD, SlotType::GoodRod, HOENN_GOOD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, VICTORY_ROAD, SlotType::SuperRod, HOENN_SUPER_ROD_BARBOACH),
+    area(GameVersion::GroupRubySapphireEmerald, SKY_PILLAR, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Golbat, 33, 39),
+        EncounterSlot::new(SpeciesID::Sableye, 34, 38),
+        EncounterSlot::new(SpeciesID::Mawile, 34, 38),
+        EncounterSlot::new(SpeciesID::Claydol, 34, 38),
+        EncounterSlot::new(SpeciesID::Banette, 37, 39),
+        EncounterSlot::new(SpeciesID::Dusclops, 37, 39),
+        EncounterSlot::new(SpeciesID::Altaria, 39, 39),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ABANDONED_SHIP, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+        EncounterSlot::new(SpeciesID::Tentacruel, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, ABANDONED_SHIP, SlotType::OldRod, HOENN_OLD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ABANDONED_SHIP, SlotType::GoodRod, HOENN_GOOD_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, ABANDONED_SHIP, SlotType::SuperRod, HOENN_SUPER_ROD_SEA),
+    area(GameVersion::GroupRubySapphireEmerald, NEW_MAUVILLE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Voltorb, 22, 26),
+        EncounterSlot::new(SpeciesID::Magnemite, 22, 26),
+    ]),
+    area(GameVersion::Emerald, NEW_MAUVILLE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Electrode, 24, 26),
+        EncounterSlot::new(SpeciesID::Magneton, 24, 26),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 25, 27),
+        EncounterSlot::new(SpeciesID::Gloom, 25, 29),
+        EncounterSlot::new(SpeciesID::Doduo, 25, 27),
+        EncounterSlot::new(SpeciesID::Natu, 25, 29),
+        EncounterSlot::new(SpeciesID::Girafarig, 27, 29),
+        EncounterSlot::new(SpeciesID::Wobbuffet, 27, 29),
+        EncounterSlot::new(SpeciesID::Pikachu, 25, 27),
+        EncounterSlot::new(SpeciesID::Heracross, 27, 29),
+        EncounterSlot::new(SpeciesID::Rhyhorn, 27, 29),
+        EncounterSlot::new(SpeciesID::Phanpy, 27, 29),
+        EncounterSlot::new(SpeciesID::Pinsir, 27, 27),
+        EncounterSlot::new(SpeciesID::Xatu, 29, 29),
+        EncounterSlot::new(SpeciesID::Dodrio, 29, 29),
+    ]),
+    area(GameVersion::Emerald, SAFARI_ZONE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Hoothoot, 33, 35),
+        EncounterSlot::new(SpeciesID::Spinarak, 33, 35),
+        EncounterSlot::new(SpeciesID::Ledyba, 33, 35),
+        EncounterSlot::new(SpeciesID::Sunkern, 33, 35),
+        EncounterSlot::new(SpeciesID::Mareep, 33, 35),
+        EncounterSlot::new(SpeciesID::Aipom, 33, 35),
+        EncounterSlot::new(SpeciesID::Snubbull, 33, 35),
+        EncounterSlot::new(SpeciesID::Gligar, 33, 35),
+        EncounterSlot::new(SpeciesID::Teddiursa, 33, 35),
+        EncounterSlot::new(SpeciesID::Stantler, 33, 35),
+        EncounterSlot::new(SpeciesID::Houndour, 33, 35),
+        EncounterSlot::new(SpeciesID::Miltank, 33, 35),
+        EncounterSlot::new(SpeciesID::Shuckle, 33, 35),
+        EncounterSlot::new(SpeciesID::Pineco, 33, 35),
+        EncounterSlot::new(SpeciesID::Wooper, 33, 35),
+        EncounterSlot::new(SpeciesID::Quagsire, 33, 35),
+        EncounterSlot::new(SpeciesID::Remoraid, 33, 35),
+        EncounterSlot::new(SpeciesID::Octillery, 33, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 30),
+        EncounterSlot::new(SpeciesID::Golduck, 25, 40),
+    ]),
+    area(GameVersion::Emerald, SAFARI_ZONE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Wooper, 25, 30),
+        EncounterSlot::new(SpeciesID::Marill, 25, 30),
+        EncounterSlot::new(SpeciesID::Quagsire, 25, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::OldRod, HOENN_OLD_ROD_FRESH),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::GoodRod, &[
+        EncounterSlot::new(SpeciesID::Magikarp, 10, 30),
+        EncounterSlot::new(SpeciesID::Goldeen, 10, 30),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Goldeen, 25, 35),
+        EncounterSlot::new(SpeciesID::Seaking, 25, 40),
+    ]),
+    area(GameVersion::Emerald, SAFARI_ZONE, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Remoraid, 25, 35),
+        EncounterSlot::new(SpeciesID::Octillery, 30, 35),
+    ]),
+    area(GameVersion::GroupRubySapphireEmerald, SAFARI_ZONE, SlotType::RockSmash, &[
+        EncounterSlot::new(SpeciesID::Geodude, 5, 20),
+    ]),
+    area(GameVersion::Emerald, SAFARI_ZONE, SlotType::RockSmash, &[
+        EncounterSlot::new(SpeciesID::Shuckle, 20, 30),
+    ]),
+    area(GameVersion::Emerald, ARTISAN_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Smeargle, 40, 50),
+    ]),
+    area(GameVersion::Emerald, DESERT_UNDERPASS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ditto, 38, 45),
+        EncounterSlot::new(SpeciesID::Whismur, 35, 40),
+        EncounterSlot::new(SpeciesID::Loudred, 40, 45),
+    ]),
+    area(GameVersion::Emerald, MIRAGE_TOWER, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 21, 24),
+        EncounterSlot::new(SpeciesID::Trapinch, 21, 24),
+    ]),
+    area(GameVersion::Emerald, MAGMA_HIDEOUT, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Geodude, 27, 30),
+        EncounterSlot::new(SpeciesID::Graveler, 29, 33),
+        EncounterSlot::new(SpeciesID::Torkoal, 28, 30),
+    ]),
+    area(GameVersion::Emerald, ALTERING_CAVE_EMERALD, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 6, 16),
+    ]),
+    area(GameVersion::Emerald, UNDERWATER_MARINE_CAVE, SlotType::Surf, HOENN_UNDERWATER),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE1, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 2, 5),
+        EncounterSlot::new(SpeciesID::Rattata, 2, 4),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE2, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 2, 5),
+        EncounterSlot::new(SpeciesID::Rattata, 2, 5),
+        EncounterSlot::new(SpeciesID::Caterpie, 4, 5),
+        EncounterSlot::new(SpeciesID::Weedle, 4, 5),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE3, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 6, 8),
+        EncounterSlot::new(SpeciesID::NidoranF, 6, 8),
+        EncounterSlot::new(SpeciesID::NidoranM, 6, 8),
+        EncounterSlot::new(SpeciesID::Jigglypuff, 3, 7),
+        EncounterSlot::new(SpeciesID::Mankey, 7, 7),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, MT_MOON, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 7, 10),
+        EncounterSlot::new(SpeciesID::Geodude, 7, 10),
+        EncounterSlot::new(SpeciesID::Paras, 8, 8),
+        EncounterSlot::new(SpeciesID::Clefairy, 8, 8),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, MT_MOON, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE4, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Rattata, 8, 12),
+        EncounterSlot::new(SpeciesID::Spearow, 8, 12),
+        EncounterSlot::new(SpeciesID::Mankey, 10, 12),
+    ]),
+    area(GameVersion::FireRed, ROUTE4, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 6, 12),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE4, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 6, 12),
+    ]),
+    area(GameVersion::FireRed, ROUTE4, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE4, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE4, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE4, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE4, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE24, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Caterpie, 7, 7),
+        EncounterSlot::new(SpeciesID::Metapod, 8, 8),
+        EncounterSlot::new(SpeciesID::Weedle, 7, 7),
+        EncounterSlot::new(SpeciesID::Kakuna, 8, 8),
+        EncounterSlot::new(SpeciesID::Pidgey, 11, 13),
+        EncounterSlot::new(SpeciesID::Abra, 8, 12),
+        EncounterSlot::new(SpeciesID::Venonat, 13, 16),
+    ]),
+    area(GameVersion::FireRed, ROUTE24, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 12, 14),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE24, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 12, 14),
+    ]),
+    area(GameVersion::FireRed, ROUTE24, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE24, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE24, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE24, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE24, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE25, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Caterpie, 7, 7),
+        EncounterSlot::new(SpeciesID::Metapod, 8, 8),
+        EncounterSlot::new(SpeciesID::Weedle, 7, 7),
+        EncounterSlot::new(SpeciesID::Kakuna, 8, 8),
+        EncounterSlot::new(SpeciesID::Pidgey, 11, 13),
+        EncounterSlot::new(SpeciesID::Abra, 8, 12),
+        EncounterSlot::new(SpeciesID::Venonat, 13, 16),
+    ]),
+    area(GameVersion::FireRed, ROUTE25, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 12, 14),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE25, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 12, 14),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE25, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE25, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE25, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE5, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 13, 16),
+        EncounterSlot::new(SpeciesID::Meowth, 10, 16),
+    ]),
+    area(GameVersion::FireRed, ROUTE5, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 13, 16),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE5, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 13, 16),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE6, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 13, 16),
+        EncounterSlot::new(SpeciesID::Meowth, 10, 16),
+    ]),
+    area(GameVersion::FireRed, ROUTE6, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 13, 16),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE6, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 13, 16),
+    ]),
+    area(GameVersion::FireRed, ROUTE6, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE6, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE6, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE6, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE6, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE7, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 19, 22),
+        EncounterSlot::new(SpeciesID::Meowth, 17, 20),
+    ]),
+    area(GameVersion::FireRed, ROUTE7, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Growlithe, 18, 20),
+        EncounterSlot::new(SpeciesID::Oddish, 19, 22),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE7, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Vulpix, 18, 20),
+        EncounterSlot::new(SpeciesID::Bellsprout, 19, 22),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE8, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 18, 20),
+        EncounterSlot::new(SpeciesID::Meowth, 18, 20),
+    ]),
+    area(GameVersion::FireRed, ROUTE8, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 17, 19),
+        EncounterSlot::new(SpeciesID::Growlithe, 15, 18),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE8, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 17, 19),
+        EncounterSlot::new(SpeciesID::Vulpix, 15, 18),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE9, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Rattata, 14, 17),
+        EncounterSlot::new(SpeciesID::Spearow, 13, 17),
+    ]),
+    area(GameVersion::FireRed, ROUTE9, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 11, 17),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE9, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 11, 17),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE10, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Voltorb, 14, 17),
+        EncounterSlot::new(SpeciesID::Spearow, 13, 17),
+    ]),
+    area(GameVersion::FireRed, ROUTE10, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 11, 17),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE10, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 11, 17),
+    ]),
+    area(GameVersion::FireRed, ROUTE10, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE10, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE10, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE10, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE10, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROCK_TUNNEL, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 15, 17),
+        EncounterSlot::new(SpeciesID::Geodude, 15, 17),
+        EncounterSlot::new(SpeciesID::Machop, 15, 17),
+        EncounterSlot::new(SpeciesID::Mankey, 16, 17),
+        EncounterSlot::new(SpeciesID::Onix, 13, 17),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROCK_TUNNEL, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE11, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 13, 17),
+        EncounterSlot::new(SpeciesID::Drowzee, 9, 15),
+    ]),
+    area(GameVersion::FireRed, ROUTE11, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 12, 15),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE11, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 12, 15),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE11, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE11, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE11, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE11, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, DIGLETTS_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Diglett, 15, 22),
+        EncounterSlot::new(SpeciesID::Dugtrio, 29, 31),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, POKEMON_TOWER, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Gastly, 13, 19),
+        EncounterSlot::new(SpeciesID::Haunter, 20, 25),
+        EncounterSlot::new(SpeciesID::Cubone, 15, 17),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE12, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Venonat, 22, 26),
+        EncounterSlot::new(SpeciesID::Pidgey, 23, 27),
+    ]),
+    area(GameVersion::FireRed, ROUTE12, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 22, 26),
+        EncounterSlot::new(SpeciesID::Gloom, 28, 30),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE12, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 22, 26),
+        EncounterSlot::new(SpeciesID::Weepinbell, 28, 30),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE12, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE12, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE12, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE12, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE13, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Venonat, 22, 26),
+        EncounterSlot::new(SpeciesID::Pidgey, 23, 27),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 29, 29),
+        EncounterSlot::new(SpeciesID::Ditto, 25, 25),
+    ]),
+    area(GameVersion::FireRed, ROUTE13, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 22, 26),
+        EncounterSlot::new(SpeciesID::Gloom, 28, 30),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE13, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 22, 26),
+        EncounterSlot::new(SpeciesID::Weepinbell, 28, 30),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE13, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE13, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE13, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE13, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE14, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Venonat, 24, 26),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 28, 30),
+        EncounterSlot::new(SpeciesID::Ditto, 23, 23),
+    ]),
+    area(GameVersion::FireRed, ROUTE14, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 22, 26),
+        EncounterSlot::new(SpeciesID::Gloom, 30, 30),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE14, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 22, 26),
+        EncounterSlot::new(SpeciesID::Weepinbell, 30, 30),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE15, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Venonat, 24, 26),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 28, 30),
+        EncounterSlot::new(SpeciesID::Ditto, 23, 23),
+    ]),
+    area(GameVersion::FireRed, ROUTE15, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 22, 26),
+        EncounterSlot::new(SpeciesID::Gloom, 30, 30),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE15, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 22, 26),
+        EncounterSlot::new(SpeciesID::Weepinbell, 30, 30),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE16, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Rattata, 18, 22),
+        EncounterSlot::new(SpeciesID::Spearow, 20, 22),
+        EncounterSlot::new(SpeciesID::Doduo, 18, 22),
+        EncounterSlot::new(SpeciesID::Raticate, 23, 25),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE17, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Raticate, 25, 29),
+        EncounterSlot::new(SpeciesID::Spearow, 20, 22),
+        EncounterSlot::new(SpeciesID::Fearow, 25, 27),
+        EncounterSlot::new(SpeciesID::Doduo, 24, 28),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE18, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Raticate, 25, 29),
+        EncounterSlot::new(SpeciesID::Spearow, 20, 22),
+        EncounterSlot::new(SpeciesID::Fearow, 25, 29),
+        EncounterSlot::new(SpeciesID::Doduo, 24, 28),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE19, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE19, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE19, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE19, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE20, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE20, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE20, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE20, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE21, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 22, 30),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 25, 32),
+        EncounterSlot::new(SpeciesID::Rattata, 22, 28),
+        EncounterSlot::new(SpeciesID::Raticate, 25, 30),
+        EncounterSlot::new(SpeciesID::Tangela, 17, 28),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE21, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE21, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE21, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE21, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE22, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Rattata, 2, 5),
+        EncounterSlot::new(SpeciesID::Mankey, 2, 5),
+        EncounterSlot::new(SpeciesID::Spearow, 3, 5),
+    ]),
+    area(GameVersion::FireRed, ROUTE22, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE22, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE22, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE22, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE22, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE23, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 26, 30),
+        EncounterSlot::new(SpeciesID::Fearow, 38, 43),
+        EncounterSlot::new(SpeciesID::Mankey, 32, 34),
+        EncounterSlot::new(SpeciesID::Primeape, 44, 46),
+    ]),
+    area(GameVersion::FireRed, ROUTE23, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Ekans, 32, 35),
+        EncounterSlot::new(SpeciesID::Arbok, 44, 46),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE23, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 32, 35),
+        EncounterSlot::new(SpeciesID::Sandslash, 44, 46),
+    ]),
+    area(GameVersion::FireRed, ROUTE23, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, ROUTE23, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE23, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE23, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ROUTE23, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, VIRIDIAN_FOREST, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Caterpie, 3, 5),
+        EncounterSlot::new(SpeciesID::Metapod, 4, 6),
+        EncounterSlot::new(SpeciesID::Weedle, 3, 5),
+        EncounterSlot::new(SpeciesID::Kakuna, 4, 6),
+        EncounterSlot::new(SpeciesID::Pikachu, 3, 5),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, PALLET_TOWN, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, PALLET_TOWN, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, PALLET_TOWN, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, PALLET_TOWN, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, VIRIDIAN_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Poliwag, 20, 40),
+        EncounterSlot::new(SpeciesID::Poliwhirl, 30, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, VIRIDIAN_CITY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, VIRIDIAN_CITY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, VIRIDIAN_CITY, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::FireRed, CERULEAN_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+    ]),
+    area(GameVersion::LeafGreen, CERULEAN_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CITY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CITY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CITY, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, VERMILION_CITY, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, VERMILION_CITY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, VERMILION_CITY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, VERMILION_CITY, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, CELADON_CITY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Grimer, 30, 40),
+        EncounterSlot::new(SpeciesID::Muk, 35, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, CELADON_CITY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CELADON_CITY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CELADON_CITY, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, FUCHSIA_CITY, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, FUCHSIA_CITY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, FUCHSIA_CITY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, FUCHSIA_CITY, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, CINNABAR_ISLAND, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, CINNABAR_ISLAND, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CINNABAR_ISLAND, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CINNABAR_ISLAND, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, VICTORY_ROAD_KANTO, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Machop, 32, 32),
+        EncounterSlot::new(SpeciesID::Machoke, 44, 46),
+        EncounterSlot::new(SpeciesID::Geodude, 40, 40),
+        EncounterSlot::new(SpeciesID::Graveler, 44, 46),
+        EncounterSlot::new(SpeciesID::Zubat, 32, 32),
+        EncounterSlot::new(SpeciesID::Golbat, 44, 46),
+        EncounterSlot::new(SpeciesID::Onix, 40, 46),
+        EncounterSlot::new(SpeciesID::Marowak, 44, 46),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, VICTORY_ROAD_KANTO, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, SEAFOAM_ISLANDS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seel, 28, 30),
+        EncounterSlot::new(SpeciesID::Zubat, 22, 26),
+        EncounterSlot::new(SpeciesID::Golbat, 26, 30),
+        EncounterSlot::new(SpeciesID::Dewgong, 34, 36),
+    ]),
+    area(GameVersion::FireRed, SEAFOAM_ISLANDS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 26, 32),
+        EncounterSlot::new(SpeciesID::Golduck, 32, 38),
+    ]),
+    area(GameVersion::LeafGreen, SEAFOAM_ISLANDS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 26, 32),
+        EncounterSlot::new(SpeciesID::Slowbro, 32, 38),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, SEAFOAM_ISLANDS, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Seel, 25, 35),
+        EncounterSlot::new(SpeciesID::Dewgong, 35, 40),
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, SEAFOAM_ISLANDS, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, SEAFOAM_ISLANDS, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, SEAFOAM_ISLANDS, SlotType::SuperRod, KANTO_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, POKEMON_MANSION, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Raticate, 32, 36),
+        EncounterSlot::new(SpeciesID::Grimer, 28, 32),
+        EncounterSlot::new(SpeciesID::Muk, 32, 36),
+        EncounterSlot::new(SpeciesID::Koffing, 28, 32),
+        EncounterSlot::new(SpeciesID::Weezing, 32, 37),
+        EncounterSlot::new(SpeciesID::Ponyta, 28, 32),
+        EncounterSlot::new(SpeciesID::Ditto, 20, 30),
+    ]),
+    area(GameVersion::FireRed, POKEMON_MANSION, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Growlithe, 30, 32),
+    ]),
+    area(GameVersion::LeafGreen, POKEMON_MANSION, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Vulpix, 30, 32),
+        EncounterSlot::new(SpeciesID::Magmar, 34, 38),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, POWER_PLANT, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Voltorb, 22, 25),
+        EncounterSlot::new(SpeciesID::Magnemite, 22, 25),
+        EncounterSlot::new(SpeciesID::Pikachu, 22, 26),
+        EncounterSlot::new(SpeciesID::Magneton, 31, 34),
+    ]),
+    area(GameVersion::FireRed, POWER_PLANT, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Electabuzz, 32, 35),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, SAFARI_ZONE_KANTO, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::NidoranF, 22, 30),
+        EncounterSlot::new(SpeciesID::NidoranM, 22, 30),
+        EncounterSlot::new(SpeciesID::Nidorina, 30, 33),
+        EncounterSlot::new(SpeciesID::Nidorino, 30, 33),
+        EncounterSlot::new(SpeciesID::Paras, 22, 26),
+        EncounterSlot::new(SpeciesID::Parasect, 30, 32),
+        EncounterSlot::new(SpeciesID::Venonat, 22, 30),
+        EncounterSlot::new(SpeciesID::Venomoth, 30, 32),
+        EncounterSlot::new(SpeciesID::Exeggcute, 24, 28),
+        EncounterSlot::new(SpeciesID::Rhyhorn, 25, 28),
+        EncounterSlot::new(SpeciesID::Chansey, 23, 26),
+        EncounterSlot::new(SpeciesID::Kangaskhan, 25, 28),
+        EncounterSlot::new(SpeciesID::Doduo, 26, 28),
+        EncounterSlot::new(SpeciesID::Tauros, 25, 28),
+    ]),
+    area(GameVersion::FireRed, SAFARI_ZONE_KANTO, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Scyther, 23, 26),
+    ]),
+    area(GameVersion::LeafGreen, SAFARI_ZONE_KANTO, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pinsir, 23, 26),
+    ]),
+    area(GameVersion::FireRed, SAFARI_ZONE_KANTO, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 35),
+    ]),
+    area(GameVersion::LeafGreen, SAFARI_ZONE_KANTO, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 35),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, SAFARI_ZONE_KANTO, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, SAFARI_ZONE_KANTO, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, SAFARI_ZONE_KANTO, SlotType::SuperRod, &[
+        EncounterSlot::new(SpeciesID::Dratini, 15, 25),
+        EncounterSlot::new(SpeciesID::Dragonair, 15, 25),
+        EncounterSlot::new(SpeciesID::Magikarp, 15, 25),
+        EncounterSlot::new(SpeciesID::Seaking, 25, 35),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Golbat, 46, 58),
+        EncounterSlot::new(SpeciesID::Parasect, 52, 59),
+        EncounterSlot::new(SpeciesID::Raichu, 53, 56),
+        EncounterSlot::new(SpeciesID::Magneton, 49, 55),
+        EncounterSlot::new(SpeciesID::Ditto, 46, 55),
+        EncounterSlot::new(SpeciesID::Wobbuffet, 55, 58),
+        EncounterSlot::new(SpeciesID::Kadabra, 49, 55),
+        EncounterSlot::new(SpeciesID::Electrode, 55, 58),
+        EncounterSlot::new(SpeciesID::Chansey, 56, 64),
+    ]),
+    area(GameVersion::FireRed, CERULEAN_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Arbok, 52, 56),
+    ]),
+    area(GameVersion::LeafGreen, CERULEAN_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandslash, 52, 56),
+    ]),
+    area(GameVersion::FireRed, CERULEAN_CAVE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 35, 45),
+        EncounterSlot::new(SpeciesID::Golduck, 40, 60),
+    ]),
+    area(GameVersion::LeafGreen, CERULEAN_CAVE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 35, 45),
+        EncounterSlot::new(SpeciesID::Slowbro, 40, 60),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CAVE, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CAVE, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CAVE, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, CERULEAN_CAVE, SlotType::RockSmash, &[
+        EncounterSlot::new(SpeciesID::Geodude, 35, 50),
+        EncounterSlot::new(SpeciesID::Graveler, 40, 50),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 31, 37),
+        EncounterSlot::new(SpeciesID::Fearow, 36, 40),
+        EncounterSlot::new(SpeciesID::Ponyta, 31, 37),
+        EncounterSlot::new(SpeciesID::Rapidash, 37, 40),
+        EncounterSlot::new(SpeciesID::Raticate, 31, 37),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, KINDLE_ROAD, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, TREASURE_BEACH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 31, 37),
+        EncounterSlot::new(SpeciesID::Fearow, 36, 40),
+        EncounterSlot::new(SpeciesID::Tangela, 33, 35),
+        EncounterSlot::new(SpeciesID::Meowth, 31, 37),
+        EncounterSlot::new(SpeciesID::Persian, 37, 40),
+    ]),
+    area(GameVersion::FireRed, TREASURE_BEACH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 31, 37),
+    ]),
+    area(GameVersion::LeafGreen, TREASURE_BEACH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 31, 37),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, TREASURE_BEACH, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, TREASURE_BEACH, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, TREASURE_BEACH, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, TREASURE_BEACH, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, CAPE_BRINK, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 31, 37),
+        EncounterSlot::new(SpeciesID::Fearow, 36, 40),
+    ]),
+    area(GameVersion::FireRed, CAPE_BRINK, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 31, 37),
+        EncounterSlot::new(SpeciesID::Gloom, 35, 40),
+        EncounterSlot::new(SpeciesID::Psyduck, 31, 37),
+        EncounterSlot::new(SpeciesID::Golduck, 37, 40),
+    ]),
+    area(GameVersion::LeafGreen, CAPE_BRINK, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 31, 37),
+        EncounterSlot::new(SpeciesID::Weepinbell, 35, 40),
+        EncounterSlot::new(SpeciesID::Slowpoke, 31, 37),
+        EncounterSlot::new(SpeciesID::Slowbro, 37, 40),
+    ]),
+    area(GameVersion::FireRed, CAPE_BRINK, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+        EncounterSlot::new(SpeciesID::Golduck, 30, 40),
+    ]),
+    area(GameVersion::LeafGreen, CAPE_BRINK, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+        EncounterSlot::new(SpeciesID::Slowbro, 30, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, CAPE_BRINK, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CAPE_BRINK, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, CAPE_BRINK, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, BOND_BRIDGE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 29, 32),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 34, 37),
+        EncounterSlot::new(SpeciesID::Meowth, 31, 37),
+        EncounterSlot::new(SpeciesID::Persian, 37, 40),
+        EncounterSlot::new(SpeciesID::Venonat, 34, 36),
+    ]),
+    area(GameVersion::FireRed, BOND_BRIDGE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 31, 37),
+        EncounterSlot::new(SpeciesID::Psyduck, 31, 37),
+    ]),
+    area(GameVersion::LeafGreen, BOND_BRIDGE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 31, 37),
+        EncounterSlot::new(SpeciesID::Slowpoke, 31, 37),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, BOND_BRIDGE, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, BOND_BRIDGE, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, BOND_BRIDGE, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, BOND_BRIDGE, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, BERRY_FOREST, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgeotto, 32, 35),
+        EncounterSlot::new(SpeciesID::Venonat, 30, 34),
+        EncounterSlot::new(SpeciesID::Venomoth, 34, 37),
+        EncounterSlot::new(SpeciesID::Exeggcute, 30, 34),
+        EncounterSlot::new(SpeciesID::Drowzee, 30, 34),
+        EncounterSlot::new(SpeciesID::Hypno, 34, 37),
+    ]),
+    area(GameVersion::FireRed, BERRY_FOREST, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 31, 34),
+        EncounterSlot::new(SpeciesID::Gloom, 35, 37),
+        EncounterSlot::new(SpeciesID::Psyduck, 31, 34),
+        EncounterSlot::new(SpeciesID::Golduck, 35, 37),
+    ]),
+    area(GameVersion::LeafGreen, BERRY_FOREST, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 31, 34),
+        EncounterSlot::new(SpeciesID::Weepinbell, 35, 37),
+        EncounterSlot::new(SpeciesID::Slowpoke, 31, 34),
+        EncounterSlot::new(SpeciesID::Slowbro, 35, 37),
+    ]),
+    area(GameVersion::FireRed, BERRY_FOREST, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Psyduck, 20, 40),
+        EncounterSlot::new(SpeciesID::Golduck, 30, 40),
+    ]),
+    area(GameVersion::LeafGreen, BERRY_FOREST, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Slowpoke, 20, 40),
+        EncounterSlot::new(SpeciesID::Slowbro, 30, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, BERRY_FOREST, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, BERRY_FOREST, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, BERRY_FOREST, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, MT_EMBER, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 32, 37),
+        EncounterSlot::new(SpeciesID::Fearow, 38, 40),
+        EncounterSlot::new(SpeciesID::Ponyta, 32, 37),
+        EncounterSlot::new(SpeciesID::Rapidash, 38, 40),
+        EncounterSlot::new(SpeciesID::Geodude, 33, 39),
+        EncounterSlot::new(SpeciesID::Graveler, 36, 42),
+        EncounterSlot::new(SpeciesID::Machop, 33, 39),
+        EncounterSlot::new(SpeciesID::Machoke, 36, 42),
+        EncounterSlot::new(SpeciesID::Magmar, 38, 39),
+        EncounterSlot::new(SpeciesID::Slugma, 38, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, MT_EMBER, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, ICEFALL_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Seel, 43, 47),
+        EncounterSlot::new(SpeciesID::Dewgong, 49, 53),
+        EncounterSlot::new(SpeciesID::Zubat, 45, 48),
+        EncounterSlot::new(SpeciesID::Golbat, 45, 48),
+        EncounterSlot::new(SpeciesID::Swinub, 43, 45),
+    ]),
+    area(GameVersion::FireRed, ICEFALL_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Delibird, 43, 45),
+    ]),
+    area(GameVersion::LeafGreen, ICEFALL_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sneasel, 43, 45),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ICEFALL_CAVE, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Seel, 25, 35),
+        EncounterSlot::new(SpeciesID::Dewgong, 35, 45),
+        EncounterSlot::new(SpeciesID::Tentacool, 5, 35),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ICEFALL_CAVE, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ICEFALL_CAVE, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, ICEFALL_CAVE, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, FIVE_ISLE_MEADOW, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Pidgey, 44, 46),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 48, 50),
+        EncounterSlot::new(SpeciesID::Meowth, 44, 46),
+        EncounterSlot::new(SpeciesID::Persian, 47, 50),
+        EncounterSlot::new(SpeciesID::Sentret, 10, 15),
+        EncounterSlot::new(SpeciesID::Hoppip, 10, 15),
+    ]),
+    area(GameVersion::FireRed, FIVE_ISLE_MEADOW, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 44, 46),
+        EncounterSlot::new(SpeciesID::Gloom, 48, 50),
+    ]),
+    area(GameVersion::LeafGreen, FIVE_ISLE_MEADOW, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 44, 46),
+        EncounterSlot::new(SpeciesID::Weepinbell, 48, 50),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, FIVE_ISLE_MEADOW, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_LABYRINTH, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_LABYRINTH, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_LABYRINTH, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_LABYRINTH, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, MEMORIAL_PILLAR, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 44, 46),
+        EncounterSlot::new(SpeciesID::Fearow, 48, 50),
+        EncounterSlot::new(SpeciesID::Meowth, 44, 46),
+        EncounterSlot::new(SpeciesID::Persian, 47, 50),
+        EncounterSlot::new(SpeciesID::Pidgeotto, 48, 50),
+    ]),
+    area(GameVersion::FireRed, MEMORIAL_PILLAR, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 44, 46),
+        EncounterSlot::new(SpeciesID::Gloom, 48, 50),
+    ]),
+    area(GameVersion::LeafGreen, MEMORIAL_PILLAR, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 44, 46),
+        EncounterSlot::new(SpeciesID::Weepinbell, 48, 50),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, MEMORIAL_PILLAR, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, LOST_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Gastly, 44, 50),
+        EncounterSlot::new(SpeciesID::Haunter, 46, 52),
+    ]),
+    area(GameVersion::FireRed, LOST_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Murkrow, 44, 50),
+    ]),
+    area(GameVersion::LeafGreen, LOST_CAVE, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Misdreavus, 44, 50),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, OUTCAST_ISLAND, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, OUTCAST_ISLAND, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, OUTCAST_ISLAND, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, OUTCAST_ISLAND, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, GREEN_PATH, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, GREEN_PATH, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, GREEN_PATH, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, GREEN_PATH, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_PATH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Spearow, 44, 46),
+        EncounterSlot::new(SpeciesID::Fearow, 48, 50),
+        EncounterSlot::new(SpeciesID::Meowth, 44, 46),
+        EncounterSlot::new(SpeciesID::Persian, 47, 50),
+    ]),
+    area(GameVersion::FireRed, WATER_PATH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Oddish, 44, 46),
+        EncounterSlot::new(SpeciesID::Gloom, 48, 50),
+        EncounterSlot::new(SpeciesID::Psyduck, 44, 46),
+        EncounterSlot::new(SpeciesID::Golduck, 48, 50),
+    ]),
+    area(GameVersion::LeafGreen, WATER_PATH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Bellsprout, 44, 46),
+        EncounterSlot::new(SpeciesID::Weepinbell, 48, 50),
+        EncounterSlot::new(SpeciesID::Slowpoke, 44, 46),
+        EncounterSlot::new(SpeciesID::Slowbro, 48, 50),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_PATH, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_PATH, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_PATH, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, WATER_PATH, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, RUIN_VALLEY, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Natu, 43, 49),
+        EncounterSlot::new(SpeciesID::Yanma, 45, 52),
+        EncounterSlot::new(SpeciesID::Wobbuffet, 47, 49),
+        EncounterSlot::new(SpeciesID::Spearow, 44, 46),
+        EncounterSlot::new(SpeciesID::Fearow, 48, 50),
+        EncounterSlot::new(SpeciesID::Meowth, 44, 46),
+        EncounterSlot::new(SpeciesID::Persian, 47, 50),
+    ]),
+    area(GameVersion::FireRed, RUIN_VALLEY, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Wooper, 44, 48),
+        EncounterSlot::new(SpeciesID::Spinarak, 43, 46),
+    ]),
+    area(GameVersion::LeafGreen, RUIN_VALLEY, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Marill, 44, 48),
+        EncounterSlot::new(SpeciesID::Ledyba, 43, 46),
+    ]),
+    area(GameVersion::FireRed, RUIN_VALLEY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Wooper, 20, 35),
+        EncounterSlot::new(SpeciesID::Quagsire, 25, 40),
+    ]),
+    area(GameVersion::LeafGreen, RUIN_VALLEY, SlotType::Surf, &[
+        EncounterSlot::new(SpeciesID::Marill, 20, 35),
+        EncounterSlot::new(SpeciesID::Azumarill, 25, 40),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, RUIN_VALLEY, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, RUIN_VALLEY, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, RUIN_VALLEY, SlotType::SuperRod, KANTO_SUPER_ROD_FRESH),
+    area(GameVersion::GroupFireRedLeafGreen, SEVAULT_CANYON, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Cubone, 46, 50),
+        EncounterSlot::new(SpeciesID::Marowak, 52, 54),
+        EncounterSlot::new(SpeciesID::Onix, 46, 52),
+        EncounterSlot::new(SpeciesID::Phanpy, 46, 52),
+        EncounterSlot::new(SpeciesID::Geodude, 46, 50),
+        EncounterSlot::new(SpeciesID::Graveler, 52, 54),
+    ]),
+    area(GameVersion::FireRed, SEVAULT_CANYON, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Skarmory, 46, 52),
+    ]),
+    area(GameVersion::LeafGreen, SEVAULT_CANYON, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Sandslash, 46, 52),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, SEVAULT_CANYON, SlotType::RockSmash, KANTO_ROCK_SMASH),
+    area(GameVersion::GroupFireRedLeafGreen, TANOBY_RUINS, SlotType::Surf, KANTO_SURF_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, TANOBY_RUINS, SlotType::OldRod, KANTO_OLD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, TANOBY_RUINS, SlotType::GoodRod, KANTO_GOOD_ROD),
+    area(GameVersion::GroupFireRedLeafGreen, TANOBY_RUINS, SlotType::SuperRod, SEVII_SUPER_ROD_SEA),
+    area(GameVersion::GroupFireRedLeafGreen, TANOBY_CHAMBERS, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Unown, 25, 25),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, PATTERN_BUSH, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Caterpie, 9, 14),
+        EncounterSlot::new(SpeciesID::Metapod, 9, 14),
+        EncounterSlot::new(SpeciesID::Weedle, 9, 14),
+        EncounterSlot::new(SpeciesID::Kakuna, 9, 14),
+        EncounterSlot::new(SpeciesID::Spinarak, 9, 14),
+        EncounterSlot::new(SpeciesID::Ledyba, 9, 14),
+        EncounterSlot::new(SpeciesID::Heracross, 15, 30),
+    ]),
+    area(GameVersion::GroupFireRedLeafGreen, ALTERING_CAVE_FIRE_RED_LEAF_GREEN, SlotType::Grass, &[
+        EncounterSlot::new(SpeciesID::Zubat, 6, 16),
+    ]),
+    area(GameVersion::GroupXD, POKE_SPOT_ROCK, SlotType::PokeSpot, &[
+        EncounterSlot::new(SpeciesID::Sandshrew, 10, 23),
+        EncounterSlot::new(SpeciesID::Gligar, 10, 23),
+        EncounterSlot::new(SpeciesID::Trapinch, 10, 23),
+    ]),
+    area(GameVersion::GroupXD, POKE_SPOT_OASIS, SlotType::PokeSpot, &[
+        EncounterSlot::new(SpeciesID::Hoppip, 10, 23),
+        EncounterSlot::new(SpeciesID::Phanpy, 10, 23),
+        EncounterSlot::new(SpeciesID::Surskit, 10, 23),
+    ]),
+    area(GameVersion::GroupXD, POKE_SPOT_CAVE, SlotType::PokeSpot, &[
+        EncounterSlot::new(SpeciesID::Zubat, 10, 23),
+        EncounterSlot::new(SpeciesID::Aron, 10, 23),
+        EncounterSlot::new(SpeciesID::Wooper, 10, 23),
+    ]),
+];
+// endregion
+
+// region: Static
+const fn fixed(kind: EncounterKind, version: GameVersion, species: SpeciesID, location: u16, level: u8) -> Encounter {
+    Encounter::new(kind, version, species, Some(location), level)
+}
+
+const fn roaming(version: GameVersion, species: SpeciesID, level: u8) -> Encounter {
+    Encounter::new(EncounterKind::Roaming, version, species, None, level)
+}
+
+pub const STATIC_ENCOUNTERS: &[Encounter] = &[
+    // Ruby/Sapphire/Emerald
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Treecko, ROUTE101, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Torchic, ROUTE101, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Mudkip, ROUTE101, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Lileep, RUSTBORO_CITY, 20),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Anorith, RUSTBORO_CITY, 20),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Castform, ROUTE119, 25),
+    fixed(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Beldum, MOSSDEEP_CITY, 5),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Kecleon, ROUTE119, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Kecleon, ROUTE120, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Regirock, DESERT_RUINS, 40),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Regice, ISLAND_CAVE, 40),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Registeel, ANCIENT_TOMB, 40),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Latias, SOUTHERN_ISLAND, 50),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Latios, SOUTHERN_ISLAND, 50),
+    fixed(EncounterKind::Static, GameVersion::GroupRubySapphireEmerald, SpeciesID::Rayquaza, SKY_PILLAR, 70),
+    fixed(EncounterKind::Static, GameVersion::Ruby, SpeciesID::Groudon, CAVE_OF_ORIGIN, 45),
+    fixed(EncounterKind::Static, GameVersion::Sapphire, SpeciesID::Kyogre, CAVE_OF_ORIGIN, 45),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Groudon, TERRA_CAVE, 70),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Kyogre, MARINE_CAVE, 70),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Sudowoodo, BATTLE_FRONTIER, 40),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Mew, FARAWAY_ISLAND, 30),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Deoxys, BIRTH_ISLAND_EMERALD, 30),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::Lugia, NAVEL_ROCK_EMERALD, 70),
+    fixed(EncounterKind::Static, GameVersion::Emerald, SpeciesID::HoOh, NAVEL_ROCK_EMERALD, 70),
+    roaming(GameVersion::Ruby, SpeciesID::Latios, 40),
+    roaming(GameVersion::Sapphire, SpeciesID::Latias, 40),
+    roaming(GameVersion::Emerald, SpeciesID::Latios, 40),
+    roaming(GameVersion::Emerald, SpeciesID::Latias, 40),
+    // FireRed/LeafGreen
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Bulbasaur, PALLET_TOWN, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Charmander, PALLET_TOWN, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Squirtle, PALLET_TOWN, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Magikarp, ROUTE4_POKEMON_CENTER, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Omanyte, CINNABAR_ISLAND, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Kabuto, CINNABAR_ISLAND, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Aerodactyl, CINNABAR_ISLAND, 5),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Hitmonlee, SAFFRON_CITY, 25),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Hitmonchan, SAFFRON_CITY, 25),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Eevee, CELADON_CITY, 25),
+    fixed(EncounterKind::Gift, GameVersion::GroupFireRedLeafGreen, SpeciesID::Lapras, SILPH_CO, 25),
+    fixed(EncounterKind::Gift, GameVersion::FireRed, SpeciesID::Abra, CELADON_CITY, 9),
+    fixed(EncounterKind::Gift, GameVersion::FireRed, SpeciesID::Clefairy, CELADON_CITY, 8),
+    fixed(EncounterKind::Gift, GameVersion::FireRed, SpeciesID::Dratini, CELADON_CITY, 18),
+    fixed(EncounterKind::Gift, GameVersion::FireRed, SpeciesID::Scyther, CELADON_CITY, 25),
+    fixed(EncounterKind::Gift, GameVersion::FireRed, SpeciesID::Porygon, CELADON_CITY, 26),
+    fixed(EncounterKind::Gift, GameVersion::LeafGreen, SpeciesID::Abra, CELADON_CITY, 7),
+    fixed(EncounterKind::Gift, GameVersion::LeafGreen, SpeciesID::Clefairy, CELADON_CITY, 12),
+    fixed(EncounterKind::Gift, GameVersion::LeafGreen, SpeciesID::Pinsir, CELADON_CITY, 18),
+    fixed(EncounterKind::Gift, GameVersion::LeafGreen, SpeciesID::Dratini, CELADON_CITY, 24),
+    fixed(EncounterKind::Gift, GameVersion::LeafGreen, SpeciesID::Porygon, CELADON_CITY, 18),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Snorlax, ROUTE12, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Snorlax, ROUTE16, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Electrode, POWER_PLANT, 34),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Zapdos, POWER_PLANT, 50),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Articuno, SEAFOAM_ISLANDS, 50),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Moltres, MT_EMBER, 50),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Mewtwo, CERULEAN_CAVE, 70),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Hypno, BERRY_FOREST, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Deoxys, BIRTH_ISLAND_FIRE_RED_LEAF_GREEN, 30),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::Lugia, NAVEL_ROCK_FIRE_RED_LEAF_GREEN, 70),
+    fixed(EncounterKind::Static, GameVersion::GroupFireRedLeafGreen, SpeciesID::HoOh, NAVEL_ROCK_FIRE_RED_LEAF_GREEN, 70),
+    roaming(GameVersion::GroupFireRedLeafGreen, SpeciesID::Raikou, 50),
+    roaming(GameVersion::GroupFireRedLeafGreen, SpeciesID::Entei, 50),
+    roaming(GameVersion::GroupFireRedLeafGreen, SpeciesID::Suicune, 50),
+    // Colosseum
+    fixed(EncounterKind::Gift, GameVersion::GroupColosseum, SpeciesID::Espeon, GAMECUBE_GIFT, 25),
+    fixed(EncounterKind::Gift, GameVersion::GroupColosseum, SpeciesID::Umbreon, GAMECUBE_GIFT, 26),
+    fixed(EncounterKind::Gift, GameVersion::GroupColosseum, SpeciesID::Plusle, GAMECUBE_GIFT, 10),
+    fixed(EncounterKind::Gift, GameVersion::GroupColosseum, SpeciesID::HoOh, GAMECUBE_GIFT, 70),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Makuhita, PHENAC_CITY, 30),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Bayleef, PYRITE_TOWN, 30),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Quilava, PYRITE_TOWN, 30),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Croconaw, PYRITE_TOWN, 30),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Raikou, THE_UNDER, 40),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Entei, MT_BATTLE, 40),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Suicune, REALGAM_TOWER, 40),
+    fixed(EncounterKind::Shadow, GameVersion::GroupColosseum, SpeciesID::Tyranitar, REALGAM_TOWER, 55),
+    // XD
+    fixed(EncounterKind::Gift, GameVersion::GroupXD, SpeciesID::Eevee, POKEMON_HQ_LAB, 10),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Teddiursa, GATEON_PORT, 11),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Togepi, PYRITE_TOWN_XD, 20),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Articuno, CITADARK_ISLE, 50),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Zapdos, CITADARK_ISLE, 50),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Moltres, CITADARK_ISLE, 50),
+    fixed(EncounterKind::Shadow, GameVersion::GroupXD, SpeciesID::Lugia, CITADARK_ISLE, 50),
+];
+// endregion
+
+// region: Trades
+const fn trade(version: GameVersion, species: SpeciesID, min_level: u8) -> Encounter {
+    Encounter::new(EncounterKind::Trade, version, species, Some(LINK_TRADE_GENERATION3_NPC), min_level).with_levels(min_level, 100)
+}
+
+/// In-game trades, the received Pokémon has the level of the Pokémon given in exchange
+pub const TRADE_ENCOUNTERS: &[Encounter] = &[
+    trade(GameVersion::GroupRubySapphireEmerald, SpeciesID::Seedot, 4),
+    trade(GameVersion::GroupRubySapphireEmerald, SpeciesID::Plusle, 5),
+    trade(GameVersion::GroupRubySapphireEmerald, SpeciesID::Horsea, 5),
+    trade(GameVersion::GroupRubySapphireEmerald, SpeciesID::Meowth, 3),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::MrMime, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::NidoranF, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::NidoranM, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Nidorina, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Nidorino, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Jynx, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Lickitung, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Farfetchd, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Electrode, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Tangela, 5),
+    trade(GameVersion::GroupFireRedLeafGreen, SpeciesID::Seel, 5),
+];
+// endregion
+
+// region: Events
+const fn event(species: SpeciesID, level: u8) -> Encounter {
+    Encounter::new(EncounterKind::Event, GameVersion::CollectionGeneration3, species, Some(FATEFUL_ENCOUNTER_GENERATION3), level).fateful()
+}
+
+pub const EVENT_ENCOUNTERS: &[Encounter] = &[
+    // Aura Mew
+    event(SpeciesID::Mew, 10),
+    // WISHMKR and Channel Jirachi
+    event(SpeciesID::Jirachi, 5),
+    // Ageto Celebi
+    event(SpeciesID::Celebi, 10),
+];
+// endregion
+
+// region: Eggs
+/// Species that can hatch from an egg in the Game Boy Advance games
+pub const EGG_SPECIES: &[SpeciesID] = &[
+    SpeciesID::Bulbasaur, SpeciesID::Charmander, SpeciesID::Squirtle, SpeciesID::Caterpie, SpeciesID::Weedle,
+    SpeciesID::Pidgey, SpeciesID::Rattata, SpeciesID::Spearow, SpeciesID::Ekans, SpeciesID::Sandshrew,
+    SpeciesID::NidoranF, SpeciesID::NidoranM, SpeciesID::Vulpix, SpeciesID::Zubat, SpeciesID::Oddish,
+    SpeciesID::Paras, SpeciesID::Venonat, SpeciesID::Diglett, SpeciesID::Meowth, SpeciesID::Psyduck,
+    SpeciesID::Mankey, SpeciesID::Growlithe, SpeciesID::Poliwag, SpeciesID::Abra, SpeciesID::Machop,
+    SpeciesID::Bellsprout, SpeciesID::Tentacool, SpeciesID::Geodude, SpeciesID::Ponyta, SpeciesID::Slowpoke,
+    SpeciesID::Magnemite, SpeciesID::Farfetchd, SpeciesID::Doduo, SpeciesID::Seel, SpeciesID::Grimer,
+    SpeciesID::Shellder, SpeciesID::Gastly, SpeciesID::Onix, SpeciesID::Drowzee, SpeciesID::Krabby,
+    SpeciesID::Voltorb, SpeciesID::Exeggcute, SpeciesID::Cubone, SpeciesID::Lickitung, SpeciesID::Koffing,
+    SpeciesID::Rhyhorn, SpeciesID::Chansey, SpeciesID::Tangela, SpeciesID::Kangaskhan, SpeciesID::Horsea,
+    SpeciesID::Goldeen, SpeciesID::Staryu, SpeciesID::MrMime, SpeciesID::Scyther, SpeciesID::Pinsir,
+    SpeciesID::Tauros, SpeciesID::Magikarp, SpeciesID::Lapras, SpeciesID::Eevee, SpeciesID::Porygon,
+    SpeciesID::Omanyte, SpeciesID::Kabuto, SpeciesID::Aerodactyl, SpeciesID::Snorlax, SpeciesID::Dratini,
+    SpeciesID::Chikorita, SpeciesID::Cyndaquil, SpeciesID::Totodile, SpeciesID::Sentret, SpeciesID::Hoothoot,
+    SpeciesID::Ledyba, SpeciesID::Spinarak, SpeciesID::Chinchou, SpeciesID::Pichu, SpeciesID::Cleffa,
+    SpeciesID::Igglybuff, SpeciesID::Togepi, SpeciesID::Natu, SpeciesID::Mareep, SpeciesID::Marill,
+    SpeciesID::Sudowoodo, SpeciesID::Hoppip, SpeciesID::Aipom, SpeciesID::Sunkern, SpeciesID::Yanma,
+    SpeciesID::Wooper, SpeciesID::Murkrow, SpeciesID::Misdreavus, SpeciesID::Wobbuffet, SpeciesID::Girafarig,
+    SpeciesID::Pineco, SpeciesID::Dunsparce, SpeciesID::Gligar, SpeciesID::Snubbull, SpeciesID::Qwilfish,
+    SpeciesID::Shuckle, SpeciesID::Heracross, SpeciesID::Sneasel, SpeciesID::Teddiursa, SpeciesID::Slugma,
+    SpeciesID::Swinub, SpeciesID::Corsola, SpeciesID::Remoraid, SpeciesID::Delibird, SpeciesID::Mantine,
+    SpeciesID::Skarmory, SpeciesID::Houndour, SpeciesID::Phanpy, SpeciesID::Stantler, SpeciesID::Smeargle,
+    SpeciesID::Tyrogue, SpeciesID::Smoochum, SpeciesID::Elekid, SpeciesID::Magby, SpeciesID::Miltank,
+    SpeciesID::Larvitar, SpeciesID::Treecko, SpeciesID::Torchic, SpeciesID::Mudkip, SpeciesID::Poochyena,
+    SpeciesID::Zigzagoon, SpeciesID::Wurmple, SpeciesID::Lotad, SpeciesID::Seedot, SpeciesID::Taillow,
+    SpeciesID::Wingull, SpeciesID::Ralts, SpeciesID::Surskit, SpeciesID::Shroomish, SpeciesID::Slakoth,
+    SpeciesID::Nincada, SpeciesID::Whismur, SpeciesID::Makuhita, SpeciesID::Azurill, SpeciesID::Nosepass,
+    SpeciesID::Skitty, SpeciesID::Sableye, SpeciesID::Mawile, SpeciesID::Aron, SpeciesID::Meditite,
+    SpeciesID::Electrike, SpeciesID::Plusle, SpeciesID::Minun, SpeciesID::Volbeat, SpeciesID::Illumise,
+    SpeciesID::Roselia, SpeciesID::Gulpin, SpeciesID::Carvanha, SpeciesID::Wailmer, SpeciesID::Numel,
+    SpeciesID::Torkoal, SpeciesID::Spoink, SpeciesID::Spinda, SpeciesID::Trapinch, SpeciesID::Cacnea,
+    SpeciesID::Swablu, SpeciesID::Zangoose, SpeciesID::Seviper, SpeciesID::Lunatone, SpeciesID::Solrock,
+    SpeciesID::Barboach, SpeciesID::Corphish, SpeciesID::Baltoy, SpeciesID::Lileep, SpeciesID::Anorith,
+    SpeciesID::Feebas, SpeciesID::Castform, SpeciesID::Kecleon, SpeciesID::Shuppet, SpeciesID::Duskull,
+    SpeciesID::Tropius, SpeciesID::Chimecho, SpeciesID::Absol, SpeciesID::Wynaut, SpeciesID::Snorunt,
+    SpeciesID::Spheal, SpeciesID::Clamperl, SpeciesID::Relicanth, SpeciesID::Luvdisc, SpeciesID::Bagon,
+    SpeciesID::Beldum,
+];
+
+/// Level of Generation 3 Pokémon when they hatch, their met level is stored as 0
+pub const EGG_HATCH_LEVEL: u8 = 5;
+// endregion
+
+/// Gets every encounter matching the given data.
+///
+/// Pokémon can evolve after being met, so the encounters of every pre-evolution of the species
+/// are matched too. Eggs are matched when the met level is 0 and the location is not checked,
+/// as it is validated with the rest of the met data.
+///
+/// # Arguments
+/// * `species` - Species of the Pokémon
+/// * `met_level` - Met level of the Pokémon, `0` for hatched Pokémon
+/// * `met_location` - Met location of the Pokémon
+/// * `version` - Origin game of the Pokémon
+pub fn find_encounters(species: SpeciesID, met_level: u8, met_location: u16, version: GameVersion) -> Vec<Encounter> {
+    let chain = evolution_chain(EVOLUTIONS, species);
+
+    if met_level == 0 {
+        if version == GameVersion::ColosseumXD {
+            return Vec::new();
+        }
+
+        return chain
+            .iter()
+            .filter(|species| EGG_SPECIES.contains(species))
+            .map(|species| Encounter::new(EncounterKind::Egg, GameVersion::CollectionGeneration3, *species, None, 0))
+            .collect();
+    }
+
+    all_encounters()
+        .filter(|encounter| encounter.matches(&chain, met_level, met_location, version))
+        .collect()
+}
+
+/// Gets every encounter of the species in the given game, without eggs.
+///
+/// # Arguments
+/// * `species` - Species of the encounter
+/// * `version` - Game or group of games, `Any` for every game
+pub fn find_species_encounters(species: SpeciesID, version: GameVersion) -> Vec<Encounter> {
+    all_encounters()
+        .filter(|encounter| encounter.species == species && (version.contains(encounter.version) || encounter.version.contains(version)))
+        .collect()
+}
+
+/// Gets every encounter of the tables, with the wild areas expanded into single encounters
+fn all_encounters() -> impl Iterator<Item = Encounter> {
+    let wild = WILD_AREAS.iter().flat_map(|area| {
+        area.slots.iter().map(|slot| Encounter {
+            kind: EncounterKind::Wild(area.slot_type),
+            version: area.version,
+            species: slot.species,
+            location: Some(area.location),
+            min_level: slot.min_level,
+            max_level: slot.max_level,
+            fateful: false,
+        })
+    });
+
+    wild.chain(STATIC_ENCOUNTERS.iter().copied())
+        .chain(TRADE_ENCOUNTERS.iter().copied())
+        .chain(EVENT_ENCOUNTERS.iter().copied())
+}
+
+/// Gets every encounter matching the met data of a Generation 3 Pokémon.
+pub fn find_encounters_for(pokemon: &Generation3) -> Vec<Encounter> {
+    let base = &pokemon.base;
+    find_encounters(base.species, base.met_level, base.met_location, base.version)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, location::LINK_TRADE_GENERATION3_NPC, species_id::SpeciesID},
+        legality::encounters::{
+            generation3::{find_encounters, GAMECUBE_GIFT, POKE_SPOT_CAVE, ROUTE101, VIRIDIAN_FOREST},
+            EncounterKind, SlotType,
+        },
+    };
+
+    #[test]
+    fn wild_encounters() {
+        let encounters = find_encounters(SpeciesID::Zigzagoon, 3, ROUTE101, GameVersion::Emerald);
+        assert_eq!(encounters.len(), 1);
+        assert_eq!(encounters[0].kind, EncounterKind::Wild(SlotType::Grass));
+
+        // Too high for Route 101
+        assert!(find_encounters(SpeciesID::Zigzagoon, 5, ROUTE101, GameVersion::Emerald).is_empty());
+        // Route 101 is not a Kanto location
+        assert!(find_encounters(SpeciesID::Zigzagoon, 3, ROUTE101, GameVersion::FireRed).is_empty());
+        assert_eq!(find_encounters(SpeciesID::Zubat, 20, POKE_SPOT_CAVE, GameVersion::ColosseumXD)[0].kind, EncounterKind::Wild(SlotType::PokeSpot));
+    }
+
+    #[test]
+    fn evolved_wild_encounters() {
+        // Met as a Zigzagoon on Route 101
+        let encounters = find_encounters(SpeciesID::Linoone, 3, ROUTE101, GameVersion::Emerald);
+        assert_eq!(encounters.len(), 1);
+        assert_eq!(encounters[0].species, SpeciesID::Zigzagoon);
+
+        // Met as a Caterpie or a Metapod in Viridian Forest
+        let encounters = find_encounters(SpeciesID::Butterfree, 4, VIRIDIAN_FOREST, GameVersion::LeafGreen);
+        assert_eq!(encounters.len(), 2);
+        assert!(find_encounters(SpeciesID::Butterfree, 7, VIRIDIAN_FOREST, GameVersion::LeafGreen).is_empty());
+        // Weedle doesn't evolve into Butterfree
+        assert!(find_encounters(SpeciesID::Beedrill, 4, VIRIDIAN_FOREST, GameVersion::LeafGreen).iter().all(|encounter| encounter.species != SpeciesID::Caterpie));
+    }
+
+    #[test]
+    fn static_encounters() {
+        assert_eq!(find_encounters(SpeciesID::Groudon, 45, 72, GameVersion::Ruby)[0].kind, EncounterKind::Static);
+        assert!(find_encounters(SpeciesID::Groudon, 45, 72, GameVersion::Sapphire).is_empty());
+        assert_eq!(find_encounters(SpeciesID::Latios, 40, 30, GameVersion::Ruby)[0].kind, EncounterKind::Roaming);
+        assert_eq!(find_encounters(SpeciesID::Espeon, 25, GAMECUBE_GIFT, GameVersion::ColosseumXD)[0].kind, EncounterKind::Gift);
+        assert!(find_encounters(SpeciesID::Espeon, 25, 30, GameVersion::ColosseumXD).is_empty());
+    }
+
+    #[test]
+    fn trades_eggs_and_events() {
+        assert_eq!(find_encounters(SpeciesID::Seel, 30, LINK_TRADE_GENERATION3_NPC, GameVersion::LeafGreen)[0].kind, EncounterKind::Trade);
+        assert_eq!(find_encounters(SpeciesID::Pichu, 0, 32, GameVersion::Emerald)[0].kind, EncounterKind::Egg);
+        // Hatched as a Pichu
+        assert_eq!(find_encounters(SpeciesID::Pikachu, 0, 32, GameVersion::Emerald)[0].species, SpeciesID::Pichu);
+        assert!(find_encounters(SpeciesID::Mewtwo, 0, 32, GameVersion::Emerald).is_empty());
+        assert!(find_encounters(SpeciesID::Pichu, 0, 32, GameVersion::ColosseumXD).is_empty());
+
+        let events = find_encounters(SpeciesID::Mew, 10, 255, GameVersion::Emerald);
+        assert!(events[0].fateful);
+    }
+}
@@ -0,0 +1,169 @@
+pub mod generation1;
+pub mod generation2;
+pub mod generation3;
+
+use std::fmt::Display;
+
+use crate::{
+    game::enums::{game_version::GameVersion, generation::Generation, language_id::LanguageID, species_id::SpeciesID},
+    rng::generation3::PIDIVMethod,
+};
+
+/// Pokémon distributed at an event, or unlocked in the game by an event item or another game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventGift {
+    /// Name the distribution is usually known by
+    pub name: &'static str,
+    pub generation: Generation,
+    /// Version or group of versions that could receive the Pokémon
+    pub version: GameVersion,
+    pub species: SpeciesID,
+    /// Level the Pokémon was received at, the met level in Generation 3
+    pub level: u8,
+    /// Met location, `None` when it is not fixed by the distribution
+    pub location: Option<u16>,
+    /// Moves the Pokémon is received with, only the special moves for eggs
+    pub moves: &'static [u16],
+    /// Accepted OT names, empty when the OT is the player or depends on the language
+    pub ot_names: &'static [&'static str],
+    pub trainer_id: Option<u16>,
+    pub secret_id: Option<u16>,
+    pub language: Option<LanguageID>,
+    /// Ribbons set on every distributed Pokémon
+    pub ribbons: u32,
+    /// Pokémon is flagged as a fateful encounter
+    pub fateful: bool,
+    /// Pokémon is received as an egg
+    pub egg: bool,
+    /// Method used to generate the PID and IVs, `None` when it is not known
+    pub method: Option<PIDIVMethod>,
+}
+
+impl EventGift {
+    /// Creates a distribution received by the player, without fixed trainer data.
+    pub const fn new(name: &'static str, generation: Generation, version: GameVersion, species: SpeciesID, level: u8) -> Self {
+        Self {
+            name,
+            generation,
+            version,
+            species,
+            level,
+            location: None,
+            moves: &[],
+            ot_names: &[],
+            trainer_id: None,
+            secret_id: None,
+            language: None,
+            ribbons: 0,
+            fateful: false,
+            egg: false,
+            method: None,
+        }
+    }
+
+    /// Sets the met location
+    pub const fn at(mut self, location: u16) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the moves the Pokémon is received with
+    pub const fn with_moves(mut self, moves: &'static [u16]) -> Self {
+        self.moves = moves;
+        self
+    }
+
+    /// Sets the fixed OT of the distribution.
+    ///
+    /// # Arguments
+    /// * `ot_names` - Accepted OT names, empty when the name is not checked
+    /// * `trainer_id` - Trainer ID of every distributed Pokémon
+    /// * `secret_id` - Secret ID, `None` when it differs between Pokémon
+    pub const fn with_trainer(mut self, ot_names: &'static [&'static str], trainer_id: u16, secret_id: Option<u16>) -> Self {
+        self.ot_names = ot_names;
+        self.trainer_id = Some(trainer_id);
+        self.secret_id = secret_id;
+        self
+    }
+
+    /// Sets the only language the distribution exists in
+    pub const fn with_language(mut self, language: LanguageID) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets the PID/IV method of the distribution
+    pub const fn with_method(mut self, method: PIDIVMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Flags the distribution as a fateful encounter
+    pub const fn fateful(mut self) -> Self {
+        self.fateful = true;
+        self
+    }
+
+    /// Flags the distribution as an egg
+    pub const fn egg(mut self) -> Self {
+        self.egg = true;
+        self
+    }
+}
+
+/// Reason a Pokémon does not match an event distribution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventMismatch {
+    /// The species is not the distributed species or one of its evolutions
+    Species(SpeciesID),
+    Version(GameVersion),
+    Level { expected: u8, found: u8 },
+    Location { expected: u16, found: u16 },
+    /// The event Pokémon must have been hatched
+    NotHatched,
+    FatefulEncounter,
+    TrainerName(String),
+    TrainerID { expected: u16, found: u16 },
+    SecretID { expected: u16, found: u16 },
+    Language(LanguageID),
+    /// Ribbons of the distribution are missing
+    Ribbons(u32),
+    /// The PID and IVs were not generated by the method of the distribution
+    Method(PIDIVMethod),
+}
+
+impl Display for EventMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventMismatch::Species(species) => write!(f, "{:?} is not the distributed species or an evolution of it", species),
+            EventMismatch::Version(version) => write!(f, "{:?} could not receive the distribution", version),
+            EventMismatch::Level { expected, found } => write!(f, "Met level is {}, expected {}", found, expected),
+            EventMismatch::Location { expected, found } => write!(f, "Met location is {}, expected {}", found, expected),
+            EventMismatch::NotHatched => write!(f, "Distributed eggs must be hatched or still be an egg"),
+            EventMismatch::FatefulEncounter => write!(f, "Fateful encounter flag is not set"),
+            EventMismatch::TrainerName(name) => write!(f, "OT name {} is not the distribution OT", name),
+            EventMismatch::TrainerID { expected, found } => write!(f, "Trainer ID is {}, expected {}", found, expected),
+            EventMismatch::SecretID { expected, found } => write!(f, "Secret ID is {}, expected {}", found, expected),
+            EventMismatch::Language(language) => write!(f, "Language {:?} did not receive the distribution", language),
+            EventMismatch::Ribbons(ribbons) => write!(f, "Distribution ribbons {:#010X} are missing", ribbons),
+            EventMismatch::Method(method) => write!(f, "PID and IVs were not generated with {:?}", method),
+        }
+    }
+}
+
+/// Checks the trainer data shared by every generation against the distribution
+fn validate_trainer(event: &EventGift, name: &str, trainer_id: u16, secret_id: u16) -> Result<(), EventMismatch> {
+    if !event.ot_names.is_empty() && !event.ot_names.contains(&name) {
+        return Err(EventMismatch::TrainerName(name.to_string()));
+    }
+
+    if let Some(expected) = event.trainer_id.filter(|expected| *expected != trainer_id) {
+        return Err(EventMismatch::TrainerID { expected, found: trainer_id });
+    }
+
+    if let Some(expected) = event.secret_id.filter(|expected| *expected != secret_id) {
+        return Err(EventMismatch::SecretID { expected, found: secret_id });
+    }
+
+    Ok(())
+}
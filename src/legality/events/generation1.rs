@@ -0,0 +1,66 @@
+//! Event distributions of Red, Green, Blue and Yellow.
+
+use crate::{
+    game::enums::{game_version::GameVersion, generation::Generation, species_id::SpeciesID},
+    pokemon::Generation1,
+    species::evolution::{evolution_chain, generation1::EVOLUTIONS},
+};
+
+use super::{validate_trainer, EventGift, EventMismatch};
+
+/// Trainer ID of the Pokémon received in the international Pokémon Stadium
+const STADIUM_TRAINER_ID: u16 = 2000;
+
+/// Pokémon received in Pokémon Stadium, the OT name depends on the language
+const fn stadium(species: SpeciesID, level: u8) -> EventGift {
+    EventGift::new("Pokémon Stadium", Generation::G1, GameVersion::GroupRedBlueYellow, species, level).with_trainer(&[], STADIUM_TRAINER_ID, None)
+}
+
+pub const EVENTS: &[EventGift] = &[
+    // Distributed at many events with different OTs, all with Pound
+    EventGift::new("Mew", Generation::G1, GameVersion::GroupRedBlueYellow, SpeciesID::Mew, 5).with_moves(&[1]),
+    // region: Pokémon Stadium
+    stadium(SpeciesID::Bulbasaur, 5),
+    stadium(SpeciesID::Charmander, 5),
+    stadium(SpeciesID::Squirtle, 5),
+    // Surf
+    stadium(SpeciesID::Pikachu, 5).with_moves(&[57]),
+    // Amnesia
+    stadium(SpeciesID::Psyduck, 15).with_moves(&[133]),
+    stadium(SpeciesID::Eevee, 25),
+    stadium(SpeciesID::Hitmonlee, 30),
+    stadium(SpeciesID::Hitmonchan, 30),
+    stadium(SpeciesID::Omanyte, 30),
+    stadium(SpeciesID::Kabuto, 30),
+    // endregion
+];
+
+/// Gets the distributions of the species or one of its pre-evolutions
+pub fn find_events(species: SpeciesID) -> Vec<&'static EventGift> {
+    let chain = evolution_chain(EVOLUTIONS, species);
+    EVENTS.iter().filter(|event| chain.contains(&event.species)).collect()
+}
+
+/// Checks if the Pokémon could have been received from the distribution. Generation 1 does not
+/// store met data, so the current level is compared instead.
+///
+/// # Arguments
+/// * `pokemon` - Pokémon to check
+/// * `event` - Distribution the Pokémon claims to come from
+pub fn validate(pokemon: &Generation1, event: &EventGift) -> Result<(), EventMismatch> {
+    let base = &pokemon.base;
+
+    if !evolution_chain(EVOLUTIONS, base.species).contains(&event.species) {
+        return Err(EventMismatch::Species(base.species));
+    }
+
+    if base.level < event.level {
+        return Err(EventMismatch::Level { expected: event.level, found: base.level });
+    }
+
+    if event.language.is_some_and(|language| language != base.language) {
+        return Err(EventMismatch::Language(base.language));
+    }
+
+    validate_trainer(event, &base.ot_info.name, base.ot_info.public_id, 0)
+}
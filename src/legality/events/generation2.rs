@@ -0,0 +1,101 @@
+//! Event distributions of Gold, Silver and Crystal. Generation 2 Pokémon can't be loaded yet,
+//! so the distributions are checked against the shared Pokémon data.
+
+use crate::{
+    game::enums::{game_version::GameVersion, generation::Generation, language_id::LanguageID, species_id::SpeciesID},
+    pokemon::base::PokemonBase,
+    species::evolution::{evolution_chain, generation3::EVOLUTIONS},
+};
+
+use super::{validate_trainer, EventGift, EventMismatch};
+
+/// Last species of Generation 2
+const MAX_SPECIES_ID: u16 = 251;
+/// Met level Crystal stores for hatched Pokémon, the other games store no met data
+const HATCHED_MET_LEVEL: u8 = 1;
+
+const DIZZY_PUNCH: u16 = 146;
+
+/// Egg received in the Goldenrod Pokémon Center of Crystal, which always knows Dizzy Punch
+const fn odd_egg(species: SpeciesID) -> EventGift {
+    EventGift::new("Odd Egg", Generation::G2, GameVersion::GroupGoldSilverCrystal, species, 5).with_moves(&[DIZZY_PUNCH]).egg()
+}
+
+pub const EVENTS: &[EventGift] = &[
+    odd_egg(SpeciesID::Pichu),
+    odd_egg(SpeciesID::Cleffa),
+    odd_egg(SpeciesID::Igglybuff),
+    odd_egg(SpeciesID::Smoochum),
+    odd_egg(SpeciesID::Magby),
+    odd_egg(SpeciesID::Elekid),
+    odd_egg(SpeciesID::Tyrogue),
+    // Unlocked by the Mobile System in Japanese Crystal
+    EventGift::new("GS Ball Celebi", Generation::G2, GameVersion::GroupGoldSilverCrystal, SpeciesID::Celebi, 30).with_language(LanguageID::Japanese),
+];
+
+/// Gets the species and its pre-evolutions that exist in Generation 2, from the evolution table
+/// shared with Generation 3
+fn pre_evolutions(species: SpeciesID) -> Vec<SpeciesID> {
+    evolution_chain(EVOLUTIONS, species)
+        .into_iter()
+        .filter(|species| species.national_id() <= MAX_SPECIES_ID)
+        .collect()
+}
+
+/// Gets the distributions of the species or one of its pre-evolutions
+pub fn find_events(species: SpeciesID) -> Vec<&'static EventGift> {
+    let chain = pre_evolutions(species);
+    EVENTS.iter().filter(|event| chain.contains(&event.species)).collect()
+}
+
+/// Checks if the Pokémon could have been received from the distribution. Only Crystal stores
+/// met data, so the current level is compared like in Generation 1.
+///
+/// # Arguments
+/// * `pokemon` - Data of the Pokémon to check
+/// * `is_egg` - Pokémon is still an egg
+/// * `event` - Distribution the Pokémon claims to come from
+pub fn validate(pokemon: &PokemonBase, is_egg: bool, event: &EventGift) -> Result<(), EventMismatch> {
+    if !pre_evolutions(pokemon.species).contains(&event.species) {
+        return Err(EventMismatch::Species(pokemon.species));
+    }
+
+    // A met level of 0 means the game did not store met data
+    if event.egg && !is_egg && !matches!(pokemon.met_level, 0 | HATCHED_MET_LEVEL) {
+        return Err(EventMismatch::NotHatched);
+    }
+
+    if !event.egg && pokemon.level < event.level {
+        return Err(EventMismatch::Level { expected: event.level, found: pokemon.level });
+    }
+
+    if event.language.is_some_and(|language| language != pokemon.language) {
+        return Err(EventMismatch::Language(pokemon.language));
+    }
+
+    validate_trainer(event, &pokemon.ot_info.name, pokemon.ot_info.public_id, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{language_id::LanguageID, species_id::SpeciesID},
+        legality::events::{generation2::{find_events, validate}, EventMismatch},
+        pokemon::base::PokemonBase,
+    };
+
+    #[test]
+    fn odd_eggs_and_celebi_are_validated() {
+        let pichu = PokemonBase { species: SpeciesID::Raichu, level: 30, met_level: 1, ..Default::default() };
+        let odd_egg = find_events(SpeciesID::Raichu)[0];
+        assert_eq!(odd_egg.name, "Odd Egg");
+        assert_eq!(validate(&pichu, false, odd_egg), Ok(()));
+
+        let caught = PokemonBase { met_level: 10, ..pichu };
+        assert_eq!(validate(&caught, false, odd_egg), Err(EventMismatch::NotHatched));
+
+        let celebi = PokemonBase { species: SpeciesID::Celebi, level: 30, language: LanguageID::English, ..Default::default() };
+        let gs_ball = find_events(SpeciesID::Celebi)[0];
+        assert_eq!(validate(&celebi, false, gs_ball), Err(EventMismatch::Language(LanguageID::English)));
+    }
+}
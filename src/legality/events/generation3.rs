@@ -0,0 +1,258 @@
+//! Event distributions of the Game Boy Advance games, also used by the GameCube bonus discs.
+//!
+//! Only the best known distributions are listed, Pokémon from other events are reported as
+//! not recognized instead of invalid.
+
+use crate::{
+    game::enums::{game_version::GameVersion, generation::Generation, language_id::LanguageID, location::FATEFUL_ENCOUNTER_GENERATION3, species_id::SpeciesID},
+    legality::encounters::generation3::{
+        BIRTH_ISLAND_EMERALD, BIRTH_ISLAND_FIRE_RED_LEAF_GREEN, FARAWAY_ISLAND, NAVEL_ROCK_EMERALD, NAVEL_ROCK_FIRE_RED_LEAF_GREEN, SOUTHERN_ISLAND,
+    },
+    pokemon::Generation3,
+    save::generation3::base::Generation3SaveFileBase,
+    rng::generation3::{find_pidiv_matches_for, PIDIVMethod, CHANNEL_JIRACHI_TRAINER_ID, WISHMKR_JIRACHI_TRAINER_ID},
+    species::evolution::{evolution_chain, generation3::EVOLUTIONS},
+};
+
+use super::{validate_trainer, EventGift, EventMismatch};
+
+const fn event(name: &'static str, version: GameVersion, species: SpeciesID, level: u8) -> EventGift {
+    EventGift::new(name, Generation::G3, version, species, level)
+}
+
+/// Level 70 Pokémon of the 2006 distributions for the 10th anniversary, with a shared OT
+const fn anniversary(species: SpeciesID) -> EventGift {
+    event("10 ANIV", GameVersion::CollectionGeneration3, species, 70)
+        .at(FATEFUL_ENCOUNTER_GENERATION3)
+        .with_trainer(&["10 ANIV"], 6227, None)
+        .fateful()
+}
+
+/// Jirachi moves of both GameCube distributions: Wish, Confusion and Rest
+const JIRACHI_MOVES: &[u16] = &[273, 93, 156];
+
+pub const EVENTS: &[EventGift] = &[
+    // region: GameCube
+    event("WISHMKR Jirachi", GameVersion::GroupRubySapphire, SpeciesID::Jirachi, 5)
+        .at(FATEFUL_ENCOUNTER_GENERATION3)
+        .with_moves(JIRACHI_MOVES)
+        .with_trainer(&["WISHMKR"], WISHMKR_JIRACHI_TRAINER_ID, Some(0))
+        .with_language(LanguageID::English)
+        .with_method(PIDIVMethod::WishmkrJirachi)
+        .fateful(),
+    // OT name depends on the language of the European release
+    event("Channel Jirachi", GameVersion::GroupRubySapphireEmerald, SpeciesID::Jirachi, 5)
+        .at(FATEFUL_ENCOUNTER_GENERATION3)
+        .with_moves(JIRACHI_MOVES)
+        .with_trainer(&[], CHANNEL_JIRACHI_TRAINER_ID, None)
+        .with_method(PIDIVMethod::ChannelJirachi)
+        .fateful(),
+    // Confusion, Recover, Heal Bell and Safeguard
+    event("Ageto Celebi", GameVersion::GroupRubySapphire, SpeciesID::Celebi, 10)
+        .at(FATEFUL_ENCOUNTER_GENERATION3)
+        .with_moves(&[93, 105, 215, 219])
+        .with_trainer(&["アゲト"], 31121, None)
+        .with_language(LanguageID::Japanese)
+        .with_method(PIDIVMethod::ColosseumXD)
+        .fateful(),
+    // endregion
+
+    // region: Mystery Gift
+    // Pound, OT name depends on the language
+    event("Aura Mew", GameVersion::CollectionGeneration3, SpeciesID::Mew, 10)
+        .at(FATEFUL_ENCOUNTER_GENERATION3)
+        .with_moves(&[1])
+        .with_trainer(&[], 20078, None)
+        .fateful(),
+    // endregion
+
+    // region: 10th anniversary
+    anniversary(SpeciesID::Pikachu),
+    anniversary(SpeciesID::Articuno),
+    anniversary(SpeciesID::Zapdos),
+    anniversary(SpeciesID::Moltres),
+    anniversary(SpeciesID::Mewtwo),
+    // endregion
+
+    // region: Event tickets
+    // Caught by the player, so only the location and level are fixed
+    event("Mystic Ticket Lugia", GameVersion::Emerald, SpeciesID::Lugia, 70).at(NAVEL_ROCK_EMERALD).with_method(PIDIVMethod::Method1),
+    event("Mystic Ticket Ho-Oh", GameVersion::Emerald, SpeciesID::HoOh, 70).at(NAVEL_ROCK_EMERALD).with_method(PIDIVMethod::Method1),
+    event("Mystic Ticket Lugia", GameVersion::GroupFireRedLeafGreen, SpeciesID::Lugia, 70).at(NAVEL_ROCK_FIRE_RED_LEAF_GREEN).with_method(PIDIVMethod::Method1),
+    event("Mystic Ticket Ho-Oh", GameVersion::GroupFireRedLeafGreen, SpeciesID::HoOh, 70).at(NAVEL_ROCK_FIRE_RED_LEAF_GREEN).with_method(PIDIVMethod::Method1),
+    event("Aurora Ticket Deoxys", GameVersion::Emerald, SpeciesID::Deoxys, 30).at(BIRTH_ISLAND_EMERALD).with_method(PIDIVMethod::Method1).fateful(),
+    event("Aurora Ticket Deoxys", GameVersion::GroupFireRedLeafGreen, SpeciesID::Deoxys, 30)
+        .at(BIRTH_ISLAND_FIRE_RED_LEAF_GREEN)
+        .with_method(PIDIVMethod::Method1)
+        .fateful(),
+    event("Old Sea Map Mew", GameVersion::Emerald, SpeciesID::Mew, 30).at(FARAWAY_ISLAND).with_method(PIDIVMethod::Method1).fateful(),
+    event("Eon Ticket Latias", GameVersion::GroupRubySapphireEmerald, SpeciesID::Latias, 50).at(SOUTHERN_ISLAND).with_method(PIDIVMethod::Method1),
+    event("Eon Ticket Latios", GameVersion::GroupRubySapphireEmerald, SpeciesID::Latios, 50).at(SOUTHERN_ISLAND).with_method(PIDIVMethod::Method1),
+    // endregion
+
+    // region: Pokémon Box
+    // Unlocked by depositing Pokémon, hatched by the player
+    event("Pokémon Box Swablu", GameVersion::GroupRubySapphire, SpeciesID::Swablu, 5).with_moves(&[206]).egg(),
+    event("Pokémon Box Zigzagoon", GameVersion::GroupRubySapphire, SpeciesID::Zigzagoon, 5).with_moves(&[245]).egg(),
+    event("Pokémon Box Skitty", GameVersion::GroupRubySapphire, SpeciesID::Skitty, 5).with_moves(&[6]).egg(),
+    event("Pokémon Box Pichu", GameVersion::GroupRubySapphire, SpeciesID::Pichu, 5).with_moves(&[57]).egg(),
+    // endregion
+];
+
+/// Gets the GameCube and Pokémon Box distributions the save has received, from the flags the
+/// games set when sending them. Pokémon from these distributions can also be traded from other
+/// saves, so they are not required to be listed here.
+pub fn received_events(save: &Generation3SaveFileBase) -> Vec<&'static EventGift> {
+    // Eggs unlocked by the deposit count, in the order of the count value
+    let box_eggs = ["Pokémon Box Zigzagoon", "Pokémon Box Skitty", "Pokémon Box Pichu"];
+    let unlocked_eggs = &box_eggs[..(save.rubysapphirebox_deposit_eggs_unlocked as usize).min(box_eggs.len())];
+
+    EVENTS
+        .iter()
+        .filter(|event| match event.name {
+            "WISHMKR Jirachi" => save.has_received_wishmkr_jirachi,
+            "Ageto Celebi" => save.colosseum_received_ageto,
+            "Pokémon Box Swablu" => save.has_used_rubysapphirebox,
+            name => unlocked_eggs.contains(&name),
+        })
+        .collect()
+}
+
+/// Gets the distributions of the species or one of its pre-evolutions
+pub fn find_events(species: SpeciesID) -> Vec<&'static EventGift> {
+    let chain = evolution_chain(EVOLUTIONS, species);
+    EVENTS.iter().filter(|event| chain.contains(&event.species)).collect()
+}
+
+/// Gets the distributions the Pokémon matches
+pub fn find_events_for(pokemon: &Generation3) -> Vec<&'static EventGift> {
+    find_events(pokemon.base.species).into_iter().filter(|event| validate(pokemon, event).is_ok()).collect()
+}
+
+/// Checks if the Pokémon could have been received from the distribution.
+///
+/// # Arguments
+/// * `pokemon` - Pokémon to check
+/// * `event` - Distribution the Pokémon claims to come from
+pub fn validate(pokemon: &Generation3, event: &EventGift) -> Result<(), EventMismatch> {
+    let base = &pokemon.base;
+
+    if !evolution_chain(EVOLUTIONS, base.species).contains(&event.species) {
+        return Err(EventMismatch::Species(base.species));
+    }
+
+    if !event.version.contains(base.version) {
+        return Err(EventMismatch::Version(base.version));
+    }
+
+    // Eggs take the met data of the hatch, checked with the other eggs
+    if event.egg {
+        return if pokemon.is_egg || base.met_level == 0 { Ok(()) } else { Err(EventMismatch::NotHatched) };
+    }
+
+    if base.met_level != event.level {
+        return Err(EventMismatch::Level { expected: event.level, found: base.met_level });
+    }
+
+    if let Some(expected) = event.location.filter(|expected| *expected != base.met_location) {
+        return Err(EventMismatch::Location { expected, found: base.met_location });
+    }
+
+    if event.fateful && !base.fateful_encounter {
+        return Err(EventMismatch::FatefulEncounter);
+    }
+
+    validate_trainer(event, &base.ot_info.name, base.ot_info.public_id, base.ot_info.secret_id)?;
+
+    if event.language.is_some_and(|language| language != base.language) {
+        return Err(EventMismatch::Language(base.language));
+    }
+
+    if pokemon.ribbons & event.ribbons != event.ribbons {
+        return Err(EventMismatch::Ribbons(event.ribbons));
+    }
+
+    if let Some(method) = event.method {
+        if !find_pidiv_matches_for(pokemon).iter().any(|found| found.method == method) {
+            return Err(EventMismatch::Method(method));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, language_id::LanguageID, location::FATEFUL_ENCOUNTER_GENERATION3, species_id::SpeciesID},
+        legality::events::{generation3::{find_events, find_events_for, received_events, validate, EVENTS}, EventMismatch},
+        pokemon::Generation3,
+        rng::generation3::{generate_pidiv, PIDIVMethod},
+        save::generation3::{base::Generation3SaveFileBase, utils::SAVE_RAW_HALF_SIZE},
+        species::conversion::generation3_index_from_species,
+        strings::{self, StringConverterOption},
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    fn pokemon(species: SpeciesID, pid: u32, ivs: [u8; 6], ot_name: &str, trainer_id: u16, met_location: u8, met_level: u8) -> Generation3 {
+        let mut data = vec![0u8; 100];
+        data[0x00..0x04].copy_from_slice(&pid.to_le_bytes());
+        data[0x04..0x06].copy_from_slice(&trainer_id.to_le_bytes());
+        data[0x12] = LanguageID::English as u8;
+        data[0x13] = 0x02;
+        strings::generation_3::encode(&mut data[0x14..0x1B], ot_name, 7, LanguageID::English, StringConverterOption::ClearFF).unwrap();
+
+        data[0x20..0x22].copy_from_slice(&generation3_index_from_species(species).unwrap().to_le_bytes());
+        data[0x45] = met_location;
+        data[0x46..0x48].copy_from_slice(&(met_level as u16 | ((GameVersion::Ruby as u16) << 7) | (4 << 11)).to_le_bytes());
+        let iv32 = ivs.iter().enumerate().fold(0u32, |iv32, (index, iv)| iv32 | ((*iv as u32) << (index * 5)));
+        data[0x48..0x4C].copy_from_slice(&iv32.to_le_bytes());
+        // Fateful encounter
+        data[0x4C..0x50].copy_from_slice(&(1u32 << 31).to_le_bytes());
+
+        let checksum = get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+        Generation3::new(&data)
+    }
+
+    #[test]
+    fn wishmkr_jirachi_is_recognized() {
+        let (pid, ivs) = generate_pidiv(0x1234, PIDIVMethod::Method1).unwrap();
+        let jirachi = pokemon(SpeciesID::Jirachi, pid, ivs, "WISHMKR", 20043, FATEFUL_ENCOUNTER_GENERATION3 as u8, 5);
+
+        let found = find_events_for(&jirachi);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "WISHMKR Jirachi");
+
+        let edited = pokemon(SpeciesID::Jirachi, pid, ivs, "WISHMKR", 1, FATEFUL_ENCOUNTER_GENERATION3 as u8, 5);
+        assert_eq!(validate(&edited, &EVENTS[0]), Err(EventMismatch::TrainerID { expected: 20043, found: 1 }));
+
+        // Seeds above 16 bits can't come from the bonus disc
+        let (pid, ivs) = generate_pidiv(0x1234_5678, PIDIVMethod::Method1).unwrap();
+        let jirachi = pokemon(SpeciesID::Jirachi, pid, ivs, "WISHMKR", 20043, FATEFUL_ENCOUNTER_GENERATION3 as u8, 5);
+        assert_eq!(validate(&jirachi, &EVENTS[0]), Err(EventMismatch::Method(PIDIVMethod::WishmkrJirachi)));
+    }
+
+    #[test]
+    fn evolved_box_eggs_are_recognized() {
+        assert!(find_events(SpeciesID::Linoone).iter().any(|event| event.name == "Pokémon Box Zigzagoon"));
+
+        let linoone = pokemon(SpeciesID::Linoone, 0, [0; 6], "ASH", 12345, 16, 0);
+        assert!(find_events_for(&linoone).iter().any(|event| event.moves.contains(&245)));
+
+        let caught = pokemon(SpeciesID::Linoone, 0, [0; 6], "ASH", 12345, 16, 3);
+        assert_eq!(validate(&caught, find_events(SpeciesID::Linoone)[0]), Err(EventMismatch::NotHatched));
+    }
+
+    #[test]
+    fn received_events_follow_the_save_flags() {
+        let mut save = Generation3SaveFileBase::initilize(vec![0; SAVE_RAW_HALF_SIZE]);
+        assert!(received_events(&save).is_empty());
+
+        save.has_received_wishmkr_jirachi = true;
+        save.has_used_rubysapphirebox = true;
+        save.rubysapphirebox_deposit_eggs_unlocked = 2;
+        let names: Vec<_> = received_events(&save).iter().map(|event| event.name).collect();
+        assert_eq!(names, ["WISHMKR Jirachi", "Pokémon Box Swablu", "Pokémon Box Zigzagoon", "Pokémon Box Skitty"]);
+    }
+}
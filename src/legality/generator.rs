@@ -0,0 +1,82 @@
+pub mod generation3;
+
+use std::fmt::Display;
+
+use crate::game::enums::{
+    game_version::GameVersion, language_id::LanguageID, pokemon_gender::PokemonGender, pokemon_nature::PokemonNature, species_id::SpeciesID,
+};
+
+use super::items::POKE_BALL;
+
+/// What the generated Pokémon must look like, every `None` value is picked by the generator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorCriteria {
+    pub species: SpeciesID,
+    /// Origin game, must be a single game
+    pub version: GameVersion,
+    /// Current level, the lowest level of the encounter when `None`
+    pub level: Option<u8>,
+    pub nature: Option<PokemonNature>,
+    pub gender: Option<PokemonGender>,
+    pub shiny: Option<bool>,
+    /// Moves to use instead of the latest level-up moves
+    pub moves: Option<[u16; 4]>,
+    pub language: LanguageID,
+    pub ball: u8,
+}
+
+impl GeneratorCriteria {
+    /// Creates criteria for an English Pokémon caught in a Poké Ball, without other requirements.
+    pub fn new(species: SpeciesID, version: GameVersion) -> Self {
+        Self {
+            species,
+            version,
+            level: None,
+            nature: None,
+            gender: None,
+            shiny: None,
+            moves: None,
+            language: LanguageID::English,
+            ball: POKE_BALL,
+        }
+    }
+}
+
+/// Reason a Pokémon could not be generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// The generator does not support the game
+    UnsupportedVersion(GameVersion),
+    /// No supported encounter of the species exists in the game
+    NoEncounter { species: SpeciesID, version: GameVersion },
+    /// The level is below every encounter of the species
+    LevelTooLow { level: u8, min_level: u8 },
+    /// No moves were given and the species has no learnset data
+    NoMoves(SpeciesID),
+    /// No PID/IV combination satisfying the nature, gender and shiny requirements was found
+    NoPIDIV,
+    /// The species has no name in the language, or it has characters the game can't encode
+    SpeciesName { species: SpeciesID, language: LanguageID },
+    /// The trainer has no name to use as the Original Trainer name
+    MissingTrainerName,
+    /// The trainer name is too long or has characters the game can't encode
+    TrainerName(String),
+    /// The generated Pokémon did not pass the legality checks, with the report of the checks
+    Illegal(String),
+}
+
+impl Display for GeneratorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeneratorError::UnsupportedVersion(version) => write!(f, "Generating Pokémon from {:?} is not supported", version),
+            GeneratorError::NoEncounter { species, version } => write!(f, "No supported encounter of {:?} in {:?}", species, version),
+            GeneratorError::LevelTooLow { level, min_level } => write!(f, "Level {} is below the lowest encounter level {}", level, min_level),
+            GeneratorError::NoMoves(species) => write!(f, "No moves given and no learnset data for {:?}", species),
+            GeneratorError::NoPIDIV => write!(f, "No PID/IV satisfies the nature, gender and shiny requirements"),
+            GeneratorError::SpeciesName { species, language } => write!(f, "No {:?} name of {:?} can be stored", language, species),
+            GeneratorError::MissingTrainerName => write!(f, "The trainer has no name"),
+            GeneratorError::TrainerName(name) => write!(f, "Trainer name {} can't be stored", name),
+            GeneratorError::Illegal(report) => write!(f, "Generated Pokémon is not legal:\n{}", report),
+        }
+    }
+}
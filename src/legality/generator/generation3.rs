@@ -0,0 +1,299 @@
+//! Legal Pokémon generator for Ruby/Sapphire/Emerald and FireRed/LeafGreen.
+//!
+//! Wild, static and gift encounters with a known met location are supported. Trades and
+//! events are skipped as their trainer data does not come from the player.
+
+use rand::Rng;
+
+use crate::{
+    game::enums::{game_version::GameVersion, language_id::LanguageID, species_id::SpeciesID},
+    legality::{
+        analysis::LegalityAnalysis,
+        encounters::{generation3::find_species_encounters, Encounter, EncounterKind},
+        learnsets::generation3::learnset,
+        tables::generation3::MOVE_PP,
+    },
+    pokemon::{
+        base::PokemonTrait,
+        utils::{experience::get_minimum_level_experience, gender::generate_from_pid_and_ratio, nickname::get_default_nickname, pid::is_shiny},
+        Generation3,
+    },
+    rng::generation3::{generate_pidiv, PIDIVMethod},
+    species::{conversion::generation3_index_from_species, personal::PersonalInfo},
+    strings::{self, StringConverterOption},
+    trainer::TrainerInfo,
+    utils::constants::poke_crypto::{get_generatation3_checksum, SIZE_GENERATION_3_PARTY},
+};
+
+use super::{GeneratorCriteria, GeneratorError};
+
+/// Seeds tried before giving up on the nature, gender and shiny requirements
+const MAX_ATTEMPTS: usize = 1 << 22;
+
+/// Base friendship of almost every species, the species data does not include it yet
+const BASE_FRIENDSHIP: u8 = 70;
+
+/// Gets the method the games use to generate the PID and IVs of the encounter
+fn encounter_method(encounter: &Encounter) -> Option<PIDIVMethod> {
+    match encounter.kind {
+        EncounterKind::Wild(_) => Some(PIDIVMethod::H1),
+        EncounterKind::Static | EncounterKind::Gift => Some(PIDIVMethod::Method1),
+        _ => None,
+    }
+}
+
+/// Gets the moves a Pokémon knows when met at the level, the last four learned by level-up
+fn level_up_moves(species: SpeciesID, level: u8) -> Option<[u16; 4]> {
+    let mut known: Vec<u16> = Vec::new();
+
+    for entry in learnset(species)?.level_up.iter().filter(|entry| entry.level <= level) {
+        if !known.contains(&entry.move_id) {
+            known.push(entry.move_id);
+        }
+    }
+
+    let mut moves = [0; 4];
+    for (slot, move_id) in known.iter().rev().take(4).rev().enumerate() {
+        moves[slot] = *move_id;
+    }
+
+    Some(moves)
+}
+
+/// Generates a Generation 3 Pokémon from an encounter of the game, owned by the trainer.
+///
+/// The result is checked with `LegalityAnalysis` and only returned when no check is invalid.
+///
+/// # Arguments
+/// * `criteria` - Species, game and the other requirements of the Pokémon
+/// * `trainer` - Original Trainer of the Pokémon, which must have a name
+pub fn generate(criteria: &GeneratorCriteria, trainer: &TrainerInfo) -> Result<Generation3, GeneratorError> {
+    if !matches!(criteria.version, GameVersion::Ruby | GameVersion::Sapphire | GameVersion::Emerald | GameVersion::FireRed | GameVersion::LeafGreen) {
+        return Err(GeneratorError::UnsupportedVersion(criteria.version));
+    }
+
+    if trainer.name.is_empty() {
+        return Err(GeneratorError::MissingTrainerName);
+    }
+
+    let encounters: Vec<Encounter> = find_species_encounters(criteria.species, criteria.version)
+        .into_iter()
+        .filter(|encounter| encounter.location.is_some() && encounter_method(encounter).is_some())
+        .collect();
+
+    let Some(min_level) = encounters.iter().map(|encounter| encounter.min_level).min() else {
+        return Err(GeneratorError::NoEncounter { species: criteria.species, version: criteria.version });
+    };
+
+    let level = criteria.level.unwrap_or(min_level).clamp(1, 100);
+    let Some(encounter) = encounters.iter().find(|encounter| encounter.min_level <= level) else {
+        return Err(GeneratorError::LevelTooLow { level, min_level });
+    };
+
+    let moves = match criteria.moves {
+        Some(moves) => moves,
+        None => level_up_moves(criteria.species, level).ok_or(GeneratorError::NoMoves(criteria.species))?,
+    };
+
+    let personal = PersonalInfo::generation3(criteria.species).ok_or(GeneratorError::NoEncounter { species: criteria.species, version: criteria.version })?;
+    let (pid, ivs) = roll_pidiv(criteria, encounter, personal.gender_ratio, trainer)?;
+
+    let mut ot_name = [0u8; 7];
+    let max_length = if criteria.language == LanguageID::Japanese { 5 } else { 7 };
+    if trainer.name.chars().count() > max_length
+        || strings::generation_3::encode(&mut ot_name, &trainer.name, max_length, criteria.language, StringConverterOption::ClearFF).is_err()
+    {
+        return Err(GeneratorError::TrainerName(trainer.name.clone()));
+    }
+
+    // The nickname is not reset after building the data, as it would be left empty when the name can't be encoded
+    let mut nickname = [0u8; 10];
+    let nickname_length = if criteria.language == LanguageID::Japanese { 5 } else { 10 };
+    let name_error = GeneratorError::SpeciesName { species: criteria.species, language: criteria.language };
+    let name = get_default_nickname(criteria.species, criteria.language, 3).ok_or(name_error.clone())?;
+    if name.chars().count() > nickname_length
+        || strings::generation_3::encode(&mut nickname, &name, nickname_length, criteria.language, StringConverterOption::ClearFF).is_err()
+    {
+        return Err(name_error);
+    }
+
+    let mut data = vec![0u8; SIZE_GENERATION_3_PARTY as usize];
+    data[0x00..0x04].copy_from_slice(&pid.to_le_bytes());
+    data[0x04..0x06].copy_from_slice(&trainer.public_id.to_le_bytes());
+    data[0x06..0x08].copy_from_slice(&trainer.secret_id.to_le_bytes());
+    data[0x08..0x12].copy_from_slice(&nickname);
+    data[0x12] = criteria.language as u8;
+    // Has species flag
+    data[0x13] = 0x02;
+    data[0x14..0x1B].copy_from_slice(&ot_name);
+
+    // region: Growth
+    let species_index = generation3_index_from_species(criteria.species).ok_or(GeneratorError::NoEncounter { species: criteria.species, version: criteria.version })?;
+    data[0x20..0x22].copy_from_slice(&species_index.to_le_bytes());
+    data[0x24..0x28].copy_from_slice(&get_minimum_level_experience(level, personal.growth_rate as usize).to_le_bytes());
+    data[0x29] = BASE_FRIENDSHIP;
+    // endregion
+
+    // region: Attacks
+    for (slot, move_id) in moves.iter().filter(|move_id| **move_id != 0).enumerate() {
+        data[0x2C + slot * 2..0x2E + slot * 2].copy_from_slice(&move_id.to_le_bytes());
+        data[0x34 + slot] = MOVE_PP.get(*move_id as usize).copied().unwrap_or(0);
+    }
+    // endregion
+
+    // region: Misc
+    let met_level = level.min(encounter.max_level);
+    let origins = met_level as u16 | ((criteria.version as u16) << 7) | ((criteria.ball as u16 & 0xF) << 11) | ((trainer.gender as u16 & 1) << 15);
+    data[0x45] = encounter.location.unwrap_or_default() as u8;
+    data[0x46..0x48].copy_from_slice(&origins.to_le_bytes());
+
+    let iv32 = ivs.iter().enumerate().fold(0u32, |iv32, (index, iv)| iv32 | ((*iv as u32) << (index * 5))) | ((pid & 1) << 31);
+    data[0x48..0x4C].copy_from_slice(&iv32.to_le_bytes());
+
+    if encounter.fateful {
+        data[0x4C..0x50].copy_from_slice(&(1u32 << 31).to_le_bytes());
+    }
+    // endregion
+
+    // region: Party
+    data[0x54] = level;
+    data[0x55] = 0xFF;
+    // endregion
+
+    // Data with a valid checksum is read as decrypted
+    let checksum = get_generatation3_checksum(&data);
+    data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+    let mut pokemon = Generation3::new(&data);
+    pokemon.refresh_stats();
+    pokemon.regenerate_checksum();
+
+    let analysis = LegalityAnalysis::generation3(&pokemon, Some(trainer));
+    if !analysis.is_valid() {
+        return Err(GeneratorError::Illegal(analysis.report(false)));
+    }
+
+    Ok(pokemon)
+}
+
+/// Rolls seeds until the PID and IVs of the encounter method satisfy the criteria
+fn roll_pidiv(criteria: &GeneratorCriteria, encounter: &Encounter, gender_ratio: u8, trainer: &TrainerInfo) -> Result<(u32, [u8; 6]), GeneratorError> {
+    let method = encounter_method(encounter).ok_or(GeneratorError::NoPIDIV)?;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..MAX_ATTEMPTS {
+        let Some((pid, ivs)) = generate_pidiv(rng.gen(), method) else {
+            continue;
+        };
+
+        let nature_matches = criteria.nature.is_none_or(|nature| (pid % 25) as u8 == nature as u8);
+        let gender_matches = criteria.gender.is_none_or(|gender| generate_from_pid_and_ratio(pid, gender_ratio) == gender);
+        let shiny_matches = criteria.shiny.is_none_or(|shiny| is_shiny(pid, trainer.public_id, trainer.secret_id) == shiny);
+
+        if nature_matches && gender_matches && shiny_matches {
+            return Ok((pid, ivs));
+        }
+    }
+
+    Err(GeneratorError::NoPIDIV)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, language_id::LanguageID, pokemon_nature::PokemonNature, species_id::SpeciesID},
+        legality::{
+            analysis::LegalityAnalysis,
+            generator::{generation3::generate, GeneratorCriteria, GeneratorError},
+        },
+        pokemon::{base::PokemonTrait, utils::nickname::get_default_nickname, Generation3},
+        rng::generation3::{find_pidiv_matches_for, PIDIVMethod},
+        trainer::TrainerInfo,
+    };
+
+    fn trainer() -> TrainerInfo {
+        TrainerInfo {
+            name: "ASH".to_string(),
+            public_id: 12345,
+            secret_id: 54321,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn generates_legal_gift() {
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Treecko, GameVersion::Emerald);
+        criteria.nature = Some(PokemonNature::Jolly);
+
+        let pokemon = generate(&criteria, &trainer()).unwrap();
+        assert_eq!(pokemon.base.nature, PokemonNature::Jolly);
+        assert_eq!(pokemon.base.nickname, "TREECKO");
+        assert_eq!(pokemon.base.ot_info.name, "ASH");
+        assert!(LegalityAnalysis::generation3(&pokemon, Some(&trainer())).is_valid());
+        assert!(find_pidiv_matches_for(&pokemon).iter().any(|found| found.method == PIDIVMethod::Method1));
+    }
+
+    #[test]
+    fn generates_shiny_wild() {
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Zigzagoon, GameVersion::Emerald);
+        criteria.shiny = Some(true);
+        criteria.moves = Some([33, 45, 0, 0]);
+
+        let pokemon = generate(&criteria, &trainer()).unwrap();
+        assert!(pokemon.base.is_shiny());
+        assert!(find_pidiv_matches_for(&pokemon).iter().any(|found| found.method == PIDIVMethod::H1));
+    }
+
+    #[test]
+    fn rejects_impossible_requests() {
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Zigzagoon, GameVersion::FireRed);
+        assert!(matches!(generate(&criteria, &trainer()), Err(GeneratorError::NoEncounter { .. })));
+
+        criteria.version = GameVersion::ColosseumXD;
+        assert_eq!(generate(&criteria, &trainer()).unwrap_err(), GeneratorError::UnsupportedVersion(GameVersion::ColosseumXD));
+
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Treecko, GameVersion::Emerald);
+        criteria.level = Some(3);
+        assert_eq!(generate(&criteria, &trainer()).unwrap_err(), GeneratorError::LevelTooLow { level: 3, min_level: 5 });
+
+        let unnamed = TrainerInfo { name: String::new(), ..trainer() };
+        assert_eq!(generate(&criteria, &unnamed).unwrap_err(), GeneratorError::MissingTrainerName);
+    }
+
+    #[test]
+    fn round_trips_every_language() {
+        let trainers = [
+            (LanguageID::Japanese, "サトシ"),
+            (LanguageID::English, "ASH"),
+            (LanguageID::French, "SACHA"),
+            (LanguageID::German, "ASH"),
+            (LanguageID::Italian, "ASH"),
+            (LanguageID::Spanish, "ASH"),
+        ];
+
+        for (language, trainer_name) in trainers {
+            let trainer = TrainerInfo { name: trainer_name.to_string(), ..trainer() };
+            // A species added after the Kanto ones
+            let mut criteria = GeneratorCriteria::new(SpeciesID::Zigzagoon, GameVersion::Emerald);
+            criteria.language = language;
+
+            let pokemon = generate(&criteria, &trainer).unwrap();
+            let read = Generation3::new(&pokemon.base.data);
+            assert_eq!(read.base.language, language);
+            assert_eq!(Some(read.base.nickname.clone()), get_default_nickname(SpeciesID::Zigzagoon, language, 3));
+            assert_eq!(read.base.ot_info.name, trainer_name);
+            assert!(!read.is_nicknamed());
+        }
+    }
+
+    #[test]
+    fn rejects_unstorable_species_names() {
+        let mut criteria = GeneratorCriteria::new(SpeciesID::Zigzagoon, GameVersion::Emerald);
+        criteria.language = LanguageID::Korean;
+
+        assert_eq!(
+            generate(&criteria, &trainer()).unwrap_err(),
+            GeneratorError::SpeciesName { species: SpeciesID::Zigzagoon, language: LanguageID::Korean }
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Rules about the balls and held items a Pokémon can have.
+
+use crate::{
+    game::enums::{game_version::GameVersion, species_id::SpeciesID},
+    species::{evolution::{self, generation1::EVOLUTIONS}, personal::PersonalInfo},
+};
+
+use super::{
+    encounters::{Encounter, EncounterKind},
+    tables::{
+        generation2::{GENERATION3_ITEMS_GOLDSILVERCRYSTAL, HELD_ITEMS_GOLDSILVERCRYSTAL, TIME_CAPSULE_ITEMS},
+        generation3::HELD_ITEMS_RUBYSAPPHIRE,
+    },
+};
+
+// region: Generation 3 balls
+pub const MASTER_BALL: u8 = 1;
+pub const ULTRA_BALL: u8 = 2;
+pub const GREAT_BALL: u8 = 3;
+pub const POKE_BALL: u8 = 4;
+pub const SAFARI_BALL: u8 = 5;
+pub const NET_BALL: u8 = 6;
+pub const DIVE_BALL: u8 = 7;
+pub const NEST_BALL: u8 = 8;
+pub const REPEAT_BALL: u8 = 9;
+pub const TIMER_BALL: u8 = 10;
+pub const LUXURY_BALL: u8 = 11;
+pub const PREMIER_BALL: u8 = 12;
+
+const SAFARI_ZONE_RUBY_SAPPHIRE_EMERALD: u16 = 57;
+const SAFARI_ZONE_FIRE_RED_LEAF_GREEN: u16 = 136;
+// endregion
+
+/// Checks if the location is a Safari Zone, where only Safari Balls can be thrown.
+/// The Hoenn and Kanto location values do not overlap, so the game is not needed.
+fn is_safari_zone(location: Option<u16>) -> bool {
+    matches!(location, Some(SAFARI_ZONE_RUBY_SAPPHIRE_EMERALD | SAFARI_ZONE_FIRE_RED_LEAF_GREEN))
+}
+
+/// Checks if a Generation 3 Pokémon from the encounter can be in the ball.
+///
+/// Pokémon that are not caught by the player, like gifts, trades, events and eggs, are always
+/// in a Poké Ball, which also applies to the Colosseum/XD gifts. Master and Premier Balls can
+/// only be thrown, like every other ball, and Safari Zone Pokémon can only be in a Safari Ball.
+///
+/// # Arguments
+/// * `ball` - Ball ID
+/// * `encounter` - Encounter the Pokémon comes from
+pub fn is_ball_allowed_generation3(ball: u8, encounter: &Encounter) -> bool {
+    if !(MASTER_BALL..=PREMIER_BALL).contains(&ball) {
+        return false;
+    }
+
+    match encounter.kind {
+        EncounterKind::Gift | EncounterKind::Trade | EncounterKind::Egg | EncounterKind::Event => ball == POKE_BALL,
+        EncounterKind::Wild(_) | EncounterKind::Static | EncounterKind::Roaming if is_safari_zone(encounter.location) => ball == SAFARI_BALL,
+        EncounterKind::Wild(_) | EncounterKind::Static | EncounterKind::Roaming | EncounterKind::Shadow => ball != SAFARI_BALL,
+    }
+}
+
+/// Gets the items that can be held in the game.
+///
+/// Every Generation 3 game shares the same holdable items, the IDs only used by one game are
+/// key items and can't be held.
+pub fn held_items(version: GameVersion) -> &'static [u16] {
+    if GameVersion::CollectionGeneration3.contains(version) {
+        &HELD_ITEMS_RUBYSAPPHIRE
+    } else {
+        &[]
+    }
+}
+
+// region: Generation 1 catch rate
+/// Yellow changed the catch rate of Pikachu, which is 190 in Red/Blue
+pub const CATCH_RATE_PIKACHU_YELLOW: u8 = 163;
+
+/// Reason the catch rate byte of a Generation 1 Pokémon has its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatchRateOrigin {
+    /// Catch rate of the species, or of the species it was caught as before evolving
+    Species(SpeciesID),
+    /// Pikachu caught or received in Yellow
+    YellowPikachu,
+    /// Held item of a Pokémon traded back from Generation 2, which replaces the catch rate
+    Generation2Item(u8),
+}
+
+/// Checks the catch rate byte of a Generation 1 Pokémon, which Generation 2 reads as the held item.
+///
+/// Species are checked from the current one to their first pre-evolution, Pokémon keep the
+/// catch rate of the species they were caught as.
+///
+/// # Arguments
+/// * `species` - Current species of the Pokémon
+/// * `catch_rate` - Catch rate byte
+pub fn validate_generation1_catch_rate(species: SpeciesID, catch_rate: u8) -> Option<CatchRateOrigin> {
+    let chain = evolution::evolution_chain(EVOLUTIONS, species);
+
+    // Generation 1 species kept their catch rate in the later games
+    if let Some(caught_as) = chain.iter().find(|species| PersonalInfo::generation3(**species).is_some_and(|personal| personal.catch_rate == catch_rate)) {
+        return Some(CatchRateOrigin::Species(*caught_as));
+    }
+
+    if catch_rate == CATCH_RATE_PIKACHU_YELLOW && chain.contains(&SpeciesID::Pikachu) {
+        return Some(CatchRateOrigin::YellowPikachu);
+    }
+
+    // Trading back without a held item clears the catch rate
+    if catch_rate == 0 || HELD_ITEMS_GOLDSILVERCRYSTAL.contains(&catch_rate) {
+        return Some(CatchRateOrigin::Generation2Item(catch_rate));
+    }
+
+    None
+}
+
+/// Gets the Generation 2 held item of a Generation 1 Pokémon transferred with the Time Capsule.
+///
+/// Catch rates that are valid items are kept, the others are converted into berries or Leftovers.
+pub fn generation2_item_from_catch_rate(catch_rate: u8) -> u8 {
+    TIME_CAPSULE_ITEMS
+        .iter()
+        .find(|(rate, _)| *rate == catch_rate)
+        .map_or(catch_rate, |(_, item)| *item)
+}
+// endregion
+
+// region: Generation 2 items
+/// Gets the Generation 3 ID of a Gold/Silver/Crystal held item, `None` when the item is not in Generation 3
+pub fn generation3_item_from_generation2(item: u8) -> Option<u16> {
+    GENERATION3_ITEMS_GOLDSILVERCRYSTAL.iter().find(|(generation2, _)| *generation2 == item).map(|(_, generation3)| *generation3)
+}
+
+/// Gets the Gold/Silver/Crystal ID of a Generation 3 item, `None` when the item can't be held in Generation 2
+pub fn generation2_item_from_generation3(item: u16) -> Option<u8> {
+    GENERATION3_ITEMS_GOLDSILVERCRYSTAL.iter().find(|(_, generation3)| *generation3 == item).map(|(generation2, _)| *generation2)
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, species_id::SpeciesID},
+        legality::{
+            encounters::{Encounter, EncounterKind, SlotType},
+            items::{
+                generation2_item_from_catch_rate, held_items, is_ball_allowed_generation3, validate_generation1_catch_rate, CatchRateOrigin,
+                MASTER_BALL, POKE_BALL, PREMIER_BALL, SAFARI_BALL,
+            },
+        },
+    };
+
+    #[test]
+    fn balls_depend_on_the_encounter() {
+        let gift = Encounter::new(EncounterKind::Gift, GameVersion::GroupRubySapphireEmerald, SpeciesID::Treecko, Some(16), 5);
+        assert!(is_ball_allowed_generation3(POKE_BALL, &gift));
+        assert!(!is_ball_allowed_generation3(MASTER_BALL, &gift));
+
+        let wild = Encounter::new(EncounterKind::Wild(SlotType::Grass), GameVersion::GroupRubySapphireEmerald, SpeciesID::Zigzagoon, Some(16), 3);
+        assert!(is_ball_allowed_generation3(PREMIER_BALL, &wild));
+        assert!(!is_ball_allowed_generation3(SAFARI_BALL, &wild));
+        assert!(!is_ball_allowed_generation3(13, &wild));
+
+        let safari = Encounter::new(EncounterKind::Wild(SlotType::Grass), GameVersion::GroupFireRedLeafGreen, SpeciesID::Tauros, Some(136), 25);
+        assert!(is_ball_allowed_generation3(SAFARI_BALL, &safari));
+        assert!(!is_ball_allowed_generation3(MASTER_BALL, &safari));
+    }
+
+    #[test]
+    fn held_items_exclude_key_items() {
+        let items = held_items(GameVersion::Emerald);
+        assert!(items.contains(&200));
+        assert!(!items.contains(&259));
+        assert!(held_items(GameVersion::HeartGold).is_empty());
+    }
+
+    #[test]
+    fn generation1_catch_rates() {
+        assert_eq!(validate_generation1_catch_rate(SpeciesID::Snorlax, 25), Some(CatchRateOrigin::Species(SpeciesID::Snorlax)));
+        assert_eq!(validate_generation1_catch_rate(SpeciesID::Raichu, 190), Some(CatchRateOrigin::Species(SpeciesID::Pikachu)));
+        assert_eq!(validate_generation1_catch_rate(SpeciesID::Raichu, 163), Some(CatchRateOrigin::YellowPikachu));
+        // Leftovers held in Generation 2
+        assert_eq!(validate_generation1_catch_rate(SpeciesID::Bulbasaur, 0x92), Some(CatchRateOrigin::Generation2Item(0x92)));
+        // Bicycle is a key item
+        assert_eq!(validate_generation1_catch_rate(SpeciesID::Bulbasaur, 7), None);
+    }
+
+    #[test]
+    fn time_capsule_conversion() {
+        assert_eq!(generation2_item_from_catch_rate(25), 0x92);
+        assert_eq!(generation2_item_from_catch_rate(45), 0x53);
+        assert_eq!(generation2_item_from_catch_rate(190), 0xAD);
+        assert_eq!(generation2_item_from_catch_rate(0x92), 0x92);
+    }
+}
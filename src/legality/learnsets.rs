@@ -0,0 +1,67 @@
+pub mod generation3;
+
+use crate::game::enums::species_id::SpeciesID;
+
+/// Move learned when reaching a level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUpMove {
+    pub level: u8,
+    pub move_id: u16,
+}
+
+/// Moves a species can learn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Learnset {
+    pub species: SpeciesID,
+    pub level_up: &'static [LevelUpMove],
+    /// Moves taught by TMs and HMs
+    pub machines: &'static [u16],
+    pub tutors: Tutors,
+    /// Moves inherited from the parents when breeding
+    pub egg_moves: &'static [u16],
+    /// Moves only known by event distributions
+    pub event_moves: &'static [u16],
+}
+
+impl Learnset {
+    /// Gets the lowest level the move is learned at, if it is learned by level-up
+    pub fn level_up_level(&self, move_id: u16) -> Option<u8> {
+        self.level_up
+            .iter()
+            .filter(|entry| entry.move_id == move_id)
+            .map(|entry| entry.level)
+            .min()
+    }
+}
+
+/// Moves taught by the move tutors of each version group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tutors {
+    pub fire_red_leaf_green: &'static [u16],
+    pub emerald: &'static [u16],
+    pub xd: &'static [u16],
+}
+
+impl Tutors {
+    /// Checks if any tutor teaches the move, as Pokémon can be traded between the version groups
+    pub fn contains(&self, move_id: u16) -> bool {
+        self.fire_red_leaf_green.contains(&move_id) || self.emerald.contains(&move_id) || self.xd.contains(&move_id)
+    }
+}
+
+/// Way a move can be learned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveSource {
+    LevelUp(u8),
+    Machine,
+    Tutor,
+    Egg,
+    Event,
+}
+
+/// Move source of a species of the evolution chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveLearnSource {
+    pub species: SpeciesID,
+    pub source: MoveSource,
+}
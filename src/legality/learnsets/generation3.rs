@@ -0,0 +1,14370 @@
+//! Learnsets of the Game Boy Advance and GameCube games.
+//!
+//! Level-up, machine and egg moves are shared by every Generation 3 game, except for the
+//! Deoxys forms of FireRed, LeafGreen and Emerald. Tutors depend on the version group.
+
+use crate::{
+    game::enums::{game_version::GameVersion, species_id::SpeciesID},
+    species::evolution::{self, generation3::EVOLUTIONS},
+};
+
+use super::{Learnset, LevelUpMove, MoveLearnSource, MoveSource, Tutors};
+
+// region: Shared
+/// Moves taught by TM01 to TM50 and HM01 to HM08, in order
+pub const MACHINE_MOVES: &[u16] = &[
+    264, 337, 352, 347, 46, 92, 258, 339, 331, 237,
+    241, 269, 58, 59, 63, 113, 182, 240, 202, 219,
+    218, 76, 231, 85, 87, 89, 216, 91, 94, 247,
+    280, 104, 115, 351, 53, 188, 201, 126, 317, 332,
+    259, 263, 290, 156, 213, 168, 211, 285, 289, 315,
+    15, 19, 57, 70, 148, 249, 127, 291,
+];
+
+/// Moves taught by the FireRed/LeafGreen tutors
+pub const TUTOR_MOVES_FIRE_RED_LEAF_GREEN: &[u16] = &[
+    5, 14, 25, 34, 38, 68, 69, 102, 118, 135,
+    138, 86, 153, 157, 164, 338, 307, 308,
+];
+
+/// Moves taught by the Emerald tutors
+pub const TUTOR_MOVES_EMERALD: &[u16] = &[
+    223, 205, 69, 68, 102, 135, 138, 86, 153, 157,
+    164, 5, 25, 14, 34, 38, 118, 189, 210, 207,
+    214, 129, 111, 173, 8, 9, 7, 244, 203, 196,
+];
+
+/// Moves taught by the Pokémon XD tutors
+pub const TUTOR_MOVES_XD: &[u16] = &[
+    34, 38, 69, 102, 138, 86, 164, 207, 143, 120,
+    171, 192,
+];
+
+/// Moves taught by any Generation 3 tutor
+pub const TUTOR_MOVES: &[u16] = &[
+    5, 14, 25, 34, 38, 68, 69, 102, 118, 135,
+    138, 86, 153, 157, 164, 338, 307, 308, 223, 205,
+    189, 210, 207, 214, 129, 111, 173, 8, 9, 7,
+    244, 203, 196, 143, 120, 171, 192,
+];
+// endregion
+
+const fn level(level: u8, move_id: u16) -> LevelUpMove {
+    LevelUpMove { level, move_id }
+}
+
+// region: Deoxys
+/// Level-up moves of the Deoxys forms, which replace the Normal Forme moves in the games they appear in
+pub const DEOXYS_FORMS: &[(GameVersion, &[LevelUpMove])] = &[
+    // Attack Forme
+    (
+        GameVersion::FireRed,
+        &[
+            level(1, 43), // Leer
+            level(1, 35), // Wrap
+            level(5, 101), // Night Shade
+            level(10, 100), // Teleport
+            level(15, 269), // Taunt
+            level(20, 228), // Pursuit
+            level(25, 94), // Psychic
+            level(30, 276), // Superpower
+            level(35, 354), // Psycho Boost
+            level(40, 192), // Zap Cannon
+            level(45, 199), // Lock-On
+            level(50, 104), // Double Team
+            level(55, 63), // Hyper Beam
+        ],
+    ),
+    // Defense Forme
+    (
+        GameVersion::LeafGreen,
+        &[
+            level(1, 43), // Leer
+            level(1, 35), // Wrap
+            level(5, 101), // Night Shade
+            level(10, 100), // Teleport
+            level(15, 282), // Knock Off
+            level(20, 191), // Spikes
+            level(25, 94), // Psychic
+            level(30, 289), // Snatch
+            level(35, 354), // Psycho Boost
+            level(40, 334), // Iron Defense
+            level(40, 133), // Amnesia
+            level(45, 105), // Recover
+            level(50, 68), // Counter
+            level(50, 243), // Mirror Coat
+        ],
+    ),
+    // Speed Forme
+    (
+        GameVersion::Emerald,
+        &[
+            level(1, 43), // Leer
+            level(1, 35), // Wrap
+            level(5, 101), // Night Shade
+            level(10, 104), // Double Team
+            level(15, 282), // Knock Off
+            level(20, 228), // Pursuit
+            level(25, 94), // Psychic
+            level(30, 129), // Swift
+            level(35, 354), // Psycho Boost
+            level(40, 97), // Agility
+            level(45, 105), // Recover
+            level(50, 245), // Extreme Speed
+        ],
+    ),
+];
+// endregion
+
+/// Learnsets of every species, sorted by National Pokédex number
+pub const LEARNSETS: &[Learnset] = &[
+    Learnset {
+        species: SpeciesID::Bulbasaur,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 45), // Growl
+            level(7, 73), // Leech Seed
+            level(10, 22), // Vine Whip
+            level(15, 77), // Poison Powder
+            level(15, 79), // Sleep Powder
+            level(20, 75), // Razor Leaf
+            level(25, 230), // Sweet Scent
+            level(32, 74), // Growth
+            level(39, 235), // Synthesis
+            level(46, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            130, // Skull Bash
+            219, // Safeguard
+            204, // Charm
+            80, // Petal Dance
+            345, // Magical Leaf
+            320, // Grass Whistle
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ivysaur,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 73), // Leech Seed
+            level(4, 45), // Growl
+            level(7, 73), // Leech Seed
+            level(10, 22), // Vine Whip
+            level(15, 77), // Poison Powder
+            level(15, 79), // Sleep Powder
+            level(22, 75), // Razor Leaf
+            level(29, 230), // Sweet Scent
+            level(38, 74), // Growth
+            level(47, 235), // Synthesis
+            level(56, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Venusaur,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 73), // Leech Seed
+            level(1, 22), // Vine Whip
+            level(4, 45), // Growl
+            level(7, 73), // Leech Seed
+            level(10, 22), // Vine Whip
+            level(15, 77), // Poison Powder
+            level(15, 79), // Sleep Powder
+            level(22, 75), // Razor Leaf
+            level(29, 230), // Sweet Scent
+            level(41, 74), // Growth
+            level(53, 235), // Synthesis
+            level(65, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 89,
+            216, 104, 188, 263, 290, 156, 213, 15, 70, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164, 338,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Charmander,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(7, 52), // Ember
+            level(13, 232), // Metal Claw
+            level(19, 108), // Smokescreen
+            level(25, 184), // Scary Face
+            level(31, 53), // Flamethrower
+            level(37, 163), // Slash
+            level(43, 82), // Dragon Rage
+            level(49, 83), // Fire Spin
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 182, 218, 231, 216, 91,
+            280, 104, 53, 126, 317, 332, 263, 290, 156, 213,
+            315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            187, // Belly Drum
+            246, // Ancient Power
+            157, // Rock Slide
+            44, // Bite
+            200, // Outrage
+            251, // Beat Up
+            14, // Swords Dance
+            349, // Dragon Dance
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Charmeleon,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(1, 52), // Ember
+            level(7, 52), // Ember
+            level(13, 232), // Metal Claw
+            level(20, 108), // Smokescreen
+            level(27, 184), // Scary Face
+            level(34, 53), // Flamethrower
+            level(41, 163), // Slash
+            level(48, 82), // Dragon Rage
+            level(55, 83), // Fire Spin
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 182, 218, 231, 216, 91,
+            280, 104, 53, 126, 317, 332, 263, 290, 156, 213,
+            315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Charizard,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(1, 52), // Ember
+            level(1, 232), // Metal Claw
+            level(7, 52), // Ember
+            level(13, 232), // Metal Claw
+            level(20, 108), // Smokescreen
+            level(27, 184), // Scary Face
+            level(34, 53), // Flamethrower
+            level(36, 17), // Wing Attack
+            level(44, 163), // Slash
+            level(54, 82), // Dragon Rage
+            level(64, 83), // Fire Spin
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 63, 182, 218, 231, 89,
+            216, 91, 280, 104, 53, 126, 317, 332, 263, 290,
+            156, 213, 211, 315, 15, 19, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+                307,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Squirtle,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 39), // Tail Whip
+            level(7, 145), // Bubble
+            level(10, 110), // Withdraw
+            level(13, 55), // Water Gun
+            level(18, 44), // Bite
+            level(23, 229), // Rapid Spin
+            level(28, 182), // Protect
+            level(33, 240), // Rain Dance
+            level(40, 130), // Skull Bash
+            level(47, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 237, 58, 59, 182, 240, 218, 231,
+            216, 91, 280, 104, 263, 290, 156, 213, 57, 70,
+            249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            243, // Mirror Coat
+            114, // Haze
+            54, // Mist
+            193, // Foresight
+            175, // Flail
+            287, // Refresh
+            300, // Mud Sport
+            281, // Yawn
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wartortle,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 145), // Bubble
+            level(4, 39), // Tail Whip
+            level(7, 145), // Bubble
+            level(10, 110), // Withdraw
+            level(13, 55), // Water Gun
+            level(19, 44), // Bite
+            level(25, 229), // Rapid Spin
+            level(31, 182), // Protect
+            level(37, 240), // Rain Dance
+            level(45, 130), // Skull Bash
+            level(53, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 237, 58, 59, 182, 240, 218, 231,
+            216, 91, 280, 104, 263, 290, 156, 213, 57, 70,
+            249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Blastoise,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 145), // Bubble
+            level(1, 110), // Withdraw
+            level(4, 39), // Tail Whip
+            level(7, 145), // Bubble
+            level(10, 110), // Withdraw
+            level(13, 55), // Water Gun
+            level(19, 44), // Bite
+            level(25, 229), // Rapid Spin
+            level(31, 182), // Protect
+            level(42, 240), // Rain Dance
+            level(55, 130), // Skull Bash
+            level(68, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 237, 58, 59, 63, 182, 240, 218,
+            231, 89, 216, 91, 280, 104, 263, 290, 156, 213,
+            57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164, 308,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Caterpie,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 81), // String Shot
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Metapod,
+        level_up: &[
+            level(1, 106), // Harden
+            level(7, 106), // Harden
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Butterfree,
+        level_up: &[
+            level(1, 93), // Confusion
+            level(10, 93), // Confusion
+            level(13, 77), // Poison Powder
+            level(14, 78), // Stun Spore
+            level(15, 79), // Sleep Powder
+            level(18, 48), // Supersonic
+            level(23, 18), // Whirlwind
+            level(28, 16), // Gust
+            level(34, 60), // Psybeam
+            level(40, 219), // Safeguard
+            level(47, 318), // Silver Wind
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 240, 202, 219, 218, 76,
+            216, 94, 247, 104, 332, 259, 263, 290, 156, 213,
+            168, 285, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 129, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Weedle,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 81), // String Shot
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kakuna,
+        level_up: &[
+            level(1, 106), // Harden
+            level(7, 106), // Harden
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Beedrill,
+        level_up: &[
+            level(1, 31), // Fury Attack
+            level(10, 31), // Fury Attack
+            level(15, 116), // Focus Energy
+            level(20, 41), // Twineedle
+            level(25, 99), // Rage
+            level(30, 228), // Pursuit
+            level(35, 42), // Pin Missile
+            level(40, 97), // Agility
+            level(45, 283), // Endeavor
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 202, 219, 218, 216, 104,
+            188, 332, 259, 263, 290, 156, 213, 168, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 210, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pidgey,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 28), // Sand Attack
+            level(9, 16), // Gust
+            level(13, 98), // Quick Attack
+            level(19, 18), // Whirlwind
+            level(25, 17), // Wing Attack
+            level(31, 297), // Feather Dance
+            level(39, 97), // Agility
+            level(47, 119), // Mirror Move
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 216, 104, 332, 263,
+            290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            228, // Pursuit
+            185, // Feint Attack
+            193, // Foresight
+            211, // Steel Wing
+            314, // Air Cutter
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pidgeotto,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 28), // Sand Attack
+            level(1, 16), // Gust
+            level(5, 28), // Sand Attack
+            level(9, 16), // Gust
+            level(13, 98), // Quick Attack
+            level(20, 18), // Whirlwind
+            level(27, 17), // Wing Attack
+            level(34, 297), // Feather Dance
+            level(43, 97), // Agility
+            level(52, 119), // Mirror Move
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 216, 104, 332, 263,
+            290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pidgeot,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 28), // Sand Attack
+            level(1, 16), // Gust
+            level(1, 98), // Quick Attack
+            level(5, 28), // Sand Attack
+            level(9, 16), // Gust
+            level(13, 98), // Quick Attack
+            level(20, 18), // Whirlwind
+            level(27, 17), // Wing Attack
+            level(34, 297), // Feather Dance
+            level(48, 97), // Agility
+            level(62, 119), // Mirror Move
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 240, 218, 216, 104, 332,
+            263, 290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Rattata,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(7, 98), // Quick Attack
+            level(13, 158), // Hyper Fang
+            level(20, 116), // Focus Energy
+            level(27, 228), // Pursuit
+            level(34, 162), // Super Fang
+            level(41, 283), // Endeavor
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 58, 59, 182, 240, 218,
+            231, 85, 87, 216, 91, 247, 104, 351, 259, 263,
+            290, 156, 213, 168, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                205, 68, 102, 164, 34, 38, 189, 207, 214, 129,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            103, // Screech
+            172, // Flame Wheel
+            154, // Fury Swipes
+            44, // Bite
+            68, // Counter
+            179, // Reversal
+            253, // Uproar
+            207, // Swagger
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Raticate,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 98), // Quick Attack
+            level(7, 98), // Quick Attack
+            level(13, 158), // Hyper Fang
+            level(20, 184), // Scary Face
+            level(30, 228), // Pursuit
+            level(40, 162), // Super Fang
+            level(50, 283), // Endeavor
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 58, 59, 63, 182, 240,
+            218, 231, 85, 87, 216, 91, 247, 104, 351, 259,
+            263, 290, 156, 213, 168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                205, 68, 102, 164, 34, 38, 189, 207, 214, 129,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Spearow,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(7, 43), // Leer
+            level(13, 31), // Fury Attack
+            level(19, 228), // Pursuit
+            level(25, 332), // Aerial Ace
+            level(31, 119), // Mirror Move
+            level(37, 65), // Drill Peck
+            level(43, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 216, 104, 332, 259,
+            263, 290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            185, // Feint Attack
+            206, // False Swipe
+            184, // Scary Face
+            98, // Quick Attack
+            161, // Tri Attack
+            310, // Astonish
+            143, // Sky Attack
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Fearow,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(1, 43), // Leer
+            level(1, 31), // Fury Attack
+            level(7, 43), // Leer
+            level(13, 31), // Fury Attack
+            level(26, 228), // Pursuit
+            level(32, 119), // Mirror Move
+            level(40, 65), // Drill Peck
+            level(47, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 240, 218, 216, 104, 332,
+            259, 263, 290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ekans,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(1, 43), // Leer
+            level(8, 40), // Poison Sting
+            level(13, 44), // Bite
+            level(20, 137), // Glare
+            level(25, 103), // Screech
+            level(32, 51), // Acid
+            level(37, 254), // Stockpile
+            level(37, 256), // Swallow
+            level(37, 255), // Spit Up
+            level(44, 114), // Haze
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 231, 89, 216, 91,
+            104, 188, 201, 317, 259, 263, 290, 156, 213, 168,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            228, // Pursuit
+            21, // Slam
+            180, // Spite
+            251, // Beat Up
+            305, // Poison Fang
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Arbok,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(1, 43), // Leer
+            level(1, 40), // Poison Sting
+            level(1, 44), // Bite
+            level(8, 40), // Poison Sting
+            level(13, 44), // Bite
+            level(20, 137), // Glare
+            level(28, 103), // Screech
+            level(38, 51), // Acid
+            level(46, 254), // Stockpile
+            level(46, 256), // Swallow
+            level(46, 255), // Spit Up
+            level(56, 114), // Haze
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 240, 218, 231, 89, 216,
+            91, 104, 188, 201, 317, 259, 263, 290, 156, 213,
+            168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pikachu,
+        level_up: &[
+            level(1, 84), // Thunder Shock
+            level(1, 45), // Growl
+            level(6, 39), // Tail Whip
+            level(8, 86), // Thunder Wave
+            level(11, 98), // Quick Attack
+            level(15, 104), // Double Team
+            level(20, 21), // Slam
+            level(26, 85), // Thunderbolt
+            level(33, 97), // Agility
+            level(41, 87), // Thunder
+            level(50, 113), // Light Screen
+        ],
+        machines: &[
+            92, 237, 113, 182, 240, 218, 231, 85, 87, 216,
+            91, 280, 104, 351, 263, 290, 156, 213, 70, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 111, 173, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Raichu,
+        level_up: &[
+            level(1, 84), // Thunder Shock
+            level(1, 39), // Tail Whip
+            level(1, 98), // Quick Attack
+            level(1, 85), // Thunderbolt
+        ],
+        machines: &[
+            92, 237, 63, 113, 182, 240, 218, 231, 85, 87,
+            216, 91, 280, 104, 351, 263, 290, 156, 213, 70,
+            148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 111, 173, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sandshrew,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(6, 111), // Defense Curl
+            level(11, 28), // Sand Attack
+            level(17, 40), // Poison Sting
+            level(23, 163), // Slash
+            level(30, 129), // Swift
+            level(37, 154), // Fury Swipes
+            level(45, 328), // Sand Tomb
+            level(53, 201), // Sandstorm
+        ],
+        machines: &[
+            92, 237, 241, 182, 218, 231, 89, 216, 91, 280,
+            104, 201, 317, 332, 259, 263, 290, 156, 213, 168,
+            15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 14, 34, 38,
+                189, 210, 207, 214, 129, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            175, // Flail
+            219, // Safeguard
+            68, // Counter
+            229, // Rapid Spin
+            232, // Metal Claw
+            14, // Swords Dance
+            306, // Crush Claw
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sandslash,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 111), // Defense Curl
+            level(1, 28), // Sand Attack
+            level(6, 111), // Defense Curl
+            level(11, 28), // Sand Attack
+            level(17, 40), // Poison Sting
+            level(24, 163), // Slash
+            level(33, 129), // Swift
+            level(42, 154), // Fury Swipes
+            level(52, 328), // Sand Tomb
+            level(62, 201), // Sandstorm
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 218, 231, 89, 216, 91,
+            280, 104, 201, 317, 332, 259, 263, 290, 156, 213,
+            168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 14, 34, 38,
+                189, 210, 207, 214, 129, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::NidoranF,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 10), // Scratch
+            level(8, 39), // Tail Whip
+            level(12, 24), // Double Kick
+            level(17, 40), // Poison Sting
+            level(20, 44), // Bite
+            level(23, 270), // Helping Hand
+            level(30, 154), // Fury Swipes
+            level(38, 260), // Flatter
+            level(47, 242), // Crunch
+        ],
+        machines: &[
+            92, 237, 241, 58, 59, 182, 240, 218, 231, 85,
+            87, 216, 104, 188, 332, 259, 263, 290, 156, 213,
+            168, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            48, // Supersonic
+            50, // Disable
+            36, // Take Down
+            116, // Focus Energy
+            204, // Charm
+            68, // Counter
+            251, // Beat Up
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nidorina,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 10), // Scratch
+            level(1, 39), // Tail Whip
+            level(1, 24), // Double Kick
+            level(8, 39), // Tail Whip
+            level(12, 24), // Double Kick
+            level(18, 40), // Poison Sting
+            level(22, 44), // Bite
+            level(26, 270), // Helping Hand
+            level(34, 154), // Fury Swipes
+            level(43, 260), // Flatter
+            level(53, 242), // Crunch
+        ],
+        machines: &[
+            92, 237, 241, 58, 59, 182, 240, 218, 231, 85,
+            87, 216, 104, 188, 332, 259, 263, 290, 156, 213,
+            168, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nidoqueen,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 39), // Tail Whip
+            level(1, 24), // Double Kick
+            level(1, 40), // Poison Sting
+            level(23, 34), // Body Slam
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 269, 58, 59, 63, 182,
+            240, 218, 231, 85, 87, 89, 216, 91, 247, 280,
+            104, 351, 53, 188, 201, 126, 317, 332, 259, 263,
+            290, 156, 213, 168, 15, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 210, 207, 214, 129, 173, 8, 9, 7,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::NidoranM,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 64), // Peck
+            level(8, 116), // Focus Energy
+            level(12, 24), // Double Kick
+            level(17, 40), // Poison Sting
+            level(20, 30), // Horn Attack
+            level(23, 270), // Helping Hand
+            level(30, 31), // Fury Attack
+            level(38, 260), // Flatter
+            level(47, 32), // Horn Drill
+        ],
+        machines: &[
+            92, 237, 241, 58, 59, 182, 240, 218, 231, 85,
+            87, 216, 104, 188, 332, 259, 263, 290, 156, 213,
+            168, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            68, // Counter
+            50, // Disable
+            48, // Supersonic
+            36, // Take Down
+            133, // Amnesia
+            93, // Confusion
+            251, // Beat Up
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nidorino,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 64), // Peck
+            level(1, 116), // Focus Energy
+            level(1, 24), // Double Kick
+            level(8, 116), // Focus Energy
+            level(12, 24), // Double Kick
+            level(18, 40), // Poison Sting
+            level(22, 30), // Horn Attack
+            level(26, 270), // Helping Hand
+            level(34, 31), // Fury Attack
+            level(43, 260), // Flatter
+            level(53, 32), // Horn Drill
+        ],
+        machines: &[
+            92, 237, 241, 58, 59, 182, 240, 218, 231, 85,
+            87, 216, 104, 188, 332, 259, 263, 290, 156, 213,
+            168, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nidoking,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 116), // Focus Energy
+            level(1, 24), // Double Kick
+            level(1, 40), // Poison Sting
+            level(23, 37), // Thrash
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 269, 58, 59, 63, 182,
+            240, 218, 231, 85, 87, 89, 216, 91, 247, 280,
+            104, 351, 53, 188, 201, 126, 317, 332, 259, 263,
+            290, 156, 213, 168, 15, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 210, 207, 214, 129, 173, 8, 9, 7,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Clefairy,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 45), // Growl
+            level(5, 227), // Encore
+            level(9, 47), // Sing
+            level(13, 3), // Double Slap
+            level(17, 266), // Follow Me
+            level(21, 107), // Minimize
+            level(25, 111), // Defense Curl
+            level(29, 118), // Metronome
+            level(33, 322), // Cosmic Power
+            level(37, 236), // Moonlight
+            level(41, 113), // Light Screen
+            level(45, 309), // Meteor Mash
+        ],
+        machines: &[
+            352, 347, 92, 258, 237, 241, 58, 59, 113, 182,
+            240, 219, 218, 76, 231, 85, 87, 216, 94, 247,
+            280, 104, 115, 351, 53, 126, 263, 290, 156, 213,
+            285, 289, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 164, 5,
+                25, 34, 38, 118, 189, 207, 214, 129, 111, 173,
+                8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Clefable,
+        level_up: &[
+            level(1, 47), // Sing
+            level(1, 3), // Double Slap
+            level(1, 107), // Minimize
+            level(1, 118), // Metronome
+        ],
+        machines: &[
+            352, 347, 92, 258, 237, 241, 58, 59, 63, 113,
+            182, 240, 219, 218, 76, 231, 85, 87, 216, 94,
+            247, 280, 104, 115, 351, 53, 126, 263, 290, 156,
+            213, 285, 289, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 164, 5,
+                25, 34, 38, 118, 189, 207, 214, 129, 111, 173,
+                8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Vulpix,
+        level_up: &[
+            level(1, 52), // Ember
+            level(5, 39), // Tail Whip
+            level(9, 46), // Roar
+            level(13, 98), // Quick Attack
+            level(17, 261), // Will-O-Wisp
+            level(21, 109), // Confuse Ray
+            level(25, 286), // Imprison
+            level(29, 53), // Flamethrower
+            level(33, 219), // Safeguard
+            level(37, 288), // Grudge
+            level(41, 83), // Fire Spin
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 219, 218, 231, 216, 91,
+            104, 53, 126, 259, 263, 290, 156, 213, 168, 315,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            185, // Feint Attack
+            95, // Hypnosis
+            175, // Flail
+            180, // Spite
+            50, // Disable
+            336, // Howl
+            244, // Psych Up
+            257, // Heat Wave
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ninetales,
+        level_up: &[
+            level(1, 52), // Ember
+            level(1, 98), // Quick Attack
+            level(1, 109), // Confuse Ray
+            level(1, 219), // Safeguard
+            level(45, 83), // Fire Spin
+        ],
+        machines: &[
+            46, 92, 237, 241, 63, 182, 219, 218, 231, 216,
+            91, 104, 53, 126, 259, 263, 290, 156, 213, 168,
+            315,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Jigglypuff,
+        level_up: &[
+            level(1, 47), // Sing
+            level(4, 111), // Defense Curl
+            level(9, 1), // Pound
+            level(14, 50), // Disable
+            level(19, 205), // Rollout
+            level(24, 3), // Double Slap
+            level(29, 156), // Rest
+            level(34, 34), // Body Slam
+            level(39, 102), // Mimic
+            level(44, 304), // Hyper Voice
+            level(49, 38), // Double-Edge
+        ],
+        machines: &[
+            352, 92, 258, 237, 241, 58, 59, 113, 182, 240,
+            219, 218, 76, 85, 87, 216, 94, 247, 280, 104,
+            115, 351, 53, 126, 259, 263, 290, 156, 213, 168,
+            285, 289, 70, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 138, 86, 164, 5, 25,
+                34, 38, 189, 207, 214, 129, 111, 173, 8, 9,
+                7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            195, // Perish Song
+            217, // Present
+            185, // Feint Attack
+            273, // Wish
+            313, // Fake Tears
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wigglytuff,
+        level_up: &[
+            level(1, 47), // Sing
+            level(1, 50), // Disable
+            level(1, 111), // Defense Curl
+            level(1, 3), // Double Slap
+        ],
+        machines: &[
+            352, 92, 258, 237, 241, 58, 59, 63, 113, 182,
+            240, 219, 218, 76, 85, 87, 216, 94, 247, 280,
+            104, 115, 351, 53, 126, 259, 263, 290, 156, 213,
+            168, 285, 289, 70, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 138, 86, 164, 5, 25,
+                34, 38, 189, 207, 214, 129, 111, 173, 8, 9,
+                7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Zubat,
+        level_up: &[
+            level(1, 141), // Leech Life
+            level(6, 48), // Supersonic
+            level(11, 310), // Astonish
+            level(16, 44), // Bite
+            level(21, 17), // Wing Attack
+            level(26, 109), // Confuse Ray
+            level(31, 314), // Air Cutter
+            level(36, 212), // Mean Look
+            level(41, 305), // Poison Fang
+            level(46, 114), // Haze
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 202, 218, 216, 247,
+            104, 188, 332, 259, 263, 290, 156, 213, 168, 211,
+            289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            98, // Quick Attack
+            228, // Pursuit
+            185, // Feint Attack
+            16, // Gust
+            18, // Whirlwind
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Golbat,
+        level_up: &[
+            level(1, 103), // Screech
+            level(1, 141), // Leech Life
+            level(1, 48), // Supersonic
+            level(1, 310), // Astonish
+            level(6, 48), // Supersonic
+            level(11, 310), // Astonish
+            level(16, 44), // Bite
+            level(21, 17), // Wing Attack
+            level(28, 109), // Confuse Ray
+            level(35, 314), // Air Cutter
+            level(42, 212), // Mean Look
+            level(49, 305), // Poison Fang
+            level(56, 114), // Haze
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 240, 202, 218, 216,
+            247, 104, 188, 332, 259, 263, 290, 156, 213, 168,
+            211, 289, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Oddish,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(7, 230), // Sweet Scent
+            level(14, 77), // Poison Powder
+            level(16, 78), // Stun Spore
+            level(18, 79), // Sleep Powder
+            level(23, 51), // Acid
+            level(32, 236), // Moonlight
+            level(39, 80), // Petal Dance
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            14, // Swords Dance
+            75, // Razor Leaf
+            175, // Flail
+            235, // Synthesis
+            204, // Charm
+            275, // Ingrain
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gloom,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 230), // Sweet Scent
+            level(1, 77), // Poison Powder
+            level(7, 230), // Sweet Scent
+            level(14, 77), // Poison Powder
+            level(16, 78), // Stun Spore
+            level(18, 79), // Sleep Powder
+            level(24, 51), // Acid
+            level(35, 236), // Moonlight
+            level(44, 80), // Petal Dance
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Vileplume,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 312), // Aromatherapy
+            level(1, 78), // Stun Spore
+            level(1, 72), // Mega Drain
+            level(44, 80), // Petal Dance
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 216,
+            104, 188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Paras,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(7, 78), // Stun Spore
+            level(13, 77), // Poison Powder
+            level(19, 141), // Leech Life
+            level(25, 147), // Spore
+            level(31, 163), // Slash
+            level(37, 74), // Growth
+            level(43, 202), // Giga Drain
+            level(49, 312), // Aromatherapy
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 91,
+            280, 104, 188, 332, 263, 290, 156, 213, 168, 15,
+            148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 14, 34, 38, 189, 210, 207, 214,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            206, // False Swipe
+            103, // Screech
+            68, // Counter
+            60, // Psybeam
+            175, // Flail
+            113, // Light Screen
+            228, // Pursuit
+            232, // Metal Claw
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Parasect,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 78), // Stun Spore
+            level(1, 77), // Poison Powder
+            level(7, 78), // Stun Spore
+            level(13, 77), // Poison Powder
+            level(19, 141), // Leech Life
+            level(27, 147), // Spore
+            level(35, 163), // Slash
+            level(43, 74), // Growth
+            level(51, 202), // Giga Drain
+            level(59, 312), // Aromatherapy
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 216,
+            91, 280, 104, 188, 332, 263, 290, 156, 213, 168,
+            15, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 14, 34, 38, 189, 210, 207, 214,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Venonat,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 50), // Disable
+            level(1, 193), // Foresight
+            level(9, 48), // Supersonic
+            level(17, 93), // Confusion
+            level(20, 77), // Poison Powder
+            level(25, 141), // Leech Life
+            level(28, 78), // Stun Spore
+            level(33, 60), // Psybeam
+            level(36, 79), // Sleep Powder
+            level(41, 94), // Psychic
+        ],
+        machines: &[
+            92, 237, 241, 182, 202, 218, 76, 216, 94, 104,
+            188, 263, 290, 156, 213, 168, 285, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            226, // Baton Pass
+            103, // Screech
+            202, // Giga Drain
+            324, // Signal Beam
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Venomoth,
+        level_up: &[
+            level(1, 318), // Silver Wind
+            level(1, 33), // Tackle
+            level(1, 50), // Disable
+            level(1, 193), // Foresight
+            level(1, 48), // Supersonic
+            level(9, 48), // Supersonic
+            level(17, 93), // Confusion
+            level(20, 77), // Poison Powder
+            level(25, 141), // Leech Life
+            level(28, 78), // Stun Spore
+            level(31, 16), // Gust
+            level(36, 60), // Psybeam
+            level(42, 79), // Sleep Powder
+            level(52, 94), // Psychic
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 202, 218, 76, 216, 94,
+            104, 188, 332, 263, 290, 156, 213, 168, 285, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Diglett,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 28), // Sand Attack
+            level(5, 45), // Growl
+            level(9, 222), // Magnitude
+            level(17, 91), // Dig
+            level(21, 189), // Mud-Slap
+            level(25, 163), // Slash
+            level(33, 89), // Earthquake
+            level(41, 90), // Fissure
+            level(49, 201), // Sandstorm
+        ],
+        machines: &[
+            92, 237, 241, 182, 218, 89, 216, 91, 104, 201,
+            317, 259, 263, 290, 156, 213, 168, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            185, // Feint Attack
+            103, // Screech
+            246, // Ancient Power
+            228, // Pursuit
+            251, // Beat Up
+            253, // Uproar
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dugtrio,
+        level_up: &[
+            level(1, 161), // Tri Attack
+            level(1, 10), // Scratch
+            level(1, 28), // Sand Attack
+            level(1, 45), // Growl
+            level(5, 45), // Growl
+            level(9, 222), // Magnitude
+            level(17, 91), // Dig
+            level(21, 189), // Mud-Slap
+            level(25, 163), // Slash
+            level(26, 328), // Sand Tomb
+            level(38, 89), // Earthquake
+            level(51, 90), // Fissure
+            level(64, 201), // Sandstorm
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 218, 89, 216, 91, 104,
+            201, 317, 259, 263, 290, 156, 213, 168, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Meowth,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(11, 44), // Bite
+            level(20, 6), // Pay Day
+            level(28, 185), // Feint Attack
+            level(35, 103), // Screech
+            level(41, 154), // Fury Swipes
+            level(46, 163), // Slash
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 218, 231, 85, 87,
+            216, 91, 247, 104, 351, 332, 259, 263, 290, 156,
+            213, 168, 289, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                223, 68, 102, 164, 5, 25, 34, 38, 189, 210,
+                207, 214, 129, 173, 8, 9, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            180, // Spite
+            204, // Charm
+            95, // Hypnosis
+            133, // Amnesia
+            244, // Psych Up
+            274, // Assist
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Persian,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(1, 44), // Bite
+            level(1, 6), // Pay Day
+            level(11, 44), // Bite
+            level(20, 6), // Pay Day
+            level(29, 185), // Feint Attack
+            level(38, 103), // Screech
+            level(46, 154), // Fury Swipes
+            level(53, 163), // Slash
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 63, 182, 240, 218, 231,
+            85, 87, 216, 91, 247, 104, 351, 332, 259, 263,
+            290, 156, 213, 168, 289, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                223, 68, 102, 164, 5, 25, 34, 38, 189, 210,
+                207, 214, 129, 173, 8, 9, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Psyduck,
+        level_up: &[
+            level(1, 346), // Water Sport
+            level(1, 10), // Scratch
+            level(5, 39), // Tail Whip
+            level(10, 50), // Disable
+            level(16, 93), // Confusion
+            level(23, 103), // Screech
+            level(31, 244), // Psych Up
+            level(40, 154), // Fury Swipes
+            level(50, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 182, 240, 218,
+            231, 216, 91, 280, 104, 332, 263, 290, 156, 213,
+            15, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 189,
+                210, 207, 214, 129, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            95, // Hypnosis
+            60, // Psybeam
+            193, // Foresight
+            113, // Light Screen
+            248, // Future Sight
+            94, // Psychic
+            238, // Cross Chop
+            287, // Refresh
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Golduck,
+        level_up: &[
+            level(1, 346), // Water Sport
+            level(1, 10), // Scratch
+            level(1, 39), // Tail Whip
+            level(1, 50), // Disable
+            level(5, 39), // Tail Whip
+            level(10, 50), // Disable
+            level(16, 93), // Confusion
+            level(23, 103), // Screech
+            level(31, 244), // Psych Up
+            level(44, 154), // Fury Swipes
+            level(58, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 237, 58, 59, 63, 182,
+            240, 218, 231, 216, 91, 94, 280, 104, 332, 263,
+            290, 156, 213, 15, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 189,
+                210, 207, 214, 129, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mankey,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(6, 67), // Low Kick
+            level(11, 2), // Karate Chop
+            level(16, 154), // Fury Swipes
+            level(21, 116), // Focus Energy
+            level(26, 69), // Seismic Toss
+            level(31, 238), // Cross Chop
+            level(36, 103), // Screech
+            level(41, 37), // Thrash
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 269, 182, 218, 231, 85,
+            87, 89, 216, 91, 280, 104, 317, 332, 259, 263,
+            290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 173, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            157, // Rock Slide
+            193, // Foresight
+            96, // Meditate
+            68, // Counter
+            179, // Reversal
+            251, // Beat Up
+            279, // Revenge
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Primeape,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 67), // Low Kick
+            level(1, 99), // Rage
+            level(6, 67), // Low Kick
+            level(11, 2), // Karate Chop
+            level(16, 154), // Fury Swipes
+            level(21, 116), // Focus Energy
+            level(26, 69), // Seismic Toss
+            level(28, 99), // Rage
+            level(35, 238), // Cross Chop
+            level(44, 103), // Screech
+            level(53, 37), // Thrash
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 269, 63, 182, 218, 231,
+            85, 87, 89, 216, 91, 280, 104, 317, 332, 259,
+            263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 173, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Growlithe,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 46), // Roar
+            level(7, 52), // Ember
+            level(13, 43), // Leer
+            level(19, 316), // Odor Sleuth
+            level(25, 36), // Take Down
+            level(31, 172), // Flame Wheel
+            level(37, 270), // Helping Hand
+            level(43, 97), // Agility
+            level(49, 53), // Flamethrower
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 231, 216, 91, 104,
+            53, 126, 259, 263, 290, 156, 213, 168, 315,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            34, // Body Slam
+            219, // Safeguard
+            242, // Crunch
+            37, // Thrash
+            83, // Fire Spin
+            336, // Howl
+            257, // Heat Wave
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Arcanine,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 46), // Roar
+            level(1, 316), // Odor Sleuth
+            level(1, 172), // Flame Wheel
+            level(49, 245), // Extreme Speed
+        ],
+        machines: &[
+            46, 92, 237, 241, 63, 182, 218, 231, 216, 91,
+            104, 53, 126, 259, 263, 290, 156, 213, 168, 315,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Poliwag,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(7, 95), // Hypnosis
+            level(13, 55), // Water Gun
+            level(19, 3), // Double Slap
+            level(25, 240), // Rain Dance
+            level(31, 34), // Body Slam
+            level(37, 187), // Belly Drum
+            level(43, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 237, 58, 59, 182, 240, 218, 216, 91,
+            94, 104, 263, 290, 156, 213, 285, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 173, 244, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            54, // Mist
+            150, // Splash
+            61, // Bubble Beam
+            114, // Haze
+            170, // Mind Reader
+            346, // Water Sport
+            301, // Ice Ball
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Poliwhirl,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 95), // Hypnosis
+            level(1, 55), // Water Gun
+            level(7, 95), // Hypnosis
+            level(13, 55), // Water Gun
+            level(19, 3), // Double Slap
+            level(27, 240), // Rain Dance
+            level(35, 34), // Body Slam
+            level(43, 187), // Belly Drum
+            level(51, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 237, 58, 59, 182, 240, 218, 89,
+            216, 91, 94, 280, 104, 263, 290, 156, 213, 285,
+            57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 207, 214, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Poliwrath,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 95), // Hypnosis
+            level(1, 3), // Double Slap
+            level(1, 66), // Submission
+            level(35, 66), // Submission
+            level(51, 170), // Mind Reader
+        ],
+        machines: &[
+            264, 352, 92, 339, 237, 58, 59, 63, 182, 240,
+            218, 89, 216, 91, 94, 280, 104, 263, 290, 156,
+            213, 285, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 207, 214, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Abra,
+        level_up: &[
+            level(1, 100), // Teleport
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 113, 182, 240, 219,
+            218, 231, 216, 94, 247, 280, 104, 115, 351, 259,
+            263, 290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            227, // Encore
+            112, // Barrier
+            7, // Fire Punch
+            8, // Ice Punch
+            9, // Thunder Punch
+            282, // Knock Off
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kadabra,
+        level_up: &[
+            level(1, 100), // Teleport
+            level(1, 134), // Kinesis
+            level(1, 93), // Confusion
+            level(16, 93), // Confusion
+            level(18, 50), // Disable
+            level(21, 60), // Psybeam
+            level(23, 115), // Reflect
+            level(25, 105), // Recover
+            level(30, 248), // Future Sight
+            level(33, 272), // Role Play
+            level(36, 94), // Psychic
+            level(43, 271), // Trick
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 113, 182, 240, 219,
+            218, 231, 216, 94, 247, 280, 104, 115, 351, 259,
+            263, 290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Alakazam,
+        level_up: &[
+            level(1, 100), // Teleport
+            level(1, 134), // Kinesis
+            level(1, 93), // Confusion
+            level(16, 93), // Confusion
+            level(18, 50), // Disable
+            level(21, 60), // Psybeam
+            level(23, 115), // Reflect
+            level(25, 105), // Recover
+            level(30, 248), // Future Sight
+            level(33, 347), // Calm Mind
+            level(36, 94), // Psychic
+            level(43, 271), // Trick
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 63, 113, 182, 240,
+            219, 218, 231, 216, 94, 247, 280, 104, 115, 351,
+            259, 263, 290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Machop,
+        level_up: &[
+            level(1, 67), // Low Kick
+            level(1, 43), // Leer
+            level(7, 116), // Focus Energy
+            level(13, 2), // Karate Chop
+            level(19, 69), // Seismic Toss
+            level(22, 193), // Foresight
+            level(25, 279), // Revenge
+            level(31, 233), // Vital Throw
+            level(37, 66), // Submission
+            level(40, 238), // Cross Chop
+            level(43, 184), // Scary Face
+            level(49, 223), // Dynamic Punch
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 182, 218, 89, 216, 91,
+            280, 104, 53, 126, 317, 259, 263, 290, 156, 213,
+            168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            96, // Meditate
+            27, // Rolling Kick
+            227, // Encore
+            265, // Smelling Salts
+            68, // Counter
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Machoke,
+        level_up: &[
+            level(1, 67), // Low Kick
+            level(1, 43), // Leer
+            level(1, 116), // Focus Energy
+            level(7, 116), // Focus Energy
+            level(13, 2), // Karate Chop
+            level(19, 69), // Seismic Toss
+            level(22, 193), // Foresight
+            level(25, 279), // Revenge
+            level(33, 233), // Vital Throw
+            level(41, 66), // Submission
+            level(46, 238), // Cross Chop
+            level(51, 184), // Scary Face
+            level(59, 223), // Dynamic Punch
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 182, 218, 89, 216, 91,
+            280, 104, 53, 126, 317, 259, 263, 290, 156, 213,
+            168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Machamp,
+        level_up: &[
+            level(1, 67), // Low Kick
+            level(1, 43), // Leer
+            level(1, 116), // Focus Energy
+            level(7, 116), // Focus Energy
+            level(13, 2), // Karate Chop
+            level(19, 69), // Seismic Toss
+            level(22, 193), // Foresight
+            level(25, 279), // Revenge
+            level(33, 233), // Vital Throw
+            level(41, 66), // Submission
+            level(46, 238), // Cross Chop
+            level(51, 184), // Scary Face
+            level(59, 223), // Dynamic Punch
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 63, 182, 218, 89, 216,
+            91, 280, 104, 53, 126, 317, 259, 263, 290, 156,
+            213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Bellsprout,
+        level_up: &[
+            level(1, 22), // Vine Whip
+            level(6, 74), // Growth
+            level(11, 35), // Wrap
+            level(15, 79), // Sleep Powder
+            level(17, 77), // Poison Powder
+            level(19, 78), // Stun Spore
+            level(23, 51), // Acid
+            level(30, 282), // Knock Off
+            level(37, 230), // Sweet Scent
+            level(45, 75), // Razor Leaf
+            level(53, 21), // Slam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            14, // Swords Dance
+            227, // Encore
+            115, // Reflect
+            235, // Synthesis
+            141, // Leech Life
+            275, // Ingrain
+            345, // Magical Leaf
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Weepinbell,
+        level_up: &[
+            level(1, 22), // Vine Whip
+            level(1, 74), // Growth
+            level(1, 35), // Wrap
+            level(6, 74), // Growth
+            level(11, 35), // Wrap
+            level(15, 79), // Sleep Powder
+            level(17, 77), // Poison Powder
+            level(19, 78), // Stun Spore
+            level(24, 51), // Acid
+            level(33, 282), // Knock Off
+            level(42, 230), // Sweet Scent
+            level(54, 75), // Razor Leaf
+            level(66, 21), // Slam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Victreebel,
+        level_up: &[
+            level(1, 22), // Vine Whip
+            level(1, 79), // Sleep Powder
+            level(1, 230), // Sweet Scent
+            level(1, 75), // Razor Leaf
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 216,
+            104, 188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tentacool,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(6, 48), // Supersonic
+            level(12, 132), // Constrict
+            level(19, 51), // Acid
+            level(25, 61), // Bubble Beam
+            level(30, 35), // Wrap
+            level(36, 112), // Barrier
+            level(43, 103), // Screech
+            level(49, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 237, 58, 59, 182, 240, 202, 218, 216,
+            104, 188, 332, 263, 290, 156, 213, 168, 15, 57,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            62, // Aurora Beam
+            243, // Mirror Coat
+            229, // Rapid Spin
+            114, // Haze
+            219, // Safeguard
+            109, // Confuse Ray
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tentacruel,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 48), // Supersonic
+            level(1, 132), // Constrict
+            level(6, 48), // Supersonic
+            level(12, 132), // Constrict
+            level(19, 51), // Acid
+            level(25, 61), // Bubble Beam
+            level(30, 35), // Wrap
+            level(38, 112), // Barrier
+            level(47, 103), // Screech
+            level(55, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 237, 58, 59, 63, 182, 240, 202, 218,
+            216, 104, 188, 332, 263, 290, 156, 213, 168, 15,
+            57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Geodude,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 111), // Defense Curl
+            level(6, 300), // Mud Sport
+            level(11, 88), // Rock Throw
+            level(16, 222), // Magnitude
+            level(21, 120), // Self-Destruct
+            level(26, 205), // Rollout
+            level(31, 350), // Rock Blast
+            level(36, 89), // Earthquake
+            level(41, 153), // Explosion
+            level(46, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 182, 218, 89, 216, 91, 280,
+            104, 53, 201, 126, 317, 263, 290, 156, 213, 168,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 38, 68, 69, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 157, 164, 5, 34,
+                38, 189, 207, 214, 111, 173, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            5, // Mega Punch
+            157, // Rock Slide
+            335, // Block
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Graveler,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 111), // Defense Curl
+            level(1, 300), // Mud Sport
+            level(1, 88), // Rock Throw
+            level(6, 300), // Mud Sport
+            level(11, 88), // Rock Throw
+            level(16, 222), // Magnitude
+            level(21, 120), // Self-Destruct
+            level(29, 205), // Rollout
+            level(37, 350), // Rock Blast
+            level(45, 89), // Earthquake
+            level(53, 153), // Explosion
+            level(62, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 182, 218, 89, 216, 91, 280,
+            104, 53, 201, 126, 317, 263, 290, 156, 213, 168,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 38, 68, 69, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 157, 164, 5, 34,
+                38, 189, 207, 214, 111, 173, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Golem,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 111), // Defense Curl
+            level(1, 300), // Mud Sport
+            level(1, 88), // Rock Throw
+            level(6, 300), // Mud Sport
+            level(11, 88), // Rock Throw
+            level(16, 222), // Magnitude
+            level(21, 120), // Self-Destruct
+            level(29, 205), // Rollout
+            level(37, 350), // Rock Blast
+            level(45, 89), // Earthquake
+            level(53, 153), // Explosion
+            level(62, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 63, 182, 218, 89, 216, 91,
+            280, 104, 53, 201, 126, 317, 263, 290, 156, 213,
+            168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 157, 164, 5, 25,
+                34, 38, 189, 207, 214, 111, 173, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ponyta,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 45), // Growl
+            level(9, 39), // Tail Whip
+            level(14, 52), // Ember
+            level(19, 23), // Stomp
+            level(25, 83), // Fire Spin
+            level(31, 36), // Take Down
+            level(38, 97), // Agility
+            level(45, 340), // Bounce
+            level(53, 126), // Fire Blast
+        ],
+        machines: &[
+            92, 237, 241, 182, 218, 231, 216, 104, 53, 126,
+            263, 290, 156, 213, 315, 70,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            172, // Flame Wheel
+            37, // Thrash
+            24, // Double Kick
+            95, // Hypnosis
+            204, // Charm
+            38, // Double-Edge
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Rapidash,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 39), // Tail Whip
+            level(1, 52), // Ember
+            level(5, 45), // Growl
+            level(9, 39), // Tail Whip
+            level(14, 52), // Ember
+            level(19, 23), // Stomp
+            level(25, 83), // Fire Spin
+            level(31, 36), // Take Down
+            level(38, 97), // Agility
+            level(40, 31), // Fury Attack
+            level(50, 340), // Bounce
+            level(63, 126), // Fire Blast
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 218, 231, 216, 104, 53,
+            126, 263, 290, 156, 213, 315, 70,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slowpoke,
+        level_up: &[
+            level(1, 174), // Curse
+            level(1, 281), // Yawn
+            level(1, 33), // Tackle
+            level(6, 45), // Growl
+            level(15, 55), // Water Gun
+            level(20, 93), // Confusion
+            level(29, 50), // Disable
+            level(34, 29), // Headbutt
+            level(43, 133), // Amnesia
+            level(48, 94), // Psychic
+        ],
+        machines: &[
+            352, 347, 92, 258, 237, 58, 59, 113, 182, 240,
+            218, 231, 89, 216, 91, 94, 247, 104, 115, 351,
+            53, 126, 263, 290, 156, 213, 285, 57, 70, 148,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 118, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 118, 189, 207, 214,
+                129, 111, 173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            187, // Belly Drum
+            248, // Future Sight
+            23, // Stomp
+            300, // Mud Sport
+            214, // Sleep Talk
+            173, // Snore
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slowbro,
+        level_up: &[
+            level(1, 174), // Curse
+            level(1, 281), // Yawn
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(6, 45), // Growl
+            level(15, 55), // Water Gun
+            level(20, 93), // Confusion
+            level(29, 50), // Disable
+            level(34, 29), // Headbutt
+            level(37, 110), // Withdraw
+            level(46, 133), // Amnesia
+            level(54, 94), // Psychic
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 237, 58, 59, 63, 113,
+            182, 240, 218, 231, 89, 216, 91, 94, 247, 280,
+            104, 115, 351, 53, 126, 263, 290, 156, 213, 285,
+            57, 70, 148, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 129, 111, 173, 8, 7,
+                244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magnemite,
+        level_up: &[
+            level(1, 319), // Metal Sound
+            level(1, 33), // Tackle
+            level(6, 84), // Thunder Shock
+            level(11, 48), // Supersonic
+            level(16, 49), // Sonic Boom
+            level(21, 86), // Thunder Wave
+            level(26, 209), // Spark
+            level(32, 199), // Lock-On
+            level(38, 103), // Screech
+            level(44, 192), // Zap Cannon
+        ],
+        machines: &[
+            92, 237, 113, 182, 240, 218, 85, 87, 216, 104,
+            115, 351, 263, 290, 156, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magneton,
+        level_up: &[
+            level(1, 319), // Metal Sound
+            level(1, 33), // Tackle
+            level(1, 84), // Thunder Shock
+            level(1, 48), // Supersonic
+            level(6, 84), // Thunder Shock
+            level(11, 48), // Supersonic
+            level(16, 49), // Sonic Boom
+            level(21, 86), // Thunder Wave
+            level(26, 209), // Spark
+            level(35, 199), // Lock-On
+            level(44, 161), // Tri Attack
+            level(53, 103), // Screech
+            level(62, 192), // Zap Cannon
+        ],
+        machines: &[
+            92, 237, 63, 113, 182, 240, 218, 85, 87, 216,
+            104, 115, 351, 263, 290, 156, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Farfetchd,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 28), // Sand Attack
+            level(6, 43), // Leer
+            level(11, 31), // Fury Attack
+            level(16, 282), // Knock Off
+            level(21, 210), // Fury Cutter
+            level(26, 14), // Swords Dance
+            level(31, 97), // Agility
+            level(36, 163), // Slash
+            level(41, 206), // False Swipe
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 216, 104, 332, 263,
+            290, 156, 213, 168, 211, 15, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 210, 207, 214, 129,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            211, // Steel Wing
+            193, // Foresight
+            119, // Mirror Move
+            16, // Gust
+            98, // Quick Attack
+            175, // Flail
+            297, // Feather Dance
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Doduo,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(9, 228), // Pursuit
+            level(13, 31), // Fury Attack
+            level(21, 161), // Tri Attack
+            level(25, 99), // Rage
+            level(33, 253), // Uproar
+            level(37, 65), // Drill Peck
+            level(45, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 216, 104, 332, 263,
+            290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            98, // Quick Attack
+            48, // Supersonic
+            114, // Haze
+            185, // Feint Attack
+            175, // Flail
+            283, // Endeavor
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dodrio,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(1, 228), // Pursuit
+            level(1, 31), // Fury Attack
+            level(9, 228), // Pursuit
+            level(13, 31), // Fury Attack
+            level(21, 161), // Tri Attack
+            level(25, 99), // Rage
+            level(38, 253), // Uproar
+            level(47, 65), // Drill Peck
+            level(60, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 240, 218, 216, 104, 332,
+            263, 290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Seel,
+        level_up: &[
+            level(1, 29), // Headbutt
+            level(9, 45), // Growl
+            level(17, 196), // Icy Wind
+            level(21, 62), // Aurora Beam
+            level(29, 156), // Rest
+            level(37, 36), // Take Down
+            level(41, 58), // Ice Beam
+            level(49, 219), // Safeguard
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            122, // Lick
+            195, // Perish Song
+            50, // Disable
+            64, // Peck
+            21, // Slam
+            227, // Encore
+            252, // Fake Out
+            333, // Icicle Spear
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dewgong,
+        level_up: &[
+            level(1, 29), // Headbutt
+            level(1, 45), // Growl
+            level(1, 196), // Icy Wind
+            level(1, 62), // Aurora Beam
+            level(9, 45), // Growl
+            level(17, 196), // Icy Wind
+            level(21, 62), // Aurora Beam
+            level(29, 156), // Rest
+            level(34, 329), // Sheer Cold
+            level(42, 36), // Take Down
+            level(51, 58), // Ice Beam
+            level(64, 219), // Safeguard
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 263, 290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Grimer,
+        level_up: &[
+            level(1, 139), // Poison Gas
+            level(1, 1), // Pound
+            level(4, 106), // Harden
+            level(8, 50), // Disable
+            level(13, 124), // Sludge
+            level(19, 107), // Minimize
+            level(26, 103), // Screech
+            level(34, 151), // Acid Armor
+            level(43, 188), // Sludge Bomb
+            level(53, 262), // Memento
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 202, 218, 85, 87,
+            216, 247, 104, 53, 188, 126, 317, 259, 263, 290,
+            156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 102, 153, 164, 5, 34, 189, 207, 214, 173,
+                8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            114, // Haze
+            212, // Mean Look
+            122, // Lick
+            286, // Imprison
+            174, // Curse
+            325, // Shadow Punch
+            153, // Explosion
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Muk,
+        level_up: &[
+            level(1, 139), // Poison Gas
+            level(1, 1), // Pound
+            level(1, 106), // Harden
+            level(4, 106), // Harden
+            level(8, 50), // Disable
+            level(13, 124), // Sludge
+            level(19, 107), // Minimize
+            level(26, 103), // Screech
+            level(34, 151), // Acid Armor
+            level(47, 188), // Sludge Bomb
+            level(61, 262), // Memento
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 240, 202, 218, 85,
+            87, 216, 247, 280, 104, 53, 188, 126, 317, 259,
+            263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 102, 153, 164, 5, 34, 189, 207, 214, 173,
+                8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shellder,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 110), // Withdraw
+            level(8, 48), // Supersonic
+            level(15, 62), // Aurora Beam
+            level(22, 182), // Protect
+            level(29, 43), // Leer
+            level(36, 128), // Clamp
+            level(43, 58), // Ice Beam
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 115, 263, 290, 156, 213, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 153, 164,
+            ],
+            emerald: &[
+                102, 153, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            61, // Bubble Beam
+            36, // Take Down
+            112, // Barrier
+            229, // Rapid Spin
+            103, // Screech
+            333, // Icicle Spear
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cloyster,
+        level_up: &[
+            level(1, 110), // Withdraw
+            level(1, 48), // Supersonic
+            level(1, 62), // Aurora Beam
+            level(1, 182), // Protect
+            level(33, 191), // Spikes
+            level(41, 131), // Spike Cannon
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 115, 263, 290, 156, 213, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 153, 164,
+            ],
+            emerald: &[
+                102, 153, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gastly,
+        level_up: &[
+            level(1, 95), // Hypnosis
+            level(1, 122), // Lick
+            level(8, 180), // Spite
+            level(13, 212), // Mean Look
+            level(16, 174), // Curse
+            level(21, 101), // Night Shade
+            level(28, 109), // Confuse Ray
+            level(33, 138), // Dream Eater
+            level(36, 194), // Destiny Bond
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 202, 218, 85, 87,
+            216, 94, 247, 104, 188, 259, 263, 290, 156, 213,
+            168, 285, 289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                102, 138, 153, 164,
+            ],
+            emerald: &[
+                102, 138, 153, 164, 207, 214, 173, 244, 203,
+            ],
+            xd: &[
+                102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[
+            149, // Psywave
+            195, // Perish Song
+            114, // Haze
+            310, // Astonish
+            261, // Will-O-Wisp
+            288, // Grudge
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Haunter,
+        level_up: &[
+            level(1, 95), // Hypnosis
+            level(1, 122), // Lick
+            level(1, 180), // Spite
+            level(8, 180), // Spite
+            level(13, 212), // Mean Look
+            level(16, 174), // Curse
+            level(21, 101), // Night Shade
+            level(25, 325), // Shadow Punch
+            level(31, 109), // Confuse Ray
+            level(39, 138), // Dream Eater
+            level(48, 194), // Destiny Bond
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 202, 218, 85, 87,
+            216, 94, 247, 104, 188, 259, 263, 290, 156, 213,
+            168, 285, 289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 102, 138, 153, 164,
+            ],
+            emerald: &[
+                223, 102, 138, 153, 164, 5, 207, 214, 173, 8,
+                9, 7, 244, 203,
+            ],
+            xd: &[
+                102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gengar,
+        level_up: &[
+            level(1, 95), // Hypnosis
+            level(1, 122), // Lick
+            level(1, 180), // Spite
+            level(8, 180), // Spite
+            level(13, 212), // Mean Look
+            level(16, 174), // Curse
+            level(21, 101), // Night Shade
+            level(25, 325), // Shadow Punch
+            level(31, 109), // Confuse Ray
+            level(39, 138), // Dream Eater
+            level(48, 194), // Destiny Bond
+        ],
+        machines: &[
+            264, 92, 237, 241, 269, 63, 182, 240, 202, 218,
+            85, 87, 216, 94, 247, 280, 104, 188, 259, 263,
+            290, 156, 213, 168, 285, 289, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 153,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 153, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Onix,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 103), // Screech
+            level(9, 20), // Bind
+            level(13, 88), // Rock Throw
+            level(21, 106), // Harden
+            level(25, 99), // Rage
+            level(33, 201), // Sandstorm
+            level(37, 21), // Slam
+            level(41, 231), // Iron Tail
+            level(45, 328), // Sand Tomb
+            level(49, 38), // Double-Edge
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 231, 89, 216, 91,
+            104, 201, 317, 259, 263, 290, 156, 213, 15, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            157, // Rock Slide
+            175, // Flail
+            335, // Block
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Drowzee,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 95), // Hypnosis
+            level(10, 50), // Disable
+            level(18, 93), // Confusion
+            level(25, 29), // Headbutt
+            level(31, 139), // Poison Gas
+            level(36, 96), // Meditate
+            level(40, 94), // Psychic
+            level(43, 244), // Psych Up
+            level(45, 207), // Swagger
+            level(50, 248), // Future Sight
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 113, 182, 240, 219,
+            218, 216, 94, 247, 280, 104, 115, 351, 259, 263,
+            290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            112, // Barrier
+            274, // Assist
+            272, // Role Play
+            7, // Fire Punch
+            9, // Thunder Punch
+            8, // Ice Punch
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hypno,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 95), // Hypnosis
+            level(1, 50), // Disable
+            level(1, 93), // Confusion
+            level(10, 50), // Disable
+            level(18, 93), // Confusion
+            level(25, 29), // Headbutt
+            level(33, 139), // Poison Gas
+            level(40, 96), // Meditate
+            level(49, 94), // Psychic
+            level(55, 244), // Psych Up
+            level(60, 207), // Swagger
+            level(69, 248), // Future Sight
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 63, 113, 182, 240,
+            219, 218, 216, 94, 247, 280, 104, 115, 351, 259,
+            263, 290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Krabby,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(5, 43), // Leer
+            level(12, 11), // Vice Grip
+            level(16, 106), // Harden
+            level(23, 341), // Mud Shot
+            level(27, 23), // Stomp
+            level(34, 12), // Guillotine
+            level(41, 182), // Protect
+            level(45, 152), // Crabhammer
+            level(49, 175), // Flail
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            91, 280, 104, 263, 290, 156, 213, 168, 15, 57,
+            70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 210, 207, 214, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            91, // Dig
+            114, // Haze
+            133, // Amnesia
+            175, // Flail
+            21, // Slam
+            282, // Knock Off
+            14, // Swords Dance
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kingler,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 43), // Leer
+            level(1, 11), // Vice Grip
+            level(5, 43), // Leer
+            level(12, 11), // Vice Grip
+            level(16, 106), // Harden
+            level(23, 341), // Mud Shot
+            level(27, 23), // Stomp
+            level(38, 12), // Guillotine
+            level(49, 182), // Protect
+            level(57, 152), // Crabhammer
+            level(65, 175), // Flail
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 91, 280, 104, 263, 290, 156, 213, 168, 15,
+            57, 70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 210, 207, 214, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Voltorb,
+        level_up: &[
+            level(1, 268), // Charge
+            level(1, 33), // Tackle
+            level(8, 103), // Screech
+            level(15, 49), // Sonic Boom
+            level(21, 209), // Spark
+            level(27, 120), // Self-Destruct
+            level(32, 205), // Rollout
+            level(37, 113), // Light Screen
+            level(42, 129), // Swift
+            level(46, 153), // Explosion
+            level(49, 243), // Mirror Coat
+        ],
+        machines: &[
+            92, 237, 269, 113, 182, 240, 218, 85, 87, 216,
+            104, 351, 259, 263, 290, 156, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                102, 86, 153, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 153, 164, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                102, 86, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Electrode,
+        level_up: &[
+            level(1, 268), // Charge
+            level(1, 33), // Tackle
+            level(1, 103), // Screech
+            level(1, 49), // Sonic Boom
+            level(8, 103), // Screech
+            level(15, 49), // Sonic Boom
+            level(21, 209), // Spark
+            level(27, 120), // Self-Destruct
+            level(34, 205), // Rollout
+            level(41, 113), // Light Screen
+            level(48, 129), // Swift
+            level(54, 153), // Explosion
+            level(59, 243), // Mirror Coat
+        ],
+        machines: &[
+            92, 237, 269, 63, 113, 182, 240, 218, 85, 87,
+            216, 104, 351, 259, 263, 290, 156, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                102, 86, 153, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 153, 164, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                102, 86, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Exeggcute,
+        level_up: &[
+            level(1, 140), // Barrage
+            level(1, 253), // Uproar
+            level(1, 95), // Hypnosis
+            level(7, 115), // Reflect
+            level(13, 73), // Leech Seed
+            level(19, 93), // Confusion
+            level(25, 78), // Stun Spore
+            level(31, 77), // Poison Powder
+            level(37, 79), // Sleep Powder
+            level(43, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 94,
+            104, 115, 263, 290, 156, 213, 168, 285, 70, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 153, 164,
+            ],
+            emerald: &[
+                102, 138, 153, 164, 38, 207, 214, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[
+            235, // Synthesis
+            236, // Moonlight
+            115, // Reflect
+            246, // Ancient Power
+            244, // Psych Up
+            275, // Ingrain
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Exeggutor,
+        level_up: &[
+            level(1, 140), // Barrage
+            level(1, 95), // Hypnosis
+            level(1, 93), // Confusion
+            level(19, 23), // Stomp
+            level(31, 121), // Egg Bomb
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 216,
+            94, 104, 115, 263, 290, 156, 213, 168, 285, 70,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 153, 164,
+            ],
+            emerald: &[
+                102, 138, 153, 164, 38, 207, 214, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cubone,
+        level_up: &[
+            level(1, 45), // Growl
+            level(5, 39), // Tail Whip
+            level(9, 125), // Bone Club
+            level(13, 29), // Headbutt
+            level(17, 43), // Leer
+            level(21, 116), // Focus Energy
+            level(25, 155), // Bonemerang
+            level(29, 99), // Rage
+            level(33, 206), // False Swipe
+            level(37, 37), // Thrash
+            level(41, 198), // Bone Rush
+            level(45, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 58, 59, 182, 218, 231, 89,
+            216, 91, 280, 104, 53, 201, 126, 317, 259, 263,
+            290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            157, // Rock Slide
+            246, // Ancient Power
+            187, // Belly Drum
+            103, // Screech
+            130, // Skull Bash
+            195, // Perish Song
+            14, // Swords Dance
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Marowak,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 39), // Tail Whip
+            level(1, 125), // Bone Club
+            level(1, 29), // Headbutt
+            level(5, 39), // Tail Whip
+            level(9, 125), // Bone Club
+            level(13, 29), // Headbutt
+            level(17, 43), // Leer
+            level(21, 116), // Focus Energy
+            level(25, 155), // Bonemerang
+            level(32, 99), // Rage
+            level(39, 206), // False Swipe
+            level(46, 37), // Thrash
+            level(53, 198), // Bone Rush
+            level(61, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 58, 59, 63, 182, 218, 231,
+            89, 216, 91, 280, 104, 53, 201, 126, 317, 259,
+            263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hitmonlee,
+        level_up: &[
+            level(1, 279), // Revenge
+            level(1, 24), // Double Kick
+            level(6, 96), // Meditate
+            level(11, 27), // Rolling Kick
+            level(16, 26), // Jump Kick
+            level(20, 280), // Brick Break
+            level(21, 116), // Focus Energy
+            level(26, 136), // High Jump Kick
+            level(31, 170), // Mind Reader
+            level(36, 193), // Foresight
+            level(41, 203), // Endure
+            level(46, 25), // Mega Kick
+            level(51, 179), // Reversal
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 182, 218, 89, 216, 91,
+            280, 104, 317, 332, 259, 263, 290, 156, 213, 168,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hitmonchan,
+        level_up: &[
+            level(1, 279), // Revenge
+            level(1, 4), // Comet Punch
+            level(7, 97), // Agility
+            level(13, 228), // Pursuit
+            level(20, 183), // Mach Punch
+            level(26, 9), // Thunder Punch
+            level(26, 8), // Ice Punch
+            level(26, 7), // Fire Punch
+            level(32, 327), // Sky Uppercut
+            level(38, 5), // Mega Punch
+            level(44, 197), // Detect
+            level(50, 68), // Counter
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 182, 218, 89, 216, 91,
+            280, 104, 317, 332, 259, 263, 290, 156, 213, 168,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lickitung,
+        level_up: &[
+            level(1, 122), // Lick
+            level(7, 48), // Supersonic
+            level(12, 111), // Defense Curl
+            level(18, 282), // Knock Off
+            level(23, 23), // Stomp
+            level(29, 35), // Wrap
+            level(34, 50), // Disable
+            level(40, 21), // Slam
+            level(45, 103), // Screech
+            level(51, 287), // Refresh
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 241, 58, 59, 63, 182,
+            240, 218, 76, 231, 85, 87, 89, 216, 91, 280,
+            104, 351, 53, 201, 126, 317, 259, 263, 290, 156,
+            213, 168, 15, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 189, 210, 207, 214, 111, 173, 8, 9,
+                7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            187, // Belly Drum
+            222, // Magnitude
+            34, // Body Slam
+            174, // Curse
+            265, // Smelling Salts
+            214, // Sleep Talk
+            173, // Snore
+            164, // Substitute
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Koffing,
+        level_up: &[
+            level(1, 139), // Poison Gas
+            level(1, 33), // Tackle
+            level(9, 123), // Smog
+            level(17, 120), // Self-Destruct
+            level(21, 124), // Sludge
+            level(25, 108), // Smokescreen
+            level(33, 114), // Haze
+            level(41, 153), // Explosion
+            level(45, 194), // Destiny Bond
+            level(49, 262), // Memento
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 218, 85, 87, 216,
+            247, 104, 53, 188, 126, 259, 263, 290, 156, 213,
+            168,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                102, 153, 164,
+            ],
+            emerald: &[
+                102, 153, 164, 207, 214, 173, 203,
+            ],
+            xd: &[
+                102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            103, // Screech
+            149, // Psywave
+            60, // Psybeam
+            194, // Destiny Bond
+            220, // Pain Split
+            261, // Will-O-Wisp
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Weezing,
+        level_up: &[
+            level(1, 139), // Poison Gas
+            level(1, 33), // Tackle
+            level(1, 123), // Smog
+            level(1, 120), // Self-Destruct
+            level(9, 123), // Smog
+            level(17, 120), // Self-Destruct
+            level(21, 124), // Sludge
+            level(25, 108), // Smokescreen
+            level(33, 114), // Haze
+            level(44, 153), // Explosion
+            level(51, 194), // Destiny Bond
+            level(58, 262), // Memento
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 240, 218, 85, 87,
+            216, 247, 104, 53, 188, 126, 259, 263, 290, 156,
+            213, 168,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                102, 153, 164,
+            ],
+            emerald: &[
+                102, 153, 164, 207, 214, 173, 203,
+            ],
+            xd: &[
+                102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Rhyhorn,
+        level_up: &[
+            level(1, 30), // Horn Attack
+            level(1, 39), // Tail Whip
+            level(10, 23), // Stomp
+            level(15, 31), // Fury Attack
+            level(24, 184), // Scary Face
+            level(29, 350), // Rock Blast
+            level(38, 32), // Horn Drill
+            level(43, 36), // Take Down
+            level(52, 89), // Earthquake
+            level(57, 224), // Megahorn
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 182, 218, 231, 85, 87,
+            89, 216, 91, 104, 351, 53, 201, 126, 317, 259,
+            263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 157, 164,
+            ],
+            emerald: &[
+                68, 102, 157, 164, 34, 38, 189, 207, 214, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            242, // Crunch
+            179, // Reversal
+            157, // Rock Slide
+            37, // Thrash
+            228, // Pursuit
+            68, // Counter
+            222, // Magnitude
+            207, // Swagger
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Rhydon,
+        level_up: &[
+            level(1, 30), // Horn Attack
+            level(1, 39), // Tail Whip
+            level(1, 23), // Stomp
+            level(1, 31), // Fury Attack
+            level(10, 23), // Stomp
+            level(15, 31), // Fury Attack
+            level(24, 184), // Scary Face
+            level(29, 350), // Rock Blast
+            level(38, 32), // Horn Drill
+            level(46, 36), // Take Down
+            level(58, 89), // Earthquake
+            level(66, 224), // Megahorn
+        ],
+        machines: &[
+            264, 352, 46, 92, 237, 241, 269, 58, 59, 63,
+            182, 218, 231, 85, 87, 89, 216, 91, 280, 104,
+            351, 53, 201, 126, 317, 259, 263, 290, 156, 213,
+            15, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 210, 207, 214, 173, 8, 9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Chansey,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 45), // Growl
+            level(5, 39), // Tail Whip
+            level(9, 287), // Refresh
+            level(13, 135), // Soft-Boiled
+            level(17, 3), // Double Slap
+            level(23, 107), // Minimize
+            level(29, 47), // Sing
+            level(35, 121), // Egg Bomb
+            level(41, 111), // Defense Curl
+            level(47, 113), // Light Screen
+            level(57, 38), // Double-Edge
+        ],
+        machines: &[
+            352, 347, 92, 258, 237, 241, 58, 59, 63, 113,
+            182, 240, 219, 218, 76, 85, 87, 89, 216, 94,
+            247, 280, 104, 351, 53, 201, 126, 317, 263, 290,
+            156, 213, 285, 289, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 157, 164,
+                5, 25, 34, 38, 118, 189, 207, 214, 111, 173,
+                8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            217, // Present
+            118, // Metronome
+            215, // Heal Bell
+            312, // Aromatherapy
+            164, // Substitute
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tangela,
+        level_up: &[
+            level(1, 275), // Ingrain
+            level(1, 132), // Constrict
+            level(4, 79), // Sleep Powder
+            level(10, 71), // Absorb
+            level(13, 74), // Growth
+            level(19, 77), // Poison Powder
+            level(22, 22), // Vine Whip
+            level(28, 20), // Bind
+            level(31, 72), // Mega Drain
+            level(37, 78), // Stun Spore
+            level(40, 21), // Slam
+            level(46, 321), // Tickle
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 259, 263, 290, 156, 213, 168, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            175, // Flail
+            93, // Confusion
+            72, // Mega Drain
+            115, // Reflect
+            133, // Amnesia
+            73, // Leech Seed
+            267, // Nature Power
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kangaskhan,
+        level_up: &[
+            level(1, 4), // Comet Punch
+            level(1, 43), // Leer
+            level(7, 44), // Bite
+            level(13, 39), // Tail Whip
+            level(19, 252), // Fake Out
+            level(25, 5), // Mega Punch
+            level(31, 99), // Rage
+            level(37, 203), // Endure
+            level(43, 146), // Dizzy Punch
+            level(49, 179), // Reversal
+        ],
+        machines: &[
+            264, 352, 46, 92, 258, 237, 241, 58, 59, 63,
+            182, 240, 218, 76, 231, 85, 87, 89, 216, 91,
+            247, 280, 104, 351, 53, 201, 126, 317, 259, 263,
+            290, 156, 213, 168, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 210, 207, 214, 173, 8, 9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            23, // Stomp
+            193, // Foresight
+            116, // Focus Energy
+            219, // Safeguard
+            50, // Disable
+            68, // Counter
+            306, // Crush Claw
+            164, // Substitute
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Horsea,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(8, 108), // Smokescreen
+            level(15, 43), // Leer
+            level(22, 55), // Water Gun
+            level(29, 239), // Twister
+            level(36, 97), // Agility
+            level(43, 56), // Hydro Pump
+            level(50, 349), // Dragon Dance
+        ],
+        machines: &[
+            337, 352, 92, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            175, // Flail
+            62, // Aurora Beam
+            190, // Octazooka
+            50, // Disable
+            150, // Splash
+            82, // Dragon Rage
+            225, // Dragon Breath
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Seadra,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 108), // Smokescreen
+            level(1, 43), // Leer
+            level(1, 55), // Water Gun
+            level(8, 108), // Smokescreen
+            level(15, 43), // Leer
+            level(22, 55), // Water Gun
+            level(29, 239), // Twister
+            level(40, 97), // Agility
+            level(51, 56), // Hydro Pump
+            level(62, 349), // Dragon Dance
+        ],
+        machines: &[
+            337, 352, 92, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Goldeen,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 39), // Tail Whip
+            level(1, 346), // Water Sport
+            level(10, 48), // Supersonic
+            level(15, 30), // Horn Attack
+            level(24, 175), // Flail
+            level(29, 31), // Fury Attack
+            level(38, 127), // Waterfall
+            level(43, 32), // Horn Drill
+            level(52, 97), // Agility
+        ],
+        machines: &[
+            352, 92, 237, 58, 59, 182, 240, 218, 216, 104,
+            263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            60, // Psybeam
+            114, // Haze
+            56, // Hydro Pump
+            214, // Sleep Talk
+            300, // Mud Sport
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Seaking,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 39), // Tail Whip
+            level(1, 346), // Water Sport
+            level(1, 48), // Supersonic
+            level(10, 48), // Supersonic
+            level(15, 30), // Horn Attack
+            level(24, 175), // Flail
+            level(29, 31), // Fury Attack
+            level(41, 127), // Waterfall
+            level(49, 32), // Horn Drill
+            level(61, 97), // Agility
+        ],
+        machines: &[
+            352, 92, 237, 58, 59, 63, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Staryu,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(6, 55), // Water Gun
+            level(10, 229), // Rapid Spin
+            level(15, 105), // Recover
+            level(19, 293), // Camouflage
+            level(24, 129), // Swift
+            level(28, 61), // Bubble Beam
+            level(33, 107), // Minimize
+            level(37, 113), // Light Screen
+            level(42, 322), // Cosmic Power
+            level(46, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 113, 182, 240, 218,
+            85, 87, 216, 94, 104, 115, 351, 263, 290, 156,
+            57, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 129, 173, 244, 203,
+                196,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Starmie,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 229), // Rapid Spin
+            level(1, 105), // Recover
+            level(1, 129), // Swift
+            level(33, 109), // Confuse Ray
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 113, 182, 240,
+            218, 85, 87, 216, 94, 247, 104, 115, 351, 263,
+            290, 156, 285, 57, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 173, 244,
+                203, 196,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::MrMime,
+        level_up: &[
+            level(1, 112), // Barrier
+            level(5, 93), // Confusion
+            level(9, 164), // Substitute
+            level(13, 96), // Meditate
+            level(17, 3), // Double Slap
+            level(21, 113), // Light Screen
+            level(21, 115), // Reflect
+            level(25, 227), // Encore
+            level(29, 60), // Psybeam
+            level(33, 226), // Baton Pass
+            level(37, 272), // Role Play
+            level(41, 94), // Psychic
+            level(45, 271), // Trick
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 113, 182, 240, 219,
+            218, 76, 85, 87, 216, 94, 247, 280, 104, 115,
+            351, 259, 263, 290, 156, 213, 168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            248, // Future Sight
+            95, // Hypnosis
+            102, // Mimic
+            244, // Psych Up
+            252, // Fake Out
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Scyther,
+        level_up: &[
+            level(1, 98), // Quick Attack
+            level(1, 43), // Leer
+            level(6, 116), // Focus Energy
+            level(11, 228), // Pursuit
+            level(16, 206), // False Swipe
+            level(21, 97), // Agility
+            level(26, 17), // Wing Attack
+            level(31, 163), // Slash
+            level(36, 14), // Swords Dance
+            level(41, 104), // Double Team
+            level(46, 210), // Fury Cutter
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 218, 216, 104, 332,
+            259, 263, 290, 156, 213, 168, 211, 15,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 14, 34, 38, 189, 210, 207, 214,
+                129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            68, // Counter
+            219, // Safeguard
+            226, // Baton Pass
+            13, // Razor Wind
+            179, // Reversal
+            113, // Light Screen
+            203, // Endure
+            318, // Silver Wind
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Jynx,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 122), // Lick
+            level(1, 142), // Lovely Kiss
+            level(1, 181), // Powder Snow
+            level(9, 142), // Lovely Kiss
+            level(13, 181), // Powder Snow
+            level(21, 3), // Double Slap
+            level(25, 8), // Ice Punch
+            level(35, 212), // Mean Look
+            level(41, 34), // Body Slam
+            level(51, 195), // Perish Song
+            level(57, 59), // Blizzard
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 216, 94, 247, 280, 104,
+            115, 259, 263, 290, 156, 213, 168, 285, 289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 164, 5, 25, 34, 38,
+                118, 207, 214, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Electabuzz,
+        level_up: &[
+            level(1, 98), // Quick Attack
+            level(1, 43), // Leer
+            level(1, 9), // Thunder Punch
+            level(9, 9), // Thunder Punch
+            level(17, 113), // Light Screen
+            level(25, 129), // Swift
+            level(36, 103), // Screech
+            level(47, 85), // Thunderbolt
+            level(58, 87), // Thunder
+        ],
+        machines: &[
+            264, 92, 237, 63, 113, 182, 240, 218, 231, 85,
+            87, 216, 94, 280, 104, 351, 259, 263, 290, 156,
+            213, 168, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 86, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 86, 164, 5, 25, 34, 38,
+                118, 207, 214, 129, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magmar,
+        level_up: &[
+            level(1, 52), // Ember
+            level(1, 43), // Leer
+            level(1, 123), // Smog
+            level(1, 7), // Fire Punch
+            level(7, 43), // Leer
+            level(13, 123), // Smog
+            level(19, 7), // Fire Punch
+            level(25, 108), // Smokescreen
+            level(33, 241), // Sunny Day
+            level(41, 53), // Flamethrower
+            level(49, 109), // Confuse Ray
+            level(57, 126), // Fire Blast
+        ],
+        machines: &[
+            264, 92, 237, 241, 63, 182, 218, 231, 216, 94,
+            280, 104, 53, 126, 259, 263, 290, 156, 213, 168,
+            315, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                207, 214, 173, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pinsir,
+        level_up: &[
+            level(1, 11), // Vice Grip
+            level(1, 116), // Focus Energy
+            level(7, 20), // Bind
+            level(13, 69), // Seismic Toss
+            level(19, 106), // Harden
+            level(25, 279), // Revenge
+            level(31, 280), // Brick Break
+            level(37, 12), // Guillotine
+            level(43, 66), // Submission
+            level(49, 14), // Swords Dance
+        ],
+        machines: &[
+            92, 339, 237, 241, 63, 182, 218, 89, 216, 91,
+            280, 104, 317, 259, 263, 290, 156, 213, 168, 15,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                69, 102, 157, 164, 14, 34, 38, 189, 210, 207,
+                214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            31, // Fury Attack
+            175, // Flail
+            206, // False Swipe
+            185, // Feint Attack
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tauros,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 39), // Tail Whip
+            level(8, 99), // Rage
+            level(13, 30), // Horn Attack
+            level(19, 184), // Scary Face
+            level(26, 228), // Pursuit
+            level(34, 156), // Rest
+            level(43, 37), // Thrash
+            level(53, 36), // Take Down
+        ],
+        machines: &[
+            352, 46, 92, 237, 241, 58, 59, 63, 182, 240,
+            218, 76, 231, 85, 87, 89, 216, 104, 351, 53,
+            201, 126, 317, 263, 290, 156, 213, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 102, 157, 164,
+            ],
+            emerald: &[
+                68, 102, 157, 164, 34, 38, 189, 207, 214, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magikarp,
+        level_up: &[
+            level(1, 150), // Splash
+            level(15, 33), // Tackle
+            level(30, 175), // Flail
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gyarados,
+        level_up: &[
+            level(1, 37), // Thrash
+            level(20, 44), // Bite
+            level(25, 82), // Dragon Rage
+            level(30, 43), // Leer
+            level(35, 239), // Twister
+            level(40, 56), // Hydro Pump
+            level(45, 240), // Rain Dance
+            level(50, 349), // Dragon Dance
+            level(55, 63), // Hyper Beam
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 269, 58, 59, 63, 182,
+            240, 218, 85, 87, 89, 216, 104, 53, 126, 263,
+            290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lapras,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 45), // Growl
+            level(1, 47), // Sing
+            level(7, 54), // Mist
+            level(13, 34), // Body Slam
+            level(19, 109), // Confuse Ray
+            level(25, 195), // Perish Song
+            level(31, 58), // Ice Beam
+            level(37, 240), // Rain Dance
+            level(43, 219), // Safeguard
+            level(49, 56), // Hydro Pump
+            level(55, 329), // Sheer Cold
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 219,
+            218, 231, 85, 87, 89, 216, 94, 104, 263, 290,
+            156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 207, 214, 173, 244,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            193, // Foresight
+            321, // Tickle
+            287, // Refresh
+            349, // Dragon Dance
+            174, // Curse
+            214, // Sleep Talk
+            32, // Horn Drill
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ditto,
+        level_up: &[
+            level(1, 144), // Transform
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Eevee,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 45), // Growl
+            level(23, 98), // Quick Attack
+            level(30, 44), // Bite
+            level(36, 226), // Baton Pass
+            level(42, 36), // Take Down
+        ],
+        machines: &[
+            92, 237, 241, 182, 240, 218, 231, 216, 91, 247,
+            104, 263, 290, 156, 213, 168,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            204, // Charm
+            175, // Flail
+            203, // Endure
+            174, // Curse
+            321, // Tickle
+            273, // Wish
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Vaporeon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 55), // Water Gun
+            level(23, 98), // Quick Attack
+            level(30, 44), // Bite
+            level(36, 62), // Aurora Beam
+            level(42, 114), // Haze
+            level(47, 151), // Acid Armor
+            level(52, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 241, 58, 59, 63, 182,
+            240, 218, 231, 216, 91, 247, 104, 263, 290, 156,
+            213, 168, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Jolteon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 84), // Thunder Shock
+            level(23, 98), // Quick Attack
+            level(30, 24), // Double Kick
+            level(36, 42), // Pin Missile
+            level(42, 86), // Thunder Wave
+            level(47, 97), // Agility
+            level(52, 87), // Thunder
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 241, 58, 59, 63, 113,
+            182, 240, 218, 231, 85, 87, 216, 91, 247, 104,
+            351, 263, 290, 156, 213, 168, 57, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 189, 207, 214, 129, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Flareon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 52), // Ember
+            level(23, 98), // Quick Attack
+            level(30, 44), // Bite
+            level(36, 83), // Fire Spin
+            level(42, 123), // Smog
+            level(47, 43), // Leer
+            level(52, 53), // Flamethrower
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 241, 58, 59, 63, 113,
+            182, 240, 218, 231, 85, 87, 216, 91, 247, 104,
+            351, 53, 126, 263, 290, 156, 213, 168, 315, 57,
+            70, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 189, 207, 214, 129, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Porygon,
+        level_up: &[
+            level(1, 176), // Conversion 2
+            level(1, 33), // Tackle
+            level(1, 160), // Conversion
+            level(9, 97), // Agility
+            level(12, 60), // Psybeam
+            level(20, 105), // Recover
+            level(24, 159), // Sharpen
+            level(32, 199), // Lock-On
+            level(36, 161), // Tri Attack
+            level(44, 278), // Recycle
+            level(48, 192), // Zap Cannon
+        ],
+        machines: &[
+            92, 237, 58, 59, 113, 182, 240, 218, 231, 85,
+            87, 216, 94, 247, 104, 115, 351, 263, 290, 156,
+            168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Omanyte,
+        level_up: &[
+            level(1, 132), // Constrict
+            level(1, 110), // Withdraw
+            level(13, 44), // Bite
+            level(19, 55), // Water Gun
+            level(25, 341), // Mud Shot
+            level(31, 43), // Leer
+            level(37, 182), // Protect
+            level(43, 321), // Tickle
+            level(49, 246), // Ancient Power
+            level(55, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 201, 317, 263, 290, 156, 213, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            61, // Bubble Beam
+            62, // Aurora Beam
+            21, // Slam
+            48, // Supersonic
+            114, // Haze
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Omastar,
+        level_up: &[
+            level(1, 132), // Constrict
+            level(1, 110), // Withdraw
+            level(1, 44), // Bite
+            level(13, 44), // Bite
+            level(19, 55), // Water Gun
+            level(25, 341), // Mud Shot
+            level(31, 43), // Leer
+            level(37, 182), // Protect
+            level(40, 131), // Spike Cannon
+            level(46, 321), // Tickle
+            level(55, 246), // Ancient Power
+            level(65, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 201, 317, 263, 290, 156, 213, 57, 249,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 69, 102, 157, 164, 34, 38, 189, 207, 214,
+                173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kabuto,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(13, 71), // Absorb
+            level(19, 43), // Leer
+            level(25, 341), // Mud Shot
+            level(31, 28), // Sand Attack
+            level(37, 203), // Endure
+            level(43, 319), // Metal Sound
+            level(49, 72), // Mega Drain
+            level(55, 246), // Ancient Power
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 202, 218,
+            216, 104, 201, 317, 263, 290, 156, 213, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            61, // Bubble Beam
+            62, // Aurora Beam
+            229, // Rapid Spin
+            91, // Dig
+            175, // Flail
+            282, // Knock Off
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kabutops,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(1, 71), // Absorb
+            level(13, 71), // Absorb
+            level(19, 43), // Leer
+            level(25, 341), // Mud Shot
+            level(31, 28), // Sand Attack
+            level(37, 203), // Endure
+            level(40, 163), // Slash
+            level(46, 319), // Metal Sound
+            level(55, 72), // Mega Drain
+            level(65, 246), // Ancient Power
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 202,
+            218, 216, 280, 104, 201, 317, 332, 263, 290, 156,
+            213, 15, 57, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                69, 102, 157, 164, 14, 34, 38, 189, 210, 207,
+                214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Aerodactyl,
+        level_up: &[
+            level(1, 17), // Wing Attack
+            level(8, 97), // Agility
+            level(15, 44), // Bite
+            level(22, 48), // Supersonic
+            level(29, 246), // Ancient Power
+            level(36, 184), // Scary Face
+            level(43, 36), // Take Down
+            level(50, 63), // Hyper Beam
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 269, 63, 182, 218, 231,
+            89, 216, 104, 53, 201, 126, 317, 332, 263, 290,
+            156, 213, 211, 19, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            18, // Whirlwind
+            228, // Pursuit
+            193, // Foresight
+            211, // Steel Wing
+            225, // Dragon Breath
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Snorlax,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(6, 133), // Amnesia
+            level(10, 111), // Defense Curl
+            level(15, 187), // Belly Drum
+            level(19, 29), // Headbutt
+            level(24, 281), // Yawn
+            level(28, 156), // Rest
+            level(28, 173), // Snore
+            level(33, 34), // Body Slam
+            level(37, 335), // Block
+            level(42, 343), // Covet
+            level(46, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 76, 85, 87, 89, 216, 94, 247, 280, 104,
+            351, 53, 201, 126, 317, 263, 290, 156, 213, 57,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            122, // Lick
+            204, // Charm
+            38, // Double-Edge
+            174, // Curse
+            90, // Fissure
+            164, // Substitute
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Articuno,
+        level_up: &[
+            level(1, 16), // Gust
+            level(1, 181), // Powder Snow
+            level(13, 54), // Mist
+            level(25, 97), // Agility
+            level(37, 102), // Mimic
+            level(49, 58), // Ice Beam
+            level(61, 114), // Haze
+            level(73, 59), // Blizzard
+            level(85, 329), // Sheer Cold
+        ],
+        machines: &[
+            92, 258, 237, 241, 58, 59, 63, 182, 240, 219,
+            218, 216, 104, 332, 263, 290, 156, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Zapdos,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 84), // Thunder Shock
+            level(13, 86), // Thunder Wave
+            level(25, 97), // Agility
+            level(37, 197), // Detect
+            level(49, 65), // Drill Peck
+            level(61, 268), // Charge
+            level(73, 113), // Light Screen
+            level(85, 87), // Thunder
+        ],
+        machines: &[
+            92, 237, 241, 63, 113, 182, 240, 218, 85, 87,
+            216, 104, 351, 332, 263, 290, 156, 211, 19, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Moltres,
+        level_up: &[
+            level(1, 17), // Wing Attack
+            level(1, 52), // Ember
+            level(13, 83), // Fire Spin
+            level(25, 97), // Agility
+            level(37, 203), // Endure
+            level(49, 53), // Flamethrower
+            level(61, 219), // Safeguard
+            level(73, 257), // Heat Wave
+            level(85, 143), // Sky Attack
+        ],
+        machines: &[
+            46, 92, 237, 241, 63, 182, 219, 218, 216, 104,
+            53, 126, 332, 263, 290, 156, 211, 315, 19, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dratini,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(1, 43), // Leer
+            level(8, 86), // Thunder Wave
+            level(15, 239), // Twister
+            level(22, 82), // Dragon Rage
+            level(29, 21), // Slam
+            level(36, 97), // Agility
+            level(43, 219), // Safeguard
+            level(50, 349), // Dragon Dance
+            level(57, 200), // Outrage
+            level(64, 63), // Hyper Beam
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 59, 182, 240, 219, 218,
+            231, 85, 87, 216, 104, 351, 53, 126, 263, 290,
+            156, 213, 57, 127,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            54, // Mist
+            114, // Haze
+            48, // Supersonic
+            225, // Dragon Breath
+            245, // Extreme Speed
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dragonair,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(1, 43), // Leer
+            level(1, 86), // Thunder Wave
+            level(1, 239), // Twister
+            level(8, 86), // Thunder Wave
+            level(15, 239), // Twister
+            level(22, 82), // Dragon Rage
+            level(29, 21), // Slam
+            level(38, 97), // Agility
+            level(47, 219), // Safeguard
+            level(56, 349), // Dragon Dance
+            level(65, 200), // Outrage
+            level(74, 63), // Hyper Beam
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 59, 182, 240, 219, 218,
+            231, 85, 87, 216, 104, 351, 53, 126, 263, 290,
+            156, 213, 57, 127,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dragonite,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(1, 43), // Leer
+            level(1, 86), // Thunder Wave
+            level(1, 239), // Twister
+            level(8, 86), // Thunder Wave
+            level(15, 239), // Twister
+            level(22, 82), // Dragon Rage
+            level(29, 21), // Slam
+            level(38, 97), // Agility
+            level(47, 219), // Safeguard
+            level(55, 17), // Wing Attack
+            level(61, 349), // Dragon Dance
+            level(75, 200), // Outrage
+            level(85, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 337, 352, 46, 92, 237, 241, 58, 59, 63,
+            182, 240, 219, 218, 231, 85, 87, 89, 216, 280,
+            104, 351, 53, 201, 126, 317, 332, 263, 290, 156,
+            213, 211, 15, 19, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 86, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 86, 157, 164, 5, 25, 34,
+                38, 189, 210, 207, 214, 173, 8, 9, 7, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mewtwo,
+        level_up: &[
+            level(1, 93), // Confusion
+            level(1, 50), // Disable
+            level(11, 112), // Barrier
+            level(22, 129), // Swift
+            level(33, 244), // Psych Up
+            level(44, 248), // Future Sight
+            level(55, 54), // Mist
+            level(66, 94), // Psychic
+            level(77, 133), // Amnesia
+            level(88, 105), // Recover
+            level(99, 219), // Safeguard
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 339, 237, 241, 269, 58,
+            59, 63, 113, 182, 240, 219, 218, 231, 85, 87,
+            89, 216, 91, 94, 247, 280, 104, 115, 351, 53,
+            201, 126, 317, 259, 263, 290, 156, 168, 285, 289,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 157, 164, 5, 25,
+                34, 38, 118, 189, 207, 214, 129, 173, 8, 9,
+                7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mew,
+        level_up: &[
+            level(1, 1), // Pound
+            level(10, 144), // Transform
+            level(20, 5), // Mega Punch
+            level(30, 118), // Metronome
+            level(40, 94), // Psychic
+            level(50, 246), // Ancient Power
+        ],
+        machines: &[
+            264, 337, 352, 347, 46, 92, 258, 339, 331, 237,
+            241, 269, 58, 59, 63, 113, 182, 240, 202, 219,
+            218, 76, 231, 85, 87, 89, 216, 91, 94, 247,
+            280, 104, 115, 351, 53, 188, 201, 126, 317, 332,
+            259, 263, 290, 156, 168, 211, 285, 289, 315, 15,
+            19, 57, 70, 148, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 135,
+                138, 86, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 153, 157,
+                164, 5, 25, 14, 34, 38, 118, 189, 210, 207,
+                214, 129, 111, 173, 8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 143, 120,
+                171, 192,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Chikorita,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(8, 75), // Razor Leaf
+            level(12, 115), // Reflect
+            level(15, 77), // Poison Powder
+            level(22, 235), // Synthesis
+            level(29, 34), // Body Slam
+            level(36, 113), // Light Screen
+            level(43, 219), // Safeguard
+            level(50, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 219, 218, 76, 216,
+            104, 115, 263, 290, 156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            22, // Vine Whip
+            73, // Leech Seed
+            68, // Counter
+            246, // Ancient Power
+            175, // Flail
+            14, // Swords Dance
+            320, // Grass Whistle
+            275, // Ingrain
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Bayleef,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 75), // Razor Leaf
+            level(1, 115), // Reflect
+            level(8, 75), // Razor Leaf
+            level(12, 115), // Reflect
+            level(15, 77), // Poison Powder
+            level(23, 235), // Synthesis
+            level(31, 34), // Body Slam
+            level(39, 113), // Light Screen
+            level(47, 219), // Safeguard
+            level(55, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 219, 218, 76, 216,
+            104, 115, 263, 290, 156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Meganium,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 75), // Razor Leaf
+            level(1, 115), // Reflect
+            level(8, 75), // Razor Leaf
+            level(12, 115), // Reflect
+            level(15, 77), // Poison Powder
+            level(23, 235), // Synthesis
+            level(31, 34), // Body Slam
+            level(41, 113), // Light Screen
+            level(51, 219), // Safeguard
+            level(61, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 219, 218, 76,
+            89, 216, 104, 115, 263, 290, 156, 213, 15, 70,
+            148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164, 338,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cyndaquil,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 43), // Leer
+            level(6, 108), // Smokescreen
+            level(12, 52), // Ember
+            level(19, 98), // Quick Attack
+            level(27, 172), // Flame Wheel
+            level(36, 129), // Swift
+            level(46, 53), // Flamethrower
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 216, 91, 104, 53,
+            126, 263, 290, 156, 213, 315, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 189, 207, 214, 129, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            154, // Fury Swipes
+            98, // Quick Attack
+            179, // Reversal
+            37, // Thrash
+            193, // Foresight
+            343, // Covet
+            336, // Howl
+            306, // Crush Claw
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Quilava,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 43), // Leer
+            level(1, 108), // Smokescreen
+            level(6, 108), // Smokescreen
+            level(12, 52), // Ember
+            level(21, 98), // Quick Attack
+            level(31, 172), // Flame Wheel
+            level(42, 129), // Swift
+            level(54, 53), // Flamethrower
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 216, 91, 104, 53,
+            126, 263, 290, 156, 213, 315, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 189, 207, 214, 129, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Typhlosion,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 43), // Leer
+            level(1, 108), // Smokescreen
+            level(1, 52), // Ember
+            level(6, 108), // Smokescreen
+            level(12, 52), // Ember
+            level(21, 98), // Quick Attack
+            level(31, 172), // Flame Wheel
+            level(45, 129), // Swift
+            level(60, 53), // Flamethrower
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 63, 182, 218, 89, 216,
+            91, 280, 104, 53, 126, 317, 263, 290, 156, 213,
+            315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164, 307,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Totodile,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(7, 99), // Rage
+            level(13, 55), // Water Gun
+            level(20, 44), // Bite
+            level(27, 184), // Scary Face
+            level(35, 163), // Slash
+            level(43, 103), // Screech
+            level(52, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 269, 58, 59, 182, 240,
+            218, 231, 216, 91, 280, 104, 332, 263, 290, 156,
+            213, 15, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            242, // Crunch
+            37, // Thrash
+            56, // Hydro Pump
+            246, // Ancient Power
+            13, // Razor Wind
+            157, // Rock Slide
+            300, // Mud Sport
+            346, // Water Sport
+            337, // Dragon Claw
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Croconaw,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 99), // Rage
+            level(7, 99), // Rage
+            level(13, 55), // Water Gun
+            level(21, 44), // Bite
+            level(28, 184), // Scary Face
+            level(37, 163), // Slash
+            level(45, 103), // Screech
+            level(55, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 269, 58, 59, 182, 240,
+            218, 231, 216, 91, 280, 104, 332, 263, 290, 156,
+            213, 15, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Feraligatr,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 99), // Rage
+            level(1, 55), // Water Gun
+            level(7, 99), // Rage
+            level(13, 55), // Water Gun
+            level(21, 44), // Bite
+            level(28, 184), // Scary Face
+            level(38, 163), // Slash
+            level(47, 103), // Screech
+            level(58, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 337, 352, 92, 258, 237, 269, 58, 59, 63,
+            182, 240, 218, 231, 89, 216, 91, 280, 104, 317,
+            332, 263, 290, 156, 213, 15, 57, 70, 249, 127,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+                308,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sentret,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(4, 111), // Defense Curl
+            level(7, 98), // Quick Attack
+            level(12, 154), // Fury Swipes
+            level(17, 270), // Helping Hand
+            level(24, 21), // Slam
+            level(31, 266), // Follow Me
+            level(40, 156), // Rest
+            level(49, 133), // Amnesia
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 182, 240, 218, 231, 85,
+            87, 216, 91, 247, 280, 104, 351, 332, 263, 290,
+            156, 213, 168, 15, 57, 70,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 9, 7, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            38, // Double-Edge
+            228, // Pursuit
+            163, // Slash
+            116, // Focus Energy
+            179, // Reversal
+            164, // Substitute
+            271, // Trick
+            274, // Assist
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Furret,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 111), // Defense Curl
+            level(1, 98), // Quick Attack
+            level(4, 111), // Defense Curl
+            level(7, 98), // Quick Attack
+            level(12, 154), // Fury Swipes
+            level(19, 270), // Helping Hand
+            level(28, 21), // Slam
+            level(37, 266), // Follow Me
+            level(48, 156), // Rest
+            level(59, 133), // Amnesia
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 63, 182, 240, 218, 231,
+            85, 87, 89, 216, 91, 247, 280, 104, 351, 332,
+            263, 290, 156, 213, 168, 15, 57, 70,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 9, 7, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hoothoot,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 193), // Foresight
+            level(6, 64), // Peck
+            level(11, 95), // Hypnosis
+            level(16, 115), // Reflect
+            level(22, 36), // Take Down
+            level(28, 93), // Confusion
+            level(34, 244), // Psych Up
+            level(48, 138), // Dream Eater
+        ],
+        machines: &[
+            347, 92, 237, 241, 182, 219, 218, 216, 94, 247,
+            104, 115, 332, 263, 290, 156, 213, 168, 211, 285,
+            19, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 129, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            119, // Mirror Move
+            48, // Supersonic
+            185, // Feint Attack
+            17, // Wing Attack
+            18, // Whirlwind
+            143, // Sky Attack
+            97, // Agility
+            101, // Night Shade
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Noctowl,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 193), // Foresight
+            level(1, 64), // Peck
+            level(6, 64), // Peck
+            level(11, 95), // Hypnosis
+            level(16, 115), // Reflect
+            level(25, 36), // Take Down
+            level(33, 93), // Confusion
+            level(41, 244), // Psych Up
+            level(57, 138), // Dream Eater
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 182, 219, 218, 216, 94,
+            247, 104, 115, 332, 263, 290, 156, 213, 168, 211,
+            285, 19, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 129, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ledyba,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(8, 48), // Supersonic
+            level(15, 4), // Comet Punch
+            level(22, 113), // Light Screen
+            level(22, 115), // Reflect
+            level(22, 219), // Safeguard
+            level(29, 226), // Baton Pass
+            level(36, 129), // Swift
+            level(43, 97), // Agility
+            level(50, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 113, 182, 202, 219, 218, 76,
+            216, 280, 104, 115, 332, 263, 290, 156, 213, 168,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                223, 68, 102, 164, 5, 38, 207, 214, 129, 173,
+                8, 9, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            60, // Psybeam
+            117, // Bide
+            318, // Silver Wind
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ledian,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 48), // Supersonic
+            level(8, 48), // Supersonic
+            level(15, 4), // Comet Punch
+            level(24, 113), // Light Screen
+            level(24, 115), // Reflect
+            level(24, 219), // Safeguard
+            level(33, 226), // Baton Pass
+            level(42, 129), // Swift
+            level(51, 97), // Agility
+            level(60, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 63, 113, 182, 202, 219, 218,
+            76, 216, 280, 104, 115, 332, 263, 290, 156, 213,
+            168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 38, 207, 214, 129,
+                173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Spinarak,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 81), // String Shot
+            level(6, 184), // Scary Face
+            level(11, 132), // Constrict
+            level(17, 101), // Night Shade
+            level(23, 141), // Leech Life
+            level(30, 154), // Fury Swipes
+            level(37, 169), // Spider Web
+            level(45, 97), // Agility
+            level(53, 94), // Psychic
+        ],
+        machines: &[
+            92, 331, 237, 182, 202, 218, 76, 216, 94, 104,
+            188, 263, 290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 189, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            60, // Psybeam
+            50, // Disable
+            49, // Sonic Boom
+            226, // Baton Pass
+            228, // Pursuit
+            324, // Signal Beam
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ariados,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 81), // String Shot
+            level(1, 184), // Scary Face
+            level(1, 132), // Constrict
+            level(6, 184), // Scary Face
+            level(11, 132), // Constrict
+            level(17, 101), // Night Shade
+            level(25, 141), // Leech Life
+            level(34, 154), // Fury Swipes
+            level(43, 169), // Spider Web
+            level(53, 97), // Agility
+            level(63, 94), // Psychic
+        ],
+        machines: &[
+            92, 331, 237, 63, 182, 202, 218, 76, 216, 94,
+            104, 188, 263, 290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 189, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Crobat,
+        level_up: &[
+            level(1, 103), // Screech
+            level(1, 141), // Leech Life
+            level(1, 48), // Supersonic
+            level(6, 48), // Supersonic
+            level(11, 310), // Astonish
+            level(16, 44), // Bite
+            level(21, 17), // Wing Attack
+            level(28, 109), // Confuse Ray
+            level(35, 314), // Air Cutter
+            level(42, 212), // Mean Look
+            level(49, 305), // Poison Fang
+            level(56, 114), // Haze
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 202, 218, 216, 247,
+            104, 188, 332, 259, 263, 290, 156, 213, 168, 211,
+            289, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Chinchou,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 86), // Thunder Wave
+            level(5, 48), // Supersonic
+            level(13, 175), // Flail
+            level(17, 55), // Water Gun
+            level(25, 209), // Spark
+            level(29, 109), // Confuse Ray
+            level(37, 36), // Take Down
+            level(41, 56), // Hydro Pump
+            level(49, 268), // Charge
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 85,
+            87, 216, 104, 351, 263, 290, 156, 213, 57, 148,
+            127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            48, // Supersonic
+            103, // Screech
+            175, // Flail
+            133, // Amnesia
+            60, // Psybeam
+            54, // Mist
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lanturn,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 86), // Thunder Wave
+            level(1, 48), // Supersonic
+            level(5, 48), // Supersonic
+            level(13, 175), // Flail
+            level(17, 55), // Water Gun
+            level(25, 209), // Spark
+            level(32, 109), // Confuse Ray
+            level(43, 36), // Take Down
+            level(50, 56), // Hydro Pump
+            level(61, 268), // Charge
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            85, 87, 216, 104, 351, 263, 290, 156, 213, 57,
+            148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pichu,
+        level_up: &[
+            level(1, 84), // Thunder Shock
+            level(1, 204), // Charm
+            level(6, 39), // Tail Whip
+            level(8, 86), // Thunder Wave
+            level(11, 186), // Sweet Kiss
+        ],
+        machines: &[
+            92, 237, 113, 182, 218, 231, 85, 87, 216, 104,
+            351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 118, 207, 214, 129, 111, 173, 8, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            179, // Reversal
+            117, // Bide
+            217, // Present
+            227, // Encore
+            3, // Double Slap
+            273, // Wish
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cleffa,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 204), // Charm
+            level(4, 227), // Encore
+            level(8, 47), // Sing
+            level(13, 186), // Sweet Kiss
+        ],
+        machines: &[
+            352, 347, 92, 237, 241, 113, 182, 240, 219, 218,
+            85, 87, 216, 94, 247, 104, 115, 351, 53, 126,
+            263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 164, 5,
+                25, 34, 38, 118, 189, 207, 214, 129, 111, 173,
+                8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            217, // Present
+            118, // Metronome
+            133, // Amnesia
+            187, // Belly Drum
+            150, // Splash
+            102, // Mimic
+            273, // Wish
+            313, // Fake Tears
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Igglybuff,
+        level_up: &[
+            level(1, 47), // Sing
+            level(1, 204), // Charm
+            level(4, 111), // Defense Curl
+            level(9, 1), // Pound
+            level(14, 186), // Sweet Kiss
+        ],
+        machines: &[
+            352, 347, 92, 237, 241, 113, 182, 240, 219, 218,
+            85, 87, 216, 94, 247, 104, 115, 351, 53, 126,
+            263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 138, 86, 164, 5, 25,
+                34, 38, 118, 189, 207, 214, 129, 111, 173, 8,
+                9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            195, // Perish Song
+            217, // Present
+            185, // Feint Attack
+            273, // Wish
+            313, // Fake Tears
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Togepi,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 204), // Charm
+            level(4, 118), // Metronome
+            level(9, 186), // Sweet Kiss
+            level(14, 281), // Yawn
+            level(18, 227), // Encore
+            level(25, 266), // Follow Me
+            level(29, 273), // Wish
+            level(34, 219), // Safeguard
+            level(38, 38), // Double-Edge
+            level(42, 226), // Baton Pass
+        ],
+        machines: &[
+            352, 347, 92, 237, 241, 113, 182, 219, 218, 216,
+            94, 247, 104, 115, 53, 126, 263, 290, 156, 213,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 164, 5,
+                25, 34, 38, 118, 189, 207, 214, 129, 111, 173,
+                8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            217, // Present
+            119, // Mirror Move
+            64, // Peck
+            193, // Foresight
+            248, // Future Sight
+            164, // Substitute
+            244, // Psych Up
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Togetic,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 204), // Charm
+            level(1, 118), // Metronome
+            level(4, 118), // Metronome
+            level(9, 186), // Sweet Kiss
+            level(14, 281), // Yawn
+            level(18, 227), // Encore
+            level(25, 266), // Follow Me
+            level(29, 273), // Wish
+            level(34, 219), // Safeguard
+            level(38, 38), // Double-Edge
+            level(42, 226), // Baton Pass
+        ],
+        machines: &[
+            352, 347, 92, 237, 241, 63, 113, 182, 219, 218,
+            216, 94, 247, 104, 115, 53, 126, 332, 263, 290,
+            156, 213, 211, 19, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 164, 5,
+                25, 34, 38, 118, 189, 207, 214, 129, 111, 173,
+                8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Natu,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 43), // Leer
+            level(10, 101), // Night Shade
+            level(20, 100), // Teleport
+            level(30, 273), // Wish
+            level(30, 248), // Future Sight
+            level(40, 109), // Confuse Ray
+            level(50, 94), // Psychic
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 219, 218, 216, 94,
+            247, 104, 115, 332, 259, 263, 290, 156, 213, 168,
+            285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            114, // Haze
+            65, // Drill Peck
+            98, // Quick Attack
+            185, // Feint Attack
+            211, // Steel Wing
+            244, // Psych Up
+            297, // Feather Dance
+            287, // Refresh
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Xatu,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 43), // Leer
+            level(10, 101), // Night Shade
+            level(20, 100), // Teleport
+            level(35, 273), // Wish
+            level(35, 248), // Future Sight
+            level(50, 109), // Confuse Ray
+            level(65, 94), // Psychic
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 113, 182, 219, 218, 216,
+            94, 247, 104, 115, 332, 259, 263, 290, 156, 213,
+            168, 211, 285, 289, 19, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mareep,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(9, 84), // Thunder Shock
+            level(16, 86), // Thunder Wave
+            level(23, 178), // Cotton Spore
+            level(30, 113), // Light Screen
+            level(37, 87), // Thunder
+        ],
+        machines: &[
+            92, 237, 113, 182, 218, 231, 85, 87, 216, 104,
+            351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 69, 102, 86, 164,
+            ],
+            emerald: &[
+                205, 69, 68, 102, 86, 164, 34, 38, 207, 214,
+                129, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            9, // Thunder Punch
+            219, // Safeguard
+            103, // Screech
+            115, // Reflect
+            316, // Odor Sleuth
+            36, // Take Down
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Flaaffy,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 84), // Thunder Shock
+            level(9, 84), // Thunder Shock
+            level(18, 86), // Thunder Wave
+            level(27, 178), // Cotton Spore
+            level(36, 113), // Light Screen
+            level(45, 87), // Thunder
+        ],
+        machines: &[
+            264, 92, 237, 113, 182, 218, 231, 85, 87, 216,
+            280, 104, 351, 263, 290, 156, 213, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 207, 214, 129, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ampharos,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 84), // Thunder Shock
+            level(1, 86), // Thunder Wave
+            level(9, 84), // Thunder Shock
+            level(18, 86), // Thunder Wave
+            level(27, 178), // Cotton Spore
+            level(30, 9), // Thunder Punch
+            level(42, 113), // Light Screen
+            level(57, 87), // Thunder
+        ],
+        machines: &[
+            264, 92, 237, 63, 113, 182, 218, 231, 85, 87,
+            216, 280, 104, 351, 263, 290, 156, 213, 70, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 207, 214, 129, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Bellossom,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 230), // Sweet Scent
+            level(1, 78), // Stun Spore
+            level(1, 345), // Magical Leaf
+            level(44, 80), // Petal Dance
+            level(55, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 219, 218, 76, 216,
+            104, 188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Marill,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(3, 111), // Defense Curl
+            level(6, 39), // Tail Whip
+            level(10, 55), // Water Gun
+            level(15, 205), // Rollout
+            level(21, 61), // Bubble Beam
+            level(28, 38), // Double-Edge
+            level(36, 240), // Rain Dance
+            level(45, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 182, 240, 218,
+            216, 280, 104, 263, 290, 156, 213, 57, 70, 249,
+            127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            113, // Light Screen
+            217, // Present
+            133, // Amnesia
+            248, // Future Sight
+            187, // Belly Drum
+            195, // Perish Song
+            48, // Supersonic
+            193, // Foresight
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Azumarill,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 111), // Defense Curl
+            level(1, 39), // Tail Whip
+            level(1, 55), // Water Gun
+            level(3, 111), // Defense Curl
+            level(6, 39), // Tail Whip
+            level(10, 55), // Water Gun
+            level(15, 205), // Rollout
+            level(24, 61), // Bubble Beam
+            level(34, 38), // Double-Edge
+            level(45, 240), // Rain Dance
+            level(57, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 216, 280, 104, 263, 290, 156, 213, 57, 70,
+            249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sudowoodo,
+        level_up: &[
+            level(1, 88), // Rock Throw
+            level(1, 102), // Mimic
+            level(1, 175), // Flail
+            level(9, 67), // Low Kick
+            level(17, 88), // Rock Throw
+            level(25, 102), // Mimic
+            level(33, 21), // Slam
+            level(41, 185), // Feint Attack
+            level(49, 157), // Rock Slide
+            level(57, 335), // Block
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 182, 218, 89, 216, 91,
+            280, 104, 201, 317, 263, 290, 156, 213, 168, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            120, // Self-Destruct
+            29, // Headbutt
+            106, // Harden
+            111, // Defense Curl
+            205, // Rollout
+            328, // Sand Tomb
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Politoed,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 95), // Hypnosis
+            level(1, 3), // Double Slap
+            level(1, 195), // Perish Song
+            level(35, 195), // Perish Song
+            level(51, 207), // Swagger
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 89, 216, 91, 94, 280, 104, 263, 290, 156,
+            213, 168, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 189,
+                207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hoppip,
+        level_up: &[
+            level(1, 150), // Splash
+            level(5, 235), // Synthesis
+            level(5, 39), // Tail Whip
+            level(10, 33), // Tackle
+            level(13, 77), // Poison Powder
+            level(15, 78), // Stun Spore
+            level(17, 79), // Sleep Powder
+            level(20, 331), // Bullet Seed
+            level(25, 73), // Leech Seed
+            level(30, 72), // Mega Drain
+            level(35, 178), // Cotton Spore
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 332, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            93, // Confusion
+            45, // Growl
+            227, // Encore
+            38, // Double-Edge
+            115, // Reflect
+            133, // Amnesia
+            270, // Helping Hand
+            244, // Psych Up
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Skiploom,
+        level_up: &[
+            level(1, 150), // Splash
+            level(1, 235), // Synthesis
+            level(1, 39), // Tail Whip
+            level(1, 33), // Tackle
+            level(5, 235), // Synthesis
+            level(5, 39), // Tail Whip
+            level(10, 33), // Tackle
+            level(13, 77), // Poison Powder
+            level(15, 78), // Stun Spore
+            level(17, 79), // Sleep Powder
+            level(22, 331), // Bullet Seed
+            level(29, 73), // Leech Seed
+            level(36, 72), // Mega Drain
+            level(43, 178), // Cotton Spore
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 332, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Jumpluff,
+        level_up: &[
+            level(1, 150), // Splash
+            level(1, 235), // Synthesis
+            level(1, 39), // Tail Whip
+            level(1, 33), // Tackle
+            level(5, 235), // Synthesis
+            level(5, 39), // Tail Whip
+            level(10, 33), // Tackle
+            level(13, 77), // Poison Powder
+            level(15, 78), // Stun Spore
+            level(17, 79), // Sleep Powder
+            level(22, 331), // Bullet Seed
+            level(33, 73), // Leech Seed
+            level(44, 72), // Mega Drain
+            level(55, 178), // Cotton Spore
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 216,
+            104, 188, 332, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Aipom,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 39), // Tail Whip
+            level(6, 28), // Sand Attack
+            level(13, 310), // Astonish
+            level(18, 226), // Baton Pass
+            level(25, 321), // Tickle
+            level(31, 154), // Fury Swipes
+            level(38, 129), // Swift
+            level(43, 103), // Screech
+            level(50, 97), // Agility
+        ],
+        machines: &[
+            264, 352, 92, 237, 241, 269, 182, 240, 218, 231,
+            85, 87, 216, 91, 247, 280, 104, 351, 332, 259,
+            263, 290, 156, 213, 168, 285, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                189, 207, 214, 129, 111, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            68, // Counter
+            103, // Screech
+            228, // Pursuit
+            97, // Agility
+            180, // Spite
+            21, // Slam
+            3, // Double Slap
+            251, // Beat Up
+            252, // Fake Out
+            343, // Covet
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sunkern,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 74), // Growth
+            level(6, 275), // Ingrain
+            level(13, 320), // Grass Whistle
+            level(18, 72), // Mega Drain
+            level(25, 283), // Endeavor
+            level(30, 241), // Sunny Day
+            level(37, 235), // Synthesis
+            level(42, 202), // Giga Drain
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 219, 218, 76, 216,
+            104, 188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            320, // Grass Whistle
+            227, // Encore
+            73, // Leech Seed
+            267, // Nature Power
+            174, // Curse
+            270, // Helping Hand
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sunflora,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 1), // Pound
+            level(6, 74), // Growth
+            level(13, 75), // Razor Leaf
+            level(18, 80), // Petal Dance
+            level(25, 275), // Ingrain
+            level(30, 331), // Bullet Seed
+            level(37, 241), // Sunny Day
+            level(42, 348), // Leaf Blade
+            level(49, 76), // Solar Beam
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 219, 218, 76,
+            216, 104, 188, 263, 290, 156, 213, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Yanma,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 193), // Foresight
+            level(7, 98), // Quick Attack
+            level(13, 104), // Double Team
+            level(19, 49), // Sonic Boom
+            level(25, 197), // Detect
+            level(31, 48), // Supersonic
+            level(37, 253), // Uproar
+            level(43, 17), // Wing Attack
+            level(49, 103), // Screech
+        ],
+        machines: &[
+            92, 237, 241, 182, 202, 218, 216, 94, 247, 104,
+            332, 263, 290, 156, 213, 168, 211, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 189, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            18, // Whirlwind
+            179, // Reversal
+            141, // Leech Life
+            324, // Signal Beam
+            318, // Silver Wind
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wooper,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 39), // Tail Whip
+            level(11, 21), // Slam
+            level(16, 341), // Mud Shot
+            level(21, 133), // Amnesia
+            level(31, 281), // Yawn
+            level(36, 89), // Earthquake
+            level(41, 240), // Rain Dance
+            level(51, 54), // Mist
+            level(51, 114), // Haze
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 89,
+            216, 91, 104, 188, 201, 317, 263, 290, 156, 213,
+            57, 70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            24, // Double Kick
+            34, // Body Slam
+            246, // Ancient Power
+            219, // Safeguard
+            174, // Curse
+            300, // Mud Sport
+            254, // Stockpile
+            256, // Swallow
+            255, // Spit Up
+            187, // Belly Drum
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Quagsire,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(1, 39), // Tail Whip
+            level(11, 21), // Slam
+            level(16, 341), // Mud Shot
+            level(23, 133), // Amnesia
+            level(35, 281), // Yawn
+            level(42, 89), // Earthquake
+            level(49, 240), // Rain Dance
+            level(61, 54), // Mist
+            level(61, 114), // Haze
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 89, 216, 91, 280, 104, 188, 201, 317, 263,
+            290, 156, 213, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Espeon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 93), // Confusion
+            level(23, 98), // Quick Attack
+            level(30, 129), // Swift
+            level(36, 60), // Psybeam
+            level(42, 244), // Psych Up
+            level(47, 94), // Psychic
+            level(52, 234), // Morning Sun
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 113, 182, 240, 219, 218,
+            231, 216, 94, 247, 104, 115, 263, 290, 156, 213,
+            168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 129, 173,
+                244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Umbreon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 39), // Tail Whip
+            level(1, 270), // Helping Hand
+            level(8, 28), // Sand Attack
+            level(16, 228), // Pursuit
+            level(23, 98), // Quick Attack
+            level(30, 109), // Confuse Ray
+            level(36, 185), // Feint Attack
+            level(42, 212), // Mean Look
+            level(47, 103), // Screech
+            level(52, 236), // Moonlight
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 240, 218, 231, 216,
+            91, 94, 247, 104, 259, 263, 290, 156, 213, 168,
+            289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 129, 173,
+                244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Murkrow,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 310), // Astonish
+            level(9, 228), // Pursuit
+            level(14, 114), // Haze
+            level(22, 101), // Night Shade
+            level(27, 185), // Feint Attack
+            level(35, 269), // Taunt
+            level(40, 212), // Mean Look
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 218, 216, 94, 247,
+            104, 332, 259, 263, 290, 156, 213, 168, 289, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 129, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            18, // Whirlwind
+            65, // Drill Peck
+            98, // Quick Attack
+            119, // Mirror Move
+            17, // Wing Attack
+            143, // Sky Attack
+            109, // Confuse Ray
+            297, // Feather Dance
+            195, // Perish Song
+            354, // Psycho Boost
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slowking,
+        level_up: &[
+            level(1, 174), // Curse
+            level(1, 281), // Yawn
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(6, 45), // Growl
+            level(15, 55), // Water Gun
+            level(20, 93), // Confusion
+            level(29, 50), // Disable
+            level(34, 29), // Headbutt
+            level(43, 207), // Swagger
+            level(48, 94), // Psychic
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 231, 89, 216, 91, 94,
+            247, 280, 104, 115, 53, 126, 259, 263, 290, 156,
+            213, 285, 289, 57, 70, 148, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 157, 164, 5, 25,
+                34, 38, 118, 189, 207, 214, 173, 8, 7, 244,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Misdreavus,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 149), // Psywave
+            level(6, 180), // Spite
+            level(11, 310), // Astonish
+            level(17, 109), // Confuse Ray
+            level(23, 212), // Mean Look
+            level(30, 60), // Psybeam
+            level(37, 220), // Pain Split
+            level(45, 195), // Perish Song
+            level(53, 288), // Grudge
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 218, 85, 87, 216,
+            94, 247, 104, 351, 259, 263, 290, 156, 213, 168,
+            285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            103, // Screech
+            194, // Destiny Bond
+            244, // Psych Up
+            286, // Imprison
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Unown,
+        level_up: &[
+            level(1, 237), // Hidden Power
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wobbuffet,
+        level_up: &[
+            level(1, 68), // Counter
+            level(1, 243), // Mirror Coat
+            level(1, 219), // Safeguard
+            level(1, 194), // Destiny Bond
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Girafarig,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 310), // Astonish
+            level(7, 93), // Confusion
+            level(13, 316), // Odor Sleuth
+            level(19, 23), // Stomp
+            level(25, 97), // Agility
+            level(31, 60), // Psybeam
+            level(37, 226), // Baton Pass
+            level(43, 242), // Crunch
+            level(49, 94), // Psychic
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 113, 182, 240, 219, 218,
+            231, 85, 87, 216, 94, 247, 104, 115, 351, 259,
+            263, 290, 156, 213, 285, 289, 70, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 189, 207, 214, 129,
+                173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            36, // Take Down
+            133, // Amnesia
+            193, // Foresight
+            248, // Future Sight
+            251, // Beat Up
+            244, // Psych Up
+            273, // Wish
+            277, // Magic Coat
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pineco,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 182), // Protect
+            level(8, 120), // Self-Destruct
+            level(15, 36), // Take Down
+            level(22, 229), // Rapid Spin
+            level(29, 117), // Bide
+            level(36, 153), // Explosion
+            level(43, 191), // Spikes
+            level(50, 38), // Double-Edge
+        ],
+        machines: &[
+            92, 237, 241, 182, 202, 219, 218, 76, 89, 216,
+            91, 104, 201, 317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                129, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            115, // Reflect
+            42, // Pin Missile
+            175, // Flail
+            129, // Swift
+            68, // Counter
+            328, // Sand Tomb
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Forretress,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 182), // Protect
+            level(1, 120), // Self-Destruct
+            level(1, 36), // Take Down
+            level(8, 120), // Self-Destruct
+            level(15, 36), // Take Down
+            level(22, 229), // Rapid Spin
+            level(29, 117), // Bide
+            level(31, 192), // Zap Cannon
+            level(39, 153), // Explosion
+            level(49, 191), // Spikes
+            level(59, 38), // Double-Edge
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 202, 219, 218, 76, 89,
+            216, 91, 104, 201, 317, 263, 290, 156, 213, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                129, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dunsparce,
+        level_up: &[
+            level(1, 99), // Rage
+            level(4, 111), // Defense Curl
+            level(11, 281), // Yawn
+            level(14, 137), // Glare
+            level(21, 180), // Spite
+            level(24, 228), // Pursuit
+            level(31, 103), // Screech
+            level(34, 36), // Take Down
+            level(41, 283), // Endeavor
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 59, 182, 240, 218, 231,
+            85, 87, 89, 216, 91, 94, 247, 104, 351, 53,
+            201, 126, 317, 263, 290, 156, 213, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 138, 86, 157, 164, 34, 38, 189, 207,
+                214, 111, 173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            117, // Bide
+            246, // Ancient Power
+            157, // Rock Slide
+            44, // Bite
+            99, // Rage
+            310, // Astonish
+            174, // Curse
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gligar,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(6, 28), // Sand Attack
+            level(13, 106), // Harden
+            level(20, 98), // Quick Attack
+            level(28, 185), // Feint Attack
+            level(36, 163), // Slash
+            level(44, 103), // Screech
+            level(52, 12), // Guillotine
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 218, 89, 216, 91,
+            280, 104, 201, 317, 332, 263, 290, 156, 213, 168,
+            211, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 157, 164,
+            ],
+            emerald: &[
+                68, 102, 157, 164, 14, 34, 38, 189, 210, 207,
+                214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            232, // Metal Claw
+            17, // Wing Attack
+            13, // Razor Wind
+            68, // Counter
+            328, // Sand Tomb
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Steelix,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 103), // Screech
+            level(9, 20), // Bind
+            level(13, 88), // Rock Throw
+            level(21, 106), // Harden
+            level(25, 99), // Rage
+            level(33, 201), // Sandstorm
+            level(37, 21), // Slam
+            level(45, 231), // Iron Tail
+            level(49, 242), // Crunch
+            level(57, 38), // Double-Edge
+        ],
+        machines: &[
+            92, 237, 63, 182, 218, 231, 89, 216, 91, 104,
+            188, 201, 317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Snubbull,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 184), // Scary Face
+            level(4, 39), // Tail Whip
+            level(8, 204), // Charm
+            level(13, 44), // Bite
+            level(19, 122), // Lick
+            level(26, 46), // Roar
+            level(34, 99), // Rage
+            level(43, 36), // Take Down
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 182, 240, 218, 85,
+            87, 89, 216, 91, 247, 280, 104, 351, 53, 126,
+            263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            118, // Metronome
+            185, // Feint Attack
+            115, // Reflect
+            217, // Present
+            242, // Crunch
+            215, // Heal Bell
+            122, // Lick
+            43, // Leer
+            173, // Snore
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Granbull,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 184), // Scary Face
+            level(1, 39), // Tail Whip
+            level(1, 204), // Charm
+            level(4, 39), // Tail Whip
+            level(8, 204), // Charm
+            level(13, 44), // Bite
+            level(19, 122), // Lick
+            level(28, 46), // Roar
+            level(38, 99), // Rage
+            level(49, 36), // Take Down
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 240, 218,
+            85, 87, 89, 216, 91, 247, 280, 104, 351, 53,
+            126, 317, 263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Qwilfish,
+        level_up: &[
+            level(1, 191), // Spikes
+            level(1, 33), // Tackle
+            level(1, 40), // Poison Sting
+            level(10, 106), // Harden
+            level(10, 107), // Minimize
+            level(19, 55), // Water Gun
+            level(28, 42), // Pin Missile
+            level(37, 36), // Take Down
+            level(46, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 269, 58, 59, 182, 240, 218,
+            85, 87, 216, 104, 351, 188, 259, 263, 290, 156,
+            213, 168, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 153, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            175, // Flail
+            114, // Haze
+            61, // Bubble Beam
+            48, // Supersonic
+            310, // Astonish
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Scizor,
+        level_up: &[
+            level(1, 98), // Quick Attack
+            level(1, 43), // Leer
+            level(6, 116), // Focus Energy
+            level(11, 228), // Pursuit
+            level(16, 206), // False Swipe
+            level(21, 97), // Agility
+            level(26, 232), // Metal Claw
+            level(31, 163), // Slash
+            level(36, 14), // Swords Dance
+            level(41, 104), // Double Team
+            level(46, 210), // Fury Cutter
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 218, 216, 280, 104,
+            332, 259, 263, 290, 156, 213, 168, 211, 15, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                68, 102, 164, 14, 34, 38, 189, 210, 207, 214,
+                129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shuckle,
+        level_up: &[
+            level(1, 132), // Constrict
+            level(1, 110), // Withdraw
+            level(9, 35), // Wrap
+            level(14, 227), // Encore
+            level(23, 219), // Safeguard
+            level(28, 117), // Bide
+            level(37, 156), // Rest
+        ],
+        machines: &[
+            92, 237, 241, 182, 219, 218, 89, 216, 91, 104,
+            201, 317, 263, 290, 156, 213, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            230, // Sweet Scent
+            270, // Helping Hand
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Heracross,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 43), // Leer
+            level(6, 30), // Horn Attack
+            level(11, 203), // Endure
+            level(17, 31), // Fury Attack
+            level(23, 280), // Brick Break
+            level(30, 68), // Counter
+            level(37, 36), // Take Down
+            level(45, 179), // Reversal
+            level(53, 224), // Megahorn
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 218, 89,
+            216, 91, 280, 104, 201, 317, 332, 263, 290, 156,
+            213, 168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                69, 68, 102, 157, 164, 14, 34, 38, 189, 210,
+                207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            106, // Harden
+            117, // Bide
+            175, // Flail
+            206, // False Swipe
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sneasel,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 269), // Taunt
+            level(8, 98), // Quick Attack
+            level(15, 103), // Screech
+            level(22, 185), // Feint Attack
+            level(29, 154), // Fury Swipes
+            level(36, 97), // Agility
+            level(43, 163), // Slash
+            level(50, 251), // Beat Up
+            level(57, 232), // Metal Claw
+            level(64, 8), // Ice Punch
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 269, 58, 59, 182, 240,
+            218, 231, 216, 91, 247, 280, 104, 332, 259, 263,
+            290, 156, 213, 168, 289, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 102, 164,
+            ],
+            emerald: &[
+                223, 68, 102, 164, 14, 34, 38, 189, 210, 207,
+                214, 129, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            68, // Counter
+            180, // Spite
+            115, // Reflect
+            44, // Bite
+            306, // Crush Claw
+            252, // Fake Out
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Teddiursa,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 122), // Lick
+            level(7, 154), // Fury Swipes
+            level(13, 185), // Feint Attack
+            level(19, 156), // Rest
+            level(25, 163), // Slash
+            level(31, 173), // Snore
+            level(37, 37), // Thrash
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 218, 89,
+            216, 91, 280, 104, 317, 332, 263, 290, 156, 213,
+            168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 118, 189, 207, 214, 129, 111, 173, 8,
+                9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            242, // Crunch
+            36, // Take Down
+            69, // Seismic Toss
+            116, // Focus Energy
+            68, // Counter
+            232, // Metal Claw
+            281, // Yawn
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ursaring,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 43), // Leer
+            level(1, 122), // Lick
+            level(1, 154), // Fury Swipes
+            level(7, 154), // Fury Swipes
+            level(13, 185), // Feint Attack
+            level(19, 156), // Rest
+            level(25, 163), // Slash
+            level(31, 173), // Snore
+            level(37, 184), // Scary Face
+            level(49, 37), // Thrash
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 218, 89,
+            216, 91, 280, 104, 317, 332, 263, 290, 156, 213,
+            168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 118, 189, 207, 214, 129, 111, 173, 8,
+                9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slugma,
+        level_up: &[
+            level(1, 281), // Yawn
+            level(1, 123), // Smog
+            level(8, 52), // Ember
+            level(15, 88), // Rock Throw
+            level(22, 106), // Harden
+            level(29, 133), // Amnesia
+            level(36, 53), // Flamethrower
+            level(43, 157), // Rock Slide
+            level(50, 34), // Body Slam
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 216, 104, 53, 201,
+            126, 317, 263, 290, 156, 213, 315,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            151, // Acid Armor
+            257, // Heat Wave
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magcargo,
+        level_up: &[
+            level(1, 281), // Yawn
+            level(1, 123), // Smog
+            level(1, 52), // Ember
+            level(1, 88), // Rock Throw
+            level(8, 52), // Ember
+            level(15, 88), // Rock Throw
+            level(22, 106), // Harden
+            level(29, 133), // Amnesia
+            level(36, 53), // Flamethrower
+            level(48, 157), // Rock Slide
+            level(60, 34), // Body Slam
+        ],
+        machines: &[
+            46, 92, 237, 241, 63, 182, 218, 89, 216, 104,
+            53, 201, 126, 317, 263, 290, 156, 213, 315, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Swinub,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 316), // Odor Sleuth
+            level(10, 181), // Powder Snow
+            level(19, 300), // Mud Sport
+            level(28, 203), // Endure
+            level(37, 36), // Take Down
+            level(46, 54), // Mist
+            level(55, 59), // Blizzard
+            level(64, 133), // Amnesia
+        ],
+        machines: &[
+            46, 92, 258, 237, 58, 59, 182, 240, 218, 89,
+            216, 91, 104, 201, 317, 263, 290, 156, 213, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            36, // Take Down
+            44, // Bite
+            34, // Body Slam
+            157, // Rock Slide
+            246, // Ancient Power
+            341, // Mud Shot
+            333, // Icicle Spear
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Piloswine,
+        level_up: &[
+            level(1, 30), // Horn Attack
+            level(1, 316), // Odor Sleuth
+            level(1, 181), // Powder Snow
+            level(1, 300), // Mud Sport
+            level(10, 181), // Powder Snow
+            level(19, 300), // Mud Sport
+            level(28, 203), // Endure
+            level(33, 31), // Fury Attack
+            level(42, 36), // Take Down
+            level(56, 54), // Mist
+            level(70, 59), // Blizzard
+            level(84, 133), // Amnesia
+        ],
+        machines: &[
+            46, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            89, 216, 91, 104, 201, 317, 263, 290, 156, 213,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Corsola,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(6, 106), // Harden
+            level(12, 145), // Bubble
+            level(17, 105), // Recover
+            level(17, 287), // Refresh
+            level(23, 61), // Bubble Beam
+            level(28, 131), // Spike Cannon
+            level(34, 350), // Rock Blast
+            level(39, 243), // Mirror Coat
+            level(45, 246), // Ancient Power
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 113, 182, 240, 218,
+            89, 216, 94, 104, 115, 201, 317, 263, 290, 156,
+            213, 57, 70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                111, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            157, // Rock Slide
+            219, // Safeguard
+            103, // Screech
+            54, // Mist
+            133, // Amnesia
+            112, // Barrier
+            275, // Ingrain
+            109, // Confuse Ray
+            333, // Icicle Spear
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Remoraid,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(11, 199), // Lock-On
+            level(22, 60), // Psybeam
+            level(22, 62), // Aurora Beam
+            level(22, 61), // Bubble Beam
+            level(33, 116), // Focus Energy
+            level(44, 58), // Ice Beam
+            level(55, 63), // Hyper Beam
+        ],
+        machines: &[
+            352, 92, 258, 237, 241, 58, 59, 63, 182, 240,
+            218, 216, 94, 104, 53, 188, 126, 263, 290, 156,
+            213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            62, // Aurora Beam
+            190, // Octazooka
+            48, // Supersonic
+            114, // Haze
+            103, // Screech
+            86, // Thunder Wave
+            350, // Rock Blast
+            300, // Mud Sport
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Octillery,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(11, 132), // Constrict
+            level(22, 60), // Psybeam
+            level(22, 62), // Aurora Beam
+            level(22, 61), // Bubble Beam
+            level(25, 190), // Octazooka
+            level(38, 116), // Focus Energy
+            level(54, 58), // Ice Beam
+            level(70, 63), // Hyper Beam
+        ],
+        machines: &[
+            352, 92, 258, 237, 241, 58, 59, 63, 182, 240,
+            218, 216, 94, 104, 53, 188, 126, 263, 290, 156,
+            213, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Delibird,
+        level_up: &[
+            level(1, 217), // Present
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 218, 216, 104,
+            332, 263, 290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                207, 214, 129, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            62, // Aurora Beam
+            98, // Quick Attack
+            248, // Future Sight
+            150, // Splash
+            229, // Rapid Spin
+            301, // Ice Ball
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mantine,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 145), // Bubble
+            level(8, 48), // Supersonic
+            level(15, 61), // Bubble Beam
+            level(22, 36), // Take Down
+            level(29, 97), // Agility
+            level(36, 17), // Wing Attack
+            level(43, 352), // Water Pulse
+            level(50, 109), // Confuse Ray
+            level(57, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            89, 216, 104, 332, 263, 290, 156, 213, 57, 127,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            239, // Twister
+            56, // Hydro Pump
+            114, // Haze
+            21, // Slam
+            300, // Mud Sport
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Skarmory,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 64), // Peck
+            level(10, 28), // Sand Attack
+            level(13, 129), // Swift
+            level(16, 97), // Agility
+            level(26, 31), // Fury Attack
+            level(29, 314), // Air Cutter
+            level(32, 211), // Steel Wing
+            level(42, 191), // Spikes
+            level(45, 319), // Metal Sound
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 218, 216, 91, 104, 201,
+            317, 332, 259, 263, 290, 156, 213, 168, 211, 15,
+            19, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 38, 189, 210, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            65, // Drill Peck
+            228, // Pursuit
+            18, // Whirlwind
+            143, // Sky Attack
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Houndour,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 52), // Ember
+            level(7, 46), // Roar
+            level(13, 123), // Smog
+            level(19, 44), // Bite
+            level(25, 316), // Odor Sleuth
+            level(31, 251), // Beat Up
+            level(37, 185), // Feint Attack
+            level(43, 53), // Flamethrower
+            level(49, 242), // Crunch
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 182, 218, 216, 247, 104,
+            53, 188, 126, 259, 263, 290, 156, 213, 168, 289,
+            315, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            83, // Fire Spin
+            99, // Rage
+            228, // Pursuit
+            68, // Counter
+            180, // Spite
+            179, // Reversal
+            251, // Beat Up
+            261, // Will-O-Wisp
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Houndoom,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 52), // Ember
+            level(1, 46), // Roar
+            level(1, 123), // Smog
+            level(7, 46), // Roar
+            level(13, 123), // Smog
+            level(19, 44), // Bite
+            level(27, 316), // Odor Sleuth
+            level(35, 251), // Beat Up
+            level(43, 185), // Feint Attack
+            level(51, 53), // Flamethrower
+            level(59, 242), // Crunch
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 63, 182, 218, 216, 247,
+            104, 53, 188, 126, 259, 263, 290, 156, 213, 168,
+            289, 315, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kingdra,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 108), // Smokescreen
+            level(1, 43), // Leer
+            level(1, 55), // Water Gun
+            level(8, 108), // Smokescreen
+            level(15, 43), // Leer
+            level(22, 55), // Water Gun
+            level(29, 239), // Twister
+            level(40, 97), // Agility
+            level(51, 56), // Hydro Pump
+            level(62, 349), // Dragon Dance
+        ],
+        machines: &[
+            337, 352, 92, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Phanpy,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 316), // Odor Sleuth
+            level(9, 111), // Defense Curl
+            level(17, 175), // Flail
+            level(25, 36), // Take Down
+            level(33, 205), // Rollout
+            level(41, 203), // Endure
+            level(49, 38), // Double-Edge
+        ],
+        machines: &[
+            46, 92, 237, 182, 218, 89, 216, 91, 104, 201,
+            317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 69, 68, 102, 157, 164, 34, 38, 189, 207,
+                214, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            116, // Focus Energy
+            34, // Body Slam
+            246, // Ancient Power
+            55, // Water Gun
+            173, // Snore
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Donphan,
+        level_up: &[
+            level(1, 30), // Horn Attack
+            level(1, 45), // Growl
+            level(1, 316), // Odor Sleuth
+            level(1, 111), // Defense Curl
+            level(9, 111), // Defense Curl
+            level(17, 175), // Flail
+            level(25, 31), // Fury Attack
+            level(33, 205), // Rollout
+            level(41, 229), // Rapid Spin
+            level(49, 89), // Earthquake
+        ],
+        machines: &[
+            46, 92, 237, 63, 182, 218, 89, 216, 91, 104,
+            201, 317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 69, 68, 102, 157, 164, 34, 38, 189, 207,
+                214, 111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Porygon2,
+        level_up: &[
+            level(1, 176), // Conversion 2
+            level(1, 33), // Tackle
+            level(1, 160), // Conversion
+            level(9, 97), // Agility
+            level(12, 60), // Psybeam
+            level(20, 105), // Recover
+            level(24, 111), // Defense Curl
+            level(32, 199), // Lock-On
+            level(36, 161), // Tri Attack
+            level(44, 278), // Recycle
+            level(48, 192), // Zap Cannon
+            level(56, 63), // Hyper Beam
+        ],
+        machines: &[
+            92, 237, 58, 59, 63, 113, 182, 240, 218, 231,
+            85, 87, 216, 94, 247, 104, 115, 351, 263, 290,
+            156, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 129, 111, 173,
+                244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Stantler,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(7, 43), // Leer
+            level(13, 310), // Astonish
+            level(19, 95), // Hypnosis
+            level(25, 23), // Stomp
+            level(31, 28), // Sand Attack
+            level(37, 36), // Take Down
+            level(43, 109), // Confuse Ray
+            level(49, 347), // Calm Mind
+        ],
+        machines: &[
+            347, 92, 237, 241, 182, 240, 218, 231, 85, 87,
+            216, 94, 247, 104, 351, 263, 290, 156, 213, 168,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 207, 214, 129, 173,
+                244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            115, // Reflect
+            180, // Spite
+            50, // Disable
+            113, // Light Screen
+            44, // Bite
+            326, // Extrasensory
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Smeargle,
+        level_up: &[
+            level(1, 166), // Sketch
+            level(11, 166), // Sketch
+            level(21, 166), // Sketch
+            level(31, 166), // Sketch
+            level(41, 166), // Sketch
+            level(51, 166), // Sketch
+            level(61, 166), // Sketch
+            level(71, 166), // Sketch
+            level(81, 166), // Sketch
+            level(91, 166), // Sketch
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tyrogue,
+        level_up: &[
+            level(1, 33), // Tackle
+        ],
+        machines: &[
+            264, 92, 339, 237, 182, 218, 216, 280, 104, 332,
+            263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 164, 5, 25, 34, 38,
+                189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            229, // Rapid Spin
+            136, // High Jump Kick
+            183, // Mach Punch
+            170, // Mind Reader
+            270, // Helping Hand
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hitmontop,
+        level_up: &[
+            level(1, 279), // Revenge
+            level(7, 116), // Focus Energy
+            level(13, 228), // Pursuit
+            level(19, 229), // Rapid Spin
+            level(20, 167), // Triple Kick
+            level(25, 98), // Quick Attack
+            level(31, 68), // Counter
+            level(37, 97), // Agility
+            level(43, 197), // Detect
+            level(49, 283), // Endeavor
+            level(55, 91), // Dig
+        ],
+        machines: &[
+            264, 92, 339, 237, 63, 182, 218, 216, 91, 280,
+            104, 317, 332, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Smoochum,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 122), // Lick
+            level(9, 186), // Sweet Kiss
+            level(13, 181), // Powder Snow
+            level(21, 93), // Confusion
+            level(25, 47), // Sing
+            level(33, 212), // Mean Look
+            level(37, 313), // Fake Tears
+            level(45, 94), // Psychic
+            level(49, 195), // Perish Song
+            level(57, 59), // Blizzard
+        ],
+        machines: &[
+            352, 347, 92, 258, 237, 58, 59, 113, 182, 240,
+            218, 216, 94, 247, 104, 115, 263, 290, 156, 213,
+            285, 289,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 164, 5, 25, 34, 38,
+                118, 207, 214, 173, 8, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            96, // Meditate
+            244, // Psych Up
+            252, // Fake Out
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Elekid,
+        level_up: &[
+            level(1, 98), // Quick Attack
+            level(1, 43), // Leer
+            level(9, 9), // Thunder Punch
+            level(17, 113), // Light Screen
+            level(25, 129), // Swift
+            level(33, 103), // Screech
+            level(41, 85), // Thunderbolt
+            level(49, 87), // Thunder
+        ],
+        machines: &[
+            264, 92, 237, 113, 182, 218, 85, 87, 216, 280,
+            104, 351, 259, 263, 290, 156, 213, 168, 70, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 86, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 86, 164, 5, 25, 34, 38,
+                118, 207, 214, 129, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            2, // Karate Chop
+            112, // Barrier
+            27, // Rolling Kick
+            96, // Meditate
+            238, // Cross Chop
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Magby,
+        level_up: &[
+            level(1, 52), // Ember
+            level(7, 43), // Leer
+            level(13, 123), // Smog
+            level(19, 7), // Fire Punch
+            level(25, 108), // Smokescreen
+            level(31, 241), // Sunny Day
+            level(37, 53), // Flamethrower
+            level(43, 109), // Confuse Ray
+            level(49, 126), // Fire Blast
+        ],
+        machines: &[
+            264, 92, 237, 241, 182, 218, 216, 280, 104, 53,
+            126, 259, 263, 290, 156, 213, 168, 315, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                207, 214, 173, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            2, // Karate Chop
+            5, // Mega Punch
+            112, // Barrier
+            103, // Screech
+            238, // Cross Chop
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Miltank,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 45), // Growl
+            level(8, 111), // Defense Curl
+            level(13, 23), // Stomp
+            level(19, 208), // Milk Drink
+            level(26, 117), // Bide
+            level(34, 205), // Rollout
+            level(43, 34), // Body Slam
+            level(53, 215), // Heal Bell
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 231, 85, 87, 89, 216, 94, 247, 280, 104,
+            351, 53, 201, 126, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            217, // Present
+            179, // Reversal
+            69, // Seismic Toss
+            203, // Endure
+            244, // Psych Up
+            174, // Curse
+            270, // Helping Hand
+            214, // Sleep Talk
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Blissey,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 45), // Growl
+            level(4, 39), // Tail Whip
+            level(7, 287), // Refresh
+            level(10, 135), // Soft-Boiled
+            level(13, 3), // Double Slap
+            level(18, 107), // Minimize
+            level(23, 47), // Sing
+            level(28, 121), // Egg Bomb
+            level(33, 111), // Defense Curl
+            level(40, 113), // Light Screen
+            level(47, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 352, 347, 92, 258, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 85, 87, 216, 94, 247,
+            280, 104, 115, 351, 53, 201, 126, 263, 290, 156,
+            213, 289, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 135, 138,
+                86, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 135, 138, 86, 157, 164,
+                5, 25, 34, 38, 118, 189, 207, 214, 111, 173,
+                8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Raikou,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(11, 84), // Thunder Shock
+            level(21, 46), // Roar
+            level(31, 98), // Quick Attack
+            level(41, 209), // Spark
+            level(51, 115), // Reflect
+            level(61, 242), // Crunch
+            level(71, 87), // Thunder
+            level(81, 347), // Calm Mind
+        ],
+        machines: &[
+            347, 46, 92, 237, 63, 113, 182, 240, 218, 231,
+            85, 87, 216, 91, 247, 104, 115, 351, 263, 290,
+            156, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Entei,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(11, 52), // Ember
+            level(21, 46), // Roar
+            level(31, 83), // Fire Spin
+            level(41, 23), // Stomp
+            level(51, 53), // Flamethrower
+            level(61, 207), // Swagger
+            level(71, 126), // Fire Blast
+            level(81, 347), // Calm Mind
+        ],
+        machines: &[
+            347, 46, 92, 237, 241, 63, 182, 218, 231, 216,
+            91, 247, 104, 115, 53, 126, 263, 290, 156, 315,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Suicune,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(11, 61), // Bubble Beam
+            level(21, 240), // Rain Dance
+            level(31, 16), // Gust
+            level(41, 62), // Aurora Beam
+            level(51, 54), // Mist
+            level(61, 243), // Mirror Coat
+            level(71, 56), // Hydro Pump
+            level(81, 347), // Calm Mind
+        ],
+        machines: &[
+            352, 347, 46, 92, 258, 237, 58, 59, 63, 182,
+            240, 218, 231, 216, 91, 247, 104, 115, 263, 290,
+            156, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Larvitar,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(8, 201), // Sandstorm
+            level(15, 103), // Screech
+            level(22, 157), // Rock Slide
+            level(29, 37), // Thrash
+            level(36, 184), // Scary Face
+            level(43, 242), // Crunch
+            level(50, 89), // Earthquake
+            level(57, 63), // Hyper Beam
+        ],
+        machines: &[
+            46, 92, 237, 182, 218, 89, 216, 91, 104, 201,
+            317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            228, // Pursuit
+            23, // Stomp
+            200, // Outrage
+            116, // Focus Energy
+            246, // Ancient Power
+            349, // Dragon Dance
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pupitar,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(1, 201), // Sandstorm
+            level(1, 103), // Screech
+            level(8, 201), // Sandstorm
+            level(15, 103), // Screech
+            level(22, 157), // Rock Slide
+            level(29, 37), // Thrash
+            level(38, 184), // Scary Face
+            level(47, 242), // Crunch
+            level(56, 89), // Earthquake
+            level(65, 63), // Hyper Beam
+        ],
+        machines: &[
+            46, 92, 237, 182, 218, 89, 216, 91, 104, 201,
+            317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tyranitar,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(1, 201), // Sandstorm
+            level(1, 103), // Screech
+            level(8, 201), // Sandstorm
+            level(15, 103), // Screech
+            level(22, 157), // Rock Slide
+            level(29, 37), // Thrash
+            level(38, 184), // Scary Face
+            level(47, 242), // Crunch
+            level(61, 89), // Earthquake
+            level(75, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 337, 352, 46, 92, 258, 237, 269, 58, 59,
+            63, 113, 182, 240, 218, 231, 85, 87, 89, 216,
+            91, 280, 104, 53, 201, 126, 317, 332, 259, 263,
+            290, 156, 213, 168, 15, 57, 70, 249, 127,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                189, 210, 207, 214, 173, 8, 9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lugia,
+        level_up: &[
+            level(1, 177), // Aeroblast
+            level(11, 219), // Safeguard
+            level(22, 16), // Gust
+            level(33, 105), // Recover
+            level(44, 56), // Hydro Pump
+            level(55, 240), // Rain Dance
+            level(66, 129), // Swift
+            level(77, 246), // Ancient Power
+            level(88, 326), // Extrasensory
+            level(99, 248), // Future Sight
+        ],
+        machines: &[
+            352, 347, 46, 92, 258, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 231, 85, 87, 89, 216,
+            94, 247, 104, 115, 351, 201, 332, 263, 290, 156,
+            211, 285, 19, 57, 70, 148, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 189, 207, 214, 129,
+                173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::HoOh,
+        level_up: &[
+            level(1, 221), // Sacred Fire
+            level(11, 219), // Safeguard
+            level(22, 16), // Gust
+            level(33, 105), // Recover
+            level(44, 126), // Fire Blast
+            level(55, 241), // Sunny Day
+            level(66, 129), // Swift
+            level(77, 246), // Ancient Power
+            level(88, 326), // Extrasensory
+            level(99, 248), // Future Sight
+        ],
+        machines: &[
+            347, 46, 92, 237, 241, 63, 113, 182, 240, 219,
+            218, 76, 231, 85, 87, 89, 216, 94, 247, 104,
+            115, 351, 53, 201, 126, 332, 263, 290, 156, 211,
+            285, 315, 19, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 189, 207, 214, 129,
+                173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Celebi,
+        level_up: &[
+            level(1, 73), // Leech Seed
+            level(1, 93), // Confusion
+            level(1, 105), // Recover
+            level(1, 215), // Heal Bell
+            level(10, 219), // Safeguard
+            level(20, 246), // Ancient Power
+            level(30, 248), // Future Sight
+            level(40, 226), // Baton Pass
+            level(50, 195), // Perish Song
+        ],
+        machines: &[
+            347, 92, 331, 237, 241, 113, 182, 202, 219, 218,
+            76, 216, 94, 247, 104, 115, 263, 290, 156, 285,
+            15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 118, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 14, 34, 38, 118, 189, 207, 214,
+                129, 173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Treecko,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 43), // Leer
+            level(6, 71), // Absorb
+            level(11, 98), // Quick Attack
+            level(16, 228), // Pursuit
+            level(21, 103), // Screech
+            level(26, 72), // Mega Drain
+            level(31, 97), // Agility
+            level(36, 21), // Slam
+            level(41, 197), // Detect
+            level(46, 202), // Giga Drain
+        ],
+        machines: &[
+            264, 92, 331, 237, 182, 202, 218, 76, 231, 216,
+            91, 280, 104, 317, 332, 263, 290, 156, 213, 15,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            242, // Crunch
+            300, // Mud Sport
+            283, // Endeavor
+            73, // Leech Seed
+            225, // Dragon Breath
+            306, // Crush Claw
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Grovyle,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 43), // Leer
+            level(1, 71), // Absorb
+            level(1, 98), // Quick Attack
+            level(6, 71), // Absorb
+            level(11, 98), // Quick Attack
+            level(16, 210), // Fury Cutter
+            level(17, 228), // Pursuit
+            level(23, 103), // Screech
+            level(29, 348), // Leaf Blade
+            level(35, 97), // Agility
+            level(41, 21), // Slam
+            level(47, 197), // Detect
+            level(53, 206), // False Swipe
+        ],
+        machines: &[
+            264, 92, 331, 237, 182, 202, 218, 76, 231, 216,
+            91, 280, 104, 317, 332, 263, 290, 156, 213, 15,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sceptile,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 43), // Leer
+            level(1, 71), // Absorb
+            level(1, 98), // Quick Attack
+            level(6, 71), // Absorb
+            level(11, 98), // Quick Attack
+            level(16, 210), // Fury Cutter
+            level(17, 228), // Pursuit
+            level(23, 103), // Screech
+            level(29, 348), // Leaf Blade
+            level(35, 97), // Agility
+            level(43, 21), // Slam
+            level(51, 197), // Detect
+            level(59, 206), // False Swipe
+        ],
+        machines: &[
+            264, 337, 92, 331, 237, 63, 182, 202, 218, 76,
+            231, 89, 216, 91, 280, 104, 317, 332, 263, 290,
+            156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164, 338,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Torchic,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(7, 116), // Focus Energy
+            level(10, 52), // Ember
+            level(16, 64), // Peck
+            level(19, 28), // Sand Attack
+            level(25, 83), // Fire Spin
+            level(28, 98), // Quick Attack
+            level(34, 163), // Slash
+            level(37, 119), // Mirror Move
+            level(43, 53), // Flamethrower
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 216, 91, 104, 53,
+            126, 332, 263, 290, 156, 213, 315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            68, // Counter
+            179, // Reversal
+            203, // Endure
+            207, // Swagger
+            157, // Rock Slide
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Combusken,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(1, 116), // Focus Energy
+            level(1, 52), // Ember
+            level(7, 116), // Focus Energy
+            level(13, 52), // Ember
+            level(16, 24), // Double Kick
+            level(17, 64), // Peck
+            level(21, 28), // Sand Attack
+            level(28, 339), // Bulk Up
+            level(32, 98), // Quick Attack
+            level(39, 163), // Slash
+            level(43, 119), // Mirror Move
+            level(50, 327), // Sky Uppercut
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 182, 218, 216, 91,
+            280, 104, 53, 126, 317, 332, 263, 290, 156, 213,
+            315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Blaziken,
+        level_up: &[
+            level(1, 7), // Fire Punch
+            level(1, 10), // Scratch
+            level(1, 45), // Growl
+            level(1, 116), // Focus Energy
+            level(1, 52), // Ember
+            level(7, 116), // Focus Energy
+            level(13, 52), // Ember
+            level(16, 24), // Double Kick
+            level(17, 64), // Peck
+            level(21, 28), // Sand Attack
+            level(28, 339), // Bulk Up
+            level(32, 98), // Quick Attack
+            level(36, 299), // Blaze Kick
+            level(42, 163), // Slash
+            level(49, 119), // Mirror Move
+            level(59, 327), // Sky Uppercut
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 218, 89,
+            216, 91, 280, 104, 53, 126, 317, 332, 263, 290,
+            156, 213, 315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+                307,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mudkip,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(6, 189), // Mud-Slap
+            level(10, 55), // Water Gun
+            level(15, 117), // Bide
+            level(19, 193), // Foresight
+            level(24, 300), // Mud Sport
+            level(28, 36), // Take Down
+            level(33, 250), // Whirlpool
+            level(37, 182), // Protect
+            level(42, 56), // Hydro Pump
+            level(46, 283), // Endeavor
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            91, 104, 201, 317, 263, 290, 156, 213, 57, 70,
+            249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 111,
+                173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            287, // Refresh
+            253, // Uproar
+            174, // Curse
+            23, // Stomp
+            301, // Ice Ball
+            243, // Mirror Coat
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Marshtomp,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 189), // Mud-Slap
+            level(1, 55), // Water Gun
+            level(6, 189), // Mud-Slap
+            level(10, 55), // Water Gun
+            level(15, 117), // Bide
+            level(16, 341), // Mud Shot
+            level(20, 193), // Foresight
+            level(25, 300), // Mud Sport
+            level(31, 36), // Take Down
+            level(37, 330), // Muddy Water
+            level(42, 182), // Protect
+            level(46, 89), // Earthquake
+            level(53, 283), // Endeavor
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 89,
+            216, 91, 280, 104, 201, 317, 263, 290, 156, 213,
+            57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Swampert,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 189), // Mud-Slap
+            level(1, 55), // Water Gun
+            level(6, 189), // Mud-Slap
+            level(10, 55), // Water Gun
+            level(15, 117), // Bide
+            level(16, 341), // Mud Shot
+            level(20, 193), // Foresight
+            level(25, 300), // Mud Sport
+            level(31, 36), // Take Down
+            level(39, 330), // Muddy Water
+            level(46, 182), // Protect
+            level(52, 89), // Earthquake
+            level(61, 283), // Endeavor
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 89, 216, 91, 280, 104, 201, 317, 263, 290,
+            156, 213, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164, 308,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 111, 173, 8, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Poochyena,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 336), // Howl
+            level(9, 28), // Sand Attack
+            level(13, 44), // Bite
+            level(17, 316), // Odor Sleuth
+            level(21, 46), // Roar
+            level(25, 207), // Swagger
+            level(29, 184), // Scary Face
+            level(33, 36), // Take Down
+            level(37, 269), // Taunt
+            level(41, 242), // Crunch
+            level(45, 168), // Thief
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 182, 240, 218, 231, 216,
+            91, 247, 104, 259, 263, 290, 156, 213, 168, 289,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 173, 244,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            310, // Astonish
+            305, // Poison Fang
+            343, // Covet
+            43, // Leer
+            281, // Yawn
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mightyena,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 336), // Howl
+            level(1, 28), // Sand Attack
+            level(1, 44), // Bite
+            level(5, 336), // Howl
+            level(9, 28), // Sand Attack
+            level(13, 44), // Bite
+            level(17, 316), // Odor Sleuth
+            level(22, 46), // Roar
+            level(27, 207), // Swagger
+            level(32, 184), // Scary Face
+            level(37, 36), // Take Down
+            level(42, 269), // Taunt
+            level(47, 242), // Crunch
+            level(52, 168), // Thief
+        ],
+        machines: &[
+            46, 92, 237, 241, 269, 63, 182, 240, 218, 231,
+            216, 91, 247, 104, 259, 263, 290, 156, 213, 168,
+            289, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 173, 244,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Zigzagoon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(5, 39), // Tail Whip
+            level(9, 29), // Headbutt
+            level(13, 28), // Sand Attack
+            level(17, 316), // Odor Sleuth
+            level(21, 300), // Mud Sport
+            level(25, 42), // Pin Missile
+            level(29, 343), // Covet
+            level(33, 175), // Flail
+            level(37, 156), // Rest
+            level(41, 187), // Belly Drum
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 59, 182, 240, 218, 231,
+            85, 87, 216, 91, 247, 104, 351, 263, 290, 156,
+            213, 168, 15, 57, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 164, 34, 38, 189, 207, 214, 129,
+                111, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            204, // Charm
+            228, // Pursuit
+            164, // Substitute
+            321, // Tickle
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Linoone,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 45), // Growl
+            level(1, 39), // Tail Whip
+            level(1, 29), // Headbutt
+            level(5, 39), // Tail Whip
+            level(9, 29), // Headbutt
+            level(13, 28), // Sand Attack
+            level(17, 316), // Odor Sleuth
+            level(23, 300), // Mud Sport
+            level(29, 154), // Fury Swipes
+            level(35, 343), // Covet
+            level(41, 163), // Slash
+            level(47, 156), // Rest
+            level(53, 187), // Belly Drum
+        ],
+        machines: &[
+            352, 92, 237, 241, 58, 59, 63, 182, 240, 218,
+            231, 85, 87, 216, 91, 247, 104, 351, 332, 263,
+            290, 156, 213, 168, 15, 57, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 164, 14, 34, 38, 189, 207, 214,
+                129, 111, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wurmple,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 81), // String Shot
+            level(5, 40), // Poison Sting
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Silcoon,
+        level_up: &[
+            level(1, 106), // Harden
+            level(7, 106), // Harden
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Beautifly,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(10, 71), // Absorb
+            level(13, 16), // Gust
+            level(17, 78), // Stun Spore
+            level(20, 234), // Morning Sun
+            level(24, 72), // Mega Drain
+            level(27, 18), // Whirlwind
+            level(31, 213), // Attract
+            level(34, 318), // Silver Wind
+            level(38, 202), // Giga Drain
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 202, 218, 76, 216, 94,
+            247, 104, 332, 263, 290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cascoon,
+        level_up: &[
+            level(1, 106), // Harden
+            level(7, 106), // Harden
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dustox,
+        level_up: &[
+            level(1, 93), // Confusion
+            level(10, 93), // Confusion
+            level(13, 77), // Poison Powder
+            level(17, 236), // Moonlight
+            level(20, 60), // Psybeam
+            level(24, 18), // Whirlwind
+            level(27, 113), // Light Screen
+            level(31, 92), // Toxic
+            level(34, 318), // Silver Wind
+            level(38, 94), // Psychic
+        ],
+        machines: &[
+            92, 237, 241, 63, 113, 182, 202, 218, 76, 216,
+            94, 247, 104, 188, 332, 263, 290, 156, 213, 168,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lotad,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(3, 45), // Growl
+            level(7, 71), // Absorb
+            level(13, 267), // Nature Power
+            level(21, 54), // Mist
+            level(31, 240), // Rain Dance
+            level(43, 72), // Mega Drain
+        ],
+        machines: &[
+            352, 92, 258, 331, 237, 58, 59, 182, 240, 202,
+            218, 76, 216, 104, 263, 290, 156, 213, 57, 148,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            235, // Synthesis
+            75, // Razor Leaf
+            230, // Sweet Scent
+            73, // Leech Seed
+            175, // Flail
+            55, // Water Gun
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lombre,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(1, 45), // Growl
+            level(1, 71), // Absorb
+            level(3, 45), // Growl
+            level(7, 71), // Absorb
+            level(13, 267), // Nature Power
+            level(19, 252), // Fake Out
+            level(25, 154), // Fury Swipes
+            level(31, 346), // Water Sport
+            level(37, 168), // Thief
+            level(43, 253), // Uproar
+            level(49, 56), // Hydro Pump
+        ],
+        machines: &[
+            264, 352, 92, 258, 331, 237, 58, 59, 182, 240,
+            202, 218, 76, 216, 91, 280, 104, 263, 290, 156,
+            213, 168, 15, 57, 70, 148, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 38, 210,
+                207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ludicolo,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(1, 45), // Growl
+            level(1, 71), // Absorb
+            level(1, 267), // Nature Power
+        ],
+        machines: &[
+            264, 352, 92, 258, 331, 237, 58, 59, 63, 182,
+            240, 202, 218, 76, 216, 91, 280, 104, 263, 290,
+            156, 213, 168, 15, 57, 70, 148, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 38, 118,
+                210, 207, 214, 173, 8, 203, 196,
+            ],
+            xd: &[
+                38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Seedot,
+        level_up: &[
+            level(1, 117), // Bide
+            level(3, 106), // Harden
+            level(7, 74), // Growth
+            level(13, 267), // Nature Power
+            level(21, 235), // Synthesis
+            level(31, 241), // Sunny Day
+            level(43, 153), // Explosion
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            201, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 153, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 164, 14, 38, 207, 214, 111, 173,
+                203,
+            ],
+            xd: &[
+                38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            73, // Leech Seed
+            133, // Amnesia
+            98, // Quick Attack
+            13, // Razor Wind
+            36, // Take Down
+            206, // False Swipe
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nuzleaf,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 106), // Harden
+            level(1, 74), // Growth
+            level(3, 106), // Harden
+            level(7, 74), // Growth
+            level(13, 267), // Nature Power
+            level(19, 252), // Fake Out
+            level(25, 259), // Torment
+            level(31, 185), // Feint Attack
+            level(37, 13), // Razor Wind
+            level(43, 207), // Swagger
+            level(49, 326), // Extrasensory
+        ],
+        machines: &[
+            264, 92, 331, 237, 241, 269, 182, 202, 218, 76,
+            216, 91, 280, 104, 201, 259, 263, 290, 156, 213,
+            168, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 38, 68, 69, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 164, 5, 25, 14,
+                38, 210, 207, 214, 111, 173, 203,
+            ],
+            xd: &[
+                38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shiftry,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 106), // Harden
+            level(1, 74), // Growth
+            level(1, 267), // Nature Power
+        ],
+        machines: &[
+            264, 92, 331, 237, 241, 269, 63, 182, 202, 218,
+            76, 216, 91, 280, 104, 201, 332, 259, 263, 290,
+            156, 213, 168, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 38, 68, 69, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 164, 5, 25, 14,
+                38, 210, 207, 214, 111, 173, 203,
+            ],
+            xd: &[
+                38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Taillow,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(4, 116), // Focus Energy
+            level(8, 98), // Quick Attack
+            level(13, 17), // Wing Attack
+            level(19, 104), // Double Team
+            level(26, 283), // Endeavor
+            level(34, 332), // Aerial Ace
+            level(43, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 182, 218, 216, 104, 332, 263, 290,
+            156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            228, // Pursuit
+            48, // Supersonic
+            287, // Refresh
+            119, // Mirror Move
+            99, // Rage
+            143, // Sky Attack
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Swellow,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(1, 116), // Focus Energy
+            level(1, 98), // Quick Attack
+            level(4, 116), // Focus Energy
+            level(8, 98), // Quick Attack
+            level(13, 17), // Wing Attack
+            level(19, 104), // Double Team
+            level(28, 283), // Endeavor
+            level(38, 332), // Aerial Ace
+            level(49, 97), // Agility
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 218, 216, 104, 332, 263,
+            290, 156, 213, 168, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wingull,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(7, 48), // Supersonic
+            level(13, 17), // Wing Attack
+            level(21, 54), // Mist
+            level(31, 98), // Quick Attack
+            level(43, 228), // Pursuit
+            level(55, 97), // Agility
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 332, 263, 290, 156, 213, 168, 211, 19, 57,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            54, // Mist
+            239, // Twister
+            97, // Agility
+            16, // Gust
+            346, // Water Sport
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Pelipper,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(1, 346), // Water Sport
+            level(1, 17), // Wing Attack
+            level(3, 55), // Water Gun
+            level(7, 48), // Supersonic
+            level(13, 17), // Wing Attack
+            level(21, 54), // Mist
+            level(25, 182), // Protect
+            level(33, 254), // Stockpile
+            level(33, 256), // Swallow
+            level(47, 255), // Spit Up
+            level(61, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 332, 263, 290, 156, 213, 168, 211, 19,
+            57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ralts,
+        level_up: &[
+            level(1, 45), // Growl
+            level(6, 93), // Confusion
+            level(11, 104), // Double Team
+            level(16, 100), // Teleport
+            level(21, 347), // Calm Mind
+            level(26, 94), // Psychic
+            level(31, 286), // Imprison
+            level(36, 248), // Future Sight
+            level(41, 95), // Hypnosis
+            level(46, 138), // Dream Eater
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 219, 218, 85, 87,
+            216, 94, 247, 104, 115, 351, 259, 263, 290, 156,
+            213, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 118, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 118, 207, 214, 111,
+                173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            50, // Disable
+            261, // Will-O-Wisp
+            212, // Mean Look
+            262, // Memento
+            194, // Destiny Bond
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kirlia,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 93), // Confusion
+            level(1, 104), // Double Team
+            level(1, 100), // Teleport
+            level(6, 93), // Confusion
+            level(11, 104), // Double Team
+            level(16, 100), // Teleport
+            level(21, 347), // Calm Mind
+            level(26, 94), // Psychic
+            level(33, 286), // Imprison
+            level(40, 248), // Future Sight
+            level(47, 95), // Hypnosis
+            level(54, 138), // Dream Eater
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 219, 218, 85, 87,
+            216, 94, 247, 104, 115, 351, 259, 263, 290, 156,
+            213, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 118, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 118, 207, 214, 111,
+                173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gardevoir,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 93), // Confusion
+            level(1, 104), // Double Team
+            level(1, 100), // Teleport
+            level(6, 93), // Confusion
+            level(11, 104), // Double Team
+            level(16, 100), // Teleport
+            level(21, 347), // Calm Mind
+            level(26, 94), // Psychic
+            level(33, 286), // Imprison
+            level(42, 248), // Future Sight
+            level(51, 95), // Hypnosis
+            level(60, 138), // Dream Eater
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 113, 182, 219, 218, 85,
+            87, 216, 94, 247, 104, 115, 351, 259, 263, 290,
+            156, 213, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 118, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 118, 207, 214, 111,
+                173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Surskit,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(7, 98), // Quick Attack
+            level(13, 230), // Sweet Scent
+            level(19, 346), // Water Sport
+            level(25, 61), // Bubble Beam
+            level(31, 97), // Agility
+            level(37, 114), // Haze
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 202, 218,
+            76, 216, 104, 263, 290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            193, // Foresight
+            341, // Mud Shot
+            60, // Psybeam
+            56, // Hydro Pump
+            170, // Mind Reader
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Masquerain,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 98), // Quick Attack
+            level(1, 230), // Sweet Scent
+            level(1, 346), // Water Sport
+            level(7, 98), // Quick Attack
+            level(13, 230), // Sweet Scent
+            level(19, 346), // Water Sport
+            level(26, 16), // Gust
+            level(33, 184), // Scary Face
+            level(40, 78), // Stun Spore
+            level(47, 318), // Silver Wind
+            level(53, 18), // Whirlwind
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 202,
+            218, 76, 216, 104, 332, 263, 290, 156, 213, 168,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shroomish,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(4, 33), // Tackle
+            level(7, 78), // Stun Spore
+            level(10, 73), // Leech Seed
+            level(16, 72), // Mega Drain
+            level(22, 29), // Headbutt
+            level(28, 77), // Poison Powder
+            level(36, 74), // Growth
+            level(45, 202), // Giga Drain
+            level(54, 147), // Spore
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 216, 104,
+            188, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            313, // Fake Tears
+            207, // Swagger
+            204, // Charm
+            206, // False Swipe
+            270, // Helping Hand
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Breloom,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(1, 33), // Tackle
+            level(1, 78), // Stun Spore
+            level(1, 73), // Leech Seed
+            level(4, 33), // Tackle
+            level(7, 78), // Stun Spore
+            level(10, 73), // Leech Seed
+            level(16, 72), // Mega Drain
+            level(22, 29), // Headbutt
+            level(23, 183), // Mach Punch
+            level(28, 68), // Counter
+            level(36, 327), // Sky Uppercut
+            level(45, 170), // Mind Reader
+            level(54, 223), // Dynamic Punch
+        ],
+        machines: &[
+            264, 92, 339, 331, 237, 241, 63, 182, 202, 218,
+            76, 89, 216, 91, 280, 104, 188, 317, 332, 263,
+            290, 156, 213, 15, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 210, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slakoth,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 281), // Yawn
+            level(7, 227), // Encore
+            level(13, 303), // Slack Off
+            level(19, 185), // Feint Attack
+            level(25, 133), // Amnesia
+            level(31, 343), // Covet
+            level(37, 68), // Counter
+            level(43, 175), // Flail
+        ],
+        machines: &[
+            264, 352, 46, 92, 237, 241, 58, 59, 182, 240,
+            218, 231, 85, 87, 89, 216, 91, 247, 280, 104,
+            351, 53, 126, 332, 263, 290, 156, 213, 168, 15,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 118, 189, 210, 207, 214, 111, 173, 8,
+                9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            228, // Pursuit
+            163, // Slash
+            34, // Body Slam
+            173, // Snore
+            306, // Crush Claw
+            174, // Curse
+            214, // Sleep Talk
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Vigoroth,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 116), // Focus Energy
+            level(1, 227), // Encore
+            level(1, 253), // Uproar
+            level(7, 227), // Encore
+            level(13, 253), // Uproar
+            level(19, 154), // Fury Swipes
+            level(25, 203), // Endure
+            level(31, 163), // Slash
+            level(37, 68), // Counter
+            level(43, 264), // Focus Punch
+            level(49, 179), // Reversal
+        ],
+        machines: &[
+            264, 352, 46, 92, 339, 237, 241, 58, 59, 182,
+            240, 218, 231, 85, 87, 89, 216, 91, 247, 280,
+            104, 351, 53, 126, 332, 263, 290, 156, 213, 168,
+            15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 118, 189, 210, 207, 214, 111, 173, 8,
+                9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Slaking,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 281), // Yawn
+            level(1, 227), // Encore
+            level(1, 303), // Slack Off
+            level(7, 227), // Encore
+            level(13, 303), // Slack Off
+            level(19, 185), // Feint Attack
+            level(25, 133), // Amnesia
+            level(31, 343), // Covet
+            level(36, 207), // Swagger
+            level(37, 68), // Counter
+            level(43, 175), // Flail
+        ],
+        machines: &[
+            264, 352, 46, 92, 339, 237, 241, 58, 59, 63,
+            182, 240, 218, 231, 85, 87, 89, 216, 91, 247,
+            280, 104, 351, 53, 126, 332, 263, 290, 156, 213,
+            168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 118, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 118, 189, 210, 207, 214, 111, 173, 8,
+                9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nincada,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(5, 141), // Leech Life
+            level(9, 28), // Sand Attack
+            level(14, 154), // Fury Swipes
+            level(19, 170), // Mind Reader
+            level(25, 206), // False Swipe
+            level(31, 189), // Mud-Slap
+            level(38, 232), // Metal Claw
+            level(45, 91), // Dig
+        ],
+        machines: &[
+            92, 237, 182, 202, 218, 216, 91, 104, 201, 263,
+            290, 156, 213, 168, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 189, 210, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            203, // Endure
+            185, // Feint Attack
+            16, // Gust
+            318, // Silver Wind
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Ninjask,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(1, 141), // Leech Life
+            level(1, 28), // Sand Attack
+            level(5, 141), // Leech Life
+            level(9, 28), // Sand Attack
+            level(14, 154), // Fury Swipes
+            level(19, 170), // Mind Reader
+            level(20, 104), // Double Team
+            level(20, 210), // Fury Cutter
+            level(20, 103), // Screech
+            level(25, 14), // Swords Dance
+            level(31, 163), // Slash
+            level(38, 97), // Agility
+            level(45, 226), // Baton Pass
+        ],
+        machines: &[
+            92, 237, 63, 182, 202, 218, 216, 91, 104, 201,
+            332, 263, 290, 156, 213, 168, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 189, 210, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shedinja,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(5, 141), // Leech Life
+            level(9, 28), // Sand Attack
+            level(14, 154), // Fury Swipes
+            level(19, 170), // Mind Reader
+            level(25, 180), // Spite
+            level(31, 109), // Confuse Ray
+            level(38, 247), // Shadow Ball
+            level(45, 288), // Grudge
+        ],
+        machines: &[
+            92, 237, 182, 202, 218, 216, 91, 247, 104, 201,
+            263, 290, 156, 168, 15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 189, 210, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Whismur,
+        level_up: &[
+            level(1, 1), // Pound
+            level(5, 253), // Uproar
+            level(11, 310), // Astonish
+            level(15, 336), // Howl
+            level(21, 48), // Supersonic
+            level(25, 23), // Stomp
+            level(31, 103), // Screech
+            level(35, 46), // Roar
+            level(41, 156), // Rest
+            level(45, 214), // Sleep Talk
+            level(51, 304), // Hyper Voice
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 216, 247, 104, 53,
+            126, 259, 263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            36, // Take Down
+            173, // Snore
+            207, // Swagger
+            326, // Extrasensory
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Loudred,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 253), // Uproar
+            level(1, 310), // Astonish
+            level(1, 336), // Howl
+            level(5, 253), // Uproar
+            level(11, 310), // Astonish
+            level(15, 336), // Howl
+            level(23, 48), // Supersonic
+            level(29, 23), // Stomp
+            level(37, 103), // Screech
+            level(43, 46), // Roar
+            level(51, 156), // Rest
+            level(57, 214), // Sleep Talk
+            level(65, 304), // Hyper Voice
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 182, 218, 89, 216, 91,
+            247, 280, 104, 53, 126, 317, 259, 263, 290, 156,
+            213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Exploud,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 253), // Uproar
+            level(1, 310), // Astonish
+            level(1, 336), // Howl
+            level(5, 253), // Uproar
+            level(11, 310), // Astonish
+            level(15, 336), // Howl
+            level(23, 48), // Supersonic
+            level(29, 23), // Stomp
+            level(37, 103), // Screech
+            level(45, 46), // Roar
+            level(55, 156), // Rest
+            level(63, 214), // Sleep Talk
+            level(71, 304), // Hyper Voice
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 63, 182, 218, 89, 216,
+            91, 247, 280, 104, 53, 126, 317, 259, 263, 290,
+            156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 111, 173, 8, 9, 7,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Makuhita,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 116), // Focus Energy
+            level(4, 28), // Sand Attack
+            level(10, 292), // Arm Thrust
+            level(13, 233), // Vital Throw
+            level(19, 252), // Fake Out
+            level(22, 18), // Whirlwind
+            level(28, 282), // Knock Off
+            level(31, 265), // Smelling Salts
+            level(37, 187), // Belly Drum
+            level(40, 203), // Endure
+            level(46, 69), // Seismic Toss
+            level(49, 179), // Reversal
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 182, 218, 89, 216, 91,
+            280, 104, 317, 263, 290, 156, 213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            185, // Feint Attack
+            197, // Detect
+            193, // Foresight
+            270, // Helping Hand
+            238, // Cross Chop
+            279, // Revenge
+            223, // Dynamic Punch
+            68, // Counter
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Hariyama,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 116), // Focus Energy
+            level(1, 28), // Sand Attack
+            level(1, 292), // Arm Thrust
+            level(4, 28), // Sand Attack
+            level(10, 292), // Arm Thrust
+            level(13, 233), // Vital Throw
+            level(19, 252), // Fake Out
+            level(22, 18), // Whirlwind
+            level(29, 282), // Knock Off
+            level(33, 265), // Smelling Salts
+            level(40, 187), // Belly Drum
+            level(44, 203), // Endure
+            level(51, 69), // Seismic Toss
+            level(55, 179), // Reversal
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 63, 182, 218, 89, 216,
+            91, 280, 104, 317, 263, 290, 156, 213, 168, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Azurill,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 150), // Splash
+            level(3, 204), // Charm
+            level(6, 39), // Tail Whip
+            level(10, 145), // Bubble
+            level(15, 21), // Slam
+            level(21, 55), // Water Gun
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 129, 111, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            227, // Encore
+            21, // Slam
+            47, // Sing
+            287, // Refresh
+            313, // Fake Tears
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Nosepass,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(7, 106), // Harden
+            level(13, 88), // Rock Throw
+            level(16, 335), // Block
+            level(22, 86), // Thunder Wave
+            level(28, 157), // Rock Slide
+            level(31, 201), // Sandstorm
+            level(37, 156), // Rest
+            level(43, 192), // Zap Cannon
+            level(46, 199), // Lock-On
+        ],
+        machines: &[
+            264, 46, 92, 237, 241, 182, 218, 85, 87, 89,
+            216, 91, 280, 104, 351, 201, 317, 263, 290, 156,
+            213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 38, 102, 86, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 153, 157, 164, 5, 34, 38, 189,
+                207, 214, 111, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            222, // Magnitude
+            205, // Rollout
+            153, // Explosion
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Skitty,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 33), // Tackle
+            level(3, 39), // Tail Whip
+            level(7, 213), // Attract
+            level(13, 47), // Sing
+            level(15, 3), // Double Slap
+            level(19, 274), // Assist
+            level(25, 204), // Charm
+            level(27, 185), // Feint Attack
+            level(31, 343), // Covet
+            level(37, 215), // Heal Bell
+            level(39, 38), // Double-Edge
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 113, 182, 240, 219,
+            218, 231, 85, 87, 216, 91, 247, 104, 351, 263,
+            290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 164, 34, 38, 189, 207, 214, 129,
+                111, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            270, // Helping Hand
+            244, // Psych Up
+            253, // Uproar
+            313, // Fake Tears
+            273, // Wish
+            226, // Baton Pass
+            164, // Substitute
+            321, // Tickle
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Delcatty,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 213), // Attract
+            level(1, 47), // Sing
+            level(1, 3), // Double Slap
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 113, 182, 240,
+            219, 218, 231, 85, 87, 216, 91, 247, 104, 351,
+            263, 290, 156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                205, 102, 86, 164, 34, 38, 189, 207, 214, 129,
+                111, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sableye,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 10), // Scratch
+            level(5, 193), // Foresight
+            level(9, 101), // Night Shade
+            level(13, 310), // Astonish
+            level(17, 154), // Fury Swipes
+            level(21, 252), // Fake Out
+            level(25, 197), // Detect
+            level(29, 185), // Feint Attack
+            level(33, 282), // Knock Off
+            level(37, 109), // Confuse Ray
+            level(41, 247), // Shadow Ball
+            level(45, 212), // Mean Look
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 182, 240, 218, 231,
+            216, 91, 94, 247, 280, 104, 332, 259, 263, 290,
+            156, 213, 168, 289, 15, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 164, 5, 25, 34, 38,
+                118, 189, 210, 207, 214, 173, 8, 9, 7, 244,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            244, // Psych Up
+            105, // Recover
+            236, // Moonlight
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Mawile,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(6, 313), // Fake Tears
+            level(11, 44), // Bite
+            level(16, 230), // Sweet Scent
+            level(21, 11), // Vice Grip
+            level(26, 185), // Feint Attack
+            level(31, 226), // Baton Pass
+            level(36, 242), // Crunch
+            level(41, 334), // Iron Defense
+            level(46, 254), // Stockpile
+            level(46, 256), // Swallow
+            level(46, 255), // Spit Up
+        ],
+        machines: &[
+            264, 92, 339, 237, 241, 269, 63, 182, 218, 231,
+            89, 216, 91, 280, 104, 53, 188, 259, 263, 290,
+            156, 213, 168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 207, 214, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            14, // Swords Dance
+            206, // False Swipe
+            305, // Poison Fang
+            244, // Psych Up
+            246, // Ancient Power
+            321, // Tickle
+            236, // Moonlight
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Aron,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 106), // Harden
+            level(7, 189), // Mud-Slap
+            level(10, 29), // Headbutt
+            level(13, 232), // Metal Claw
+            level(17, 334), // Iron Defense
+            level(21, 46), // Roar
+            level(25, 36), // Take Down
+            level(29, 231), // Iron Tail
+            level(34, 182), // Protect
+            level(39, 319), // Metal Sound
+            level(44, 38), // Double-Edge
+        ],
+        machines: &[
+            46, 92, 237, 182, 218, 231, 89, 216, 91, 104,
+            351, 201, 317, 263, 290, 156, 213, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            283, // Endeavor
+            34, // Body Slam
+            23, // Stomp
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lairon,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(1, 189), // Mud-Slap
+            level(1, 29), // Headbutt
+            level(4, 106), // Harden
+            level(7, 189), // Mud-Slap
+            level(10, 29), // Headbutt
+            level(13, 232), // Metal Claw
+            level(17, 334), // Iron Defense
+            level(21, 46), // Roar
+            level(25, 36), // Take Down
+            level(29, 231), // Iron Tail
+            level(37, 182), // Protect
+            level(45, 319), // Metal Sound
+            level(53, 38), // Double-Edge
+        ],
+        machines: &[
+            46, 92, 237, 182, 218, 231, 89, 216, 91, 104,
+            351, 201, 317, 263, 290, 156, 213, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Aggron,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(1, 189), // Mud-Slap
+            level(1, 29), // Headbutt
+            level(4, 106), // Harden
+            level(7, 189), // Mud-Slap
+            level(10, 29), // Headbutt
+            level(13, 232), // Metal Claw
+            level(17, 334), // Iron Defense
+            level(21, 46), // Roar
+            level(25, 36), // Take Down
+            level(29, 231), // Iron Tail
+            level(37, 182), // Protect
+            level(50, 319), // Metal Sound
+            level(63, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 337, 352, 46, 92, 237, 58, 59, 63, 182,
+            240, 218, 231, 85, 87, 89, 216, 91, 280, 104,
+            351, 53, 201, 126, 317, 263, 290, 156, 213, 15,
+            57, 70, 249, 127,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 34,
+                38, 189, 207, 214, 173, 8, 9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Meditite,
+        level_up: &[
+            level(1, 117), // Bide
+            level(4, 96), // Meditate
+            level(9, 93), // Confusion
+            level(12, 197), // Detect
+            level(18, 237), // Hidden Power
+            level(22, 170), // Mind Reader
+            level(28, 347), // Calm Mind
+            level(32, 136), // High Jump Kick
+            level(38, 244), // Psych Up
+            level(42, 179), // Reversal
+            level(48, 105), // Recover
+        ],
+        machines: &[
+            264, 347, 92, 339, 237, 113, 182, 218, 216, 94,
+            247, 280, 104, 115, 263, 290, 156, 213, 285, 70,
+            148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            7, // Fire Punch
+            9, // Thunder Punch
+            8, // Ice Punch
+            193, // Foresight
+            252, // Fake Out
+            226, // Baton Pass
+            223, // Dynamic Punch
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Medicham,
+        level_up: &[
+            level(1, 7), // Fire Punch
+            level(1, 9), // Thunder Punch
+            level(1, 8), // Ice Punch
+            level(1, 117), // Bide
+            level(1, 96), // Meditate
+            level(1, 93), // Confusion
+            level(1, 197), // Detect
+            level(4, 96), // Meditate
+            level(9, 93), // Confusion
+            level(12, 197), // Detect
+            level(18, 237), // Hidden Power
+            level(22, 170), // Mind Reader
+            level(28, 347), // Calm Mind
+            level(32, 136), // High Jump Kick
+            level(40, 244), // Psych Up
+            level(46, 179), // Reversal
+            level(54, 105), // Recover
+        ],
+        machines: &[
+            264, 347, 92, 339, 237, 63, 113, 182, 218, 216,
+            94, 247, 280, 104, 115, 263, 290, 156, 213, 285,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 34, 38,
+                118, 189, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Electrike,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 86), // Thunder Wave
+            level(9, 43), // Leer
+            level(12, 336), // Howl
+            level(17, 98), // Quick Attack
+            level(20, 209), // Spark
+            level(25, 316), // Odor Sleuth
+            level(28, 46), // Roar
+            level(33, 44), // Bite
+            level(36, 87), // Thunder
+            level(41, 268), // Charge
+        ],
+        machines: &[
+            46, 92, 237, 113, 182, 218, 231, 85, 87, 216,
+            104, 351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            242, // Crunch
+            29, // Headbutt
+            253, // Uproar
+            174, // Curse
+            129, // Swift
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Manectric,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 86), // Thunder Wave
+            level(1, 43), // Leer
+            level(1, 336), // Howl
+            level(4, 86), // Thunder Wave
+            level(9, 43), // Leer
+            level(12, 336), // Howl
+            level(17, 98), // Quick Attack
+            level(20, 209), // Spark
+            level(25, 316), // Odor Sleuth
+            level(31, 46), // Roar
+            level(39, 44), // Bite
+            level(45, 87), // Thunder
+            level(53, 268), // Charge
+        ],
+        machines: &[
+            46, 92, 237, 63, 113, 182, 218, 231, 85, 87,
+            216, 104, 351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Plusle,
+        level_up: &[
+            level(1, 45), // Growl
+            level(4, 86), // Thunder Wave
+            level(10, 98), // Quick Attack
+            level(13, 270), // Helping Hand
+            level(19, 209), // Spark
+            level(22, 227), // Encore
+            level(28, 313), // Fake Tears
+            level(31, 268), // Charge
+            level(37, 87), // Thunder
+            level(40, 226), // Baton Pass
+            level(47, 97), // Agility
+        ],
+        machines: &[
+            352, 92, 237, 113, 182, 218, 231, 85, 87, 216,
+            104, 351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 129, 111, 173, 8, 9,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            164, // Substitute
+            273, // Wish
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Minun,
+        level_up: &[
+            level(1, 45), // Growl
+            level(4, 86), // Thunder Wave
+            level(10, 98), // Quick Attack
+            level(13, 270), // Helping Hand
+            level(19, 209), // Spark
+            level(22, 227), // Encore
+            level(28, 204), // Charm
+            level(31, 268), // Charge
+            level(37, 87), // Thunder
+            level(40, 226), // Baton Pass
+            level(47, 97), // Agility
+        ],
+        machines: &[
+            352, 92, 237, 113, 182, 218, 231, 85, 87, 216,
+            104, 351, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 86, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 86, 164, 5, 25, 34,
+                38, 118, 189, 207, 214, 129, 111, 173, 8, 9,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            164, // Substitute
+            273, // Wish
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Volbeat,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 109), // Confuse Ray
+            level(9, 104), // Double Team
+            level(13, 236), // Moonlight
+            level(17, 98), // Quick Attack
+            level(21, 294), // Tail Glow
+            level(25, 324), // Signal Beam
+            level(29, 182), // Protect
+            level(33, 270), // Helping Hand
+            level(37, 38), // Double-Edge
+        ],
+        machines: &[
+            264, 92, 237, 241, 113, 182, 240, 218, 85, 87,
+            216, 94, 247, 280, 104, 115, 351, 332, 263, 290,
+            156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                189, 207, 214, 129, 173, 8, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            226, // Baton Pass
+            318, // Silver Wind
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Illumise,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 230), // Sweet Scent
+            level(9, 204), // Charm
+            level(13, 236), // Moonlight
+            level(17, 98), // Quick Attack
+            level(21, 273), // Wish
+            level(25, 227), // Encore
+            level(29, 260), // Flatter
+            level(33, 270), // Helping Hand
+            level(37, 343), // Covet
+        ],
+        machines: &[
+            264, 92, 237, 241, 113, 182, 240, 218, 85, 87,
+            216, 94, 247, 280, 104, 115, 351, 332, 263, 290,
+            156, 213, 168, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 34, 38, 118,
+                189, 207, 214, 129, 173, 8, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            226, // Baton Pass
+            318, // Silver Wind
+            74, // Growth
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Roselia,
+        level_up: &[
+            level(1, 71), // Absorb
+            level(5, 74), // Growth
+            level(9, 40), // Poison Sting
+            level(13, 78), // Stun Spore
+            level(17, 72), // Mega Drain
+            level(21, 73), // Leech Seed
+            level(25, 345), // Magical Leaf
+            level(29, 320), // Grass Whistle
+            level(33, 202), // Giga Drain
+            level(37, 230), // Sweet Scent
+            level(41, 275), // Ingrain
+            level(45, 92), // Toxic
+            level(49, 80), // Petal Dance
+            level(53, 312), // Aromatherapy
+            level(57, 235), // Synthesis
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 219, 218, 76, 216,
+            104, 188, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            191, // Spikes
+            235, // Synthesis
+            42, // Pin Missile
+            178, // Cotton Spore
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gulpin,
+        level_up: &[
+            level(1, 1), // Pound
+            level(6, 281), // Yawn
+            level(9, 139), // Poison Gas
+            level(14, 124), // Sludge
+            level(17, 133), // Amnesia
+            level(23, 227), // Encore
+            level(28, 92), // Toxic
+            level(34, 254), // Stockpile
+            level(34, 255), // Spit Up
+            level(34, 256), // Swallow
+            level(39, 188), // Sludge Bomb
+        ],
+        machines: &[
+            264, 92, 258, 331, 237, 241, 58, 59, 182, 240,
+            202, 218, 76, 216, 247, 280, 104, 188, 259, 263,
+            290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 164, 5, 25, 34,
+                38, 207, 214, 111, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            138, // Dream Eater
+            151, // Acid Armor
+            123, // Smog
+            220, // Pain Split
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Swalot,
+        level_up: &[
+            level(1, 1), // Pound
+            level(1, 281), // Yawn
+            level(1, 139), // Poison Gas
+            level(1, 124), // Sludge
+            level(6, 281), // Yawn
+            level(9, 139), // Poison Gas
+            level(14, 124), // Sludge
+            level(17, 133), // Amnesia
+            level(23, 227), // Encore
+            level(26, 34), // Body Slam
+            level(31, 92), // Toxic
+            level(40, 254), // Stockpile
+            level(40, 255), // Spit Up
+            level(40, 256), // Swallow
+            level(48, 188), // Sludge Bomb
+        ],
+        machines: &[
+            264, 92, 258, 331, 237, 241, 58, 59, 63, 182,
+            240, 202, 218, 76, 89, 216, 247, 280, 104, 188,
+            259, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 164, 5, 25, 34,
+                38, 207, 214, 111, 173, 8, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Carvanha,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 44), // Bite
+            level(7, 99), // Rage
+            level(13, 116), // Focus Energy
+            level(16, 184), // Scary Face
+            level(22, 242), // Crunch
+            level(28, 103), // Screech
+            level(31, 36), // Take Down
+            level(37, 207), // Swagger
+            level(43, 97), // Agility
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 269, 58, 59, 182, 240,
+            218, 216, 247, 104, 259, 263, 290, 156, 213, 168,
+            57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            56, // Hydro Pump
+            38, // Double-Edge
+            37, // Thrash
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sharpedo,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 44), // Bite
+            level(1, 99), // Rage
+            level(1, 116), // Focus Energy
+            level(7, 99), // Rage
+            level(13, 116), // Focus Energy
+            level(16, 184), // Scary Face
+            level(22, 242), // Crunch
+            level(28, 103), // Screech
+            level(33, 163), // Slash
+            level(38, 269), // Taunt
+            level(43, 207), // Swagger
+            level(48, 130), // Skull Bash
+            level(53, 97), // Agility
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 269, 58, 59, 63, 182,
+            240, 218, 89, 216, 247, 280, 104, 259, 263, 290,
+            156, 213, 168, 57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wailmer,
+        level_up: &[
+            level(1, 150), // Splash
+            level(5, 45), // Growl
+            level(10, 55), // Water Gun
+            level(14, 205), // Rollout
+            level(19, 250), // Whirlpool
+            level(23, 310), // Astonish
+            level(28, 352), // Water Pulse
+            level(32, 54), // Mist
+            level(37, 156), // Rest
+            level(41, 323), // Water Spout
+            level(46, 133), // Amnesia
+            level(50, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 58, 59, 182, 240, 218,
+            216, 104, 263, 290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            38, // Double-Edge
+            37, // Thrash
+            207, // Swagger
+            173, // Snore
+            214, // Sleep Talk
+            174, // Curse
+            90, // Fissure
+            321, // Tickle
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wailord,
+        level_up: &[
+            level(1, 150), // Splash
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(1, 205), // Rollout
+            level(5, 45), // Growl
+            level(10, 55), // Water Gun
+            level(14, 205), // Rollout
+            level(19, 250), // Whirlpool
+            level(23, 310), // Astonish
+            level(28, 352), // Water Pulse
+            level(32, 54), // Mist
+            level(37, 156), // Rest
+            level(44, 323), // Water Spout
+            level(52, 133), // Amnesia
+            level(59, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 89, 216, 104, 263, 290, 156, 213, 57, 70,
+            127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Numel,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 33), // Tackle
+            level(11, 52), // Ember
+            level(19, 222), // Magnitude
+            level(25, 116), // Focus Energy
+            level(29, 36), // Take Down
+            level(31, 133), // Amnesia
+            level(35, 89), // Earthquake
+            level(41, 53), // Flamethrower
+            level(49, 38), // Double-Edge
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 89, 216, 91, 104,
+            53, 201, 126, 317, 263, 290, 156, 213, 315, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 34, 38, 189, 207, 214, 111,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            336, // Howl
+            184, // Scary Face
+            34, // Body Slam
+            205, // Rollout
+            111, // Defense Curl
+            23, // Stomp
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Camerupt,
+        level_up: &[
+            level(1, 45), // Growl
+            level(1, 33), // Tackle
+            level(1, 52), // Ember
+            level(1, 222), // Magnitude
+            level(11, 52), // Ember
+            level(19, 222), // Magnitude
+            level(25, 116), // Focus Energy
+            level(29, 36), // Take Down
+            level(31, 133), // Amnesia
+            level(33, 157), // Rock Slide
+            level(37, 89), // Earthquake
+            level(45, 284), // Eruption
+            level(55, 90), // Fissure
+        ],
+        machines: &[
+            46, 92, 237, 241, 63, 182, 218, 89, 216, 91,
+            104, 53, 201, 126, 317, 263, 290, 156, 213, 315,
+            70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Torkoal,
+        level_up: &[
+            level(1, 52), // Ember
+            level(4, 123), // Smog
+            level(7, 110), // Withdraw
+            level(13, 174), // Curse
+            level(17, 83), // Fire Spin
+            level(20, 108), // Smokescreen
+            level(23, 229), // Rapid Spin
+            level(28, 53), // Flamethrower
+            level(33, 34), // Body Slam
+            level(36, 182), // Protect
+            level(40, 175), // Flail
+            level(44, 334), // Iron Defense
+            level(49, 133), // Amnesia
+        ],
+        machines: &[
+            46, 92, 237, 241, 182, 218, 89, 216, 91, 104,
+            53, 201, 126, 317, 263, 290, 156, 213, 315, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 157, 164, 34, 38, 189, 207, 214,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[
+            284, // Eruption
+            203, // Endure
+            214, // Sleep Talk
+            281, // Yawn
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Spoink,
+        level_up: &[
+            level(1, 150), // Splash
+            level(7, 149), // Psywave
+            level(10, 316), // Odor Sleuth
+            level(16, 60), // Psybeam
+            level(19, 244), // Psych Up
+            level(25, 109), // Confuse Ray
+            level(28, 277), // Magic Coat
+            level(34, 94), // Psychic
+            level(37, 156), // Rest
+            level(37, 173), // Snore
+            level(43, 340), // Bounce
+        ],
+        machines: &[
+            347, 92, 237, 113, 182, 240, 218, 85, 87, 216,
+            94, 247, 104, 115, 351, 263, 290, 156, 213, 285,
+            289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 34, 38, 189, 207, 214, 173, 244,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            248, // Future Sight
+            326, // Extrasensory
+            164, // Substitute
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Grumpig,
+        level_up: &[
+            level(1, 150), // Splash
+            level(1, 149), // Psywave
+            level(1, 316), // Odor Sleuth
+            level(1, 60), // Psybeam
+            level(7, 149), // Psywave
+            level(10, 316), // Odor Sleuth
+            level(16, 60), // Psybeam
+            level(19, 244), // Psych Up
+            level(25, 109), // Confuse Ray
+            level(28, 277), // Magic Coat
+            level(37, 94), // Psychic
+            level(43, 156), // Rest
+            level(43, 173), // Snore
+            level(55, 340), // Bounce
+        ],
+        machines: &[
+            264, 347, 92, 237, 63, 113, 182, 240, 218, 85,
+            87, 216, 94, 247, 280, 104, 115, 351, 263, 290,
+            156, 213, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 138, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 164, 5, 25, 34, 38,
+                189, 207, 214, 173, 8, 9, 7, 244, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Spinda,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(5, 253), // Uproar
+            level(12, 185), // Feint Attack
+            level(16, 60), // Psybeam
+            level(23, 95), // Hypnosis
+            level(27, 146), // Dizzy Punch
+            level(34, 298), // Teeter Dance
+            level(38, 244), // Psych Up
+            level(45, 38), // Double-Edge
+            level(49, 175), // Flail
+            level(56, 37), // Thrash
+        ],
+        machines: &[
+            264, 352, 92, 237, 241, 113, 182, 240, 218, 231,
+            85, 87, 216, 91, 94, 247, 280, 104, 115, 351,
+            332, 263, 290, 156, 213, 168, 285, 289, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 157,
+                164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 138, 157, 164, 5, 25,
+                34, 38, 118, 189, 207, 214, 129, 111, 173, 8,
+                9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            227, // Encore
+            157, // Rock Slide
+            274, // Assist
+            50, // Disable
+            226, // Baton Pass
+            273, // Wish
+            271, // Trick
+            265, // Smelling Salts
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Trapinch,
+        level_up: &[
+            level(1, 44), // Bite
+            level(9, 28), // Sand Attack
+            level(17, 185), // Feint Attack
+            level(25, 328), // Sand Tomb
+            level(33, 242), // Crunch
+            level(41, 91), // Dig
+            level(49, 201), // Sandstorm
+            level(57, 63), // Hyper Beam
+        ],
+        machines: &[
+            92, 237, 241, 63, 182, 202, 218, 89, 216, 91,
+            104, 188, 201, 317, 263, 290, 156, 213, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            116, // Focus Energy
+            98, // Quick Attack
+            16, // Gust
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Vibrava,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 28), // Sand Attack
+            level(1, 185), // Feint Attack
+            level(1, 328), // Sand Tomb
+            level(9, 28), // Sand Attack
+            level(17, 185), // Feint Attack
+            level(25, 328), // Sand Tomb
+            level(33, 242), // Crunch
+            level(35, 225), // Dragon Breath
+            level(41, 103), // Screech
+            level(49, 201), // Sandstorm
+            level(57, 63), // Hyper Beam
+        ],
+        machines: &[
+            337, 92, 237, 241, 63, 182, 202, 218, 89, 216,
+            91, 104, 188, 201, 317, 332, 263, 290, 156, 213,
+            211, 19, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 129, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Flygon,
+        level_up: &[
+            level(1, 44), // Bite
+            level(1, 28), // Sand Attack
+            level(1, 185), // Feint Attack
+            level(1, 328), // Sand Tomb
+            level(9, 28), // Sand Attack
+            level(17, 185), // Feint Attack
+            level(25, 328), // Sand Tomb
+            level(33, 242), // Crunch
+            level(35, 225), // Dragon Breath
+            level(41, 103), // Screech
+            level(53, 201), // Sandstorm
+            level(65, 63), // Hyper Beam
+        ],
+        machines: &[
+            337, 92, 237, 241, 63, 182, 202, 218, 76, 89,
+            216, 91, 104, 53, 188, 201, 126, 317, 332, 263,
+            290, 156, 213, 211, 315, 15, 19, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 210, 207, 214, 129,
+                173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cacnea,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 43), // Leer
+            level(5, 71), // Absorb
+            level(9, 74), // Growth
+            level(13, 73), // Leech Seed
+            level(17, 28), // Sand Attack
+            level(21, 42), // Pin Missile
+            level(25, 275), // Ingrain
+            level(29, 185), // Feint Attack
+            level(33, 191), // Spikes
+            level(37, 302), // Needle Arm
+            level(41, 178), // Cotton Spore
+            level(45, 201), // Sandstorm
+        ],
+        machines: &[
+            264, 92, 331, 237, 241, 182, 202, 218, 76, 216,
+            280, 104, 188, 201, 263, 290, 156, 213, 168, 15,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 173, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            320, // Grass Whistle
+            51, // Acid
+            298, // Teeter Dance
+            223, // Dynamic Punch
+            68, // Counter
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cacturne,
+        level_up: &[
+            level(1, 40), // Poison Sting
+            level(1, 43), // Leer
+            level(1, 71), // Absorb
+            level(1, 74), // Growth
+            level(5, 71), // Absorb
+            level(9, 74), // Growth
+            level(13, 73), // Leech Seed
+            level(17, 28), // Sand Attack
+            level(21, 42), // Pin Missile
+            level(25, 275), // Ingrain
+            level(29, 185), // Feint Attack
+            level(35, 191), // Spikes
+            level(41, 302), // Needle Arm
+            level(47, 178), // Cotton Spore
+            level(53, 201), // Sandstorm
+        ],
+        machines: &[
+            264, 92, 331, 237, 241, 63, 182, 202, 218, 76,
+            216, 280, 104, 188, 201, 263, 290, 156, 213, 168,
+            15, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 164, 5, 25, 14, 34, 38,
+                189, 210, 207, 214, 173, 9, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Swablu,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(8, 310), // Astonish
+            level(11, 47), // Sing
+            level(18, 31), // Fury Attack
+            level(21, 219), // Safeguard
+            level(28, 54), // Mist
+            level(31, 36), // Take Down
+            level(38, 119), // Mirror Move
+            level(41, 287), // Refresh
+            level(48, 195), // Perish Song
+        ],
+        machines: &[
+            92, 237, 241, 58, 59, 182, 219, 218, 216, 104,
+            332, 263, 290, 156, 213, 211, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            97, // Agility
+            114, // Haze
+            228, // Pursuit
+            99, // Rage
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Altaria,
+        level_up: &[
+            level(1, 64), // Peck
+            level(1, 45), // Growl
+            level(1, 310), // Astonish
+            level(1, 47), // Sing
+            level(8, 310), // Astonish
+            level(11, 47), // Sing
+            level(18, 31), // Fury Attack
+            level(21, 219), // Safeguard
+            level(28, 54), // Mist
+            level(31, 36), // Take Down
+            level(35, 225), // Dragon Breath
+            level(40, 349), // Dragon Dance
+            level(45, 287), // Refresh
+            level(54, 195), // Perish Song
+            level(59, 143), // Sky Attack
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 58, 59, 63, 182, 219,
+            218, 89, 216, 104, 53, 126, 332, 263, 290, 156,
+            213, 211, 315, 19,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 189, 207, 214, 129, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Zangoose,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(4, 43), // Leer
+            level(7, 98), // Quick Attack
+            level(10, 14), // Swords Dance
+            level(13, 210), // Fury Cutter
+            level(19, 163), // Slash
+            level(25, 228), // Pursuit
+            level(31, 306), // Crush Claw
+            level(37, 269), // Taunt
+            level(46, 197), // Detect
+            level(55, 206), // False Swipe
+        ],
+        machines: &[
+            264, 352, 46, 92, 258, 339, 237, 241, 269, 58,
+            59, 182, 240, 218, 231, 85, 87, 216, 91, 247,
+            280, 104, 351, 53, 126, 332, 259, 263, 290, 156,
+            213, 168, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 157, 164, 5, 25, 14,
+                34, 38, 189, 210, 207, 214, 129, 111, 173, 8,
+                9, 7, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            175, // Flail
+            24, // Double Kick
+            13, // Razor Wind
+            68, // Counter
+            46, // Roar
+            174, // Curse
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Seviper,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(7, 122), // Lick
+            level(10, 44), // Bite
+            level(16, 342), // Poison Tail
+            level(19, 103), // Screech
+            level(25, 137), // Glare
+            level(28, 242), // Crunch
+            level(34, 305), // Poison Fang
+            level(37, 207), // Swagger
+            level(43, 114), // Haze
+        ],
+        machines: &[
+            92, 331, 237, 241, 269, 182, 202, 218, 231, 89,
+            216, 91, 104, 53, 188, 126, 259, 263, 290, 156,
+            213, 168, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            254, // Stockpile
+            256, // Swallow
+            255, // Spit Up
+            34, // Body Slam
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lunatone,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(7, 93), // Confusion
+            level(13, 88), // Rock Throw
+            level(19, 95), // Hypnosis
+            level(25, 149), // Psywave
+            level(31, 322), // Cosmic Power
+            level(37, 94), // Psychic
+            level(43, 248), // Future Sight
+            level(49, 153), // Explosion
+        ],
+        machines: &[
+            347, 92, 258, 237, 58, 59, 113, 182, 240, 219,
+            218, 89, 216, 94, 247, 104, 115, 201, 317, 263,
+            290, 156, 285, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 138, 153, 157, 164, 34, 38, 189, 207,
+                214, 111, 173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Solrock,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(7, 93), // Confusion
+            level(13, 88), // Rock Throw
+            level(19, 83), // Fire Spin
+            level(25, 149), // Psywave
+            level(31, 322), // Cosmic Power
+            level(37, 157), // Rock Slide
+            level(43, 76), // Solar Beam
+            level(49, 153), // Explosion
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 219, 218, 76, 89,
+            216, 94, 247, 104, 115, 201, 317, 263, 290, 156,
+            285, 315, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 138, 153, 157, 164, 34, 38, 189, 207,
+                214, 111, 173, 244, 203,
+            ],
+            xd: &[
+                34, 38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Barboach,
+        level_up: &[
+            level(1, 189), // Mud-Slap
+            level(6, 300), // Mud Sport
+            level(6, 346), // Water Sport
+            level(11, 55), // Water Gun
+            level(16, 222), // Magnitude
+            level(21, 133), // Amnesia
+            level(26, 156), // Rest
+            level(26, 173), // Snore
+            level(31, 89), // Earthquake
+            level(36, 248), // Future Sight
+            level(41, 90), // Fissure
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 89,
+            216, 104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 189, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            37, // Thrash
+            250, // Whirlpool
+            209, // Spark
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Whiscash,
+        level_up: &[
+            level(1, 321), // Tickle
+            level(1, 189), // Mud-Slap
+            level(1, 300), // Mud Sport
+            level(1, 346), // Water Sport
+            level(6, 300), // Mud Sport
+            level(6, 346), // Water Sport
+            level(11, 55), // Water Gun
+            level(16, 222), // Magnitude
+            level(21, 133), // Amnesia
+            level(26, 156), // Rest
+            level(26, 173), // Snore
+            level(36, 89), // Earthquake
+            level(46, 248), // Future Sight
+            level(56, 90), // Fissure
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            89, 216, 104, 263, 290, 156, 213, 57, 70, 127,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 38, 189, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Corphish,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(7, 106), // Harden
+            level(10, 11), // Vice Grip
+            level(13, 43), // Leer
+            level(20, 61), // Bubble Beam
+            level(23, 182), // Protect
+            level(26, 282), // Knock Off
+            level(32, 269), // Taunt
+            level(35, 152), // Crabhammer
+            level(38, 14), // Swords Dance
+            level(44, 12), // Guillotine
+        ],
+        machines: &[
+            352, 92, 258, 237, 269, 58, 59, 182, 240, 218,
+            216, 280, 104, 332, 263, 290, 156, 213, 168, 15,
+            57, 70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            300, // Mud Sport
+            283, // Endeavor
+            34, // Body Slam
+            246, // Ancient Power
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Crawdaunt,
+        level_up: &[
+            level(1, 145), // Bubble
+            level(1, 106), // Harden
+            level(1, 11), // Vice Grip
+            level(1, 43), // Leer
+            level(7, 106), // Harden
+            level(10, 11), // Vice Grip
+            level(13, 43), // Leer
+            level(20, 61), // Bubble Beam
+            level(23, 182), // Protect
+            level(26, 282), // Knock Off
+            level(34, 269), // Taunt
+            level(39, 152), // Crabhammer
+            level(44, 14), // Swords Dance
+            level(52, 12), // Guillotine
+        ],
+        machines: &[
+            352, 92, 258, 237, 269, 58, 59, 63, 182, 240,
+            218, 216, 280, 104, 332, 259, 263, 290, 156, 213,
+            168, 15, 57, 70, 249, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Baltoy,
+        level_up: &[
+            level(1, 93), // Confusion
+            level(1, 106), // Harden
+            level(3, 229), // Rapid Spin
+            level(5, 189), // Mud-Slap
+            level(7, 60), // Psybeam
+            level(11, 317), // Rock Tomb
+            level(15, 120), // Self-Destruct
+            level(19, 246), // Ancient Power
+            level(25, 201), // Sandstorm
+            level(31, 322), // Cosmic Power
+            level(37, 153), // Explosion
+            level(45, 286), // Imprison
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 219, 218, 76, 89,
+            216, 91, 94, 247, 104, 115, 201, 317, 263, 290,
+            156, 285, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 138, 153, 157, 164, 38, 189, 207, 214,
+                173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Claydol,
+        level_up: &[
+            level(1, 100), // Teleport
+            level(1, 93), // Confusion
+            level(1, 106), // Harden
+            level(1, 229), // Rapid Spin
+            level(3, 229), // Rapid Spin
+            level(5, 189), // Mud-Slap
+            level(7, 60), // Psybeam
+            level(11, 317), // Rock Tomb
+            level(15, 120), // Self-Destruct
+            level(19, 246), // Ancient Power
+            level(25, 201), // Sandstorm
+            level(31, 322), // Cosmic Power
+            level(36, 63), // Hyper Beam
+            level(42, 153), // Explosion
+            level(55, 286), // Imprison
+        ],
+        machines: &[
+            347, 92, 237, 241, 63, 113, 182, 219, 218, 76,
+            89, 216, 91, 94, 247, 104, 115, 201, 317, 263,
+            290, 156, 285, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 153, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 138, 153, 157, 164, 38, 189, 207, 214,
+                173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 120, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Lileep,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(8, 132), // Constrict
+            level(15, 51), // Acid
+            level(22, 275), // Ingrain
+            level(29, 109), // Confuse Ray
+            level(36, 133), // Amnesia
+            level(43, 246), // Ancient Power
+            level(50, 254), // Stockpile
+            level(50, 255), // Spit Up
+            level(50, 256), // Swallow
+        ],
+        machines: &[
+            92, 331, 237, 241, 182, 202, 218, 76, 89, 216,
+            104, 188, 201, 317, 263, 290, 156, 213,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            112, // Barrier
+            105, // Recover
+            243, // Mirror Coat
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Cradily,
+        level_up: &[
+            level(1, 310), // Astonish
+            level(1, 132), // Constrict
+            level(1, 51), // Acid
+            level(1, 275), // Ingrain
+            level(8, 132), // Constrict
+            level(15, 51), // Acid
+            level(22, 275), // Ingrain
+            level(29, 109), // Confuse Ray
+            level(36, 133), // Amnesia
+            level(48, 246), // Ancient Power
+            level(60, 254), // Stockpile
+            level(60, 255), // Spit Up
+            level(60, 256), // Swallow
+        ],
+        machines: &[
+            92, 331, 237, 241, 63, 182, 202, 218, 76, 89,
+            216, 104, 188, 201, 317, 263, 290, 156, 213, 70,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Anorith,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(7, 106), // Harden
+            level(13, 300), // Mud Sport
+            level(19, 55), // Water Gun
+            level(25, 232), // Metal Claw
+            level(31, 182), // Protect
+            level(37, 246), // Ancient Power
+            level(43, 210), // Fury Cutter
+            level(49, 163), // Slash
+            level(55, 350), // Rock Blast
+        ],
+        machines: &[
+            92, 237, 182, 218, 89, 216, 91, 280, 104, 201,
+            317, 332, 263, 290, 156, 213, 15, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 102, 157, 164, 14, 38, 189, 210, 207, 214,
+                173, 203,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            229, // Rapid Spin
+            282, // Knock Off
+            14, // Swords Dance
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Armaldo,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(1, 106), // Harden
+            level(1, 300), // Mud Sport
+            level(1, 55), // Water Gun
+            level(7, 106), // Harden
+            level(13, 300), // Mud Sport
+            level(19, 55), // Water Gun
+            level(25, 232), // Metal Claw
+            level(31, 182), // Protect
+            level(37, 246), // Ancient Power
+            level(46, 210), // Fury Cutter
+            level(55, 163), // Slash
+            level(64, 350), // Rock Blast
+        ],
+        machines: &[
+            92, 237, 63, 182, 218, 89, 216, 91, 280, 104,
+            201, 317, 332, 263, 290, 156, 213, 15, 57, 70,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                205, 69, 68, 102, 157, 164, 14, 34, 38, 189,
+                210, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Feebas,
+        level_up: &[
+            level(1, 150), // Splash
+            level(15, 33), // Tackle
+            level(30, 175), // Flail
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            243, // Mirror Coat
+            225, // Dragon Breath
+            300, // Mud Sport
+            95, // Hypnosis
+            113, // Light Screen
+            109, // Confuse Ray
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Milotic,
+        level_up: &[
+            level(1, 55), // Water Gun
+            level(5, 35), // Wrap
+            level(10, 346), // Water Sport
+            level(15, 287), // Refresh
+            level(20, 352), // Water Pulse
+            level(25, 239), // Twister
+            level(30, 105), // Recover
+            level(35, 240), // Rain Dance
+            level(40, 56), // Hydro Pump
+            level(45, 213), // Attract
+            level(50, 219), // Safeguard
+        ],
+        machines: &[
+            352, 46, 92, 258, 237, 58, 59, 63, 182, 240,
+            218, 231, 216, 104, 263, 290, 156, 213, 57, 127,
+            291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Castform,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(10, 55), // Water Gun
+            level(10, 52), // Ember
+            level(10, 181), // Powder Snow
+            level(20, 240), // Rain Dance
+            level(20, 241), // Sunny Day
+            level(20, 258), // Hail
+            level(30, 311), // Weather Ball
+        ],
+        machines: &[
+            352, 92, 258, 237, 241, 58, 59, 182, 240, 218,
+            231, 85, 87, 216, 247, 104, 351, 53, 126, 263,
+            290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 207, 214, 129, 111, 173,
+                244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            248, // Future Sight
+            244, // Psych Up
+            50, // Disable
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kecleon,
+        level_up: &[
+            level(1, 168), // Thief
+            level(1, 39), // Tail Whip
+            level(1, 310), // Astonish
+            level(1, 122), // Lick
+            level(1, 10), // Scratch
+            level(4, 20), // Bind
+            level(7, 185), // Feint Attack
+            level(12, 154), // Fury Swipes
+            level(17, 60), // Psybeam
+            level(24, 103), // Screech
+            level(31, 163), // Slash
+            level(40, 164), // Substitute
+            level(49, 246), // Ancient Power
+        ],
+        machines: &[
+            264, 352, 92, 258, 237, 241, 269, 58, 59, 182,
+            240, 218, 231, 85, 87, 89, 216, 91, 94, 247,
+            280, 104, 351, 53, 126, 317, 332, 259, 263, 290,
+            156, 213, 168, 285, 289, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 86,
+                157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 138, 86, 157, 164, 5,
+                25, 34, 38, 118, 189, 210, 207, 214, 129, 111,
+                173, 8, 9, 7, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            50, // Disable
+            277, // Magic Coat
+            271, // Trick
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shuppet,
+        level_up: &[
+            level(1, 282), // Knock Off
+            level(8, 103), // Screech
+            level(13, 101), // Night Shade
+            level(20, 174), // Curse
+            level(25, 180), // Spite
+            level(32, 261), // Will-O-Wisp
+            level(37, 185), // Feint Attack
+            level(44, 247), // Shadow Ball
+            level(49, 289), // Snatch
+            level(56, 288), // Grudge
+        ],
+        machines: &[
+            92, 237, 241, 269, 182, 240, 218, 85, 87, 216,
+            94, 247, 104, 351, 259, 263, 290, 156, 213, 168,
+            285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            50, // Disable
+            194, // Destiny Bond
+            193, // Foresight
+            310, // Astonish
+            286, // Imprison
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Banette,
+        level_up: &[
+            level(1, 282), // Knock Off
+            level(1, 103), // Screech
+            level(1, 101), // Night Shade
+            level(1, 174), // Curse
+            level(8, 103), // Screech
+            level(13, 101), // Night Shade
+            level(20, 174), // Curse
+            level(25, 180), // Spite
+            level(32, 261), // Will-O-Wisp
+            level(39, 185), // Feint Attack
+            level(48, 247), // Shadow Ball
+            level(55, 289), // Snatch
+            level(64, 288), // Grudge
+        ],
+        machines: &[
+            92, 237, 241, 269, 63, 182, 240, 218, 85, 87,
+            216, 94, 247, 104, 351, 259, 263, 290, 156, 213,
+            168, 285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 38, 207, 214, 173, 244, 203,
+            ],
+            xd: &[
+                38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Duskull,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 101), // Night Shade
+            level(5, 50), // Disable
+            level(12, 193), // Foresight
+            level(16, 310), // Astonish
+            level(23, 109), // Confuse Ray
+            level(27, 228), // Pursuit
+            level(34, 174), // Curse
+            level(38, 261), // Will-O-Wisp
+            level(45, 212), // Mean Look
+            level(49, 248), // Future Sight
+        ],
+        machines: &[
+            347, 92, 237, 241, 269, 58, 59, 182, 240, 218,
+            216, 94, 247, 104, 259, 263, 290, 156, 213, 168,
+            285, 289, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 173, 244, 203, 196,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            286, // Imprison
+            194, // Destiny Bond
+            220, // Pain Split
+            288, // Grudge
+            262, // Memento
+            185, // Feint Attack
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Dusclops,
+        level_up: &[
+            level(1, 20), // Bind
+            level(1, 43), // Leer
+            level(1, 101), // Night Shade
+            level(1, 50), // Disable
+            level(5, 50), // Disable
+            level(12, 193), // Foresight
+            level(16, 310), // Astonish
+            level(23, 109), // Confuse Ray
+            level(27, 228), // Pursuit
+            level(34, 174), // Curse
+            level(37, 325), // Shadow Punch
+            level(41, 261), // Will-O-Wisp
+            level(51, 212), // Mean Look
+            level(58, 248), // Future Sight
+        ],
+        machines: &[
+            264, 347, 92, 237, 241, 269, 58, 59, 63, 182,
+            240, 218, 89, 216, 94, 247, 280, 104, 201, 317,
+            259, 263, 290, 156, 213, 168, 285, 289, 70, 148,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 118, 138, 157,
+                164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 157, 164, 5, 25, 34,
+                38, 118, 207, 214, 173, 8, 9, 7, 244, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Tropius,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 16), // Gust
+            level(7, 74), // Growth
+            level(11, 75), // Razor Leaf
+            level(17, 23), // Stomp
+            level(21, 230), // Sweet Scent
+            level(27, 18), // Whirlwind
+            level(31, 345), // Magical Leaf
+            level(37, 34), // Body Slam
+            level(41, 76), // Solar Beam
+            level(47, 235), // Synthesis
+        ],
+        machines: &[
+            46, 92, 331, 237, 241, 63, 182, 202, 219, 218,
+            76, 89, 216, 104, 332, 263, 290, 156, 213, 211,
+            15, 19, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 14, 34, 38, 189, 207, 214, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            29, // Headbutt
+            21, // Slam
+            13, // Razor Wind
+            73, // Leech Seed
+            267, // Nature Power
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Chimecho,
+        level_up: &[
+            level(1, 35), // Wrap
+            level(6, 45), // Growl
+            level(9, 310), // Astonish
+            level(14, 93), // Confusion
+            level(17, 36), // Take Down
+            level(22, 253), // Uproar
+            level(25, 281), // Yawn
+            level(30, 149), // Psywave
+            level(33, 38), // Double-Edge
+            level(38, 215), // Heal Bell
+            level(41, 219), // Safeguard
+            level(46, 94), // Psychic
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 240, 219, 218, 216,
+            94, 247, 104, 115, 263, 290, 156, 213, 285, 289,
+            148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 138, 164,
+            ],
+            emerald: &[
+                102, 138, 164, 38, 207, 214, 129, 111, 173, 244,
+                203,
+            ],
+            xd: &[
+                38, 102, 138, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            50, // Disable
+            174, // Curse
+            95, // Hypnosis
+            138, // Dream Eater
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Absol,
+        level_up: &[
+            level(1, 10), // Scratch
+            level(5, 43), // Leer
+            level(9, 269), // Taunt
+            level(13, 98), // Quick Attack
+            level(17, 13), // Razor Wind
+            level(21, 44), // Bite
+            level(26, 14), // Swords Dance
+            level(31, 104), // Double Team
+            level(36, 163), // Slash
+            level(41, 248), // Future Sight
+            level(46, 195), // Perish Song
+        ],
+        machines: &[
+            352, 46, 92, 237, 241, 269, 58, 59, 63, 182,
+            240, 218, 231, 85, 87, 216, 91, 247, 104, 53,
+            126, 332, 259, 263, 290, 156, 213, 168, 289, 15,
+            249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 68, 69, 102, 138, 86, 157, 164,
+            ],
+            emerald: &[
+                69, 68, 102, 138, 86, 157, 164, 14, 34, 38,
+                189, 210, 207, 214, 129, 173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[
+            226, // Baton Pass
+            185, // Feint Attack
+            38, // Double-Edge
+            277, // Magic Coat
+            174, // Curse
+            164, // Substitute
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Wynaut,
+        level_up: &[
+            level(1, 150), // Splash
+            level(1, 204), // Charm
+            level(1, 227), // Encore
+            level(15, 68), // Counter
+            level(15, 243), // Mirror Coat
+            level(15, 219), // Safeguard
+            level(15, 194), // Destiny Bond
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Snorunt,
+        level_up: &[
+            level(1, 181), // Powder Snow
+            level(1, 43), // Leer
+            level(7, 104), // Double Team
+            level(10, 44), // Bite
+            level(16, 196), // Icy Wind
+            level(19, 29), // Headbutt
+            level(25, 182), // Protect
+            level(28, 242), // Crunch
+            level(34, 58), // Ice Beam
+            level(37, 258), // Hail
+            level(43, 59), // Blizzard
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            247, 104, 263, 290, 156, 213, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            335, // Block
+            191, // Spikes
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Glalie,
+        level_up: &[
+            level(1, 181), // Powder Snow
+            level(1, 43), // Leer
+            level(1, 104), // Double Team
+            level(1, 44), // Bite
+            level(7, 104), // Double Team
+            level(10, 44), // Bite
+            level(16, 196), // Icy Wind
+            level(19, 29), // Headbutt
+            level(25, 182), // Protect
+            level(28, 242), // Crunch
+            level(34, 58), // Ice Beam
+            level(42, 258), // Hail
+            level(53, 329), // Sheer Cold
+            level(61, 59), // Blizzard
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            89, 216, 247, 104, 263, 290, 156, 213, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 153, 164,
+            ],
+            emerald: &[
+                205, 102, 153, 164, 34, 38, 207, 214, 111, 173,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Spheal,
+        level_up: &[
+            level(1, 181), // Powder Snow
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(1, 111), // Defense Curl
+            level(7, 227), // Encore
+            level(13, 301), // Ice Ball
+            level(19, 34), // Body Slam
+            level(25, 62), // Aurora Beam
+            level(31, 258), // Hail
+            level(37, 156), // Rest
+            level(37, 173), // Snore
+            level(43, 59), // Blizzard
+            level(49, 329), // Sheer Cold
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            346, // Water Sport
+            254, // Stockpile
+            256, // Swallow
+            255, // Spit Up
+            281, // Yawn
+            157, // Rock Slide
+            174, // Curse
+            90, // Fissure
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Sealeo,
+        level_up: &[
+            level(1, 181), // Powder Snow
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(1, 227), // Encore
+            level(7, 227), // Encore
+            level(13, 301), // Ice Ball
+            level(19, 34), // Body Slam
+            level(25, 62), // Aurora Beam
+            level(31, 258), // Hail
+            level(39, 156), // Rest
+            level(39, 173), // Snore
+            level(47, 59), // Blizzard
+            level(55, 329), // Sheer Cold
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 70, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Walrein,
+        level_up: &[
+            level(1, 181), // Powder Snow
+            level(1, 45), // Growl
+            level(1, 55), // Water Gun
+            level(1, 227), // Encore
+            level(7, 227), // Encore
+            level(13, 301), // Ice Ball
+            level(19, 34), // Body Slam
+            level(25, 62), // Aurora Beam
+            level(31, 258), // Hail
+            level(39, 156), // Rest
+            level(39, 173), // Snore
+            level(50, 59), // Blizzard
+            level(61, 329), // Sheer Cold
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            89, 216, 104, 263, 290, 156, 213, 57, 70, 249,
+            127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 164,
+            ],
+            emerald: &[
+                205, 102, 164, 34, 38, 207, 214, 111, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Clamperl,
+        level_up: &[
+            level(1, 128), // Clamp
+            level(1, 55), // Water Gun
+            level(1, 250), // Whirlpool
+            level(1, 334), // Iron Defense
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            287, // Refresh
+            300, // Mud Sport
+            34, // Body Slam
+            48, // Supersonic
+            112, // Barrier
+            109, // Confuse Ray
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Huntail,
+        level_up: &[
+            level(1, 250), // Whirlpool
+            level(8, 44), // Bite
+            level(15, 103), // Screech
+            level(22, 352), // Water Pulse
+            level(29, 184), // Scary Face
+            level(36, 242), // Crunch
+            level(43, 226), // Baton Pass
+            level(50, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Gorebyss,
+        level_up: &[
+            level(1, 250), // Whirlpool
+            level(8, 93), // Confusion
+            level(15, 97), // Agility
+            level(22, 352), // Water Pulse
+            level(29, 133), // Amnesia
+            level(36, 94), // Psychic
+            level(43, 226), // Baton Pass
+            level(50, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 63, 182, 240, 218,
+            216, 94, 104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Relicanth,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(1, 106), // Harden
+            level(8, 55), // Water Gun
+            level(15, 317), // Rock Tomb
+            level(22, 281), // Yawn
+            level(29, 36), // Take Down
+            level(36, 300), // Mud Sport
+            level(43, 246), // Ancient Power
+            level(50, 156), // Rest
+            level(57, 38), // Double-Edge
+            level(64, 56), // Hydro Pump
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 89,
+            216, 104, 201, 317, 263, 290, 156, 213, 57, 70,
+            249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 173, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            222, // Magnitude
+            130, // Skull Bash
+            346, // Water Sport
+            133, // Amnesia
+            214, // Sleep Talk
+            157, // Rock Slide
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Luvdisc,
+        level_up: &[
+            level(1, 33), // Tackle
+            level(4, 204), // Charm
+            level(12, 55), // Water Gun
+            level(16, 97), // Agility
+            level(24, 36), // Take Down
+            level(28, 213), // Attract
+            level(36, 186), // Sweet Kiss
+            level(40, 175), // Flail
+            level(48, 219), // Safeguard
+        ],
+        machines: &[
+            352, 92, 258, 237, 58, 59, 182, 240, 218, 216,
+            104, 263, 290, 156, 213, 57, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                38, 102, 164,
+            ],
+            emerald: &[
+                102, 164, 38, 207, 214, 129, 173, 203, 196,
+            ],
+            xd: &[
+                38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            150, // Splash
+            48, // Supersonic
+            346, // Water Sport
+            300, // Mud Sport
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Bagon,
+        level_up: &[
+            level(1, 99), // Rage
+            level(5, 44), // Bite
+            level(9, 43), // Leer
+            level(17, 29), // Headbutt
+            level(21, 116), // Focus Energy
+            level(25, 52), // Ember
+            level(33, 225), // Dragon Breath
+            level(37, 184), // Scary Face
+            level(41, 242), // Crunch
+            level(49, 337), // Dragon Claw
+            level(53, 38), // Double-Edge
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 182, 218, 216, 91, 104,
+            53, 126, 263, 290, 156, 213, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 111, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[
+            56, // Hydro Pump
+            37, // Thrash
+            82, // Dragon Rage
+            239, // Twister
+            349, // Dragon Dance
+        ],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Shelgon,
+        level_up: &[
+            level(1, 99), // Rage
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(1, 29), // Headbutt
+            level(5, 44), // Bite
+            level(9, 43), // Leer
+            level(17, 29), // Headbutt
+            level(21, 116), // Focus Energy
+            level(25, 52), // Ember
+            level(30, 182), // Protect
+            level(38, 225), // Dragon Breath
+            level(47, 184), // Scary Face
+            level(56, 242), // Crunch
+            level(69, 337), // Dragon Claw
+            level(78, 38), // Double-Edge
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 182, 218, 216, 91, 104,
+            53, 126, 263, 290, 156, 213, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 207, 214, 111, 173,
+                203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Salamence,
+        level_up: &[
+            level(1, 99), // Rage
+            level(1, 44), // Bite
+            level(1, 43), // Leer
+            level(1, 29), // Headbutt
+            level(5, 44), // Bite
+            level(9, 43), // Leer
+            level(17, 29), // Headbutt
+            level(21, 116), // Focus Energy
+            level(25, 52), // Ember
+            level(30, 182), // Protect
+            level(38, 225), // Dragon Breath
+            level(47, 184), // Scary Face
+            level(50, 19), // Fly
+            level(61, 242), // Crunch
+            level(79, 337), // Dragon Claw
+            level(93, 38), // Double-Edge
+        ],
+        machines: &[
+            337, 46, 92, 237, 241, 63, 182, 218, 89, 216,
+            91, 104, 53, 126, 332, 263, 290, 156, 213, 211,
+            315, 15, 19, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 34, 38, 189, 210, 207, 214, 129,
+                111, 173, 203,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Beldum,
+        level_up: &[
+            level(1, 36), // Take Down
+        ],
+        machines: &[],
+        tutors: Tutors {
+            fire_red_leaf_green: &[],
+            emerald: &[],
+            xd: &[],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Metang,
+        level_up: &[
+            level(1, 36), // Take Down
+            level(1, 232), // Metal Claw
+            level(1, 93), // Confusion
+            level(20, 93), // Confusion
+            level(20, 232), // Metal Claw
+            level(26, 184), // Scary Face
+            level(32, 228), // Pursuit
+            level(38, 94), // Psychic
+            level(44, 334), // Iron Defense
+            level(50, 309), // Meteor Mash
+            level(56, 97), // Agility
+            level(62, 63), // Hyper Beam
+        ],
+        machines: &[
+            347, 92, 237, 241, 269, 63, 113, 182, 240, 219,
+            218, 85, 87, 89, 216, 94, 247, 280, 104, 115,
+            351, 201, 317, 332, 259, 263, 290, 156, 285, 15,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 38, 68, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 68, 102, 153, 157, 164, 5, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 9, 244, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Metagross,
+        level_up: &[
+            level(1, 36), // Take Down
+            level(1, 232), // Metal Claw
+            level(1, 93), // Confusion
+            level(1, 184), // Scary Face
+            level(20, 93), // Confusion
+            level(20, 232), // Metal Claw
+            level(26, 184), // Scary Face
+            level(32, 228), // Pursuit
+            level(38, 94), // Psychic
+            level(44, 334), // Iron Defense
+            level(55, 309), // Meteor Mash
+            level(66, 97), // Agility
+            level(77, 63), // Hyper Beam
+        ],
+        machines: &[
+            347, 92, 237, 241, 269, 63, 113, 182, 240, 219,
+            218, 85, 87, 89, 216, 94, 247, 280, 104, 115,
+            351, 201, 317, 332, 259, 263, 290, 156, 285, 15,
+            70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 34, 38, 68, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 68, 102, 153, 157, 164, 5, 34, 38,
+                189, 207, 214, 129, 111, 173, 8, 9, 244, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Regirock,
+        level_up: &[
+            level(1, 153), // Explosion
+            level(9, 88), // Rock Throw
+            level(17, 174), // Curse
+            level(25, 276), // Superpower
+            level(33, 246), // Ancient Power
+            level(41, 334), // Iron Defense
+            level(49, 192), // Zap Cannon
+            level(57, 199), // Lock-On
+            level(65, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 240, 218,
+            231, 85, 87, 89, 216, 91, 280, 104, 351, 201,
+            317, 263, 290, 156, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 157, 164, 5, 25,
+                34, 38, 189, 207, 214, 111, 173, 8, 9, 7,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Regice,
+        level_up: &[
+            level(1, 153), // Explosion
+            level(9, 196), // Icy Wind
+            level(17, 174), // Curse
+            level(25, 276), // Superpower
+            level(33, 246), // Ancient Power
+            level(41, 133), // Amnesia
+            level(49, 192), // Zap Cannon
+            level(57, 199), // Lock-On
+            level(65, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 46, 92, 258, 339, 237, 58, 59, 63, 182,
+            240, 218, 231, 85, 87, 89, 216, 280, 104, 351,
+            263, 290, 156, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 164, 5, 25, 34,
+                38, 189, 207, 214, 111, 173, 8, 9, 7, 203,
+                196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Registeel,
+        level_up: &[
+            level(1, 153), // Explosion
+            level(9, 232), // Metal Claw
+            level(17, 174), // Curse
+            level(25, 276), // Superpower
+            level(33, 246), // Ancient Power
+            level(41, 334), // Iron Defense
+            level(41, 133), // Amnesia
+            level(49, 192), // Zap Cannon
+            level(57, 199), // Lock-On
+            level(65, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 46, 92, 339, 237, 241, 63, 182, 240, 218,
+            231, 85, 87, 89, 216, 91, 280, 104, 351, 201,
+            317, 263, 290, 156, 70, 148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 153, 157, 164,
+            ],
+            emerald: &[
+                223, 205, 69, 68, 102, 153, 157, 164, 5, 25,
+                34, 38, 189, 207, 214, 111, 173, 8, 9, 7,
+                203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207, 120,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Latias,
+        level_up: &[
+            level(1, 149), // Psywave
+            level(5, 273), // Wish
+            level(10, 270), // Helping Hand
+            level(15, 219), // Safeguard
+            level(20, 225), // Dragon Breath
+            level(25, 346), // Water Sport
+            level(30, 287), // Refresh
+            level(35, 296), // Mist Ball
+            level(40, 94), // Psychic
+            level(45, 105), // Recover
+            level(50, 204), // Charm
+        ],
+        machines: &[
+            337, 352, 347, 46, 92, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 231, 85, 87, 89, 216,
+            94, 247, 104, 115, 351, 332, 263, 290, 156, 213,
+            211, 19, 57, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 189, 207, 214, 129,
+                173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Latios,
+        level_up: &[
+            level(1, 149), // Psywave
+            level(5, 262), // Memento
+            level(10, 270), // Helping Hand
+            level(15, 219), // Safeguard
+            level(20, 225), // Dragon Breath
+            level(25, 182), // Protect
+            level(30, 287), // Refresh
+            level(35, 295), // Luster Purge
+            level(40, 94), // Psychic
+            level(45, 105), // Recover
+            level(50, 349), // Dragon Dance
+        ],
+        machines: &[
+            337, 352, 347, 46, 92, 237, 241, 58, 59, 63,
+            113, 182, 240, 219, 218, 231, 85, 87, 89, 216,
+            94, 247, 104, 115, 351, 332, 263, 290, 156, 213,
+            211, 19, 57, 148, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 189, 207, 214, 129,
+                173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Kyogre,
+        level_up: &[
+            level(1, 352), // Water Pulse
+            level(5, 184), // Scary Face
+            level(15, 246), // Ancient Power
+            level(20, 34), // Body Slam
+            level(30, 347), // Calm Mind
+            level(35, 58), // Ice Beam
+            level(45, 56), // Hydro Pump
+            level(50, 156), // Rest
+            level(60, 329), // Sheer Cold
+            level(65, 38), // Double-Edge
+            level(75, 323), // Water Spout
+        ],
+        machines: &[
+            352, 347, 46, 92, 258, 237, 58, 59, 63, 182,
+            240, 218, 85, 87, 89, 216, 104, 263, 290, 156,
+            57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 86, 164,
+            ],
+            emerald: &[
+                102, 86, 164, 34, 38, 207, 214, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 86, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Groudon,
+        level_up: &[
+            level(1, 341), // Mud Shot
+            level(5, 184), // Scary Face
+            level(15, 246), // Ancient Power
+            level(20, 163), // Slash
+            level(30, 339), // Bulk Up
+            level(35, 89), // Earthquake
+            level(45, 126), // Fire Blast
+            level(50, 156), // Rest
+            level(60, 90), // Fissure
+            level(65, 76), // Solar Beam
+            level(75, 284), // Eruption
+        ],
+        machines: &[
+            264, 337, 46, 92, 339, 237, 241, 63, 182, 218,
+            76, 231, 89, 216, 91, 280, 104, 53, 201, 126,
+            317, 332, 263, 290, 156, 315, 15, 70, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 14, 25, 34, 38, 68, 69, 102, 157, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 157, 164, 5, 25, 14, 34,
+                38, 189, 210, 207, 214, 173, 9, 7, 203,
+            ],
+            xd: &[
+                34, 38, 69, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Rayquaza,
+        level_up: &[
+            level(1, 239), // Twister
+            level(5, 184), // Scary Face
+            level(15, 242), // Crunch
+            level(20, 19), // Fly
+            level(30, 337), // Dragon Claw
+            level(35, 245), // Extreme Speed
+            level(45, 246), // Ancient Power
+            level(50, 156), // Rest
+            level(60, 200), // Outrage
+            level(65, 349), // Dragon Dance
+            level(75, 63), // Hyper Beam
+        ],
+        machines: &[
+            337, 352, 46, 92, 258, 237, 241, 58, 59, 63,
+            182, 240, 218, 231, 85, 87, 89, 216, 280, 104,
+            351, 53, 126, 332, 263, 290, 156, 211, 315, 19,
+            57, 70, 249, 127, 291,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                14, 34, 38, 102, 157, 164,
+            ],
+            emerald: &[
+                102, 157, 164, 14, 34, 38, 189, 210, 207, 214,
+                129, 173, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 164, 207,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Jirachi,
+        level_up: &[
+            level(1, 273), // Wish
+            level(1, 93), // Confusion
+            level(5, 156), // Rest
+            level(10, 129), // Swift
+            level(15, 270), // Helping Hand
+            level(20, 94), // Psychic
+            level(25, 287), // Refresh
+            level(30, 156), // Rest
+            level(35, 38), // Double-Edge
+            level(40, 248), // Future Sight
+            level(45, 322), // Cosmic Power
+            level(50, 353), // Doom Desire
+        ],
+        machines: &[
+            347, 92, 237, 241, 113, 182, 240, 219, 218, 231,
+            85, 87, 216, 94, 247, 104, 115, 351, 263, 290,
+            156, 285, 148,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                34, 38, 102, 118, 138, 86, 164,
+            ],
+            emerald: &[
+                102, 138, 86, 164, 34, 38, 118, 189, 207, 214,
+                129, 111, 173, 244, 203, 196,
+            ],
+            xd: &[
+                34, 38, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+    Learnset {
+        species: SpeciesID::Deoxys,
+        level_up: &[
+            level(1, 43), // Leer
+            level(1, 35), // Wrap
+            level(5, 101), // Night Shade
+            level(10, 100), // Teleport
+            level(15, 282), // Knock Off
+            level(20, 228), // Pursuit
+            level(25, 94), // Psychic
+            level(30, 289), // Snatch
+            level(35, 354), // Psycho Boost
+            level(40, 105), // Recover
+            level(45, 192), // Zap Cannon
+            level(50, 104), // Double Team
+            level(55, 63), // Hyper Beam
+        ],
+        machines: &[
+            264, 347, 92, 339, 237, 241, 269, 63, 113, 182,
+            240, 218, 231, 85, 87, 216, 94, 247, 280, 104,
+            115, 351, 53, 259, 263, 290, 156, 285, 289, 70,
+            148, 249,
+        ],
+        tutors: Tutors {
+            fire_red_leaf_green: &[
+                5, 25, 34, 38, 68, 69, 102, 138, 86, 164,
+            ],
+            emerald: &[
+                223, 69, 68, 102, 138, 86, 164, 5, 25, 34,
+                38, 189, 207, 214, 129, 173, 8, 9, 7, 244,
+                203, 196,
+            ],
+            xd: &[
+                34, 38, 69, 102, 138, 86, 164, 207, 171,
+            ],
+        },
+        egg_moves: &[],
+        event_moves: &[],
+    },
+];
+
+/// Gets the learnset of the species
+pub fn learnset(species: SpeciesID) -> Option<&'static Learnset> {
+    LEARNSETS.iter().find(|learnset| learnset.species == species)
+}
+
+/// Gets the learnsets of the species and its pre-evolutions, starting from the species
+pub fn evolution_chain(species: SpeciesID) -> Vec<&'static Learnset> {
+    evolution::evolution_chain(EVOLUTIONS, species).into_iter().filter_map(learnset).collect()
+}
+
+/// Gets the lowest level any form specific to a game learns the move at
+fn form_level_up_level(species: SpeciesID, move_id: u16) -> Option<u8> {
+    if species != SpeciesID::Deoxys {
+        return None;
+    }
+    DEOXYS_FORMS
+        .iter()
+        .flat_map(|(_, level_up)| level_up.iter())
+        .filter(|entry| entry.move_id == move_id)
+        .map(|entry| entry.level)
+        .min()
+}
+
+/// Gets every way a Pokémon could have learned the move.
+///
+/// # Arguments
+/// * `species` - Current species of the Pokémon
+/// * `move_id` - Move to look for
+/// * `level` - Current level of the Pokémon
+/// * `hatched` - The Pokémon hatched from an egg, so it could inherit moves from its parents
+/// * `fateful` - The Pokémon comes from an event distribution
+pub fn find_move_sources(species: SpeciesID, move_id: u16, level: u8, hatched: bool, fateful: bool) -> Vec<MoveLearnSource> {
+    let chain = evolution_chain(species);
+    let mut sources = Vec::new();
+
+    for learnset in &chain {
+        let mut push = |source| sources.push(MoveLearnSource { species: learnset.species, source });
+
+        let learned_at = learnset.level_up_level(move_id).into_iter().chain(form_level_up_level(learnset.species, move_id)).min();
+        if let Some(learned_at) = learned_at.filter(|learned_at| *learned_at <= level) {
+            push(MoveSource::LevelUp(learned_at));
+        }
+        if learnset.machines.contains(&move_id) {
+            push(MoveSource::Machine);
+        }
+        if learnset.tutors.contains(move_id) {
+            push(MoveSource::Tutor);
+        }
+        if fateful && learnset.event_moves.contains(&move_id) {
+            push(MoveSource::Event);
+        }
+    }
+
+    // Parents can pass their egg moves and the level-up moves of the baby at any level
+    if let Some(baby) = chain.last().filter(|_| hatched) {
+        if baby.egg_moves.contains(&move_id) || baby.level_up_level(move_id).is_some() {
+            sources.push(MoveLearnSource { species: baby.species, source: MoveSource::Egg });
+        }
+    }
+
+    sources
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::species_id::SpeciesID,
+        legality::learnsets::{
+            generation3::{find_move_sources, learnset, LEARNSETS},
+            MoveSource,
+        },
+    };
+
+    const GIGA_DRAIN: u16 = 202;
+    const LEAF_BLADE: u16 = 348;
+    const CRUNCH: u16 = 242;
+    const FLAIL: u16 = 175;
+    const SUPERPOWER: u16 = 276;
+    const ICY_WIND: u16 = 196;
+
+    #[test]
+    fn level_up_depends_on_level() {
+        assert!(find_move_sources(SpeciesID::Grovyle, LEAF_BLADE, 28, false, false).is_empty());
+        assert_eq!(find_move_sources(SpeciesID::Grovyle, LEAF_BLADE, 29, false, false)[0].source, MoveSource::LevelUp(29));
+    }
+
+    #[test]
+    fn pre_evolutions_are_inherited() {
+        let sources = find_move_sources(SpeciesID::Gyarados, FLAIL, 30, false, false);
+        assert_eq!(sources[0].species, SpeciesID::Magikarp);
+        assert!(find_move_sources(SpeciesID::Gyarados, FLAIL, 29, false, false).is_empty());
+    }
+
+    #[test]
+    fn egg_moves_need_an_egg() {
+        assert!(find_move_sources(SpeciesID::Sceptile, CRUNCH, 100, false, false).is_empty());
+        assert_eq!(find_move_sources(SpeciesID::Sceptile, CRUNCH, 100, true, false)[0].source, MoveSource::Egg);
+        // Taught by TM19 regardless of the level
+        assert!(find_move_sources(SpeciesID::Treecko, GIGA_DRAIN, 5, false, false).iter().any(|found| found.source == MoveSource::Machine));
+    }
+
+    #[test]
+    fn every_species_has_a_learnset() {
+        for national in 1..=386 {
+            let species = SpeciesID::from_national_id(national).unwrap();
+            assert!(learnset(species).is_some(), "{:?} has no learnset", species);
+        }
+        assert_eq!(LEARNSETS.len(), 386);
+    }
+
+    #[test]
+    fn tutors_depend_on_the_species() {
+        // Emerald teaches Icy Wind to Lapras but not to Charizard
+        assert!(find_move_sources(SpeciesID::Lapras, ICY_WIND, 50, false, false).iter().any(|found| found.source == MoveSource::Tutor));
+        assert!(find_move_sources(SpeciesID::Charizard, ICY_WIND, 50, false, false).is_empty());
+    }
+
+    #[test]
+    fn deoxys_forms_are_learned() {
+        assert_eq!(find_move_sources(SpeciesID::Deoxys, SUPERPOWER, 30, false, false)[0].source, MoveSource::LevelUp(30));
+    }
+}
@@ -1,2 +1,11 @@
+pub mod analysis;
+pub mod check;
+pub mod encounters;
+pub mod events;
+pub mod generator;
+pub mod items;
+pub mod learnsets;
+pub mod met_location;
+pub mod repair;
 pub mod species_table;
 pub mod tables;
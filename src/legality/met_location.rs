@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use crate::game::enums::{
+    game_version::GameVersion,
+    location::{EGG_UNHATCHED_GENERATION3, FATEFUL_ENCOUNTER_GENERATION3, LINK_TRADE_GENERATION3_NPC},
+};
+
+use super::tables::generation3::{VALID_METLOCATION_EMERALD, VALID_METLOCATION_FIRERED_LEAFGREEN, VALID_METLOCATION_RUBYSAPPHIRE};
+
+/// How a Generation 3 Pokémon was obtained, according to its met data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetOrigin {
+    /// Egg that did not hatch yet
+    Unhatched,
+    /// Hatched from an egg, `in_other_game` is set when it was traded as an egg and hatched in another game
+    Hatched { in_other_game: bool },
+    InGameTrade,
+    FatefulEncounter,
+    /// Caught or received in the origin game
+    Encounter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidMetData {
+    /// The origin game is not a Generation 3 game
+    Version(GameVersion),
+    /// The location can't be reached in the origin game
+    Location { location: u16, version: GameVersion },
+    /// The level is not possible for the kind of encounter
+    Level { level: u8, origin: MetOrigin },
+    /// Generation 3 does not store the egg location
+    EggLocation(u16),
+    /// Eggs must keep the unhatched met data
+    UnhatchedEgg { location: u16, level: u8 },
+}
+
+impl Display for InvalidMetData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidMetData::Version(version) => write!(f, "Origin game {:?} is not a Generation 3 game", version),
+            InvalidMetData::Location { location, version } => write!(f, "Met location {} can't be reached in {:?}", location, version),
+            InvalidMetData::Level { level, origin } => write!(f, "Met level {} is not possible for {:?}", level, origin),
+            InvalidMetData::EggLocation(location) => write!(f, "Egg location {} is set but Generation 3 does not store it", location),
+            InvalidMetData::UnhatchedEgg { location, level } => write!(f, "Unhatched eggs must be met at location {} level 0, found location {} level {}", EGG_UNHATCHED_GENERATION3, location, level),
+        }
+    }
+}
+
+/// Checks if the location can be reached in the given Game Boy Advance game
+pub fn is_valid_generation3_location(version: GameVersion, location: u16) -> bool {
+    let Ok(location) = u8::try_from(location) else {
+        return false;
+    };
+
+    match version {
+        GameVersion::Ruby | GameVersion::Sapphire => VALID_METLOCATION_RUBYSAPPHIRE.contains(&location),
+        GameVersion::Emerald => VALID_METLOCATION_EMERALD.contains(&location),
+        GameVersion::FireRed | GameVersion::LeafGreen => VALID_METLOCATION_FIRERED_LEAFGREEN.contains(&location),
+        _ => false,
+    }
+}
+
+/// Checks if an egg could hatch at the location in any of the Game Boy Advance games, eggs can be traded before hatching
+pub fn is_valid_generation3_hatch_location(location: u16) -> bool {
+    [GameVersion::Ruby, GameVersion::Emerald, GameVersion::FireRed]
+        .into_iter()
+        .any(|version| is_valid_generation3_location(version, location))
+}
+
+/// Validates the met data of a Generation 3 Pokémon against its origin game.
+///
+/// # Arguments
+/// * `version` - Origin game
+/// * `location` - Met location
+/// * `level` - Met level, `0` for hatched Pokémon
+/// * `egg_location` - Egg location, not stored by Generation 3 so it must be `0`
+/// * `is_egg` - The Pokémon did not hatch yet
+pub fn validate_generation3_met(version: GameVersion, location: u16, level: u8, egg_location: u16, is_egg: bool) -> Result<MetOrigin, InvalidMetData> {
+    let is_colosseum_xd = version == GameVersion::ColosseumXD;
+    if !is_colosseum_xd && !(GameVersion::Sapphire..=GameVersion::LeafGreen).contains(&version) {
+        return Err(InvalidMetData::Version(version));
+    }
+
+    if egg_location != 0 {
+        return Err(InvalidMetData::EggLocation(egg_location));
+    }
+
+    if is_egg {
+        if location != EGG_UNHATCHED_GENERATION3 || level != 0 {
+            return Err(InvalidMetData::UnhatchedEgg { location, level });
+        }
+
+        return Ok(MetOrigin::Unhatched);
+    }
+
+    let origin = match location {
+        LINK_TRADE_GENERATION3_NPC => MetOrigin::InGameTrade,
+        FATEFUL_ENCOUNTER_GENERATION3 => MetOrigin::FatefulEncounter,
+        // There are no eggs in Colosseum/XD
+        _ if level == 0 && !is_colosseum_xd => {
+            if !is_valid_generation3_hatch_location(location) {
+                return Err(InvalidMetData::Location { location, version });
+            }
+
+            MetOrigin::Hatched { in_other_game: !is_valid_generation3_location(version, location) }
+        },
+        EGG_UNHATCHED_GENERATION3 => return Err(InvalidMetData::Location { location, version }),
+        // Colosseum/XD locations are validated by the encounters
+        _ if is_colosseum_xd => MetOrigin::Encounter,
+        _ => {
+            if !is_valid_generation3_location(version, location) {
+                return Err(InvalidMetData::Location { location, version });
+            }
+
+            MetOrigin::Encounter
+        },
+    };
+
+    if !matches!(origin, MetOrigin::Hatched { .. }) && !(1..=100).contains(&level) {
+        return Err(InvalidMetData::Level { level, origin });
+    }
+
+    Ok(origin)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{game_version::GameVersion, location::*},
+        legality::met_location::{validate_generation3_met, InvalidMetData, MetOrigin},
+    };
+
+    #[test]
+    fn location_depends_on_the_game() {
+        // Route 101
+        assert_eq!(validate_generation3_met(GameVersion::Ruby, 16, 2, 0, false), Ok(MetOrigin::Encounter));
+        assert_eq!(validate_generation3_met(GameVersion::FireRed, 16, 2, 0, false), Err(InvalidMetData::Location { location: 16, version: GameVersion::FireRed }));
+        // Birth Island only has its own map section in Emerald
+        assert_eq!(validate_generation3_met(GameVersion::Emerald, 200, 50, 0, false), Ok(MetOrigin::Encounter));
+        assert!(validate_generation3_met(GameVersion::Sapphire, 200, 50, 0, false).is_err());
+        // Unused Meteor Falls location
+        assert!(validate_generation3_met(GameVersion::Emerald, 64, 20, 0, false).is_err());
+    }
+
+    #[test]
+    fn hatched_eggs() {
+        assert_eq!(
+            validate_generation3_met(GameVersion::Emerald, HATCH_LOCATION_ROUTE117_RUBY_SAPPHIRE_EMERALD, 0, 0, false),
+            Ok(MetOrigin::Hatched { in_other_game: false })
+        );
+        assert_eq!(
+            validate_generation3_met(GameVersion::Emerald, HATCH_LOCATION_FOUR_ISLAND_FIRE_RED_LEAF_GREEN, 0, 0, false),
+            Ok(MetOrigin::Hatched { in_other_game: true })
+        );
+        assert_eq!(validate_generation3_met(GameVersion::Ruby, EGG_UNHATCHED_GENERATION3, 0, 0, true), Ok(MetOrigin::Unhatched));
+        assert!(validate_generation3_met(GameVersion::Ruby, 32, 0, 0, true).is_err());
+        assert!(validate_generation3_met(GameVersion::ColosseumXD, 32, 0, 0, false).is_err());
+    }
+
+    #[test]
+    fn special_locations() {
+        assert_eq!(validate_generation3_met(GameVersion::LeafGreen, LINK_TRADE_GENERATION3_NPC, 5, 0, false), Ok(MetOrigin::InGameTrade));
+        assert_eq!(validate_generation3_met(GameVersion::ColosseumXD, FATEFUL_ENCOUNTER_GENERATION3, 10, 0, false), Ok(MetOrigin::FatefulEncounter));
+        assert!(validate_generation3_met(GameVersion::LeafGreen, LINK_TRADE_GENERATION3_NPC, 0, 0, false).is_err());
+        assert!(validate_generation3_met(GameVersion::Diamond, 16, 5, 0, false).is_err());
+        assert!(validate_generation3_met(GameVersion::Ruby, 16, 5, 32, false).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+pub mod generation3;
+
+use std::fmt::Display;
+
+/// Area of the Pokémon data changed by a repair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepairKind {
+    Experience,
+    Level,
+    Moves,
+    EffortValues,
+    MetLocation,
+    Ball,
+    Ability,
+    Stats,
+    Checksum,
+}
+
+/// Correction of a single area of the Pokémon data, stored as the bytes it replaces so it can
+/// be reverted.
+///
+/// Repairs are proposed against the data left by the previous ones, so they must be applied in
+/// order and reverted in reverse order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    pub kind: RepairKind,
+    pub description: String,
+    /// Offset of the replaced bytes in the decrypted data
+    pub offset: usize,
+    pub original: Vec<u8>,
+    pub replacement: Vec<u8>,
+}
+
+impl Repair {
+    /// Creates a repair replacing the bytes at the offset of the data.
+    ///
+    /// # Arguments
+    /// * `data` - Decrypted data before the repair, the replaced bytes are copied from it
+    /// * `offset` - Offset of the first replaced byte
+    /// * `replacement` - Bytes written by the repair
+    pub fn new(kind: RepairKind, description: impl Into<String>, data: &[u8], offset: usize, replacement: Vec<u8>) -> Self {
+        Self {
+            kind,
+            description: description.into(),
+            offset,
+            original: data[offset..offset + replacement.len()].to_vec(),
+            replacement,
+        }
+    }
+
+    /// Checks if the repair would not change anything
+    pub fn is_empty(&self) -> bool {
+        self.original == self.replacement
+    }
+
+    /// Writes the repaired bytes into the decrypted data
+    pub fn apply(&self, data: &mut [u8]) {
+        data[self.offset..self.offset + self.replacement.len()].copy_from_slice(&self.replacement);
+    }
+
+    /// Writes back the bytes replaced by the repair
+    pub fn revert(&self, data: &mut [u8]) {
+        data[self.offset..self.offset + self.original.len()].copy_from_slice(&self.original);
+    }
+}
+
+impl Display for Repair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.description)
+    }
+}
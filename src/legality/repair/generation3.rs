@@ -0,0 +1,354 @@
+//! Repairs of the Generation 3 legality checks.
+//!
+//! Gender, nature and shininess are derived from the PID in Generation 3, so they are never
+//! repaired: changing the PID would also change every other value derived from it.
+
+use crate::{
+    legality::{
+        analysis::{generation3::MAX_EV_TOTAL, LegalityAnalysis},
+        check::{CheckIdentifier, CheckSeverity},
+        encounters::generation3::{find_encounters_for, find_species_encounters},
+        events::generation3::find_events_for,
+        items::{is_ball_allowed_generation3, POKE_BALL, SAFARI_BALL},
+        learnsets::generation3::{find_move_sources, learnset},
+        tables::generation3::{MAX_MOVE_ID, MOVE_PP},
+    },
+    pokemon::{utils::experience::get_minimum_level_experience, Generation3},
+    species::personal::PersonalInfo,
+    trainer::TrainerInfo,
+    utils::{
+        constants::poke_crypto::{get_generatation3_checksum, SIZE_GENERATION_3_PARTY},
+        data_manipulation::SliceUtils,
+    },
+};
+
+use super::{Repair, RepairKind};
+
+/// Proposes the repairs of every invalid result of the analysis, followed by the stats and
+/// checksum when they are outdated. The Pokémon is not changed.
+///
+/// # Arguments
+/// * `pokemon` - Analyzed Pokémon
+/// * `analysis` - Legality analysis of the Pokémon
+pub fn propose(pokemon: &Generation3, analysis: &LegalityAnalysis) -> Vec<Repair> {
+    let is_invalid = |identifier| analysis.results_for(identifier).any(|result| result.severity == CheckSeverity::Invalid);
+
+    // Each repair is proposed against the data left by the previous ones
+    let mut working = Generation3::from_decrypted(&pokemon.base.data);
+    let mut repairs = Vec::new();
+    let mut propose_with = |working: &mut Generation3, repair: fn(&Generation3) -> Option<Repair>| {
+        if let Some(repair) = repair(working).filter(|repair| !repair.is_empty()) {
+            repair.apply(&mut working.base.data);
+            *working = Generation3::from_decrypted(&working.base.data);
+            repairs.push(repair);
+        }
+    };
+
+    // Nothing else can be repaired without the species data
+    if !working.base.valid || PersonalInfo::generation3(working.base.species).is_none() {
+        return Vec::new();
+    }
+
+    if is_invalid(CheckIdentifier::Level) {
+        propose_with(&mut working, repair_experience);
+        propose_with(&mut working, repair_level);
+    }
+    if is_invalid(CheckIdentifier::Moves) {
+        propose_with(&mut working, repair_moves);
+    }
+    if is_invalid(CheckIdentifier::EffortValues) {
+        propose_with(&mut working, repair_effort_values);
+    }
+    if is_invalid(CheckIdentifier::Met) || is_invalid(CheckIdentifier::Level) {
+        propose_with(&mut working, repair_met_location);
+    }
+    if is_invalid(CheckIdentifier::Ball) {
+        propose_with(&mut working, repair_ball);
+    }
+    if is_invalid(CheckIdentifier::Ability) {
+        propose_with(&mut working, repair_ability);
+    }
+    propose_with(&mut working, repair_stats);
+    propose_with(&mut working, repair_checksum);
+
+    repairs
+}
+
+/// Applies the repairs in order, reloading the Pokémon from the repaired data
+pub fn apply(pokemon: &mut Generation3, repairs: &[Repair]) {
+    for repair in repairs {
+        repair.apply(&mut pokemon.base.data);
+    }
+    *pokemon = Generation3::from_decrypted(&pokemon.base.data);
+}
+
+/// Reverts the repairs in reverse order, reloading the Pokémon from the original data
+pub fn revert(pokemon: &mut Generation3, repairs: &[Repair]) {
+    for repair in repairs.iter().rev() {
+        repair.revert(&mut pokemon.base.data);
+    }
+    *pokemon = Generation3::from_decrypted(&pokemon.base.data);
+}
+
+/// Analyzes the Pokémon and applies every proposed repair.
+///
+/// # Arguments
+/// * `pokemon` - Pokémon to repair
+/// * `trainer` - Trainer of the save holding the Pokémon, when known
+pub fn repair(pokemon: &mut Generation3, trainer: Option<&TrainerInfo>) -> Vec<Repair> {
+    let repairs = propose(pokemon, &LegalityAnalysis::generation3(pokemon, trainer));
+    apply(pokemon, &repairs);
+    repairs
+}
+
+fn repair_experience(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    let growth_rate = PersonalInfo::generation3(base.species)?.growth_rate as usize;
+    let max_experience = get_minimum_level_experience(100, growth_rate);
+
+    (base.experience > max_experience).then(|| {
+        let description = format!("Lower the experience from {} to {}", base.experience, max_experience);
+        Repair::new(RepairKind::Experience, description, &base.data, 0x24, max_experience.to_le_bytes().to_vec())
+    })
+}
+
+fn repair_level(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    if base.data.len() < SIZE_GENERATION_3_PARTY as usize {
+        return None;
+    }
+
+    let level = base.current_level();
+    let description = format!("Set the level from {} to {}, matching the experience", base.level, level);
+    Some(Repair::new(RepairKind::Level, description, &base.data, 0x54, vec![level]))
+}
+
+/// Replaces the moves that can't be learned with the latest level-up moves. Without learnset
+/// data only the event moves can be verified, so the other moves are removed.
+fn repair_moves(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    let level = base.current_level();
+    let hatched = base.met_level == 0;
+    let event_moves: Vec<u16> = find_events_for(pokemon).iter().flat_map(|event| event.moves.iter().copied()).collect();
+
+    let moves = [base.move_1, base.move_2, base.move_3, base.move_4];
+    let pp = [base.move_1_pp, base.move_2_pp, base.move_3_pp, base.move_4_pp];
+    let pp_ups = [base.move_1_pp_ups, base.move_2_pp_ups, base.move_3_pp_ups, base.move_4_pp_ups];
+
+    // Machines and tutors are checked against the learnset of the species and its pre-evolutions
+    let is_learnable = |move_id: u16| {
+        event_moves.contains(&move_id) || !find_move_sources(base.species, move_id, level, hatched, base.fateful_encounter).is_empty()
+    };
+
+    // Moves kept with their PP and PP Ups
+    let mut kept: Vec<(u16, u8, u8)> = Vec::new();
+    for ((move_id, pp), pp_ups) in moves.into_iter().zip(pp).zip(pp_ups) {
+        if move_id != 0 && move_id as u32 <= MAX_MOVE_ID && is_learnable(move_id) && !kept.iter().any(|(kept_id, _, _)| *kept_id == move_id) {
+            kept.push((move_id, pp, pp_ups));
+        }
+    }
+
+    let known = moves.iter().filter(|move_id| **move_id != 0).count().max(1);
+    let level_up = learnset(base.species).map(|learnset| learnset.level_up).unwrap_or_default();
+    for entry in level_up.iter().rev().filter(|entry| entry.level <= level) {
+        if kept.len() >= known {
+            break;
+        }
+        if !kept.iter().any(|(kept_id, _, _)| *kept_id == entry.move_id) {
+            kept.push((entry.move_id, MOVE_PP.get(entry.move_id as usize).copied().unwrap_or(0), 0));
+        }
+    }
+
+    let mut replacement = base.data[0x28..0x38].to_vec();
+    replacement[0] = 0;
+    replacement[0x04..0x10].fill(0);
+    for (slot, (move_id, pp, pp_ups)) in kept.iter().enumerate() {
+        replacement[0] |= pp_ups << (slot * 2);
+        replacement[0x04 + slot * 2..0x06 + slot * 2].copy_from_slice(&move_id.to_le_bytes());
+        replacement[0x0C + slot] = *pp;
+    }
+
+    let description = format!("Replace the moves {:?} with {:?}", moves, kept.iter().map(|(move_id, _, _)| *move_id).collect::<Vec<u16>>());
+    Some(Repair::new(RepairKind::Moves, description, &base.data, 0x28, replacement))
+}
+
+/// Removes every EV of eggs, or the EVs above the total limit starting from the last stat
+fn repair_effort_values(pokemon: &Generation3) -> Option<Repair> {
+    let data = &pokemon.base.data;
+    let mut evs = data[0x38..0x3E].to_vec();
+
+    if pokemon.is_egg {
+        evs.fill(0);
+    } else {
+        let mut excess = evs.iter().map(|ev| *ev as u16).sum::<u16>().saturating_sub(MAX_EV_TOTAL);
+        for ev in evs.iter_mut().rev() {
+            let removed = excess.min(*ev as u16);
+            *ev -= removed as u8;
+            excess -= removed;
+        }
+    }
+
+    Some(Repair::new(RepairKind::EffortValues, format!("Set the EVs to {:?}", evs), data, 0x38, evs))
+}
+
+/// Moves the Pokémon to the lowest level encounter of its species in the game
+fn repair_met_location(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    // Hatched and received Pokémon are not moved to an encounter
+    if pokemon.is_egg || base.met_level == 0 || !find_encounters_for(pokemon).is_empty() {
+        return None;
+    }
+
+    let level = base.current_level();
+    let encounter = find_species_encounters(base.species, base.version)
+        .into_iter()
+        .filter(|encounter| encounter.location.is_some() && encounter.min_level <= level)
+        .min_by_key(|encounter| encounter.min_level)?;
+
+    let met_level = base.met_level.clamp(encounter.min_level, encounter.max_level.min(level));
+    let origins = (base.data.get_u16_le_offset(0x46).ok()? & !0x7F) | met_level as u16;
+    let location = encounter.location.unwrap_or_default() as u8;
+
+    let description = format!("Set the met location to {} at level {}, a {:?} encounter", location, met_level, encounter.kind);
+    let mut replacement = vec![location];
+    replacement.extend_from_slice(&origins.to_le_bytes());
+    Some(Repair::new(RepairKind::MetLocation, description, &base.data, 0x45, replacement))
+}
+
+/// Uses a Poké Ball, or a Safari Ball for Safari Zone encounters
+fn repair_ball(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    let ball = match find_encounters_for(pokemon).first() {
+        Some(encounter) if !pokemon.is_egg && base.met_level != 0 => {
+            [POKE_BALL, SAFARI_BALL].into_iter().find(|ball| is_ball_allowed_generation3(*ball, encounter))?
+        }
+        _ => POKE_BALL,
+    };
+
+    let origins = (base.data.get_u16_le_offset(0x46).ok()? & !(0xF << 11)) | ((ball as u16) << 11);
+    Some(Repair::new(RepairKind::Ball, format!("Set the ball from {} to {}", base.ball_type, ball), &base.data, 0x46, origins.to_le_bytes().to_vec()))
+}
+
+/// Sets the ability bit to the one selected by the PID
+fn repair_ability(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    let iv32 = base.data.get_u32_le_offset(0x48).ok()?;
+    let ability_bit = base.personality_id & 1;
+
+    let description = format!("Set the ability bit to {}, matching the PID", ability_bit);
+    Some(Repair::new(RepairKind::Ability, description, &base.data, 0x48, ((iv32 & !(1 << 31)) | (ability_bit << 31)).to_le_bytes().to_vec()))
+}
+
+/// Recalculates the current HP and stats of party data
+fn repair_stats(pokemon: &Generation3) -> Option<Repair> {
+    let base = &pokemon.base;
+    if base.data.len() < SIZE_GENERATION_3_PARTY as usize {
+        return None;
+    }
+
+    let mut refreshed = Generation3::from_decrypted(&base.data);
+    refreshed.refresh_stats();
+
+    let stats = refreshed.calculated_stats();
+    Some(Repair::new(RepairKind::Stats, format!("Set the stats to {:?}", stats), &base.data, 0x56, refreshed.base.data[0x56..0x64].to_vec()))
+}
+
+fn repair_checksum(pokemon: &Generation3) -> Option<Repair> {
+    let checksum = get_generatation3_checksum(&pokemon.base.data);
+    Some(Repair::new(RepairKind::Checksum, format!("Set the checksum to {:#06X}", checksum), &pokemon.base.data, 0x1C, checksum.to_le_bytes().to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        legality::{
+            analysis::LegalityAnalysis,
+            repair::{generation3::{apply, propose, revert}, RepairKind},
+        },
+        pokemon::Generation3,
+        rng::lcrng::{high, POKEMON_LCRNG},
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    /// Level 5 Treecko from Emerald with a level 7 level byte, 600 EVs and Outrage
+    fn broken_treecko() -> Vec<u8> {
+        let mut data = vec![0u8; 100];
+        // Method 1 PID and IVs, which can't be repaired
+        let seed = 0x1234_5678;
+        let [low, high_pid, iv1, iv2] = [1, 2, 3, 4].map(|count| high(POKEMON_LCRNG.advance(seed, count)) as u32);
+        data[0x00..0x04].copy_from_slice(&(low | (high_pid << 16)).to_le_bytes());
+        data[0x48..0x4C].copy_from_slice(&((iv1 & 0x7FFF) | ((iv2 & 0x7FFF) << 15)).to_le_bytes());
+        data[0x04..0x06].copy_from_slice(&12345u16.to_le_bytes());
+        data[0x06..0x08].copy_from_slice(&54321u16.to_le_bytes());
+        data[0x08..0x12].copy_from_slice(&[0xCE, 0xCC, 0xBF, 0xBF, 0xBD, 0xC5, 0xC9, 0xFF, 0xFF, 0xFF]);
+        data[0x12] = 2;
+        data[0x13] = 0x02;
+        data[0x14..0x1B].copy_from_slice(&[0xBB, 0xCD, 0xC2, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        data[0x20..0x22].copy_from_slice(&277u16.to_le_bytes());
+        data[0x24..0x28].copy_from_slice(&135u32.to_le_bytes());
+        data[0x2C..0x2E].copy_from_slice(&1u16.to_le_bytes());
+        data[0x2E..0x30].copy_from_slice(&200u16.to_le_bytes());
+        data[0x34] = 35;
+        data[0x35] = 10;
+        data[0x38..0x3B].copy_from_slice(&[200, 200, 200]);
+        data[0x45] = 16;
+        data[0x46..0x48].copy_from_slice(&(5u16 | (3 << 7) | (4 << 11)).to_le_bytes());
+        data[0x54] = 7;
+
+        let checksum = get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn repairs_make_the_pokemon_legal() {
+        let mut pokemon = Generation3::new(&broken_treecko());
+        let analysis = LegalityAnalysis::generation3(&pokemon, None);
+        assert!(!analysis.is_valid());
+
+        let repairs = propose(&pokemon, &analysis);
+        let kinds: Vec<RepairKind> = repairs.iter().map(|repair| repair.kind).collect();
+        assert_eq!(kinds, [RepairKind::Level, RepairKind::Moves, RepairKind::EffortValues, RepairKind::Stats, RepairKind::Checksum]);
+
+        apply(&mut pokemon, &repairs);
+        let analysis = LegalityAnalysis::generation3(&pokemon, None);
+        assert!(analysis.is_valid(), "{}", analysis.report(false));
+        assert_eq!(pokemon.base.level, 5);
+        assert_eq!(pokemon.base.move_2, 43);
+        assert_eq!(pokemon.base.ev_defense, 110);
+    }
+
+    #[test]
+    fn repairs_can_be_reverted() {
+        let original = broken_treecko();
+        let mut pokemon = Generation3::new(&original);
+        let repairs = propose(&pokemon, &LegalityAnalysis::generation3(&pokemon, None));
+
+        apply(&mut pokemon, &repairs);
+        assert_ne!(pokemon.base.data, original);
+
+        revert(&mut pokemon, &repairs);
+        assert_eq!(pokemon.base.data, original);
+        assert_eq!(pokemon.base.level, 7);
+    }
+
+    #[test]
+    fn machine_moves_depend_on_the_species() {
+        const SURF: u16 = 57;
+
+        let mut data = broken_treecko();
+        data[0x30..0x32].copy_from_slice(&SURF.to_le_bytes());
+        data[0x36] = 15;
+        let checksum = get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        let mut pokemon = Generation3::new(&data);
+        let repairs = propose(&pokemon, &LegalityAnalysis::generation3(&pokemon, None));
+        apply(&mut pokemon, &repairs);
+
+        // Treecko can't learn Surf, so only its level-up moves are left
+        assert_eq!([pokemon.base.move_1, pokemon.base.move_2, pokemon.base.move_3], [1, 43, 0]);
+        assert!(LegalityAnalysis::generation3(&pokemon, None).is_valid());
+    }
+}
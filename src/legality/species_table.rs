@@ -1,4 +1,7 @@
 pub mod generation1;
+pub mod generation3;
+
+use super::tables::generation3::MAX_SPECIES_ID;
 
 pub struct SpeciesTableBase {
   pub max_species_id: u16,
@@ -16,6 +19,6 @@ pub enum SpeciesTable {
 
 impl Default for SpeciesTable {
     fn default() -> Self {
-        todo!()
+        SpeciesTable::Latest(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
     }
 }
@@ -1,11 +1,15 @@
-use super::SpeciesTable;
+use crate::game::enums::species_id::SpeciesID;
+
+use super::{SpeciesTable, SpeciesTableBase};
+
+const MAX_SPECIES_ID: u16 = SpeciesID::Mew as u16;
 
 impl SpeciesTable {
   pub const fn red_or_blue() -> Self {
-    todo!()
+    SpeciesTable::RedBlue(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 
   pub const fn yellow() -> Self {
-    todo!()
+    SpeciesTable::Yellow(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 }
@@ -1,19 +1,21 @@
-use super::SpeciesTable;
+use crate::legality::tables::generation3::MAX_SPECIES_ID;
+
+use super::{SpeciesTable, SpeciesTableBase};
 
 impl SpeciesTable {
   pub const fn ruby_or_sapphire() -> Self {
-    todo!()
+    SpeciesTable::RubySapphire(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 
   pub const fn emerald() -> Self {
-    todo!()
+    SpeciesTable::Emerald(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 
   pub const fn firered() -> Self {
-    todo!()
+    SpeciesTable::FireRed(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 
   pub const fn leafgreen() -> Self {
-    todo!()
+    SpeciesTable::LeafGreen(SpeciesTableBase { max_species_id: MAX_SPECIES_ID })
   }
 }
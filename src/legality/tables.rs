@@ -1 +1,3 @@
+pub mod generation1;
+pub mod generation2;
 pub mod generation3;
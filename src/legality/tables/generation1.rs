@@ -0,0 +1,47 @@
+// region: Constants
+pub const MAX_SPECIES_ID: u16 = 151;
+pub const MAX_MOVE_ID: u16 = 165;
+// endregion
+
+/// Base PP of every move in Red/Blue/Yellow, indexed by move ID.
+/// Struggle has 10 PP instead of the 1 of the later games.
+pub const MOVE_PP: [u8; MAX_MOVE_ID as usize + 1] = [
+    0, 35, 25, 10, 15, 20, 20, 15, 15, 15,
+    35, 30, 5, 10, 30, 30, 35, 35, 20, 15,
+    20, 20, 10, 20, 30, 5, 25, 15, 15, 15,
+    25, 20, 5, 35, 15, 20, 20, 20, 15, 30,
+    35, 20, 20, 30, 25, 40, 20, 15, 20, 20,
+    20, 30, 25, 15, 30, 25, 5, 15, 10, 5,
+    20, 20, 20, 5, 35, 20, 25, 20, 20, 20,
+    15, 20, 10, 10, 40, 25, 10, 35, 30, 15,
+    20, 40, 10, 15, 30, 15, 20, 10, 15, 10,
+    5, 10, 10, 25, 10, 20, 40, 30, 30, 20,
+    20, 15, 10, 40, 15, 20, 30, 20, 20, 10,
+    40, 40, 30, 30, 30, 20, 30, 10, 10, 20,
+    5, 10, 30, 20, 20, 20, 5, 15, 10, 20,
+    15, 15, 35, 20, 15, 10, 20, 30, 15, 40,
+    20, 15, 10, 5, 10, 30, 10, 15, 20, 15,
+    40, 40, 10, 5, 15, 10, 10, 10, 15, 30,
+    30, 10, 10, 20, 10, 10,
+];
+
+/// Base Special stat of every species in Red/Blue/Yellow, indexed by National Pokédex number.
+/// The other base stats did not change in Generation 2, the Special was split in two.
+pub const SPECIAL_BASE_STATS: [u8; MAX_SPECIES_ID as usize + 1] = [
+    0, 65, 80, 100, 50, 65, 85, 50, 65, 85,
+    20, 25, 80, 20, 25, 45, 35, 50, 70, 25,
+    50, 31, 61, 40, 65, 50, 90, 30, 55, 40,
+    55, 75, 40, 55, 75, 60, 85, 65, 100, 25,
+    50, 40, 75, 75, 85, 100, 55, 80, 40, 90,
+    45, 70, 40, 65, 50, 80, 35, 60, 50, 80,
+    40, 50, 70, 105, 120, 135, 35, 50, 65, 70,
+    85, 100, 100, 120, 30, 45, 55, 65, 80, 40,
+    80, 95, 120, 58, 35, 60, 70, 95, 40, 65,
+    45, 85, 100, 115, 130, 30, 90, 115, 25, 50,
+    55, 80, 60, 125, 40, 50, 35, 35, 60, 60,
+    85, 30, 45, 105, 100, 40, 70, 95, 50, 80,
+    70, 100, 100, 55, 95, 85, 85, 55, 70, 20,
+    100, 95, 48, 65, 110, 110, 110, 75, 90, 115,
+    45, 70, 60, 65, 125, 125, 125, 50, 70, 100,
+    154, 100,
+];
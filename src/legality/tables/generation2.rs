@@ -0,0 +1,36 @@
+// region: Gold/Silver/Crystal
+/// Items that are not key items, TMs or HMs, and can be held in Gold/Silver/Crystal
+pub const HELD_ITEMS_GOLDSILVERCRYSTAL: [u8; 143] =
+[
+    1, 2, 3, 4, 5, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+    36, 37, 38, 39, 40, 41, 42, 43, 44, 46, 47, 48, 49, 51, 52, 53, 57, 60, 62, 63, 64, 65, 72, 73, 74, 75, 76, 77, 78, 79,
+    80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 91, 92, 93, 94, 95, 96, 97, 98, 99, 101, 102, 103, 104, 105, 106, 107, 108, 109,
+    110, 111, 112, 113, 114, 117, 118, 119, 121, 122, 123, 124, 125, 126, 131, 132, 138, 139, 140, 143, 144, 146, 150, 151,
+    152, 156, 157, 158, 159, 160, 161, 163, 164, 165, 166, 167, 168, 169, 170, 172, 173, 174, 177, 180, 181, 182, 183, 184,
+    185, 186, 187, 188, 189,
+];
+
+/// Held items of Gold/Silver/Crystal that are also in Generation 3, with their Generation 3 item ID.
+/// Items only in Generation 2, like the Apricorns and the berries renamed in Generation 3, are left out.
+pub const GENERATION3_ITEMS_GOLDSILVERCRYSTAL: [(u8, u16); 98] =
+[
+    (1, 1), (2, 2), (3, 179), (4, 3), (5, 4), (8, 94), (9, 14), (10, 15), (11, 16), (12, 17), (13, 18), (14, 19),
+    (15, 20), (16, 21), (17, 22), (18, 13), (19, 85), (20, 86), (21, 37), (22, 95), (23, 96), (24, 97), (26, 63),
+    (27, 64), (28, 65), (29, 66), (30, 222), (31, 67), (32, 68), (33, 78), (34, 98), (35, 223), (36, 110), (37, 80),
+    (38, 23), (39, 24), (40, 25), (41, 73), (42, 83), (43, 84), (44, 74), (46, 26), (47, 27), (48, 28), (49, 75),
+    (51, 76), (52, 77), (53, 79), (57, 182), (62, 69), (63, 34), (64, 35), (65, 36), (72, 29), (73, 183), (76, 203),
+    (77, 210), (81, 211), (82, 187), (86, 103), (87, 104), (88, 188), (91, 189), (94, 190), (95, 209), (96, 214),
+    (98, 207), (102, 206), (105, 225), (106, 194), (107, 212), (108, 208), (110, 106), (111, 107), (112, 195),
+    (113, 213), (117, 205), (118, 224), (119, 196), (121, 30), (122, 31), (123, 32), (124, 33), (125, 204), (126, 197),
+    (131, 108), (132, 109), (138, 215), (139, 44), (140, 198), (143, 199), (144, 216), (146, 200), (151, 201),
+    (156, 45), (163, 202), (169, 93), (172, 218),
+];
+
+/// Items the Time Capsule gives to Generation 1 Pokémon whose catch rate is not a valid item,
+/// every other catch rate is kept as the held item
+pub const TIME_CAPSULE_ITEMS: [(u8, u8); 12] =
+[
+    (0x19, 0x92), (0x2D, 0x53), (0x32, 0xAE), (0x5A, 0xAD), (0x64, 0xAD), (0x78, 0xAD),
+    (0x87, 0xAD), (0xBE, 0xAD), (0xC3, 0xAD), (0xDC, 0xAD), (0xFA, 0xAD), (0xFF, 0xAD),
+];
+// endregion
@@ -16,6 +16,46 @@ pub const MAX_POKEBALL_ID: u16 = 0xC;
 /// GroupColosseumXD
 pub const MAX_GAME_ID: u16 = 15;
 
+/// Base PP of every move, indexed by move ID
+pub const MOVE_PP: [u8; MAX_MOVE_ID as usize + 1] = [
+    0, 35, 25, 10, 15, 20, 20, 15, 15, 15,
+    35, 30, 5, 10, 30, 30, 35, 35, 20, 15,
+    20, 20, 10, 20, 30, 5, 25, 15, 15, 15,
+    25, 20, 5, 35, 15, 20, 20, 20, 15, 30,
+    35, 20, 20, 30, 25, 40, 20, 15, 20, 20,
+    20, 30, 25, 15, 30, 25, 5, 15, 10, 5,
+    20, 20, 20, 5, 35, 20, 25, 20, 20, 20,
+    15, 20, 10, 10, 40, 25, 10, 35, 30, 15,
+    20, 40, 10, 15, 30, 15, 20, 10, 15, 10,
+    5, 10, 10, 25, 10, 20, 40, 30, 30, 20,
+    20, 15, 10, 40, 15, 20, 30, 20, 20, 10,
+    40, 40, 30, 30, 30, 20, 30, 10, 10, 20,
+    5, 10, 30, 20, 20, 20, 5, 15, 10, 20,
+    15, 15, 35, 20, 15, 10, 20, 30, 15, 40,
+    20, 15, 10, 5, 10, 30, 10, 15, 20, 15,
+    40, 40, 10, 5, 15, 10, 10, 10, 15, 30,
+    30, 10, 10, 20, 10, 1, 1, 10, 10, 10,
+    5, 15, 25, 15, 10, 15, 30, 5, 40, 15,
+    10, 25, 10, 30, 10, 20, 10, 10, 10, 10,
+    10, 20, 5, 40, 5, 5, 15, 5, 10, 5,
+    15, 10, 5, 10, 20, 20, 40, 15, 10, 20,
+    20, 25, 5, 15, 10, 5, 20, 15, 20, 25,
+    20, 5, 30, 5, 10, 20, 40, 5, 20, 40,
+    20, 15, 35, 10, 5, 5, 5, 15, 5, 20,
+    5, 5, 15, 20, 10, 5, 5, 15, 15, 15,
+    15, 10, 10, 10, 10, 10, 10, 10, 10, 15,
+    15, 15, 10, 20, 20, 10, 20, 20, 20, 20,
+    20, 10, 10, 10, 20, 20, 5, 15, 10, 10,
+    15, 10, 20, 5, 5, 10, 10, 20, 5, 10,
+    20, 10, 20, 20, 20, 5, 5, 15, 20, 10,
+    15, 20, 15, 10, 10, 15, 10, 5, 5, 10,
+    15, 10, 5, 20, 25, 5, 40, 10, 5, 40,
+    15, 20, 20, 5, 15, 20, 30, 15, 15, 5,
+    10, 30, 20, 30, 15, 5, 40, 15, 5, 20,
+    5, 15, 25, 40, 15, 20, 15, 20, 15, 20,
+    10, 20, 20, 5, 5,
+];
+
 lazy_static! {
     /// `064` - is an unused location for Meteor Falls
     ///
@@ -4,6 +4,8 @@ pub mod pokemon;
 pub mod species;
 #[path ="utils/utils.rs"]
 pub mod utils;
+#[path ="error/error.rs"]
+pub mod error;
 #[path ="game/game.rs"]
 pub mod game;
 #[path ="save/save.rs"]
@@ -16,3 +18,11 @@ pub mod trainer;
 pub mod legality;
 #[path ="strings/strings.rs"]
 pub mod strings;
+#[path ="rng/rng.rs"]
+pub mod rng;
+#[path ="ffi/ffi.rs"]
+pub mod ffi;
+#[path ="json/json.rs"]
+pub mod json;
+#[path ="showdown/showdown.rs"]
+pub mod showdown;
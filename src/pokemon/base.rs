@@ -1,8 +1,8 @@
 use std::cmp::max;
 
-use crate::{species::base::{SpeciesInfo, SpeciesGenderCategory}, game::enums::{pokemon_gender::PokemonGender, game_version::GameVersion, pokemon_nature::PokemonNature, language_id::LanguageID, species_id::SpeciesID, location}, trainer::TrainerInfo};
+use crate::{error::PkmError, species::{base::{SpeciesInfo, SpeciesGenderCategory}, conversion::InvalidSpeciesIndex}, game::enums::{pokemon_gender::PokemonGender, game_version::GameVersion, pokemon_nature::PokemonNature, pokemon_type::PokemonType, language_id::LanguageID, species_id::SpeciesID, location}, trainer::TrainerInfo};
 
-use super::utils::{experience::{get_level, get_minimum_level_experience}, gender::generate_from_pid_and_ratio};
+use super::utils::{experience::{get_level, get_minimum_level_experience}, dv, gender::generate_from_pid_and_ratio, hidden_power, pid};
 
 #[derive(Debug, Default)]
 pub struct PokemonBase {
@@ -29,6 +29,10 @@ pub struct PokemonBase {
 	pub species_info: SpeciesInfo,
 
     pub species: SpeciesID,
+    /// Species value as stored by the game, only differs from the National Pokédex number in Generation 1 and 3
+    pub species_index: u16,
+    /// Set when `species_index` does not map to any species (e.g. MissingNo.)
+    pub invalid_species_index: Option<InvalidSpeciesIndex>,
     pub gender: PokemonGender,
     pub nature: PokemonNature,
     pub stats_nature: PokemonNature,
@@ -48,6 +52,8 @@ pub struct PokemonBase {
     pub ball_type: u8,
     pub met_level: u8,
 
+    pub held_item: u16,
+
     // Battle
     pub move_1: u16,
     pub move_1_pp: u8,
@@ -148,14 +154,18 @@ pub struct PokemonBase {
 
 /// ID Related implementations
 impl PokemonBase {
+    /// Checks if the Pokémon is shiny, from the DVs in Generations 1 and 2 and the PID afterwards
     pub fn is_shiny(&self) -> bool {
-        u32::from(self.ot_info.secret_id) == self.personality_id
+        if self.has_dvs() {
+            return dv::is_shiny(self.dvs());
+        }
+
+        let threshold = if self.generation() >= Some(7) { pid::SHINY_THRESHOLD_GENERATION7 } else { pid::SHINY_THRESHOLD };
+        self.shiny_xor() < threshold as u32
     }
 
     pub fn shiny_xor(&self) -> u32 {
-        let upper_bits = (self.personality_id >> 16) ^ (self.ot_info.secret_id as u32);
-
-        (self.personality_id * 0xFFFF) ^ (self.ot_info.public_id as u32) ^ upper_bits
+        pid::shiny_xor(self.personality_id, self.ot_info.public_id, self.ot_info.secret_id) as u32
     }
 }
 
@@ -235,12 +245,12 @@ impl PokemonBase {
 impl PokemonBase {
     /// Pokemon was transferred from Let's Go Eevee/Let's Go Pikachu
     pub fn is_from_gotransfer_letsgopikachu_letsgoeeve(&self) -> bool {
-        self.is_from_go() && self.met_location == location::TRANSFER_GO_LETS_GO_EEVEE_LETS_GO_PIKACHU as u16
+        self.is_from_go() && self.met_location == location::TRANSFER_GO_LETS_GO_EEVEE_LETS_GO_PIKACHU
     }
 
     /// Pokemon was transferred from Go to Home
     pub fn is_from_gotransfer_home(&self) -> bool {
-        self.is_from_go() && self.met_location == location::TRANSFER_GO_HOME as u16
+        self.is_from_go() && self.met_location == location::TRANSFER_GO_HOME
     }
 
     pub fn is_from_virtualconsole(&self) -> bool {
@@ -362,6 +372,49 @@ impl PokemonBase {
     }
 }
 
+/// Hidden Power related methods
+impl PokemonBase {
+    /// Gets the IVs in the HP, Attack, Defense, Speed, Special Attack, Special Defense order
+    pub fn ivs(&self) -> [u8; 6] {
+        [self.iv_hitpoints, self.iv_attack, self.iv_defense, self.iv_speed, self.iv_special_attack, self.iv_special_defense].map(|iv| iv as u8)
+    }
+
+    /// Checks if the IVs are Generation 1 and 2 DVs
+    fn has_dvs(&self) -> bool {
+        matches!(self.generation(), Some(1) | Some(2))
+    }
+
+    /// Gets the DVs in the Attack, Defense, Speed, Special order, the Special DV is stored as the Special Attack IV
+    pub fn dvs(&self) -> [u8; 4] {
+        [self.iv_attack, self.iv_defense, self.iv_speed, self.iv_special_attack].map(|dv| dv as u8)
+    }
+
+    pub fn hidden_power_type(&self) -> PokemonType {
+        if self.has_dvs() {
+            hidden_power::hidden_power_type_generation2(self.dvs())
+        } else {
+            hidden_power::hidden_power_type(self.ivs())
+        }
+    }
+
+    /// Gets the base power of Hidden Power, always 60 from Generation 6 onwards
+    pub fn hidden_power_power(&self) -> u8 {
+        if self.has_dvs() {
+            hidden_power::hidden_power_power_generation2(self.dvs())
+        } else if self.generation() >= Some(6) {
+            60
+        } else {
+            hidden_power::hidden_power_power(self.ivs())
+        }
+    }
+
+    /// Calculates the characteristic index, stored in `characteristic`
+    pub fn calculate_characteristic(&self) -> u32 {
+        let source = if self.generation() >= Some(6) { self.encryption_constant } else { self.personality_id };
+        hidden_power::characteristic(self.ivs(), source) as u32
+    }
+}
+
 impl PokemonBase {
     pub fn pid_ability(&self) -> Option<u32> {
         if self.generation() > Some(5) {
@@ -406,13 +459,21 @@ pub trait PokemonTrait {
     fn decrypt(&self) -> Vec<u8>;
     fn encrypt(&self) -> Vec<u8>;
     fn write(&self) -> Vec<u8>;
+    /// Same as `write`, with an error when the data is shorter than a stored Pokémon
+    fn try_write(&self) -> Result<Vec<u8>, PkmError>;
     fn read(data: &[u8]) -> Self;
+    /// Same as `read`, with an error when the data is shorter than a stored Pokémon
+    fn try_read(data: &[u8]) -> Result<Self, PkmError> where Self: Sized;
 
     fn get_species(&self) -> u16;
 
     fn is_nicknamed(&self) -> bool;
+    /// Sets the nickname back to the species name, writing the encoded bytes
+    fn reset_nickname(&mut self);
     fn is_egg(&self) -> bool;
 
-    fn regenerate_checksum(&self);
-    fn validate_checksum(&self);
+    /// Writes the checksum of the current data
+    fn regenerate_checksum(&mut self);
+    /// Checks that the stored checksum matches the data, always `true` for formats without a checksum
+    fn validate_checksum(&self) -> bool;
 }
@@ -0,0 +1,659 @@
+//! Standalone Pokémon files, as shared between save editors.
+//!
+//! The format is chosen by the extension when it is known, otherwise it is detected from the size:
+//!
+//! | Extension | Games | Sizes |
+//! |---|---|---|
+//! | `.pk1` | Red/Blue/Yellow, a list of one Pokémon with its names | 69, 59 (Japanese), 44 or 33 without names |
+//! | `.pk3` | Ruby/Sapphire/Emerald and FireRed/LeafGreen | 100 (party) or 80 (stored) |
+//! | `.ek3` | Same as `.pk3`, encrypted | 100 or 80 |
+//! | `.ck3` | Colosseum | 312 |
+//! | `.xk3` | XD | 196 |
+//!
+//! Colosseum and XD Pokémon are converted to and from the Generation 3 format when read and written,
+//! the Shadow Pokémon data is not kept.
+
+use std::{fs, path::Path};
+
+use crate::{
+    error::PkmError,
+    game::enums::{game_version::GameVersion, language_id::LanguageID},
+    pokemon::{base::{PokemonBase, PokemonTrait}, Generation1, Generation3},
+    save::{base::SaveSlot, generation1::utils::get_string_length, generation3::base::Generation3SaveFileBase},
+    strings::{self, StringConverterOption},
+    utils::{
+        constants::poke_crypto::{
+            self, SIZE_GENERATION_1_INTERNATIONAL_LIST, SIZE_GENERATION_1_JAPANESE_LIST, SIZE_GENERATION_1_PARTY, SIZE_GENERATION_1_STORED,
+            SIZE_GENERATION_3_COLOSSEUM_STORED, SIZE_GENERATION_3_PARTY, SIZE_GENERATION_3_STORED, SIZE_GENERATION_3_XD_STORED,
+        },
+        data_manipulation::SliceUtils,
+    },
+};
+
+/// Fills the unused characters of the Generation 1 names
+const GENERATION_1_STRING_TERMINATOR: u8 = 0x50;
+/// Ends the species list of the Generation 1 Pokémon lists
+const GENERATION_1_LIST_TERMINATOR: u8 = 0xFF;
+/// Characters of the Colosseum and XD names, without the terminator
+const GAMECUBE_STRING_LENGTH: usize = 10;
+/// Index of the Generation 3 stats (HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense) in the
+/// Colosseum and XD order, where the Speed is last
+const GAMECUBE_STAT_ORDER: [usize; 6] = [0, 1, 2, 5, 3, 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PokemonFileFormat {
+    Generation1,
+    Generation3,
+    Generation3Encrypted,
+    Colosseum,
+    XD,
+}
+
+impl PokemonFileFormat {
+    pub const ALL: [PokemonFileFormat; 5] = [
+        PokemonFileFormat::Generation1,
+        PokemonFileFormat::Generation3,
+        PokemonFileFormat::Generation3Encrypted,
+        PokemonFileFormat::Colosseum,
+        PokemonFileFormat::XD,
+    ];
+
+    /// Gets the extension of the format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            PokemonFileFormat::Generation1 => "pk1",
+            PokemonFileFormat::Generation3 => "pk3",
+            PokemonFileFormat::Generation3Encrypted => "ek3",
+            PokemonFileFormat::Colosseum => "ck3",
+            PokemonFileFormat::XD => "xk3",
+        }
+    }
+
+    /// Gets the format of the extension, which is case insensitive and can start with a dot
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let extension = extension.trim_start_matches('.').to_lowercase();
+        Self::ALL.into_iter().find(|format| format.extension() == extension)
+    }
+
+    /// Gets the format of the file extension of the path
+    pub fn from_path(path: &Path) -> Option<Self> {
+        Self::from_extension(path.extension()?.to_str()?)
+    }
+
+    /// Gets the sizes of the files of the format
+    pub fn sizes(self) -> &'static [usize] {
+        match self {
+            PokemonFileFormat::Generation1 => &[
+                SIZE_GENERATION_1_INTERNATIONAL_LIST,
+                SIZE_GENERATION_1_JAPANESE_LIST,
+                SIZE_GENERATION_1_PARTY,
+                SIZE_GENERATION_1_STORED,
+            ],
+            PokemonFileFormat::Generation3 | PokemonFileFormat::Generation3Encrypted => {
+                &[SIZE_GENERATION_3_PARTY as usize, SIZE_GENERATION_3_STORED as usize]
+            }
+            PokemonFileFormat::Colosseum => &[SIZE_GENERATION_3_COLOSSEUM_STORED],
+            PokemonFileFormat::XD => &[SIZE_GENERATION_3_XD_STORED],
+        }
+    }
+
+    /// Detects the format from the size of the data.
+    /// Generation 3 data is encrypted when its checksum does not match.
+    pub fn detect(data: &[u8]) -> Result<Self, PkmError> {
+        let format = Self::ALL
+            .into_iter()
+            .find(|format| format.sizes().contains(&data.len()))
+            .ok_or(PkmError::UnsupportedSize(data.len()))?;
+
+        if format == PokemonFileFormat::Generation3 && poke_crypto::get_generatation3_checksum(data) != u16::from_le_bytes([data[0x1C], data[0x1D]]) {
+            return Ok(PokemonFileFormat::Generation3Encrypted);
+        }
+
+        Ok(format)
+    }
+}
+
+/// Pokémon read from a file
+#[derive(Debug)]
+pub enum PokemonFile {
+    Generation1(Generation1),
+    Generation3(Generation3),
+}
+
+impl PokemonFile {
+    pub fn base(&self) -> &PokemonBase {
+        match self {
+            PokemonFile::Generation1(pokemon) => &pokemon.base,
+            PokemonFile::Generation3(pokemon) => &pokemon.base,
+        }
+    }
+
+    /// Reads the Pokémon, `current_extension` is set to the extension of its format.
+    /// Generation 3 data is decrypted when needed, whatever the extension.
+    ///
+    /// # Arguments
+    /// * `data` - Content of the file
+    /// * `extension` - Extension of the file, the format is detected from the data when it is missing or unknown
+    pub fn read(data: &[u8], extension: Option<&str>) -> Result<Self, PkmError> {
+        let format = match extension.and_then(PokemonFileFormat::from_extension) {
+            Some(format) if format.sizes().contains(&data.len()) => format,
+            Some(_) => return Err(PkmError::UnsupportedSize(data.len())),
+            None => PokemonFileFormat::detect(data)?,
+        };
+
+        let mut file = match format {
+            PokemonFileFormat::Generation1 => PokemonFile::Generation1(read_generation1(data)?),
+            PokemonFileFormat::Generation3 | PokemonFileFormat::Generation3Encrypted => PokemonFile::Generation3(Generation3::try_read(data)?),
+            PokemonFileFormat::Colosseum | PokemonFileFormat::XD => PokemonFile::Generation3(read_gamecube(data, format)?),
+        };
+
+        file.base_mut().current_extension = format.extension().to_string();
+        Ok(file)
+    }
+
+    /// Reads the Pokémon file at the path, see `read`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PkmError> {
+        let path = path.as_ref();
+        let data = fs::read(path)?;
+
+        Self::read(&data, path.extension().and_then(|extension| extension.to_str()))
+    }
+
+    /// Gets the content of the file in the format
+    ///
+    /// # Arguments
+    /// * `format` - Format of the file, the Pokémon is converted to it when the games allow it
+    /// * `party` - Whether Generation 3 files include the party data, `.pk1`, `.ck3` and `.xk3` files always do
+    pub fn write(&self, format: PokemonFileFormat, party: bool) -> Result<Vec<u8>, PkmError> {
+        match (self, format) {
+            (PokemonFile::Generation1(pokemon), PokemonFileFormat::Generation1) => Ok(write_generation1(pokemon)),
+            (PokemonFile::Generation3(_), PokemonFileFormat::Generation1) => Err(PkmError::InvalidValue("Generation 3 Pokémon can't be transferred to Generation 1")),
+            (_, PokemonFileFormat::Generation3 | PokemonFileFormat::Generation3Encrypted) => {
+                let pokemon = self.to_generation3()?;
+                let mut data = match party {
+                    true => pokemon.to_party().try_write()?,
+                    false => pokemon.try_write()?[..SIZE_GENERATION_3_STORED as usize].to_vec(),
+                };
+
+                if format == PokemonFileFormat::Generation3Encrypted {
+                    data = poke_crypto::encrypt_generation3_array(&data);
+                }
+
+                Ok(data)
+            }
+            (_, PokemonFileFormat::Colosseum | PokemonFileFormat::XD) => write_gamecube(&self.to_generation3()?, format),
+        }
+    }
+
+    /// Writes the Pokémon file at the path, in the format of its extension
+    ///
+    /// # Arguments
+    /// * `path` - Path of the file, ending with one of the extensions of `PokemonFileFormat`
+    /// * `party` - Whether Generation 3 files include the party data
+    pub fn save(&self, path: impl AsRef<Path>, party: bool) -> Result<(), PkmError> {
+        let path = path.as_ref();
+        let format = PokemonFileFormat::from_path(path).ok_or(PkmError::InvalidValue("the file extension is not a Pokémon format"))?;
+
+        fs::write(path, self.write(format, party)?)?;
+        Ok(())
+    }
+
+    /// Converts the Pokémon to the Generation 3 format.
+    /// Generation 1 Pokémon can't be transferred to Generation 3.
+    pub fn to_generation3(&self) -> Result<Generation3, PkmError> {
+        match self {
+            PokemonFile::Generation1(_) => Err(PkmError::InvalidValue("Generation 1 Pokémon can't be transferred to Generation 3")),
+            PokemonFile::Generation3(pokemon) => {
+                let mut result = Generation3::new(&pokemon.write());
+                result.base.current_extension = pokemon.base.current_extension.clone();
+                Ok(result)
+            }
+        }
+    }
+
+    /// Stores the Pokémon in the save slot, converted to the save format.
+    /// Party slots get the party data, see `Generation3SaveFileBase::set_party_slot`.
+    pub fn inject(&self, save: &mut Generation3SaveFileBase, slot: SaveSlot) -> Result<(), PkmError> {
+        let pokemon = self.to_generation3()?;
+        let stored = match slot {
+            SaveSlot::Party(slot) => save.set_party_slot(slot, &pokemon),
+            SaveSlot::Box(box_index, slot) => save.set_box_slot(box_index, slot, &pokemon),
+        };
+
+        match stored {
+            true => Ok(()),
+            false => Err(PkmError::InvalidValue("the slot does not exist")),
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut PokemonBase {
+        match self {
+            PokemonFile::Generation1(pokemon) => &mut pokemon.base,
+            PokemonFile::Generation3(pokemon) => &mut pokemon.base,
+        }
+    }
+}
+
+// region: Generation 1
+/// Reads a `.pk1` file, either a list of one Pokémon (count, species, terminator, party data,
+/// OT name and nickname) or the Pokémon data alone
+fn read_generation1(data: &[u8]) -> Result<Generation1, PkmError> {
+    if data.len() == SIZE_GENERATION_1_STORED || data.len() == SIZE_GENERATION_1_PARTY {
+        return Ok(Generation1::new(data, false));
+    }
+
+    if data[0] != 1 || data[2] != GENERATION_1_LIST_TERMINATOR {
+        return Err(PkmError::InvalidValue("the list must hold a single Pokémon"));
+    }
+
+    let is_japanese = data.len() == SIZE_GENERATION_1_JAPANESE_LIST;
+    let string_length = get_string_length(is_japanese);
+    let ot_offset = 3 + SIZE_GENERATION_1_PARTY;
+    let nickname_offset = ot_offset + string_length;
+
+    let mut pokemon = Generation1::new(&data[3..ot_offset], is_japanese);
+    pokemon.base.ot_bytes = data[ot_offset..nickname_offset].to_vec();
+    pokemon.base.nickname_bytes = data[nickname_offset..nickname_offset + string_length].to_vec();
+    pokemon.load_strings();
+
+    Ok(pokemon)
+}
+
+/// Writes the Pokémon as a list of one Pokémon, with its party stats recalculated.
+/// Stored data gets its party level and full HP.
+fn write_generation1(pokemon: &Generation1) -> Vec<u8> {
+    let mut data = pokemon.base.data.clone();
+    data.resize(SIZE_GENERATION_1_STORED, 0);
+    data.resize(SIZE_GENERATION_1_PARTY, 0);
+    if pokemon.base.data.len() < SIZE_GENERATION_1_PARTY {
+        data[0x21] = data[0x03];
+    }
+
+    let mut party = Generation1::new(&data, pokemon.is_japanese);
+    party.refresh_stats();
+    let mut data = party.base.data;
+    if pokemon.base.data.len() < SIZE_GENERATION_1_PARTY {
+        data[0x01..0x03].copy_from_slice(&party.base.hitpoints.to_be_bytes());
+    }
+
+    let string_length = get_string_length(pokemon.is_japanese);
+    let name = |bytes: &[u8]| {
+        let mut name = bytes.to_vec();
+        name.resize(string_length, GENERATION_1_STRING_TERMINATOR);
+        name
+    };
+
+    let mut result = vec![1, data[0x00], GENERATION_1_LIST_TERMINATOR];
+    result.extend(data);
+    result.extend(name(&pokemon.base.ot_bytes));
+    result.extend(name(&pokemon.base.nickname_bytes));
+
+    result
+}
+// endregion
+
+// region: Colosseum and XD
+/// Offsets of the Colosseum and XD formats, which are Big Endian with UTF-16 names.
+/// Both store the species, items and moves with their Generation 3 IDs.
+struct GameCubeLayout {
+    held_item: usize,
+    current_hitpoints: usize,
+    /// Stored in 2 bytes
+    friendship: usize,
+    met_location: usize,
+    /// Followed by the ball and the OT gender
+    met_level: usize,
+    level: usize,
+    experience: usize,
+    /// Followed by the trainer ID
+    secret_id: usize,
+    personality_id: usize,
+    /// Followed by the current region, the original region and the language
+    version: usize,
+    ot_name: usize,
+    /// Followed by a copy of the nickname
+    nickname: usize,
+    /// ID, PP and PP Ups of every move, 4 bytes each
+    moves: usize,
+    stats: usize,
+    /// Stored in 2 bytes each
+    evs: usize,
+    ivs: usize,
+    iv_size: usize,
+}
+
+const COLOSSEUM_LAYOUT: GameCubeLayout = GameCubeLayout {
+    held_item: 0x88,
+    current_hitpoints: 0x8A,
+    friendship: 0xB0,
+    met_location: 0x0C,
+    met_level: 0x0E,
+    level: 0x60,
+    experience: 0x5C,
+    secret_id: 0x14,
+    personality_id: 0x04,
+    version: 0x08,
+    ot_name: 0x18,
+    nickname: 0x2E,
+    moves: 0x78,
+    stats: 0x8C,
+    evs: 0x98,
+    ivs: 0xA4,
+    iv_size: 2,
+};
+
+const XD_LAYOUT: GameCubeLayout = GameCubeLayout {
+    held_item: 0x02,
+    current_hitpoints: 0x04,
+    friendship: 0x06,
+    met_location: 0x08,
+    met_level: 0x0E,
+    level: 0x11,
+    experience: 0x20,
+    secret_id: 0x24,
+    personality_id: 0x28,
+    version: 0x34,
+    ot_name: 0x38,
+    nickname: 0x4E,
+    moves: 0x80,
+    stats: 0x90,
+    evs: 0x9C,
+    ivs: 0xA8,
+    iv_size: 1,
+};
+
+// Colosseum flags
+const COLOSSEUM_IS_EGG: usize = 0xCB;
+const COLOSSEUM_ABILITY_NUMBER: usize = 0xCC;
+const COLOSSEUM_FATEFUL_ENCOUNTER: usize = 0x11C;
+
+// XD flags, in one byte
+const XD_FLAGS: usize = 0x1D;
+const XD_IS_EGG_BIT: u8 = 7;
+const XD_ABILITY_NUMBER_BIT: u8 = 6;
+const XD_FATEFUL_ENCOUNTER_BIT: u8 = 0;
+
+/// Flags both formats store in their own way
+#[derive(Debug, Default, Clone, Copy)]
+struct GameCubeFlags {
+    is_egg: bool,
+    ability_number: bool,
+    fateful_encounter: bool,
+}
+
+impl PokemonFileFormat {
+    fn gamecube_layout(self) -> Option<&'static GameCubeLayout> {
+        match self {
+            PokemonFileFormat::Colosseum => Some(&COLOSSEUM_LAYOUT),
+            PokemonFileFormat::XD => Some(&XD_LAYOUT),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a `.ck3` or `.xk3` file as a Generation 3 party Pokémon
+fn read_gamecube(data: &[u8], format: PokemonFileFormat) -> Result<Generation3, PkmError> {
+    let layout = format.gamecube_layout().ok_or(PkmError::Unsupported("Reading the format as a Colosseum or XD Pokémon"))?;
+    let u16_at = |offset: usize| u16::from_be_bytes([data[offset], data[offset + 1]]);
+    let u32_at = |offset: usize| u32::from_be_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]]);
+
+    let flags = match format {
+        PokemonFileFormat::Colosseum => GameCubeFlags {
+            is_egg: data[COLOSSEUM_IS_EGG] == 1,
+            ability_number: data[COLOSSEUM_ABILITY_NUMBER] == 1,
+            fateful_encounter: data[COLOSSEUM_FATEFUL_ENCOUNTER] == 1,
+        },
+        _ => GameCubeFlags {
+            is_egg: (data[XD_FLAGS] >> XD_IS_EGG_BIT) & 1 != 0,
+            ability_number: (data[XD_FLAGS] >> XD_ABILITY_NUMBER_BIT) & 1 != 0,
+            fateful_encounter: (data[XD_FLAGS] >> XD_FATEFUL_ENCOUNTER_BIT) & 1 != 0,
+        },
+    };
+
+    let language = LanguageID::from_gamecube_id(data[layout.version + 3]);
+    let mut result = vec![0u8; SIZE_GENERATION_3_PARTY as usize];
+
+    result[0x00..0x04].copy_from_slice(&u32_at(layout.personality_id).to_le_bytes());
+    result[0x04..0x06].copy_from_slice(&u16_at(layout.secret_id + 2).to_le_bytes());
+    result[0x06..0x08].copy_from_slice(&u16_at(layout.secret_id).to_le_bytes());
+    let nickname = decode_gamecube_string(&data[layout.nickname..]);
+    strings::generation_3::encode(&mut result[0x08..0x12], &nickname, GAMECUBE_STRING_LENGTH, language, StringConverterOption::ClearFF)
+        .map_err(|_| PkmError::InvalidValue("the nickname has characters Generation 3 can't store"))?;
+    result[0x12] = language as u8;
+
+    let species = u16_at(0x00);
+    result[0x13] = ((species != 0) as u8) << 1 | (flags.is_egg as u8) << 2;
+    let ot_name = decode_gamecube_string(&data[layout.ot_name..]);
+    strings::generation_3::encode(&mut result[0x14..0x1B], &ot_name, 7, language, StringConverterOption::ClearFF)
+        .map_err(|_| PkmError::InvalidValue("the OT name has characters Generation 3 can't store"))?;
+
+    // region: Growth
+    result[0x20..0x22].copy_from_slice(&species.to_le_bytes());
+    result[0x22..0x24].copy_from_slice(&u16_at(layout.held_item).to_le_bytes());
+    result[0x24..0x28].copy_from_slice(&u32_at(layout.experience).to_le_bytes());
+    result[0x29] = u16_at(layout.friendship).min(u8::MAX as u16) as u8;
+    // endregion
+
+    // region: Attacks
+    for slot in 0..4 {
+        let offset = layout.moves + slot * 4;
+        result[0x2C + slot * 2..0x2E + slot * 2].copy_from_slice(&u16_at(offset).to_le_bytes());
+        result[0x34 + slot] = data[offset + 2];
+        result[0x28] |= (data[offset + 3] & 3) << (slot * 2);
+    }
+    // endregion
+
+    // region: EVs, IVs and party stats
+    let mut iv32 = (flags.is_egg as u32) << 30 | (flags.ability_number as u32) << 31;
+    for (stat, gamecube_stat) in GAMECUBE_STAT_ORDER.into_iter().enumerate() {
+        result[0x38 + stat] = u16_at(layout.evs + gamecube_stat * 2).min(u8::MAX as u16) as u8;
+
+        let iv = match layout.iv_size {
+            2 => u16_at(layout.ivs + gamecube_stat * 2),
+            _ => data[layout.ivs + gamecube_stat] as u16,
+        };
+        iv32 |= (iv.min(31) as u32) << (stat * 5);
+
+        result[0x58 + stat * 2..0x5A + stat * 2].copy_from_slice(&u16_at(layout.stats + gamecube_stat * 2).to_le_bytes());
+    }
+    result[0x48..0x4C].copy_from_slice(&iv32.to_le_bytes());
+    // endregion
+
+    // region: Misc
+    result[0x45] = u16_at(layout.met_location).min(u8::MAX as u16) as u8;
+    let version = GameVersion::from_colosseumxd_id(data[layout.version]) as u16;
+    let origins = (data[layout.met_level] as u16 & 0x7F)
+        | (version & 0xF) << 7
+        | (data[layout.met_level + 1] as u16 & 0xF) << 11
+        | (data[layout.met_level + 2] as u16 & 1) << 15;
+    result[0x46..0x48].copy_from_slice(&origins.to_le_bytes());
+    result[0x4C..0x50].copy_from_slice(&((flags.fateful_encounter as u32) << 31).to_le_bytes());
+    // endregion
+
+    result[0x54] = data[layout.level];
+    result[0x56..0x58].copy_from_slice(&u16_at(layout.current_hitpoints).to_le_bytes());
+
+    let mut pokemon = Generation3::from_decrypted(&result);
+    pokemon.regenerate_checksum();
+    Ok(Generation3::from_decrypted(&pokemon.base.data))
+}
+
+/// Writes the Pokémon as a `.ck3` or `.xk3` file, with its party stats
+fn write_gamecube(pokemon: &Generation3, format: PokemonFileFormat) -> Result<Vec<u8>, PkmError> {
+    let layout = format.gamecube_layout().ok_or(PkmError::Unsupported("Writing the format as a Colosseum or XD Pokémon"))?;
+    let party = pokemon.to_party();
+    let base = &party.base;
+    let current_hitpoints = base.data.get_u16_le_offset(0x56)?;
+
+    let mut data = vec![0u8; format.sizes()[0]];
+    let mut set = |offset: usize, bytes: &[u8]| data[offset..offset + bytes.len()].copy_from_slice(bytes);
+
+    set(0x00, &base.species_index.to_be_bytes());
+    set(layout.personality_id, &base.personality_id.to_be_bytes());
+    set(layout.secret_id, &base.ot_info.secret_id.to_be_bytes());
+    set(layout.secret_id + 2, &base.ot_info.public_id.to_be_bytes());
+    let region = gamecube_region(base.language);
+    set(layout.version, &[base.version.to_colosseumxd_id(), region, region, base.language.to_gamecube_id()]);
+    set(layout.ot_name, &encode_gamecube_string(&base.ot_info.name));
+    set(layout.nickname, &encode_gamecube_string(&base.nickname));
+    set(layout.nickname + (GAMECUBE_STRING_LENGTH + 1) * 2, &encode_gamecube_string(&base.nickname));
+
+    set(layout.held_item, &base.held_item.to_be_bytes());
+    set(layout.experience, &base.experience.to_be_bytes());
+    set(layout.friendship, &(base.current_friendship as u16).to_be_bytes());
+    set(layout.met_location, &base.met_location.to_be_bytes());
+    set(layout.met_level, &[base.met_level, base.ball_type, base.ot_info.gender]);
+    set(layout.level, &[base.level]);
+    set(layout.current_hitpoints, &current_hitpoints.to_be_bytes());
+
+    let moves = [
+        (base.move_1, base.move_1_pp, base.move_1_pp_ups),
+        (base.move_2, base.move_2_pp, base.move_2_pp_ups),
+        (base.move_3, base.move_3_pp, base.move_3_pp_ups),
+        (base.move_4, base.move_4_pp, base.move_4_pp_ups),
+    ];
+    for (slot, (move_id, pp, pp_ups)) in moves.into_iter().enumerate() {
+        let [high, low] = move_id.to_be_bytes();
+        set(layout.moves + slot * 4, &[high, low, pp, pp_ups]);
+    }
+
+    let ivs = base.ivs();
+    let evs = [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack, base.ev_special_defense];
+    let stats = [base.hitpoints, base.attack, base.defense, base.speed, base.special_attack, base.special_defense];
+    for (stat, gamecube_stat) in GAMECUBE_STAT_ORDER.into_iter().enumerate() {
+        set(layout.evs + gamecube_stat * 2, &evs[stat].to_be_bytes());
+        match layout.iv_size {
+            2 => set(layout.ivs + gamecube_stat * 2, &(ivs[stat] as u16).to_be_bytes()),
+            _ => set(layout.ivs + gamecube_stat, &[ivs[stat]]),
+        }
+        set(layout.stats + gamecube_stat * 2, &stats[stat].to_be_bytes());
+    }
+
+    let flags = GameCubeFlags {
+        is_egg: party.is_egg,
+        ability_number: base.ability_number != 0,
+        fateful_encounter: base.fateful_encounter,
+    };
+    match format {
+        PokemonFileFormat::Colosseum => {
+            data[COLOSSEUM_IS_EGG] = flags.is_egg as u8;
+            data[COLOSSEUM_ABILITY_NUMBER] = flags.ability_number as u8;
+            data[COLOSSEUM_FATEFUL_ENCOUNTER] = flags.fateful_encounter as u8;
+        }
+        _ => {
+            data[XD_FLAGS] = (flags.is_egg as u8) << XD_IS_EGG_BIT
+                | (flags.ability_number as u8) << XD_ABILITY_NUMBER_BIT
+                | (flags.fateful_encounter as u8) << XD_FATEFUL_ENCOUNTER_BIT;
+        }
+    }
+
+    Ok(data)
+}
+
+/// Gets the region of the games of the language, Japan (0), North America (1) or Europe (2)
+fn gamecube_region(language: LanguageID) -> u8 {
+    match language {
+        LanguageID::Japanese => 0,
+        LanguageID::English => 1,
+        _ => 2,
+    }
+}
+
+/// Decodes a UTF-16 Big Endian name, ending at the first NUL character
+fn decode_gamecube_string(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .take(GAMECUBE_STRING_LENGTH)
+        .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+        .take_while(|unit| *unit != 0)
+        .collect();
+
+    String::from_utf16_lossy(&units)
+}
+
+/// Encodes a name as UTF-16 Big Endian, cut at 10 characters and followed by a NUL character
+fn encode_gamecube_string(value: &str) -> Vec<u8> {
+    let mut result: Vec<u8> = value.encode_utf16().take(GAMECUBE_STRING_LENGTH).flat_map(u16::to_be_bytes).collect();
+    result.resize((GAMECUBE_STRING_LENGTH + 1) * 2, 0);
+    result
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::PkmError,
+        game::enums::species_id::SpeciesID,
+        pokemon::{base::PokemonTrait, Generation1, Generation3},
+        showdown::{self, ShowdownSet},
+        utils::constants::poke_crypto::{SIZE_GENERATION_1_INTERNATIONAL_LIST, SIZE_GENERATION_1_STORED, SIZE_GENERATION_3_PARTY},
+    };
+
+    use super::{PokemonFile, PokemonFileFormat};
+
+    #[test]
+    fn detects_formats() {
+        let set: ShowdownSet = "Mudkip\nLevel: 5".parse().unwrap();
+        let mut pokemon = Generation3::default();
+        showdown::generation3::apply(&set, &mut pokemon).unwrap();
+        let file = PokemonFile::Generation3(pokemon);
+
+        let encrypted = file.write(PokemonFileFormat::Generation3Encrypted, false).unwrap();
+        assert_eq!(PokemonFileFormat::detect(&encrypted), Ok(PokemonFileFormat::Generation3Encrypted));
+
+        let read = PokemonFile::read(&encrypted, None).unwrap();
+        assert_eq!(read.base().species, SpeciesID::Mudkip);
+        assert_eq!(read.base().current_extension, "ek3");
+
+        let party = read.write(PokemonFileFormat::Generation3, true).unwrap();
+        assert_eq!(party.len(), SIZE_GENERATION_3_PARTY as usize);
+        assert_eq!(PokemonFileFormat::detect(&party), Ok(PokemonFileFormat::Generation3));
+        assert_eq!(Generation3::new(&party).base.level, 5);
+
+        assert_eq!(PokemonFile::read(&party, Some(".PK1")).unwrap_err(), PkmError::UnsupportedSize(100));
+        assert_eq!(PokemonFileFormat::detect(&[0; 312]), Ok(PokemonFileFormat::Colosseum));
+        assert!(file.write(PokemonFileFormat::Generation1, true).is_err());
+    }
+
+    #[test]
+    fn round_trips_generation1_lists() {
+        let set: ShowdownSet = "Sparky (Pikachu)\nLevel: 25\n- Thunderbolt".parse().unwrap();
+        let mut pokemon = Generation1::new(&[0; SIZE_GENERATION_1_STORED], false);
+        showdown::generation1::apply(&set, &mut pokemon).unwrap();
+
+        let file = PokemonFile::Generation1(pokemon);
+        let data = file.write(PokemonFileFormat::Generation1, false).unwrap();
+        assert_eq!(data.len(), SIZE_GENERATION_1_INTERNATIONAL_LIST);
+
+        let PokemonFile::Generation1(read) = PokemonFile::read(&data, None).unwrap() else {
+            panic!("expected a Generation 1 Pokémon");
+        };
+        assert_eq!(read.base.nickname, "Sparky");
+        assert_eq!(read.base.species, SpeciesID::Pikachu);
+        assert_eq!(read.base.level, 25);
+        assert_eq!([read.base.hitpoints, read.base.attack, read.base.defense, read.base.speed, read.base.special_attack], read.calculated_stats());
+        assert_eq!(&data[4..6], &read.base.hitpoints.to_be_bytes());
+        assert_eq!(read.write(), data[3..47]);
+        assert!(PokemonFile::Generation1(read).to_generation3().is_err());
+    }
+
+    #[test]
+    fn round_trips_colosseum_and_xd() {
+        let set: ShowdownSet = "Splash (Mudkip) @ Mystic Water\nLevel: 12\nEVs: 20 Atk / 8 Spe\nIVs: 3 Def\n- Water Gun\n- Mud-Slap".parse().unwrap();
+        let mut pokemon = Generation3::default();
+        showdown::generation3::apply(&set, &mut pokemon).unwrap();
+        let file = PokemonFile::Generation3(pokemon);
+        let party = file.write(PokemonFileFormat::Generation3, true).unwrap();
+
+        for (format, size) in [(PokemonFileFormat::Colosseum, 312), (PokemonFileFormat::XD, 196)] {
+            let data = file.write(format, true).unwrap();
+            assert_eq!(data.len(), size);
+            assert_eq!(u16::from_be_bytes([data[0], data[1]]), u16::from_le_bytes([party[0x20], party[0x21]]));
+
+            let read = PokemonFile::read(&data, Some(format.extension())).unwrap();
+            assert_eq!(read.base().nickname, "Splash");
+            assert_eq!(read.base().current_extension, format.extension());
+            assert_eq!(read.write(PokemonFileFormat::Generation3, true).unwrap(), party);
+        }
+    }
+}
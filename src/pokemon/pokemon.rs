@@ -1,6 +1,7 @@
 use self::base::PokemonBase;
 
 pub mod base;
+pub mod file;
 pub mod utils;
 pub mod variants;
 
@@ -14,4 +15,12 @@ pub struct Generation1 {
 pub struct Generation3 {
   pub base: PokemonBase,
   pub is_japanese: bool,
+
+  pub is_egg: bool,
+  pub is_bad_egg: bool,
+  pub has_species: bool,
+  pub use_egg_name: bool,
+
+  /// Raw ribbon and obedience bits, the Fateful Encounter flag is also exposed in `base`
+  pub ribbons: u32,
 }
@@ -1,2 +1,7 @@
+pub mod dv;
 pub mod experience;
 pub mod gender;
+pub mod hidden_power;
+pub mod nickname;
+pub mod pid;
+pub mod stats;
@@ -0,0 +1,62 @@
+//! Attributes derived from the DVs in Generations 1 and 2, which have no PID.
+//!
+//! DV arrays use the Attack, Defense, Speed, Special order, the HP DV is derived from them.
+
+use crate::game::enums::pokemon_gender::PokemonGender;
+
+use super::gender::generate_from_pid_and_ratio;
+
+/// DV of the Defense, Speed and Special of shiny Pokémon
+pub const SHINY_DV: u8 = 10;
+
+/// Gets the HP DV, made from the lowest bit of every other DV
+pub fn hitpoints_dv(dvs: [u8; 4]) -> u8 {
+    ((dvs[0] & 1) << 3) | ((dvs[1] & 1) << 2) | ((dvs[2] & 1) << 1) | (dvs[3] & 1)
+}
+
+/// Gets the gender in Generation 2, Pokémon with a low Attack DV are female.
+///
+/// # Arguments
+/// * `gender_ratio` - Gender ratio of the species, the same as in Generation 3
+pub fn gender(dvs: [u8; 4], gender_ratio: u8) -> PokemonGender {
+    generate_from_pid_and_ratio(((dvs[0] & 0xF) as u32) << 4, gender_ratio)
+}
+
+/// Checks if the Pokémon is shiny in Generation 2: Defense, Speed and Special of 10, and an
+/// Attack DV with the second bit set
+pub fn is_shiny(dvs: [u8; 4]) -> bool {
+    dvs[1] == SHINY_DV && dvs[2] == SHINY_DV && dvs[3] == SHINY_DV && dvs[0] & 2 != 0
+}
+
+/// Gets the Unown form in Generation 2, from 0 (A) to 25 (Z), made from the middle two bits of
+/// each DV
+pub fn unown_form(dvs: [u8; 4]) -> u8 {
+    let letter = ((dvs[0] & 6) << 5) | ((dvs[1] & 6) << 3) | ((dvs[2] & 6) << 1) | ((dvs[3] & 6) >> 1);
+    letter / 10
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::pokemon_gender::PokemonGender,
+        pokemon::utils::dv::{gender, hitpoints_dv, is_shiny, unown_form},
+    };
+
+    #[test]
+    fn derived_attributes() {
+        assert_eq!(hitpoints_dv([15, 15, 15, 15]), 15);
+        assert_eq!(hitpoints_dv([10, 10, 10, 10]), 0);
+        assert!(is_shiny([2, 10, 10, 10]));
+        assert!(!is_shiny([1, 10, 10, 10]));
+        assert_eq!(unown_form([0; 4]), 0);
+        assert_eq!(unown_form([15; 4]), 25);
+    }
+
+    #[test]
+    fn gender_depends_on_the_attack_dv() {
+        // One in eight female, like the starters
+        assert_eq!(gender([1, 15, 15, 15], 31), PokemonGender::Female);
+        assert_eq!(gender([2, 15, 15, 15], 31), PokemonGender::Male);
+        assert_eq!(gender([15, 15, 15, 15], 254), PokemonGender::Female);
+    }
+}
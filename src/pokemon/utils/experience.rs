@@ -113,9 +113,10 @@ pub fn get_level(experience: u32, growth_rate: usize) -> u8 {
     return 100;
   }
 
-  let level_search = EXPERIENCE_TABLE.into_iter().position(|x|  x[growth_rate] >= experience);
+  // The level is the count of table entries reached by the experience
+  let level_search = EXPERIENCE_TABLE.into_iter().position(|x|  x[growth_rate] > experience);
   match level_search {
-    Some(level) => (level as u8) + 1,
+    Some(level) => level as u8,
     None => 1
   }
 }
@@ -147,6 +148,13 @@ mod tests {
     assert_eq!(result, level);
   }
 
+  #[test]
+  fn get_level_between_table_entries() {
+    assert_eq!(get_level(9, 0), 2);
+    assert_eq!(get_level(26, 0), 2);
+    assert_eq!(get_level(27, 0), 3);
+  }
+
   #[test]
   fn get_minimum_experience_by_level() {
     let mut rng = rand::thread_rng();
@@ -0,0 +1,136 @@
+//! Hidden Power and characteristic, both derived from the IVs (DVs in Generation 2).
+//!
+//! IV arrays use the HP, Attack, Defense, Speed, Special Attack, Special Defense order and DV
+//! arrays the Attack, Defense, Speed, Special order.
+
+use crate::game::enums::pokemon_type::PokemonType;
+
+/// Types Hidden Power can have, in the order of the type index
+pub const HIDDEN_POWER_TYPES: [PokemonType; 16] = [
+    PokemonType::Fighting, PokemonType::Flying, PokemonType::Poison, PokemonType::Ground,
+    PokemonType::Rock, PokemonType::Bug, PokemonType::Ghost, PokemonType::Steel,
+    PokemonType::Fire, PokemonType::Water, PokemonType::Grass, PokemonType::Electric,
+    PokemonType::Psychic, PokemonType::Ice, PokemonType::Dragon, PokemonType::Dark,
+];
+
+/// Joins one bit of every IV, the HP bit being the lowest
+fn iv_bits(ivs: [u8; 6], bit: u8) -> u32 {
+    ivs.iter().enumerate().fold(0, |bits, (index, iv)| bits | (((*iv >> bit) & 1) as u32) << index)
+}
+
+/// Gets the Hidden Power type from Generation 3 onwards
+pub fn hidden_power_type(ivs: [u8; 6]) -> PokemonType {
+    HIDDEN_POWER_TYPES[(iv_bits(ivs, 0) * 15 / 63) as usize]
+}
+
+/// Gets the Hidden Power base power of Generations 3 to 5, from 30 to 70.
+/// Later generations always use 60.
+pub fn hidden_power_power(ivs: [u8; 6]) -> u8 {
+    (iv_bits(ivs, 1) * 40 / 63 + 30) as u8
+}
+
+/// Gets the Hidden Power type of Generation 2, only decided by the Attack and Defense DVs
+pub fn hidden_power_type_generation2(dvs: [u8; 4]) -> PokemonType {
+    HIDDEN_POWER_TYPES[(((dvs[0] & 3) << 2) | (dvs[1] & 3)) as usize]
+}
+
+/// Gets the Hidden Power base power of Generation 2, from 31 to 70
+pub fn hidden_power_power_generation2(dvs: [u8; 4]) -> u8 {
+    // Highest bit of each DV, Special being the lowest
+    let high_bits = dvs.iter().rev().enumerate().fold(0u32, |bits, (index, dv)| bits | (((*dv >> 3) & 1) as u32) << index);
+    ((5 * high_bits + (dvs[3] & 3) as u32) / 2 + 31) as u8
+}
+
+/// Gets the characteristic index, `stat * 5 + IV % 5` of the highest IV.
+/// Ties go to the first highest stat starting from `PID % 6`, using the encryption constant
+/// instead of the PID from Generation 6 onwards.
+pub fn characteristic(ivs: [u8; 6], pid: u32) -> u8 {
+    let highest = *ivs.iter().max().unwrap_or(&0);
+    let start = (pid % 6) as usize;
+    let stat = (0..6).map(|offset| (start + offset) % 6).find(|stat| ivs[*stat] == highest).unwrap_or(0);
+
+    (stat * 5) as u8 + highest % 5
+}
+
+/// Flips the lowest bit of every IV whose bit is set in `flipped`
+fn flip_lowest_bits(mut ivs: [u8; 6], flipped: u32) -> [u8; 6] {
+    for (index, iv) in ivs.iter_mut().enumerate() {
+        if (flipped >> index) & 1 != 0 {
+            *iv ^= 1;
+        }
+    }
+    ivs
+}
+
+/// Finds the IVs closest to the given ones with the Hidden Power type, only changing the lowest
+/// bit of the fewest IVs and keeping the highest power among them.
+///
+/// # Arguments
+/// * `hidden_power` - Requested Hidden Power type
+/// * `ivs` - IVs to start from
+pub fn find_hidden_power_ivs(hidden_power: PokemonType, ivs: [u8; 6]) -> Option<[u8; 6]> {
+    (0..64u32)
+        .map(|flipped| flip_lowest_bits(ivs, flipped))
+        .filter(|candidate| hidden_power_type(*candidate) == hidden_power)
+        .min_by_key(|candidate| {
+            let changed = candidate.iter().zip(ivs).filter(|(new, old)| **new != *old).count();
+            (changed, u8::MAX - hidden_power_power(*candidate))
+        })
+}
+
+/// Finds the DVs closest to the given ones with the Generation 2 Hidden Power type, only
+/// changing the two lowest bits of the Attack and Defense DVs.
+pub fn find_hidden_power_dvs(hidden_power: PokemonType, dvs: [u8; 4]) -> Option<[u8; 4]> {
+    let index = HIDDEN_POWER_TYPES.iter().position(|found| *found == hidden_power)? as u8;
+
+    let mut result = dvs;
+    result[0] = (dvs[0] & !3) | (index >> 2);
+    result[1] = (dvs[1] & !3) | (index & 3);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::pokemon_type::PokemonType,
+        pokemon::utils::hidden_power::{
+            characteristic, find_hidden_power_dvs, find_hidden_power_ivs, hidden_power_power, hidden_power_power_generation2, hidden_power_type,
+            hidden_power_type_generation2,
+        },
+    };
+
+    #[test]
+    fn perfect_ivs_give_dark_70() {
+        assert_eq!(hidden_power_type([31; 6]), PokemonType::Dark);
+        assert_eq!(hidden_power_power([31; 6]), 70);
+        assert_eq!(hidden_power_type_generation2([15; 4]), PokemonType::Dark);
+        assert_eq!(hidden_power_power_generation2([15; 4]), 70);
+        assert_eq!(hidden_power_power_generation2([0; 4]), 31);
+    }
+
+    #[test]
+    fn known_spreads() {
+        // The usual Hidden Power Fire and Ice spreads
+        assert_eq!(hidden_power_type([31, 30, 31, 30, 30, 31]), PokemonType::Fire);
+        assert_eq!(hidden_power_type([31, 30, 30, 31, 31, 31]), PokemonType::Ice);
+        assert_eq!(hidden_power_type_generation2([15, 13, 15, 15]), PokemonType::Ice);
+    }
+
+    #[test]
+    fn characteristics() {
+        assert_eq!(characteristic([0, 31, 0, 0, 0, 0], 0), 6);
+        // Every IV is tied, the PID picks Speed
+        assert_eq!(characteristic([31; 6], 3), 16);
+    }
+
+    #[test]
+    fn searched_ivs_have_the_type() {
+        let ivs = find_hidden_power_ivs(PokemonType::Fire, [31; 6]).unwrap();
+        assert_eq!(hidden_power_type(ivs), PokemonType::Fire);
+        assert_eq!(ivs.iter().filter(|iv| **iv == 30).count(), 3);
+
+        let dvs = find_hidden_power_dvs(PokemonType::Water, [15; 4]).unwrap();
+        assert_eq!(hidden_power_type_generation2(dvs), PokemonType::Water);
+        assert!(find_hidden_power_dvs(PokemonType::Normal, [15; 4]).is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    game::enums::{language_id::LanguageID, species_id::SpeciesID},
+    strings::{generation_1_or_2::normalize_japanese, names::species_name},
+};
+
+/// Languages that share the international Generation 1/2 character set, whose saves do not store the language
+pub const GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES: [LanguageID; 5] = [
+    LanguageID::English,
+    LanguageID::French,
+    LanguageID::Italian,
+    LanguageID::German,
+    LanguageID::Spanish,
+];
+
+/// Removes the accents that Generation 1/2 fonts cannot display in upper case
+fn strip_generation_1_or_2_accents(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'À' | 'Â' => 'A',
+            'Ç' => 'C',
+            'É' | 'È' | 'Ê' | 'Ë' => 'E',
+            'Î' | 'Ï' => 'I',
+            'Ô' => 'O',
+            'Ù' | 'Û' => 'U',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Gets the name given by the games to a species that was not nicknamed.
+///
+/// # Arguments
+/// * `species` - Species of the Pokémon
+/// * `language` - Language of the game the Pokémon was named in
+/// * `generation` - Generation of the Pokémon format, Generation 1 to 3 store the names in upper case
+pub fn get_default_nickname(species: SpeciesID, language: LanguageID, generation: u8) -> Option<String> {
+    let name = species_name(species, language)?;
+
+    if language == LanguageID::Japanese || language == LanguageID::Korean || generation > 3 {
+        return Some(name.to_string());
+    }
+
+    let name = name.to_uppercase();
+    if generation > 2 {
+        return Some(name);
+    }
+
+    // Generation 1/2 species names do not have a space after the period
+    Some(strip_generation_1_or_2_accents(&name).replace(". ", "."))
+}
+
+/// Checks if a nickname is the default name of the species in the given language.
+pub fn is_default_nickname(nickname: &str, species: SpeciesID, language: LanguageID, generation: u8) -> bool {
+    match get_default_nickname(species, language, generation) {
+        // Some katakana share their code with a hiragana in Generation 1/2
+        Some(name) if language == LanguageID::Japanese && generation <= 2 => {
+            normalize_japanese(&name) == normalize_japanese(nickname)
+        }
+        Some(name) => name == nickname,
+        None => false,
+    }
+}
+
+/// Checks if a nickname is the default name of the species in any of the given languages.
+///
+/// Used by formats that do not store the language, like the International Generation 1/2 saves.
+pub fn is_default_nickname_any_language(nickname: &str, species: SpeciesID, languages: &[LanguageID], generation: u8) -> bool {
+    languages
+        .iter()
+        .any(|language| is_default_nickname(nickname, species, *language, generation))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{language_id::LanguageID, species_id::SpeciesID},
+        pokemon::utils::nickname::{get_default_nickname, is_default_nickname, is_default_nickname_any_language, GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES},
+    };
+
+    #[test]
+    fn default_nickname_is_upper_case() {
+        assert_eq!(get_default_nickname(SpeciesID::Treecko, LanguageID::English, 3), Some("TREECKO".to_string()));
+        assert_eq!(get_default_nickname(SpeciesID::MrMime, LanguageID::English, 3), Some("MR. MIME".to_string()));
+        assert_eq!(get_default_nickname(SpeciesID::MrMime, LanguageID::English, 1), Some("MR.MIME".to_string()));
+        assert_eq!(get_default_nickname(SpeciesID::Electrode, LanguageID::French, 1), Some("ELECTRODE".to_string()));
+        assert_eq!(get_default_nickname(SpeciesID::Pikachu, LanguageID::Japanese, 1), Some("ピカチュウ".to_string()));
+    }
+
+    #[test]
+    fn detect_nickname() {
+        assert!(is_default_nickname("BULBASAUR", SpeciesID::Bulbasaur, LanguageID::English, 3));
+        assert!(!is_default_nickname("Bulby", SpeciesID::Bulbasaur, LanguageID::English, 3));
+        assert!(!is_default_nickname("BULBASAUR", SpeciesID::Ivysaur, LanguageID::English, 3));
+        assert!(is_default_nickname("りザードン", SpeciesID::Charizard, LanguageID::Japanese, 1));
+        assert!(!is_default_nickname("りザードン", SpeciesID::Charizard, LanguageID::Japanese, 3));
+    }
+
+    #[test]
+    fn detect_nickname_any_language() {
+        assert!(is_default_nickname_any_language("GLURAK", SpeciesID::Charizard, &GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES, 1));
+        assert!(!is_default_nickname_any_language("GLURAK", SpeciesID::Charmander, &GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES, 1));
+    }
+}
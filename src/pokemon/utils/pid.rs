@@ -0,0 +1,203 @@
+//! Attributes derived from the Personality ID (PID) in Generation 3.
+
+use rand::Rng;
+
+use crate::game::enums::{pokemon_gender::PokemonGender, pokemon_nature::PokemonNature};
+
+use super::gender::generate_from_pid_and_ratio;
+
+/// Shiny XOR values below this are shiny, until Generation 6
+pub const SHINY_THRESHOLD: u16 = 8;
+/// Shiny XOR values below this are shiny, from Generation 7 onwards
+pub const SHINY_THRESHOLD_GENERATION7: u16 = 16;
+
+/// Number of Unown forms, A to Z then ! and ?
+pub const UNOWN_FORM_COUNT: u8 = 28;
+
+/// Random PIDs tried by `search_pid` before giving up
+const MAX_SEARCH_ATTEMPTS: usize = 1 << 22;
+
+/// Species Wurmple evolves into, decided by the PID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WurmpleEvolution {
+    Silcoon,
+    Cascoon,
+}
+
+pub fn nature(pid: u32) -> PokemonNature {
+    PokemonNature::from((pid % 25) as u8)
+}
+
+/// Gets the ability slot, 0 for the first ability and 1 for the second
+pub fn ability_slot(pid: u32) -> u8 {
+    (pid & 1) as u8
+}
+
+/// Gets the gender for the gender ratio of the species
+pub fn gender(pid: u32, gender_ratio: u8) -> PokemonGender {
+    generate_from_pid_and_ratio(pid, gender_ratio)
+}
+
+/// Gets the value compared against the shiny threshold, 0 for the rarer "square" shinies.
+///
+/// # Arguments
+/// * `trainer_id` - Original Trainer ID
+/// * `secret_id` - Original Trainer Secret ID
+pub fn shiny_xor(pid: u32, trainer_id: u16, secret_id: u16) -> u16 {
+    trainer_id ^ secret_id ^ (pid >> 16) as u16 ^ pid as u16
+}
+
+pub fn is_shiny(pid: u32, trainer_id: u16, secret_id: u16) -> bool {
+    shiny_xor(pid, trainer_id, secret_id) < SHINY_THRESHOLD
+}
+
+/// Gets the Unown form, made from the lowest two bits of each PID byte
+pub fn unown_form(pid: u32) -> u8 {
+    let letter = ((pid >> 18) & 0xC0) | ((pid >> 12) & 0x30) | ((pid >> 6) & 0x0C) | (pid & 0x03);
+    (letter % UNOWN_FORM_COUNT as u32) as u8
+}
+
+/// Gets the letter of an Unown form
+pub fn unown_letter(form: u8) -> Option<char> {
+    match form {
+        0..=25 => Some((b'A' + form) as char),
+        26 => Some('!'),
+        27 => Some('?'),
+        _ => None,
+    }
+}
+
+/// Gets the offsets of the four Spinda spots from their default position, as (x, y) pairs.
+/// Each PID byte places a spot, starting from the lowest byte.
+pub fn spinda_spots(pid: u32) -> [(u8, u8); 4] {
+    pid.to_le_bytes().map(|byte| (byte & 0xF, byte >> 4))
+}
+
+pub fn wurmple_evolution(pid: u32) -> WurmpleEvolution {
+    if (pid >> 16) % 10 < 5 {
+        WurmpleEvolution::Silcoon
+    } else {
+        WurmpleEvolution::Cascoon
+    }
+}
+
+/// Every attribute derived from a PID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PidAttributes {
+    pub pid: u32,
+    pub nature: PokemonNature,
+    pub ability_slot: u8,
+    pub gender: PokemonGender,
+    pub shiny: bool,
+    pub unown_form: u8,
+    pub spinda_spots: [(u8, u8); 4],
+    pub wurmple_evolution: WurmpleEvolution,
+}
+
+impl PidAttributes {
+    /// Gets the attributes of the PID.
+    ///
+    /// # Arguments
+    /// * `gender_ratio` - Gender ratio of the species
+    /// * `trainer_id` - Original Trainer ID
+    /// * `secret_id` - Original Trainer Secret ID
+    pub fn new(pid: u32, gender_ratio: u8, trainer_id: u16, secret_id: u16) -> Self {
+        Self {
+            pid,
+            nature: nature(pid),
+            ability_slot: ability_slot(pid),
+            gender: gender(pid, gender_ratio),
+            shiny: is_shiny(pid, trainer_id, secret_id),
+            unown_form: unown_form(pid),
+            spinda_spots: spinda_spots(pid),
+            wurmple_evolution: wurmple_evolution(pid),
+        }
+    }
+}
+
+/// Attributes a PID must have, every `None` value is not checked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PidCriteria {
+    pub nature: Option<PokemonNature>,
+    pub ability_slot: Option<u8>,
+    pub gender: Option<PokemonGender>,
+    pub shiny: Option<bool>,
+    pub unown_form: Option<u8>,
+    pub wurmple_evolution: Option<WurmpleEvolution>,
+}
+
+impl PidCriteria {
+    pub fn matches(&self, attributes: &PidAttributes) -> bool {
+        self.nature.is_none_or(|nature| nature == attributes.nature)
+            && self.ability_slot.is_none_or(|slot| slot == attributes.ability_slot)
+            && self.gender.is_none_or(|gender| gender == attributes.gender)
+            && self.shiny.is_none_or(|shiny| shiny == attributes.shiny)
+            && self.unown_form.is_none_or(|form| form == attributes.unown_form)
+            && self.wurmple_evolution.is_none_or(|evolution| evolution == attributes.wurmple_evolution)
+    }
+}
+
+/// Searches a random PID with the requested attributes. The PID is not tied to any RNG method,
+/// use `rng::generation3::generate_pidiv` when the PID must match an encounter.
+///
+/// # Arguments
+/// * `criteria` - Attributes the PID must have
+/// * `gender_ratio` - Gender ratio of the species
+/// * `trainer_id` - Original Trainer ID
+/// * `secret_id` - Original Trainer Secret ID
+pub fn search_pid(criteria: &PidCriteria, gender_ratio: u8, trainer_id: u16, secret_id: u16) -> Option<u32> {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..MAX_SEARCH_ATTEMPTS {
+        let mut pid: u32 = rng.gen();
+
+        // Shiny PIDs are too rare to find by chance, so the upper half is made from the lower one
+        if criteria.shiny == Some(true) {
+            let upper = trainer_id ^ secret_id ^ pid as u16 ^ (rng.gen::<u16>() % SHINY_THRESHOLD);
+            pid = ((upper as u32) << 16) | (pid & 0xFFFF);
+        }
+
+        if criteria.matches(&PidAttributes::new(pid, gender_ratio, trainer_id, secret_id)) {
+            return Some(pid);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{pokemon_gender::PokemonGender, pokemon_nature::PokemonNature},
+        pokemon::utils::pid::{is_shiny, search_pid, spinda_spots, unown_form, unown_letter, wurmple_evolution, PidAttributes, PidCriteria, WurmpleEvolution},
+    };
+
+    #[test]
+    fn derived_attributes() {
+        assert_eq!(unown_letter(unown_form(0)), Some('A'));
+        // Lowest bits of every byte set, 0xFF % 28 = 3
+        assert_eq!(unown_letter(unown_form(0x0303_0303)), Some('D'));
+        assert_eq!(spinda_spots(0x1234_5678), [(8, 7), (6, 5), (4, 3), (2, 1)]);
+        assert_eq!(wurmple_evolution(0x0004_FFFF), WurmpleEvolution::Silcoon);
+        assert_eq!(wurmple_evolution(0x0005_0000), WurmpleEvolution::Cascoon);
+        assert!(is_shiny(0x0000_0007, 0, 0));
+        assert!(!is_shiny(0x0000_0008, 0, 0));
+    }
+
+    #[test]
+    fn searched_pid_matches() {
+        let criteria = PidCriteria {
+            nature: Some(PokemonNature::Timid),
+            ability_slot: Some(1),
+            gender: Some(PokemonGender::Female),
+            shiny: Some(true),
+            ..Default::default()
+        };
+
+        let pid = search_pid(&criteria, 127, 12345, 54321).unwrap();
+        let attributes = PidAttributes::new(pid, 127, 12345, 54321);
+        assert!(criteria.matches(&attributes));
+        assert_eq!(attributes.nature, PokemonNature::Timid);
+        assert!(attributes.shiny);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::game::enums::pokemon_nature::PokemonNature;
+
+/// Gets the nature multiplier of a stat in tenths.
+///
+/// # Arguments
+/// * `stat` - Stat index without HP, Attack, Defense, Speed, Special Attack, Special Defense
+pub fn nature_multiplier(nature: PokemonNature, stat: usize) -> u32 {
+    let nature = nature as usize;
+    if nature >= 25 {
+        return 10;
+    }
+
+    let increased = nature / 5;
+    let decreased = nature % 5;
+
+    if increased == decreased {
+        10
+    } else if increased == stat {
+        11
+    } else if decreased == stat {
+        9
+    } else {
+        10
+    }
+}
+
+/// Calculates the stats from Generation 3 onwards, in the HP, Attack, Defense, Speed,
+/// Special Attack, Special Defense order. Species with 1 base HP (Shedinja) always have 1 HP.
+pub fn calculate_stats(base_stats: [u8; 6], ivs: [u16; 6], evs: [u16; 6], level: u8, nature: PokemonNature) -> [u16; 6] {
+    let level = level as u32;
+    let raw = |index: usize| (2 * base_stats[index] as u32 + ivs[index] as u32 + evs[index] as u32 / 4) * level / 100;
+
+    let mut stats = [0u16; 6];
+    stats[0] = if base_stats[0] == 1 { 1 } else { (raw(0) + level + 10) as u16 };
+
+    // Nature order is Attack, Defense, Speed, Special Attack, Special Defense
+    for (index, stat) in stats.iter_mut().enumerate().skip(1) {
+        *stat = ((raw(index) + 5) * nature_multiplier(nature, index - 1) / 10) as u16;
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{game::enums::pokemon_nature::PokemonNature, pokemon::utils::stats::calculate_stats};
+
+    #[test]
+    fn calculate_garchomp_stats() {
+        // Level 78 Adamant Garchomp, the usual example of the stat formula
+        let stats = calculate_stats([108, 130, 95, 102, 80, 85], [24, 12, 30, 5, 16, 23], [74, 190, 91, 23, 48, 84], 78, PokemonNature::Adamant);
+        assert_eq!(stats, [289, 278, 193, 171, 135, 171]);
+    }
+
+    #[test]
+    fn shedinja_has_one_hitpoint() {
+        let stats = calculate_stats([1, 90, 45, 40, 30, 30], [31; 6], [0; 6], 50, PokemonNature::Hardy);
+        assert_eq!(stats[0], 1);
+    }
+}
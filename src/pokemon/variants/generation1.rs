@@ -1,4 +1,13 @@
-use crate::pokemon::{base::{PokemonTrait, PokemonBase}, Generation1};
+use crate::{
+  error::{ensure_size, PkmError},
+  game::enums::{game_version::GameVersion, language_id::LanguageID, pokemon_gender::PokemonGender},
+  legality::tables::generation1::SPECIAL_BASE_STATS,
+  pokemon::{base::{PokemonTrait, PokemonBase}, file::PokemonFileFormat, utils::dv, utils::nickname::{get_default_nickname, is_default_nickname, is_default_nickname_any_language, GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES}, Generation1},
+  save::generation1::utils::get_string_length,
+  species::{base::SpeciesInfo, conversion::species_from_generation1_index, personal::PersonalInfo},
+  strings::{self, StringConverterOption},
+  utils::{constants::poke_crypto::{SIZE_GENERATION_1_PARTY, SIZE_GENERATION_1_STORED}, data_manipulation::SliceUtils},
+};
 
 impl Generation1 {
   pub fn new(data: &[u8], is_japanese: bool) -> Self {
@@ -8,9 +17,174 @@ impl Generation1 {
       };
 
       result.base.data = data.to_vec();
+      result.base.valid_extensions = vec![PokemonFileFormat::Generation1.extension().to_string()];
+      result.base.current_extension = PokemonFileFormat::Generation1.extension().to_string();
+      result.base.size_on_party = SIZE_GENERATION_1_PARTY as u16;
+      result.base.size_when_stored = SIZE_GENERATION_1_STORED as u16;
+      result.base.version = GameVersion::GroupRedBlueYellow;
+      result.base.language = if is_japanese { LanguageID::Japanese } else { LanguageID::English };
+      result.base.max_iv = 15;
+      result.base.max_ev = u16::MAX;
+
+      if data.len() >= SIZE_GENERATION_1_STORED {
+        result.load_from_data();
+      }
 
       result
   }
+
+  /// Reads the stored fields from `base.data`, data is Big Endian
+  fn load_from_data(&mut self) {
+    let data = self.base.data.clone();
+    let base = &mut self.base;
+
+    base.species_index = data[0x00] as u16;
+    match species_from_generation1_index(data[0x00]) {
+      Ok(species) => {
+        base.species = species;
+        base.invalid_species_index = None;
+      },
+      Err(error) => {
+        base.species = Default::default();
+        base.invalid_species_index = Some(error);
+      },
+    }
+
+    base.level = data[0x03];
+    base.status_condition = data[0x04];
+    // Catch rate in Generation 1, held item when traded to Generation 2
+    base.held_item = data[0x07] as u16;
+
+    base.move_1 = data[0x08] as u16;
+    base.move_2 = data[0x09] as u16;
+    base.move_3 = data[0x0A] as u16;
+    base.move_4 = data[0x0B] as u16;
+
+    base.ot_info.public_id = data.get_u16_be_offset(0x0C).unwrap();
+    base.experience = u32::from_be_bytes([0, data[0x0E], data[0x0F], data[0x10]]);
+
+    base.ev_hitpoints = data.get_u16_be_offset(0x11).unwrap();
+    base.ev_attack = data.get_u16_be_offset(0x13).unwrap();
+    base.ev_defense = data.get_u16_be_offset(0x15).unwrap();
+    base.ev_speed = data.get_u16_be_offset(0x17).unwrap();
+    // Generation 1 has a single Special stat
+    base.ev_special_attack = data.get_u16_be_offset(0x19).unwrap();
+    base.ev_special_defense = base.ev_special_attack;
+
+    let dvs = data.get_u16_be_offset(0x1B).unwrap();
+    base.iv_attack = (dvs >> 12) & 0xF;
+    base.iv_defense = (dvs >> 8) & 0xF;
+    base.iv_speed = (dvs >> 4) & 0xF;
+    base.iv_special_attack = dvs & 0xF;
+    base.iv_special_defense = base.iv_special_attack;
+    base.iv_hitpoints = dv::hitpoints_dv(base.dvs()) as u16;
+
+    base.move_1_pp = data[0x1D] & 0x3F;
+    base.move_1_pp_ups = data[0x1D] >> 6;
+    base.move_2_pp = data[0x1E] & 0x3F;
+    base.move_2_pp_ups = data[0x1E] >> 6;
+    base.move_3_pp = data[0x1F] & 0x3F;
+    base.move_3_pp_ups = data[0x1F] >> 6;
+    base.move_4_pp = data[0x20] & 0x3F;
+    base.move_4_pp_ups = data[0x20] >> 6;
+
+    if data.len() >= SIZE_GENERATION_1_PARTY {
+      base.level = data[0x21];
+      base.hitpoints = data.get_u16_be_offset(0x22).unwrap();
+      base.attack = data.get_u16_be_offset(0x24).unwrap();
+      base.defense = data.get_u16_be_offset(0x26).unwrap();
+      base.speed = data.get_u16_be_offset(0x28).unwrap();
+      base.special_attack = data.get_u16_be_offset(0x2A).unwrap();
+      base.special_defense = base.special_attack;
+    }
+
+    // Generation 1 has no gender, it is decided by the DVs once traded to Generation 2
+    let personal = PersonalInfo::generation3(base.species);
+    base.species_info = personal.map(SpeciesInfo::from).unwrap_or_default();
+    base.gender = dv::gender(base.dvs(), base.species_info.gender_ratio);
+
+    base.valid = base.invalid_species_index.is_none();
+  }
+
+  /// Gets the DVs in the Attack, Defense, Speed, Special order
+  pub fn dvs(&self) -> [u8; 4] {
+    self.base.dvs()
+  }
+
+  /// Gets the HP DV, derived from the other DVs
+  pub fn hitpoints_dv(&self) -> u8 {
+    dv::hitpoints_dv(self.dvs())
+  }
+
+  /// Gets the gender the Pokémon has once traded to Generation 2
+  pub fn gender(&self) -> PokemonGender {
+    dv::gender(self.dvs(), self.base.species_info.gender_ratio)
+  }
+
+  /// Checks if the Pokémon is shiny once traded to Generation 2
+  pub fn is_shiny(&self) -> bool {
+    dv::is_shiny(self.dvs())
+  }
+
+  /// Calculates the party stats at the level in the HP, Attack, Defense, Speed, Special order,
+  /// from the base stats, the DVs and the stat experience
+  pub fn calculated_stats(&self) -> [u16; 5] {
+    let base = &self.base;
+    let Some(personal) = PersonalInfo::generation3(base.species) else {
+      return [0; 5];
+    };
+
+    let [hitpoints, attack, defense, speed, ..] = personal.base_stats;
+    let special = SPECIAL_BASE_STATS.get(base.species.national_id() as usize).copied().unwrap_or_default();
+    let base_stats = [hitpoints, attack, defense, speed, special];
+    let [attack_dv, defense_dv, speed_dv, special_dv] = self.dvs();
+    let dvs = [self.hitpoints_dv(), attack_dv, defense_dv, speed_dv, special_dv];
+    let stat_experience = [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack];
+
+    let level = base.level as u32;
+    std::array::from_fn(|stat| {
+      let raw = ((base_stats[stat] as u32 + dvs[stat] as u32) * 2 + stat_experience_bonus(stat_experience[stat])) * level / 100;
+      (if stat == 0 { raw + level + 10 } else { raw + 5 }) as u16
+    })
+  }
+
+  /// Writes the level and the calculated stats into the party data, the current HP keeps the damage taken
+  pub fn refresh_stats(&mut self) {
+    if self.base.data.len() < SIZE_GENERATION_1_PARTY {
+      return;
+    }
+
+    let stats = self.calculated_stats();
+    let data = &mut self.base.data;
+    let previous_hitpoints = u16::from_be_bytes([data[0x22], data[0x23]]);
+    let damage = previous_hitpoints.saturating_sub(u16::from_be_bytes([data[0x01], data[0x02]]));
+    data[0x01..0x03].copy_from_slice(&stats[0].saturating_sub(damage).to_be_bytes());
+    data[0x21] = self.base.level;
+    for (stat, value) in stats.iter().enumerate() {
+      data[0x22 + stat * 2..0x24 + stat * 2].copy_from_slice(&value.to_be_bytes());
+    }
+
+    let base = &mut self.base;
+    [base.hitpoints, base.attack, base.defense, base.speed, base.special_attack] = stats;
+    base.special_defense = base.special_attack;
+  }
+
+  /// Sets the language of the game the Pokémon is from, International saves do not store it
+  ///
+  /// # Arguments
+  /// * `language` - Language of the save, used to reset the nickname
+  pub fn set_language(&mut self, language: LanguageID) {
+    if !self.is_japanese && language != LanguageID::Japanese {
+      self.base.language = language;
+      self.load_strings();
+    }
+  }
+
+  /// Decodes `nickname_bytes` and `ot_bytes`, which are stored apart from the Pokémon data in Generation 1
+  pub fn load_strings(&mut self) {
+    self.base.nickname = strings::generation_1_or_2::get_string(&self.base.nickname_bytes, self.base.language);
+    self.base.ot_info.name = strings::generation_1_or_2::get_string(&self.base.ot_bytes, self.base.language);
+  }
 }
 
 impl PokemonTrait for Generation1 {
@@ -19,38 +193,106 @@ impl PokemonTrait for Generation1 {
     }
 
     fn decrypt(&self) -> Vec<u8> {
-        todo!()
+        // Generation 1 data is not encrypted
+        self.base.data.to_vec()
     }
 
     fn encrypt(&self) -> Vec<u8> {
-        todo!()
+        self.base.data.to_vec()
     }
 
     fn write(&self) -> Vec<u8> {
-        todo!()
+        self.base.data.to_vec()
+    }
+
+    fn try_write(&self) -> Result<Vec<u8>, PkmError> {
+        ensure_size(&self.base.data, SIZE_GENERATION_1_STORED)?;
+
+        Ok(self.write())
     }
 
     fn read(data: &[u8]) -> Self {
-        todo!()
+        Self::new(data, false)
+    }
+
+    fn try_read(data: &[u8]) -> Result<Self, PkmError> {
+        ensure_size(data, SIZE_GENERATION_1_STORED)?;
+
+        Ok(Self::read(data))
     }
 
     fn get_species(&self) -> u16 {
-        todo!()
+        self.base.species.national_id()
     }
 
     fn is_nicknamed(&self) -> bool {
-        todo!()
+        if self.is_japanese {
+            return !is_default_nickname(&self.base.nickname, self.base.species, LanguageID::Japanese, 1);
+        }
+
+        // International saves do not store the language, any of the default names is accepted
+        !is_default_nickname_any_language(&self.base.nickname, self.base.species, &GENERATION_1_OR_2_INTERNATIONAL_LANGUAGES, 1)
+    }
+
+    fn reset_nickname(&mut self) {
+        let Some(name) = get_default_nickname(self.base.species, self.base.language, 1) else {
+            return;
+        };
+
+        let max_length = if self.is_japanese { 5 } else { 10 };
+        let mut buffer = vec![0u8; get_string_length(self.is_japanese)];
+        strings::generation_1_or_2::set_string(&mut buffer, &name, max_length, self.base.language, StringConverterOption::Clear50);
+
+        self.base.nickname_bytes = buffer;
+        self.base.nickname = name;
     }
 
     fn is_egg(&self) -> bool {
-        todo!()
+        // There is no eggs in Generation 1
+        false
     }
 
-    fn regenerate_checksum(&self) {
-        todo!()
+    fn regenerate_checksum(&mut self) {
+        // The Generation 1 format has no checksum
     }
 
-    fn validate_checksum(&self) {
-        todo!()
+    fn validate_checksum(&self) -> bool {
+        true
     }
 }
+
+/// Gets the stat experience added to a stat, a quarter of its rounded up square root
+fn stat_experience_bonus(stat_experience: u16) -> u32 {
+  match stat_experience {
+    0 => 0,
+    _ => ((stat_experience as f64 - 1.0).sqrt() as u32 + 1).min(255) / 4,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    game::enums::{language_id::LanguageID, species_id::SpeciesID},
+    pokemon::{base::PokemonTrait, Generation1},
+    species::conversion::generation1_index_from_species,
+    strings::{self, StringConverterOption},
+    utils::constants::poke_crypto::SIZE_GENERATION_1_PARTY,
+  };
+
+  #[test]
+  fn reset_nickname_uses_save_language() {
+    let mut data = [0u8; SIZE_GENERATION_1_PARTY];
+    data[0x00] = generation1_index_from_species(SpeciesID::Charizard).unwrap();
+
+    let mut pokemon = Generation1::new(&data, false);
+    pokemon.base.nickname_bytes = vec![0x50; 11];
+    strings::generation_1_or_2::set_string(&mut pokemon.base.nickname_bytes, "FLAMMY", 10, LanguageID::English, StringConverterOption::Clear50);
+    pokemon.set_language(LanguageID::German);
+    assert!(pokemon.is_nicknamed());
+
+    pokemon.reset_nickname();
+    assert_eq!(pokemon.base.nickname, "GLURAK");
+    assert_eq!(strings::generation_1_or_2::get_string(&pokemon.base.nickname_bytes, LanguageID::German), "GLURAK");
+  }
+}
+
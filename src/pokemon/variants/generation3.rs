@@ -1,46 +1,565 @@
-use crate::pokemon::{
-    base::{PokemonBase, PokemonTrait},
-    Generation3,
+use crate::{
+    error::{ensure_size, PkmError},
+    game::enums::{game_version::GameVersion, language_id::LanguageID, pokemon_nature::PokemonNature},
+    pokemon::{
+        base::{PokemonBase, PokemonTrait},
+        file::PokemonFileFormat,
+        Generation3,
+    },
+    pokemon::utils::{gender::generate_from_pid_and_ratio, nickname::{get_default_nickname, is_default_nickname}},
+    game::enums::species_id::SpeciesID,
+    pokemon::utils::{pid::{wurmple_evolution, WurmpleEvolution}, stats::calculate_stats},
+    species::{
+        base::SpeciesInfo,
+        conversion::{generation3_index_from_species, species_from_generation3_index},
+        evolution::{self, generation3::{EVERSTONE, EVOLUTIONS}, Evolution, EvolutionMethod, EvolutionTrigger, FRIENDSHIP_EVOLUTION_THRESHOLD},
+        personal::PersonalInfo,
+    },
+    strings::{self, StringConverterOption},
+    utils::{
+        constants::{
+            poke_crypto::{self, SIZE_GENERATION_3_PARTY, SIZE_GENERATION_3_STORED},
+            MAX_PARTY_SIZE,
+        },
+        data_manipulation::SliceUtils,
+    },
 };
 
+impl Generation3 {
+    pub fn new(data: &[u8]) -> Self {
+        if data.len() >= SIZE_GENERATION_3_STORED as usize {
+            Self::from_decrypted(&poke_crypto::decrypt_generatation3_array_if_encrypted(data.to_vec()))
+        } else {
+            Self::from_decrypted(data)
+        }
+    }
+
+    /// Loads data known to be decrypted, even when its checksum is outdated
+    pub(crate) fn from_decrypted(data: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        result.base.valid_extensions = [PokemonFileFormat::Generation3, PokemonFileFormat::Generation3Encrypted]
+            .map(|format| format.extension().to_string())
+            .to_vec();
+        result.base.current_extension = PokemonFileFormat::Generation3.extension().to_string();
+        result.base.size_on_party = SIZE_GENERATION_3_PARTY;
+        result.base.size_when_stored = SIZE_GENERATION_3_STORED;
+        result.base.max_iv = 31;
+        result.base.max_ev = 255;
+        result.base.data = data.to_vec();
+
+        if data.len() >= SIZE_GENERATION_3_STORED as usize {
+            result.load_from_data();
+        }
+
+        result
+    }
+
+    /// Reads the stored fields from the decrypted `base.data`
+    fn load_from_data(&mut self) {
+        let data = self.base.data.clone();
+        let base = &mut self.base;
+
+        base.personality_id = data.get_u32_le_offset(0x00).unwrap();
+        base.ot_info.public_id = data.get_u16_le_offset(0x04).unwrap();
+        base.ot_info.secret_id = data.get_u16_le_offset(0x06).unwrap();
+        base.nickname_bytes = data[0x08..0x12].to_vec();
+        base.language = LanguageID::from(data[0x12]);
+
+        let flags = data[0x13];
+        self.is_bad_egg = flags & 1 != 0;
+        self.has_species = (flags >> 1) & 1 != 0;
+        self.use_egg_name = (flags >> 2) & 1 != 0;
+
+        base.ot_bytes = data[0x14..0x1B].to_vec();
+        base.mark_value = data[0x1B] as u16;
+
+        // region: Growth
+        base.species_index = data.get_u16_le_offset(0x20).unwrap();
+        match species_from_generation3_index(base.species_index) {
+            Ok(species) => {
+                base.species = species;
+                base.invalid_species_index = None;
+            }
+            Err(error) => {
+                base.species = Default::default();
+                base.invalid_species_index = Some(error);
+            }
+        }
+
+        base.held_item = data.get_u16_le_offset(0x22).unwrap();
+        base.experience = data.get_u32_le_offset(0x24).unwrap();
+
+        let pp_ups = data[0x28];
+        base.move_1_pp_ups = pp_ups & 3;
+        base.move_2_pp_ups = (pp_ups >> 2) & 3;
+        base.move_3_pp_ups = (pp_ups >> 4) & 3;
+        base.move_4_pp_ups = (pp_ups >> 6) & 3;
+
+        base.current_friendship = data[0x29];
+        base.ot_friendship = data[0x29];
+        // endregion
+
+        // region: Attacks
+        base.move_1 = data.get_u16_le_offset(0x2C).unwrap();
+        base.move_2 = data.get_u16_le_offset(0x2E).unwrap();
+        base.move_3 = data.get_u16_le_offset(0x30).unwrap();
+        base.move_4 = data.get_u16_le_offset(0x32).unwrap();
+
+        base.move_1_pp = data[0x34];
+        base.move_2_pp = data[0x35];
+        base.move_3_pp = data[0x36];
+        base.move_4_pp = data[0x37];
+        // endregion
+
+        // region: EVs & Contest
+        base.ev_hitpoints = data[0x38] as u16;
+        base.ev_attack = data[0x39] as u16;
+        base.ev_defense = data[0x3A] as u16;
+        base.ev_speed = data[0x3B] as u16;
+        base.ev_special_attack = data[0x3C] as u16;
+        base.ev_special_defense = data[0x3D] as u16;
+        // endregion
+
+        // region: Misc
+        base.pokerus_strain = (data[0x44] >> 4) as u16;
+        base.pokerus_days = (data[0x44] & 0xF) as u16;
+        base.met_location = data[0x45] as u16;
+
+        let origins = data.get_u16_le_offset(0x46).unwrap();
+        base.met_level = (origins & 0x7F) as u8;
+        base.version = GameVersion::from(((origins >> 7) & 0xF) as u8);
+        base.ball_type = ((origins >> 11) & 0xF) as u8;
+        base.ot_info.gender = (origins >> 15) as u8;
+
+        let iv32 = data.get_u32_le_offset(0x48).unwrap();
+        base.iv_hitpoints = (iv32 & 0x1F) as u16;
+        base.iv_attack = ((iv32 >> 5) & 0x1F) as u16;
+        base.iv_defense = ((iv32 >> 10) & 0x1F) as u16;
+        base.iv_speed = ((iv32 >> 15) & 0x1F) as u16;
+        base.iv_special_attack = ((iv32 >> 20) & 0x1F) as u16;
+        base.iv_special_defense = ((iv32 >> 25) & 0x1F) as u16;
+        self.is_egg = (iv32 >> 30) & 1 != 0;
+        base.ability_number = ((iv32 >> 31) & 1) as u16;
+
+        self.ribbons = data.get_u32_le_offset(0x4C).unwrap();
+        base.fateful_encounter = (self.ribbons >> 31) & 1 != 0;
+        // endregion
+
+        // region: Party
+        if data.len() >= SIZE_GENERATION_3_PARTY as usize {
+            base.status_condition = data.get_u32_le_offset(0x50).unwrap() as u8;
+            base.level = data[0x54];
+            base.hitpoints = data.get_u16_le_offset(0x58).unwrap();
+            base.attack = data.get_u16_le_offset(0x5A).unwrap();
+            base.defense = data.get_u16_le_offset(0x5C).unwrap();
+            base.speed = data.get_u16_le_offset(0x5E).unwrap();
+            base.special_attack = data.get_u16_le_offset(0x60).unwrap();
+            base.special_defense = data.get_u16_le_offset(0x62).unwrap();
+        }
+        // endregion
+
+        // region: Derived
+        let personal = PersonalInfo::generation3(base.species);
+        base.species_info = personal.map(SpeciesInfo::from).unwrap_or_default();
+        base.ability = personal.map_or(0, |personal| personal.ability(base.ability_number));
+        base.gender = generate_from_pid_and_ratio(base.personality_id, base.species_info.gender_ratio);
+        base.nature = PokemonNature::from((base.personality_id % 25) as u8);
+        base.stats_nature = base.nature;
+        base.characteristic = base.calculate_characteristic();
+
+        // Stored data does not include the level
+        if data.len() < SIZE_GENERATION_3_PARTY as usize {
+            base.level = base.current_level();
+        }
+        // endregion
+
+        self.is_japanese = base.language == LanguageID::Japanese;
+        base.nickname = strings::generation_3::decode(&base.nickname_bytes, base.language);
+        base.ot_info.name = strings::generation_3::decode(&base.ot_bytes, base.language);
+
+        base.valid = base.invalid_species_index.is_none() && !self.is_bad_egg;
+    }
+
+    /// Changes the species, keeping the PID, IVs and EXP. Species without a Generation 3 index are ignored.
+    pub fn set_species(&mut self, species: SpeciesID) -> Result<(), PkmError> {
+        ensure_size(&self.base.data, SIZE_GENERATION_3_STORED as usize)?;
+        let Some(index) = generation3_index_from_species(species) else {
+            return Ok(());
+        };
+
+        let base = &mut self.base;
+        base.species = species;
+        base.species_index = index;
+        base.invalid_species_index = None;
+        base.data[0x20..0x22].copy_from_slice(&index.to_le_bytes());
+
+        let personal = PersonalInfo::generation3(species);
+        base.species_info = personal.map(SpeciesInfo::from).unwrap_or_default();
+        base.ability = personal.map_or(0, |personal| personal.ability(base.ability_number));
+        base.gender = generate_from_pid_and_ratio(base.personality_id, base.species_info.gender_ratio);
+        base.level = base.current_level();
+
+        Ok(())
+    }
+
+    /// Gets the stats calculated from the species, level, IVs, EVs and nature
+    pub fn calculated_stats(&self) -> [u16; 6] {
+        let base = &self.base;
+        let base_stats = PersonalInfo::generation3(base.species).map_or([0; 6], |personal| personal.base_stats);
+        let ivs = [base.iv_hitpoints, base.iv_attack, base.iv_defense, base.iv_speed, base.iv_special_attack, base.iv_special_defense];
+        let evs = [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack, base.ev_special_defense];
+
+        calculate_stats(base_stats, ivs, evs, base.level, base.stats_nature)
+    }
+
+    /// Recalculates the stats, also writing them in the party data.
+    /// The current HP changes by the same amount as the max HP.
+    pub fn refresh_stats(&mut self) {
+        let stats = self.calculated_stats();
+        let base = &mut self.base;
+        let previous_hitpoints = base.hitpoints;
+
+        [base.hitpoints, base.attack, base.defense, base.speed, base.special_attack, base.special_defense] = stats;
+
+        if base.data.len() >= SIZE_GENERATION_3_PARTY as usize {
+            let data = &mut base.data;
+            let current_hitpoints = data.get_u16_le_offset(0x56).unwrap();
+            let current_hitpoints = (current_hitpoints as i32 + stats[0] as i32 - previous_hitpoints as i32).clamp(0, stats[0] as i32) as u16;
+
+            data[0x54] = base.level;
+            data[0x56..0x58].copy_from_slice(&current_hitpoints.to_le_bytes());
+            for (index, stat) in stats.iter().enumerate() {
+                data[0x58 + index * 2..0x5A + index * 2].copy_from_slice(&stat.to_le_bytes());
+            }
+        }
+    }
+
+    /// Gets the Pokémon with party data, with the stats calculated and full HP when it only had the stored data
+    pub fn to_party(&self) -> Self {
+        let mut data = self.write();
+        if data.len() >= SIZE_GENERATION_3_PARTY as usize {
+            return Self::from_decrypted(&data);
+        }
+
+        data.resize(SIZE_GENERATION_3_PARTY as usize, 0);
+        let mut result = Self::from_decrypted(&data);
+        result.base.level = result.base.current_level();
+        result.refresh_stats();
+
+        let hitpoints = result.base.hitpoints.to_le_bytes();
+        result.base.data[0x56..0x58].copy_from_slice(&hitpoints);
+        result
+    }
+
+    /// Writes the checksum of the current data
+    pub(crate) fn update_checksum(&mut self) -> Result<(), PkmError> {
+        self.base.data = self.try_write()?;
+        Ok(())
+    }
+
+    /// Writes the bytes into the decrypted data, then reloads the Pokémon with an updated checksum
+    pub(crate) fn patch(&mut self, offset: usize, bytes: &[u8]) -> Result<(), PkmError> {
+        ensure_size(&self.base.data, (SIZE_GENERATION_3_STORED as usize).max(offset + bytes.len()))?;
+
+        self.base.data[offset..offset + bytes.len()].copy_from_slice(bytes);
+        self.update_checksum()?;
+        *self = Self::from_decrypted(&self.base.data);
+        Ok(())
+    }
+
+    // region: Evolution
+    /// Gets the evolution caused by the trigger, if the Pokémon can evolve with it.
+    /// Shedinja is never returned, it is created by `evolve_in_party` alongside Ninjask.
+    pub fn find_evolution(&self, trigger: EvolutionTrigger) -> Option<&'static Evolution> {
+        let base = &self.base;
+        if self.is_egg || base.held_item == EVERSTONE {
+            return None;
+        }
+
+        let level = base.level;
+        let friendship = base.current_friendship >= FRIENDSHIP_EVOLUTION_THRESHOLD;
+        let stats = self.calculated_stats();
+
+        evolution::evolutions_from(EVOLUTIONS, base.species).find(|evolution| match (trigger, evolution.method) {
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::Level(min) | EvolutionMethod::LevelNinjask(min)) => level >= min,
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::LevelAttackHigher(min)) => level >= min && stats[1] > stats[2],
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::LevelDefenseHigher(min)) => level >= min && stats[1] < stats[2],
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::LevelAttackDefenseEqual(min)) => level >= min && stats[1] == stats[2],
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::LevelSilcoon(min)) => level >= min && wurmple_evolution(base.personality_id) == WurmpleEvolution::Silcoon,
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::LevelCascoon(min)) => level >= min && wurmple_evolution(base.personality_id) == WurmpleEvolution::Cascoon,
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::Friendship) => friendship,
+            (EvolutionTrigger::LevelUp { daytime }, EvolutionMethod::FriendshipDay) => friendship && daytime,
+            (EvolutionTrigger::LevelUp { daytime }, EvolutionMethod::FriendshipNight) => friendship && !daytime,
+            (EvolutionTrigger::LevelUp { .. }, EvolutionMethod::Beauty(min)) => base.data[0x3F] >= min,
+            (EvolutionTrigger::UseItem(item), EvolutionMethod::UseItem(needed)) => item == needed,
+            (EvolutionTrigger::Trade, EvolutionMethod::Trade) => true,
+            (EvolutionTrigger::Trade, EvolutionMethod::TradeHeldItem(needed)) => base.held_item == needed,
+            _ => false,
+        })
+    }
+
+    /// Evolves the Pokémon in place, updating the species, stats and the name unless it is nicknamed.
+    /// Trade evolutions with a held item consume it.
+    pub fn evolve(&mut self, evolution: &Evolution) -> Result<(), PkmError> {
+        ensure_size(&self.base.data, SIZE_GENERATION_3_STORED as usize)?;
+        let is_nicknamed = self.is_nicknamed();
+
+        if let EvolutionMethod::TradeHeldItem(_) = evolution.method {
+            self.base.held_item = 0;
+            self.base.data[0x22..0x24].fill(0);
+        }
+
+        self.set_species(evolution.to)?;
+        self.refresh_stats();
+
+        if !is_nicknamed {
+            self.reset_nickname();
+        }
+
+        self.update_checksum()
+    }
+
+    /// Creates the Shedinja left by a Nincada evolving into Ninjask.
+    /// Generation 3 only needs a free party slot, a Poké Ball is not used from the bag until Generation 4.
+    pub fn create_shedinja(&self) -> Result<Self, PkmError> {
+        let mut data = self.base.data.clone();
+
+        let ribbons = data.get_u32_le_offset(0x4C)? & 0x8000_0000;
+        data[0x1B] = 0;
+        data[0x22..0x24].fill(0);
+        data[0x4C..0x50].copy_from_slice(&ribbons.to_le_bytes());
+
+        if data.len() >= SIZE_GENERATION_3_PARTY as usize {
+            data[0x50..0x54].fill(0);
+            data[0x55] = 0xFF;
+        }
+
+        let checksum = poke_crypto::get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        let mut shedinja = Self::new(&data);
+        shedinja.set_species(SpeciesID::Shedinja)?;
+        shedinja.reset_nickname();
+        shedinja.refresh_stats();
+
+        if let Some(current_hitpoints) = shedinja.base.data.get_mut(0x56..0x58) {
+            current_hitpoints.copy_from_slice(&1u16.to_le_bytes());
+        }
+        shedinja.update_checksum()?;
+
+        Ok(shedinja)
+    }
+
+    /// Evolves a party member if the trigger allows it, returning the species it evolved into.
+    /// Nincada evolving into Ninjask also adds Shedinja when the party has a free slot.
+    ///
+    /// # Arguments
+    /// * `party` - Party Pokémon
+    /// * `index` - Index of the evolving Pokémon in the party
+    /// * `trigger` - Action that can make it evolve
+    pub fn evolve_in_party(party: &mut Vec<Self>, index: usize, trigger: EvolutionTrigger) -> Option<SpeciesID> {
+        let evolution = party.get(index)?.find_evolution(trigger)?;
+        let pokemon = &mut party[index];
+
+        let shedinja = matches!(evolution.method, EvolutionMethod::LevelNinjask(_)).then(|| pokemon.create_shedinja()).transpose().ok()?;
+        pokemon.evolve(evolution).ok()?;
+
+        if let Some(shedinja) = shedinja {
+            if party.len() < MAX_PARTY_SIZE {
+                party.push(shedinja);
+            }
+        }
+
+        Some(evolution.to)
+    }
+    // endregion
+}
+
 impl PokemonTrait for Generation3 {
     fn get_base(&self) -> &PokemonBase {
         &self.base
     }
 
     fn decrypt(&self) -> Vec<u8> {
-        todo!()
+        self.base.data.to_vec()
     }
 
     fn encrypt(&self) -> Vec<u8> {
-        todo!()
+        poke_crypto::encrypt_generation3_array(&self.write())
     }
 
+    /// Gets the decrypted data with an updated checksum, data shorter than a stored Pokémon is returned as is
     fn write(&self) -> Vec<u8> {
-        todo!()
+        self.try_write().unwrap_or_else(|_| self.base.data.clone())
+    }
+
+    fn try_write(&self) -> Result<Vec<u8>, PkmError> {
+        ensure_size(&self.base.data, SIZE_GENERATION_3_STORED as usize)?;
+
+        let mut data = self.base.data.clone();
+        let checksum = poke_crypto::get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        Ok(data)
     }
 
     fn read(data: &[u8]) -> Self {
-        todo!()
+        Self::new(data)
+    }
+
+    fn try_read(data: &[u8]) -> Result<Self, PkmError> {
+        ensure_size(data, SIZE_GENERATION_3_STORED as usize)?;
+
+        Ok(Self::read(data))
     }
 
     fn get_species(&self) -> u16 {
-        todo!()
+        self.base.species.national_id()
     }
 
     fn is_nicknamed(&self) -> bool {
-        todo!()
+        if self.is_egg {
+            return false;
+        }
+
+        !is_default_nickname(&self.base.nickname, self.base.species, self.base.language, 3)
+    }
+
+    fn reset_nickname(&mut self) {
+        let Some(name) = get_default_nickname(self.base.species, self.base.language, 3) else {
+            return;
+        };
+
+        let max_length = if self.is_japanese { 5 } else { 10 };
+        let mut buffer = [0u8; 10];
+        if strings::generation_3::encode(&mut buffer, &name, max_length, self.base.language, StringConverterOption::ClearFF).is_err() {
+            return;
+        }
+
+        self.base.nickname_bytes = buffer.to_vec();
+        if self.base.data.len() >= SIZE_GENERATION_3_STORED as usize {
+            self.base.data[0x08..0x12].copy_from_slice(&buffer);
+            self.base.data = self.write();
+        }
+        self.base.nickname = name;
     }
 
     fn is_egg(&self) -> bool {
-        todo!()
+        self.is_egg
+    }
+
+    fn regenerate_checksum(&mut self) {
+        self.base.data = self.write();
+    }
+
+    fn validate_checksum(&self) -> bool {
+        self.base.data.get_u16_le_offset(0x1C).is_ok_and(|checksum| checksum == poke_crypto::get_generatation3_checksum(&self.base.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::PkmError,
+        game::enums::species_id::SpeciesID,
+        pokemon::{base::PokemonTrait, utils::experience::get_minimum_level_experience, Generation3},
+        species::{conversion::generation3_index_from_species, evolution::EvolutionTrigger, personal::PersonalInfo},
+        utils::constants::poke_crypto::get_generatation3_checksum,
+    };
+
+    /// Level 20 English party Pokémon without a nickname
+    fn party_pokemon(species: SpeciesID, pid: u32) -> Generation3 {
+        let mut data = vec![0u8; 100];
+        data[0x00..0x04].copy_from_slice(&pid.to_le_bytes());
+        data[0x12] = 2;
+        data[0x20..0x22].copy_from_slice(&generation3_index_from_species(species).unwrap().to_le_bytes());
+        let growth_rate = PersonalInfo::generation3(species).unwrap().growth_rate;
+        data[0x24..0x28].copy_from_slice(&get_minimum_level_experience(20, growth_rate.into()).to_le_bytes());
+        data[0x54] = 20;
+        data[0x55] = 0xFF;
+
+        let checksum = get_generatation3_checksum(&data);
+        data[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        let mut pokemon = Generation3::new(&data);
+        pokemon.reset_nickname();
+        pokemon.refresh_stats();
+        pokemon
+    }
+
+    #[test]
+    fn wurmple_evolution_depends_on_pid() {
+        let silcoon = party_pokemon(SpeciesID::Wurmple, 0x0000_0000);
+        let cascoon = party_pokemon(SpeciesID::Wurmple, 0x0005_0000);
+        let trigger = EvolutionTrigger::LevelUp { daytime: true };
+
+        assert_eq!(silcoon.find_evolution(trigger).unwrap().to, SpeciesID::Silcoon);
+        assert_eq!(cascoon.find_evolution(trigger).unwrap().to, SpeciesID::Cascoon);
+    }
+
+    #[test]
+    fn evolving_renames_and_recalculates() {
+        let mut pokemon = party_pokemon(SpeciesID::Treecko, 0);
+        let previous_hitpoints = pokemon.base.hitpoints;
+        let evolution = pokemon.find_evolution(EvolutionTrigger::LevelUp { daytime: true }).unwrap();
+
+        pokemon.evolve(evolution).unwrap();
+        assert_eq!(pokemon.base.species, SpeciesID::Grovyle);
+        assert_eq!(pokemon.base.nickname, "GROVYLE");
+        assert!(pokemon.base.hitpoints > previous_hitpoints);
+        assert_eq!(Generation3::new(&pokemon.base.data).base.species, SpeciesID::Grovyle);
+    }
+
+    #[test]
+    fn nincada_creates_shedinja_with_free_slot() {
+        let mut party = vec![party_pokemon(SpeciesID::Nincada, 0)];
+        let evolved = Generation3::evolve_in_party(&mut party, 0, EvolutionTrigger::LevelUp { daytime: true });
+
+        assert_eq!(evolved, Some(SpeciesID::Ninjask));
+        assert_eq!(party.len(), 2);
+        assert_eq!(party[1].base.species, SpeciesID::Shedinja);
+        assert_eq!(party[1].base.hitpoints, 1);
+        assert_eq!(party[1].base.nickname, "SHEDINJA");
+
+        let mut full_party: Vec<Generation3> = (0..6).map(|_| party_pokemon(SpeciesID::Nincada, 0)).collect();
+        Generation3::evolve_in_party(&mut full_party, 0, EvolutionTrigger::LevelUp { daytime: true });
+        assert_eq!(full_party.len(), 6);
     }
 
-    fn regenerate_checksum(&self) {
-        todo!()
+    #[test]
+    fn reset_nickname_updates_checksum() {
+        let mut pokemon = party_pokemon(SpeciesID::Chikorita, 0);
+        pokemon.base.data[0x12] = 5;
+        pokemon.update_checksum().unwrap();
+
+        let mut pokemon = Generation3::new(&pokemon.base.data);
+        assert!(pokemon.is_nicknamed());
+
+        pokemon.reset_nickname();
+        assert_eq!(pokemon.base.nickname, "ENDIVIE");
+        assert!(!pokemon.is_nicknamed());
+        assert_eq!(get_generatation3_checksum(&pokemon.base.data).to_le_bytes(), pokemon.base.data[0x1C..0x1E]);
     }
 
-    fn validate_checksum(&self) {
-        todo!()
+    #[test]
+    fn try_read_rejects_truncated_data() {
+        let data = party_pokemon(SpeciesID::Zigzagoon, 0).base.data;
+
+        assert_eq!(Generation3::try_read(&data[..40]).unwrap_err(), PkmError::TruncatedData { expected: 80, found: 40 });
+        assert_eq!(Generation3::try_read(&data).unwrap().base.species, SpeciesID::Zigzagoon);
+    }
+
+    #[test]
+    fn short_data_returns_errors() {
+        let truncated = PkmError::TruncatedData { expected: 80, found: 0 };
+        let mut pokemon = Generation3::default();
+
+        assert!(pokemon.write().is_empty());
+        assert_eq!(pokemon.try_write().unwrap_err(), truncated);
+        assert_eq!(pokemon.update_checksum().unwrap_err(), truncated);
+        assert_eq!(pokemon.set_species(SpeciesID::Shedinja).unwrap_err(), truncated);
+        assert_eq!(pokemon.patch(0x22, &[1, 0]).unwrap_err(), truncated);
+        assert!(pokemon.create_shedinja().is_err());
     }
 }
@@ -0,0 +1,414 @@
+//! PID/IV correlations of the Generation 3 games.
+//!
+//! Every method is checked by recovering the seeds that generate the PID and then
+//! generating the IVs forward from them.
+
+use crate::{
+    game::enums::{pokemon_gender::PokemonGender, pokemon_nature::PokemonNature},
+    pokemon::{utils::gender::generate_from_pid_and_ratio, Generation3},
+};
+
+use super::lcrng::{high, LinearCongruentialGenerator, POKEMON_LCRNG, XD_LCRNG};
+
+/// Trainer ID of the Jirachi from the Pokémon Channel bonus
+pub const CHANNEL_JIRACHI_TRAINER_ID: u16 = 40122;
+/// Trainer ID of the WISHMKR Jirachi from the Colosseum bonus disc
+pub const WISHMKR_JIRACHI_TRAINER_ID: u16 = 20043;
+
+/// Amount of rerolled PIDs searched for wild and shadow lock correlations
+const MAX_REROLLS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PIDIVMethod {
+    /// PID then IVs, used by static encounters and gifts
+    Method1,
+    /// Method 1 with a skipped call between the PID and the IVs
+    Method2,
+    /// Method 1 with a skipped call between both IV halves
+    Method4,
+    /// Wild encounter with Method 1 PID/IVs, the PID is rerolled until it matches the nature
+    H1,
+    /// Wild encounter with Method 2 PID/IVs
+    H2,
+    /// Wild encounter with Method 4 PID/IVs
+    H4,
+    ChannelJirachi,
+    /// Method 1 from a 16-bit seed
+    WishmkrJirachi,
+    ColosseumXD,
+}
+
+/// Method matching the PID and IVs of a Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PIDIVMatch {
+    pub method: PIDIVMethod,
+    /// Seed before the first call of the method, before the nature call for wild methods
+    pub seed: u32,
+}
+
+/// Nature and gender required from a team member generated before a shadow Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShadowLock {
+    pub nature: PokemonNature,
+    /// Required gender, `None` when any gender is allowed
+    pub gender: Option<PokemonGender>,
+    pub gender_ratio: u8,
+}
+
+impl ShadowLock {
+    /// Checks if the PID satisfies the lock
+    pub fn matches(&self, pid: u32) -> bool {
+        (pid % 25) as u8 == self.nature as u8
+            && self.gender.is_none_or(|gender| generate_from_pid_and_ratio(pid, self.gender_ratio) == gender)
+    }
+}
+
+/// Packs the IVs into the two 15-bit halves generated by the games.
+///
+/// # Arguments
+/// * `ivs` - IVs in the HP, Attack, Defense, Speed, Special Attack, Special Defense order
+fn pack_ivs(ivs: [u8; 6]) -> (u16, u16) {
+    let pack = |a: u8, b: u8, c: u8| (a as u16 & 0x1F) | ((b as u16 & 0x1F) << 5) | ((c as u16 & 0x1F) << 10);
+    (pack(ivs[0], ivs[1], ivs[2]), pack(ivs[3], ivs[4], ivs[5]))
+}
+
+/// Gets the seeds whose next two calls give the given 16-bit values
+fn seeds_from_pair(generator: &LinearCongruentialGenerator, first: u16, second: u16) -> impl Iterator<Item = u32> + '_ {
+    (0..=0xFFFFu32)
+        .map(move |low| ((first as u32) << 16) | low)
+        .filter(move |&state| high(generator.next(state)) == second)
+        .map(move |state| generator.prev(state))
+}
+
+/// Gets the 15-bit IV halves generated from the seed for Method 1, 2 or 4.
+///
+/// # Arguments
+/// * `seed` - Seed after the PID calls
+fn method_ivs(seed: u32, method: PIDIVMethod) -> (u16, u16) {
+    let seed = match method {
+        PIDIVMethod::Method2 => POKEMON_LCRNG.next(seed),
+        _ => seed,
+    };
+    let iv1 = POKEMON_LCRNG.next(seed);
+    let iv2 = match method {
+        PIDIVMethod::Method4 => POKEMON_LCRNG.advance(iv1, 2),
+        _ => POKEMON_LCRNG.next(iv1),
+    };
+
+    (high(iv1) & 0x7FFF, high(iv2) & 0x7FFF)
+}
+
+/// Walks back from a wild PID seed through the rerolled PIDs to the nature call.
+///
+/// # Arguments
+/// * `seed` - Seed before the accepted PID calls
+/// * `nature` - Nature of the PID
+fn find_nature_call(seed: u32, nature: u32) -> Option<u32> {
+    let mut state = seed;
+
+    for _ in 0..MAX_REROLLS {
+        if high(state) as u32 % 25 == nature {
+            return Some(POKEMON_LCRNG.prev(state));
+        }
+
+        // The state could also be the end of a rerolled PID, which must not have the nature
+        let rerolled = ((high(state) as u32) << 16) | high(POKEMON_LCRNG.prev(state)) as u32;
+        if rerolled % 25 == nature {
+            return None;
+        }
+
+        state = POKEMON_LCRNG.reverse(state, 2);
+    }
+
+    None
+}
+
+/// Gets every PID/IV correlation matching the given data.
+///
+/// # Arguments
+/// * `pid` - Personality ID
+/// * `ivs` - IVs in the HP, Attack, Defense, Speed, Special Attack, Special Defense order
+/// * `trainer_id` - Original Trainer ID, checked by the Jirachi events
+/// * `secret_id` - Original Trainer Secret ID, checked by the Jirachi events
+pub fn find_pidiv_matches(pid: u32, ivs: [u8; 6], trainer_id: u16, secret_id: u16) -> Vec<PIDIVMatch> {
+    let mut matches = Vec::new();
+    let (iv1, iv2) = pack_ivs(ivs);
+
+    // Main series, the lower half of the PID is generated first
+    for seed in seeds_from_pair(&POKEMON_LCRNG, pid as u16, (pid >> 16) as u16) {
+        let after_pid = POKEMON_LCRNG.advance(seed, 2);
+
+        for (method, wild) in [
+            (PIDIVMethod::Method1, PIDIVMethod::H1),
+            (PIDIVMethod::Method2, PIDIVMethod::H2),
+            (PIDIVMethod::Method4, PIDIVMethod::H4),
+        ] {
+            if method_ivs(after_pid, method) != (iv1, iv2) {
+                continue;
+            }
+
+            matches.push(PIDIVMatch { method, seed });
+
+            if method == PIDIVMethod::Method1 && seed <= 0xFFFF && trainer_id == WISHMKR_JIRACHI_TRAINER_ID && secret_id == 0 {
+                matches.push(PIDIVMatch { method: PIDIVMethod::WishmkrJirachi, seed });
+            }
+
+            if let Some(nature_seed) = find_nature_call(seed, pid % 25) {
+                matches.push(PIDIVMatch { method: wild, seed: nature_seed });
+            }
+        }
+    }
+
+    // Colosseum/XD, IVs and ability before the PID, upper half first
+    for seed in seeds_from_pair(&XD_LCRNG, (pid >> 16) as u16, pid as u16) {
+        // The seed before the PID calls comes from the ability call
+        let second = XD_LCRNG.prev(seed);
+        let first = XD_LCRNG.prev(second);
+
+        if (high(first) & 0x7FFF, high(second) & 0x7FFF) == (iv1, iv2) {
+            matches.push(PIDIVMatch { method: PIDIVMethod::ColosseumXD, seed: XD_LCRNG.reverse(seed, 3) });
+        }
+    }
+
+    if trainer_id == CHANNEL_JIRACHI_TRAINER_ID {
+        if let Some(seed) = find_channel_seed(pid, ivs, secret_id) {
+            matches.push(PIDIVMatch { method: PIDIVMethod::ChannelJirachi, seed });
+        }
+    }
+
+    matches
+}
+
+/// Gets the seed of a Channel Jirachi, which gets the Secret ID, the PID, three unrelated
+/// values and then every IV from its own call.
+fn find_channel_seed(pid: u32, ivs: [u8; 6], secret_id: u16) -> Option<u32> {
+    // The upper bit of the PID may be flipped after generating it
+    [pid, pid ^ 0x8000_0000].into_iter().find_map(|generated| {
+        // The seed before the PID calls comes from the Secret ID call
+        seeds_from_pair(&XD_LCRNG, (generated >> 16) as u16, generated as u16).find_map(|seed| {
+            if high(seed) != secret_id {
+                return None;
+            }
+
+            let upper = (generated >> 16) as u16;
+            let lower = generated as u16;
+            let flip = (if lower > 7 { 0 } else { 1 }) != ((upper ^ CHANNEL_JIRACHI_TRAINER_ID ^ secret_id) & 1);
+            if (generated ^ if flip { 0x8000_0000 } else { 0 }) != pid {
+                return None;
+            }
+
+            let mut state = XD_LCRNG.advance(seed, 5);
+            for iv in ivs {
+                state = XD_LCRNG.next(state);
+                if (high(state) >> 11) as u8 != iv {
+                    return None;
+                }
+            }
+
+            Some(XD_LCRNG.prev(seed))
+        })
+    })
+}
+
+/// Gets every PID/IV correlation matching a Generation 3 Pokémon.
+pub fn find_pidiv_matches_for(pokemon: &Generation3) -> Vec<PIDIVMatch> {
+    let base = &pokemon.base;
+    let ivs = [
+        base.iv_hitpoints,
+        base.iv_attack,
+        base.iv_defense,
+        base.iv_speed,
+        base.iv_special_attack,
+        base.iv_special_defense,
+    ]
+    .map(|iv| iv as u8);
+
+    find_pidiv_matches(base.personality_id, ivs, base.ot_info.public_id, base.ot_info.secret_id)
+}
+
+/// Checks if the team members generated before a shadow Pokémon can satisfy their locks.
+///
+/// Each team member takes two calls for a hidden trainer ID, two for the IVs, one for the
+/// ability and then rerolls its PID until the lock is satisfied.
+///
+/// # Arguments
+/// * `seed` - Colosseum/XD seed of the shadow Pokémon
+/// * `locks` - Locks of the team members in the order they are generated, empty when unlocked
+pub fn is_shadow_lock_valid(seed: u32, locks: &[ShadowLock]) -> bool {
+    // Skip the hidden trainer ID calls of the shadow Pokémon
+    is_lock_chain_valid(XD_LCRNG.reverse(seed, 2), locks)
+}
+
+/// Checks the locks backwards from the seed after the last PID call of a team member
+fn is_lock_chain_valid(end: u32, locks: &[ShadowLock]) -> bool {
+    let Some((lock, previous)) = locks.split_last() else {
+        return true;
+    };
+
+    let pid_of = |state: u32| ((high(XD_LCRNG.prev(state)) as u32) << 16) | high(state) as u32;
+    if !lock.matches(pid_of(end)) {
+        return false;
+    }
+
+    if previous.is_empty() {
+        return true;
+    }
+
+    let mut ability = XD_LCRNG.reverse(end, 2);
+    for _ in 0..MAX_REROLLS {
+        // Hidden trainer ID, IVs and ability of this member
+        if is_lock_chain_valid(XD_LCRNG.reverse(ability, 5), previous) {
+            return true;
+        }
+
+        // Otherwise the state ends a rerolled PID, which must not satisfy the lock
+        if lock.matches(pid_of(ability)) {
+            return false;
+        }
+
+        ability = XD_LCRNG.reverse(ability, 2);
+    }
+
+    false
+}
+
+/// Generates the PID and IVs of a main series method, `None` for the other methods.
+///
+/// # Arguments
+/// * `seed` - Seed before the first call, before the nature call for wild methods
+/// * `method` - Method 1, 2 or 4, or their wild variants
+pub fn generate_pidiv(seed: u32, method: PIDIVMethod) -> Option<(u32, [u8; 6])> {
+    let (ivs_method, nature) = match method {
+        PIDIVMethod::Method1 | PIDIVMethod::Method2 | PIDIVMethod::Method4 => (method, None),
+        PIDIVMethod::H1 => (PIDIVMethod::Method1, Some(high(POKEMON_LCRNG.next(seed)) as u32 % 25)),
+        PIDIVMethod::H2 => (PIDIVMethod::Method2, Some(high(POKEMON_LCRNG.next(seed)) as u32 % 25)),
+        PIDIVMethod::H4 => (PIDIVMethod::Method4, Some(high(POKEMON_LCRNG.next(seed)) as u32 % 25)),
+        _ => return None,
+    };
+
+    let pid_of = |state: u32| high(POKEMON_LCRNG.next(state)) as u32 | ((high(POKEMON_LCRNG.advance(state, 2)) as u32) << 16);
+    let mut state = if nature.is_some() { POKEMON_LCRNG.next(seed) } else { seed };
+
+    // Wild PIDs are rerolled until they have the nature picked before
+    if let Some(nature) = nature {
+        let mut rerolls = 0;
+        while pid_of(state) % 25 != nature {
+            rerolls += 1;
+            if rerolls == MAX_REROLLS {
+                return None;
+            }
+
+            state = POKEMON_LCRNG.advance(state, 2);
+        }
+    }
+
+    let pid = pid_of(state);
+    let (iv1, iv2) = method_ivs(POKEMON_LCRNG.advance(state, 2), ivs_method);
+    let ivs = [iv1, iv1 >> 5, iv1 >> 10, iv2, iv2 >> 5, iv2 >> 10].map(|iv| (iv & 0x1F) as u8);
+
+    Some((pid, ivs))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::pokemon_nature::PokemonNature,
+        rng::{
+            generation3::{find_pidiv_matches, generate_pidiv, is_shadow_lock_valid, PIDIVMethod, ShadowLock},
+            lcrng::{high, POKEMON_LCRNG, XD_LCRNG},
+        },
+    };
+
+    /// Generates a Method 1 Pokémon from the seed
+    fn method1(seed: u32) -> (u32, [u8; 6]) {
+        let mut state = seed;
+        let mut next = || {
+            state = POKEMON_LCRNG.next(state);
+            high(state) as u32
+        };
+
+        let pid = next() | (next() << 16);
+        let iv1 = next();
+        let iv2 = next();
+        let ivs = [iv1, iv1 >> 5, iv1 >> 10, iv2, iv2 >> 5, iv2 >> 10].map(|iv| (iv & 0x1F) as u8);
+
+        (pid, ivs)
+    }
+
+    #[test]
+    fn finds_method1_seed() {
+        let (pid, ivs) = method1(0x1234_5678);
+        let matches = find_pidiv_matches(pid, ivs, 0, 0);
+
+        assert!(matches.iter().any(|found| found.method == PIDIVMethod::Method1 && found.seed == 0x1234_5678));
+        assert!(!matches.iter().any(|found| found.method == PIDIVMethod::Method2));
+    }
+
+    #[test]
+    fn finds_wishmkr_seed() {
+        let (pid, ivs) = method1(0x0000_0020);
+        let matches = find_pidiv_matches(pid, ivs, 20043, 0);
+
+        assert!(matches.iter().any(|found| found.method == PIDIVMethod::WishmkrJirachi));
+        assert!(!find_pidiv_matches(pid, ivs, 12345, 0).iter().any(|found| found.method == PIDIVMethod::WishmkrJirachi));
+    }
+
+    #[test]
+    fn finds_wild_seed() {
+        // Nature call followed directly by a PID with the same nature
+        let seed = (0..).map(|seed: u32| seed * 7919).find(|&seed| {
+            let nature = high(POKEMON_LCRNG.next(seed)) as u32 % 25;
+            method1(POKEMON_LCRNG.next(seed)).0 % 25 == nature
+        }).unwrap();
+        let (pid, ivs) = method1(POKEMON_LCRNG.next(seed));
+
+        let matches = find_pidiv_matches(pid, ivs, 0, 0);
+        assert!(matches.iter().any(|found| found.method == PIDIVMethod::H1 && found.seed == seed));
+    }
+
+    #[test]
+    fn generated_pidivs_are_found() {
+        for method in [PIDIVMethod::Method1, PIDIVMethod::Method4, PIDIVMethod::H1, PIDIVMethod::H2] {
+            let (pid, ivs) = generate_pidiv(0xCAFE_F00D, method).unwrap();
+            let matches = find_pidiv_matches(pid, ivs, 0, 0);
+            assert!(matches.iter().any(|found| found.method == method && found.seed == 0xCAFE_F00D), "{:?}", method);
+        }
+
+        assert_eq!(method1(0x1234_5678), generate_pidiv(0x1234_5678, PIDIVMethod::Method1).unwrap());
+    }
+
+    #[test]
+    fn finds_colosseum_xd_seed() {
+        let seed = 0xDEAD_BEEF;
+        let mut state = seed;
+        let mut next = || {
+            state = XD_LCRNG.next(state);
+            high(state) as u32
+        };
+
+        let iv1 = next();
+        let iv2 = next();
+        next();
+        let pid = (next() << 16) | next();
+        let ivs = [iv1, iv1 >> 5, iv1 >> 10, iv2, iv2 >> 5, iv2 >> 10].map(|iv| (iv & 0x1F) as u8);
+
+        let matches = find_pidiv_matches(pid, ivs, 0, 0);
+        assert!(matches.iter().any(|found| found.method == PIDIVMethod::ColosseumXD && found.seed == seed));
+    }
+
+    #[test]
+    fn shadow_lock() {
+        let lock = |nature: PokemonNature| ShadowLock { nature, gender: None, gender_ratio: 0 };
+        let seed = 0x0BAD_CAFE;
+
+        // Nature of the PID right before the hidden trainer ID calls of the shadow
+        let end = XD_LCRNG.reverse(seed, 2);
+        let pid = ((high(XD_LCRNG.prev(end)) as u32) << 16) | high(end) as u32;
+        let nature = PokemonNature::from((pid % 25) as u8);
+        let other = PokemonNature::from(((pid + 1) % 25) as u8);
+
+        assert!(is_shadow_lock_valid(seed, &[]));
+        assert!(is_shadow_lock_valid(seed, &[lock(nature)]));
+        assert!(!is_shadow_lock_valid(seed, &[lock(other)]));
+    }
+}
@@ -0,0 +1,84 @@
+/// Linear congruential generator, `seed * multiplier + increment`, that can also be reversed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearCongruentialGenerator {
+    pub multiplier: u32,
+    pub increment: u32,
+    pub reverse_multiplier: u32,
+    pub reverse_increment: u32,
+}
+
+/// Generator used by the main series Game Boy Advance games
+pub const POKEMON_LCRNG: LinearCongruentialGenerator = LinearCongruentialGenerator {
+    multiplier: 0x41C64E6D,
+    increment: 0x6073,
+    reverse_multiplier: 0xEEB9EB65,
+    reverse_increment: 0x0A3561A1,
+};
+
+/// Generator used by Colosseum/XD and the Channel event
+pub const XD_LCRNG: LinearCongruentialGenerator = LinearCongruentialGenerator {
+    multiplier: 0x343FD,
+    increment: 0x269EC3,
+    reverse_multiplier: 0xB9B33155,
+    reverse_increment: 0xA170F641,
+};
+
+impl LinearCongruentialGenerator {
+    /// Gets the seed after the given one
+    pub const fn next(&self, seed: u32) -> u32 {
+        seed.wrapping_mul(self.multiplier).wrapping_add(self.increment)
+    }
+
+    /// Gets the seed before the given one
+    pub const fn prev(&self, seed: u32) -> u32 {
+        seed.wrapping_mul(self.reverse_multiplier).wrapping_add(self.reverse_increment)
+    }
+
+    /// Advances the seed the given amount of times
+    pub const fn advance(&self, mut seed: u32, count: usize) -> u32 {
+        let mut i = 0;
+        while i < count {
+            seed = self.next(seed);
+            i += 1;
+        }
+
+        seed
+    }
+
+    /// Reverses the seed the given amount of times
+    pub const fn reverse(&self, mut seed: u32, count: usize) -> u32 {
+        let mut i = 0;
+        while i < count {
+            seed = self.prev(seed);
+            i += 1;
+        }
+
+        seed
+    }
+}
+
+/// Gets the 16-bit value the games take from a seed
+pub const fn high(seed: u32) -> u16 {
+    (seed >> 16) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::lcrng::{POKEMON_LCRNG, XD_LCRNG};
+
+    #[test]
+    fn reverse_undoes_next() {
+        for generator in [POKEMON_LCRNG, XD_LCRNG] {
+            for seed in [0, 1, 0x1234_5678, u32::MAX] {
+                assert_eq!(generator.prev(generator.next(seed)), seed);
+                assert_eq!(generator.reverse(generator.advance(seed, 10), 10), seed);
+            }
+        }
+    }
+
+    #[test]
+    fn known_sequence() {
+        assert_eq!(POKEMON_LCRNG.next(0), 0x6073);
+        assert_eq!(POKEMON_LCRNG.next(0x6073), 0xE97E7B6A);
+    }
+}
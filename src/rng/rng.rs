@@ -0,0 +1,2 @@
+pub mod generation3;
+pub mod lcrng;
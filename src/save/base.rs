@@ -1,4 +1,5 @@
 use crate::{
+    error::PkmError,
     game::enums::{game_version::GameVersion, generation::Generation, language_id::LanguageID},
     legality::species_table::SpeciesTable,
     mystery_gifts::MysteryGiftAlbum,
@@ -35,6 +36,15 @@ pub struct SaveFileOffsets {
     pub daycare: Option<usize>,
 }
 
+/// Location of a Pokémon in a save
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveSlot {
+    /// Party slot, from 0
+    Party(u8),
+    /// Box and slot in the box, from 0
+    Box(usize, usize),
+}
+
 pub struct SaveFileConstants {
     pub ot_length: u8,
     pub nickname_length: u8,
@@ -195,8 +205,17 @@ pub trait SaveFileTrait where Self::Pokemon: PokemonTrait{
 
     fn get_checksum_info(&self) -> String;
 
-    fn write_to_data(&mut self);
-    fn load_from_data(&mut self);
+    fn write_to_data(&mut self) -> Result<(), PkmError>;
+    fn load_from_data(&mut self) -> Result<(), PkmError>;
+
+    /// Checks that the offsets and data used by `load_from_data` are present
+    fn check_loadable(&self) -> Result<(), PkmError>;
+
+    /// Same as `load_from_data`, checking every offset before anything is read.
+    fn try_load_from_data(&mut self) -> Result<(), PkmError> {
+        self.check_loadable()?;
+        self.load_from_data()
+    }
 
     fn get_flag(&self, offset: usize, bit_index: u8) -> bool;
     fn set_flag(&mut self, offset: usize, bit_index: u8, value: bool);
@@ -253,7 +272,7 @@ pub trait SaveFileTrait where Self::Pokemon: PokemonTrait{
     fn get_string(&self, data: &[u8]) -> String;
     fn set_string(&self, buffer: &mut [u8], value: &mut [char], max_length: usize, option: StringConverterOption) -> usize;
 
-    fn get_box_name(&mut self, box_index: usize) -> String;
+    fn get_box_name(&self, box_index: usize) -> String;
     fn set_box_name(&mut self, box_index: usize, name: String);
 
     fn set_pokedex_from_pokemon(&mut self, pokemon: Self::Pokemon);
@@ -264,14 +283,14 @@ pub trait SaveFileTrait where Self::Pokemon: PokemonTrait{
     fn get_seen(&self, species: u16) -> bool;
     fn set_seen(&mut self, species: u16, seen: bool);
 
-    fn get_daycare_slot_offset(&self, loc: u32, slot: usize) -> usize;
+    fn get_daycare_slot_offset(&self, loc: u32, slot: usize) -> Result<usize, PkmError>;
 
     fn is_daycare_occupied(&self, loc: u32, slot: usize) -> bool;
-    fn set_daycare_occupied(&mut self, loc: u32, slot: usize, occupied: bool);
+    fn set_daycare_occupied(&mut self, loc: u32, slot: usize, occupied: bool) -> Result<(), PkmError>;
 
-    fn get_daycare_exp(&self, loc: u32, slot: usize) -> u32;
-    fn set_daycare_exp(&mut self, loc: u32, slot: usize, exp: u32);
+    fn get_daycare_exp(&self, loc: u32, slot: usize) -> Result<u32, PkmError>;
+    fn set_daycare_exp(&mut self, loc: u32, slot: usize, exp: u32) -> Result<(), PkmError>;
 
-    fn get_daycare_has_egg(&mut self, loc: u32) -> bool;
-    fn set_daycare_has_egg(&mut self, loc: u32, has_egg: bool);
+    fn get_daycare_has_egg(&mut self, loc: u32) -> Result<bool, PkmError>;
+    fn set_daycare_has_egg(&mut self, loc: u32, has_egg: bool) -> Result<(), PkmError>;
 }
@@ -9,14 +9,15 @@ use crate::{
 use super::base::SaveFileConstants;
 
 pub mod base;
+pub mod inventory;
 pub mod utils;
 
 impl SaveFileConstants {
     pub const fn generation_3_constants(is_japanese: bool) -> SaveFileConstants {
         let mut result = SaveFileConstants::default();
 
-        result.ot_length = 7;
-        result.nickname_length = 10;
+        result.ot_length = if is_japanese { 5 } else { 7 };
+        result.nickname_length = if is_japanese { 5 } else { 10 };
         result.max_move_id = MAX_MOVE_ID;
         result.max_species_id = MAX_SPECIES_ID;
         result.max_ability_id = MAX_ABILITY_ID;
@@ -51,31 +52,71 @@ impl SaveFileConstants {
     }
 }
 
+/// Offsets in the large data that differ between Ruby/Sapphire, Emerald and FireRed/LeafGreen
 #[derive(Debug, Default)]
 pub struct Generation3Offsets {
     mail: usize,
 
     event_flag: usize,
+    event_flag_count: usize,
 
+    /// Flag number, not an offset
     egg_event_flag: usize,
 
     external_event_data: usize,
     external_event_flag: usize,
 
+    daycare: usize,
+    /// The stored Pokémon, followed by the mail and the EXP in Emerald and FireRed/LeafGreen
+    daycare_slot_size: usize,
+
     seen_2: usize,
     seen_3: usize,
 }
 
 impl Generation3Offsets {
+    pub fn ruby_sapphire() -> Self {
+        Self {
+            mail: 0x2B4C,
+            event_flag: 0x1220,
+            event_flag_count: 8 * 288,
+            egg_event_flag: 0x86,
+            external_event_data: 0x311B,
+            external_event_flag: 0x311B + 0x14,
+            daycare: 0x2F9C,
+            daycare_slot_size: SIZE_GENERATION_3_STORED as usize,
+            seen_2: 0x938,
+            seen_3: 0x3A8C,
+        }
+    }
+
     pub fn emerald() -> Self {
         Self {
-            mail: todo!(),
-            event_flag: todo!(),
-            egg_event_flag: todo!(),
-            external_event_data: todo!(),
-            external_event_flag: 0 + 0x14,
-            seen_2: todo!(),
-            seen_3: todo!(), // TODO
+            mail: 0x2BE0,
+            event_flag: 0x1270,
+            event_flag_count: 8 * 300,
+            egg_event_flag: 0x86,
+            external_event_data: 0x31B3,
+            external_event_flag: 0x31B3 + 0x14,
+            daycare: 0x3030,
+            daycare_slot_size: SIZE_GENERATION_3_STORED as usize + 0x3C,
+            seen_2: 0x988,
+            seen_3: 0x3B24,
+        }
+    }
+
+    pub fn firered_leafgreen() -> Self {
+        Self {
+            mail: 0x2CD0,
+            event_flag: 0xEE0,
+            event_flag_count: 8 * 288,
+            egg_event_flag: 0x266,
+            external_event_data: 0x30A7,
+            external_event_flag: 0x30A7 + 0x14,
+            daycare: 0x2F80,
+            daycare_slot_size: SIZE_GENERATION_3_STORED as usize + 0x3C,
+            seen_2: 0x5F8,
+            seen_3: 0x3A18,
         }
     }
 }
@@ -1,24 +1,25 @@
-use std::{borrow::Borrow, f32::consts::E};
-
 use crate::{
-    game::enums::{generation::Generation, species_id::SpeciesID},
+    error::{ensure_size, PkmError},
+    game::enums::{game_version::GameVersion, generation::Generation, language_id::LanguageID, species_id::SpeciesID},
     pokemon::{self, base::PokemonTrait},
     save::{
-        base::{SaveFile, SaveFileConstants, SaveFileTrait},
+        base::{SaveFile, SaveFileConstants, SaveFileTrait, SaveSlot},
+        slots::{SlotLayout, SlotStorage},
         substructures::{
             generation3,
-            mail::{self, MailDetailTrait},
+            mail,
         },
     },
     strings::{self, StringConverterOption},
+    trainer::TrainerInfo,
     utils::{
-        constants::poke_crypto::{self, SIZE_GENERATION_3_STORED},
-        data_manipulation::{self, FlagTrait, SliceUtils},
+        constants::poke_crypto::{self, SIZE_GENERATION_3_PARTY, SIZE_GENERATION_3_STORED},
+        data_manipulation::{FlagTrait, SliceUtils},
         helpers::{checksum_helper, detection_helper},
     },
 };
 
-use super::{utils::SAVE_RAW_SIZE, Generation3Offsets};
+use super::{utils::{SAVE_RAW_HALF_SIZE, SAVE_RAW_SIZE}, Generation3Offsets};
 
 // Similar to future games, the Generation 3 Mainline save files are comprised of separate objects:
 // Object 1 - Small, containing misc configuration data & the Pokédex.
@@ -44,7 +45,31 @@ const SECTOR_COUNT: usize = 14;
 const SIZE_MAIN: usize = SECTOR_COUNT * SIZE_SECTOR;
 
 const BOX_NAME_SIZE: usize = 8 + 1; // 8 characters + 1 terminator
-                                    // endregion
+
+const SIZE_SMALL: usize = SIZE_SECTOR_USED;
+const SIZE_LARGE: usize = 4 * SIZE_SECTOR_USED;
+const SIZE_STORAGE: usize = 9 * SIZE_SECTOR_USED;
+// endregion
+
+// region: Offsets
+// Small
+const TRAINER_NAME: usize = 0x00;
+const TRAINER_GENDER: usize = 0x08;
+const TRAINER_ID: usize = 0x0A;
+const SECRET_ID: usize = 0x0C;
+const PLAYTIME: usize = 0x0E;
+const POKEDEX: usize = 0x18;
+/// 0 in Ruby/Sapphire, 1 in FireRed/LeafGreen and the security key in Emerald
+const GAME_CODE: usize = 0xAC;
+const SECURITY_KEY_FIREREDLEAFGREEN: usize = 0xF20;
+
+// Large, the party is stored right after the count
+const PARTY_COUNT_RUBYSAPPHIREEMERALD: usize = 0x234;
+const PARTY_COUNT_FIREREDLEAFGREEN: usize = 0x34;
+
+/// 6 for the party and 10 stored in the PC
+const MAIL_COUNT: usize = 16;
+// endregion
 
 pub struct Generation3SaveFileBase {
     pub base: SaveFile,
@@ -107,11 +132,11 @@ impl SaveFileTrait for Generation3SaveFileBase {
     type Pokemon = pokemon::Generation3;
 
     fn get_base(&self) -> &SaveFile {
-        todo!()
+        &self.base
     }
 
     fn mutate_base(&mut self) -> &mut SaveFile {
-        todo!()
+        &mut self.base
     }
 
     fn short_summary(&self) -> String {
@@ -139,7 +164,7 @@ impl SaveFileTrait for Generation3SaveFileBase {
             return false;
         }
 
-        return true;
+        true
     }
 
     fn set_checksum(&mut self) {
@@ -202,11 +227,12 @@ impl SaveFileTrait for Generation3SaveFileBase {
         }
     }
 
-    fn write_to_data(&mut self) {
+    fn write_to_data(&mut self) -> Result<(), PkmError> {
+        self.check_loadable()?;
         self.storage_data[0] = self.base.current_box as u8;
 
         // Pokedex
-        let pokedex_offset = self.base.offsets.pokedex.unwrap();
+        let pokedex_offset = self.pokedex_offset()?;
 
         self.small_data
             .write_into(&self.pokedex_pid_unown.to_le_bytes(), pokedex_offset + 0x4);
@@ -247,40 +273,40 @@ impl SaveFileTrait for Generation3SaveFileBase {
         );
         self.large_data
             .write_into(&self.colosseum_raw_1.to_le_bytes(), external_event_data + 7);
+
+        Ok(())
     }
 
-    fn load_from_data(&mut self) {
-        self.base.current_box = self.storage_data[0] as u16;
+    fn check_loadable(&self) -> Result<(), PkmError> {
+        let pokedex_offset = self.pokedex_offset()?;
+        ensure_size(&self.storage_data, 1)?;
+        ensure_size(&self.small_data, pokedex_offset + 0xC)?;
+
+        let external_event_data_offset = self.specific_offsets.external_event_data;
+        let external_event_flag_offset = self.specific_offsets.external_event_flag;
+        ensure_size(&self.large_data, (external_event_data_offset + 15).max(external_event_flag_offset + 3))
+    }
+
+    fn load_from_data(&mut self) -> Result<(), PkmError> {
+        self.base.current_box = *self.storage_data.first().ok_or(PkmError::OutOfBounds)? as u16;
 
         // Pokedex
-        let pokedex_offset = self.base.offsets.pokedex.unwrap();
-
-        self.pokedex_pid_unown = self
-            .small_data
-            .get_u32_le_offset(pokedex_offset + 0x4)
-            .unwrap();
-        self.pokedex_pid_spinda = self
-            .small_data
-            .get_u32_le_offset(pokedex_offset + 0x8)
-            .unwrap();
-
-        self.pokedex_mode = self.small_data[pokedex_offset + 0x01];
-        self.pokedex_national_magicnumber_rubysapphireemerald =
-            self.small_data[pokedex_offset + 0x02];
-        self.pokedex_national_magicnumber_fireredleafgreen = self.small_data[pokedex_offset + 0x03];
+        let pokedex_offset = self.pokedex_offset()?;
+        let pokedex = self.small_data.get_offset_checked(pokedex_offset, 0xC).ok_or(PkmError::OutOfBounds)?;
+
+        self.pokedex_pid_unown = pokedex.get_u32_le_offset(0x4)?;
+        self.pokedex_pid_spinda = pokedex.get_u32_le_offset(0x8)?;
+
+        self.pokedex_mode = pokedex[0x01];
+        self.pokedex_national_magicnumber_rubysapphireemerald = pokedex[0x02];
+        self.pokedex_national_magicnumber_fireredleafgreen = pokedex[0x03];
 
         // External Event Data
         let external_event_data_offset = self.specific_offsets.external_event_data;
         let external_event_flag_offset = self.specific_offsets.external_event_flag;
 
-        self.colosseum_raw_1 = self
-            .large_data
-            .get_u32_le_offset(external_event_data_offset + 7)
-            .unwrap();
-        self.colosseum_raw_2 = self
-            .large_data
-            .get_u32_le_offset(external_event_data_offset + 11)
-            .unwrap();
+        self.colosseum_raw_1 = self.large_data.get_u32_le_offset(external_event_data_offset + 7)?;
+        self.colosseum_raw_2 = self.large_data.get_u32_le_offset(external_event_data_offset + 11)?;
 
         self.colosseum_coupons = self.colosseum_raw_1 >> 8;
         self.colosseum_pokecoupons_title_bronze = (self.colosseum_raw_2 & (1 << 2)) != 0;
@@ -288,10 +314,16 @@ impl SaveFileTrait for Generation3SaveFileBase {
         self.colosseum_pokecoupons_title_gold = (self.colosseum_raw_2 & (1 << 0)) != 0;
         self.colosseum_received_ageto = (self.colosseum_raw_2 & (1 << 3)) != 0;
         self.colosseum_coupons_total = self.colosseum_raw_2 >> 8;
-        self.has_used_rubysapphirebox = self.get_flag(external_event_flag_offset, 0);
-        self.rubysapphirebox_deposit_eggs_unlocked =
-            (self.large_data[external_event_flag_offset] >> 1) & 3;
-        self.has_received_wishmkr_jirachi = self.get_flag(external_event_flag_offset + 2, 0);
+
+        let external_event_flags = self
+            .large_data
+            .get_offset_checked(external_event_flag_offset, 3)
+            .ok_or(PkmError::OutOfBounds)?;
+        self.has_used_rubysapphirebox = external_event_flags.get_flag(0, 0);
+        self.rubysapphirebox_deposit_eggs_unlocked = (external_event_flags[0] >> 1) & 3;
+        self.has_received_wishmkr_jirachi = external_event_flags.get_flag(2, 0);
+
+        Ok(())
     }
 
     fn get_flag(&self, offset: usize, bit_index: u8) -> bool {
@@ -307,11 +339,11 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn get_party_slot_offset(&self, slot: u8) -> usize {
-        todo!()
+        self.base.offsets.party.unwrap_or_default() + (slot as usize * SIZE_GENERATION_3_PARTY as usize)
     }
 
     fn get_party_slot(&self, data: &[u8], offset: usize) -> Self::Pokemon {
-        todo!()
+        Self::Pokemon::read(data.get_offset(offset, SIZE_GENERATION_3_PARTY as usize))
     }
 
     fn is_pokemon_present(&self, data: &[u8]) -> bool {
@@ -327,7 +359,7 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn get_box_offset(&self, box_index: usize) -> usize {
-        self.base.offsets.box_start.unwrap()
+        self.base.offsets.box_start.unwrap_or_default()
             + 4
             + (SIZE_GENERATION_3_STORED as usize
                 * box_index
@@ -340,7 +372,7 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn set_box_wallpaper(&mut self, box_index: usize, value: u8) {
-        if box_index > self.base.constants.box_count as usize {
+        if box_index >= self.base.constants.box_count as usize {
             return;
         }
 
@@ -349,7 +381,7 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn get_string(&self, data: &[u8]) -> String {
-        strings::generation_3::get_string(data, self.is_japanese)
+        strings::generation_3::decode(data, self.string_language())
     }
 
     fn set_string(
@@ -359,11 +391,16 @@ impl SaveFileTrait for Generation3SaveFileBase {
         max_length: usize,
         option: StringConverterOption,
     ) -> usize {
-        strings::generation_3::set_string(buffer, value, max_length, self.is_japanese, option)
+        let value = value.iter().collect::<String>();
+        strings::generation_3::encode(buffer, &value, max_length, self.string_language(), option).unwrap_or(0)
     }
 
-    fn get_box_name(&mut self, box_index: usize) -> String {
-        let offset = self.get_box_offset(box_index);
+    fn get_box_name(&self, box_index: usize) -> String {
+        if box_index >= self.base.constants.box_count as usize {
+            return String::new();
+        }
+
+        let offset = self.get_box_offset(self.base.constants.box_count as usize);
         self.get_string(
             self.storage_data
                 .get_offset(offset + (box_index * BOX_NAME_SIZE), BOX_NAME_SIZE),
@@ -371,16 +408,22 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn set_box_name(&mut self, box_index: usize, name: String) {
-        let offset = self.get_box_offset(box_index);
+        if box_index >= self.base.constants.box_count as usize {
+            return;
+        }
+
+        let offset = self.get_box_offset(self.base.constants.box_count as usize);
+        let language = self.string_language();
         let box_name_data = self
             .storage_data
             .get_mutable_offset(offset + (box_index * BOX_NAME_SIZE), BOX_NAME_SIZE);
 
-        strings::generation_3::set_string(
+        // The name is left unchanged when it has characters the game can't show
+        let _ = strings::generation_3::encode(
             box_name_data,
-            &mut name.chars().collect::<Vec<char>>(),
+            &name,
             BOX_NAME_SIZE - 1,
-            self.is_japanese,
+            language,
             StringConverterOption::ClearZero,
         );
     }
@@ -409,119 +452,157 @@ impl SaveFileTrait for Generation3SaveFileBase {
     }
 
     fn get_caught(&self, species: u16) -> bool {
-        let bit = species - 1;
-        let offset = (bit >> 3) as usize;
-        let caught_offset = self.base.offsets.pokedex.unwrap() + 0x10;
+        let (Some((offset, bit)), Ok(pokedex_offset)) = (self.get_pokedex_bit(species), self.pokedex_offset()) else {
+            return false;
+        };
 
-        self.small_data
-            .get_flag(caught_offset + offset, (bit & 7) as u8)
+        self.small_data.get_flag(pokedex_offset + 0x10 + offset, bit)
     }
 
     fn set_caught(&mut self, species: u16, caught: bool) {
-        let bit = species - 1;
-        let offset = (bit >> 3) as usize;
-
-        let caught_offset = self.base.offsets.pokedex.unwrap() + 0x10;
+        let (Some((offset, bit)), Ok(pokedex_offset)) = (self.get_pokedex_bit(species), self.pokedex_offset()) else {
+            return;
+        };
 
-        self.small_data
-            .set_flag(caught_offset + offset, (bit & 7) as u8, caught);
+        self.small_data.set_flag(pokedex_offset + 0x10 + offset, bit, caught);
     }
 
     fn get_seen(&self, species: u16) -> bool {
-        let bit = species - 1;
-        let offset = (bit >> 3) as usize;
-        let seen_offset = self.base.offsets.pokedex.unwrap() + 0x44;
+        let (Some((offset, bit)), Ok(pokedex_offset)) = (self.get_pokedex_bit(species), self.pokedex_offset()) else {
+            return false;
+        };
 
-        self.small_data
-            .get_flag(seen_offset + offset, (bit & 7) as u8)
+        self.small_data.get_flag(pokedex_offset + 0x44 + offset, bit)
     }
 
     fn set_seen(&mut self, species: u16, seen: bool) {
-        let bit = species - 1;
-        let offset = (bit >> 3) as usize;
-
-        let seen_offset = self.base.offsets.pokedex.unwrap() + 0x44;
+        let (Some((offset, bit)), Ok(pokedex_offset)) = (self.get_pokedex_bit(species), self.pokedex_offset()) else {
+            return;
+        };
 
-        self.small_data
-            .set_flag(seen_offset + offset, (bit & 7) as u8, seen);
-        self.large_data
-            .set_flag(self.specific_offsets.seen_2 + offset, (bit & 7) as u8, seen);
-        self.large_data
-            .set_flag(self.specific_offsets.seen_3 + offset, (bit & 7) as u8, seen);
+        // The seen flags are duplicated twice in the large data
+        self.small_data.set_flag(pokedex_offset + 0x44 + offset, bit, seen);
+        self.large_data.set_flag(self.specific_offsets.seen_2 + offset, bit, seen);
+        self.large_data.set_flag(self.specific_offsets.seen_3 + offset, bit, seen);
     }
 
-    fn get_daycare_has_egg(&mut self, loc: u32) -> bool {
+    fn get_daycare_has_egg(&mut self, _loc: u32) -> Result<bool, PkmError> {
         self.get_event_flag(self.specific_offsets.egg_event_flag as u32)
     }
 
-    fn set_daycare_has_egg(&mut self, loc: u32, has_egg: bool) {
-        self.set_event_flag(self.specific_offsets.egg_event_flag as u32, has_egg);
+    fn set_daycare_has_egg(&mut self, _loc: u32, has_egg: bool) -> Result<(), PkmError> {
+        self.set_event_flag(self.specific_offsets.egg_event_flag as u32, has_egg)
     }
 
-    fn get_daycare_slot_offset(&self, loc: u32, slot: usize) -> usize {
-        self.base.offsets.daycare.unwrap() + (slot * self.daycare_slot_size)
+    fn get_daycare_slot_offset(&self, _loc: u32, slot: usize) -> Result<usize, PkmError> {
+        let daycare = self.base.offsets.daycare.ok_or(PkmError::MissingOffset("daycare"))?;
+        if slot >= 2 {
+            return Err(PkmError::OutOfBounds);
+        }
+
+        Ok(daycare + (slot * self.daycare_slot_size))
     }
 
     fn is_daycare_occupied(&self, loc: u32, slot: usize) -> bool {
-        self.is_pokemon_present(&self.large_data[self.get_daycare_slot_offset(loc, slot)..])
+        self.get_daycare_slot_offset(loc, slot)
+            .ok()
+            .and_then(|offset| self.large_data.get_offset_checked(offset, SIZE_GENERATION_3_STORED as usize))
+            .is_some_and(|data| self.is_pokemon_present(data))
     }
-    fn set_daycare_occupied(&mut self, loc: u32, slot: usize, occupied: bool) {
-        todo!()
+
+    /// The games only check the Pokémon stored in the slot, so marking the slot as empty removes it
+    fn set_daycare_occupied(&mut self, loc: u32, slot: usize, occupied: bool) -> Result<(), PkmError> {
+        let offset = self.get_daycare_slot_offset(loc, slot)?;
+        ensure_size(&self.large_data, offset + SIZE_GENERATION_3_STORED as usize)?;
+
+        if !occupied {
+            self.large_data.write_into(&[0; SIZE_GENERATION_3_STORED as usize], offset);
+        }
+
+        Ok(())
     }
 
-    fn get_daycare_exp(&self, loc: u32, slot: usize) -> u32 {
-        self.large_data
-            .get_u32_le_offset(self.get_daycare_slot_offset(loc, slot))
-            .unwrap()
+    fn get_daycare_exp(&self, loc: u32, slot: usize) -> Result<u32, PkmError> {
+        Ok(self.large_data.get_u32_le_offset(self.get_daycare_exp_offset(loc, slot)?)?)
     }
 
-    fn set_daycare_exp(&mut self, loc: u32, slot: usize, exp: u32) {
-        self.large_data
-            .write_into(&exp.to_le_bytes(), self.get_daycare_slot_offset(loc, slot));
+    fn set_daycare_exp(&mut self, loc: u32, slot: usize, exp: u32) -> Result<(), PkmError> {
+        let offset = self.get_daycare_exp_offset(loc, slot)?;
+        ensure_size(&self.large_data, offset + 4)?;
+
+        self.large_data.write_into(&exp.to_le_bytes(), offset);
+        Ok(())
     }
 }
 
 impl Generation3SaveFileBase {
-    fn is_sector_valid(&self, sectorIndex: usize) -> bool {
-        let start = self.active_slot as usize * SIZE_MAIN;
-        let offset = start + (sectorIndex * SIZE_SECTOR);
+    /// Language that selects the character table of the save strings
+    fn string_language(&self) -> LanguageID {
+        if self.is_japanese { LanguageID::Japanese } else { LanguageID::English }
+    }
+
+    fn pokedex_offset(&self) -> Result<usize, PkmError> {
+        self.base.offsets.pokedex.ok_or(PkmError::MissingOffset("pokedex"))
+    }
+
+    /// Gets the byte and bit of the species in the Pokédex flags, `None` when the species is not in the Pokédex
+    fn get_pokedex_bit(&self, species: u16) -> Option<(usize, u8)> {
+        if species == 0 || species > self.base.constants.max_species_id {
+            return None;
+        }
 
-        let sector = self.base.data.get_offset(offset, SIZE_SECTOR);
-        let checksum = checksum_helper::checksum_32bit(&sector[..SIZE_SECTOR_USED], 0);
+        let bit = species - 1;
+        Some(((bit >> 3) as usize, (bit & 7) as u8))
+    }
+
+    /// The EXP gained in the Daycare is stored at the end of the slot in Emerald and FireRed/LeafGreen.
+    /// Ruby/Sapphire store both Pokémon, then both mails with the OT and nickname, then both EXP values.
+    fn get_daycare_exp_offset(&self, loc: u32, slot: usize) -> Result<usize, PkmError> {
+        let slot_offset = self.get_daycare_slot_offset(loc, slot)?;
+
+        if self.base.game == GameVersion::GroupRubySapphire {
+            return Ok(self.get_daycare_slot_offset(loc, 0)? + (2 * (SIZE_GENERATION_3_STORED as usize + 0x38)) + (slot * 4));
+        }
 
-        checksum == sector.get_u16_le_offset(0xFF6).unwrap()
+        Ok(slot_offset + self.daycare_slot_size - 4)
     }
 
-    fn is_sector_valid_extra(&self, ofs: usize) -> bool {
-        let sector = self.base.data.get_offset(ofs, SIZE_SECTOR);
-        let checksum = checksum_helper::checksum_32bit(&sector[..SIZE_SECTOR_USED], 0);
+    fn is_sector_valid(&self, sector_index: usize) -> bool {
+        let start = self.active_slot as usize * SIZE_MAIN;
+        let offset = start + (sector_index * SIZE_SECTOR);
 
-        checksum == sector.get_u16_le_offset(0xFF4).unwrap()
+        self.base.data.get_offset_checked(offset, SIZE_SECTOR).is_some_and(|sector| {
+            sector.get_u16_le_offset(0xFF6).ok() == Some(checksum_helper::checksum_32bit(&sector[..SIZE_SECTOR_USED], 0))
+        })
+    }
+
+    fn is_sector_valid_extra(&self, ofs: usize) -> bool {
+        self.base.data.get_offset_checked(ofs, SIZE_SECTOR).is_some_and(|sector| {
+            sector.get_u16_le_offset(0xFF4).ok() == Some(checksum_helper::checksum_32bit(&sector[..SIZE_SECTOR_USED], 0))
+        })
     }
 
     fn read_sectors(&mut self) {
         let start = self.active_slot as usize * SIZE_MAIN;
         let end = start + SIZE_MAIN;
 
-        let save_data = &self.base.data;
-        let small_data = &mut self.small_data;
-        let large_data = &mut self.large_data;
+        self.small_data = vec![0; SIZE_SMALL];
+        self.large_data = vec![0; SIZE_LARGE];
+        self.storage_data = vec![0; SIZE_STORAGE];
 
         (start..end).step_by(SIZE_SECTOR).for_each(|offset| {
-            let sector_id = save_data.get_u16_le_offset(offset + 0xFF4).unwrap() as usize;
-
-            if sector_id >= 5 {
-                small_data.write_into(
-                    save_data.get_offset(offset, SIZE_SECTOR_USED),
-                    (sector_id - 5) * SIZE_SECTOR_USED,
-                )
-            } else if sector_id >= 1 {
-                large_data.write_into(
-                    save_data.get_offset(offset, SIZE_SECTOR_USED),
-                    (sector_id - 1) * SIZE_SECTOR_USED,
-                );
-            } else {
-                small_data.write_into(save_data.get_offset(offset, SIZE_SECTOR_USED), 0);
+            // Sectors past the end of truncated data are left empty
+            let Some(sector) = self.base.data.get_offset_checked(offset, SIZE_SECTOR_USED) else {
+                return;
+            };
+            let sector_id = self.base.data.get_u16_le_offset(offset + 0xFF4).unwrap_or(u16::MAX) as usize;
+
+            match sector_id {
+                0 => self.small_data.write_into(sector, 0),
+                1..=4 => self.large_data.write_into(sector, (sector_id - 1) * SIZE_SECTOR_USED),
+                5..=13 => self.storage_data.write_into(sector, (sector_id - 5) * SIZE_SECTOR_USED),
+                // Corrupted sector, nothing to read
+                _ => {}
             }
         });
     }
@@ -530,29 +611,190 @@ impl Generation3SaveFileBase {
         let start = self.active_slot as usize * SIZE_MAIN;
         let end = start + SIZE_MAIN;
 
-        let save_data = &mut self.base.data;
-        let small_data = &self.small_data;
-        let large_data = &self.large_data;
-
         (start..end).step_by(SIZE_SECTOR).for_each(|offset| {
-            let sector_id = save_data.get_u16_le_offset(offset + 0xFF4).unwrap() as usize;
-
-            if sector_id >= 5 {
-                save_data.write_into(
-                    small_data.get_offset(offset, SIZE_SECTOR_USED),
-                    (sector_id - 5) * SIZE_SECTOR_USED,
-                );
-            } else if sector_id >= 1 {
-                save_data.write_into(
-                    large_data.get_offset(offset, SIZE_SECTOR_USED),
-                    (sector_id - 1) * SIZE_SECTOR_USED,
-                );
-            } else {
-                save_data.write_into(small_data.get_offset(offset, SIZE_SECTOR_USED), 0);
-            }
+            let sector_id = self.base.data.get_u16_le_offset(offset + 0xFF4).unwrap_or(u16::MAX) as usize;
+
+            let sector = match sector_id {
+                0 => self.small_data.get_offset(0, SIZE_SECTOR_USED),
+                1..=4 => self.large_data.get_offset((sector_id - 1) * SIZE_SECTOR_USED, SIZE_SECTOR_USED),
+                5..=13 => self.storage_data.get_offset((sector_id - 5) * SIZE_SECTOR_USED, SIZE_SECTOR_USED),
+                _ => return,
+            };
+
+            self.base.data.write_into(sector, offset);
         });
     }
 
+    /// Reads the game, trainer and party information from the sectors
+    fn load_game_info(&mut self) {
+        let language = self.string_language();
+        let small_data = &self.small_data;
+
+        let game_code = small_data.get_u32_le_offset(GAME_CODE).unwrap_or_default();
+        let (game, party_count_offset, security_key) = match game_code {
+            0 => (GameVersion::GroupRubySapphire, PARTY_COUNT_RUBYSAPPHIREEMERALD, None),
+            1 => (
+                GameVersion::GroupFireRedLeafGreen,
+                PARTY_COUNT_FIREREDLEAFGREEN,
+                small_data.get_u32_le_offset(SECURITY_KEY_FIREREDLEAFGREEN).ok(),
+            ),
+            _ => (GameVersion::Emerald, PARTY_COUNT_RUBYSAPPHIREEMERALD, Some(game_code)),
+        };
+        self.security_key = security_key;
+
+        self.specific_offsets = match game {
+            GameVersion::GroupRubySapphire => Generation3Offsets::ruby_sapphire(),
+            GameVersion::GroupFireRedLeafGreen => Generation3Offsets::firered_leafgreen(),
+            _ => Generation3Offsets::emerald(),
+        };
+
+        let base = &mut self.base;
+        base.game = game;
+        base.offsets.daycare = Some(self.specific_offsets.daycare);
+        self.daycare_slot_size = self.specific_offsets.daycare_slot_size;
+        base.offsets.pokedex = Some(POKEDEX);
+        base.offsets.box_start = Some(0);
+        base.offsets.party = Some(party_count_offset + 4);
+        base.party_count = self.large_data[party_count_offset].min(base.constants.max_party_count);
+        base.current_box = self.storage_data[0] as u16;
+
+        base.trainer_info.generation = Generation::G3;
+        base.trainer_info.name = strings::generation_3::decode(small_data.get_offset(TRAINER_NAME, 8), language);
+        base.trainer_info.gender = small_data[TRAINER_GENDER];
+        base.trainer_info.public_id = small_data.get_u16_le_offset(TRAINER_ID).unwrap_or_default();
+        base.trainer_info.secret_id = small_data.get_u16_le_offset(SECRET_ID).unwrap_or_default();
+
+        base.hours_played = small_data.get_u16_le_offset(PLAYTIME).unwrap_or_default() as u32;
+        base.minutes_played = small_data[PLAYTIME + 2] as u32;
+        base.seconds_played = small_data[PLAYTIME + 3] as u32;
+
+        self.base.money = self.read_money();
+    }
+
+    /// Sets the party count, both in the save and in the party data
+    pub fn set_party_count(&mut self, count: u8) {
+        let count = count.min(self.base.constants.max_party_count);
+        self.base.party_count = count;
+
+        if let Some(party) = self.base.offsets.party {
+            self.large_data[party - 4] = count;
+        }
+    }
+
+    /// Writes the trainer name, gender and IDs in the small data
+    pub fn set_trainer_info(&mut self, trainer: &TrainerInfo) -> Result<(), PkmError> {
+        let language = self.string_language();
+        let max_length = if self.is_japanese { 5 } else { 7 };
+
+        let mut name = [0u8; 8];
+        strings::generation_3::encode(&mut name, &trainer.name, max_length, language, StringConverterOption::ClearFF)
+            .map_err(|_| PkmError::InvalidValue("the trainer name can't be encoded"))?;
+        if trainer.name.chars().count() > max_length {
+            return Err(PkmError::InvalidValue("the trainer name is too long"));
+        }
+
+        self.small_data.write_into(&name, TRAINER_NAME);
+        self.small_data[TRAINER_GENDER] = trainer.gender & 1;
+        self.small_data.write_into(&trainer.public_id.to_le_bytes(), TRAINER_ID);
+        self.small_data.write_into(&trainer.secret_id.to_le_bytes(), SECRET_ID);
+
+        let info = &mut self.base.trainer_info;
+        info.name = trainer.name.clone();
+        info.gender = trainer.gender & 1;
+        info.public_id = trainer.public_id;
+        info.secret_id = trainer.secret_id;
+        Ok(())
+    }
+
+    /// Sets the time played, the minutes and seconds are capped to 59
+    pub fn set_playtime(&mut self, hours: u16, minutes: u8, seconds: u8) {
+        let (minutes, seconds) = (minutes.min(59), seconds.min(59));
+
+        self.small_data.write_into(&hours.to_le_bytes(), PLAYTIME);
+        self.small_data[PLAYTIME + 2] = minutes;
+        self.small_data[PLAYTIME + 3] = seconds;
+
+        self.base.hours_played = hours as u32;
+        self.base.minutes_played = minutes as u32;
+        self.base.seconds_played = seconds as u32;
+    }
+
+    /// Sets the box shown when opening the PC, returns `false` when the box does not exist
+    pub fn set_current_box(&mut self, box_index: u16) -> bool {
+        if box_index >= self.base.constants.box_count as u16 {
+            return false;
+        }
+
+        self.storage_data[0] = box_index as u8;
+        self.base.current_box = box_index;
+        true
+    }
+
+    /// Gets the Pokémon in the party, without the empty slots
+    pub fn party(&self) -> Vec<pokemon::Generation3> {
+        (0..self.base.party_count)
+            .map(|slot| self.get_pokemon_at_party_slot(&self.large_data, slot))
+            .collect()
+    }
+
+    /// Gets the Pokémon at the box slot, `None` when the box or slot does not exist
+    ///
+    /// # Arguments
+    /// * `box_index` - Box, from 0
+    /// * `slot` - Slot in the box, from 0
+    pub fn get_box_slot(&self, box_index: usize, slot: usize) -> Option<pokemon::Generation3> {
+        let offset = self.get_box_slot_offset(box_index, slot)?;
+
+        Some(Self::get_pokemon(self.storage_data.get_offset(offset, SIZE_GENERATION_3_STORED as usize)))
+    }
+
+    /// Stores the Pokémon in the box slot, returns `false` when the box or slot does not exist
+    ///
+    /// # Arguments
+    /// * `box_index` - Box, from 0
+    /// * `slot` - Slot in the box, from 0
+    /// * `pokemon` - Pokémon to store, the party data is left out
+    pub fn set_box_slot(&mut self, box_index: usize, slot: usize, pokemon: &pokemon::Generation3) -> bool {
+        let Some(offset) = self.get_box_slot_offset(box_index, slot) else {
+            return false;
+        };
+
+        let data = pokemon.encrypt();
+        self.storage_data.write_into(&data[..SIZE_GENERATION_3_STORED as usize], offset);
+        true
+    }
+
+    /// Stores the Pokémon in the party slot, appending it when the slot is right after the last Pokémon.
+    /// Returns `false` when the slot is past the end of the party.
+    ///
+    /// # Arguments
+    /// * `slot` - Party slot, from 0
+    /// * `pokemon` - Pokémon to store, see `Generation3::to_party`
+    pub fn set_party_slot(&mut self, slot: u8, pokemon: &pokemon::Generation3) -> bool {
+        if slot > self.base.party_count || slot >= self.base.constants.max_party_count {
+            return false;
+        }
+
+        let offset = self.get_party_slot_offset(slot);
+        self.large_data.write_into(&pokemon.to_party().encrypt(), offset);
+
+        if slot == self.base.party_count {
+            self.set_party_count(slot + 1);
+        }
+
+        true
+    }
+
+    /// Gets the offset of the box slot in the storage data
+    pub fn get_box_slot_offset(&self, box_index: usize, slot: usize) -> Option<usize> {
+        let constants = &self.base.constants;
+        if box_index >= constants.box_count as usize || slot >= constants.box_size as usize {
+            return None;
+        }
+
+        Some(self.get_box_offset(box_index) + slot * SIZE_GENERATION_3_STORED as usize)
+    }
+
     pub fn is_all_sectors_present(&self, save_slot: u8) -> (bool, usize) {
         let start = SIZE_MAIN * save_slot as usize;
         let end = start + SIZE_MAIN;
@@ -563,8 +805,11 @@ impl Generation3SaveFileBase {
         let save_data = &self.base.data;
 
         (start..end).step_by(SIZE_SECTOR).for_each(|offset| {
-            let sector_id = save_data.get_u16_le_offset(offset + 0xFF4).unwrap() as usize;
-            bit_track |= 1 << sector_id;
+            let sector_id = save_data.get_u16_le_offset(offset + 0xFF4).unwrap_or(u16::MAX) as usize;
+            // Corrupted sector IDs leave the slot incomplete
+            if sector_id < SECTOR_COUNT {
+                bit_track |= 1 << sector_id;
+            }
 
             if sector_id == 0 {
                 sector_0_offset = offset;
@@ -575,7 +820,8 @@ impl Generation3SaveFileBase {
     }
 
     fn get_active_slot(&self) -> u8 {
-        if self.base.data.len() == SAVE_RAW_SIZE {
+        // Half-size saves only have one slot
+        if self.base.data.len() == SAVE_RAW_HALF_SIZE {
             return 0;
         }
 
@@ -597,16 +843,9 @@ impl Generation3SaveFileBase {
             return 0;
         }
 
-        let save_count_slot_0 = self
-            .base
-            .data
-            .get_u16_le_offset(initial_sector_offset_slot_0 + 0x0FFC)
-            .unwrap();
-        let save_count_slot_1 = self
-            .base
-            .data
-            .get_u16_le_offset(initial_sector_offset_slot_1 + 0x0FFC)
-            .unwrap();
+        // Both slots are complete, so the save counters are within the data
+        let save_count_slot_0 = self.base.data.get_u16_le_offset(initial_sector_offset_slot_0 + 0x0FFC).unwrap_or_default();
+        let save_count_slot_1 = self.base.data.get_u16_le_offset(initial_sector_offset_slot_1 + 0x0FFC).unwrap_or_default();
 
         if save_count_slot_1 > save_count_slot_0 {
             1
@@ -615,6 +854,26 @@ impl Generation3SaveFileBase {
         }
     }
 
+    /// Same as `initilize`, with an error when the data is not a save the games can load.
+    /// The size must be the one the games use, one slot must have all of its sectors and the
+    /// Pokédex and event data must be readable.
+    ///
+    /// # Arguments
+    /// * `data` - Full or half-size save data
+    pub fn try_initilize(data: Vec<u8>) -> Result<Self, PkmError> {
+        if data.len() != SAVE_RAW_SIZE && data.len() != SAVE_RAW_HALF_SIZE {
+            return Err(PkmError::UnsupportedSize(data.len()));
+        }
+
+        let mut result = Self::initilize(data);
+        if !result.is_all_sectors_present(result.active_slot).0 {
+            return Err(PkmError::InvalidValue("no save slot has all of its sectors"));
+        }
+
+        result.try_load_from_data()?;
+        Ok(result)
+    }
+
     pub fn initilize(data: Vec<u8>) -> Self {
         let is_japanese = false;
 
@@ -662,65 +921,147 @@ impl Generation3SaveFileBase {
         result.base.constants = SaveFileConstants::generation_3_constants(is_japanese);
         result.base.generation = Generation::G3;
         result.active_slot = result.get_active_slot();
+        result.read_sectors();
+        result.load_game_info();
 
         result
     }
 
-    pub fn get_mail_offset(&self, index: usize) -> usize {
-        (index * mail::SIZE_GENERATION_3) + self.specific_offsets.mail
+    /// Gets the offset of the mail in the large data, the first 6 are held by the party
+    pub fn get_mail_offset(&self, index: usize) -> Result<usize, PkmError> {
+        if index >= MAIL_COUNT {
+            return Err(PkmError::OutOfBounds);
+        }
+
+        Ok((index * mail::SIZE_GENERATION_3) + self.specific_offsets.mail)
     }
 
-    pub fn get_mail(&self, index: usize) -> mail::Generation3Mail {
-        let offset = self.get_mail_offset(index);
-        let data = self.large_data.get_offset(offset, mail::SIZE_GENERATION_3);
+    pub fn get_mail(&self, index: usize) -> Result<mail::Generation3Mail, PkmError> {
+        let offset = self.get_mail_offset(index)?;
+        let data = self
+            .large_data
+            .get_offset_checked(offset, mail::SIZE_GENERATION_3)
+            .ok_or(PkmError::OutOfBounds)?;
 
-        mail::Generation3Mail::new(data, offset, self.is_japanese)
+        Ok(mail::Generation3Mail::new(data, offset, self.is_japanese))
     }
 
-    pub fn get_hall_of_fame_data(&self) -> Vec<u8> {
+    /// Gets the Hall of Fame, which is only stored in full-size saves
+    pub fn get_hall_of_fame_data(&self) -> Result<Vec<u8>, PkmError> {
+        ensure_size(&self.base.data, SAVE_RAW_SIZE)?;
+
         // HoF Data is split across two sectors
-        let mut data = Vec::with_capacity(SIZE_SECTOR_USED * 2);
+        let mut data = vec![0; SIZE_SECTOR_USED * 2];
         data.write_into(self.base.data.get_offset(0x1C000, SIZE_SECTOR_USED), 0);
         data.write_into(
             self.base.data.get_offset(0x1D000, SIZE_SECTOR_USED),
             SIZE_SECTOR_USED,
         );
 
-        data
+        Ok(data)
     }
 
-    pub fn set_hall_of_fame_data(&mut self, data: &[u8]) {
-        assert_eq!(data.len(), SIZE_SECTOR_USED * 2);
+    pub fn set_hall_of_fame_data(&mut self, data: &[u8]) -> Result<(), PkmError> {
+        ensure_size(&self.base.data, SAVE_RAW_SIZE)?;
+        if data.len() != SIZE_SECTOR_USED * 2 {
+            return Err(PkmError::UnsupportedSize(data.len()));
+        }
 
         self.base
             .data
             .write_into(&data[..SIZE_SECTOR_USED], 0x1C000);
         self.base
             .data
-            .write_into(&data[SIZE_SECTOR_USED..SIZE_SECTOR_USED], 0x1D000);
+            .write_into(&data[SIZE_SECTOR_USED..], 0x1D000);
+
+        Ok(())
     }
 
     pub fn is_corrupt_pokedex_ff(&self) -> bool {
-        u64::from_le_bytes(self.small_data[0xAC..(0xAC + 4)].try_into().unwrap()) == u64::MAX
+        self.small_data
+            .get_offset_checked(GAME_CODE, 8)
+            .is_some_and(|data| data.iter().all(|&byte| byte == 0xFF))
+    }
+
+    /// Gets the offset and bit of the event flag in the large data
+    fn get_event_flag_offset(&self, flag_number: u32) -> Result<(usize, u8), PkmError> {
+        if flag_number as usize >= self.specific_offsets.event_flag_count {
+            return Err(PkmError::OutOfBounds);
+        }
+
+        let offset = self.specific_offsets.event_flag + ((flag_number as usize) >> 3);
+        ensure_size(&self.large_data, offset + 1)?;
+
+        Ok((offset, (flag_number & 7) as u8))
     }
 
-    pub fn get_event_flag(&self, flag_number: u32) -> bool {
-        assert!(flag_number as usize >= self.specific_offsets.event_flag);
+    pub fn get_event_flag(&self, flag_number: u32) -> Result<bool, PkmError> {
+        let (offset, bit) = self.get_event_flag_offset(flag_number)?;
 
-        self.get_flag(
-            self.specific_offsets.event_flag + ((flag_number as usize) >> 3),
-            (flag_number & 7) as u8,
-        )
+        Ok(self.get_flag(offset, bit))
     }
 
-    pub fn set_event_flag(&mut self, flag_number: u32, value: bool) {
-        assert!(flag_number as usize >= self.specific_offsets.event_flag);
+    pub fn set_event_flag(&mut self, flag_number: u32, value: bool) -> Result<(), PkmError> {
+        let (offset, bit) = self.get_event_flag_offset(flag_number)?;
 
-        self.set_flag(
-            self.specific_offsets.event_flag + ((flag_number as usize) >> 3),
-            (flag_number & 7) as u8,
-            value,
-        );
+        self.set_flag(offset, bit, value);
+        Ok(())
+    }
+}
+
+impl SlotLayout for Generation3SaveFileBase {
+    fn constants(&self) -> &SaveFileConstants {
+        &self.base.constants
+    }
+
+    fn party_count(&self) -> u8 {
+        self.base.party_count
+    }
+}
+
+impl SlotStorage for Generation3SaveFileBase {
+    type Pokemon = pokemon::Generation3;
+
+    fn read_slot(&self, slot: SaveSlot) -> Option<Self::Pokemon> {
+        match slot {
+            SaveSlot::Party(slot) if slot < self.base.party_count => Some(self.get_pokemon_at_party_slot(&self.large_data, slot)),
+            SaveSlot::Party(_) => None,
+            SaveSlot::Box(box_index, slot) => self
+                .get_box_slot(box_index, slot)
+                .filter(|pokemon| pokemon.get_species() != 0 || pokemon.is_bad_egg),
+        }
+    }
+
+    fn write_slot_data(&mut self, slot: SaveSlot, pokemon: &Self::Pokemon) {
+        match slot {
+            SaveSlot::Party(slot) if slot < self.base.constants.max_party_count => {
+                let offset = self.get_party_slot_offset(slot);
+                self.large_data.write_into(&pokemon.to_party().encrypt(), offset);
+            }
+            SaveSlot::Party(_) => {}
+            SaveSlot::Box(box_index, slot) => {
+                self.set_box_slot(box_index, slot, pokemon);
+            }
+        }
+    }
+
+    fn clear_slot_data(&mut self, slot: SaveSlot) {
+        match slot {
+            SaveSlot::Party(slot) if slot < self.base.constants.max_party_count => {
+                let offset = self.get_party_slot_offset(slot);
+                self.large_data.write_into(&[0; SIZE_GENERATION_3_PARTY as usize], offset);
+            }
+            SaveSlot::Party(_) => {}
+            SaveSlot::Box(box_index, slot) => {
+                if let Some(offset) = self.get_box_slot_offset(box_index, slot) {
+                    self.storage_data.write_into(&[0; SIZE_GENERATION_3_STORED as usize], offset);
+                }
+            }
+        }
+    }
+
+    fn write_party_count(&mut self, count: u8) {
+        self.set_party_count(count);
     }
 }
 
@@ -735,3 +1076,244 @@ pub trait Generation3SaveFileTrait {
     fn get_eberry_name(&self) -> String;
     fn is_eberry_enigma(&self) -> String;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::PkmError,
+        game::enums::{game_version::GameVersion, species_id::SpeciesID},
+        pokemon::{base::PokemonTrait, Generation3},
+        save::{
+            base::{SaveFileTrait, SaveSlot},
+            generation3::utils::{SAVE_RAW_HALF_SIZE, SAVE_RAW_SIZE},
+            slots::{SlotError, SlotStorage},
+            substructures::mail::MailDetailTrait,
+        },
+        species::conversion::generation3_index_from_species,
+        utils::constants::poke_crypto::{get_generatation3_checksum, SIZE_GENERATION_3_STORED},
+    };
+
+    use super::{
+        Generation3SaveFileBase, GAME_CODE, PARTY_COUNT_RUBYSAPPHIREEMERALD, SECTOR_COUNT, SIZE_SECTOR, SIZE_SECTOR_USED,
+    };
+
+    /// Half-size Emerald save of trainer "ASH", with a Zigzagoon in the party
+    fn emerald_save() -> Vec<u8> {
+        let mut data = vec![0u8; SAVE_RAW_HALF_SIZE];
+
+        for sector_id in 0..SECTOR_COUNT {
+            let offset = sector_id * SIZE_SECTOR;
+            data[offset + 0xFF4..offset + 0xFF6].copy_from_slice(&(sector_id as u16).to_le_bytes());
+        }
+
+        // Small, sector 0
+        data[0x00..0x08].copy_from_slice(&[0xBB, 0xCD, 0xC2, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        data[0x0A..0x0C].copy_from_slice(&12345u16.to_le_bytes());
+        data[GAME_CODE..GAME_CODE + 4].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+
+        // Large, sector 1
+        let party = SIZE_SECTOR + PARTY_COUNT_RUBYSAPPHIREEMERALD;
+        data[party] = 1;
+        let pokemon = &mut data[party + 4..party + 4 + 100];
+        pokemon[0x20..0x22].copy_from_slice(&generation3_index_from_species(SpeciesID::Zigzagoon).unwrap().to_le_bytes());
+        let checksum = get_generatation3_checksum(pokemon);
+        pokemon[0x1C..0x1E].copy_from_slice(&checksum.to_le_bytes());
+
+        data
+    }
+
+    #[test]
+    fn loads_trainer_and_party() {
+        let save = Generation3SaveFileBase::try_initilize(emerald_save()).unwrap();
+
+        assert_eq!(save.base.game, GameVersion::Emerald);
+        assert_eq!(save.base.trainer_info.name, "ASH");
+        assert_eq!(save.base.trainer_info.public_id, 12345);
+        assert_eq!(save.party().len(), 1);
+        assert_eq!(save.party()[0].base.species, SpeciesID::Zigzagoon);
+        assert_eq!(save.get_box_slot(0, 0).unwrap().base.species, SpeciesID::None);
+        assert!(save.get_box_slot(14, 0).is_none());
+    }
+
+    #[test]
+    fn exports_valid_checksums() {
+        let mut save = Generation3SaveFileBase::try_initilize(emerald_save()).unwrap();
+        let exported = save.get_final_data();
+
+        let reloaded = Generation3SaveFileBase::try_initilize(exported).unwrap();
+        assert!(reloaded.validate_checksum());
+        assert_eq!(reloaded.party()[0].base.species, SpeciesID::Zigzagoon);
+
+        assert_eq!(Generation3SaveFileBase::try_initilize(vec![0; 100]).err(), Some(PkmError::UnsupportedSize(100)));
+    }
+
+    /// Half-size save with the sectors stored in reverse order, each filled with its ID + 1
+    fn shuffled_save() -> Vec<u8> {
+        let mut data = vec![0u8; SAVE_RAW_HALF_SIZE];
+
+        for sector_id in 0..SECTOR_COUNT {
+            let offset = (SECTOR_COUNT - 1 - sector_id) * SIZE_SECTOR;
+            data[offset..offset + SIZE_SECTOR_USED].fill(sector_id as u8 + 1);
+            data[offset + 0xFF4..offset + 0xFF6].copy_from_slice(&(sector_id as u16).to_le_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn reads_and_writes_every_sector() {
+        let mut save = Generation3SaveFileBase::initilize(shuffled_save());
+
+        assert!(save.small_data.iter().all(|&byte| byte == 1));
+        assert_eq!(save.large_data[0], 2);
+        assert_eq!(save.large_data[3 * SIZE_SECTOR_USED], 5);
+        assert_eq!(save.storage_data[0], 6);
+        assert_eq!(save.storage_data[8 * SIZE_SECTOR_USED], 14);
+
+        // Storage sector 13 is stored first
+        save.storage_data[8 * SIZE_SECTOR_USED] = 0xAA;
+        save.write_sectors();
+        assert_eq!(save.base.data[0], 0xAA);
+        assert_eq!(save.base.data[1], 14);
+        assert_eq!(save.base.data[(SECTOR_COUNT - 1) * SIZE_SECTOR], 1);
+    }
+
+    #[test]
+    fn stores_box_names_after_the_boxes() {
+        let mut save = Generation3SaveFileBase::initilize(vec![0; SAVE_RAW_HALF_SIZE]);
+        save.base.offsets.box_start = Some(0);
+
+        save.set_box_name(0, "PARTY".to_string());
+        save.set_box_name(13, "LAST".to_string());
+
+        assert_eq!(save.get_box_name(0), "PARTY");
+        assert_eq!(save.get_box_name(13), "LAST");
+        // The names follow the 14 boxes of 30 Pokémon
+        assert_eq!(save.storage_data[0x8344], 0xCA);
+        assert!(save.storage_data[..0x8344].iter().all(|&byte| byte == 0));
+
+        // A name the game can't show leaves the box name unchanged
+        save.set_box_name(0, "箱".to_string());
+        assert_eq!(save.get_box_name(0), "PARTY");
+    }
+
+    #[test]
+    fn copies_both_hall_of_fame_sectors() {
+        let mut save = Generation3SaveFileBase::initilize(vec![0; SAVE_RAW_HALF_SIZE * 2]);
+
+        let mut data = vec![1; SIZE_SECTOR_USED * 2];
+        data[SIZE_SECTOR_USED..].fill(2);
+        save.set_hall_of_fame_data(&data).unwrap();
+
+        assert_eq!(save.base.data[0x1C000], 1);
+        assert_eq!(save.base.data[0x1D000 + SIZE_SECTOR_USED - 1], 2);
+        assert_eq!(save.get_hall_of_fame_data(), Ok(data));
+        assert_eq!(save.set_hall_of_fame_data(&[0; 10]), Err(PkmError::UnsupportedSize(10)));
+    }
+
+    #[test]
+    fn stores_pokemon_in_slots() {
+        let mut save = Generation3SaveFileBase::try_initilize(emerald_save()).unwrap();
+        let zigzagoon = save.party().remove(0);
+
+        assert!(save.set_box_slot(2, 5, &zigzagoon));
+        assert!(save.set_party_slot(1, &save.get_box_slot(2, 5).unwrap()));
+        assert!(!save.set_party_slot(3, &zigzagoon));
+
+        let reloaded = Generation3SaveFileBase::try_initilize(save.get_final_data()).unwrap();
+        assert_eq!(reloaded.get_box_slot(2, 5).unwrap().base.species, SpeciesID::Zigzagoon);
+        assert_eq!(reloaded.base.party_count, 2);
+        assert_eq!(reloaded.party()[1].base.species, SpeciesID::Zigzagoon);
+        assert!(reloaded.party()[1].base.hitpoints > 0);
+    }
+
+    #[test]
+    fn manages_party_and_box_slots() {
+        let mut save = Generation3SaveFileBase::try_initilize(emerald_save()).unwrap();
+        let zigzagoon = save.get_slot(SaveSlot::Party(0)).unwrap();
+        let mut egg = Generation3::new(&zigzagoon.write());
+        egg.patch(0x48, &(1u32 << 30).to_le_bytes()).unwrap();
+
+        assert_eq!(save.delete_slot(SaveSlot::Party(0)).err(), Some(SlotError::LastPartyMember));
+        assert_eq!(save.set_slot(SaveSlot::Party(0), &egg), Err(SlotError::LastPartyMember));
+        assert_eq!(save.move_slot(SaveSlot::Party(0), SaveSlot::Box(0, 3)), Err(SlotError::LastPartyMember));
+        assert_eq!(save.get_slot(SaveSlot::Box(14, 0)).err(), Some(SlotError::InvalidSlot(SaveSlot::Box(14, 0))));
+
+        // Empty party slots are filled from the end of the party
+        assert_eq!(save.set_slot(SaveSlot::Party(4), &egg), Ok(SaveSlot::Party(1)));
+        assert_eq!(save.set_slot(SaveSlot::Box(0, 3), &zigzagoon), Ok(SaveSlot::Box(0, 3)));
+        assert_eq!(save.swap_slots(SaveSlot::Box(0, 3), SaveSlot::Party(1)), Ok(()));
+        assert!(save.get_slot(SaveSlot::Box(0, 3)).unwrap().is_egg());
+
+        // Deleting moves up the rest of the party
+        save.delete_slot(SaveSlot::Party(0)).unwrap();
+        assert_eq!(save.base.party_count, 1);
+        assert_eq!(save.move_slot(SaveSlot::Box(0, 3), SaveSlot::Party(5)), Ok(SaveSlot::Party(1)));
+        assert_eq!(save.get_slot(SaveSlot::Box(0, 3)).err(), Some(SlotError::EmptySlot(SaveSlot::Box(0, 3))));
+
+        let imported: Vec<Generation3> = (0..31).map(|_| Generation3::new(&zigzagoon.write())).collect();
+        assert_eq!(save.import_pokemon(&imported[..2], 0), Ok(vec![SaveSlot::Box(0, 0), SaveSlot::Box(0, 1)]));
+        assert_eq!(save.import_pokemon(&imported, 13), Err(SlotError::StorageFull { imported: 30 }));
+
+        let reloaded = Generation3SaveFileBase::try_initilize(save.get_final_data()).unwrap();
+        assert_eq!(reloaded.base.party_count, 2);
+        assert!(reloaded.party()[1].is_egg());
+        assert_eq!(reloaded.get_slot(SaveSlot::Box(13, 29)).unwrap().base.species, SpeciesID::Zigzagoon);
+    }
+
+    #[test]
+    fn rejects_truncated_and_corrupted_saves() {
+        let mut truncated = emerald_save();
+        truncated.truncate(SAVE_RAW_HALF_SIZE - 1);
+        assert_eq!(Generation3SaveFileBase::try_initilize(truncated).err(), Some(PkmError::UnsupportedSize(SAVE_RAW_HALF_SIZE - 1)));
+
+        // Sector 5 is stored twice, so the storage sector 6 is missing
+        let mut missing_sector = emerald_save();
+        missing_sector[6 * SIZE_SECTOR + 0xFF4] = 5;
+        assert!(matches!(Generation3SaveFileBase::try_initilize(missing_sector), Err(PkmError::InvalidValue(_))));
+
+        let mut corrupted_ids = emerald_save();
+        (0..SECTOR_COUNT).for_each(|sector| corrupted_ids[sector * SIZE_SECTOR + 0xFF4..sector * SIZE_SECTOR + 0xFF6].fill(0xFF));
+        assert!(Generation3SaveFileBase::try_initilize(corrupted_ids).is_err());
+    }
+
+    #[test]
+    fn reads_corrupted_emerald_data_without_panicking() {
+        let mut data = emerald_save();
+        // Invalid party count, a garbage checksum and daycare data, the daycare is in the last large sector
+        let daycare = 4 * SIZE_SECTOR + 0x3030 - 3 * SIZE_SECTOR_USED;
+        data[SIZE_SECTOR + PARTY_COUNT_RUBYSAPPHIREEMERALD] = 0xFF;
+        data[daycare..daycare + SIZE_GENERATION_3_STORED as usize].fill(0xFF);
+        data[daycare + 0x13] = 0x06;
+        data[0xFF6] ^= 0xFF;
+
+        let mut save = Generation3SaveFileBase::try_initilize(data).unwrap();
+        assert_eq!(save.base.game, GameVersion::Emerald);
+        assert_eq!(save.base.party_count, 6);
+        assert!(!save.validate_checksum());
+        assert!(save.get_checksum_info().contains("Sector 0"));
+
+        assert!(save.get_mail(15).unwrap().is_empty());
+        assert_eq!(save.get_mail(16).err(), Some(PkmError::OutOfBounds));
+        assert_eq!(save.get_daycare_exp(0, 1), Ok(0));
+        assert_eq!(save.get_daycare_exp(0, 2), Err(PkmError::OutOfBounds));
+        assert_eq!(save.get_event_flag(8 * 300), Err(PkmError::OutOfBounds));
+        assert_eq!(save.get_hall_of_fame_data().err(), Some(PkmError::TruncatedData { expected: SAVE_RAW_SIZE, found: SAVE_RAW_HALF_SIZE }));
+        assert!(!save.get_caught(0));
+        save.set_seen(u16::MAX, true);
+
+        save.set_daycare_exp(0, 0, 1000).unwrap();
+        assert_eq!(save.get_daycare_exp(0, 0), Ok(1000));
+        assert!(save.is_daycare_occupied(0, 0));
+        save.set_daycare_occupied(0, 0, false).unwrap();
+        assert!(!save.is_daycare_occupied(0, 0));
+        assert!(save.set_daycare_has_egg(0, true).is_ok());
+        assert_eq!(save.get_daycare_has_egg(0), Ok(true));
+
+        // The party is still usable after it is written back
+        assert_eq!(save.write_to_data(), Ok(()));
+        let reloaded = Generation3SaveFileBase::try_initilize(save.get_final_data()).unwrap();
+        assert!(reloaded.validate_checksum());
+        assert_eq!(reloaded.party()[0].base.species, SpeciesID::Zigzagoon);
+    }
+}
@@ -0,0 +1,166 @@
+//! Bag pouches, PC items and money of the Generation 3 saves.
+//!
+//! Emerald and FireRed/LeafGreen encrypt the money and the bag quantities with the security key of
+//! the save. The PC items are never encrypted.
+
+use crate::{error::PkmError, game::enums::game_version::GameVersion, utils::data_manipulation::SliceUtils};
+
+use super::base::Generation3SaveFileBase;
+
+// region: Offsets
+const MONEY_RUBYSAPPHIREEMERALD: usize = 0x490;
+const MONEY_FIREREDLEAFGREEN: usize = 0x290;
+
+const SIZE_ITEM_ENTRY: usize = 4;
+// endregion
+
+/// Item storage of the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Pouch {
+    Items,
+    KeyItems,
+    Balls,
+    TMHM,
+    Berries,
+    /// Items stored in the PC
+    PC,
+}
+
+impl Pouch {
+    pub const ALL: [Pouch; 6] = [Pouch::Items, Pouch::KeyItems, Pouch::Balls, Pouch::TMHM, Pouch::Berries, Pouch::PC];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InventoryItem {
+    pub item: u16,
+    pub count: u16,
+}
+
+/// Gets the offset in the large data and the slot count of the pouch
+const fn pouch_layout(game: GameVersion, pouch: Pouch) -> (usize, usize) {
+    match (game, pouch) {
+        (GameVersion::GroupFireRedLeafGreen, Pouch::PC) => (0x298, 30),
+        (GameVersion::GroupFireRedLeafGreen, Pouch::Items) => (0x310, 42),
+        (GameVersion::GroupFireRedLeafGreen, Pouch::KeyItems) => (0x3B8, 30),
+        (GameVersion::GroupFireRedLeafGreen, Pouch::Balls) => (0x430, 13),
+        (GameVersion::GroupFireRedLeafGreen, Pouch::TMHM) => (0x464, 58),
+        (GameVersion::GroupFireRedLeafGreen, Pouch::Berries) => (0x54C, 43),
+
+        (GameVersion::Emerald, Pouch::PC) => (0x498, 50),
+        (GameVersion::Emerald, Pouch::Items) => (0x560, 30),
+        (GameVersion::Emerald, Pouch::KeyItems) => (0x5D8, 30),
+        (GameVersion::Emerald, Pouch::Balls) => (0x650, 16),
+        (GameVersion::Emerald, Pouch::TMHM) => (0x690, 64),
+        (GameVersion::Emerald, Pouch::Berries) => (0x790, 46),
+
+        // Ruby/Sapphire
+        (_, Pouch::PC) => (0x498, 50),
+        (_, Pouch::Items) => (0x560, 20),
+        (_, Pouch::KeyItems) => (0x5B0, 20),
+        (_, Pouch::Balls) => (0x600, 16),
+        (_, Pouch::TMHM) => (0x640, 64),
+        (_, Pouch::Berries) => (0x740, 46),
+    }
+}
+
+impl Generation3SaveFileBase {
+    fn money_offset(&self) -> usize {
+        if self.base.game == GameVersion::GroupFireRedLeafGreen {
+            MONEY_FIREREDLEAFGREEN
+        } else {
+            MONEY_RUBYSAPPHIREEMERALD
+        }
+    }
+
+    /// Reads the money from the large data
+    pub fn read_money(&self) -> u32 {
+        let money = self.large_data.get_u32_le_offset(self.money_offset()).unwrap_or_default();
+        money ^ self.security_key.unwrap_or_default()
+    }
+
+    /// Sets the money, capped to the maximum the game allows
+    pub fn set_money(&mut self, money: u32) {
+        let money = money.min(self.base.constants.max_money);
+        let offset = self.money_offset();
+
+        self.base.money = money;
+        self.large_data.write_into(&(money ^ self.security_key.unwrap_or_default()).to_le_bytes(), offset);
+    }
+
+    /// Gets how many different items the pouch can hold
+    pub fn pouch_capacity(&self, pouch: Pouch) -> usize {
+        pouch_layout(self.base.game, pouch).1
+    }
+
+    /// Key of the quantities in the pouch, the lower half of the security key
+    fn pouch_key(&self, pouch: Pouch) -> u16 {
+        if pouch == Pouch::PC {
+            return 0;
+        }
+
+        self.security_key.unwrap_or_default() as u16
+    }
+
+    /// Gets the items in the pouch, without the empty slots
+    pub fn pouch(&self, pouch: Pouch) -> Vec<InventoryItem> {
+        let (offset, capacity) = pouch_layout(self.base.game, pouch);
+        let key = self.pouch_key(pouch);
+
+        (0..capacity)
+            .map(|slot| offset + slot * SIZE_ITEM_ENTRY)
+            .map(|entry| InventoryItem {
+                item: self.large_data.get_u16_le_offset(entry).unwrap_or_default(),
+                count: self.large_data.get_u16_le_offset(entry + 2).unwrap_or_default() ^ key,
+            })
+            .filter(|entry| entry.item != 0)
+            .collect()
+    }
+
+    /// Replaces the items in the pouch, the slots after them are emptied.
+    ///
+    /// # Arguments
+    /// * `pouch` - Pouch to replace
+    /// * `items` - Items to store, up to the capacity of the pouch
+    pub fn set_pouch(&mut self, pouch: Pouch, items: &[InventoryItem]) -> Result<(), PkmError> {
+        let (offset, capacity) = pouch_layout(self.base.game, pouch);
+        if items.len() > capacity {
+            return Err(PkmError::InvalidValue("more items than the pouch can hold"));
+        }
+
+        let key = self.pouch_key(pouch);
+        for slot in 0..capacity {
+            let entry = items.get(slot).copied().unwrap_or_default();
+            // Empty slots keep an encrypted quantity of 0
+            let count = entry.count ^ key;
+
+            self.large_data.write_into(&entry.item.to_le_bytes(), offset + slot * SIZE_ITEM_ENTRY);
+            self.large_data.write_into(&count.to_le_bytes(), offset + slot * SIZE_ITEM_ENTRY + 2);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::save::generation3::base::Generation3SaveFileBase;
+
+    use super::{InventoryItem, Pouch};
+
+    #[test]
+    fn pouches_use_the_security_key() {
+        let mut save = Generation3SaveFileBase::initilize(vec![0; 0x10000]);
+        save.security_key = Some(0x1234_5678);
+
+        let items = [InventoryItem { item: 13, count: 5 }, InventoryItem { item: 4, count: 1 }];
+        save.set_pouch(Pouch::Items, &items).unwrap();
+        save.set_pouch(Pouch::PC, &items[..1]).unwrap();
+        save.set_money(3000);
+
+        assert_eq!(save.pouch(Pouch::Items), items);
+        assert_eq!(save.pouch(Pouch::PC), items[..1]);
+        assert_eq!(save.read_money(), 3000);
+        assert_eq!(&save.large_data[0x562..0x564], &(5 ^ 0x5678u16).to_le_bytes());
+        assert!(save.set_pouch(Pouch::Balls, &[InventoryItem::default(); 17]).is_err());
+    }
+}
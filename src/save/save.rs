@@ -1,5 +1,6 @@
 pub mod base;
 pub mod shared;
+pub mod slots;
 pub mod substructures;
 
 pub mod generation1;
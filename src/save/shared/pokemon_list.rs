@@ -1,6 +1,6 @@
 pub mod generation1;
 
-use crate::{save::generation1::utils::get_party_entry_size, strings, utils::data_manipulation::SliceUtils, pokemon::{base::PokemonTrait, self}};
+use crate::{game::enums::language_id::LanguageID, save::generation1::utils::get_party_entry_size, strings, utils::data_manipulation::SliceUtils, pokemon::{base::PokemonTrait, self}};
 
 const EMPTY_SLOT: u8 = u8::MAX;
 
@@ -23,6 +23,8 @@ pub struct PokemonListBase<T> where T: PokemonTrait {
     pub capacity: u8,
     pub entry_size: usize,
     pub is_japanese: bool,
+    /// Language of the save, International Generation 1/2 saves do not store it
+    pub language: LanguageID,
 
     pub pokemon: Vec<T>,
 }
@@ -105,9 +107,10 @@ pub trait PokemonList where Self::Pokemon: PokemonTrait {
         &self,
         data: Option<Vec<u8>>,
         capacity: u8,
-        is_japanese: bool,
+        language: LanguageID,
         max_party_size: usize,
     ) -> PokemonListBase<Self::Pokemon> {
+        let is_japanese = language == LanguageID::Japanese;
         let mut result = PokemonListBase {
             data: if let Some(unwrapped_data) = data {
                 unwrapped_data
@@ -118,6 +121,7 @@ pub trait PokemonList where Self::Pokemon: PokemonTrait {
             capacity,
             entry_size: get_party_entry_size(capacity as usize, max_party_size),
             is_japanese,
+            language,
             pokemon: Vec::new(),
         };
 
@@ -1,15 +1,15 @@
-use crate::pokemon;
+use crate::{game::enums::language_id::LanguageID, pokemon};
 
 use super::{PokemonList, PokemonListBase, Generation1PokemonList};
 
 impl Generation1PokemonList {
     pub fn new(data: Option<Vec<u8>>,
         capacity: u8,
-        is_japanese: bool,
+        language: LanguageID,
         max_party_size: usize,) -> Self {
         let mut result = Self { base: Default::default() };
 
-        result.base = result.create(data, capacity, is_japanese, max_party_size);
+        result.base = result.create(data, capacity, language, max_party_size);
 
         result
     }
@@ -32,6 +32,8 @@ impl PokemonList for Generation1PokemonList {
 
         result.base.ot_bytes = raw_ot_name.to_vec();
         result.base.nickname_bytes = raw_nickname.to_vec();
+        result.load_strings();
+        result.set_language(self.base.language);
 
         result
     }
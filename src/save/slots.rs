@@ -0,0 +1,419 @@
+//! Moving Pokémon between the party and the boxes.
+//!
+//! Saves only implement the reads and writes of single slots, the operations keep the party
+//! without gaps, update its count, and compact the boxes stored as lists (Generation 1 and 2) along
+//! with the count of each box.
+
+use std::fmt::Display;
+
+use crate::pokemon::base::PokemonTrait;
+
+use super::base::{SaveFileConstants, SaveSlot};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotError {
+    /// The box or slot does not exist in the save
+    InvalidSlot(SaveSlot),
+    EmptySlot(SaveSlot),
+    Occupied(SaveSlot),
+    /// The party would be left without a Pokémon able to battle
+    LastPartyMember,
+    /// Every box slot is taken, `imported` Pokémon were stored before
+    StorageFull { imported: usize },
+}
+
+impl Display for SlotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlotError::InvalidSlot(slot) => write!(f, "{:?} does not exist", slot),
+            SlotError::EmptySlot(slot) => write!(f, "{:?} is empty", slot),
+            SlotError::Occupied(slot) => write!(f, "{:?} is not empty", slot),
+            SlotError::LastPartyMember => write!(f, "The party must keep a Pokémon that is not an egg"),
+            SlotError::StorageFull { imported } => write!(f, "The boxes are full after storing {} Pokémon", imported),
+        }
+    }
+}
+
+impl std::error::Error for SlotError {}
+
+/// Sizes of the party and boxes, all the slot operations need to know about the save
+pub trait SlotLayout {
+    /// Constants of the save, only the box count, box size and maximum party count are used
+    fn constants(&self) -> &SaveFileConstants;
+    fn party_count(&self) -> u8;
+}
+
+pub trait SlotStorage: SlotLayout {
+    type Pokemon: PokemonTrait;
+
+    /// Gets the Pokémon in the slot, `None` when the slot is empty or does not exist
+    fn read_slot(&self, slot: SaveSlot) -> Option<Self::Pokemon>;
+    /// Writes the Pokémon in the slot, without updating the party count
+    fn write_slot_data(&mut self, slot: SaveSlot, pokemon: &Self::Pokemon);
+    /// Empties the slot, without updating the party count
+    fn clear_slot_data(&mut self, slot: SaveSlot);
+    /// Writes the party count in the save data
+    fn write_party_count(&mut self, count: u8);
+
+    /// Whether the boxes are lists without gaps, like the party
+    fn compacts_boxes(&self) -> bool {
+        false
+    }
+
+    /// Writes the count of Pokémon of a box stored as a list, called when the list grows or shrinks
+    fn write_box_count(&mut self, _box_index: usize, _count: usize) {}
+
+    fn slot_exists(&self, slot: SaveSlot) -> bool {
+        let constants = self.constants();
+
+        match slot {
+            SaveSlot::Party(slot) => slot < constants.max_party_count,
+            SaveSlot::Box(box_index, slot) => box_index < constants.box_count as usize && slot < constants.box_size as usize,
+        }
+    }
+
+    /// Gets the Pokémon in the slot
+    fn get_slot(&self, slot: SaveSlot) -> Result<Self::Pokemon, SlotError> {
+        if !self.slot_exists(slot) {
+            return Err(SlotError::InvalidSlot(slot));
+        }
+
+        self.read_slot(slot).ok_or(SlotError::EmptySlot(slot))
+    }
+
+    /// Stores the Pokémon in the slot, replacing the Pokémon in it.
+    /// Returns where it was stored, as empty party slots are filled from the end of the party.
+    fn set_slot(&mut self, slot: SaveSlot, pokemon: &Self::Pokemon) -> Result<SaveSlot, SlotError> {
+        if !self.slot_exists(slot) {
+            return Err(SlotError::InvalidSlot(slot));
+        }
+
+        if self.read_slot(slot).is_none() {
+            return Ok(place(self, slot, pokemon));
+        }
+
+        if let SaveSlot::Party(index) = slot {
+            if !keeps_party_member(self, index, Some(pokemon)) {
+                return Err(SlotError::LastPartyMember);
+            }
+        }
+
+        self.write_slot_data(slot, pokemon);
+        Ok(slot)
+    }
+
+    /// Removes the Pokémon from the slot and returns it. The Pokémon after it in the party, or
+    /// in boxes stored as lists, are moved up.
+    fn delete_slot(&mut self, slot: SaveSlot) -> Result<Self::Pokemon, SlotError> {
+        let pokemon = self.get_slot(slot)?;
+
+        if let SaveSlot::Party(index) = slot {
+            if !keeps_party_member(self, index, None) {
+                return Err(SlotError::LastPartyMember);
+            }
+        }
+
+        remove(self, slot);
+        Ok(pokemon)
+    }
+
+    /// Moves the Pokémon to the empty slot, returns where it was stored (see `set_slot`)
+    ///
+    /// # Arguments
+    /// * `from` - Slot of the Pokémon
+    /// * `to` - Empty slot
+    fn move_slot(&mut self, from: SaveSlot, to: SaveSlot) -> Result<SaveSlot, SlotError> {
+        let pokemon = self.get_slot(from)?;
+
+        if !self.slot_exists(to) {
+            return Err(SlotError::InvalidSlot(to));
+        }
+
+        if self.read_slot(to).is_some() {
+            return Err(SlotError::Occupied(to));
+        }
+
+        if let (SaveSlot::Party(index), SaveSlot::Box(..)) = (from, to) {
+            if !keeps_party_member(self, index, None) {
+                return Err(SlotError::LastPartyMember);
+            }
+        }
+
+        remove(self, from);
+        Ok(place(self, to, &pokemon))
+    }
+
+    /// Exchanges the Pokémon of the two slots, which must not be empty
+    fn swap_slots(&mut self, first: SaveSlot, second: SaveSlot) -> Result<(), SlotError> {
+        let first_pokemon = self.get_slot(first)?;
+        let second_pokemon = self.get_slot(second)?;
+
+        let keeps_member = match (first, second) {
+            (SaveSlot::Party(index), SaveSlot::Box(..)) => keeps_party_member(self, index, Some(&second_pokemon)),
+            (SaveSlot::Box(..), SaveSlot::Party(index)) => keeps_party_member(self, index, Some(&first_pokemon)),
+            _ => true,
+        };
+
+        if !keeps_member {
+            return Err(SlotError::LastPartyMember);
+        }
+
+        self.write_slot_data(first, &second_pokemon);
+        self.write_slot_data(second, &first_pokemon);
+        Ok(())
+    }
+
+    /// Stores the Pokémon in the first empty box slots, returns where each one was stored
+    ///
+    /// # Arguments
+    /// * `pokemon` - Pokémon to store, in order
+    /// * `first_box` - Box where the search for empty slots starts
+    fn import_pokemon(&mut self, pokemon: &[Self::Pokemon], first_box: usize) -> Result<Vec<SaveSlot>, SlotError> {
+        let constants = self.constants();
+        let (box_count, box_size) = (constants.box_count as usize, constants.box_size as usize);
+
+        let mut empty_slots = (first_box..box_count).flat_map(|box_index| (0..box_size).map(move |slot| SaveSlot::Box(box_index, slot)));
+        let mut stored = Vec::with_capacity(pokemon.len());
+
+        for pokemon in pokemon {
+            let slot = empty_slots
+                .find(|slot| self.read_slot(*slot).is_none())
+                .ok_or(SlotError::StorageFull { imported: stored.len() })?;
+
+            stored.push(place(self, slot, pokemon));
+        }
+
+        Ok(stored)
+    }
+}
+
+fn is_party_member<P: PokemonTrait>(pokemon: &P) -> bool {
+    !pokemon.is_egg() && pokemon.get_species() != 0
+}
+
+/// Checks that the party keeps a Pokémon able to battle once the one in the slot is replaced
+///
+/// # Arguments
+/// * `index` - Party slot that is replaced or removed
+/// * `incoming` - Pokémon replacing it, `None` when it is removed
+fn keeps_party_member<S: SlotStorage + ?Sized>(save: &S, index: u8, incoming: Option<&S::Pokemon>) -> bool {
+    let replaced_member = save.read_slot(SaveSlot::Party(index)).is_some_and(|pokemon| is_party_member(&pokemon));
+
+    !replaced_member
+        || incoming.is_some_and(is_party_member)
+        || (0..save.party_count())
+            .filter(|slot| *slot != index)
+            .any(|slot| save.read_slot(SaveSlot::Party(slot)).is_some_and(|pokemon| is_party_member(&pokemon)))
+}
+
+/// Stores the Pokémon in the empty slot, after the last Pokémon of the party or box list
+fn place<S: SlotStorage + ?Sized>(save: &mut S, slot: SaveSlot, pokemon: &S::Pokemon) -> SaveSlot {
+    let slot = match slot {
+        SaveSlot::Party(_) => SaveSlot::Party(save.party_count()),
+        SaveSlot::Box(box_index, slot) if save.compacts_boxes() => {
+            let first_empty = (0..slot).find(|index| save.read_slot(SaveSlot::Box(box_index, *index)).is_none());
+            SaveSlot::Box(box_index, first_empty.unwrap_or(slot))
+        }
+        slot => slot,
+    };
+
+    save.write_slot_data(slot, pokemon);
+    match slot {
+        SaveSlot::Party(index) => save.write_party_count(index + 1),
+        SaveSlot::Box(box_index, index) if save.compacts_boxes() => save.write_box_count(box_index, index + 1),
+        SaveSlot::Box(..) => {}
+    }
+
+    slot
+}
+
+/// Empties the slot, moving up the Pokémon after it in lists
+fn remove<S: SlotStorage + ?Sized>(save: &mut S, slot: SaveSlot) {
+    match slot {
+        SaveSlot::Party(index) => {
+            let count = save.party_count();
+            for next in index + 1..count {
+                if let Some(pokemon) = save.read_slot(SaveSlot::Party(next)) {
+                    save.write_slot_data(SaveSlot::Party(next - 1), &pokemon);
+                }
+            }
+
+            save.clear_slot_data(SaveSlot::Party(count - 1));
+            save.write_party_count(count - 1);
+        }
+        SaveSlot::Box(box_index, index) if save.compacts_boxes() => {
+            let mut last = index;
+            for next in index + 1..save.constants().box_size as usize {
+                let Some(pokemon) = save.read_slot(SaveSlot::Box(box_index, next)) else {
+                    break;
+                };
+
+                save.write_slot_data(SaveSlot::Box(box_index, next - 1), &pokemon);
+                last = next;
+            }
+
+            save.clear_slot_data(SaveSlot::Box(box_index, last));
+            save.write_box_count(box_index, last);
+        }
+        slot => save.clear_slot_data(slot),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::species_id::SpeciesID,
+        pokemon::{base::PokemonTrait, Generation1},
+        save::base::{SaveFile, SaveFileConstants, SaveSlot},
+        species::conversion::generation1_index_from_species,
+        utils::constants::poke_crypto::SIZE_GENERATION_1_STORED,
+    };
+
+    use super::{SlotLayout, SlotStorage};
+
+    const LIST_TERMINATOR: u8 = 0xFF;
+
+    /// Boxes stored as Generation 1 and 2 lists: the count, the species list ended by a terminator,
+    /// then the Pokémon data
+    struct ListBoxes {
+        base: SaveFile,
+    }
+
+    impl ListBoxes {
+        fn new(box_count: u32, box_size: u16) -> Self {
+            let constants = SaveFileConstants { box_count, box_size, max_party_count: 6, ..SaveFileConstants::default() };
+            let mut result = Self { base: SaveFile { constants, ..Default::default() } };
+            result.base.data = vec![0; box_count as usize * result.box_length()];
+            for box_index in 0..box_count as usize {
+                result.write_box_count(box_index, 0);
+            }
+
+            result
+        }
+
+        fn box_length(&self) -> usize {
+            let box_size = self.base.constants.box_size as usize;
+            2 + box_size + box_size * SIZE_GENERATION_1_STORED
+        }
+
+        fn get_box_offset(&self, box_index: usize) -> usize {
+            box_index * self.box_length()
+        }
+
+        fn box_count(&self, box_index: usize) -> usize {
+            self.base.data[self.get_box_offset(box_index)] as usize
+        }
+
+        fn data_offset(&self, box_index: usize, slot: usize) -> usize {
+            self.get_box_offset(box_index) + 2 + self.base.constants.box_size as usize + slot * SIZE_GENERATION_1_STORED
+        }
+    }
+
+    impl SlotStorage for ListBoxes {
+        type Pokemon = Generation1;
+
+        fn read_slot(&self, slot: SaveSlot) -> Option<Generation1> {
+            match slot {
+                SaveSlot::Box(box_index, slot) if slot < self.box_count(box_index) => {
+                    let offset = self.data_offset(box_index, slot);
+                    Some(Generation1::new(&self.base.data[offset..offset + SIZE_GENERATION_1_STORED], false))
+                }
+                _ => None,
+            }
+        }
+
+        fn write_slot_data(&mut self, slot: SaveSlot, pokemon: &Generation1) {
+            if let SaveSlot::Box(box_index, slot) = slot {
+                let species_offset = self.get_box_offset(box_index) + 1 + slot;
+                let offset = self.data_offset(box_index, slot);
+                self.base.data[species_offset] = pokemon.base.data[0x00];
+                self.base.data[offset..offset + SIZE_GENERATION_1_STORED].copy_from_slice(&pokemon.base.data[..SIZE_GENERATION_1_STORED]);
+            }
+        }
+
+        fn clear_slot_data(&mut self, slot: SaveSlot) {
+            if let SaveSlot::Box(box_index, slot) = slot {
+                let offset = self.data_offset(box_index, slot);
+                self.base.data[offset..offset + SIZE_GENERATION_1_STORED].fill(0);
+            }
+        }
+
+        fn write_party_count(&mut self, count: u8) {
+            self.base.party_count = count;
+        }
+
+        fn compacts_boxes(&self) -> bool {
+            true
+        }
+
+        fn write_box_count(&mut self, box_index: usize, count: usize) {
+            let offset = self.get_box_offset(box_index);
+            self.base.data[offset] = count as u8;
+            self.base.data[offset + 1 + count] = LIST_TERMINATOR;
+        }
+    }
+
+    impl SlotLayout for ListBoxes {
+        fn constants(&self) -> &SaveFileConstants {
+            &self.base.constants
+        }
+
+        fn party_count(&self) -> u8 {
+            self.base.party_count
+        }
+    }
+
+    fn pokemon(species: SpeciesID) -> Generation1 {
+        let mut data = [0u8; SIZE_GENERATION_1_STORED];
+        data[0x00] = generation1_index_from_species(species).unwrap();
+        data[0x03] = 5;
+        Generation1::new(&data, false)
+    }
+
+    /// Species of the box read through its count and species list
+    fn box_species(save: &ListBoxes, box_index: usize) -> Vec<u16> {
+        let offset = save.get_box_offset(box_index);
+        let count = save.box_count(box_index);
+        assert_eq!(save.base.data[offset + 1 + count], LIST_TERMINATOR);
+
+        (0..count).map(|slot| save.get_slot(SaveSlot::Box(box_index, slot)).unwrap().get_species()).collect()
+    }
+
+    fn compacts_boxes_and_updates_counts(mut save: ListBoxes) {
+        let box_size = save.base.constants.box_size as usize;
+
+        // Stored after the last Pokémon of the list
+        assert_eq!(save.set_slot(SaveSlot::Box(1, 5), &pokemon(SpeciesID::Pikachu)), Ok(SaveSlot::Box(1, 0)));
+        let imported = save.import_pokemon(&[pokemon(SpeciesID::Eevee), pokemon(SpeciesID::Snorlax)], 1).unwrap();
+        assert_eq!(imported, [SaveSlot::Box(1, 1), SaveSlot::Box(1, 2)]);
+        assert_eq!(box_species(&save, 1), [25, 133, 143]);
+
+        // The Pokémon after the removed one are moved up
+        assert_eq!(save.delete_slot(SaveSlot::Box(1, 0)).unwrap().get_species(), 25);
+        assert_eq!(box_species(&save, 1), [133, 143]);
+
+        assert_eq!(save.move_slot(SaveSlot::Box(1, 0), SaveSlot::Box(0, box_size - 1)), Ok(SaveSlot::Box(0, 0)));
+        assert_eq!(box_species(&save, 0), [133]);
+        assert_eq!(box_species(&save, 1), [143]);
+
+        save.delete_slot(SaveSlot::Box(1, 0)).unwrap();
+        assert!(box_species(&save, 1).is_empty());
+        assert!(save.get_slot(SaveSlot::Box(1, 0)).is_err());
+    }
+
+    #[test]
+    fn compacts_generation1_boxes() {
+        let constants = SaveFileConstants::generation_1_constants(false);
+        compacts_boxes_and_updates_counts(ListBoxes::new(constants.box_count, constants.box_size));
+
+        let constants = SaveFileConstants::generation_1_constants(true);
+        compacts_boxes_and_updates_counts(ListBoxes::new(constants.box_count, constants.box_size));
+    }
+
+    #[test]
+    fn compacts_generation2_boxes() {
+        // Gold/Silver/Crystal have 14 boxes of 20 Pokémon, 9 boxes of 30 in Japanese
+        compacts_boxes_and_updates_counts(ListBoxes::new(14, 20));
+        compacts_boxes_and_updates_counts(ListBoxes::new(9, 30));
+    }
+}
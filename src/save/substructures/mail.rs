@@ -1,3 +1,5 @@
+use crate::{game::enums::language_id::LanguageID, strings, utils::data_manipulation::SliceUtils};
+
 pub const SIZE_GENERATION_3: usize = 0x24;
 
 pub struct MailDetailBase {
@@ -5,22 +7,90 @@ pub struct MailDetailBase {
   offset: usize,
 }
 
+impl MailDetailBase {
+  /// Offset of the mail in the save data it was read from
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+}
+
 pub trait MailDetailTrait {
+  fn get_base(&self) -> &MailDetailBase;
 
+  fn author_name(&self) -> String;
+  fn author_trainer_id(&self) -> u16;
+
+  /// Mail item ID, 0 when the mail is empty
+  fn mail_type(&self) -> u16;
+
+  fn is_empty(&self) -> bool {
+    self.mail_type() == 0
+  }
 }
 
 pub struct Generation3Mail {
   pub base: MailDetailBase,
+  pub is_japanese: bool,
 }
 
 impl Generation3Mail {
+  pub const MESSAGE_WORD_COUNT: usize = 9;
+
+  /// Reads the mail, the data is padded with zeros when it is shorter than a mail
+  ///
+  /// # Arguments
+  /// * `data` - Mail data
+  /// * `offset` - Offset of the mail in the save data
+  /// * `is_japanese` - Whether the author name uses the Japanese character table
   pub fn new(data: &[u8], offset: usize, is_japanese: bool) -> Self {
+    let mut mail = vec![0u8; SIZE_GENERATION_3];
+    let length = data.len().min(SIZE_GENERATION_3);
+    mail[..length].copy_from_slice(&data[..length]);
+
     Self {
-        base: todo!(),
+      base: MailDetailBase { data: mail, offset },
+      is_japanese,
+    }
+  }
+
+  /// Gets the easy chat word of the message, `None` past the 9 words
+  pub fn get_message_word(&self, index: usize) -> Option<u16> {
+    if index >= Self::MESSAGE_WORD_COUNT {
+      return None;
     }
+
+    self.base.data.get_u16_le_offset(index * 2).ok()
+  }
+
+  pub fn author_secret_id(&self) -> u16 {
+    self.base.data.get_u16_le_offset(0x1C).unwrap_or_default()
+  }
+
+  /// Species shown on the mail, as a Generation 3 index
+  pub fn appearance_species(&self) -> u16 {
+    self.base.data.get_u16_le_offset(0x1E).unwrap_or_default()
+  }
+
+  pub fn data(&self) -> &[u8] {
+    &self.base.data
   }
 }
 
 impl MailDetailTrait for Generation3Mail {
+  fn get_base(&self) -> &MailDetailBase {
+    &self.base
+  }
+
+  fn author_name(&self) -> String {
+    let language = if self.is_japanese { LanguageID::Japanese } else { LanguageID::English };
+    strings::generation_3::decode(&self.base.data[0x12..0x1A], language)
+  }
 
+  fn author_trainer_id(&self) -> u16 {
+    self.base.data.get_u16_le_offset(0x1A).unwrap_or_default()
+  }
+
+  fn mail_type(&self) -> u16 {
+    self.base.data.get_u16_le_offset(0x20).unwrap_or_default()
+  }
 }
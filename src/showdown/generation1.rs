@@ -0,0 +1,153 @@
+//! Applies Showdown sets to Red/Blue/Yellow Pokémon.
+//!
+//! IVs are stored as DVs and EVs as stat experience, see `ShowdownSet::dvs` and
+//! `stat_experience_from_ev`. Gender and shininess only exist once traded to Generation 2, they
+//! are derived from the DVs.
+
+use crate::{
+    legality::tables::generation1::MOVE_PP,
+    pokemon::{
+        utils::{experience::get_minimum_level_experience, nickname::get_default_nickname},
+        Generation1,
+    },
+    save::generation1::utils::get_string_length,
+    species::{conversion::generation1_index_from_species, personal::PersonalInfo},
+    strings::{self, StringConverterOption},
+    utils::constants::poke_crypto::{SIZE_GENERATION_1_PARTY, SIZE_GENERATION_1_STORED},
+};
+
+use super::{stat_experience_from_ev, ShowdownError, ShowdownSet};
+
+/// Applies the set to the Pokémon, which can be empty. The Original Trainer and the catch rate are kept,
+/// party Pokémon have their stats recalculated and their HP restored.
+pub fn apply(set: &ShowdownSet, pokemon: &mut Generation1) -> Result<(), ShowdownError> {
+    set.validate(1)?;
+
+    let unrepresentable = |field| ShowdownError::Unrepresentable { generation: 1, field };
+    let index = generation1_index_from_species(set.species).ok_or(unrepresentable("The species"))?;
+    let personal = PersonalInfo::generation3(set.species).ok_or(unrepresentable("The species"))?;
+    let dvs = set.dvs(1)?;
+
+    // region: Nickname
+    let language = pokemon.base.language;
+    let nickname = match &set.nickname {
+        Some(nickname) => nickname.clone(),
+        None => get_default_nickname(set.species, language, 1).unwrap_or_default(),
+    };
+
+    let max_length = if pokemon.is_japanese { 5 } else { 10 };
+    let mut nickname_bytes = vec![0u8; get_string_length(pokemon.is_japanese)];
+    strings::generation_1_or_2::set_string(&mut nickname_bytes, &nickname, max_length, language, StringConverterOption::Clear50);
+
+    // Characters without a code are skipped by the encoding
+    if nickname.chars().count() > max_length || strings::generation_1_or_2::get_string(&nickname_bytes, language) != nickname {
+        return Err(unrepresentable("The nickname"));
+    }
+    // endregion
+
+    let mut data = pokemon.base.data.clone();
+    if data.len() < SIZE_GENERATION_1_STORED {
+        data.resize(SIZE_GENERATION_1_STORED, 0);
+    }
+
+    data[0x00] = index;
+    data[0x03] = set.level;
+
+    // Every type of Red/Blue/Yellow Pokémon has a Generation 1 ID
+    let types = personal.generation1_types().map(|pokemon_type| pokemon_type.generation1_id().unwrap_or_default());
+    data[0x05..0x07].copy_from_slice(&types);
+
+    // The experience is only kept when it is already in the level
+    if pokemon.base.species != set.species || pokemon.base.level != set.level {
+        let experience = get_minimum_level_experience(set.level, personal.growth_rate as usize);
+        data[0x0E..0x11].copy_from_slice(&experience.to_be_bytes()[1..]);
+    }
+
+    for slot in 0..4 {
+        let move_id = set.moves.get(slot).copied().unwrap_or(0);
+        if data[0x08 + slot] as u16 == move_id {
+            continue;
+        }
+
+        let pp_ups = data[0x1D + slot] >> 6;
+        let base_pp = MOVE_PP.get(move_id as usize).copied().unwrap_or(0);
+        data[0x08 + slot] = move_id as u8;
+        data[0x1D + slot] = (pp_ups << 6) | (base_pp + base_pp * pp_ups / 5);
+    }
+
+    // HP, Attack, Defense, Speed and Special
+    for (stat, ev) in set.evs.iter().take(5).enumerate() {
+        data[0x11 + stat * 2..0x13 + stat * 2].copy_from_slice(&stat_experience_from_ev(*ev).to_be_bytes());
+    }
+
+    let dvs = ((dvs[0] as u16) << 12) | ((dvs[1] as u16) << 8) | ((dvs[2] as u16) << 4) | dvs[3] as u16;
+    data[0x1B..0x1D].copy_from_slice(&dvs.to_be_bytes());
+
+    if data.len() >= SIZE_GENERATION_1_PARTY {
+        data[0x21] = set.level;
+    }
+
+    let ot_bytes = pokemon.base.ot_bytes.clone();
+    *pokemon = Generation1::new(&data, pokemon.is_japanese);
+    pokemon.refresh_stats();
+    if pokemon.base.data.len() >= SIZE_GENERATION_1_PARTY {
+        let hitpoints = pokemon.base.hitpoints.to_be_bytes();
+        pokemon.base.data[0x01..0x03].copy_from_slice(&hitpoints);
+    }
+    pokemon.base.nickname_bytes = nickname_bytes;
+    pokemon.base.ot_bytes = ot_bytes;
+    // The names are encoded for the language of the save, which `new` does not know
+    pokemon.set_language(language);
+    pokemon.load_strings();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{language_id::LanguageID, species_id::SpeciesID},
+        pokemon::Generation1,
+        showdown::{generation1::apply, ShowdownError, ShowdownSet},
+        utils::constants::poke_crypto::SIZE_GENERATION_1_PARTY,
+    };
+
+    #[test]
+    fn applies_sets_as_dvs() {
+        let set: ShowdownSet = "Tauros\nLevel: 55\nShiny: Yes\nEVs: 252 Atk / 252 SpA / 252 SpD\n- Body Slam\n- Hyper Beam".parse().unwrap();
+        let mut pokemon = Generation1::new(&[0; SIZE_GENERATION_1_PARTY], false);
+        apply(&set, &mut pokemon).unwrap();
+
+        let base = &pokemon.base;
+        assert_eq!(base.species, SpeciesID::Tauros);
+        assert_eq!(base.nickname, "TAUROS");
+        assert_eq!(base.level, 55);
+        assert_eq!(pokemon.dvs(), [15, 10, 10, 10]);
+        assert!(pokemon.is_shiny());
+        assert_eq!(base.ev_attack, 63504);
+        assert_eq!(base.ev_special_attack, 63504);
+        assert_eq!([base.move_1, base.move_2, base.move_3], [34, 63, 0]);
+        assert_eq!([base.move_1_pp, base.move_2_pp], [15, 5]);
+        assert_eq!(&base.data[0x05..0x07], &[0, 0]);
+        assert_eq!([base.hitpoints, base.attack, base.defense, base.speed, base.special_attack], [156, 166, 120, 137, 127]);
+        assert_eq!(&base.data[0x01..0x03], &156u16.to_be_bytes());
+
+        let exported = ShowdownSet::from_pokemon(base);
+        assert_eq!(exported.evs, [0, 252, 0, 0, 252, 252]);
+        assert!(exported.shiny);
+
+        let set: ShowdownSet = "Tauros @ Leftovers".parse().unwrap();
+        assert!(matches!(apply(&set, &mut pokemon), Err(ShowdownError::Unrepresentable { generation: 1, .. })));
+    }
+
+    #[test]
+    fn keeps_the_save_language() {
+        let mut pokemon = Generation1::new(&[0; SIZE_GENERATION_1_PARTY], false);
+        pokemon.set_language(LanguageID::German);
+
+        let set: ShowdownSet = "Stürmer (Tauros)\nLevel: 30".parse().unwrap();
+        apply(&set, &mut pokemon).unwrap();
+        assert_eq!(pokemon.base.language, LanguageID::German);
+        assert_eq!(pokemon.base.nickname, "Stürmer");
+    }
+}
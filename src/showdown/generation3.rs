@@ -0,0 +1,164 @@
+//! Applies Showdown sets to Ruby/Sapphire/Emerald and FireRed/LeafGreen Pokémon.
+//!
+//! The nature, gender, shininess and ability are all derived from the PID, a new PID is searched
+//! when the current one does not match the set.
+
+use crate::{
+    game::enums::language_id::LanguageID,
+    json::generation3::{apply_pokemon, export_pokemon},
+    pokemon::{
+        utils::{
+            experience::get_minimum_level_experience,
+            nickname::get_default_nickname,
+            pid::{search_pid, PidAttributes, PidCriteria},
+        },
+        Generation3,
+    },
+    species::personal::PersonalInfo,
+    strings::{self, StringConverterOption},
+    utils::constants::poke_crypto::SIZE_GENERATION_3_STORED,
+};
+
+use super::{ShowdownError, ShowdownSet};
+
+/// Applies the set to the Pokémon, which can be empty. The Original Trainer, met data and
+/// everything else the set does not include are kept.
+pub fn apply(set: &ShowdownSet, pokemon: &mut Generation3) -> Result<(), ShowdownError> {
+    set.validate(3)?;
+
+    let unrepresentable = |field| ShowdownError::Unrepresentable { generation: 3, field };
+    let personal = PersonalInfo::generation3(set.species).ok_or(unrepresentable("The species"))?;
+
+    if pokemon.base.data.len() < SIZE_GENERATION_3_STORED as usize {
+        *pokemon = Generation3::from_decrypted(&[0; SIZE_GENERATION_3_STORED as usize]);
+    }
+
+    let mut document = export_pokemon(pokemon);
+
+    // Empty Pokémon have no language
+    let language = match LanguageID::from(document.language) {
+        LanguageID::Hacked => LanguageID::English,
+        language => language,
+    };
+    document.language = language as u8;
+
+    // region: Nickname
+    let nickname = match &set.nickname {
+        Some(nickname) => nickname.clone(),
+        None => get_default_nickname(set.species, language, 3).unwrap_or_default(),
+    };
+
+    let max_length = if language == LanguageID::Japanese { 5 } else { 10 };
+    if nickname.chars().count() > max_length
+        || strings::generation_3::encode(&mut [0; 10], &nickname, max_length, language, StringConverterOption::ClearFF).is_err()
+    {
+        return Err(unrepresentable("The nickname"));
+    }
+    // endregion
+
+    // region: PID
+    let has_two_abilities = personal.has_two_abilities();
+    let ability_number = match set.ability {
+        Some(ability) => personal.abilities.iter().position(|slot| *slot as u16 == ability).unwrap_or(0) as u8,
+        None => document.ability_number,
+    } & has_two_abilities as u8;
+
+    let criteria = PidCriteria {
+        nature: set.nature,
+        ability_slot: has_two_abilities.then_some(ability_number),
+        gender: set.gender,
+        shiny: Some(set.shiny),
+        ..Default::default()
+    };
+
+    let trainer = &document.original_trainer;
+    let current = PidAttributes::new(document.personality_id, personal.gender_ratio, trainer.trainer_id, trainer.secret_id);
+    if !criteria.matches(&current) {
+        document.personality_id = search_pid(&criteria, personal.gender_ratio, trainer.trainer_id, trainer.secret_id)
+            .ok_or(unrepresentable("A PID with the nature, gender and shininess"))?;
+    }
+    // endregion
+
+    // The experience is only kept when it is already in the level
+    if pokemon.base.species != set.species || pokemon.base.level != set.level {
+        document.experience = get_minimum_level_experience(set.level, personal.growth_rate as usize);
+    }
+
+    let mut moves = [0; 4];
+    moves[..set.moves.len()].copy_from_slice(&set.moves);
+
+    document.species = set.species.national_id();
+    document.nickname = nickname;
+    document.is_egg = false;
+    document.level = set.level;
+    document.ability_number = ability_number;
+    document.held_item = set.item.unwrap_or(0);
+    document.moves = moves;
+    document.ivs = set.generation3_ivs()?;
+    document.evs = set.evs.map(|ev| ev as u8);
+    document.friendship = set.friendship.unwrap_or(document.friendship);
+
+    // Derived values are checked by the set validation and the PID search
+    document.species_name = None;
+    document.nature = None;
+    document.gender = None;
+    document.ability = None;
+    document.is_shiny = None;
+    document.held_item_name = None;
+
+    match apply_pokemon(pokemon, &document).is_complete() {
+        true => Ok(()),
+        false => Err(unrepresentable("A value of the set")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{pokemon_gender::PokemonGender, pokemon_nature::PokemonNature, pokemon_type::PokemonType, species_id::SpeciesID},
+        pokemon::Generation3,
+        showdown::{generation3::apply, ShowdownError, ShowdownSet},
+        utils::constants::poke_crypto::SIZE_GENERATION_3_PARTY,
+    };
+
+    const GARDEVOIR: &str = "Gardevoir (F) @ Leftovers
+Ability: Trace
+Level: 50
+Shiny: Yes
+EVs: 252 SpA / 252 Spe
+Modest Nature
+- Psychic
+- Hidden Power [Fire]";
+
+    #[test]
+    fn applies_sets() {
+        let set: ShowdownSet = GARDEVOIR.parse().unwrap();
+        let mut pokemon = Generation3::from_decrypted(&[0; SIZE_GENERATION_3_PARTY as usize]);
+        apply(&set, &mut pokemon).unwrap();
+
+        let base = &pokemon.base;
+        assert_eq!(base.species, SpeciesID::Gardevoir);
+        assert_eq!(base.nickname, "GARDEVOIR");
+        assert_eq!(base.level, 50);
+        assert_eq!(base.nature, PokemonNature::Modest);
+        assert_eq!(base.gender, PokemonGender::Female);
+        assert!(base.is_shiny());
+        assert_eq!(base.hidden_power_type(), PokemonType::Fire);
+        assert_eq!(base.ability_number as u32, base.personality_id & 1);
+        assert!(base.hitpoints > 0);
+
+        let exported = ShowdownSet::from_pokemon(base);
+        assert_eq!((exported.item, exported.ability, exported.evs), (set.item, set.ability, set.evs));
+        assert_eq!(exported.moves, set.moves);
+    }
+
+    #[test]
+    fn rejects_unrepresentable_sets() {
+        let mut pokemon = Generation3::default();
+        let set: ShowdownSet = "Gardevoir\nAbility: Levitate".parse().unwrap();
+        assert!(matches!(apply(&set, &mut pokemon), Err(ShowdownError::Unrepresentable { generation: 3, .. })));
+
+        let set: ShowdownSet = "A Very Long Nickname (Gardevoir)".parse().unwrap();
+        assert_eq!(apply(&set, &mut pokemon), Err(ShowdownError::Unrepresentable { generation: 3, field: "The nickname" }));
+    }
+}
@@ -0,0 +1,621 @@
+//! Pokémon Showdown sets, the text format used to share teams:
+//!
+//! ```text
+//! Woody (Treecko) (M) @ Quick Claw
+//! Ability: Overgrow
+//! Level: 50
+//! Shiny: Yes
+//! EVs: 252 SpA / 4 SpD / 252 Spe
+//! Timid Nature
+//! IVs: 0 Atk
+//! - Leaf Blade
+//! - Hidden Power [Ice]
+//! ```
+//!
+//! Names are read and written in English. Sets are independent of any generation until they are
+//! checked with `ShowdownSet::validate` or applied to a Pokémon, see `generation1` and `generation3`.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    game::enums::{language_id::LanguageID, pokemon_gender::PokemonGender, pokemon_nature::PokemonNature, pokemon_type::PokemonType, species_id::SpeciesID},
+    legality::{
+        items::{generation2_item_from_generation3, generation3_item_from_generation2},
+        tables::{
+            generation1::{MAX_MOVE_ID as MAX_MOVE_ID_GENERATION_1, MAX_SPECIES_ID as MAX_SPECIES_ID_GENERATION_1},
+            generation3::{MAX_MOVE_ID, MAX_SPECIES_ID},
+        },
+    },
+    pokemon::{
+        base::PokemonBase,
+        utils::{dv, hidden_power, nickname::is_default_nickname},
+    },
+    species::personal::PersonalInfo,
+    strings::names::{ability_from_name, ability_name, item_from_name, item_name, move_from_name, move_name, nature_from_name, nature_name, species_from_name, species_name},
+};
+
+pub mod generation1;
+pub mod generation3;
+
+// region: Constants
+const MAX_SPECIES_ID_GENERATION_2: u16 = 251;
+const MAX_MOVE_ID_GENERATION_2: u16 = 251;
+
+const MAX_EV: u16 = 255;
+const MAX_EV_TOTAL: u16 = 510;
+const MAX_IV: u8 = 31;
+const MAX_LEVEL: u8 = 100;
+const MAX_FRIENDSHIP: u8 = 255;
+
+pub const HIDDEN_POWER: u16 = 237;
+
+/// Labels of the stats, in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order
+const STAT_LABELS: [&str; 6] = ["HP", "Atk", "Def", "Spe", "SpA", "SpD"];
+/// Order of the stats in the EVs and IVs lines
+const STAT_DISPLAY_ORDER: [usize; 6] = [0, 1, 2, 4, 5, 3];
+
+// Indexes of the stats
+const SPEED: usize = 3;
+const SPECIAL_ATTACK: usize = 4;
+const SPECIAL_DEFENSE: usize = 5;
+// endregion
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShowdownError {
+    /// The text has no species line
+    Empty,
+    /// The line is not part of the format, or is about a feature of later generations
+    InvalidLine(String),
+    InvalidNumber(String),
+    UnknownSpecies(String),
+    /// Alternate forms are not supported, Generation 3 forms are derived from the PID
+    UnsupportedForm(String),
+    UnknownItem(String),
+    UnknownAbility(String),
+    UnknownNature(String),
+    UnknownMove(String),
+    UnknownType(String),
+    /// The target format can't store the value
+    Unrepresentable { generation: u8, field: &'static str },
+}
+
+impl Display for ShowdownError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShowdownError::Empty => write!(f, "The set has no species"),
+            ShowdownError::InvalidLine(line) => write!(f, "Unsupported line \"{}\"", line),
+            ShowdownError::InvalidNumber(value) => write!(f, "Invalid number \"{}\"", value),
+            ShowdownError::UnknownSpecies(name) => write!(f, "Unknown species \"{}\"", name),
+            ShowdownError::UnsupportedForm(name) => write!(f, "The form of \"{}\" is not supported", name),
+            ShowdownError::UnknownItem(name) => write!(f, "Unknown item \"{}\"", name),
+            ShowdownError::UnknownAbility(name) => write!(f, "Unknown ability \"{}\"", name),
+            ShowdownError::UnknownNature(name) => write!(f, "Unknown nature \"{}\"", name),
+            ShowdownError::UnknownMove(name) => write!(f, "Unknown move \"{}\"", name),
+            ShowdownError::UnknownType(name) => write!(f, "Unknown type \"{}\"", name),
+            ShowdownError::Unrepresentable { generation, field } => write!(f, "{} can't be stored in Generation {}", field, generation),
+        }
+    }
+}
+
+impl std::error::Error for ShowdownError {}
+
+/// A Showdown set. Stats are in the HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowdownSet {
+    pub species: SpeciesID,
+    pub nickname: Option<String>,
+    pub gender: Option<PokemonGender>,
+    /// Generation 3 item ID
+    pub item: Option<u16>,
+    pub ability: Option<u16>,
+    pub level: u8,
+    pub shiny: bool,
+    pub friendship: Option<u8>,
+    pub nature: Option<PokemonNature>,
+    pub evs: [u16; 6],
+    pub ivs: [u8; 6],
+    pub moves: Vec<u16>,
+    /// Type written after Hidden Power, which must match the IVs
+    pub hidden_power_type: Option<PokemonType>,
+}
+
+impl Default for ShowdownSet {
+    fn default() -> Self {
+        Self {
+            species: SpeciesID::None,
+            nickname: None,
+            gender: None,
+            item: None,
+            ability: None,
+            level: MAX_LEVEL,
+            shiny: false,
+            friendship: None,
+            nature: None,
+            evs: [0; 6],
+            ivs: [MAX_IV; 6],
+            moves: Vec::new(),
+            hidden_power_type: None,
+        }
+    }
+}
+
+// region: Parsing
+/// Parses every set of a team, the sets are separated by blank lines.
+/// Team headers (`=== [gen3ou] Team ===`) are skipped.
+pub fn parse_sets(text: &str) -> Result<Vec<ShowdownSet>, ShowdownError> {
+    let mut sets = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+
+    for line in text.lines().map(str::trim).chain(std::iter::once("")) {
+        if line.starts_with("===") {
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(line);
+        } else if !lines.is_empty() {
+            sets.push(ShowdownSet::parse_lines(&lines)?);
+            lines.clear();
+        }
+    }
+
+    Ok(sets)
+}
+
+impl FromStr for ShowdownSet {
+    type Err = ShowdownError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        Self::parse_lines(&lines)
+    }
+}
+
+impl ShowdownSet {
+    fn parse_lines(lines: &[&str]) -> Result<Self, ShowdownError> {
+        let (header, lines) = lines.split_first().ok_or(ShowdownError::Empty)?;
+        let mut set = Self::default();
+        set.parse_header(header)?;
+
+        for line in lines {
+            if let Some(name) = line.strip_prefix('-').or_else(|| line.strip_prefix('~')) {
+                set.parse_move(name.trim())?;
+            } else if let Some(name) = line.strip_suffix(" Nature") {
+                set.nature = Some(nature_from_name(name, LanguageID::English).ok_or(ShowdownError::UnknownNature(name.to_string()))?);
+            } else if let Some((key, value)) = line.split_once(':') {
+                set.parse_attribute(key.trim(), value.trim(), line)?;
+            } else {
+                return Err(ShowdownError::InvalidLine(line.to_string()));
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// Parses `Nickname (Species) (M) @ Item`, where only the species is required
+    fn parse_header(&mut self, line: &str) -> Result<(), ShowdownError> {
+        let (rest, item) = match line.rsplit_once(" @ ") {
+            Some((rest, item)) => (rest.trim(), Some(item.trim())),
+            None => (line, None),
+        };
+
+        self.item = item.map(parse_item).transpose()?;
+
+        let rest = if let Some(rest) = rest.strip_suffix("(M)") {
+            self.gender = Some(PokemonGender::Male);
+            rest.trim()
+        } else if let Some(rest) = rest.strip_suffix("(F)") {
+            self.gender = Some(PokemonGender::Female);
+            rest.trim()
+        } else {
+            rest
+        };
+
+        let species = match rest.rfind(" (") {
+            Some(open) if rest.ends_with(')') => {
+                self.nickname = Some(rest[..open].trim().to_string());
+                &rest[open + 2..rest.len() - 1]
+            }
+            _ => rest,
+        };
+
+        self.species = parse_species(species.trim())?;
+        Ok(())
+    }
+
+    fn parse_attribute(&mut self, key: &str, value: &str, line: &str) -> Result<(), ShowdownError> {
+        match key {
+            "Ability" if value == "No Ability" => self.ability = None,
+            "Ability" => self.ability = Some(ability_from_name(value).ok_or(ShowdownError::UnknownAbility(value.to_string()))?),
+            "Level" => self.level = parse_number(value)?,
+            "Shiny" => self.shiny = value.eq_ignore_ascii_case("yes"),
+            "Happiness" => self.friendship = Some(parse_number(value)?),
+            "EVs" => parse_stats(value, &mut self.evs)?,
+            "IVs" => parse_stats(value, &mut self.ivs)?,
+            _ => return Err(ShowdownError::InvalidLine(line.to_string())),
+        }
+
+        Ok(())
+    }
+
+    /// Parses a move, `Hidden Power [Type]` also sets the Hidden Power type
+    fn parse_move(&mut self, name: &str) -> Result<(), ShowdownError> {
+        if let Some(hidden_power_type) = name.strip_prefix("Hidden Power [").and_then(|rest| rest.strip_suffix(']')) {
+            self.hidden_power_type = Some(parse_type(hidden_power_type)?);
+            self.moves.push(HIDDEN_POWER);
+            return Ok(());
+        }
+
+        let move_id = move_from_name(name)
+            .or_else(|| move_from_name(&name.replace(' ', "")))
+            .ok_or(ShowdownError::UnknownMove(name.to_string()))?;
+        self.moves.push(move_id);
+        Ok(())
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, ShowdownError> {
+    value.trim().parse().map_err(|_| ShowdownError::InvalidNumber(value.to_string()))
+}
+
+/// Parses the species, accepting the names Showdown uses for the Nidoran
+fn parse_species(name: &str) -> Result<SpeciesID, ShowdownError> {
+    let name = match name {
+        "Nidoran-F" => "Nidoran♀",
+        "Nidoran-M" => "Nidoran♂",
+        _ => name,
+    };
+
+    if let Some(species) = species_from_name(&name.replace('’', "'"), LanguageID::English) {
+        return Ok(species);
+    }
+
+    // Forms are written after the species, like Unown-B or Deoxys-Attack
+    match name.rsplit_once('-') {
+        Some((species, _)) if species_from_name(species, LanguageID::English).is_some() => Err(ShowdownError::UnsupportedForm(name.to_string())),
+        _ => Err(ShowdownError::UnknownSpecies(name.to_string())),
+    }
+}
+
+/// Parses the item, Generation 3 names without spaces (BrightPowder) are also accepted
+fn parse_item(name: &str) -> Result<u16, ShowdownError> {
+    item_from_name(name)
+        .or_else(|| item_from_name(&name.replace(' ', "")))
+        .ok_or(ShowdownError::UnknownItem(name.to_string()))
+}
+
+fn parse_type(name: &str) -> Result<PokemonType, ShowdownError> {
+    (0..=PokemonType::Dark as u8)
+        .map(PokemonType::from)
+        .find(|pokemon_type| format!("{:?}", pokemon_type).eq_ignore_ascii_case(name.trim()))
+        .ok_or(ShowdownError::UnknownType(name.to_string()))
+}
+
+/// Parses `252 Atk / 4 SpD / 252 Spe`, the stats left out keep their value
+fn parse_stats<T: FromStr>(value: &str, stats: &mut [T; 6]) -> Result<(), ShowdownError> {
+    for entry in value.split('/') {
+        let (amount, label) = entry.trim().split_once(' ').ok_or(ShowdownError::InvalidLine(entry.to_string()))?;
+        let index = STAT_LABELS
+            .iter()
+            .position(|stat| stat.eq_ignore_ascii_case(label.trim()))
+            .ok_or(ShowdownError::InvalidLine(entry.to_string()))?;
+
+        stats[index] = parse_number(amount)?;
+    }
+
+    Ok(())
+}
+// endregion
+
+// region: Formatting
+/// Gets the name, or the ID when it has no name
+fn name_or_id(name: Option<&str>, id: u16) -> String {
+    name.map_or_else(|| format!("#{}", id), str::to_string)
+}
+
+/// Formats the stats different from the default value, `None` when all of them are
+fn format_stats<T: PartialEq + Display>(stats: &[T; 6], default: T) -> Option<String> {
+    let entries: Vec<String> = STAT_DISPLAY_ORDER
+        .iter()
+        .filter(|index| stats[**index] != default)
+        .map(|index| format!("{} {}", stats[*index], STAT_LABELS[*index]))
+        .collect();
+
+    (!entries.is_empty()).then(|| entries.join(" / "))
+}
+
+impl Display for ShowdownSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let species = name_or_id(species_name(self.species, LanguageID::English), self.species.national_id());
+
+        match &self.nickname {
+            Some(nickname) => write!(f, "{} ({})", nickname, species)?,
+            None => write!(f, "{}", species)?,
+        }
+
+        match self.gender {
+            Some(PokemonGender::Male) => write!(f, " (M)")?,
+            Some(PokemonGender::Female) => write!(f, " (F)")?,
+            _ => {}
+        }
+
+        if let Some(item) = self.item {
+            write!(f, " @ {}", name_or_id(item_name(item), item))?;
+        }
+        writeln!(f)?;
+
+        if let Some(ability) = self.ability {
+            writeln!(f, "Ability: {}", name_or_id(ability_name(ability), ability))?;
+        }
+
+        if self.level != MAX_LEVEL {
+            writeln!(f, "Level: {}", self.level)?;
+        }
+
+        if self.shiny {
+            writeln!(f, "Shiny: Yes")?;
+        }
+
+        if let Some(friendship) = self.friendship.filter(|friendship| *friendship != MAX_FRIENDSHIP) {
+            writeln!(f, "Happiness: {}", friendship)?;
+        }
+
+        if let Some(evs) = format_stats(&self.evs, 0) {
+            writeln!(f, "EVs: {}", evs)?;
+        }
+
+        if let Some(nature) = self.nature {
+            writeln!(f, "{} Nature", nature_name(nature, LanguageID::English).unwrap_or_default())?;
+        }
+
+        if let Some(ivs) = format_stats(&self.ivs, MAX_IV) {
+            writeln!(f, "IVs: {}", ivs)?;
+        }
+
+        for move_id in &self.moves {
+            match self.hidden_power_type {
+                Some(hidden_power_type) if *move_id == HIDDEN_POWER => writeln!(f, "- Hidden Power [{:?}]", hidden_power_type)?,
+                _ => writeln!(f, "- {}", name_or_id(move_name(*move_id), *move_id))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+// endregion
+
+// region: Conversion
+/// Converts Generation 1 and 2 stat experience to the EV Showdown uses, the square root rounded up
+pub fn ev_from_stat_experience(stat_experience: u16) -> u16 {
+    ((stat_experience as f64).sqrt().ceil() as u16).min(MAX_EV)
+}
+
+/// Converts an EV to the Generation 1 and 2 stat experience with the same stat boost
+pub fn stat_experience_from_ev(ev: u16) -> u16 {
+    (ev as u32 * ev as u32).min(u16::MAX as u32) as u16
+}
+
+/// Converts a Generation 1 and 2 DV to the IV Showdown shows, a DV of 15 is an IV of 31
+pub fn iv_from_dv(dv: u8) -> u8 {
+    dv * 2 + 1
+}
+
+impl ShowdownSet {
+    /// Gets the set of the Pokémon, Generation 1 and 2 stat experience and DVs are converted to EVs and IVs
+    pub fn from_pokemon(base: &PokemonBase) -> Self {
+        let generation = base.generation().unwrap_or(3);
+        let has_dvs = generation <= 2;
+
+        let nickname = Some(base.nickname.clone()).filter(|nickname| !nickname.is_empty() && !is_default_nickname(nickname, base.species, base.language, generation));
+        let moves: Vec<u16> = [base.move_1, base.move_2, base.move_3, base.move_4].into_iter().filter(|move_id| *move_id != 0).collect();
+        let evs = [base.ev_hitpoints, base.ev_attack, base.ev_defense, base.ev_speed, base.ev_special_attack, base.ev_special_defense];
+
+        Self {
+            species: base.species,
+            nickname,
+            gender: Some(base.gender).filter(|gender| *gender != PokemonGender::Genderless && generation >= 2),
+            // The Generation 1 byte is the catch rate, Generation 2 items are converted to Generation 3 IDs
+            item: match generation {
+                1 => None,
+                2 => generation3_item_from_generation2(base.held_item as u8),
+                _ => Some(base.held_item).filter(|item| *item != 0),
+            },
+            ability: Some(base.ability as u16).filter(|ability| *ability != 0 && !has_dvs),
+            level: base.level,
+            shiny: base.is_shiny(),
+            friendship: Some(base.current_friendship).filter(|_| generation >= 2),
+            nature: Some(base.nature).filter(|_| !has_dvs),
+            evs: if has_dvs { evs.map(ev_from_stat_experience) } else { evs },
+            ivs: if has_dvs { base.ivs().map(iv_from_dv) } else { base.ivs() },
+            hidden_power_type: moves.contains(&HIDDEN_POWER).then(|| base.hidden_power_type()),
+            moves,
+        }
+    }
+
+    /// Gets the Generation 1 and 2 DVs in the Attack, Defense, Speed, Special order.
+    ///
+    /// When every IV is left at 31, the DVs are adjusted for shininess and the Hidden Power type.
+    /// The HP and Sp. Defense IVs are derived from the others, they must be left at 31 or match them.
+    pub fn dvs(&self, generation: u8) -> Result<[u8; 4], ShowdownError> {
+        let ivs = self.ivs;
+        let mut dvs = [ivs[1], ivs[2], ivs[SPEED], ivs[SPECIAL_ATTACK]].map(|iv| iv.min(MAX_IV) / 2);
+
+        if ivs == [MAX_IV; 6] {
+            if self.shiny {
+                dvs = [15, dv::SHINY_DV, dv::SHINY_DV, dv::SHINY_DV];
+            }
+
+            if let Some(hidden_power_type) = self.hidden_power_type {
+                dvs = hidden_power::find_hidden_power_dvs(hidden_power_type, dvs).ok_or(ShowdownError::UnknownType(format!("{:?}", hidden_power_type)))?;
+            }
+        }
+
+        let unrepresentable = |field| ShowdownError::Unrepresentable { generation, field };
+        if ivs[SPECIAL_DEFENSE] != MAX_IV && ivs[SPECIAL_DEFENSE] / 2 != dvs[3] {
+            return Err(unrepresentable("A Sp. Defense IV different from the Sp. Attack IV"));
+        }
+        if ivs[0] != MAX_IV && ivs[0] / 2 != dv::hitpoints_dv(dvs) {
+            return Err(unrepresentable("An HP IV not derived from the other IVs"));
+        }
+
+        Ok(dvs)
+    }
+
+    /// Gets the IVs, adjusted for the Hidden Power type when every IV is left at 31
+    pub fn generation3_ivs(&self) -> Result<[u8; 6], ShowdownError> {
+        match self.hidden_power_type {
+            Some(hidden_power_type) if self.ivs == [MAX_IV; 6] => hidden_power::find_hidden_power_ivs(hidden_power_type, self.ivs)
+                .ok_or(ShowdownError::UnknownType(format!("{:?}", hidden_power_type))),
+            _ => Ok(self.ivs),
+        }
+    }
+
+    /// Checks that every value of the set can be stored in the generation, only 1 to 3 are supported
+    pub fn validate(&self, generation: u8) -> Result<(), ShowdownError> {
+        let unrepresentable = |field| ShowdownError::Unrepresentable { generation, field };
+        let has_dvs = generation <= 2;
+
+        let (max_species, max_move) = match generation {
+            1 => (MAX_SPECIES_ID_GENERATION_1, MAX_MOVE_ID_GENERATION_1),
+            2 => (MAX_SPECIES_ID_GENERATION_2, MAX_MOVE_ID_GENERATION_2),
+            3 => (MAX_SPECIES_ID, MAX_MOVE_ID as u16),
+            _ => return Err(unrepresentable("A Showdown set")),
+        };
+
+        let personal = PersonalInfo::generation3(self.species).filter(|_| self.species.national_id() <= max_species).ok_or(unrepresentable("The species"))?;
+
+        if !(1..=MAX_LEVEL).contains(&self.level) {
+            return Err(unrepresentable("The level"));
+        }
+        if self.moves.len() > 4 || self.moves.iter().any(|move_id| *move_id > max_move) {
+            return Err(unrepresentable("A move"));
+        }
+        if self.ivs.iter().any(|iv| *iv > MAX_IV) {
+            return Err(unrepresentable("An IV above 31"));
+        }
+        if self.evs.iter().any(|ev| *ev > MAX_EV) {
+            return Err(unrepresentable("An EV above 255"));
+        }
+
+        match (generation, self.item) {
+            (1, Some(_)) => return Err(unrepresentable("A held item")),
+            (2, Some(item)) if generation2_item_from_generation3(item).is_none() => {
+                return Err(unrepresentable("A held item that is not in Gold/Silver/Crystal"));
+            },
+            _ => {},
+        }
+
+        if has_dvs {
+            if self.ability.is_some() {
+                return Err(unrepresentable("An ability"));
+            }
+            if self.nature.is_some() {
+                return Err(unrepresentable("A nature"));
+            }
+            if generation == 1 && self.friendship.is_some() {
+                return Err(unrepresentable("Friendship"));
+            }
+            if self.evs[SPECIAL_DEFENSE] != 0 && self.evs[SPECIAL_DEFENSE] != self.evs[SPECIAL_ATTACK] {
+                return Err(unrepresentable("A Sp. Defense EV different from the Sp. Attack EV"));
+            }
+
+            let dvs = self.dvs(generation)?;
+            if self.shiny != dv::is_shiny(dvs) {
+                return Err(unrepresentable("Shininess that does not match the IVs"));
+            }
+            if self.gender.is_some_and(|gender| gender != dv::gender(dvs, personal.gender_ratio)) {
+                return Err(unrepresentable("A gender that does not match the IVs"));
+            }
+            if self.hidden_power_type.is_some_and(|hidden_power_type| hidden_power_type != hidden_power::hidden_power_type_generation2(dvs)) {
+                return Err(unrepresentable("A Hidden Power type that does not match the IVs"));
+            }
+
+            return Ok(());
+        }
+
+        if self.evs.iter().sum::<u16>() > MAX_EV_TOTAL {
+            return Err(unrepresentable("An EV total above 510"));
+        }
+        if self.ability.is_some_and(|ability| !personal.abilities.contains(&(ability as u8)) || ability == 0) {
+            return Err(unrepresentable("An ability the species can't have"));
+        }
+        if self.gender.is_some_and(|gender| !gender_possible(gender, personal.gender_ratio)) {
+            return Err(unrepresentable("A gender the species can't have"));
+        }
+        if self.hidden_power_type.is_some_and(|hidden_power_type| Ok(hidden_power_type) != self.generation3_ivs().map(hidden_power::hidden_power_type)) {
+            return Err(unrepresentable("A Hidden Power type that does not match the IVs"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks if the gender is possible with the gender ratio of the species
+fn gender_possible(gender: PokemonGender, gender_ratio: u8) -> bool {
+    match gender_ratio {
+        // Genderless
+        255 => gender == PokemonGender::Genderless,
+        // Female only
+        254 => gender == PokemonGender::Female,
+        // Male only
+        0 => gender == PokemonGender::Male,
+        _ => gender != PokemonGender::Genderless,
+    }
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::game::enums::{pokemon_gender::PokemonGender, pokemon_nature::PokemonNature, pokemon_type::PokemonType, species_id::SpeciesID};
+
+    use super::{parse_sets, ShowdownError, ShowdownSet};
+
+    const SCEPTILE: &str = "Woody (Sceptile) (M) @ Quick Claw
+Ability: Overgrow
+Level: 50
+Shiny: Yes
+EVs: 252 SpA / 4 SpD / 252 Spe
+Timid Nature
+IVs: 30 Atk / 30 Def
+- Leaf Blade
+- Hidden Power [Ice]
+";
+
+    #[test]
+    fn parses_and_formats_sets() {
+        let set: ShowdownSet = SCEPTILE.parse().unwrap();
+
+        assert_eq!(set.species, SpeciesID::Sceptile);
+        assert_eq!(set.nickname.as_deref(), Some("Woody"));
+        assert_eq!(set.gender, Some(PokemonGender::Male));
+        assert_eq!(set.level, 50);
+        assert!(set.shiny);
+        assert_eq!(set.nature, Some(PokemonNature::Timid));
+        assert_eq!(set.evs, [0, 0, 0, 252, 252, 4]);
+        assert_eq!(set.ivs, [31, 30, 30, 31, 31, 31]);
+        assert_eq!(set.hidden_power_type, Some(PokemonType::Ice));
+        assert_eq!(set.to_string(), SCEPTILE);
+        assert!(set.validate(3).is_ok());
+    }
+
+    #[test]
+    fn rejects_what_the_generation_can_not_store() {
+        let set: ShowdownSet = SCEPTILE.parse().unwrap();
+        assert!(matches!(set.validate(1), Err(ShowdownError::Unrepresentable { generation: 1, .. })));
+
+        let teams = parse_sets("=== [gen1ou] Team ===\n\nTauros\nShiny: Yes\n- Body Slam\n\nChansey\n- Soft-Boiled\nIVs: 10 HP / 2 Atk").unwrap();
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[0].dvs(1), Ok([15, 10, 10, 10]));
+        assert!(teams[0].validate(1).is_ok());
+        assert!(teams[1].validate(1).is_err());
+
+        let set: ShowdownSet = "Snorlax @ Leftovers".parse().unwrap();
+        assert!(set.validate(2).is_ok());
+        assert!(set.validate(1).is_err());
+        let set: ShowdownSet = "Snorlax @ Lum Berry".parse().unwrap();
+        assert_eq!(set.validate(2), Err(ShowdownError::Unrepresentable { generation: 2, field: "A held item that is not in Gold/Silver/Crystal" }));
+
+        assert_eq!("Unown-B".parse::<ShowdownSet>(), Err(ShowdownError::UnsupportedForm("Unown-B".to_string())));
+        assert_eq!("Pikachu\nTera Type: Electric".parse::<ShowdownSet>(), Err(ShowdownError::InvalidLine("Tera Type: Electric".to_string())));
+    }
+}
@@ -5,6 +5,8 @@ use crate::{
     utils::constants::{MAGIC_RATIO_FEMALE, MAGIC_RATIO_GENDERLESS, MAGIC_RATIO_MALE},
 };
 
+use super::personal::PersonalInfo;
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default)]
 pub struct SpeciesInfo {
@@ -72,6 +74,26 @@ pub enum SpeciesGenderCategory {
     DualGender = -1,
 }
 
+impl From<&PersonalInfo> for SpeciesInfo {
+    fn from(personal: &PersonalInfo) -> Self {
+        Self {
+            hitpoints: personal.base_stats[0].into(),
+            attack: personal.base_stats[1].into(),
+            defense: personal.base_stats[2].into(),
+            speed: personal.base_stats[3].into(),
+            special_attack: personal.base_stats[4].into(),
+            special_defense: personal.base_stats[5].into(),
+            type_1: personal.types[0] as u8,
+            type_2: personal.types[1] as u8,
+            catch_rate: personal.catch_rate,
+            gender_ratio: personal.gender_ratio,
+            exp_growth: personal.growth_rate,
+            valid_abilities: if personal.has_two_abilities() { 2 } else { 1 },
+            ..Default::default()
+        }
+    }
+}
+
 impl SpeciesInfo {
     pub fn is_present_ingame(&self) -> bool {
         self.hitpoints != 0
@@ -116,7 +138,3 @@ impl SpeciesInfo {
         }
     }
 }
-
-trait SpeciesTrait {
-    fn ability_index(ability_id: u16) -> u16;
-}
@@ -0,0 +1,263 @@
+use std::fmt::Display;
+
+use crate::{
+    game::enums::species_id::SpeciesID,
+    legality::tables::generation3::{MAX_SPECIES_ID, MAX_SPECIES_INDEX},
+};
+
+use SpeciesID::*;
+
+// Generation 1 and Generation 3 games do not store the National Pokédex number of a species,
+// instead they store an internal index that is used to access the game's own tables.
+// Generation 2 and Generation 4+ store the National Pokédex number directly.
+
+/// Reasons for an internal species index to not have a matching species
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidSpeciesIndex {
+    /// Generation 1 index that is not assigned to any species (MissingNo.)
+    MissingNo(u8),
+    /// Generation 3 index that is inside the internal range, but is an unused slot (`?`)
+    Unused(u16),
+    /// Index is outside of the game's internal range
+    OutOfRange(u16),
+}
+
+impl Display for InvalidSpeciesIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidSpeciesIndex::MissingNo(index) => write!(f, "MissingNo. (internal index {})", index),
+            InvalidSpeciesIndex::Unused(index) => write!(f, "Unused species slot (internal index {})", index),
+            InvalidSpeciesIndex::OutOfRange(index) => write!(f, "Species internal index {} is out of range", index),
+        }
+    }
+}
+
+// region: Generation 1
+
+/// Last internal index used by Generation 1 games (Victreebel)
+pub const MAX_GENERATION1_INDEX: u8 = 190;
+
+/// Generation 1 internal index to species, `None` entries are MissingNo.
+const GENERATION1_INDEX_TO_NATIONAL: [SpeciesID; MAX_GENERATION1_INDEX as usize + 1] = [
+    None,       Rhydon,     Kangaskhan, NidoranM,   Clefairy,   Spearow,    Voltorb,    Nidoking,   // 0x00
+    Slowbro,    Ivysaur,    Exeggutor,  Lickitung,  Exeggcute,  Grimer,     Gengar,     NidoranF,   // 0x08
+    Nidoqueen,  Cubone,     Rhyhorn,    Lapras,     Arcanine,   Mew,        Gyarados,   Shellder,   // 0x10
+    Tentacool,  Gastly,     Scyther,    Staryu,     Blastoise,  Pinsir,     Tangela,    None,       // 0x18
+    None,       Growlithe,  Onix,       Fearow,     Pidgey,     Slowpoke,   Kadabra,    Graveler,   // 0x20
+    Chansey,    Machoke,    MrMime,     Hitmonlee,  Hitmonchan, Arbok,      Parasect,   Psyduck,    // 0x28
+    Drowzee,    Golem,      None,       Magmar,     None,       Electabuzz, Magneton,   Koffing,    // 0x30
+    None,       Mankey,     Seel,       Diglett,    Tauros,     None,       None,       None,       // 0x38
+    Farfetchd,  Venonat,    Dragonite,  None,       None,       None,       Doduo,      Poliwag,    // 0x40
+    Jynx,       Moltres,    Articuno,   Zapdos,     Ditto,      Meowth,     Krabby,     None,       // 0x48
+    None,       None,       Vulpix,     Ninetales,  Pikachu,    Raichu,     None,       None,       // 0x50
+    Dratini,    Dragonair,  Kabuto,     Kabutops,   Horsea,     Seadra,     None,       None,       // 0x58
+    Sandshrew,  Sandslash,  Omanyte,    Omastar,    Jigglypuff, Wigglytuff, Eevee,      Flareon,    // 0x60
+    Jolteon,    Vaporeon,   Machop,     Zubat,      Ekans,      Paras,      Poliwhirl,  Poliwrath,  // 0x68
+    Weedle,     Kakuna,     Beedrill,   None,       Dodrio,     Primeape,   Dugtrio,    Venomoth,   // 0x70
+    Dewgong,    None,       None,       Caterpie,   Metapod,    Butterfree, Machamp,    None,       // 0x78
+    Golduck,    Hypno,      Golbat,     Mewtwo,     Snorlax,    Magikarp,   None,       None,       // 0x80
+    Muk,        None,       Kingler,    Cloyster,   None,       Electrode,  Clefable,   Weezing,    // 0x88
+    Persian,    Marowak,    None,       Haunter,    Abra,       Alakazam,   Pidgeotto,  Pidgeot,    // 0x90
+    Starmie,    Bulbasaur,  Venusaur,   Tentacruel, None,       Goldeen,    Seaking,    None,       // 0x98
+    None,       None,       None,       Ponyta,     Rapidash,   Rattata,    Raticate,   Nidorino,   // 0xA0
+    Nidorina,   Geodude,    Porygon,    Aerodactyl, None,       Magnemite,  None,       None,       // 0xA8
+    Charmander, Squirtle,   Charmeleon, Wartortle,  Charizard,  None,       None,       None,       // 0xB0
+    None,       Oddish,     Gloom,      Vileplume,  Bellsprout, Weepinbell, Victreebel,             // 0xB8
+];
+
+/// National Pokédex number to Generation 1 internal index, built from the inverse table
+const NATIONAL_TO_GENERATION1_INDEX: [u8; 152] = {
+    let mut result = [0u8; 152];
+
+    let mut index = 1;
+    while index < GENERATION1_INDEX_TO_NATIONAL.len() {
+        let species = GENERATION1_INDEX_TO_NATIONAL[index] as usize;
+        if species != 0 {
+            result[species] = index as u8;
+        }
+
+        index += 1;
+    }
+
+    result
+};
+
+/// Converts a Generation 1 internal index to the species it represents.
+///
+/// Index `0` is used by the games as an empty slot marker, and returns `SpeciesID::None`.
+pub fn species_from_generation1_index(index: u8) -> Result<SpeciesID, InvalidSpeciesIndex> {
+    if index == 0 {
+        return Ok(SpeciesID::None);
+    }
+
+    if index > MAX_GENERATION1_INDEX {
+        return Err(InvalidSpeciesIndex::OutOfRange(index as u16));
+    }
+
+    match GENERATION1_INDEX_TO_NATIONAL[index as usize] {
+        SpeciesID::None => Err(InvalidSpeciesIndex::MissingNo(index)),
+        species => Ok(species),
+    }
+}
+
+/// Converts a species to the internal index used by Generation 1 games.
+///
+/// Returns `None` for species that do not exist in Generation 1.
+pub fn generation1_index_from_species(species: SpeciesID) -> Option<u8> {
+    match NATIONAL_TO_GENERATION1_INDEX.get(species as usize) {
+        Some(0) | Option::None => Option::None,
+        Some(index) => Some(*index),
+    }
+}
+
+// endregion
+
+// region: Generation 3
+
+/// First internal index used by the Hoenn species, the ones before are the `?` unused slots
+const GENERATION3_FIRST_HOENN_INDEX: u16 = 277;
+/// Last species that shares the same value in the internal index and the National Pokédex (Celebi)
+const GENERATION3_LAST_SHARED_INDEX: u16 = 251;
+
+/// Hoenn species in the order they are stored internally by Generation 3 games, starting at index `277`
+const GENERATION3_HOENN_INTERNAL_ORDER: [SpeciesID; MAX_SPECIES_INDEX - GENERATION3_FIRST_HOENN_INDEX as usize] = [
+    Treecko,    Grovyle,    Sceptile,   Torchic,    Combusken,  Blaziken,   Mudkip,     Marshtomp,  // 277
+    Swampert,   Poochyena,  Mightyena,  Zigzagoon,  Linoone,    Wurmple,    Silcoon,    Beautifly,  // 285
+    Cascoon,    Dustox,     Lotad,      Lombre,     Ludicolo,   Seedot,     Nuzleaf,    Shiftry,    // 293
+    Nincada,    Ninjask,    Shedinja,   Taillow,    Swellow,    Shroomish,  Breloom,    Spinda,     // 301
+    Wingull,    Pelipper,   Surskit,    Masquerain, Wailmer,    Wailord,    Skitty,     Delcatty,   // 309
+    Kecleon,    Baltoy,     Claydol,    Nosepass,   Torkoal,    Sableye,    Barboach,   Whiscash,   // 317
+    Luvdisc,    Corphish,   Crawdaunt,  Feebas,     Milotic,    Carvanha,   Sharpedo,   Trapinch,   // 325
+    Vibrava,    Flygon,     Makuhita,   Hariyama,   Electrike,  Manectric,  Numel,      Camerupt,   // 333
+    Spheal,     Sealeo,     Walrein,    Cacnea,     Cacturne,   Snorunt,    Glalie,     Lunatone,   // 341
+    Solrock,    Azurill,    Spoink,     Grumpig,    Plusle,     Minun,      Mawile,     Meditite,   // 349
+    Medicham,   Swablu,     Altaria,    Wynaut,     Duskull,    Dusclops,   Roselia,    Slakoth,    // 357
+    Vigoroth,   Slaking,    Gulpin,     Swalot,     Tropius,    Whismur,    Loudred,    Exploud,    // 365
+    Clamperl,   Huntail,    Gorebyss,   Absol,      Shuppet,    Banette,    Seviper,    Zangoose,   // 373
+    Relicanth,  Aron,       Lairon,     Aggron,     Castform,   Volbeat,    Illumise,   Lileep,     // 381
+    Cradily,    Anorith,    Armaldo,    Ralts,      Kirlia,     Gardevoir,  Bagon,      Shelgon,    // 389
+    Salamence,  Beldum,     Metang,     Metagross,  Regirock,   Regice,     Registeel,  Kyogre,     // 397
+    Groudon,    Rayquaza,   Latias,     Latios,     Jirachi,    Deoxys,     Chimecho,               // 405
+];
+
+/// National Pokédex number to Generation 3 internal index for the Hoenn species, starting at Treecko (`252`)
+const NATIONAL_TO_GENERATION3_HOENN_INDEX: [u16; (MAX_SPECIES_ID - GENERATION3_LAST_SHARED_INDEX) as usize] = {
+    let mut result = [0u16; (MAX_SPECIES_ID - GENERATION3_LAST_SHARED_INDEX) as usize];
+
+    let mut i = 0;
+    while i < GENERATION3_HOENN_INTERNAL_ORDER.len() {
+        let species = GENERATION3_HOENN_INTERNAL_ORDER[i] as usize;
+        result[species - GENERATION3_LAST_SHARED_INDEX as usize - 1] = GENERATION3_FIRST_HOENN_INDEX + i as u16;
+
+        i += 1;
+    }
+
+    result
+};
+
+/// Converts a Generation 3 internal index to the species it represents.
+///
+/// Index `0` is used by the games as an empty slot marker, and returns `SpeciesID::None`.
+pub fn species_from_generation3_index(index: u16) -> Result<SpeciesID, InvalidSpeciesIndex> {
+    if index as usize >= MAX_SPECIES_INDEX {
+        return Err(InvalidSpeciesIndex::OutOfRange(index));
+    }
+
+    if index <= GENERATION3_LAST_SHARED_INDEX {
+        // Kanto and Johto species share the same value
+        return SpeciesID::from_national_id(index).ok_or(InvalidSpeciesIndex::OutOfRange(index));
+    }
+
+    if index < GENERATION3_FIRST_HOENN_INDEX {
+        return Err(InvalidSpeciesIndex::Unused(index));
+    }
+
+    Ok(GENERATION3_HOENN_INTERNAL_ORDER[(index - GENERATION3_FIRST_HOENN_INDEX) as usize])
+}
+
+/// Converts a species to the internal index used by Generation 3 games.
+///
+/// Returns `None` for species that do not exist in Generation 3.
+pub fn generation3_index_from_species(species: SpeciesID) -> Option<u16> {
+    let national_id = species.national_id();
+
+    if national_id <= GENERATION3_LAST_SHARED_INDEX {
+        return Some(national_id);
+    }
+
+    if national_id > MAX_SPECIES_ID {
+        return Option::None;
+    }
+
+    Some(NATIONAL_TO_GENERATION3_HOENN_INDEX[(national_id - GENERATION3_LAST_SHARED_INDEX - 1) as usize])
+}
+
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::species_id::SpeciesID,
+        legality::tables::generation3::MAX_SPECIES_ID,
+        species::conversion::{
+            generation1_index_from_species, generation3_index_from_species,
+            species_from_generation1_index, species_from_generation3_index, InvalidSpeciesIndex,
+            MAX_GENERATION1_INDEX,
+        },
+    };
+
+    #[test]
+    fn generation1_round_trip_all_species() {
+        (1..=151).for_each(|national_id| {
+            let species = SpeciesID::from_national_id(national_id).unwrap();
+            let index = generation1_index_from_species(species).unwrap();
+
+            assert_eq!(species_from_generation1_index(index), Ok(species));
+        });
+    }
+
+    #[test]
+    fn generation1_known_indexes() {
+        assert_eq!(species_from_generation1_index(0x01), Ok(SpeciesID::Rhydon));
+        assert_eq!(species_from_generation1_index(0x99), Ok(SpeciesID::Bulbasaur));
+        assert_eq!(species_from_generation1_index(0xBE), Ok(SpeciesID::Victreebel));
+        assert_eq!(generation1_index_from_species(SpeciesID::Chikorita), None);
+    }
+
+    #[test]
+    fn generation1_missingno() {
+        assert_eq!(species_from_generation1_index(0x1F), Err(InvalidSpeciesIndex::MissingNo(0x1F)));
+        assert_eq!(species_from_generation1_index(0xBF), Err(InvalidSpeciesIndex::OutOfRange(0xBF)));
+
+        let missingno_count = (1..=MAX_GENERATION1_INDEX)
+            .filter(|index| species_from_generation1_index(*index).is_err())
+            .count();
+        assert_eq!(missingno_count, MAX_GENERATION1_INDEX as usize - 151);
+    }
+
+    #[test]
+    fn generation3_round_trip_all_species() {
+        (1..=MAX_SPECIES_ID).for_each(|national_id| {
+            let species = SpeciesID::from_national_id(national_id).unwrap();
+            let index = generation3_index_from_species(species).unwrap();
+
+            assert_eq!(species_from_generation3_index(index), Ok(species));
+        });
+    }
+
+    #[test]
+    fn generation3_known_indexes() {
+        assert_eq!(species_from_generation3_index(251), Ok(SpeciesID::Celebi));
+        assert_eq!(species_from_generation3_index(277), Ok(SpeciesID::Treecko));
+        assert_eq!(species_from_generation3_index(411), Ok(SpeciesID::Chimecho));
+        assert_eq!(generation3_index_from_species(SpeciesID::Chimecho), Some(411));
+        assert_eq!(generation3_index_from_species(SpeciesID::Turtwig), None);
+    }
+
+    #[test]
+    fn generation3_unused_indexes() {
+        assert_eq!(species_from_generation3_index(252), Err(InvalidSpeciesIndex::Unused(252)));
+        assert_eq!(species_from_generation3_index(276), Err(InvalidSpeciesIndex::Unused(276)));
+        assert_eq!(species_from_generation3_index(412), Err(InvalidSpeciesIndex::OutOfRange(412)));
+    }
+}
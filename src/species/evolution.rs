@@ -0,0 +1,130 @@
+pub mod generation1;
+pub mod generation3;
+
+use crate::game::enums::species_id::SpeciesID;
+
+/// Friendship needed by the friendship evolutions
+pub const FRIENDSHIP_EVOLUTION_THRESHOLD: u8 = 220;
+
+/// Condition to evolve into another species
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionMethod {
+    /// Leveling up to the level
+    Level(u8),
+    /// Using the item
+    UseItem(u16),
+    Trade,
+    /// Trading while holding the item, which is consumed
+    TradeHeldItem(u16),
+    /// Leveling up with high friendship
+    Friendship,
+    FriendshipDay,
+    FriendshipNight,
+    /// Leveling up to the level with more Attack than Defense
+    LevelAttackHigher(u8),
+    /// Leveling up to the level with more Defense than Attack
+    LevelDefenseHigher(u8),
+    LevelAttackDefenseEqual(u8),
+    /// Leveling up to the level when the upper half of the PID picks Silcoon
+    LevelSilcoon(u8),
+    /// Leveling up to the level when the upper half of the PID picks Cascoon
+    LevelCascoon(u8),
+    /// Leveling up to the level, also creating Shedinja when possible
+    LevelNinjask(u8),
+    /// Created alongside Ninjask when there is a free party slot
+    LevelShedinja(u8),
+    /// Leveling up with the Beauty condition at least the value
+    Beauty(u8),
+}
+
+impl EvolutionMethod {
+    /// Gets the level needed by the method, if any
+    pub fn level(&self) -> Option<u8> {
+        match *self {
+            EvolutionMethod::Level(level)
+            | EvolutionMethod::LevelAttackHigher(level)
+            | EvolutionMethod::LevelDefenseHigher(level)
+            | EvolutionMethod::LevelAttackDefenseEqual(level)
+            | EvolutionMethod::LevelSilcoon(level)
+            | EvolutionMethod::LevelCascoon(level)
+            | EvolutionMethod::LevelNinjask(level)
+            | EvolutionMethod::LevelShedinja(level) => Some(level),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evolution {
+    pub from: SpeciesID,
+    pub to: SpeciesID,
+    pub method: EvolutionMethod,
+}
+
+/// Action that can make a Pokémon evolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvolutionTrigger {
+    LevelUp {
+        /// Time of the day in the games with a clock, friendship evolutions depend on it
+        daytime: bool,
+    },
+    UseItem(u16),
+    Trade,
+}
+
+/// Gets the evolutions of the species.
+///
+/// # Arguments
+/// * `table` - Evolution table of a generation
+/// * `species` - Species evolving
+pub fn evolutions_from(table: &'static [Evolution], species: SpeciesID) -> impl Iterator<Item = &'static Evolution> {
+    table.iter().filter(move |evolution| evolution.from == species)
+}
+
+/// Gets the evolution into the species, if it has a pre-evolution
+pub fn evolution_into(table: &'static [Evolution], species: SpeciesID) -> Option<&'static Evolution> {
+    table.iter().find(|evolution| evolution.to == species)
+}
+
+/// Gets the species and every pre-evolution, starting from the species
+pub fn evolution_chain(table: &'static [Evolution], species: SpeciesID) -> Vec<SpeciesID> {
+    let mut chain = vec![species];
+
+    while let Some(evolution) = evolution_into(table, *chain.last().unwrap()) {
+        chain.push(evolution.from);
+    }
+
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::species_id::SpeciesID,
+        species::evolution::{evolution_chain, evolutions_from, generation1, generation3, EvolutionMethod},
+    };
+
+    #[test]
+    fn chains() {
+        assert_eq!(
+            evolution_chain(generation3::EVOLUTIONS, SpeciesID::Pikachu),
+            vec![SpeciesID::Pikachu, SpeciesID::Pichu]
+        );
+        assert_eq!(
+            evolution_chain(generation1::EVOLUTIONS, SpeciesID::Raichu),
+            vec![SpeciesID::Raichu, SpeciesID::Pikachu]
+        );
+        assert_eq!(evolution_chain(generation3::EVOLUTIONS, SpeciesID::Mew), vec![SpeciesID::Mew]);
+    }
+
+    #[test]
+    fn generation1_has_no_later_evolutions() {
+        assert_eq!(evolutions_from(generation1::EVOLUTIONS, SpeciesID::Eevee).count(), 3);
+        assert_eq!(evolutions_from(generation3::EVOLUTIONS, SpeciesID::Eevee).count(), 5);
+        assert!(evolutions_from(generation1::EVOLUTIONS, SpeciesID::Onix).next().is_none());
+        assert_eq!(
+            evolutions_from(generation3::EVOLUTIONS, SpeciesID::Onix).next().unwrap().method,
+            EvolutionMethod::TradeHeldItem(generation3::METAL_COAT)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Evolutions of Red/Blue/Yellow, items use the Generation 1 item IDs.
+
+use crate::game::enums::species_id::SpeciesID;
+
+use super::{Evolution, EvolutionMethod::{self, *}};
+
+// region: Items
+pub const MOON_STONE: u16 = 10;
+pub const FIRE_STONE: u16 = 32;
+pub const THUNDER_STONE: u16 = 33;
+pub const WATER_STONE: u16 = 34;
+pub const LEAF_STONE: u16 = 47;
+// endregion
+
+const fn evolution(from: SpeciesID, to: SpeciesID, method: EvolutionMethod) -> Evolution {
+    Evolution { from, to, method }
+}
+
+/// Evolutions sorted by the species they evolve from
+pub const EVOLUTIONS: &[Evolution] = &[
+    evolution(SpeciesID::Bulbasaur, SpeciesID::Ivysaur, Level(16)),
+    evolution(SpeciesID::Ivysaur, SpeciesID::Venusaur, Level(32)),
+    evolution(SpeciesID::Charmander, SpeciesID::Charmeleon, Level(16)),
+    evolution(SpeciesID::Charmeleon, SpeciesID::Charizard, Level(36)),
+    evolution(SpeciesID::Squirtle, SpeciesID::Wartortle, Level(16)),
+    evolution(SpeciesID::Wartortle, SpeciesID::Blastoise, Level(36)),
+    evolution(SpeciesID::Caterpie, SpeciesID::Metapod, Level(7)),
+    evolution(SpeciesID::Metapod, SpeciesID::Butterfree, Level(10)),
+    evolution(SpeciesID::Weedle, SpeciesID::Kakuna, Level(7)),
+    evolution(SpeciesID::Kakuna, SpeciesID::Beedrill, Level(10)),
+    evolution(SpeciesID::Pidgey, SpeciesID::Pidgeotto, Level(18)),
+    evolution(SpeciesID::Pidgeotto, SpeciesID::Pidgeot, Level(36)),
+    evolution(SpeciesID::Rattata, SpeciesID::Raticate, Level(20)),
+    evolution(SpeciesID::Spearow, SpeciesID::Fearow, Level(20)),
+    evolution(SpeciesID::Ekans, SpeciesID::Arbok, Level(22)),
+    evolution(SpeciesID::Pikachu, SpeciesID::Raichu, UseItem(THUNDER_STONE)),
+    evolution(SpeciesID::Sandshrew, SpeciesID::Sandslash, Level(22)),
+    evolution(SpeciesID::NidoranF, SpeciesID::Nidorina, Level(16)),
+    evolution(SpeciesID::Nidorina, SpeciesID::Nidoqueen, UseItem(MOON_STONE)),
+    evolution(SpeciesID::NidoranM, SpeciesID::Nidorino, Level(16)),
+    evolution(SpeciesID::Nidorino, SpeciesID::Nidoking, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Clefairy, SpeciesID::Clefable, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Vulpix, SpeciesID::Ninetales, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Jigglypuff, SpeciesID::Wigglytuff, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Zubat, SpeciesID::Golbat, Level(22)),
+    evolution(SpeciesID::Oddish, SpeciesID::Gloom, Level(21)),
+    evolution(SpeciesID::Gloom, SpeciesID::Vileplume, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Paras, SpeciesID::Parasect, Level(24)),
+    evolution(SpeciesID::Venonat, SpeciesID::Venomoth, Level(31)),
+    evolution(SpeciesID::Diglett, SpeciesID::Dugtrio, Level(26)),
+    evolution(SpeciesID::Meowth, SpeciesID::Persian, Level(28)),
+    evolution(SpeciesID::Psyduck, SpeciesID::Golduck, Level(33)),
+    evolution(SpeciesID::Mankey, SpeciesID::Primeape, Level(28)),
+    evolution(SpeciesID::Growlithe, SpeciesID::Arcanine, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Poliwag, SpeciesID::Poliwhirl, Level(25)),
+    evolution(SpeciesID::Poliwhirl, SpeciesID::Poliwrath, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Abra, SpeciesID::Kadabra, Level(16)),
+    evolution(SpeciesID::Kadabra, SpeciesID::Alakazam, Trade),
+    evolution(SpeciesID::Machop, SpeciesID::Machoke, Level(28)),
+    evolution(SpeciesID::Machoke, SpeciesID::Machamp, Trade),
+    evolution(SpeciesID::Bellsprout, SpeciesID::Weepinbell, Level(21)),
+    evolution(SpeciesID::Weepinbell, SpeciesID::Victreebel, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Tentacool, SpeciesID::Tentacruel, Level(30)),
+    evolution(SpeciesID::Geodude, SpeciesID::Graveler, Level(25)),
+    evolution(SpeciesID::Graveler, SpeciesID::Golem, Trade),
+    evolution(SpeciesID::Ponyta, SpeciesID::Rapidash, Level(40)),
+    evolution(SpeciesID::Slowpoke, SpeciesID::Slowbro, Level(37)),
+    evolution(SpeciesID::Magnemite, SpeciesID::Magneton, Level(30)),
+    evolution(SpeciesID::Doduo, SpeciesID::Dodrio, Level(31)),
+    evolution(SpeciesID::Seel, SpeciesID::Dewgong, Level(34)),
+    evolution(SpeciesID::Grimer, SpeciesID::Muk, Level(38)),
+    evolution(SpeciesID::Shellder, SpeciesID::Cloyster, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Gastly, SpeciesID::Haunter, Level(25)),
+    evolution(SpeciesID::Haunter, SpeciesID::Gengar, Trade),
+    evolution(SpeciesID::Drowzee, SpeciesID::Hypno, Level(26)),
+    evolution(SpeciesID::Krabby, SpeciesID::Kingler, Level(28)),
+    evolution(SpeciesID::Voltorb, SpeciesID::Electrode, Level(30)),
+    evolution(SpeciesID::Exeggcute, SpeciesID::Exeggutor, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Cubone, SpeciesID::Marowak, Level(28)),
+    evolution(SpeciesID::Koffing, SpeciesID::Weezing, Level(35)),
+    evolution(SpeciesID::Rhyhorn, SpeciesID::Rhydon, Level(42)),
+    evolution(SpeciesID::Horsea, SpeciesID::Seadra, Level(32)),
+    evolution(SpeciesID::Goldeen, SpeciesID::Seaking, Level(33)),
+    evolution(SpeciesID::Staryu, SpeciesID::Starmie, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Magikarp, SpeciesID::Gyarados, Level(20)),
+    evolution(SpeciesID::Eevee, SpeciesID::Vaporeon, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Eevee, SpeciesID::Jolteon, UseItem(THUNDER_STONE)),
+    evolution(SpeciesID::Eevee, SpeciesID::Flareon, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Omanyte, SpeciesID::Omastar, Level(40)),
+    evolution(SpeciesID::Kabuto, SpeciesID::Kabutops, Level(40)),
+    evolution(SpeciesID::Dratini, SpeciesID::Dragonair, Level(30)),
+    evolution(SpeciesID::Dragonair, SpeciesID::Dragonite, Level(55)),
+];
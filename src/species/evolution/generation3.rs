@@ -0,0 +1,213 @@
+//! Evolutions of the Generation 3 games, which include every Generation 1 and 2 evolution.
+
+use crate::game::enums::species_id::SpeciesID;
+
+use super::{Evolution, EvolutionMethod::{self, *}};
+
+// region: Items
+pub const SUN_STONE: u16 = 93;
+pub const MOON_STONE: u16 = 94;
+pub const FIRE_STONE: u16 = 95;
+pub const THUNDER_STONE: u16 = 96;
+pub const WATER_STONE: u16 = 97;
+pub const LEAF_STONE: u16 = 98;
+pub const KINGS_ROCK: u16 = 187;
+pub const DEEP_SEA_TOOTH: u16 = 192;
+pub const DEEP_SEA_SCALE: u16 = 193;
+/// Held item that prevents evolving
+pub const EVERSTONE: u16 = 195;
+pub const METAL_COAT: u16 = 199;
+pub const DRAGON_SCALE: u16 = 201;
+pub const UP_GRADE: u16 = 218;
+// endregion
+
+const fn evolution(from: SpeciesID, to: SpeciesID, method: EvolutionMethod) -> Evolution {
+    Evolution { from, to, method }
+}
+
+/// Evolutions sorted by the species they evolve from
+pub const EVOLUTIONS: &[Evolution] = &[
+    evolution(SpeciesID::Bulbasaur, SpeciesID::Ivysaur, Level(16)),
+    evolution(SpeciesID::Ivysaur, SpeciesID::Venusaur, Level(32)),
+    evolution(SpeciesID::Charmander, SpeciesID::Charmeleon, Level(16)),
+    evolution(SpeciesID::Charmeleon, SpeciesID::Charizard, Level(36)),
+    evolution(SpeciesID::Squirtle, SpeciesID::Wartortle, Level(16)),
+    evolution(SpeciesID::Wartortle, SpeciesID::Blastoise, Level(36)),
+    evolution(SpeciesID::Caterpie, SpeciesID::Metapod, Level(7)),
+    evolution(SpeciesID::Metapod, SpeciesID::Butterfree, Level(10)),
+    evolution(SpeciesID::Weedle, SpeciesID::Kakuna, Level(7)),
+    evolution(SpeciesID::Kakuna, SpeciesID::Beedrill, Level(10)),
+    evolution(SpeciesID::Pidgey, SpeciesID::Pidgeotto, Level(18)),
+    evolution(SpeciesID::Pidgeotto, SpeciesID::Pidgeot, Level(36)),
+    evolution(SpeciesID::Rattata, SpeciesID::Raticate, Level(20)),
+    evolution(SpeciesID::Spearow, SpeciesID::Fearow, Level(20)),
+    evolution(SpeciesID::Ekans, SpeciesID::Arbok, Level(22)),
+    evolution(SpeciesID::Pikachu, SpeciesID::Raichu, UseItem(THUNDER_STONE)),
+    evolution(SpeciesID::Sandshrew, SpeciesID::Sandslash, Level(22)),
+    evolution(SpeciesID::NidoranF, SpeciesID::Nidorina, Level(16)),
+    evolution(SpeciesID::Nidorina, SpeciesID::Nidoqueen, UseItem(MOON_STONE)),
+    evolution(SpeciesID::NidoranM, SpeciesID::Nidorino, Level(16)),
+    evolution(SpeciesID::Nidorino, SpeciesID::Nidoking, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Clefairy, SpeciesID::Clefable, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Vulpix, SpeciesID::Ninetales, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Jigglypuff, SpeciesID::Wigglytuff, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Zubat, SpeciesID::Golbat, Level(22)),
+    evolution(SpeciesID::Golbat, SpeciesID::Crobat, Friendship),
+    evolution(SpeciesID::Oddish, SpeciesID::Gloom, Level(21)),
+    evolution(SpeciesID::Gloom, SpeciesID::Vileplume, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Gloom, SpeciesID::Bellossom, UseItem(SUN_STONE)),
+    evolution(SpeciesID::Paras, SpeciesID::Parasect, Level(24)),
+    evolution(SpeciesID::Venonat, SpeciesID::Venomoth, Level(31)),
+    evolution(SpeciesID::Diglett, SpeciesID::Dugtrio, Level(26)),
+    evolution(SpeciesID::Meowth, SpeciesID::Persian, Level(28)),
+    evolution(SpeciesID::Psyduck, SpeciesID::Golduck, Level(33)),
+    evolution(SpeciesID::Mankey, SpeciesID::Primeape, Level(28)),
+    evolution(SpeciesID::Growlithe, SpeciesID::Arcanine, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Poliwag, SpeciesID::Poliwhirl, Level(25)),
+    evolution(SpeciesID::Poliwhirl, SpeciesID::Poliwrath, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Poliwhirl, SpeciesID::Politoed, TradeHeldItem(KINGS_ROCK)),
+    evolution(SpeciesID::Abra, SpeciesID::Kadabra, Level(16)),
+    evolution(SpeciesID::Kadabra, SpeciesID::Alakazam, Trade),
+    evolution(SpeciesID::Machop, SpeciesID::Machoke, Level(28)),
+    evolution(SpeciesID::Machoke, SpeciesID::Machamp, Trade),
+    evolution(SpeciesID::Bellsprout, SpeciesID::Weepinbell, Level(21)),
+    evolution(SpeciesID::Weepinbell, SpeciesID::Victreebel, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Tentacool, SpeciesID::Tentacruel, Level(30)),
+    evolution(SpeciesID::Geodude, SpeciesID::Graveler, Level(25)),
+    evolution(SpeciesID::Graveler, SpeciesID::Golem, Trade),
+    evolution(SpeciesID::Ponyta, SpeciesID::Rapidash, Level(40)),
+    evolution(SpeciesID::Slowpoke, SpeciesID::Slowbro, Level(37)),
+    evolution(SpeciesID::Slowpoke, SpeciesID::Slowking, TradeHeldItem(KINGS_ROCK)),
+    evolution(SpeciesID::Magnemite, SpeciesID::Magneton, Level(30)),
+    evolution(SpeciesID::Doduo, SpeciesID::Dodrio, Level(31)),
+    evolution(SpeciesID::Seel, SpeciesID::Dewgong, Level(34)),
+    evolution(SpeciesID::Grimer, SpeciesID::Muk, Level(38)),
+    evolution(SpeciesID::Shellder, SpeciesID::Cloyster, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Gastly, SpeciesID::Haunter, Level(25)),
+    evolution(SpeciesID::Haunter, SpeciesID::Gengar, Trade),
+    evolution(SpeciesID::Onix, SpeciesID::Steelix, TradeHeldItem(METAL_COAT)),
+    evolution(SpeciesID::Drowzee, SpeciesID::Hypno, Level(26)),
+    evolution(SpeciesID::Krabby, SpeciesID::Kingler, Level(28)),
+    evolution(SpeciesID::Voltorb, SpeciesID::Electrode, Level(30)),
+    evolution(SpeciesID::Exeggcute, SpeciesID::Exeggutor, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Cubone, SpeciesID::Marowak, Level(28)),
+    evolution(SpeciesID::Koffing, SpeciesID::Weezing, Level(35)),
+    evolution(SpeciesID::Rhyhorn, SpeciesID::Rhydon, Level(42)),
+    evolution(SpeciesID::Chansey, SpeciesID::Blissey, Friendship),
+    evolution(SpeciesID::Horsea, SpeciesID::Seadra, Level(32)),
+    evolution(SpeciesID::Seadra, SpeciesID::Kingdra, TradeHeldItem(DRAGON_SCALE)),
+    evolution(SpeciesID::Goldeen, SpeciesID::Seaking, Level(33)),
+    evolution(SpeciesID::Staryu, SpeciesID::Starmie, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Scyther, SpeciesID::Scizor, TradeHeldItem(METAL_COAT)),
+    evolution(SpeciesID::Magikarp, SpeciesID::Gyarados, Level(20)),
+    evolution(SpeciesID::Eevee, SpeciesID::Vaporeon, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Eevee, SpeciesID::Jolteon, UseItem(THUNDER_STONE)),
+    evolution(SpeciesID::Eevee, SpeciesID::Flareon, UseItem(FIRE_STONE)),
+    evolution(SpeciesID::Eevee, SpeciesID::Espeon, FriendshipDay),
+    evolution(SpeciesID::Eevee, SpeciesID::Umbreon, FriendshipNight),
+    evolution(SpeciesID::Porygon, SpeciesID::Porygon2, TradeHeldItem(UP_GRADE)),
+    evolution(SpeciesID::Omanyte, SpeciesID::Omastar, Level(40)),
+    evolution(SpeciesID::Kabuto, SpeciesID::Kabutops, Level(40)),
+    evolution(SpeciesID::Dratini, SpeciesID::Dragonair, Level(30)),
+    evolution(SpeciesID::Dragonair, SpeciesID::Dragonite, Level(55)),
+    evolution(SpeciesID::Chikorita, SpeciesID::Bayleef, Level(16)),
+    evolution(SpeciesID::Bayleef, SpeciesID::Meganium, Level(32)),
+    evolution(SpeciesID::Cyndaquil, SpeciesID::Quilava, Level(14)),
+    evolution(SpeciesID::Quilava, SpeciesID::Typhlosion, Level(36)),
+    evolution(SpeciesID::Totodile, SpeciesID::Croconaw, Level(18)),
+    evolution(SpeciesID::Croconaw, SpeciesID::Feraligatr, Level(30)),
+    evolution(SpeciesID::Sentret, SpeciesID::Furret, Level(15)),
+    evolution(SpeciesID::Hoothoot, SpeciesID::Noctowl, Level(20)),
+    evolution(SpeciesID::Ledyba, SpeciesID::Ledian, Level(18)),
+    evolution(SpeciesID::Spinarak, SpeciesID::Ariados, Level(22)),
+    evolution(SpeciesID::Chinchou, SpeciesID::Lanturn, Level(27)),
+    evolution(SpeciesID::Pichu, SpeciesID::Pikachu, Friendship),
+    evolution(SpeciesID::Cleffa, SpeciesID::Clefairy, Friendship),
+    evolution(SpeciesID::Igglybuff, SpeciesID::Jigglypuff, Friendship),
+    evolution(SpeciesID::Togepi, SpeciesID::Togetic, Friendship),
+    evolution(SpeciesID::Natu, SpeciesID::Xatu, Level(25)),
+    evolution(SpeciesID::Mareep, SpeciesID::Flaaffy, Level(15)),
+    evolution(SpeciesID::Flaaffy, SpeciesID::Ampharos, Level(30)),
+    evolution(SpeciesID::Marill, SpeciesID::Azumarill, Level(18)),
+    evolution(SpeciesID::Hoppip, SpeciesID::Skiploom, Level(18)),
+    evolution(SpeciesID::Skiploom, SpeciesID::Jumpluff, Level(27)),
+    evolution(SpeciesID::Sunkern, SpeciesID::Sunflora, UseItem(SUN_STONE)),
+    evolution(SpeciesID::Wooper, SpeciesID::Quagsire, Level(20)),
+    evolution(SpeciesID::Snubbull, SpeciesID::Granbull, Level(23)),
+    evolution(SpeciesID::Teddiursa, SpeciesID::Ursaring, Level(30)),
+    evolution(SpeciesID::Slugma, SpeciesID::Magcargo, Level(38)),
+    evolution(SpeciesID::Swinub, SpeciesID::Piloswine, Level(33)),
+    evolution(SpeciesID::Remoraid, SpeciesID::Octillery, Level(25)),
+    evolution(SpeciesID::Houndour, SpeciesID::Houndoom, Level(24)),
+    evolution(SpeciesID::Phanpy, SpeciesID::Donphan, Level(25)),
+    evolution(SpeciesID::Tyrogue, SpeciesID::Hitmonlee, LevelAttackHigher(20)),
+    evolution(SpeciesID::Tyrogue, SpeciesID::Hitmonchan, LevelDefenseHigher(20)),
+    evolution(SpeciesID::Tyrogue, SpeciesID::Hitmontop, LevelAttackDefenseEqual(20)),
+    evolution(SpeciesID::Smoochum, SpeciesID::Jynx, Level(30)),
+    evolution(SpeciesID::Elekid, SpeciesID::Electabuzz, Level(30)),
+    evolution(SpeciesID::Magby, SpeciesID::Magmar, Level(30)),
+    evolution(SpeciesID::Larvitar, SpeciesID::Pupitar, Level(30)),
+    evolution(SpeciesID::Pupitar, SpeciesID::Tyranitar, Level(55)),
+    evolution(SpeciesID::Treecko, SpeciesID::Grovyle, Level(16)),
+    evolution(SpeciesID::Grovyle, SpeciesID::Sceptile, Level(36)),
+    evolution(SpeciesID::Torchic, SpeciesID::Combusken, Level(16)),
+    evolution(SpeciesID::Combusken, SpeciesID::Blaziken, Level(36)),
+    evolution(SpeciesID::Mudkip, SpeciesID::Marshtomp, Level(16)),
+    evolution(SpeciesID::Marshtomp, SpeciesID::Swampert, Level(36)),
+    evolution(SpeciesID::Poochyena, SpeciesID::Mightyena, Level(18)),
+    evolution(SpeciesID::Zigzagoon, SpeciesID::Linoone, Level(20)),
+    evolution(SpeciesID::Wurmple, SpeciesID::Silcoon, LevelSilcoon(7)),
+    evolution(SpeciesID::Wurmple, SpeciesID::Cascoon, LevelCascoon(7)),
+    evolution(SpeciesID::Silcoon, SpeciesID::Beautifly, Level(10)),
+    evolution(SpeciesID::Cascoon, SpeciesID::Dustox, Level(10)),
+    evolution(SpeciesID::Lotad, SpeciesID::Lombre, Level(14)),
+    evolution(SpeciesID::Lombre, SpeciesID::Ludicolo, UseItem(WATER_STONE)),
+    evolution(SpeciesID::Seedot, SpeciesID::Nuzleaf, Level(14)),
+    evolution(SpeciesID::Nuzleaf, SpeciesID::Shiftry, UseItem(LEAF_STONE)),
+    evolution(SpeciesID::Taillow, SpeciesID::Swellow, Level(22)),
+    evolution(SpeciesID::Wingull, SpeciesID::Pelipper, Level(25)),
+    evolution(SpeciesID::Ralts, SpeciesID::Kirlia, Level(20)),
+    evolution(SpeciesID::Kirlia, SpeciesID::Gardevoir, Level(30)),
+    evolution(SpeciesID::Surskit, SpeciesID::Masquerain, Level(22)),
+    evolution(SpeciesID::Shroomish, SpeciesID::Breloom, Level(23)),
+    evolution(SpeciesID::Slakoth, SpeciesID::Vigoroth, Level(18)),
+    evolution(SpeciesID::Vigoroth, SpeciesID::Slaking, Level(36)),
+    evolution(SpeciesID::Nincada, SpeciesID::Ninjask, LevelNinjask(20)),
+    evolution(SpeciesID::Nincada, SpeciesID::Shedinja, LevelShedinja(20)),
+    evolution(SpeciesID::Whismur, SpeciesID::Loudred, Level(20)),
+    evolution(SpeciesID::Loudred, SpeciesID::Exploud, Level(40)),
+    evolution(SpeciesID::Makuhita, SpeciesID::Hariyama, Level(24)),
+    evolution(SpeciesID::Azurill, SpeciesID::Marill, Friendship),
+    evolution(SpeciesID::Skitty, SpeciesID::Delcatty, UseItem(MOON_STONE)),
+    evolution(SpeciesID::Aron, SpeciesID::Lairon, Level(32)),
+    evolution(SpeciesID::Lairon, SpeciesID::Aggron, Level(42)),
+    evolution(SpeciesID::Meditite, SpeciesID::Medicham, Level(37)),
+    evolution(SpeciesID::Electrike, SpeciesID::Manectric, Level(26)),
+    evolution(SpeciesID::Gulpin, SpeciesID::Swalot, Level(26)),
+    evolution(SpeciesID::Carvanha, SpeciesID::Sharpedo, Level(30)),
+    evolution(SpeciesID::Wailmer, SpeciesID::Wailord, Level(40)),
+    evolution(SpeciesID::Numel, SpeciesID::Camerupt, Level(33)),
+    evolution(SpeciesID::Spoink, SpeciesID::Grumpig, Level(32)),
+    evolution(SpeciesID::Trapinch, SpeciesID::Vibrava, Level(35)),
+    evolution(SpeciesID::Vibrava, SpeciesID::Flygon, Level(45)),
+    evolution(SpeciesID::Cacnea, SpeciesID::Cacturne, Level(32)),
+    evolution(SpeciesID::Swablu, SpeciesID::Altaria, Level(35)),
+    evolution(SpeciesID::Barboach, SpeciesID::Whiscash, Level(30)),
+    evolution(SpeciesID::Corphish, SpeciesID::Crawdaunt, Level(30)),
+    evolution(SpeciesID::Baltoy, SpeciesID::Claydol, Level(36)),
+    evolution(SpeciesID::Lileep, SpeciesID::Cradily, Level(40)),
+    evolution(SpeciesID::Anorith, SpeciesID::Armaldo, Level(40)),
+    evolution(SpeciesID::Feebas, SpeciesID::Milotic, Beauty(170)),
+    evolution(SpeciesID::Shuppet, SpeciesID::Banette, Level(37)),
+    evolution(SpeciesID::Duskull, SpeciesID::Dusclops, Level(37)),
+    evolution(SpeciesID::Wynaut, SpeciesID::Wobbuffet, Level(15)),
+    evolution(SpeciesID::Snorunt, SpeciesID::Glalie, Level(42)),
+    evolution(SpeciesID::Spheal, SpeciesID::Sealeo, Level(32)),
+    evolution(SpeciesID::Sealeo, SpeciesID::Walrein, Level(44)),
+    evolution(SpeciesID::Clamperl, SpeciesID::Huntail, TradeHeldItem(DEEP_SEA_TOOTH)),
+    evolution(SpeciesID::Clamperl, SpeciesID::Gorebyss, TradeHeldItem(DEEP_SEA_SCALE)),
+    evolution(SpeciesID::Bagon, SpeciesID::Shelgon, Level(30)),
+    evolution(SpeciesID::Shelgon, SpeciesID::Salamence, Level(50)),
+    evolution(SpeciesID::Beldum, SpeciesID::Metang, Level(20)),
+    evolution(SpeciesID::Metang, SpeciesID::Metagross, Level(45)),
+];
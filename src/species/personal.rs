@@ -0,0 +1,66 @@
+pub mod generation3;
+
+use crate::game::enums::{pokemon_type::PokemonType, species_id::SpeciesID};
+
+use self::generation3::PERSONAL_GENERATION_3;
+
+// region: Growth rates
+// Values are the columns of the experience table
+pub const GROWTH_MEDIUM_FAST: u8 = 0;
+pub const GROWTH_ERRATIC: u8 = 1;
+pub const GROWTH_FLUCTUATING: u8 = 2;
+pub const GROWTH_MEDIUM_SLOW: u8 = 3;
+pub const GROWTH_FAST: u8 = 4;
+pub const GROWTH_SLOW: u8 = 5;
+// endregion
+
+/// Fixed data shared by every Pokémon of a species
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersonalInfo {
+    /// Base stats, HP, Attack, Defense, Speed, Special Attack, Special Defense
+    pub base_stats: [u8; 6],
+    /// Both types are the same for single type species
+    pub types: [PokemonType; 2],
+    pub catch_rate: u8,
+    pub gender_ratio: u8,
+    pub growth_rate: u8,
+    /// Ability IDs for the ability bit, the second is `0` when the species only has one ability
+    pub abilities: [u8; 2],
+}
+
+impl PersonalInfo {
+    pub const EMPTY: Self = Self::new([0; 6], [PokemonType::Normal; 2], 0, 0, 0, [0; 2]);
+
+    pub const fn new(base_stats: [u8; 6], types: [PokemonType; 2], catch_rate: u8, gender_ratio: u8, growth_rate: u8, abilities: [u8; 2]) -> Self {
+        Self { base_stats, types, catch_rate, gender_ratio, growth_rate, abilities }
+    }
+
+    /// Gets the personal data of a species in Generation 3, `None` for species that do not exist in it
+    pub fn generation3(species: SpeciesID) -> Option<&'static Self> {
+        match species {
+            SpeciesID::None => None,
+            _ => PERSONAL_GENERATION_3.get(species.national_id() as usize),
+        }
+    }
+
+    /// Gets the types in Red/Blue/Yellow, Magnemite and Magneton only became Steel in Generation 2
+    pub fn generation1_types(&self) -> [PokemonType; 2] {
+        match self.types {
+            [first, PokemonType::Steel] => [first, first],
+            types => types,
+        }
+    }
+
+    pub fn has_two_abilities(&self) -> bool {
+        self.abilities[1] != 0
+    }
+
+    /// Gets the ability selected by the ability bit, species with one ability ignore the bit
+    pub fn ability(&self, ability_number: u16) -> u8 {
+        if ability_number & 1 == 1 && self.has_two_abilities() {
+            self.abilities[1]
+        } else {
+            self.abilities[0]
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use crate::game::enums::pokemon_type::PokemonType::*;
+
+use super::*;
+
+/// Personal data of every species obtainable in Generation 3, indexed by National Pokédex number
+///
+/// Stats are in the order stored by the games: HP, Attack, Defense, Speed, Special Attack, Special Defense
+pub const PERSONAL_GENERATION_3: [PersonalInfo; 387] = [
+    PersonalInfo::EMPTY,
+    /* 001 Bulbasaur */ PersonalInfo::new([45, 49, 49, 45, 65, 65], [Grass, Poison], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 002 Ivysaur */ PersonalInfo::new([60, 62, 63, 60, 80, 80], [Grass, Poison], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 003 Venusaur */ PersonalInfo::new([80, 82, 83, 80, 100, 100], [Grass, Poison], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 004 Charmander */ PersonalInfo::new([39, 52, 43, 65, 60, 50], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 005 Charmeleon */ PersonalInfo::new([58, 64, 58, 80, 80, 65], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 006 Charizard */ PersonalInfo::new([78, 84, 78, 100, 109, 85], [Fire, Flying], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 007 Squirtle */ PersonalInfo::new([44, 48, 65, 43, 50, 64], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 008 Wartortle */ PersonalInfo::new([59, 63, 80, 58, 65, 80], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 009 Blastoise */ PersonalInfo::new([79, 83, 100, 78, 85, 105], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 010 Caterpie */ PersonalInfo::new([45, 30, 35, 45, 20, 20], [Bug, Bug], 255, 127, GROWTH_MEDIUM_FAST, [19, 0]),
+    /* 011 Metapod */ PersonalInfo::new([50, 20, 55, 30, 25, 25], [Bug, Bug], 120, 127, GROWTH_MEDIUM_FAST, [61, 0]),
+    /* 012 Butterfree */ PersonalInfo::new([60, 45, 50, 70, 80, 80], [Bug, Flying], 45, 127, GROWTH_MEDIUM_FAST, [14, 0]),
+    /* 013 Weedle */ PersonalInfo::new([40, 35, 30, 50, 20, 20], [Bug, Poison], 255, 127, GROWTH_MEDIUM_FAST, [19, 0]),
+    /* 014 Kakuna */ PersonalInfo::new([45, 25, 50, 35, 25, 25], [Bug, Poison], 120, 127, GROWTH_MEDIUM_FAST, [61, 0]),
+    /* 015 Beedrill */ PersonalInfo::new([65, 80, 40, 75, 45, 80], [Bug, Poison], 45, 127, GROWTH_MEDIUM_FAST, [68, 0]),
+    /* 016 Pidgey */ PersonalInfo::new([40, 45, 40, 56, 35, 35], [Normal, Flying], 255, 127, GROWTH_MEDIUM_SLOW, [51, 0]),
+    /* 017 Pidgeotto */ PersonalInfo::new([63, 60, 55, 71, 50, 50], [Normal, Flying], 120, 127, GROWTH_MEDIUM_SLOW, [51, 0]),
+    /* 018 Pidgeot */ PersonalInfo::new([83, 80, 75, 91, 70, 70], [Normal, Flying], 45, 127, GROWTH_MEDIUM_SLOW, [51, 0]),
+    /* 019 Rattata */ PersonalInfo::new([30, 56, 35, 72, 25, 35], [Normal, Normal], 255, 127, GROWTH_MEDIUM_FAST, [50, 62]),
+    /* 020 Raticate */ PersonalInfo::new([55, 81, 60, 97, 50, 70], [Normal, Normal], 127, 127, GROWTH_MEDIUM_FAST, [50, 62]),
+    /* 021 Spearow */ PersonalInfo::new([40, 60, 30, 70, 31, 31], [Normal, Flying], 255, 127, GROWTH_MEDIUM_FAST, [51, 0]),
+    /* 022 Fearow */ PersonalInfo::new([65, 90, 65, 100, 61, 61], [Normal, Flying], 90, 127, GROWTH_MEDIUM_FAST, [51, 0]),
+    /* 023 Ekans */ PersonalInfo::new([35, 60, 44, 55, 40, 54], [Poison, Poison], 255, 127, GROWTH_MEDIUM_FAST, [22, 61]),
+    /* 024 Arbok */ PersonalInfo::new([60, 85, 69, 80, 65, 79], [Poison, Poison], 90, 127, GROWTH_MEDIUM_FAST, [22, 61]),
+    /* 025 Pikachu */ PersonalInfo::new([35, 55, 30, 90, 50, 40], [Electric, Electric], 190, 127, GROWTH_MEDIUM_FAST, [9, 0]),
+    /* 026 Raichu */ PersonalInfo::new([60, 90, 55, 100, 90, 80], [Electric, Electric], 75, 127, GROWTH_MEDIUM_FAST, [9, 0]),
+    /* 027 Sandshrew */ PersonalInfo::new([50, 75, 85, 40, 20, 30], [Ground, Ground], 255, 127, GROWTH_MEDIUM_FAST, [8, 0]),
+    /* 028 Sandslash */ PersonalInfo::new([75, 100, 110, 65, 45, 55], [Ground, Ground], 90, 127, GROWTH_MEDIUM_FAST, [8, 0]),
+    /* 029 NidoranF */ PersonalInfo::new([55, 47, 52, 41, 40, 40], [Poison, Poison], 235, 254, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 030 Nidorina */ PersonalInfo::new([70, 62, 67, 56, 55, 55], [Poison, Poison], 120, 254, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 031 Nidoqueen */ PersonalInfo::new([90, 82, 87, 76, 75, 85], [Poison, Ground], 45, 254, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 032 NidoranM */ PersonalInfo::new([46, 57, 40, 50, 40, 40], [Poison, Poison], 235, 0, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 033 Nidorino */ PersonalInfo::new([61, 72, 57, 65, 55, 55], [Poison, Poison], 120, 0, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 034 Nidoking */ PersonalInfo::new([81, 92, 77, 85, 85, 75], [Poison, Ground], 45, 0, GROWTH_MEDIUM_SLOW, [38, 0]),
+    /* 035 Clefairy */ PersonalInfo::new([70, 45, 48, 35, 60, 65], [Normal, Normal], 150, 191, GROWTH_FAST, [56, 0]),
+    /* 036 Clefable */ PersonalInfo::new([95, 70, 73, 60, 85, 90], [Normal, Normal], 25, 191, GROWTH_FAST, [56, 0]),
+    /* 037 Vulpix */ PersonalInfo::new([38, 41, 40, 65, 50, 65], [Fire, Fire], 190, 191, GROWTH_MEDIUM_FAST, [18, 0]),
+    /* 038 Ninetales */ PersonalInfo::new([73, 76, 75, 100, 81, 100], [Fire, Fire], 75, 191, GROWTH_MEDIUM_FAST, [18, 0]),
+    /* 039 Jigglypuff */ PersonalInfo::new([115, 45, 20, 20, 45, 25], [Normal, Normal], 170, 191, GROWTH_FAST, [56, 0]),
+    /* 040 Wigglytuff */ PersonalInfo::new([140, 70, 45, 45, 75, 50], [Normal, Normal], 50, 191, GROWTH_FAST, [56, 0]),
+    /* 041 Zubat */ PersonalInfo::new([40, 45, 35, 55, 30, 40], [Poison, Flying], 255, 127, GROWTH_MEDIUM_FAST, [39, 0]),
+    /* 042 Golbat */ PersonalInfo::new([75, 80, 70, 90, 65, 75], [Poison, Flying], 90, 127, GROWTH_MEDIUM_FAST, [39, 0]),
+    /* 043 Oddish */ PersonalInfo::new([45, 50, 55, 30, 75, 65], [Grass, Poison], 255, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 044 Gloom */ PersonalInfo::new([60, 65, 70, 40, 85, 75], [Grass, Poison], 120, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 045 Vileplume */ PersonalInfo::new([75, 80, 85, 50, 100, 90], [Grass, Poison], 45, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 046 Paras */ PersonalInfo::new([35, 70, 55, 25, 45, 55], [Bug, Grass], 190, 127, GROWTH_MEDIUM_FAST, [27, 0]),
+    /* 047 Parasect */ PersonalInfo::new([60, 95, 80, 30, 60, 80], [Bug, Grass], 75, 127, GROWTH_MEDIUM_FAST, [27, 0]),
+    /* 048 Venonat */ PersonalInfo::new([60, 55, 50, 45, 40, 55], [Bug, Poison], 190, 127, GROWTH_MEDIUM_FAST, [14, 0]),
+    /* 049 Venomoth */ PersonalInfo::new([70, 65, 60, 90, 90, 75], [Bug, Poison], 75, 127, GROWTH_MEDIUM_FAST, [19, 0]),
+    /* 050 Diglett */ PersonalInfo::new([10, 55, 25, 95, 35, 45], [Ground, Ground], 255, 127, GROWTH_MEDIUM_FAST, [8, 71]),
+    /* 051 Dugtrio */ PersonalInfo::new([35, 80, 50, 120, 50, 70], [Ground, Ground], 50, 127, GROWTH_MEDIUM_FAST, [8, 71]),
+    /* 052 Meowth */ PersonalInfo::new([40, 45, 35, 90, 40, 40], [Normal, Normal], 255, 127, GROWTH_MEDIUM_FAST, [53, 0]),
+    /* 053 Persian */ PersonalInfo::new([65, 70, 60, 115, 65, 65], [Normal, Normal], 90, 127, GROWTH_MEDIUM_FAST, [7, 0]),
+    /* 054 Psyduck */ PersonalInfo::new([50, 52, 48, 55, 65, 50], [Water, Water], 190, 127, GROWTH_MEDIUM_FAST, [6, 13]),
+    /* 055 Golduck */ PersonalInfo::new([80, 82, 78, 85, 95, 80], [Water, Water], 75, 127, GROWTH_MEDIUM_FAST, [6, 13]),
+    /* 056 Mankey */ PersonalInfo::new([40, 80, 35, 70, 35, 45], [Fighting, Fighting], 190, 127, GROWTH_MEDIUM_FAST, [72, 0]),
+    /* 057 Primeape */ PersonalInfo::new([65, 105, 60, 95, 60, 70], [Fighting, Fighting], 75, 127, GROWTH_MEDIUM_FAST, [72, 0]),
+    /* 058 Growlithe */ PersonalInfo::new([55, 70, 45, 60, 70, 50], [Fire, Fire], 190, 63, GROWTH_SLOW, [22, 18]),
+    /* 059 Arcanine */ PersonalInfo::new([90, 110, 80, 95, 100, 80], [Fire, Fire], 75, 63, GROWTH_SLOW, [22, 18]),
+    /* 060 Poliwag */ PersonalInfo::new([40, 50, 40, 90, 40, 40], [Water, Water], 255, 127, GROWTH_MEDIUM_SLOW, [11, 6]),
+    /* 061 Poliwhirl */ PersonalInfo::new([65, 65, 65, 90, 50, 50], [Water, Water], 120, 127, GROWTH_MEDIUM_SLOW, [11, 6]),
+    /* 062 Poliwrath */ PersonalInfo::new([90, 85, 95, 70, 70, 90], [Water, Fighting], 45, 127, GROWTH_MEDIUM_SLOW, [11, 6]),
+    /* 063 Abra */ PersonalInfo::new([25, 20, 15, 90, 105, 55], [Psychic, Psychic], 200, 63, GROWTH_MEDIUM_SLOW, [28, 39]),
+    /* 064 Kadabra */ PersonalInfo::new([40, 35, 30, 105, 120, 70], [Psychic, Psychic], 100, 63, GROWTH_MEDIUM_SLOW, [28, 39]),
+    /* 065 Alakazam */ PersonalInfo::new([55, 50, 45, 120, 135, 85], [Psychic, Psychic], 50, 63, GROWTH_MEDIUM_SLOW, [28, 39]),
+    /* 066 Machop */ PersonalInfo::new([70, 80, 50, 35, 35, 35], [Fighting, Fighting], 180, 63, GROWTH_MEDIUM_SLOW, [62, 0]),
+    /* 067 Machoke */ PersonalInfo::new([80, 100, 70, 45, 50, 60], [Fighting, Fighting], 90, 63, GROWTH_MEDIUM_SLOW, [62, 0]),
+    /* 068 Machamp */ PersonalInfo::new([90, 130, 80, 55, 65, 85], [Fighting, Fighting], 45, 63, GROWTH_MEDIUM_SLOW, [62, 0]),
+    /* 069 Bellsprout */ PersonalInfo::new([50, 75, 35, 40, 70, 30], [Grass, Poison], 255, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 070 Weepinbell */ PersonalInfo::new([65, 90, 50, 55, 85, 45], [Grass, Poison], 120, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 071 Victreebel */ PersonalInfo::new([80, 105, 65, 70, 100, 60], [Grass, Poison], 45, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 072 Tentacool */ PersonalInfo::new([40, 40, 35, 70, 50, 100], [Water, Poison], 190, 127, GROWTH_SLOW, [29, 64]),
+    /* 073 Tentacruel */ PersonalInfo::new([80, 70, 65, 100, 80, 120], [Water, Poison], 60, 127, GROWTH_SLOW, [29, 64]),
+    /* 074 Geodude */ PersonalInfo::new([40, 80, 100, 20, 30, 30], [Rock, Ground], 255, 127, GROWTH_MEDIUM_SLOW, [69, 5]),
+    /* 075 Graveler */ PersonalInfo::new([55, 95, 115, 35, 45, 45], [Rock, Ground], 120, 127, GROWTH_MEDIUM_SLOW, [69, 5]),
+    /* 076 Golem */ PersonalInfo::new([80, 110, 130, 45, 55, 65], [Rock, Ground], 45, 127, GROWTH_MEDIUM_SLOW, [69, 5]),
+    /* 077 Ponyta */ PersonalInfo::new([50, 85, 55, 90, 65, 65], [Fire, Fire], 190, 127, GROWTH_MEDIUM_FAST, [50, 18]),
+    /* 078 Rapidash */ PersonalInfo::new([65, 100, 70, 105, 80, 80], [Fire, Fire], 60, 127, GROWTH_MEDIUM_FAST, [50, 18]),
+    /* 079 Slowpoke */ PersonalInfo::new([90, 65, 65, 15, 40, 40], [Water, Psychic], 190, 127, GROWTH_MEDIUM_FAST, [12, 20]),
+    /* 080 Slowbro */ PersonalInfo::new([95, 75, 110, 30, 100, 80], [Water, Psychic], 75, 127, GROWTH_MEDIUM_FAST, [12, 20]),
+    /* 081 Magnemite */ PersonalInfo::new([25, 35, 70, 45, 95, 55], [Electric, Steel], 190, 255, GROWTH_MEDIUM_FAST, [42, 5]),
+    /* 082 Magneton */ PersonalInfo::new([50, 60, 95, 70, 120, 70], [Electric, Steel], 60, 255, GROWTH_MEDIUM_FAST, [42, 5]),
+    /* 083 Farfetchd */ PersonalInfo::new([52, 65, 55, 60, 58, 62], [Normal, Flying], 45, 127, GROWTH_MEDIUM_FAST, [51, 39]),
+    /* 084 Doduo */ PersonalInfo::new([35, 85, 45, 75, 35, 35], [Normal, Flying], 190, 127, GROWTH_MEDIUM_FAST, [50, 48]),
+    /* 085 Dodrio */ PersonalInfo::new([60, 110, 70, 100, 60, 60], [Normal, Flying], 45, 127, GROWTH_MEDIUM_FAST, [50, 48]),
+    /* 086 Seel */ PersonalInfo::new([65, 45, 55, 45, 45, 70], [Water, Water], 190, 127, GROWTH_MEDIUM_FAST, [47, 0]),
+    /* 087 Dewgong */ PersonalInfo::new([90, 70, 80, 70, 70, 95], [Water, Ice], 75, 127, GROWTH_MEDIUM_FAST, [47, 0]),
+    /* 088 Grimer */ PersonalInfo::new([80, 80, 50, 25, 40, 50], [Poison, Poison], 190, 127, GROWTH_MEDIUM_FAST, [1, 60]),
+    /* 089 Muk */ PersonalInfo::new([105, 105, 75, 50, 65, 100], [Poison, Poison], 75, 127, GROWTH_MEDIUM_FAST, [1, 60]),
+    /* 090 Shellder */ PersonalInfo::new([30, 65, 100, 40, 45, 25], [Water, Water], 190, 127, GROWTH_SLOW, [75, 0]),
+    /* 091 Cloyster */ PersonalInfo::new([50, 95, 180, 70, 85, 45], [Water, Ice], 60, 127, GROWTH_SLOW, [75, 0]),
+    /* 092 Gastly */ PersonalInfo::new([30, 35, 30, 80, 100, 35], [Ghost, Poison], 190, 127, GROWTH_MEDIUM_SLOW, [26, 0]),
+    /* 093 Haunter */ PersonalInfo::new([45, 50, 45, 95, 115, 55], [Ghost, Poison], 90, 127, GROWTH_MEDIUM_SLOW, [26, 0]),
+    /* 094 Gengar */ PersonalInfo::new([60, 65, 60, 110, 130, 75], [Ghost, Poison], 45, 127, GROWTH_MEDIUM_SLOW, [26, 0]),
+    /* 095 Onix */ PersonalInfo::new([35, 45, 160, 70, 30, 45], [Rock, Ground], 45, 127, GROWTH_MEDIUM_FAST, [69, 5]),
+    /* 096 Drowzee */ PersonalInfo::new([60, 48, 45, 42, 43, 90], [Psychic, Psychic], 190, 127, GROWTH_MEDIUM_FAST, [15, 0]),
+    /* 097 Hypno */ PersonalInfo::new([85, 73, 70, 67, 73, 115], [Psychic, Psychic], 75, 127, GROWTH_MEDIUM_FAST, [15, 0]),
+    /* 098 Krabby */ PersonalInfo::new([30, 105, 90, 50, 25, 25], [Water, Water], 225, 127, GROWTH_MEDIUM_FAST, [52, 75]),
+    /* 099 Kingler */ PersonalInfo::new([55, 130, 115, 75, 50, 50], [Water, Water], 60, 127, GROWTH_MEDIUM_FAST, [52, 75]),
+    /* 100 Voltorb */ PersonalInfo::new([40, 30, 50, 100, 55, 55], [Electric, Electric], 190, 255, GROWTH_MEDIUM_FAST, [43, 9]),
+    /* 101 Electrode */ PersonalInfo::new([60, 50, 70, 140, 80, 80], [Electric, Electric], 60, 255, GROWTH_MEDIUM_FAST, [43, 9]),
+    /* 102 Exeggcute */ PersonalInfo::new([60, 40, 80, 40, 60, 45], [Grass, Psychic], 90, 127, GROWTH_SLOW, [34, 0]),
+    /* 103 Exeggutor */ PersonalInfo::new([95, 95, 85, 55, 125, 65], [Grass, Psychic], 45, 127, GROWTH_SLOW, [34, 0]),
+    /* 104 Cubone */ PersonalInfo::new([50, 50, 95, 35, 40, 50], [Ground, Ground], 190, 127, GROWTH_MEDIUM_FAST, [69, 31]),
+    /* 105 Marowak */ PersonalInfo::new([60, 80, 110, 45, 50, 80], [Ground, Ground], 75, 127, GROWTH_MEDIUM_FAST, [69, 31]),
+    /* 106 Hitmonlee */ PersonalInfo::new([50, 120, 53, 87, 35, 110], [Fighting, Fighting], 45, 0, GROWTH_MEDIUM_FAST, [7, 0]),
+    /* 107 Hitmonchan */ PersonalInfo::new([50, 105, 79, 76, 35, 110], [Fighting, Fighting], 45, 0, GROWTH_MEDIUM_FAST, [51, 0]),
+    /* 108 Lickitung */ PersonalInfo::new([90, 55, 75, 30, 60, 75], [Normal, Normal], 45, 127, GROWTH_MEDIUM_FAST, [20, 12]),
+    /* 109 Koffing */ PersonalInfo::new([40, 65, 95, 35, 60, 45], [Poison, Poison], 190, 127, GROWTH_MEDIUM_FAST, [26, 0]),
+    /* 110 Weezing */ PersonalInfo::new([65, 90, 120, 60, 85, 70], [Poison, Poison], 60, 127, GROWTH_MEDIUM_FAST, [26, 0]),
+    /* 111 Rhyhorn */ PersonalInfo::new([80, 85, 95, 25, 30, 30], [Ground, Rock], 120, 127, GROWTH_SLOW, [31, 69]),
+    /* 112 Rhydon */ PersonalInfo::new([105, 130, 120, 40, 45, 45], [Ground, Rock], 60, 127, GROWTH_SLOW, [31, 69]),
+    /* 113 Chansey */ PersonalInfo::new([250, 5, 5, 50, 35, 105], [Normal, Normal], 30, 254, GROWTH_FAST, [30, 32]),
+    /* 114 Tangela */ PersonalInfo::new([65, 55, 115, 60, 100, 40], [Grass, Grass], 45, 127, GROWTH_MEDIUM_FAST, [34, 0]),
+    /* 115 Kangaskhan */ PersonalInfo::new([105, 95, 80, 90, 40, 80], [Normal, Normal], 45, 254, GROWTH_MEDIUM_FAST, [48, 0]),
+    /* 116 Horsea */ PersonalInfo::new([30, 40, 70, 60, 70, 25], [Water, Water], 225, 127, GROWTH_MEDIUM_FAST, [33, 0]),
+    /* 117 Seadra */ PersonalInfo::new([55, 65, 95, 85, 95, 45], [Water, Water], 75, 127, GROWTH_MEDIUM_FAST, [38, 0]),
+    /* 118 Goldeen */ PersonalInfo::new([45, 67, 60, 63, 35, 50], [Water, Water], 225, 127, GROWTH_MEDIUM_FAST, [33, 41]),
+    /* 119 Seaking */ PersonalInfo::new([80, 92, 65, 68, 65, 80], [Water, Water], 60, 127, GROWTH_MEDIUM_FAST, [33, 41]),
+    /* 120 Staryu */ PersonalInfo::new([30, 45, 55, 85, 70, 55], [Water, Water], 225, 255, GROWTH_SLOW, [35, 30]),
+    /* 121 Starmie */ PersonalInfo::new([60, 75, 85, 115, 100, 85], [Water, Psychic], 60, 255, GROWTH_SLOW, [35, 30]),
+    /* 122 MrMime */ PersonalInfo::new([40, 45, 65, 90, 100, 120], [Psychic, Psychic], 45, 127, GROWTH_MEDIUM_FAST, [43, 0]),
+    /* 123 Scyther */ PersonalInfo::new([70, 110, 80, 105, 55, 80], [Bug, Flying], 45, 127, GROWTH_MEDIUM_FAST, [68, 0]),
+    /* 124 Jynx */ PersonalInfo::new([65, 50, 35, 95, 115, 95], [Ice, Psychic], 45, 254, GROWTH_MEDIUM_FAST, [12, 0]),
+    /* 125 Electabuzz */ PersonalInfo::new([65, 83, 57, 105, 95, 85], [Electric, Electric], 45, 63, GROWTH_MEDIUM_FAST, [9, 0]),
+    /* 126 Magmar */ PersonalInfo::new([65, 95, 57, 93, 100, 85], [Fire, Fire], 45, 63, GROWTH_MEDIUM_FAST, [49, 0]),
+    /* 127 Pinsir */ PersonalInfo::new([65, 125, 100, 85, 55, 70], [Bug, Bug], 45, 127, GROWTH_SLOW, [52, 0]),
+    /* 128 Tauros */ PersonalInfo::new([75, 100, 95, 110, 40, 70], [Normal, Normal], 45, 0, GROWTH_SLOW, [22, 0]),
+    /* 129 Magikarp */ PersonalInfo::new([20, 10, 55, 80, 15, 20], [Water, Water], 255, 127, GROWTH_SLOW, [33, 0]),
+    /* 130 Gyarados */ PersonalInfo::new([95, 125, 79, 81, 60, 100], [Water, Flying], 45, 127, GROWTH_SLOW, [22, 0]),
+    /* 131 Lapras */ PersonalInfo::new([130, 85, 80, 60, 85, 95], [Water, Ice], 45, 127, GROWTH_SLOW, [11, 75]),
+    /* 132 Ditto */ PersonalInfo::new([48, 48, 48, 48, 48, 48], [Normal, Normal], 35, 255, GROWTH_MEDIUM_FAST, [7, 0]),
+    /* 133 Eevee */ PersonalInfo::new([55, 55, 50, 55, 45, 65], [Normal, Normal], 45, 31, GROWTH_MEDIUM_FAST, [50, 0]),
+    /* 134 Vaporeon */ PersonalInfo::new([130, 65, 60, 65, 110, 95], [Water, Water], 45, 31, GROWTH_MEDIUM_FAST, [11, 0]),
+    /* 135 Jolteon */ PersonalInfo::new([65, 65, 60, 130, 110, 95], [Electric, Electric], 45, 31, GROWTH_MEDIUM_FAST, [10, 0]),
+    /* 136 Flareon */ PersonalInfo::new([65, 130, 60, 65, 95, 110], [Fire, Fire], 45, 31, GROWTH_MEDIUM_FAST, [18, 0]),
+    /* 137 Porygon */ PersonalInfo::new([65, 60, 70, 40, 85, 75], [Normal, Normal], 45, 255, GROWTH_MEDIUM_FAST, [36, 0]),
+    /* 138 Omanyte */ PersonalInfo::new([35, 40, 100, 35, 90, 55], [Rock, Water], 45, 31, GROWTH_MEDIUM_FAST, [33, 75]),
+    /* 139 Omastar */ PersonalInfo::new([70, 60, 125, 55, 115, 70], [Rock, Water], 45, 31, GROWTH_MEDIUM_FAST, [33, 75]),
+    /* 140 Kabuto */ PersonalInfo::new([30, 80, 90, 55, 55, 45], [Rock, Water], 45, 31, GROWTH_MEDIUM_FAST, [33, 4]),
+    /* 141 Kabutops */ PersonalInfo::new([60, 115, 105, 80, 65, 70], [Rock, Water], 45, 31, GROWTH_MEDIUM_FAST, [33, 4]),
+    /* 142 Aerodactyl */ PersonalInfo::new([80, 105, 65, 130, 60, 75], [Rock, Flying], 45, 31, GROWTH_SLOW, [69, 46]),
+    /* 143 Snorlax */ PersonalInfo::new([160, 110, 65, 30, 65, 110], [Normal, Normal], 25, 31, GROWTH_SLOW, [17, 47]),
+    /* 144 Articuno */ PersonalInfo::new([90, 85, 100, 85, 95, 125], [Ice, Flying], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 145 Zapdos */ PersonalInfo::new([90, 90, 85, 100, 125, 90], [Electric, Flying], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 146 Moltres */ PersonalInfo::new([90, 100, 90, 90, 125, 85], [Fire, Flying], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 147 Dratini */ PersonalInfo::new([41, 64, 45, 50, 50, 50], [Dragon, Dragon], 45, 127, GROWTH_SLOW, [61, 0]),
+    /* 148 Dragonair */ PersonalInfo::new([61, 84, 65, 70, 70, 70], [Dragon, Dragon], 45, 127, GROWTH_SLOW, [61, 0]),
+    /* 149 Dragonite */ PersonalInfo::new([91, 134, 95, 80, 100, 100], [Dragon, Flying], 45, 127, GROWTH_SLOW, [39, 0]),
+    /* 150 Mewtwo */ PersonalInfo::new([106, 110, 90, 130, 154, 90], [Psychic, Psychic], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 151 Mew */ PersonalInfo::new([100, 100, 100, 100, 100, 100], [Psychic, Psychic], 45, 255, GROWTH_MEDIUM_SLOW, [28, 0]),
+    /* 152 Chikorita */ PersonalInfo::new([45, 49, 65, 45, 49, 65], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 153 Bayleef */ PersonalInfo::new([60, 62, 80, 60, 63, 80], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 154 Meganium */ PersonalInfo::new([80, 82, 100, 80, 83, 100], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 155 Cyndaquil */ PersonalInfo::new([39, 52, 43, 65, 60, 50], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 156 Quilava */ PersonalInfo::new([58, 64, 58, 80, 80, 65], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 157 Typhlosion */ PersonalInfo::new([78, 84, 78, 100, 109, 85], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 158 Totodile */ PersonalInfo::new([50, 65, 64, 43, 44, 48], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 159 Croconaw */ PersonalInfo::new([65, 80, 80, 58, 59, 63], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 160 Feraligatr */ PersonalInfo::new([85, 105, 100, 78, 79, 83], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 161 Sentret */ PersonalInfo::new([35, 46, 34, 20, 35, 45], [Normal, Normal], 255, 127, GROWTH_MEDIUM_FAST, [50, 51]),
+    /* 162 Furret */ PersonalInfo::new([85, 76, 64, 90, 45, 55], [Normal, Normal], 90, 127, GROWTH_MEDIUM_FAST, [50, 51]),
+    /* 163 Hoothoot */ PersonalInfo::new([60, 30, 30, 50, 36, 56], [Normal, Flying], 255, 127, GROWTH_MEDIUM_FAST, [15, 51]),
+    /* 164 Noctowl */ PersonalInfo::new([100, 50, 50, 70, 76, 96], [Normal, Flying], 90, 127, GROWTH_MEDIUM_FAST, [15, 51]),
+    /* 165 Ledyba */ PersonalInfo::new([40, 20, 30, 55, 40, 80], [Bug, Flying], 255, 127, GROWTH_FAST, [68, 48]),
+    /* 166 Ledian */ PersonalInfo::new([55, 35, 50, 85, 55, 110], [Bug, Flying], 90, 127, GROWTH_FAST, [68, 48]),
+    /* 167 Spinarak */ PersonalInfo::new([40, 60, 40, 30, 40, 40], [Bug, Poison], 255, 127, GROWTH_FAST, [68, 15]),
+    /* 168 Ariados */ PersonalInfo::new([70, 90, 70, 40, 60, 60], [Bug, Poison], 90, 127, GROWTH_FAST, [68, 15]),
+    /* 169 Crobat */ PersonalInfo::new([85, 90, 80, 130, 70, 80], [Poison, Flying], 90, 127, GROWTH_MEDIUM_FAST, [39, 0]),
+    /* 170 Chinchou */ PersonalInfo::new([75, 38, 38, 67, 56, 56], [Water, Electric], 190, 127, GROWTH_SLOW, [10, 35]),
+    /* 171 Lanturn */ PersonalInfo::new([125, 58, 58, 67, 76, 76], [Water, Electric], 75, 127, GROWTH_SLOW, [10, 35]),
+    /* 172 Pichu */ PersonalInfo::new([20, 40, 15, 60, 35, 35], [Electric, Electric], 190, 127, GROWTH_MEDIUM_FAST, [9, 0]),
+    /* 173 Cleffa */ PersonalInfo::new([50, 25, 28, 15, 45, 55], [Normal, Normal], 150, 191, GROWTH_FAST, [56, 0]),
+    /* 174 Igglybuff */ PersonalInfo::new([90, 30, 15, 15, 40, 20], [Normal, Normal], 170, 191, GROWTH_FAST, [56, 0]),
+    /* 175 Togepi */ PersonalInfo::new([35, 20, 65, 20, 40, 65], [Normal, Normal], 190, 31, GROWTH_FAST, [55, 32]),
+    /* 176 Togetic */ PersonalInfo::new([55, 40, 85, 40, 80, 105], [Normal, Flying], 75, 31, GROWTH_FAST, [55, 32]),
+    /* 177 Natu */ PersonalInfo::new([40, 50, 45, 70, 70, 45], [Psychic, Flying], 190, 127, GROWTH_MEDIUM_FAST, [28, 48]),
+    /* 178 Xatu */ PersonalInfo::new([65, 75, 70, 95, 95, 70], [Psychic, Flying], 75, 127, GROWTH_MEDIUM_FAST, [28, 48]),
+    /* 179 Mareep */ PersonalInfo::new([55, 40, 40, 35, 65, 45], [Electric, Electric], 235, 127, GROWTH_MEDIUM_SLOW, [9, 0]),
+    /* 180 Flaaffy */ PersonalInfo::new([70, 55, 55, 45, 80, 60], [Electric, Electric], 120, 127, GROWTH_MEDIUM_SLOW, [9, 0]),
+    /* 181 Ampharos */ PersonalInfo::new([90, 75, 75, 55, 115, 90], [Electric, Electric], 45, 127, GROWTH_MEDIUM_SLOW, [9, 0]),
+    /* 182 Bellossom */ PersonalInfo::new([75, 80, 85, 50, 90, 100], [Grass, Grass], 45, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 183 Marill */ PersonalInfo::new([70, 20, 50, 40, 20, 50], [Water, Water], 190, 127, GROWTH_FAST, [47, 37]),
+    /* 184 Azumarill */ PersonalInfo::new([100, 50, 80, 50, 50, 80], [Water, Water], 75, 127, GROWTH_FAST, [47, 37]),
+    /* 185 Sudowoodo */ PersonalInfo::new([70, 100, 115, 30, 30, 65], [Rock, Rock], 65, 127, GROWTH_MEDIUM_FAST, [5, 69]),
+    /* 186 Politoed */ PersonalInfo::new([90, 75, 75, 70, 90, 100], [Water, Water], 45, 127, GROWTH_MEDIUM_SLOW, [11, 6]),
+    /* 187 Hoppip */ PersonalInfo::new([35, 35, 40, 50, 35, 55], [Grass, Flying], 255, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 188 Skiploom */ PersonalInfo::new([55, 45, 50, 80, 45, 65], [Grass, Flying], 120, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 189 Jumpluff */ PersonalInfo::new([75, 55, 70, 110, 55, 85], [Grass, Flying], 45, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 190 Aipom */ PersonalInfo::new([55, 70, 55, 85, 40, 55], [Normal, Normal], 45, 127, GROWTH_FAST, [50, 53]),
+    /* 191 Sunkern */ PersonalInfo::new([30, 30, 30, 30, 30, 30], [Grass, Grass], 235, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 192 Sunflora */ PersonalInfo::new([75, 75, 55, 30, 105, 85], [Grass, Grass], 120, 127, GROWTH_MEDIUM_SLOW, [34, 0]),
+    /* 193 Yanma */ PersonalInfo::new([65, 65, 45, 95, 75, 45], [Bug, Flying], 75, 127, GROWTH_MEDIUM_FAST, [3, 14]),
+    /* 194 Wooper */ PersonalInfo::new([55, 45, 45, 15, 25, 25], [Water, Ground], 255, 127, GROWTH_MEDIUM_FAST, [6, 11]),
+    /* 195 Quagsire */ PersonalInfo::new([95, 85, 85, 35, 65, 65], [Water, Ground], 90, 127, GROWTH_MEDIUM_FAST, [6, 11]),
+    /* 196 Espeon */ PersonalInfo::new([65, 65, 60, 110, 130, 95], [Psychic, Psychic], 45, 31, GROWTH_MEDIUM_FAST, [28, 0]),
+    /* 197 Umbreon */ PersonalInfo::new([95, 65, 110, 65, 60, 130], [Dark, Dark], 45, 31, GROWTH_MEDIUM_FAST, [28, 0]),
+    /* 198 Murkrow */ PersonalInfo::new([60, 85, 42, 91, 85, 42], [Dark, Flying], 30, 127, GROWTH_MEDIUM_SLOW, [15, 0]),
+    /* 199 Slowking */ PersonalInfo::new([95, 75, 80, 30, 100, 110], [Water, Psychic], 70, 127, GROWTH_MEDIUM_FAST, [12, 20]),
+    /* 200 Misdreavus */ PersonalInfo::new([60, 60, 60, 85, 85, 85], [Ghost, Ghost], 45, 127, GROWTH_FAST, [26, 0]),
+    /* 201 Unown */ PersonalInfo::new([48, 72, 48, 48, 72, 48], [Psychic, Psychic], 225, 255, GROWTH_MEDIUM_FAST, [26, 0]),
+    /* 202 Wobbuffet */ PersonalInfo::new([190, 33, 58, 33, 33, 58], [Psychic, Psychic], 45, 127, GROWTH_MEDIUM_FAST, [23, 0]),
+    /* 203 Girafarig */ PersonalInfo::new([70, 80, 65, 85, 90, 65], [Normal, Psychic], 60, 127, GROWTH_MEDIUM_FAST, [39, 48]),
+    /* 204 Pineco */ PersonalInfo::new([50, 65, 90, 15, 35, 35], [Bug, Bug], 190, 127, GROWTH_MEDIUM_FAST, [5, 0]),
+    /* 205 Forretress */ PersonalInfo::new([75, 90, 140, 40, 60, 60], [Bug, Steel], 75, 127, GROWTH_MEDIUM_FAST, [5, 0]),
+    /* 206 Dunsparce */ PersonalInfo::new([100, 70, 70, 45, 65, 65], [Normal, Normal], 190, 127, GROWTH_MEDIUM_FAST, [32, 50]),
+    /* 207 Gligar */ PersonalInfo::new([65, 75, 105, 85, 35, 65], [Ground, Flying], 60, 127, GROWTH_MEDIUM_SLOW, [52, 8]),
+    /* 208 Steelix */ PersonalInfo::new([75, 85, 200, 30, 55, 65], [Steel, Ground], 25, 127, GROWTH_MEDIUM_FAST, [69, 5]),
+    /* 209 Snubbull */ PersonalInfo::new([60, 80, 50, 30, 40, 40], [Normal, Normal], 190, 191, GROWTH_FAST, [22, 50]),
+    /* 210 Granbull */ PersonalInfo::new([90, 120, 75, 45, 60, 60], [Normal, Normal], 75, 191, GROWTH_FAST, [22, 0]),
+    /* 211 Qwilfish */ PersonalInfo::new([65, 95, 75, 85, 55, 55], [Water, Poison], 45, 127, GROWTH_MEDIUM_FAST, [38, 33]),
+    /* 212 Scizor */ PersonalInfo::new([70, 130, 100, 65, 55, 80], [Bug, Steel], 25, 127, GROWTH_MEDIUM_FAST, [68, 0]),
+    /* 213 Shuckle */ PersonalInfo::new([20, 10, 230, 5, 10, 230], [Bug, Rock], 190, 127, GROWTH_MEDIUM_SLOW, [5, 0]),
+    /* 214 Heracross */ PersonalInfo::new([80, 125, 75, 85, 40, 95], [Bug, Fighting], 45, 127, GROWTH_SLOW, [68, 62]),
+    /* 215 Sneasel */ PersonalInfo::new([55, 95, 55, 115, 35, 75], [Dark, Ice], 60, 127, GROWTH_MEDIUM_SLOW, [39, 51]),
+    /* 216 Teddiursa */ PersonalInfo::new([60, 80, 50, 40, 50, 50], [Normal, Normal], 120, 127, GROWTH_MEDIUM_FAST, [53, 0]),
+    /* 217 Ursaring */ PersonalInfo::new([90, 130, 75, 55, 75, 75], [Normal, Normal], 60, 127, GROWTH_MEDIUM_FAST, [62, 0]),
+    /* 218 Slugma */ PersonalInfo::new([40, 40, 40, 20, 70, 40], [Fire, Fire], 190, 127, GROWTH_MEDIUM_FAST, [40, 49]),
+    /* 219 Magcargo */ PersonalInfo::new([50, 50, 120, 30, 80, 80], [Fire, Rock], 75, 127, GROWTH_MEDIUM_FAST, [40, 49]),
+    /* 220 Swinub */ PersonalInfo::new([50, 50, 40, 50, 30, 30], [Ice, Ground], 225, 127, GROWTH_SLOW, [12, 0]),
+    /* 221 Piloswine */ PersonalInfo::new([100, 100, 80, 50, 60, 60], [Ice, Ground], 75, 127, GROWTH_SLOW, [12, 0]),
+    /* 222 Corsola */ PersonalInfo::new([55, 55, 85, 35, 65, 85], [Water, Rock], 60, 191, GROWTH_FAST, [55, 30]),
+    /* 223 Remoraid */ PersonalInfo::new([35, 65, 35, 65, 65, 35], [Water, Water], 190, 127, GROWTH_MEDIUM_FAST, [55, 0]),
+    /* 224 Octillery */ PersonalInfo::new([75, 105, 75, 45, 105, 75], [Water, Water], 75, 127, GROWTH_MEDIUM_FAST, [21, 0]),
+    /* 225 Delibird */ PersonalInfo::new([45, 55, 45, 75, 65, 45], [Ice, Flying], 45, 127, GROWTH_FAST, [72, 55]),
+    /* 226 Mantine */ PersonalInfo::new([65, 40, 70, 70, 80, 140], [Water, Flying], 25, 127, GROWTH_SLOW, [33, 11]),
+    /* 227 Skarmory */ PersonalInfo::new([65, 80, 140, 70, 40, 70], [Steel, Flying], 25, 127, GROWTH_SLOW, [51, 5]),
+    /* 228 Houndour */ PersonalInfo::new([45, 60, 30, 65, 80, 50], [Dark, Fire], 120, 127, GROWTH_SLOW, [48, 18]),
+    /* 229 Houndoom */ PersonalInfo::new([75, 90, 50, 95, 110, 80], [Dark, Fire], 45, 127, GROWTH_SLOW, [48, 18]),
+    /* 230 Kingdra */ PersonalInfo::new([75, 95, 95, 85, 95, 95], [Water, Dragon], 45, 127, GROWTH_MEDIUM_FAST, [33, 0]),
+    /* 231 Phanpy */ PersonalInfo::new([90, 60, 60, 40, 40, 40], [Ground, Ground], 120, 127, GROWTH_MEDIUM_FAST, [53, 0]),
+    /* 232 Donphan */ PersonalInfo::new([90, 120, 120, 50, 60, 60], [Ground, Ground], 60, 127, GROWTH_MEDIUM_FAST, [5, 0]),
+    /* 233 Porygon2 */ PersonalInfo::new([85, 80, 90, 60, 105, 95], [Normal, Normal], 45, 255, GROWTH_MEDIUM_FAST, [36, 0]),
+    /* 234 Stantler */ PersonalInfo::new([73, 95, 62, 85, 85, 65], [Normal, Normal], 45, 127, GROWTH_SLOW, [22, 0]),
+    /* 235 Smeargle */ PersonalInfo::new([55, 20, 35, 75, 20, 45], [Normal, Normal], 45, 127, GROWTH_FAST, [20, 0]),
+    /* 236 Tyrogue */ PersonalInfo::new([35, 35, 35, 35, 35, 35], [Fighting, Fighting], 75, 0, GROWTH_MEDIUM_FAST, [62, 0]),
+    /* 237 Hitmontop */ PersonalInfo::new([50, 95, 95, 70, 35, 110], [Fighting, Fighting], 45, 0, GROWTH_MEDIUM_FAST, [22, 0]),
+    /* 238 Smoochum */ PersonalInfo::new([45, 30, 15, 65, 85, 65], [Ice, Psychic], 45, 254, GROWTH_MEDIUM_FAST, [12, 0]),
+    /* 239 Elekid */ PersonalInfo::new([45, 63, 37, 95, 65, 55], [Electric, Electric], 45, 63, GROWTH_MEDIUM_FAST, [9, 0]),
+    /* 240 Magby */ PersonalInfo::new([45, 75, 37, 83, 70, 55], [Fire, Fire], 45, 63, GROWTH_MEDIUM_FAST, [49, 0]),
+    /* 241 Miltank */ PersonalInfo::new([95, 80, 105, 100, 40, 70], [Normal, Normal], 45, 254, GROWTH_SLOW, [47, 0]),
+    /* 242 Blissey */ PersonalInfo::new([255, 10, 10, 55, 75, 135], [Normal, Normal], 30, 254, GROWTH_FAST, [30, 32]),
+    /* 243 Raikou */ PersonalInfo::new([90, 85, 75, 115, 115, 100], [Electric, Electric], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 244 Entei */ PersonalInfo::new([115, 115, 85, 100, 90, 75], [Fire, Fire], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 245 Suicune */ PersonalInfo::new([100, 75, 115, 85, 90, 115], [Water, Water], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 246 Larvitar */ PersonalInfo::new([50, 64, 50, 41, 45, 50], [Rock, Ground], 45, 127, GROWTH_SLOW, [62, 0]),
+    /* 247 Pupitar */ PersonalInfo::new([70, 84, 70, 51, 65, 70], [Rock, Ground], 45, 127, GROWTH_SLOW, [61, 0]),
+    /* 248 Tyranitar */ PersonalInfo::new([100, 134, 110, 61, 95, 100], [Rock, Dark], 45, 127, GROWTH_SLOW, [45, 0]),
+    /* 249 Lugia */ PersonalInfo::new([106, 90, 130, 110, 90, 154], [Psychic, Flying], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 250 HoOh */ PersonalInfo::new([106, 130, 90, 90, 110, 154], [Fire, Flying], 3, 255, GROWTH_SLOW, [46, 0]),
+    /* 251 Celebi */ PersonalInfo::new([100, 100, 100, 100, 100, 100], [Psychic, Grass], 45, 255, GROWTH_MEDIUM_SLOW, [30, 0]),
+    /* 252 Treecko */ PersonalInfo::new([40, 45, 35, 70, 65, 55], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 253 Grovyle */ PersonalInfo::new([50, 65, 45, 95, 85, 65], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 254 Sceptile */ PersonalInfo::new([70, 85, 65, 120, 105, 85], [Grass, Grass], 45, 31, GROWTH_MEDIUM_SLOW, [65, 0]),
+    /* 255 Torchic */ PersonalInfo::new([45, 60, 40, 45, 70, 50], [Fire, Fire], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 256 Combusken */ PersonalInfo::new([60, 85, 60, 55, 85, 60], [Fire, Fighting], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 257 Blaziken */ PersonalInfo::new([80, 120, 70, 80, 110, 70], [Fire, Fighting], 45, 31, GROWTH_MEDIUM_SLOW, [66, 0]),
+    /* 258 Mudkip */ PersonalInfo::new([50, 70, 50, 40, 50, 50], [Water, Water], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 259 Marshtomp */ PersonalInfo::new([70, 85, 70, 50, 60, 70], [Water, Ground], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 260 Swampert */ PersonalInfo::new([100, 110, 90, 60, 85, 90], [Water, Ground], 45, 31, GROWTH_MEDIUM_SLOW, [67, 0]),
+    /* 261 Poochyena */ PersonalInfo::new([35, 55, 35, 35, 30, 30], [Dark, Dark], 255, 127, GROWTH_MEDIUM_FAST, [50, 0]),
+    /* 262 Mightyena */ PersonalInfo::new([70, 90, 70, 70, 60, 60], [Dark, Dark], 127, 127, GROWTH_MEDIUM_FAST, [22, 0]),
+    /* 263 Zigzagoon */ PersonalInfo::new([38, 30, 41, 60, 30, 41], [Normal, Normal], 255, 127, GROWTH_MEDIUM_FAST, [53, 0]),
+    /* 264 Linoone */ PersonalInfo::new([78, 70, 61, 100, 50, 61], [Normal, Normal], 90, 127, GROWTH_MEDIUM_FAST, [53, 0]),
+    /* 265 Wurmple */ PersonalInfo::new([45, 45, 35, 20, 20, 30], [Bug, Bug], 255, 127, GROWTH_MEDIUM_FAST, [19, 0]),
+    /* 266 Silcoon */ PersonalInfo::new([50, 35, 55, 15, 25, 25], [Bug, Bug], 120, 127, GROWTH_MEDIUM_FAST, [61, 0]),
+    /* 267 Beautifly */ PersonalInfo::new([60, 70, 50, 65, 90, 50], [Bug, Flying], 45, 127, GROWTH_MEDIUM_FAST, [68, 0]),
+    /* 268 Cascoon */ PersonalInfo::new([50, 35, 55, 15, 25, 25], [Bug, Bug], 120, 127, GROWTH_MEDIUM_FAST, [61, 0]),
+    /* 269 Dustox */ PersonalInfo::new([60, 50, 70, 65, 50, 90], [Bug, Poison], 45, 127, GROWTH_MEDIUM_FAST, [19, 0]),
+    /* 270 Lotad */ PersonalInfo::new([40, 30, 30, 30, 40, 50], [Water, Grass], 255, 127, GROWTH_MEDIUM_SLOW, [33, 44]),
+    /* 271 Lombre */ PersonalInfo::new([60, 50, 50, 50, 60, 70], [Water, Grass], 120, 127, GROWTH_MEDIUM_SLOW, [33, 44]),
+    /* 272 Ludicolo */ PersonalInfo::new([80, 70, 70, 70, 90, 100], [Water, Grass], 45, 127, GROWTH_MEDIUM_SLOW, [33, 44]),
+    /* 273 Seedot */ PersonalInfo::new([40, 40, 50, 30, 30, 30], [Grass, Grass], 255, 127, GROWTH_MEDIUM_SLOW, [34, 48]),
+    /* 274 Nuzleaf */ PersonalInfo::new([70, 70, 40, 60, 60, 40], [Grass, Dark], 120, 127, GROWTH_MEDIUM_SLOW, [34, 48]),
+    /* 275 Shiftry */ PersonalInfo::new([90, 100, 60, 80, 90, 60], [Grass, Dark], 45, 127, GROWTH_MEDIUM_SLOW, [34, 48]),
+    /* 276 Taillow */ PersonalInfo::new([40, 55, 30, 85, 30, 30], [Normal, Flying], 200, 127, GROWTH_MEDIUM_SLOW, [62, 0]),
+    /* 277 Swellow */ PersonalInfo::new([60, 85, 60, 125, 50, 50], [Normal, Flying], 45, 127, GROWTH_MEDIUM_SLOW, [62, 0]),
+    /* 278 Wingull */ PersonalInfo::new([40, 30, 30, 85, 55, 30], [Water, Flying], 190, 127, GROWTH_MEDIUM_FAST, [51, 0]),
+    /* 279 Pelipper */ PersonalInfo::new([60, 50, 100, 65, 85, 70], [Water, Flying], 45, 127, GROWTH_MEDIUM_FAST, [51, 0]),
+    /* 280 Ralts */ PersonalInfo::new([28, 25, 25, 40, 45, 35], [Psychic, Psychic], 235, 127, GROWTH_SLOW, [28, 36]),
+    /* 281 Kirlia */ PersonalInfo::new([38, 35, 35, 50, 65, 55], [Psychic, Psychic], 120, 127, GROWTH_SLOW, [28, 36]),
+    /* 282 Gardevoir */ PersonalInfo::new([68, 65, 65, 80, 125, 115], [Psychic, Psychic], 45, 127, GROWTH_SLOW, [28, 36]),
+    /* 283 Surskit */ PersonalInfo::new([40, 30, 32, 65, 50, 52], [Bug, Water], 200, 127, GROWTH_MEDIUM_FAST, [33, 0]),
+    /* 284 Masquerain */ PersonalInfo::new([70, 60, 62, 60, 80, 82], [Bug, Flying], 75, 127, GROWTH_MEDIUM_FAST, [22, 0]),
+    /* 285 Shroomish */ PersonalInfo::new([60, 40, 60, 35, 40, 60], [Grass, Grass], 255, 127, GROWTH_FLUCTUATING, [27, 0]),
+    /* 286 Breloom */ PersonalInfo::new([60, 130, 80, 70, 60, 60], [Grass, Fighting], 90, 127, GROWTH_FLUCTUATING, [27, 0]),
+    /* 287 Slakoth */ PersonalInfo::new([60, 60, 60, 30, 35, 35], [Normal, Normal], 255, 127, GROWTH_SLOW, [54, 0]),
+    /* 288 Vigoroth */ PersonalInfo::new([80, 80, 80, 90, 55, 55], [Normal, Normal], 120, 127, GROWTH_SLOW, [72, 0]),
+    /* 289 Slaking */ PersonalInfo::new([150, 160, 100, 100, 95, 65], [Normal, Normal], 45, 127, GROWTH_SLOW, [54, 0]),
+    /* 290 Nincada */ PersonalInfo::new([31, 45, 90, 40, 30, 30], [Bug, Ground], 255, 127, GROWTH_ERRATIC, [14, 0]),
+    /* 291 Ninjask */ PersonalInfo::new([61, 90, 45, 160, 50, 50], [Bug, Flying], 120, 127, GROWTH_ERRATIC, [3, 0]),
+    /* 292 Shedinja */ PersonalInfo::new([1, 90, 45, 40, 30, 30], [Bug, Ghost], 45, 255, GROWTH_ERRATIC, [25, 0]),
+    /* 293 Whismur */ PersonalInfo::new([64, 51, 23, 28, 51, 23], [Normal, Normal], 190, 127, GROWTH_MEDIUM_SLOW, [43, 0]),
+    /* 294 Loudred */ PersonalInfo::new([84, 71, 43, 48, 71, 43], [Normal, Normal], 120, 127, GROWTH_MEDIUM_SLOW, [43, 0]),
+    /* 295 Exploud */ PersonalInfo::new([104, 91, 63, 68, 91, 63], [Normal, Normal], 45, 127, GROWTH_MEDIUM_SLOW, [43, 0]),
+    /* 296 Makuhita */ PersonalInfo::new([72, 60, 30, 25, 20, 30], [Fighting, Fighting], 180, 63, GROWTH_FLUCTUATING, [47, 62]),
+    /* 297 Hariyama */ PersonalInfo::new([144, 120, 60, 50, 40, 60], [Fighting, Fighting], 200, 63, GROWTH_FLUCTUATING, [47, 62]),
+    /* 298 Azurill */ PersonalInfo::new([50, 20, 40, 20, 20, 40], [Normal, Normal], 150, 191, GROWTH_FAST, [47, 37]),
+    /* 299 Nosepass */ PersonalInfo::new([30, 45, 135, 30, 45, 90], [Rock, Rock], 255, 127, GROWTH_MEDIUM_FAST, [5, 42]),
+    /* 300 Skitty */ PersonalInfo::new([50, 45, 45, 50, 35, 35], [Normal, Normal], 255, 191, GROWTH_FAST, [56, 0]),
+    /* 301 Delcatty */ PersonalInfo::new([70, 65, 65, 70, 55, 55], [Normal, Normal], 60, 191, GROWTH_FAST, [56, 0]),
+    /* 302 Sableye */ PersonalInfo::new([50, 75, 75, 50, 65, 65], [Dark, Ghost], 45, 127, GROWTH_MEDIUM_SLOW, [51, 0]),
+    /* 303 Mawile */ PersonalInfo::new([50, 85, 85, 50, 55, 55], [Steel, Steel], 45, 127, GROWTH_FAST, [52, 22]),
+    /* 304 Aron */ PersonalInfo::new([50, 70, 100, 30, 40, 40], [Steel, Rock], 180, 127, GROWTH_SLOW, [5, 69]),
+    /* 305 Lairon */ PersonalInfo::new([60, 90, 140, 40, 50, 50], [Steel, Rock], 90, 127, GROWTH_SLOW, [5, 69]),
+    /* 306 Aggron */ PersonalInfo::new([70, 110, 180, 50, 60, 60], [Steel, Rock], 45, 127, GROWTH_SLOW, [5, 69]),
+    /* 307 Meditite */ PersonalInfo::new([30, 40, 55, 60, 40, 55], [Fighting, Psychic], 180, 127, GROWTH_MEDIUM_FAST, [74, 0]),
+    /* 308 Medicham */ PersonalInfo::new([60, 60, 75, 80, 60, 75], [Fighting, Psychic], 90, 127, GROWTH_MEDIUM_FAST, [74, 0]),
+    /* 309 Electrike */ PersonalInfo::new([40, 45, 40, 65, 65, 40], [Electric, Electric], 120, 127, GROWTH_SLOW, [9, 31]),
+    /* 310 Manectric */ PersonalInfo::new([70, 75, 60, 105, 105, 60], [Electric, Electric], 45, 127, GROWTH_SLOW, [9, 31]),
+    /* 311 Plusle */ PersonalInfo::new([60, 50, 40, 95, 85, 75], [Electric, Electric], 200, 127, GROWTH_MEDIUM_FAST, [57, 0]),
+    /* 312 Minun */ PersonalInfo::new([60, 40, 50, 95, 75, 85], [Electric, Electric], 200, 127, GROWTH_MEDIUM_FAST, [58, 0]),
+    /* 313 Volbeat */ PersonalInfo::new([65, 73, 55, 85, 47, 75], [Bug, Bug], 150, 0, GROWTH_ERRATIC, [35, 68]),
+    /* 314 Illumise */ PersonalInfo::new([65, 47, 55, 85, 73, 75], [Bug, Bug], 150, 254, GROWTH_FLUCTUATING, [12, 0]),
+    /* 315 Roselia */ PersonalInfo::new([50, 60, 45, 65, 100, 80], [Grass, Poison], 150, 127, GROWTH_MEDIUM_SLOW, [30, 38]),
+    /* 316 Gulpin */ PersonalInfo::new([70, 43, 53, 40, 43, 53], [Poison, Poison], 225, 127, GROWTH_FLUCTUATING, [64, 60]),
+    /* 317 Swalot */ PersonalInfo::new([100, 73, 83, 55, 73, 83], [Poison, Poison], 75, 127, GROWTH_FLUCTUATING, [64, 60]),
+    /* 318 Carvanha */ PersonalInfo::new([45, 90, 20, 65, 65, 20], [Water, Dark], 225, 127, GROWTH_SLOW, [24, 0]),
+    /* 319 Sharpedo */ PersonalInfo::new([70, 120, 40, 95, 95, 40], [Water, Dark], 60, 127, GROWTH_SLOW, [24, 0]),
+    /* 320 Wailmer */ PersonalInfo::new([130, 70, 35, 60, 70, 35], [Water, Water], 125, 127, GROWTH_FLUCTUATING, [41, 12]),
+    /* 321 Wailord */ PersonalInfo::new([170, 90, 45, 60, 90, 45], [Water, Water], 60, 127, GROWTH_FLUCTUATING, [41, 12]),
+    /* 322 Numel */ PersonalInfo::new([60, 60, 40, 35, 65, 45], [Fire, Ground], 255, 127, GROWTH_MEDIUM_FAST, [12, 0]),
+    /* 323 Camerupt */ PersonalInfo::new([70, 100, 70, 40, 105, 75], [Fire, Ground], 150, 127, GROWTH_MEDIUM_FAST, [40, 0]),
+    /* 324 Torkoal */ PersonalInfo::new([70, 85, 140, 20, 85, 70], [Fire, Fire], 90, 127, GROWTH_MEDIUM_FAST, [73, 0]),
+    /* 325 Spoink */ PersonalInfo::new([60, 25, 35, 60, 70, 80], [Psychic, Psychic], 255, 127, GROWTH_FAST, [47, 20]),
+    /* 326 Grumpig */ PersonalInfo::new([80, 45, 65, 80, 90, 110], [Psychic, Psychic], 60, 127, GROWTH_FAST, [47, 20]),
+    /* 327 Spinda */ PersonalInfo::new([60, 60, 60, 60, 60, 60], [Normal, Normal], 255, 127, GROWTH_FAST, [20, 0]),
+    /* 328 Trapinch */ PersonalInfo::new([45, 100, 45, 10, 45, 45], [Ground, Ground], 255, 127, GROWTH_MEDIUM_SLOW, [52, 71]),
+    /* 329 Vibrava */ PersonalInfo::new([50, 70, 50, 70, 50, 50], [Ground, Dragon], 120, 127, GROWTH_MEDIUM_SLOW, [26, 0]),
+    /* 330 Flygon */ PersonalInfo::new([80, 100, 80, 100, 80, 80], [Ground, Dragon], 45, 127, GROWTH_MEDIUM_SLOW, [26, 0]),
+    /* 331 Cacnea */ PersonalInfo::new([50, 85, 40, 35, 85, 40], [Grass, Grass], 190, 127, GROWTH_MEDIUM_SLOW, [8, 0]),
+    /* 332 Cacturne */ PersonalInfo::new([70, 115, 60, 55, 115, 60], [Grass, Dark], 60, 127, GROWTH_MEDIUM_SLOW, [8, 0]),
+    /* 333 Swablu */ PersonalInfo::new([45, 40, 60, 50, 40, 75], [Normal, Flying], 255, 127, GROWTH_ERRATIC, [30, 0]),
+    /* 334 Altaria */ PersonalInfo::new([75, 70, 90, 80, 70, 105], [Dragon, Flying], 45, 127, GROWTH_ERRATIC, [30, 0]),
+    /* 335 Zangoose */ PersonalInfo::new([73, 115, 60, 90, 60, 60], [Normal, Normal], 90, 127, GROWTH_ERRATIC, [17, 0]),
+    /* 336 Seviper */ PersonalInfo::new([73, 100, 60, 65, 100, 60], [Poison, Poison], 90, 127, GROWTH_FLUCTUATING, [61, 0]),
+    /* 337 Lunatone */ PersonalInfo::new([70, 55, 65, 70, 95, 85], [Rock, Psychic], 45, 255, GROWTH_FAST, [26, 0]),
+    /* 338 Solrock */ PersonalInfo::new([70, 95, 85, 70, 55, 65], [Rock, Psychic], 45, 255, GROWTH_FAST, [26, 0]),
+    /* 339 Barboach */ PersonalInfo::new([50, 48, 43, 60, 46, 41], [Water, Ground], 190, 127, GROWTH_MEDIUM_FAST, [12, 0]),
+    /* 340 Whiscash */ PersonalInfo::new([110, 78, 73, 60, 76, 71], [Water, Ground], 75, 127, GROWTH_MEDIUM_FAST, [12, 0]),
+    /* 341 Corphish */ PersonalInfo::new([43, 80, 65, 35, 50, 35], [Water, Water], 205, 127, GROWTH_FLUCTUATING, [52, 75]),
+    /* 342 Crawdaunt */ PersonalInfo::new([63, 120, 85, 55, 90, 55], [Water, Dark], 155, 127, GROWTH_FLUCTUATING, [52, 75]),
+    /* 343 Baltoy */ PersonalInfo::new([40, 40, 55, 55, 40, 70], [Ground, Psychic], 255, 255, GROWTH_MEDIUM_FAST, [26, 0]),
+    /* 344 Claydol */ PersonalInfo::new([60, 70, 105, 75, 70, 120], [Ground, Psychic], 90, 255, GROWTH_MEDIUM_FAST, [26, 0]),
+    /* 345 Lileep */ PersonalInfo::new([66, 41, 77, 23, 61, 87], [Rock, Grass], 45, 31, GROWTH_ERRATIC, [21, 0]),
+    /* 346 Cradily */ PersonalInfo::new([86, 81, 97, 43, 81, 107], [Rock, Grass], 45, 31, GROWTH_ERRATIC, [21, 0]),
+    /* 347 Anorith */ PersonalInfo::new([45, 95, 50, 75, 40, 50], [Rock, Bug], 45, 31, GROWTH_ERRATIC, [4, 0]),
+    /* 348 Armaldo */ PersonalInfo::new([75, 125, 100, 45, 70, 80], [Rock, Bug], 45, 31, GROWTH_ERRATIC, [4, 0]),
+    /* 349 Feebas */ PersonalInfo::new([20, 15, 20, 80, 10, 55], [Water, Water], 255, 127, GROWTH_ERRATIC, [33, 0]),
+    /* 350 Milotic */ PersonalInfo::new([95, 60, 79, 81, 100, 125], [Water, Water], 60, 127, GROWTH_ERRATIC, [63, 0]),
+    /* 351 Castform */ PersonalInfo::new([70, 70, 70, 70, 70, 70], [Normal, Normal], 45, 127, GROWTH_MEDIUM_FAST, [59, 0]),
+    /* 352 Kecleon */ PersonalInfo::new([60, 90, 70, 40, 60, 120], [Normal, Normal], 200, 127, GROWTH_MEDIUM_SLOW, [16, 0]),
+    /* 353 Shuppet */ PersonalInfo::new([44, 75, 35, 45, 63, 33], [Ghost, Ghost], 225, 127, GROWTH_FAST, [15, 0]),
+    /* 354 Banette */ PersonalInfo::new([64, 115, 65, 65, 83, 63], [Ghost, Ghost], 45, 127, GROWTH_FAST, [15, 0]),
+    /* 355 Duskull */ PersonalInfo::new([20, 40, 90, 25, 30, 90], [Ghost, Ghost], 190, 127, GROWTH_FAST, [26, 0]),
+    /* 356 Dusclops */ PersonalInfo::new([40, 70, 130, 25, 60, 130], [Ghost, Ghost], 90, 127, GROWTH_FAST, [46, 0]),
+    /* 357 Tropius */ PersonalInfo::new([99, 68, 83, 51, 72, 87], [Grass, Flying], 200, 127, GROWTH_SLOW, [34, 0]),
+    /* 358 Chimecho */ PersonalInfo::new([65, 50, 70, 65, 95, 80], [Psychic, Psychic], 45, 127, GROWTH_FAST, [26, 0]),
+    /* 359 Absol */ PersonalInfo::new([65, 130, 60, 75, 75, 60], [Dark, Dark], 30, 127, GROWTH_MEDIUM_SLOW, [46, 0]),
+    /* 360 Wynaut */ PersonalInfo::new([95, 23, 48, 23, 23, 48], [Psychic, Psychic], 125, 127, GROWTH_MEDIUM_FAST, [23, 0]),
+    /* 361 Snorunt */ PersonalInfo::new([50, 50, 50, 50, 50, 50], [Ice, Ice], 190, 127, GROWTH_MEDIUM_FAST, [39, 0]),
+    /* 362 Glalie */ PersonalInfo::new([80, 80, 80, 80, 80, 80], [Ice, Ice], 75, 127, GROWTH_MEDIUM_FAST, [39, 0]),
+    /* 363 Spheal */ PersonalInfo::new([70, 40, 50, 25, 55, 50], [Ice, Water], 255, 127, GROWTH_MEDIUM_SLOW, [47, 0]),
+    /* 364 Sealeo */ PersonalInfo::new([90, 60, 70, 45, 75, 70], [Ice, Water], 120, 127, GROWTH_MEDIUM_SLOW, [47, 0]),
+    /* 365 Walrein */ PersonalInfo::new([110, 80, 90, 65, 95, 90], [Ice, Water], 45, 127, GROWTH_MEDIUM_SLOW, [47, 0]),
+    /* 366 Clamperl */ PersonalInfo::new([35, 64, 85, 32, 74, 55], [Water, Water], 255, 127, GROWTH_ERRATIC, [75, 0]),
+    /* 367 Huntail */ PersonalInfo::new([55, 104, 105, 52, 94, 75], [Water, Water], 60, 127, GROWTH_ERRATIC, [33, 0]),
+    /* 368 Gorebyss */ PersonalInfo::new([55, 84, 105, 52, 114, 75], [Water, Water], 60, 127, GROWTH_ERRATIC, [33, 0]),
+    /* 369 Relicanth */ PersonalInfo::new([100, 90, 130, 55, 45, 65], [Water, Rock], 25, 31, GROWTH_SLOW, [33, 69]),
+    /* 370 Luvdisc */ PersonalInfo::new([43, 30, 55, 97, 40, 65], [Water, Water], 225, 191, GROWTH_FAST, [33, 0]),
+    /* 371 Bagon */ PersonalInfo::new([45, 75, 60, 50, 40, 30], [Dragon, Dragon], 45, 127, GROWTH_SLOW, [69, 0]),
+    /* 372 Shelgon */ PersonalInfo::new([65, 95, 100, 50, 60, 50], [Dragon, Dragon], 45, 127, GROWTH_SLOW, [69, 0]),
+    /* 373 Salamence */ PersonalInfo::new([95, 135, 80, 100, 110, 80], [Dragon, Flying], 45, 127, GROWTH_SLOW, [22, 0]),
+    /* 374 Beldum */ PersonalInfo::new([40, 55, 80, 30, 35, 60], [Steel, Psychic], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 375 Metang */ PersonalInfo::new([60, 75, 100, 50, 55, 80], [Steel, Psychic], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 376 Metagross */ PersonalInfo::new([80, 135, 130, 70, 95, 90], [Steel, Psychic], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 377 Regirock */ PersonalInfo::new([80, 100, 200, 50, 50, 100], [Rock, Rock], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 378 Regice */ PersonalInfo::new([80, 50, 100, 50, 100, 200], [Ice, Ice], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 379 Registeel */ PersonalInfo::new([80, 75, 150, 50, 75, 150], [Steel, Steel], 3, 255, GROWTH_SLOW, [29, 0]),
+    /* 380 Latias */ PersonalInfo::new([80, 80, 90, 110, 110, 130], [Dragon, Psychic], 3, 254, GROWTH_SLOW, [26, 0]),
+    /* 381 Latios */ PersonalInfo::new([80, 90, 80, 110, 130, 110], [Dragon, Psychic], 3, 0, GROWTH_SLOW, [26, 0]),
+    /* 382 Kyogre */ PersonalInfo::new([100, 100, 90, 90, 150, 140], [Water, Water], 5, 255, GROWTH_SLOW, [2, 0]),
+    /* 383 Groudon */ PersonalInfo::new([100, 150, 140, 90, 100, 90], [Ground, Ground], 5, 255, GROWTH_SLOW, [70, 0]),
+    /* 384 Rayquaza */ PersonalInfo::new([105, 150, 90, 95, 150, 90], [Dragon, Flying], 3, 255, GROWTH_SLOW, [77, 0]),
+    /* 385 Jirachi */ PersonalInfo::new([100, 100, 100, 100, 100, 100], [Steel, Psychic], 3, 255, GROWTH_SLOW, [32, 0]),
+    /* 386 Deoxys */ PersonalInfo::new([50, 150, 50, 150, 150, 50], [Psychic, Psychic], 3, 255, GROWTH_SLOW, [46, 0]),
+];
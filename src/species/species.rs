@@ -1 +1,4 @@
 pub mod base;
+pub mod conversion;
+pub mod evolution;
+pub mod personal;
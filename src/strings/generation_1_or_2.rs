@@ -1,3 +1,10 @@
+pub mod korean;
+
+use crate::game::enums::language_id::LanguageID;
+
+use self::korean::HANGUL;
+use super::StringConverterOption;
+
 pub const TERMINATOR_CODE: u8 = 0x50;
 pub const TERMINATOR_CHAR: char = '\0';
 pub const TRADE_OR_CODE: u8 = 0x5D;
@@ -16,3 +23,395 @@ pub const TRADE_OT_NAMES: [&str; 9] = [
     "Entrenador",
     "트레이너",
 ];
+
+/// Highest bank byte used by the two-byte Hangul characters of the Korean games
+const HANGUL_LAST_BANK: u8 = 0x0B;
+
+// region: Tables
+
+// Each entry is the text a code decodes to, an empty entry is a code that can't be used in names.
+// Ligatures (like `'d` or `c'`) are a single code that decodes to more than one character.
+
+type CharacterTable = [&'static str; 256];
+
+/// Replaces the entries of `table` starting at the given codes
+const fn with_entries(mut table: CharacterTable, start: u8, entries: &[&'static str]) -> CharacterTable {
+    let mut i = 0;
+    while i < entries.len() {
+        table[start as usize + i] = entries[i];
+        i += 1;
+    }
+
+    table
+}
+
+/// Codes shared by every table: upper/lower case letters, punctuation and digits
+const INTERNATIONAL: CharacterTable = {
+    let table = with_entries([""; 256], 0x7F, &[" "]);
+    let table = with_entries(table, 0x80, &[
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", // 8
+        "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "(", ")", ":", ";", "[", "]", // 9
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", // A
+        "q", "r", "s", "t", "u", "v", "w", "x", "y", "z", // B
+    ]);
+    with_entries(table, 0xE0, &[
+        "'", "ᴾᴷ", "ᴹᴺ", "-", "", "", "?", "!", ".", "", "", "", "", "", "", "♂", // E
+        "$", "×", ".", "/", ",", "♀", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", // F
+    ])
+};
+
+/// English character table
+const ENGLISH: CharacterTable = {
+    let table = with_entries(INTERNATIONAL, 0xBA, &["é", "'d", "'l", "'s", "'t", "'v"]);
+    with_entries(table, 0xE4, &["'r", "'m"])
+};
+
+/// French and German character table
+const FRENCH_GERMAN: CharacterTable = {
+    let table = with_entries(INTERNATIONAL, 0xBA, &[
+        "à", "è", "é", "ù", "ß", "ç", // B
+        "Ä", "Ö", "Ü", "ä", "ö", "ü", "ë", "ï", "â", "ô", "û", "ê", "î", // C
+    ]);
+    with_entries(table, 0xD4, &["c'", "d'", "j'", "l'", "m'", "n'", "p'", "s'", "'s", "t'", "u'", "y'"])
+};
+
+/// Italian and Spanish character table
+const ITALIAN_SPANISH: CharacterTable = with_entries(INTERNATIONAL, 0xBA, &[
+    "à", "è", "é", "ù", "À", "Á", // B
+    "Ä", "Ö", "Ü", "ä", "ö", "ü", "È", "É", "Ì", "Í", "Ñ", "Ò", "Ó", "Ù", "Ú", "á", // C
+    "ì", "í", "ñ", "ò", "ó", "ú", // D
+]);
+
+/// Japanese character table, some katakana share the code of the hiragana that looks the same
+const JAPANESE: CharacterTable = {
+    let table = with_entries([""; 256], 0x05, &[
+        "ガ", "ギ", "グ", "ゲ", "ゴ", "ザ", "ジ", "ズ", "ゼ", "ゾ", "ダ", // 0
+        "ヂ", "ヅ", "デ", "ド", "", "", "", "", "", "バ", "ビ", "ブ", "ボ", // 1
+    ]);
+    let table = with_entries(table, 0x26, &[
+        "が", "ぎ", "ぐ", "げ", "ご", "ざ", "じ", "ず", "ぜ", "ぞ", // 2
+        "だ", "ぢ", "づ", "で", "ど", "", "", "", "", "", "ば", "び", "ぶ", "べ", "ぼ", "", // 3
+        "パ", "ピ", "プ", "ポ", "ぱ", "ぴ", "ぷ", "ぺ", "ぽ", // 4
+    ]);
+    let table = with_entries(table, 0x7F, &["　"]);
+    with_entries(table, 0x80, &[
+        "ア", "イ", "ウ", "エ", "オ", "カ", "キ", "ク", "ケ", "コ", "サ", "シ", "ス", "セ", "ソ", "タ", // 8
+        "チ", "ツ", "テ", "ト", "ナ", "ニ", "ヌ", "ネ", "ノ", "ハ", "ヒ", "フ", "ホ", "マ", "ミ", "ム", // 9
+        "メ", "モ", "ヤ", "ユ", "ヨ", "ラ", "ル", "レ", "ロ", "ワ", "ヲ", "ン", "ッ", "ャ", "ュ", "ョ", // A
+        "ィ", "あ", "い", "う", "え", "お", "か", "き", "く", "け", "こ", "さ", "し", "す", "せ", "そ", // B
+        "た", "ち", "つ", "て", "と", "な", "に", "ぬ", "ね", "の", "は", "ひ", "ふ", "へ", "ほ", "ま", // C
+        "み", "む", "め", "も", "や", "ゆ", "よ", "ら", "り", "る", "れ", "ろ", "わ", "を", "ん", "っ", // D
+        "ゃ", "ゅ", "ょ", "ー", "", "", "？", "！", "。", "ァ", "ゥ", "ェ", "", "", "", "♂", // E
+        "円", "×", ".", "/", "ォ", "♀", "０", "１", "２", "３", "４", "５", "６", "７", "８", "９", // F
+    ])
+};
+
+/// Characters without a code of their own in the Japanese games, and the code used in their place
+const JAPANESE_ALIASES: [(char, u8); 5] = [(' ', 0x7F), ('リ', 0xD8), ('ヘ', 0xCD), ('ベ', 0x3D), ('ペ', 0x47)];
+
+/// Hiragana whose code is shared with the katakana that looks the same, and that katakana
+const SHARED_KANA: [(char, char); 4] = [('り', 'リ'), ('へ', 'ヘ'), ('べ', 'ベ'), ('ぺ', 'ペ')];
+
+/// Codes that can't be used in names decode to `ESCAPE_START + code`, so they are written back unchanged
+const ESCAPE_START: u32 = 0xE000;
+
+fn get_table(language: LanguageID) -> &'static CharacterTable {
+    match language {
+        LanguageID::Japanese => &JAPANESE,
+        LanguageID::French | LanguageID::German => &FRENCH_GERMAN,
+        LanguageID::Italian | LanguageID::Spanish => &ITALIAN_SPANISH,
+        // Korean uses the English table for the single byte codes
+        _ => &ENGLISH,
+    }
+}
+
+// endregion
+
+/// Finds the code of the longest table entry at the start of `value`.
+///
+/// Returns the code and the count of bytes of `value` that it encodes.
+fn find_code(value: &str, language: LanguageID) -> Option<(u8, usize)> {
+    let mut result: Option<(u8, usize)> = None;
+
+    for (code, entry) in get_table(language).iter().enumerate() {
+        if entry.is_empty() || !value.starts_with(entry) {
+            continue;
+        }
+
+        if result.is_none_or(|(_, length)| entry.len() > length) {
+            result = Some((code as u8, entry.len()));
+        }
+    }
+
+    if result.is_none() && language == LanguageID::Japanese {
+        let character = value.chars().next()?;
+        result = JAPANESE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == character)
+            .map(|(_, code)| (*code, character.len_utf8()));
+    }
+
+    result
+}
+
+fn is_katakana(character: char) -> bool {
+    ('ァ'..='ヺ').contains(&character) || character == 'ー'
+}
+
+/// Decodes the codes shared by hiragana and katakana as katakana when they are next to katakana
+fn restore_katakana(value: &str) -> String {
+    let mut characters: Vec<char> = value.chars().collect();
+
+    // Shared codes next to each other take the form of their neighbours, repeat until nothing changes
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for i in 0..characters.len() {
+            let Some((_, katakana)) = SHARED_KANA.iter().find(|(hiragana, _)| *hiragana == characters[i]) else {
+                continue;
+            };
+
+            let previous = i.checked_sub(1).map(|previous| characters[previous]);
+            if previous.is_some_and(is_katakana) || characters.get(i + 1).copied().is_some_and(is_katakana) {
+                characters[i] = *katakana;
+                changed = true;
+            }
+        }
+    }
+
+    characters.into_iter().collect()
+}
+
+/// Replaces the hiragana that share their code with a katakana by that katakana,
+/// so Japanese names can be compared regardless of how the shared codes were decoded.
+pub fn normalize_japanese(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match SHARED_KANA.iter().find(|(hiragana, _)| *hiragana == c) {
+            Some((_, katakana)) => *katakana,
+            None => c,
+        })
+        .collect()
+}
+
+fn escape(value: u8) -> char {
+    char::from_u32(ESCAPE_START + value as u32).unwrap()
+}
+
+/// Decodes a two-byte Korean Hangul character.
+fn get_hangul(bank: u8, index: u8) -> Option<char> {
+    HANGUL.get((bank as usize - 1) * 256 + index as usize).copied()
+}
+
+/// Encodes a Korean Hangul character to its bank and index.
+fn set_hangul(character: char) -> Option<[u8; 2]> {
+    let position = HANGUL.iter().position(|c| *c == character)?;
+    Some([(position / 256 + 1) as u8, (position % 256) as u8])
+}
+
+/// Converts a Generation 1/2 encoded value array to string.
+///
+/// Decoding stops at the terminator. Codes that can't be used in names decode to the private use area,
+/// see `ESCAPE_START`, and Japanese codes shared by hiragana and katakana take the form of their neighbours.
+pub fn get_string(data: &[u8], language: LanguageID) -> String {
+    // In-game trades store a single code that the games display as "TRAINER"
+    if data.first() == Some(&TRADE_OR_CODE) {
+        return TRADE_OT_STRING.to_string();
+    }
+
+    let table = get_table(language);
+    let mut result = String::with_capacity(data.len());
+
+    let mut i = 0;
+    while i < data.len() {
+        let value = data[i];
+        if value == TERMINATOR_CODE {
+            break;
+        }
+
+        if language == LanguageID::Korean && (1..=HANGUL_LAST_BANK).contains(&value) {
+            if let Some(character) = data.get(i + 1).and_then(|index| get_hangul(value, *index)) {
+                result.push(character);
+                i += 2;
+                continue;
+            }
+        }
+
+        let entry = table[value as usize];
+        if entry.is_empty() {
+            result.push(escape(value));
+        } else {
+            result.push_str(entry);
+        }
+
+        i += 1;
+    }
+
+    if language == LanguageID::Japanese {
+        return restore_katakana(&result);
+    }
+
+    result
+}
+
+/// Converts a string to a Generation 1/2 encoded value array.
+///
+/// # Arguments
+/// * `buffer` - Destination of the encoded data
+/// * `value` - Decoded string
+/// * `max_length` - Maximum count of codes written before the terminator
+/// * `language` - Language of the game, selects the character table
+/// * `option` - Buffer pre-formatting option
+///
+/// Returns the count of bytes written, including the terminator.
+pub fn set_string(buffer: &mut [u8], value: &str, max_length: usize, language: LanguageID, option: StringConverterOption) -> usize {
+    if option == StringConverterOption::Clear50 {
+        buffer.fill(TERMINATOR_CODE);
+    } else if option == StringConverterOption::Clear7F {
+        buffer.fill(SPACE_CODE);
+    } else if option == StringConverterOption::ClearZero {
+        buffer.fill(0);
+    }
+
+    if value == TRADE_OT_STRING && buffer.len() >= 2 {
+        buffer[0] = TRADE_OR_CODE;
+        buffer[1] = TERMINATOR_CODE;
+        return 2;
+    }
+
+    let mut count = 0;
+    let mut codes = 0;
+    let mut remaining = value;
+    while !remaining.is_empty() && codes < max_length {
+        if language == LanguageID::Korean {
+            let character = remaining.chars().next().unwrap();
+            if let Some(encoded) = set_hangul(character) {
+                if count + 2 > buffer.len() {
+                    break;
+                }
+
+                buffer[count..count + 2].copy_from_slice(&encoded);
+                count += 2;
+                codes += 1;
+                remaining = &remaining[character.len_utf8()..];
+                continue;
+            }
+        }
+
+        let character = remaining.chars().next().unwrap();
+        let escaped = (ESCAPE_START..ESCAPE_START + 0x100).contains(&(character as u32));
+
+        let Some((code, length)) = (if escaped {
+            Some(((character as u32 - ESCAPE_START) as u8, character.len_utf8()))
+        } else {
+            find_code(remaining, language)
+        }) else {
+            break;
+        };
+
+        if count >= buffer.len() {
+            break;
+        }
+
+        buffer[count] = code;
+        count += 1;
+        codes += 1;
+        remaining = &remaining[length..];
+    }
+
+    if count < buffer.len() {
+        buffer[count] = TERMINATOR_CODE;
+        count += 1;
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::language_id::LanguageID,
+        strings::{generation_1_or_2::*, StringConverterOption},
+    };
+
+    /// Encodes the string into a nickname sized buffer and checks that it decodes back to the same string
+    fn round_trip(value: &str, language: LanguageID) -> Vec<u8> {
+        let mut buffer = [0u8; 11];
+        set_string(&mut buffer, value, 10, language, StringConverterOption::Clear50);
+        assert_eq!(get_string(&buffer, language), value);
+
+        buffer.to_vec()
+    }
+
+    #[test]
+    fn encode_international_names() {
+        assert_eq!(round_trip("MR.MIME", LanguageID::English)[..8], [0x8C, 0x91, 0xE8, 0x8C, 0x88, 0x8C, 0x84, 0x50]);
+        assert_eq!(round_trip("NIDORAN♀", LanguageID::English)[7..9], [0xF5, 0x50]);
+        round_trip("Flambée", LanguageID::French);
+        round_trip("Größe", LanguageID::German);
+        round_trip("Ñandú", LanguageID::Spanish);
+        round_trip("Città", LanguageID::Italian);
+    }
+
+    #[test]
+    fn encode_ligatures() {
+        assert_eq!(round_trip("we'd", LanguageID::English)[..4], [0xB6, 0xA4, 0xBB, 0x50]);
+        assert_eq!(round_trip("l'ami", LanguageID::French)[..4], [0xD7, 0xA0, 0xAC, 0xA8]);
+    }
+
+    #[test]
+    fn encode_japanese_names() {
+        assert_eq!(round_trip("ピカチュウ", LanguageID::Japanese)[..6], [0x41, 0x85, 0x90, 0xAE, 0x82, 0x50]);
+        // Katakana リ shares the code of the hiragana り
+        assert_eq!(round_trip("リザードン", LanguageID::Japanese)[0], 0xD8);
+        round_trip("ヘラクロス", LanguageID::Japanese);
+        round_trip("ベトベター", LanguageID::Japanese);
+        round_trip("りんご", LanguageID::Japanese);
+        assert_eq!(normalize_japanese("りザードン"), "リザードン");
+    }
+
+    #[test]
+    fn unknown_codes_are_kept() {
+        let data = [0x80, 0x00, 0x81, 0x50];
+        let decoded = get_string(&data, LanguageID::English);
+        assert_eq!(decoded.chars().count(), 3);
+        assert!(decoded.starts_with('A') && decoded.ends_with('B'));
+
+        let mut buffer = [0u8; 4];
+        set_string(&mut buffer, &decoded, 3, LanguageID::English, StringConverterOption::Clear50);
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn encode_korean_names() {
+        assert_eq!(round_trip("피카츄", LanguageID::Korean).len(), 11);
+        assert_eq!(round_trip("가", LanguageID::Korean)[..3], [0x01, 0x00, 0x50]);
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let data = [0x80, 0x8B, 0xE7, 0xBB, 0xF6, 0x7F, 0x9A, 0x50, 0x50, 0x50, 0x50];
+        let mut buffer = [0u8; 11];
+        set_string(&mut buffer, &get_string(&data, LanguageID::English), 10, LanguageID::English, StringConverterOption::Clear50);
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn buffer_options() {
+        let mut buffer = [0u8; 6];
+        assert_eq!(set_string(&mut buffer, "AB", 5, LanguageID::English, StringConverterOption::Clear7F), 3);
+        assert_eq!(buffer, [0x80, 0x81, 0x50, 0x7F, 0x7F, 0x7F]);
+
+        assert_eq!(set_string(&mut buffer, "TOOLONGNAME", 5, LanguageID::English, StringConverterOption::Clear50), 6);
+        assert_eq!(get_string(&buffer, LanguageID::English), "TOOLO");
+    }
+
+    #[test]
+    fn trade_ot_marker() {
+        let mut buffer = [0u8; 11];
+        assert_eq!(set_string(&mut buffer, TRADE_OT_STRING, 10, LanguageID::English, StringConverterOption::Clear50), 2);
+        assert_eq!(buffer[..3], [TRADE_OR_CODE, TERMINATOR_CODE, TERMINATOR_CODE]);
+        assert_eq!(get_string(&buffer, LanguageID::English), TRADE_OT_STRING);
+    }
+}
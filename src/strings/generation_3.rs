@@ -1,3 +1,7 @@
+use std::fmt::Display;
+
+use crate::game::enums::language_id::LanguageID;
+
 use super::StringConverterOption;
 
 const TERMINATOR_BYTE: u8 = 0xFF;
@@ -49,64 +53,152 @@ const JP: [char; 256] =
     TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR, TERMINATOR,
 ];
 
+/// First character of the Unicode private use area block that keeps the codes without a character.
+///
+/// Control codes and unused codes decode to `ESCAPE_START + code`, so they are written back unchanged.
+const ESCAPE_START: u32 = 0xE000;
+
+/// Codes that decode to a different character in the French and German games
+const QUOTES_FRENCH: [(u8, char); 2] = [(0xB1, '«'), (0xB2, '»')];
+const QUOTES_GERMAN: [(u8, char); 2] = [(0xB1, '„'), (0xB2, '“')];
+
+/// A character that does not exist in the character table of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnencodableCharacter {
+    pub character: char,
+    /// Position of the character in the string
+    pub index: usize,
+}
+
+impl Display for UnencodableCharacter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Character '{}' at position {} can't be encoded", self.character, self.index)
+    }
+}
+
+fn get_table(language: LanguageID) -> &'static [char; 256] {
+    if language == LanguageID::Japanese { &JP } else { &EN }
+}
+
+fn get_quotes(language: LanguageID) -> &'static [(u8, char)] {
+    match language {
+        LanguageID::French => &QUOTES_FRENCH,
+        LanguageID::German => &QUOTES_GERMAN,
+        _ => &[],
+    }
+}
+
+/// Gets the language whose table is used when only the font is known
+fn get_language(is_japanese: bool) -> LanguageID {
+    if is_japanese { LanguageID::Japanese } else { LanguageID::English }
+}
+
 /// Decodes a character from a Generation 3 encoded value.
 ///
-/// `character` - Generation 4 decoded character.
-/// `is_japanese` - Character destination is Japanese font.
-fn get_char(character: u8, is_japanese: bool) -> char
-{
-    let table = if is_japanese { JP } else { EN };
+/// Codes without a character of their own decode to the private use area, see `ESCAPE_START`.
+fn get_char(value: u8, language: LanguageID) -> char {
+    if let Some((_, character)) = get_quotes(language).iter().find(|(code, _)| *code == value) {
+        return *character;
+    }
+
+    let table = get_table(language);
+    let character = table[value as usize];
 
-    table[character as usize]
+    // Codes that repeat a character of a previous code are escaped so they are not merged when encoding
+    if character == TERMINATOR || table.iter().position(|c| *c == character) != Some(value as usize) {
+        return char::from_u32(ESCAPE_START + value as u32).unwrap();
+    }
+
+    character
 }
 
-/// Converts a Generation 3 encoded value array to string.
-pub fn get_string(data: &[u8], is_japanese: bool) -> String {
-    let mut result = Vec::<char>::with_capacity(data.len());
+/// Encodes a character to a Generation 3 encoded value, returns `None` if the language has no code for it.
+fn set_char(character: char, language: LanguageID) -> Option<u8> {
+    if (ESCAPE_START..ESCAPE_START + TERMINATOR_BYTE as u32).contains(&(character as u32)) {
+        return Some((character as u32 - ESCAPE_START) as u8);
+    }
 
-    let mut i = 0;
-    while i < data.len()
-    {
-        let value = data[i];
-        let character = get_char(value, is_japanese); // Convert to Unicode
-        if character == TERMINATOR { // Stop if Terminator/Invalid
+    if character == '\'' { // ’
+        return Some(0xB4);
+    }
+
+    if let Some((code, _)) = get_quotes(language).iter().find(|(_, c)| *c == character) {
+        return Some(*code);
+    }
+
+    if character == TERMINATOR {
+        return None;
+    }
+
+    get_table(language).iter().position(|c| *c == character).map(|index| index as u8)
+}
+
+/// Converts a Generation 3 encoded value array to string, using the characters of the given language.
+pub fn decode(data: &[u8], language: LanguageID) -> String {
+    let mut result = String::with_capacity(data.len());
+
+    for value in data {
+        if *value == TERMINATOR_BYTE {
             break;
         }
-        result[i] = character;
 
-        i += i;
+        result.push(get_char(*value, language));
     }
 
-    result[..i].iter().collect()
+    result
 }
 
-/// Encodes a character to a Generation 3 encoded value.
+/// Converts a string to a Generation 3 encoded value array, using the characters of the given language.
 ///
-/// `character` - Generation 4 decoded character.
-/// `is_japanese` - Character destination is Japanese font.
-fn set_char(character: char, is_japanese: bool) -> u8 {
-    if character == '\'' { // ’
-        return 0xB4;
+/// # Arguments
+/// * `buffer` - Destination of the encoded data
+/// * `value` - Decoded string
+/// * `max_length` - Maximum length of the input `value`
+/// * `language` - Language of the game, selects the character table
+/// * `option` - Buffer pre-formatting option
+///
+/// Returns the count of bytes written, including the terminator,
+/// or the first character that can't be encoded, in which case the buffer is not modified.
+pub fn encode(buffer: &mut [u8], value: &str, max_length: usize, language: LanguageID, option: StringConverterOption) -> Result<usize, UnencodableCharacter> {
+    let encoded = value
+        .chars()
+        .take(max_length.min(buffer.len()))
+        .enumerate()
+        .map(|(index, character)| set_char(character, language).ok_or(UnencodableCharacter { character, index }))
+        .collect::<Result<Vec<u8>, UnencodableCharacter>>()?;
+
+    if option == StringConverterOption::ClearFF {
+        buffer.fill(TERMINATOR_BYTE);
+    } else if option == StringConverterOption::ClearZero {
+        buffer.fill(0);
     }
 
-    let table = if is_japanese { JP } else { EN };
-    let index = table.iter().position(|c| c == &character);
+    buffer[..encoded.len()].copy_from_slice(&encoded);
 
-    if index.is_none() {
-        return TERMINATOR_BYTE;
+    let mut count = encoded.len();
+    if count < buffer.len() {
+        buffer[count] = TERMINATOR_BYTE;
+        count += 1;
     }
 
-    index.unwrap() as u8
+    Ok(count)
+}
+
+/// Converts a Generation 3 encoded value array to string.
+pub fn get_string(data: &[u8], is_japanese: bool) -> String {
+    decode(data, get_language(is_japanese))
 }
 
-/// Converts a string to a Generation 3 encoded value array.
+/// Converts a string to a Generation 3 encoded value array, stopping at the first character that can't be encoded.
+///
+/// # Arguments
+/// * `buffer` - Destination of the encoded data
+/// * `value` - Decoded string
+/// * `max_length` - Maximum length of the input `value`
+/// * `is_japanese` - String destination is Japanese font
+/// * `option` - Buffer pre-formatting option
 ///
-/// <param name="buffer"></param>
-/// <param name="value">Decoded string.</param>
-/// <param name="maxLength">Maximum length of the input <see cref="value"/></param>
-/// <param name="jp">String destination is Japanese font.</param>
-/// <param name="option">Buffer pre-formatting option</param>
-/// <returns>Encoded data.</returns>
+/// Returns the count of bytes written, including the terminator.
 pub fn set_string(buffer: &mut [u8], mut value: &mut [char], max_length: usize, is_japanese: bool, option: StringConverterOption) -> usize {
     if value.len() > max_length {
         value = &mut value[..max_length]; // Hard cap
@@ -118,15 +210,13 @@ pub fn set_string(buffer: &mut [u8], mut value: &mut [char], max_length: usize,
         buffer.fill(0);
     }
 
+    let language = get_language(is_japanese);
     let mut i = 0;
-    while i < value.len()
-    {
-        let chr = value[i];
-        let b = set_char(chr, is_japanese);
-        if b == TERMINATOR_BYTE {
-            break;
+    while i < value.len() && i < buffer.len() {
+        match set_char(value[i], language) {
+            Some(b) => buffer[i] = b,
+            None => break,
         }
-        buffer[i] = b;
 
         i += 1;
     }
@@ -139,3 +229,74 @@ pub fn set_string(buffer: &mut [u8], mut value: &mut [char], max_length: usize,
 
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::language_id::LanguageID,
+        strings::{generation_3::*, StringConverterOption},
+    };
+
+    const LANGUAGES: [LanguageID; 6] = [
+        LanguageID::Japanese,
+        LanguageID::English,
+        LanguageID::French,
+        LanguageID::Italian,
+        LanguageID::German,
+        LanguageID::Spanish,
+    ];
+
+    #[test]
+    fn every_code_round_trips() {
+        for language in LANGUAGES {
+            for value in 0..TERMINATOR_BYTE {
+                let decoded = decode(&[value, TERMINATOR_BYTE], language);
+                assert_eq!(decoded.chars().count(), 1);
+
+                let mut buffer = [0u8; 2];
+                assert_eq!(encode(&mut buffer, &decoded, 1, language, StringConverterOption::ClearFF), Ok(2));
+                assert_eq!(buffer, [value, TERMINATOR_BYTE], "{:?} code {:#04X}", language, value);
+            }
+        }
+    }
+
+    #[test]
+    fn every_character_round_trips() {
+        for language in [LanguageID::Japanese, LanguageID::English] {
+            for character in get_table(language).iter().filter(|c| **c != TERMINATOR) {
+                let mut buffer = [0u8; 2];
+                encode(&mut buffer, &character.to_string(), 1, language, StringConverterOption::ClearFF).unwrap();
+                assert_eq!(decode(&buffer, language).chars().next(), Some(*character));
+            }
+        }
+    }
+
+    #[test]
+    fn quotation_marks() {
+        assert_eq!(decode(&[0xB1, 0xB2], LanguageID::English), "“”");
+        assert_eq!(decode(&[0xB1, 0xB2], LanguageID::French), "«»");
+        assert_eq!(decode(&[0xB1, 0xB2], LanguageID::German), "„“");
+    }
+
+    #[test]
+    fn control_codes_are_kept() {
+        let data = [0xCE, 0xFC, 0x01, 0xFE, 0xCF, TERMINATOR_BYTE];
+        let decoded = decode(&data, LanguageID::English);
+
+        let mut buffer = [0u8; 6];
+        assert_eq!(encode(&mut buffer, &decoded, 10, LanguageID::English, StringConverterOption::ClearFF), Ok(6));
+        assert_eq!(buffer, data);
+    }
+
+    #[test]
+    fn report_unencodable_character() {
+        let mut buffer = [0u8; 11];
+        assert_eq!(
+            encode(&mut buffer, "PIKA漢", 10, LanguageID::English, StringConverterOption::ClearFF),
+            Err(UnencodableCharacter { character: '漢', index: 4 })
+        );
+        assert_eq!(buffer, [0u8; 11]);
+        assert!(encode(&mut buffer, "ピカチュウ", 10, LanguageID::English, StringConverterOption::ClearFF).is_err());
+        assert_eq!(encode(&mut buffer, "ピカチュウ", 5, LanguageID::Japanese, StringConverterOption::ClearFF), Ok(6));
+    }
+}
@@ -0,0 +1,195 @@
+pub mod abilities;
+pub mod characteristics;
+pub mod items;
+pub mod moves;
+pub mod natures;
+pub mod species;
+
+use crate::game::enums::{language_id::LanguageID, pokemon_nature::PokemonNature, species_id::SpeciesID};
+
+use self::{
+    abilities::ABILITIES_ENGLISH,
+    characteristics::CHARACTERISTICS_ENGLISH,
+    items::ITEMS_ENGLISH,
+    moves::MOVES_ENGLISH,
+    natures::*,
+    species::{SPECIES_ENGLISH, SPECIES_FRENCH, SPECIES_GERMAN, SPECIES_JAPANESE, SPECIES_KOREAN},
+};
+
+// Every lookup returns `None` when there is no table for the requested language,
+// or when the value is outside of the table (unused or newer than Generation 3).
+// Moves, items, abilities and characteristics only have English tables, so their
+// lookups are English-only and take no language.
+
+// region: Tables
+
+fn species_table(language: LanguageID) -> Option<&'static [&'static str]> {
+    match language {
+        LanguageID::English | LanguageID::Spanish | LanguageID::Italian => Some(&SPECIES_ENGLISH),
+        LanguageID::Japanese => Some(&SPECIES_JAPANESE),
+        LanguageID::French => Some(&SPECIES_FRENCH),
+        LanguageID::German => Some(&SPECIES_GERMAN),
+        LanguageID::Korean => Some(&SPECIES_KOREAN),
+        _ => None,
+    }
+}
+
+fn natures_table(language: LanguageID) -> Option<&'static [&'static str]> {
+    match language {
+        LanguageID::Japanese => Some(&NATURES_JAPANESE),
+        LanguageID::English => Some(&NATURES_ENGLISH),
+        LanguageID::French => Some(&NATURES_FRENCH),
+        LanguageID::Italian => Some(&NATURES_ITALIAN),
+        LanguageID::German => Some(&NATURES_GERMAN),
+        LanguageID::Spanish => Some(&NATURES_SPANISH),
+        LanguageID::Korean => Some(&NATURES_KOREAN),
+        _ => None,
+    }
+}
+
+/// Gets a non-empty entry from a name table
+fn get_name(table: Option<&'static [&'static str]>, index: usize) -> Option<&'static str> {
+    match table?.get(index) {
+        Some(name) if !name.is_empty() => Some(name),
+        _ => None,
+    }
+}
+
+/// Finds the index of a name in a name table, ignoring case and surrounding whitespace
+fn find_name(table: Option<&'static [&'static str]>, name: &str) -> Option<usize> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() {
+        return None;
+    }
+
+    table?.iter().position(|entry| entry.to_lowercase() == name)
+}
+
+// endregion
+
+/// Gets the name of a species in the given language.
+pub fn species_name(species: SpeciesID, language: LanguageID) -> Option<&'static str> {
+    get_name(species_table(language), species.national_id() as usize)
+}
+
+/// Gets the species from its name in the given language.
+pub fn species_from_name(name: &str, language: LanguageID) -> Option<SpeciesID> {
+    find_name(species_table(language), name).and_then(|index| SpeciesID::from_national_id(index as u16))
+}
+
+/// Gets the English name of a move.
+pub fn move_name(move_id: u16) -> Option<&'static str> {
+    get_name(Some(&MOVES_ENGLISH), move_id as usize)
+}
+
+/// Gets the move ID from its English name.
+pub fn move_from_name(name: &str) -> Option<u16> {
+    find_name(Some(&MOVES_ENGLISH), name).map(|index| index as u16)
+}
+
+/// Gets the English name of an item.
+///
+/// `item_id` - Generation 3 item ID.
+pub fn item_name(item_id: u16) -> Option<&'static str> {
+    get_name(Some(&ITEMS_ENGLISH), item_id as usize)
+}
+
+/// Gets the Generation 3 item ID from its English name.
+pub fn item_from_name(name: &str) -> Option<u16> {
+    find_name(Some(&ITEMS_ENGLISH), name).map(|index| index as u16)
+}
+
+/// Gets the English name of an ability.
+pub fn ability_name(ability_id: u16) -> Option<&'static str> {
+    get_name(Some(&ABILITIES_ENGLISH), ability_id as usize)
+}
+
+/// Gets the ability ID from its English name.
+pub fn ability_from_name(name: &str) -> Option<u16> {
+    find_name(Some(&ABILITIES_ENGLISH), name).map(|index| index as u16)
+}
+
+/// Gets the name of a nature in the given language.
+pub fn nature_name(nature: PokemonNature, language: LanguageID) -> Option<&'static str> {
+    get_name(natures_table(language), nature as usize)
+}
+
+/// Gets the nature from its name in the given language.
+pub fn nature_from_name(name: &str, language: LanguageID) -> Option<PokemonNature> {
+    find_name(natures_table(language), name).map(|index| PokemonNature::from(index as u8))
+}
+
+/// Gets the English characteristic text.
+///
+/// `index` - Characteristic index, see `pokemon::utils::hidden_power::characteristic`.
+pub fn characteristic_name(index: u8) -> Option<&'static str> {
+    get_name(Some(&CHARACTERISTICS_ENGLISH), index as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::enums::{language_id::LanguageID, pokemon_nature::PokemonNature, species_id::SpeciesID},
+        strings::names::*,
+    };
+
+    #[test]
+    fn species_name_matches_species_id() {
+        assert_eq!(species_name(SpeciesID::Bulbasaur, LanguageID::English), Some("Bulbasaur"));
+        assert_eq!(species_name(SpeciesID::Deoxys, LanguageID::English), Some("Deoxys"));
+        assert_eq!(species_name(SpeciesID::MrMime, LanguageID::French), Some("M. Mime"));
+        assert_eq!(species_name(SpeciesID::Mew, LanguageID::Japanese), Some("ミュウ"));
+        assert_eq!(species_name(SpeciesID::Chikorita, LanguageID::German), Some("Endivie"));
+        assert_eq!(species_name(SpeciesID::Rayquaza, LanguageID::Japanese), Some("レックウザ"));
+        assert_eq!(species_name(SpeciesID::Pikachu, LanguageID::Korean), Some("피카츄"));
+        assert_eq!(species_name(SpeciesID::None, LanguageID::English), None);
+    }
+
+    #[test]
+    fn species_names_every_language() {
+        let languages = [
+            LanguageID::Japanese,
+            LanguageID::English,
+            LanguageID::French,
+            LanguageID::Italian,
+            LanguageID::German,
+            LanguageID::Spanish,
+            LanguageID::Korean,
+        ];
+
+        for language in languages {
+            for id in 1..=386 {
+                let species = SpeciesID::from_national_id(id).unwrap();
+                let name = species_name(species, language).unwrap();
+                assert_eq!(species_from_name(name, language), Some(species), "{name} in {language:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn species_reverse_lookup_ignores_case() {
+        assert_eq!(species_from_name("BULBASAUR", LanguageID::English), Some(SpeciesID::Bulbasaur));
+        assert_eq!(species_from_name("glurak", LanguageID::German), Some(SpeciesID::Charizard));
+        assert_eq!(species_from_name("Ho-Oh", LanguageID::Spanish), Some(SpeciesID::HoOh));
+        assert_eq!(species_from_name("", LanguageID::English), None);
+    }
+
+    #[test]
+    fn lookup_round_trip() {
+        (1..=354).for_each(|id| assert_eq!(move_from_name(move_name(id).unwrap()), Some(id)));
+        (1..=77).for_each(|id| assert_eq!(ability_from_name(ability_name(id).unwrap()), Some(id)));
+
+        assert_eq!(item_name(12), Some("Premier Ball"));
+        assert_eq!(item_from_name("tm01"), Some(289));
+        assert_eq!(item_name(52), None);
+        assert!(characteristic_name(0).is_some());
+    }
+
+    #[test]
+    fn nature_names_every_language() {
+        assert_eq!(nature_name(PokemonNature::Adamant, LanguageID::English), Some("Adamant"));
+        assert_eq!(nature_name(PokemonNature::Adamant, LanguageID::German), Some("Hart"));
+        assert_eq!(nature_from_name("Timide", LanguageID::French), Some(PokemonNature::Timid));
+        assert_eq!(nature_name(PokemonNature::Random, LanguageID::English), None);
+    }
+}
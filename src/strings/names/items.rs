@@ -0,0 +1,53 @@
+// Item names indexed by the Generation 3 item ID, unused IDs are left empty.
+
+/// Item names in English
+pub const ITEMS_ENGLISH: [&str; 377] = [
+    "", "Master Ball", "Ultra Ball", "Great Ball", "Poké Ball", "Safari Ball", "Net Ball", "Dive Ball", // 0
+    "Nest Ball", "Repeat Ball", "Timer Ball", "Luxury Ball", "Premier Ball", "Potion", "Antidote", "Burn Heal", // 8
+    "Ice Heal", "Awakening", "Parlyz Heal", "Full Restore", "Max Potion", "Hyper Potion", "Super Potion", "Full Heal", // 16
+    "Revive", "Max Revive", "Fresh Water", "Soda Pop", "Lemonade", "Moomoo Milk", "EnergyPowder", "Energy Root", // 24
+    "Heal Powder", "Revival Herb", "Ether", "Max Ether", "Elixir", "Max Elixir", "Lava Cookie", "Blue Flute", // 32
+    "Yellow Flute", "Red Flute", "Black Flute", "White Flute", "Berry Juice", "Sacred Ash", "Shoal Salt", "Shoal Shell", // 40
+    "Red Shard", "Blue Shard", "Yellow Shard", "Green Shard", "", "", "", "", // 48
+    "", "", "", "", "", "", "", "HP Up", // 56
+    "Protein", "Iron", "Carbos", "Calcium", "Rare Candy", "PP Up", "Zinc", "PP Max", // 64
+    "", "Guard Spec.", "Dire Hit", "X Attack", "X Defend", "X Speed", "X Accuracy", "X Special", // 72
+    "Poké Doll", "Fluffy Tail", "", "Super Repel", "Max Repel", "Escape Rope", "Repel", "", // 80
+    "", "", "", "", "", "Sun Stone", "Moon Stone", "Fire Stone", // 88
+    "Thunderstone", "Water Stone", "Leaf Stone", "", "", "", "", "TinyMushroom", // 96
+    "Big Mushroom", "", "Pearl", "Big Pearl", "Stardust", "Star Piece", "Nugget", "Heart Scale", // 104
+    "", "", "", "", "", "", "", "", // 112
+    "", "Orange Mail", "Harbor Mail", "Glitter Mail", "Mech Mail", "Wood Mail", "Wave Mail", "Bead Mail", // 120
+    "Shadow Mail", "Tropic Mail", "Dream Mail", "Fab Mail", "Retro Mail", "Cheri Berry", "Chesto Berry", "Pecha Berry", // 128
+    "Rawst Berry", "Aspear Berry", "Leppa Berry", "Oran Berry", "Persim Berry", "Lum Berry", "Sitrus Berry", "Figy Berry", // 136
+    "Wiki Berry", "Mago Berry", "Aguav Berry", "Iapapa Berry", "Razz Berry", "Bluk Berry", "Nanab Berry", "Wepear Berry", // 144
+    "Pinap Berry", "Pomeg Berry", "Kelpsy Berry", "Qualot Berry", "Hondew Berry", "Grepa Berry", "Tamato Berry", "Cornn Berry", // 152
+    "Magost Berry", "Rabuta Berry", "Nomel Berry", "Spelon Berry", "Pamtre Berry", "Watmel Berry", "Durin Berry", "Belue Berry", // 160
+    "Liechi Berry", "Ganlon Berry", "Salac Berry", "Petaya Berry", "Apicot Berry", "Lansat Berry", "Starf Berry", "Enigma Berry", // 168
+    "", "", "", "BrightPowder", "White Herb", "Macho Brace", "Exp. Share", "Quick Claw", // 176
+    "Soothe Bell", "Mental Herb", "Choice Band", "King's Rock", "SilverPowder", "Amulet Coin", "Cleanse Tag", "Soul Dew", // 184
+    "DeepSeaTooth", "DeepSeaScale", "Smoke Ball", "Everstone", "Focus Band", "Lucky Egg", "Scope Lens", "Metal Coat", // 192
+    "Leftovers", "Dragon Scale", "Light Ball", "Soft Sand", "Hard Stone", "Miracle Seed", "BlackGlasses", "Black Belt", // 200
+    "Magnet", "Mystic Water", "Sharp Beak", "Poison Barb", "NeverMeltIce", "Spell Tag", "TwistedSpoon", "Charcoal", // 208
+    "Dragon Fang", "Silk Scarf", "Up-Grade", "Shell Bell", "Sea Incense", "Lax Incense", "Lucky Punch", "Metal Powder", // 216
+    "Thick Club", "Stick", "", "", "", "", "", "", // 224
+    "", "", "", "", "", "", "", "", // 232
+    "", "", "", "", "", "", "", "", // 240
+    "", "", "", "", "", "", "Red Scarf", "Blue Scarf", // 248
+    "Pink Scarf", "Green Scarf", "Yellow Scarf", "Mach Bike", "Coin Case", "Itemfinder", "Old Rod", "Good Rod", // 256
+    "Super Rod", "S.S. Ticket", "Contest Pass", "", "Wailmer Pail", "Devon Goods", "Soot Sack", "Basement Key", // 264
+    "Acro Bike", "Pokéblock Case", "Letter", "Eon Ticket", "Red Orb", "Blue Orb", "Scanner", "Go-Goggles", // 272
+    "Meteorite", "Rm. 1 Key", "Rm. 2 Key", "Rm. 4 Key", "Rm. 6 Key", "Storage Key", "Root Fossil", "Claw Fossil", // 280
+    "Devon Scope", "TM01", "TM02", "TM03", "TM04", "TM05", "TM06", "TM07", // 288
+    "TM08", "TM09", "TM10", "TM11", "TM12", "TM13", "TM14", "TM15", // 296
+    "TM16", "TM17", "TM18", "TM19", "TM20", "TM21", "TM22", "TM23", // 304
+    "TM24", "TM25", "TM26", "TM27", "TM28", "TM29", "TM30", "TM31", // 312
+    "TM32", "TM33", "TM34", "TM35", "TM36", "TM37", "TM38", "TM39", // 320
+    "TM40", "TM41", "TM42", "TM43", "TM44", "TM45", "TM46", "TM47", // 328
+    "TM48", "TM49", "TM50", "HM01", "HM02", "HM03", "HM04", "HM05", // 336
+    "HM06", "HM07", "HM08", "", "", "Oak's Parcel", "Poké Flute", "Secret Key", // 344
+    "Bike Voucher", "Gold Teeth", "Old Amber", "Card Key", "Lift Key", "Helix Fossil", "Dome Fossil", "Silph Scope", // 352
+    "Bicycle", "Town Map", "VS Seeker", "Fame Checker", "TM Case", "Berry Pouch", "Teachy TV", "Tri-Pass", // 360
+    "Rainbow Pass", "Tea", "MysticTicket", "AuroraTicket", "Powder Jar", "Ruby", "Sapphire", "Magma Emblem", // 368
+    "Old Sea Map", // 376
+];
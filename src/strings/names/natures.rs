@@ -0,0 +1,64 @@
+// Nature names indexed by the nature value (`PID % 25` up to Generation 4).
+
+/// Nature names in English
+pub const NATURES_ENGLISH: [&str; 25] = [
+    "Hardy", "Lonely", "Brave", "Adamant", "Naughty",
+    "Bold", "Docile", "Relaxed", "Impish", "Lax",
+    "Timid", "Hasty", "Serious", "Jolly", "Naive",
+    "Modest", "Mild", "Quiet", "Bashful", "Rash",
+    "Calm", "Gentle", "Sassy", "Careful", "Quirky",
+];
+
+/// Nature names in Japanese
+pub const NATURES_JAPANESE: [&str; 25] = [
+    "がんばりや", "さみしがり", "ゆうかん", "いじっぱり", "やんちゃ",
+    "ずぶとい", "すなお", "のんき", "わんぱく", "のうてんき",
+    "おくびょう", "せっかち", "まじめ", "ようき", "むじゃき",
+    "ひかえめ", "おっとり", "れいせい", "てれや", "うっかりや",
+    "おだやか", "おとなしい", "なまいき", "しんちょう", "きまぐれ",
+];
+
+/// Nature names in French
+pub const NATURES_FRENCH: [&str; 25] = [
+    "Hardi", "Solo", "Brave", "Rigide", "Mauvais",
+    "Assuré", "Docile", "Relax", "Malin", "Lâche",
+    "Timide", "Pressé", "Sérieux", "Jovial", "Naïf",
+    "Modeste", "Doux", "Discret", "Pudique", "Foufou",
+    "Calme", "Gentil", "Malpoli", "Prudent", "Bizarre",
+];
+
+/// Nature names in Italian
+pub const NATURES_ITALIAN: [&str; 25] = [
+    "Ardita", "Schiva", "Audace", "Decisa", "Birbona",
+    "Sicura", "Docile", "Placida", "Scaltra", "Fiacca",
+    "Timida", "Lesta", "Seria", "Allegra", "Ingenua",
+    "Modesta", "Mite", "Quieta", "Ritrosa", "Ardente",
+    "Calma", "Gentile", "Vivace", "Cauta", "Furba",
+];
+
+/// Nature names in German
+pub const NATURES_GERMAN: [&str; 25] = [
+    "Robust", "Solo", "Mutig", "Hart", "Frech",
+    "Kühn", "Sanft", "Locker", "Pfiffig", "Lasch",
+    "Scheu", "Hastig", "Ernst", "Froh", "Naiv",
+    "Mäßig", "Mild", "Ruhig", "Zaghaft", "Hitzig",
+    "Still", "Zart", "Forsch", "Sacht", "Kauzig",
+];
+
+/// Nature names in Spanish
+pub const NATURES_SPANISH: [&str; 25] = [
+    "Fuerte", "Huraña", "Audaz", "Firme", "Pícara",
+    "Osada", "Dócil", "Plácida", "Agitada", "Floja",
+    "Miedosa", "Activa", "Seria", "Alegre", "Ingenua",
+    "Modesta", "Afable", "Mansa", "Tímida", "Alocada",
+    "Serena", "Amable", "Grosera", "Cauta", "Rara",
+];
+
+/// Nature names in Korean
+pub const NATURES_KOREAN: [&str; 25] = [
+    "노력", "외로움", "용감", "고집", "개구쟁이",
+    "대담", "온순", "무사태평", "장난꾸러기", "촐랑",
+    "겁쟁이", "성급", "성실", "명랑", "천진난만",
+    "조심", "의젓", "냉정", "수줍음", "덜렁",
+    "차분", "얌전", "건방", "신중", "변덕",
+];
@@ -1,5 +1,6 @@
 pub mod generation_1_or_2;
 pub mod generation_3;
+pub mod names;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum StringConverterOption {
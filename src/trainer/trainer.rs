@@ -42,7 +42,7 @@ impl TrainerInfo {
     }
 
     fn set_id_new(&mut self, sid_new: u16, tid_new: u16) {
-        let new_id = (((sid_new as u32) * 1_000_000) + ((tid_new as u32) % 1_000_000)) as u32;
+        let new_id = ((sid_new as u32) * 1_000_000) + ((tid_new as u32) % 1_000_000);
         self.public_id = new_id as u16;
         self.secret_id = (new_id >> 16) as u16;
     }
@@ -8,3 +8,5 @@ pub const MAX_MONEY_DEFAULT: u32 = 9999999;
 pub const MAX_COINS_DEFAULT: u16 = 999;
 
 pub const MAX_GIFT_FLAG_COUNT_DEFAULT: u32 = 0x800; // 2048
+
+pub const MAX_PARTY_SIZE: usize = 6;
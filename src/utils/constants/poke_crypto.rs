@@ -52,6 +52,12 @@ const BLOCK_SHUFFLE_POSITION: [u8; 128] =
     1, 0, 3, 2,
 ];
 
+/// Positions for unshuffling.
+const BLOCK_POSITION_INVERT: [u8; 32] = [
+    0, 1, 2, 4, 3, 5, 6, 7, 12, 18, 13, 19, 8, 10, 14, 20, 16, 22, 9, 11, 15, 21, 17, 23,
+    0, 1, 2, 4, 3, 5, 6, 7, // duplicates of 0-7 to eliminate modulus
+];
+
 /// Shuffles an 80 byte format Generation 3 Pokémon byte array.
 ///
 /// # Arguments
@@ -83,7 +89,7 @@ pub fn decrypt_generation3_array(data: &[u8]) -> Vec<u8> {
   let mut data_clone = data.to_vec();
   let to_decrypt = &mut data_clone[SIZE_GENERATION_3_HEADER..SIZE_GENERATION_3_STORED as usize];
 
-  (0..(data.len())).for_each(|i| {
+  (0..(to_decrypt.len())).step_by(4).for_each(|i| {
     let block = to_decrypt.get_mutable_offset(i, 4);
 
     let chunk = block.get_u32_le().unwrap();
@@ -95,32 +101,70 @@ pub fn decrypt_generation3_array(data: &[u8]) -> Vec<u8> {
   shuffle_generation3_array(&data_clone, (pid as usize) % 24)
 }
 
+/// Encrypts an 80 byte format Generation 3 Pokémon byte array, the party data after it is left as is.
+pub fn encrypt_generation3_array(data: &[u8]) -> Vec<u8> {
+  let pid = data.get_u32_le().unwrap();
+  let oid = data.get_u32_le_offset(4).unwrap();
+
+  let seed = pid ^ oid;
+
+  let mut result = shuffle_generation3_array(data, BLOCK_POSITION_INVERT[(pid as usize) % 24] as usize);
+  let to_encrypt = &mut result[SIZE_GENERATION_3_HEADER..SIZE_GENERATION_3_STORED as usize];
+
+  (0..(to_encrypt.len())).step_by(4).for_each(|i| {
+    let block = to_encrypt.get_mutable_offset(i, 4);
+    let update = block.get_u32_le().unwrap() ^ seed;
+
+    block.write_into(&update.to_le_bytes(), 0);
+  });
+
+  result
+}
+
 /// Gets the checksum of a Generation 3 byte array.
 ///
 /// # Arguments
 /// `data` - Decrypted Pokémon data.
 pub fn get_generatation3_checksum(data: &[u8]) -> u16 {
-    let mut checksum = 0;
+    let mut checksum: u16 = 0;
     let span = &data[0x20..(SIZE_GENERATION_3_STORED as usize)];
 
     // for (int i = 0; i < span.Length; i += 2)
     (0..(span.len())).step_by(2).for_each(|i| {
-      checksum += span.get_u16_le_offset(i).unwrap();
+      checksum = checksum.wrapping_add(span.get_u16_le_offset(i).unwrap());
     });
 
     checksum
 }
 
-/// Decrypts the input data into a new array if it is encrypted, and updates the reference.
+/// Decrypts the input data into a new array if it is encrypted, otherwise returns it unchanged.
 ///
 /// # Remarks
 /// Generation 3 Format encryption check which verifies the checksum
-pub fn decrypt_generatation3_array_if_encrypted(mut data: Vec<u8>)
+pub fn decrypt_generatation3_array_if_encrypted(data: Vec<u8>) -> Vec<u8>
 {
     let checksum = get_generatation3_checksum(&data);
 
     if checksum != data.get_u16_le_offset(0x1C).unwrap() {
-      let temp = decrypt_generation3_array(&data);
-      data = temp;
+      return decrypt_generation3_array(&data);
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt_generation3_array, encrypt_generation3_array};
+
+    #[test]
+    fn encryption_roundtrip() {
+        let mut data: Vec<u8> = (0..100).map(|value| value as u8).collect();
+        // PID 13, a shuffle that moves every block
+        data[0..4].copy_from_slice(&13u32.to_le_bytes());
+
+        let encrypted = encrypt_generation3_array(&data);
+        assert_ne!(encrypted, data);
+        assert_eq!(encrypted[80..], data[80..]);
+        assert_eq!(decrypt_generation3_array(&encrypted), data);
     }
 }
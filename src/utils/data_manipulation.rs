@@ -6,23 +6,32 @@ pub trait SliceUtils {
     fn get_mutable_slice(&mut self, start: usize, end: usize) -> &mut [u8];
 
     fn get_offset(&self, offset: usize, byte_quantity: usize) -> &[u8];
+    /// Gets the bytes at the offset, `None` when they are outside of the data
+    fn get_offset_checked(&self, offset: usize, byte_quantity: usize) -> Option<&[u8]>;
     fn get_mutable_offset(&mut self, offset: usize, byte_quantity: usize) -> &mut [u8];
 
     fn get_u16_le(&self) -> Result<u16, TryFromSliceError>;
     fn get_u32_le(&self) -> Result<u32, TryFromSliceError>;
 
+    fn get_u16_be(&self) -> Result<u16, TryFromSliceError>;
+
+    // Values outside of the data fail the conversion instead of panicking
     fn get_u16_le_offset(&self, offset: usize) -> Result<u16, TryFromSliceError> {
-        self.get_offset(offset, 2).get_u16_le()
+        Ok(u16::from_le_bytes(self.get_offset_checked(offset, 2).unwrap_or_default().try_into()?))
     }
 
     fn get_u32_le_offset(&self, offset: usize) -> Result<u32, TryFromSliceError> {
-        self.get_offset(offset, 4).get_u32_le()
+        Ok(u32::from_le_bytes(self.get_offset_checked(offset, 4).unwrap_or_default().try_into()?))
+    }
+
+    fn get_u16_be_offset(&self, offset: usize) -> Result<u16, TryFromSliceError> {
+        Ok(u16::from_be_bytes(self.get_offset_checked(offset, 2).unwrap_or_default().try_into()?))
     }
 }
 
 impl SliceUtils for [u8] {
     fn write_into(&mut self, data: &[u8], offset: usize) {
-        self[offset..(data.len())].copy_from_slice(data);
+        self[offset..offset + data.len()].copy_from_slice(data);
     }
 
     fn get_mutable_slice(&mut self, start: usize, end: usize) -> &mut [u8] {
@@ -33,6 +42,10 @@ impl SliceUtils for [u8] {
         &self[offset..offset + byte_quantity]
     }
 
+    fn get_offset_checked(&self, offset: usize, byte_quantity: usize) -> Option<&[u8]> {
+        self.get(offset..offset.checked_add(byte_quantity)?)
+    }
+
     fn get_mutable_offset(&mut self, offset: usize, byte_quantity: usize) -> &mut [u8] {
         &mut self[offset..offset + byte_quantity]
     }
@@ -44,11 +57,15 @@ impl SliceUtils for [u8] {
     fn get_u32_le(&self) -> Result<u32, TryFromSliceError> {
         Ok(u32::from_le_bytes(self[..4].try_into()?))
     }
+
+    fn get_u16_be(&self) -> Result<u16, TryFromSliceError> {
+        Ok(u16::from_be_bytes(self[..2].try_into()?))
+    }
 }
 
 impl SliceUtils for Vec<u8> {
     fn write_into(&mut self, data: &[u8], offset: usize) {
-        self[offset..(data.len())].copy_from_slice(data);
+        self[offset..offset + data.len()].copy_from_slice(data);
     }
 
     fn get_mutable_slice(&mut self, start: usize, end: usize) -> &mut [u8] {
@@ -59,6 +76,10 @@ impl SliceUtils for Vec<u8> {
         &self[offset..offset + byte_quantity]
     }
 
+    fn get_offset_checked(&self, offset: usize, byte_quantity: usize) -> Option<&[u8]> {
+        self.get(offset..offset.checked_add(byte_quantity)?)
+    }
+
     fn get_mutable_offset(&mut self, offset: usize, byte_quantity: usize) -> &mut [u8] {
         &mut self[offset..offset + byte_quantity]
     }
@@ -70,6 +91,10 @@ impl SliceUtils for Vec<u8> {
     fn get_u32_le(&self) -> Result<u32, TryFromSliceError> {
         Ok(u32::from_le_bytes(self[..4].try_into()?))
     }
+
+    fn get_u16_be(&self) -> Result<u16, TryFromSliceError> {
+        Ok(u16::from_be_bytes(self[..2].try_into()?))
+    }
 }
 
 pub trait FlagTrait {
@@ -82,7 +107,7 @@ impl FlagTrait for [u8] {
         // Ensure bit access is 0-7
         bit_index &= 7;
 
-        self[offset] >> bit_index != 0
+        (self[offset] >> bit_index) & 1 != 0
     }
 
     fn set_flag(&mut self, offset: usize, mut bit_index: u8, value: bool) {
@@ -101,7 +126,7 @@ impl FlagTrait for Vec<u8> {
         // Ensure bit access is between 0 and 7
         bit_index &= 7;
 
-        self[offset] >> bit_index != 0
+        (self[offset] >> bit_index) & 1 != 0
     }
 
     fn set_flag(&mut self, offset: usize, mut bit_index: u8, value: bool) {
@@ -145,7 +170,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::data_manipulation::concat_array;
+    use crate::utils::data_manipulation::{concat_array, SliceUtils};
 
     #[test]
     fn concat_two_arrays() {
@@ -167,6 +192,21 @@ mod tests {
         assert_eq!(RESULT, [1, 2, 3, 4, 5, 6, 7, 8]);
     }
 
+    #[test]
+    fn reading_outside_of_the_data_fails() {
+        let data: Vec<u8> = vec![0x34, 0x12, 0x00];
+        assert_eq!(data.get_u16_le_offset(0).unwrap(), 0x1234);
+        assert!(data.get_u16_le_offset(2).is_err());
+        assert!(data.get_u32_le_offset(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn write_into_at_offset() {
+        let mut data: Vec<u8> = vec![0; 6];
+        data.write_into(&[1, 2], 3);
+        assert_eq!(data, [0, 0, 0, 1, 2, 0]);
+    }
+
     #[test]
     fn fuck() {
         let mut array_1: Vec<u8> = vec![0, 1, 2, 3];
@@ -9,14 +9,22 @@ pub fn checksum_32bit(data: &[u8], initial: u32) -> u16 {
     let mut result = initial;
     // for (int i = 0; i < data.Length; i += 4)
     (0..(data.len())).step_by(4).for_each(|i| {
-      result += data.get_u32_le_offset(i).unwrap();
+      result = result.wrapping_add(data.get_u32_le_offset(i).unwrap());
     });
 
-    (result + result.checked_shr(16).unwrap_or(0)) as u16
+    result.wrapping_add(result >> 16) as u16
 }
 
 #[cfg(test)]
 mod tests {
+    use super::checksum_32bit;
+
+    #[test]
+    fn checksum_wraps_around() {
+        assert_eq!(checksum_32bit(&[0xFF; 8], 0), 0xFFFD);
+        assert_eq!(checksum_32bit(&[1, 0, 0, 0, 0, 0, 1, 0], 0), 2);
+    }
+
     #[test]
     fn fuck() {
         let a = u32::MAX;